@@ -0,0 +1,6 @@
+ALTER TABLE users DROP CONSTRAINT users_username_key;
+
+-- The username keys are populated, and the email addresses normalized, by the migration runner once
+-- this file has been applied, since they need the same case-folding and normalization as
+-- `Username::key()` and `Email::from_str()`.
+ALTER TABLE users ADD COLUMN username_key TEXT;
@@ -0,0 +1,13 @@
+CREATE TABLE sessions (
+  session_id UUID PRIMARY KEY,
+  version UUID NOT NULL,
+  created TIMESTAMP WITH TIME ZONE NOT NULL,
+  updated TIMESTAMP WITH TIME ZONE NOT NULL,
+  user_id UUID NOT NULL REFERENCES users(user_id) ON DELETE CASCADE,
+  device_name TEXT NULL,
+  user_agent TEXT NULL,
+  ip_address TEXT NULL,
+  last_seen TIMESTAMP WITH TIME ZONE NOT NULL
+);
+
+CREATE INDEX sessions_user_id_idx ON sessions(user_id);
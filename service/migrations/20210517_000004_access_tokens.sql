@@ -0,0 +1,15 @@
+CREATE TABLE access_tokens (
+  token_id UUID PRIMARY KEY,
+  version UUID NOT NULL,
+  created TIMESTAMP WITH TIME ZONE NOT NULL,
+  updated TIMESTAMP WITH TIME ZONE NOT NULL,
+  user_id UUID NOT NULL REFERENCES users(user_id) ON DELETE CASCADE,
+  name TEXT NOT NULL,
+  scopes TEXT[] NOT NULL,
+  token_hash TEXT NOT NULL,
+  expires TIMESTAMP WITH TIME ZONE NOT NULL,
+  last_used TIMESTAMP WITH TIME ZONE NULL
+);
+
+CREATE UNIQUE INDEX access_tokens_token_hash_idx ON access_tokens(token_hash);
+CREATE INDEX access_tokens_user_id_idx ON access_tokens(user_id);
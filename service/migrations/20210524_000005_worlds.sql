@@ -0,0 +1,36 @@
+CREATE TABLE worlds (
+  world_id UUID PRIMARY KEY,
+  version UUID NOT NULL,
+  created TIMESTAMP WITH TIME ZONE NOT NULL,
+  updated TIMESTAMP WITH TIME ZONE NOT NULL,
+  name TEXT NOT NULL,
+  description TEXT NULL
+);
+
+CREATE TABLE world_members (
+  world_id UUID NOT NULL REFERENCES worlds(world_id) ON DELETE CASCADE,
+  user_id UUID NOT NULL REFERENCES users(user_id) ON DELETE CASCADE,
+  role TEXT NOT NULL,
+  joined TIMESTAMP WITH TIME ZONE NOT NULL,
+  PRIMARY KEY (world_id, user_id)
+);
+
+CREATE INDEX world_members_user_id_idx ON world_members(user_id);
+
+CREATE TABLE world_invitations (
+  invitation_id UUID PRIMARY KEY,
+  version UUID NOT NULL,
+  created TIMESTAMP WITH TIME ZONE NOT NULL,
+  updated TIMESTAMP WITH TIME ZONE NOT NULL,
+  world_id UUID NOT NULL REFERENCES worlds(world_id) ON DELETE CASCADE,
+  invited_by UUID NOT NULL REFERENCES users(user_id) ON DELETE CASCADE,
+  invitee_username TEXT NULL,
+  invitee_email TEXT NULL,
+  role TEXT NOT NULL,
+  token_hash TEXT NOT NULL,
+  expires TIMESTAMP WITH TIME ZONE NOT NULL,
+  CHECK ((invitee_username IS NULL) <> (invitee_email IS NULL))
+);
+
+CREATE UNIQUE INDEX world_invitations_token_hash_idx ON world_invitations(token_hash);
+CREATE INDEX world_invitations_world_id_idx ON world_invitations(world_id);
@@ -0,0 +1,22 @@
+CREATE TABLE articles (
+  article_id UUID PRIMARY KEY,
+  version UUID NOT NULL,
+  created TIMESTAMP WITH TIME ZONE NOT NULL,
+  updated TIMESTAMP WITH TIME ZONE NOT NULL,
+  world_id UUID NOT NULL REFERENCES worlds(world_id) ON DELETE CASCADE,
+  title TEXT NOT NULL,
+  title_key TEXT NOT NULL,
+  slug TEXT NOT NULL,
+  body TEXT NOT NULL,
+  category TEXT NULL,
+  CONSTRAINT articles_title_key UNIQUE (world_id, title_key),
+  CONSTRAINT articles_slug_key UNIQUE (world_id, slug)
+);
+
+CREATE TABLE article_links (
+  article_id UUID NOT NULL REFERENCES articles(article_id) ON DELETE CASCADE,
+  target_key TEXT NOT NULL,
+  PRIMARY KEY (article_id, target_key)
+);
+
+CREATE INDEX article_links_target_key_idx ON article_links(target_key);
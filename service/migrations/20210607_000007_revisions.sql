@@ -0,0 +1,11 @@
+CREATE TABLE revisions (
+  resource_type TEXT NOT NULL,
+  resource_id TEXT NOT NULL,
+  version UUID NOT NULL,
+  changed_by UUID NULL REFERENCES users(user_id) ON DELETE SET NULL,
+  changed_at TIMESTAMP WITH TIME ZONE NOT NULL,
+  snapshot JSONB NOT NULL,
+  PRIMARY KEY (resource_type, resource_id, version)
+);
+
+CREATE INDEX revisions_resource_idx ON revisions(resource_type, resource_id, changed_at);
@@ -0,0 +1,14 @@
+CREATE TABLE entities (
+  entity_id UUID PRIMARY KEY,
+  version UUID NOT NULL,
+  created TIMESTAMP WITH TIME ZONE NOT NULL,
+  updated TIMESTAMP WITH TIME ZONE NOT NULL,
+  world_id UUID NOT NULL REFERENCES worlds(world_id) ON DELETE CASCADE,
+  kind TEXT NOT NULL,
+  name TEXT NOT NULL,
+  summary TEXT NULL,
+  attributes JSONB NOT NULL,
+  custom JSONB NOT NULL
+);
+
+CREATE INDEX entities_world_id_kind_idx ON entities(world_id, kind);
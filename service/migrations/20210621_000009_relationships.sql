@@ -0,0 +1,17 @@
+CREATE TABLE relationships (
+  relationship_id UUID PRIMARY KEY,
+  version UUID NOT NULL,
+  created TIMESTAMP WITH TIME ZONE NOT NULL,
+  updated TIMESTAMP WITH TIME ZONE NOT NULL,
+  world_id UUID NOT NULL REFERENCES worlds(world_id) ON DELETE CASCADE,
+  source_id UUID NOT NULL REFERENCES entities(entity_id) ON DELETE CASCADE,
+  target_id UUID NOT NULL REFERENCES entities(entity_id) ON DELETE CASCADE,
+  kind TEXT NOT NULL,
+  starts TEXT NULL,
+  ends TEXT NULL,
+
+  UNIQUE(source_id, target_id, kind)
+);
+
+CREATE INDEX relationships_world_id_idx ON relationships(world_id);
+CREATE INDEX relationships_target_id_kind_idx ON relationships(target_id, kind);
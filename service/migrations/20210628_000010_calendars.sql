@@ -0,0 +1,29 @@
+CREATE TABLE calendars (
+  calendar_id UUID PRIMARY KEY,
+  version UUID NOT NULL,
+  created TIMESTAMP WITH TIME ZONE NOT NULL,
+  updated TIMESTAMP WITH TIME ZONE NOT NULL,
+  world_id UUID NOT NULL REFERENCES worlds(world_id) ON DELETE CASCADE,
+  name TEXT NOT NULL,
+  definition JSONB NOT NULL
+);
+
+CREATE INDEX calendars_world_id_idx ON calendars(world_id);
+
+CREATE TABLE events (
+  event_id UUID PRIMARY KEY,
+  version UUID NOT NULL,
+  created TIMESTAMP WITH TIME ZONE NOT NULL,
+  updated TIMESTAMP WITH TIME ZONE NOT NULL,
+  world_id UUID NOT NULL REFERENCES worlds(world_id) ON DELETE CASCADE,
+  title TEXT NOT NULL,
+  description TEXT NULL,
+  starts BIGINT NOT NULL,
+  ends BIGINT NULL,
+  entity_ids UUID[] NOT NULL,
+
+  CHECK (ends IS NULL OR ends >= starts)
+);
+
+CREATE INDEX events_world_id_starts_idx ON events(world_id, starts);
+CREATE INDEX events_entity_ids_idx ON events USING GIN(entity_ids);
@@ -0,0 +1,22 @@
+CREATE TABLE uploads (
+  upload_id UUID PRIMARY KEY,
+  version UUID NOT NULL,
+  created TIMESTAMP WITH TIME ZONE NOT NULL,
+  updated TIMESTAMP WITH TIME ZONE NOT NULL,
+  owner_id UUID NOT NULL REFERENCES users(user_id) ON DELETE CASCADE,
+  world_id UUID NULL REFERENCES worlds(world_id) ON DELETE CASCADE,
+  entity_id UUID NULL REFERENCES entities(entity_id) ON DELETE SET NULL,
+  purpose TEXT NOT NULL,
+  filename TEXT NULL,
+  content_type TEXT NOT NULL,
+  size BIGINT NOT NULL,
+  checksum TEXT NOT NULL,
+  blob_key TEXT NOT NULL,
+  width INTEGER NULL,
+  height INTEGER NULL,
+  thumbnail_key TEXT NULL,
+  thumbnail_size BIGINT NULL
+);
+
+CREATE INDEX uploads_world_id_entity_id_idx ON uploads(world_id, entity_id);
+CREATE INDEX uploads_owner_id_purpose_idx ON uploads(owner_id, purpose);
@@ -0,0 +1,51 @@
+CREATE TABLE maps (
+  map_id UUID PRIMARY KEY,
+  version UUID NOT NULL,
+  created TIMESTAMP WITH TIME ZONE NOT NULL,
+  updated TIMESTAMP WITH TIME ZONE NOT NULL,
+  world_id UUID NOT NULL REFERENCES worlds(world_id) ON DELETE CASCADE,
+  name TEXT NOT NULL,
+  upload_id UUID NULL REFERENCES uploads(upload_id) ON DELETE SET NULL,
+  width INTEGER NOT NULL,
+  height INTEGER NOT NULL,
+  max_zoom INTEGER NOT NULL,
+  tile_prefix TEXT NOT NULL
+);
+
+CREATE INDEX maps_world_id_idx ON maps(world_id);
+
+CREATE TABLE map_layers (
+  layer_id UUID PRIMARY KEY,
+  version UUID NOT NULL,
+  created TIMESTAMP WITH TIME ZONE NOT NULL,
+  updated TIMESTAMP WITH TIME ZONE NOT NULL,
+  map_id UUID NOT NULL REFERENCES maps(map_id) ON DELETE CASCADE,
+  name TEXT NOT NULL,
+  position INTEGER NOT NULL,
+  visible BOOLEAN NOT NULL
+);
+
+CREATE INDEX map_layers_map_id_idx ON map_layers(map_id);
+
+CREATE TABLE map_markers (
+  marker_id UUID PRIMARY KEY,
+  version UUID NOT NULL,
+  created TIMESTAMP WITH TIME ZONE NOT NULL,
+  updated TIMESTAMP WITH TIME ZONE NOT NULL,
+  map_id UUID NOT NULL REFERENCES maps(map_id) ON DELETE CASCADE,
+  layer_id UUID NOT NULL REFERENCES map_layers(layer_id) ON DELETE CASCADE,
+  label TEXT NOT NULL,
+  description TEXT NULL,
+  shape JSONB NOT NULL,
+  location_id UUID NULL REFERENCES entities(entity_id) ON DELETE SET NULL,
+  article_id UUID NULL REFERENCES articles(article_id) ON DELETE SET NULL,
+  min_x DOUBLE PRECISION NOT NULL,
+  min_y DOUBLE PRECISION NOT NULL,
+  max_x DOUBLE PRECISION NOT NULL,
+  max_y DOUBLE PRECISION NOT NULL,
+
+  CHECK (location_id IS NULL OR article_id IS NULL)
+);
+
+CREATE INDEX map_markers_map_id_layer_id_idx ON map_markers(map_id, layer_id);
+CREATE INDEX map_markers_bounds_idx ON map_markers USING GIST(box(point(min_x, min_y), point(max_x, max_y)));
@@ -0,0 +1,38 @@
+ALTER TABLE worlds ADD COLUMN language TEXT NOT NULL DEFAULT 'english';
+
+CREATE TABLE search_documents (
+  resource_id TEXT PRIMARY KEY,
+  world_id UUID NOT NULL REFERENCES worlds(world_id) ON DELETE CASCADE,
+  kind TEXT NOT NULL,
+  title TEXT NOT NULL,
+  body TEXT NOT NULL,
+  document TSVECTOR NOT NULL,
+  updated TIMESTAMP WITH TIME ZONE NOT NULL
+);
+
+CREATE INDEX search_documents_world_id_kind_idx ON search_documents(world_id, kind);
+CREATE INDEX search_documents_document_idx ON search_documents USING GIN(document);
+
+INSERT INTO search_documents(resource_id, world_id, kind, title, body, document, updated)
+SELECT a.article_id::TEXT, a.world_id, 'article', a.title, a.body,
+  setweight(to_tsvector(w.language::REGCONFIG, a.title), 'A') || setweight(to_tsvector(w.language::REGCONFIG, a.body), 'B'),
+  now()
+FROM articles a
+JOIN worlds w ON w.world_id = a.world_id;
+
+WITH entity_text AS (
+  SELECT e.entity_id, e.world_id, e.kind, e.name,
+    concat_ws(E'\n', e.summary, (
+      SELECT string_agg(t.value #>> '{}', E'\n')
+      FROM jsonb_path_query(e.attributes || e.custom, 'strict $.**') AS t(value)
+      WHERE jsonb_typeof(t.value) = 'string'
+        AND t.value #>> '{}' !~* '^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$'
+    )) AS body
+  FROM entities e
+)
+INSERT INTO search_documents(resource_id, world_id, kind, title, body, document, updated)
+SELECT t.entity_id::TEXT, t.world_id, t.kind, t.name, t.body,
+  setweight(to_tsvector(w.language::REGCONFIG, t.name), 'A') || setweight(to_tsvector(w.language::REGCONFIG, t.body), 'B'),
+  now()
+FROM entity_text t
+JOIN worlds w ON w.world_id = t.world_id;
@@ -0,0 +1,52 @@
+ALTER TABLE search_documents ADD COLUMN tags TEXT[] NOT NULL DEFAULT '{}';
+
+CREATE TABLE tags (
+  tag_id UUID PRIMARY KEY,
+  version UUID NOT NULL,
+  created TIMESTAMP WITH TIME ZONE NOT NULL,
+  updated TIMESTAMP WITH TIME ZONE NOT NULL,
+  world_id UUID NOT NULL REFERENCES worlds(world_id) ON DELETE CASCADE,
+  name TEXT NOT NULL
+);
+
+CREATE UNIQUE INDEX tags_world_id_name_key ON tags(world_id, LOWER(name));
+
+CREATE TABLE categories (
+  category_id UUID PRIMARY KEY,
+  version UUID NOT NULL,
+  created TIMESTAMP WITH TIME ZONE NOT NULL,
+  updated TIMESTAMP WITH TIME ZONE NOT NULL,
+  world_id UUID NOT NULL REFERENCES worlds(world_id) ON DELETE CASCADE,
+  name TEXT NOT NULL,
+  parent_id UUID NULL REFERENCES categories(category_id) ON DELETE SET NULL
+);
+
+CREATE INDEX categories_world_id_idx ON categories(world_id);
+
+CREATE TABLE content_tags (
+  tag_id UUID NOT NULL REFERENCES tags(tag_id) ON DELETE CASCADE,
+  kind TEXT NOT NULL,
+  article_id UUID NULL REFERENCES articles(article_id) ON DELETE CASCADE,
+  entity_id UUID NULL REFERENCES entities(entity_id) ON DELETE CASCADE,
+
+  CHECK ((article_id IS NULL) <> (entity_id IS NULL)),
+  UNIQUE (tag_id, article_id),
+  UNIQUE (tag_id, entity_id)
+);
+
+CREATE INDEX content_tags_article_id_idx ON content_tags(article_id);
+CREATE INDEX content_tags_entity_id_idx ON content_tags(entity_id);
+
+CREATE TABLE content_categories (
+  category_id UUID NOT NULL REFERENCES categories(category_id) ON DELETE CASCADE,
+  kind TEXT NOT NULL,
+  article_id UUID NULL REFERENCES articles(article_id) ON DELETE CASCADE,
+  entity_id UUID NULL REFERENCES entities(entity_id) ON DELETE CASCADE,
+
+  CHECK ((article_id IS NULL) <> (entity_id IS NULL)),
+  UNIQUE (category_id, article_id),
+  UNIQUE (category_id, entity_id)
+);
+
+CREATE INDEX content_categories_article_id_idx ON content_categories(article_id);
+CREATE INDEX content_categories_entity_id_idx ON content_categories(entity_id);
@@ -0,0 +1,24 @@
+CREATE TABLE comments (
+  comment_id UUID PRIMARY KEY,
+  version UUID NOT NULL,
+  created TIMESTAMP WITH TIME ZONE NOT NULL,
+  updated TIMESTAMP WITH TIME ZONE NOT NULL,
+  world_id UUID NOT NULL REFERENCES worlds(world_id) ON DELETE CASCADE,
+  kind TEXT NOT NULL,
+  article_id UUID NULL REFERENCES articles(article_id) ON DELETE CASCADE,
+  entity_id UUID NULL REFERENCES entities(entity_id) ON DELETE CASCADE,
+  parent_id UUID NULL REFERENCES comments(comment_id) ON DELETE CASCADE,
+  author_id UUID NOT NULL REFERENCES users(user_id) ON DELETE CASCADE,
+  body TEXT NOT NULL,
+  mentions UUID[] NOT NULL,
+  resolved_by UUID NULL,
+  resolved_at TIMESTAMP WITH TIME ZONE NULL,
+  removed_by UUID NULL,
+  removed_at TIMESTAMP WITH TIME ZONE NULL,
+
+  CHECK ((article_id IS NULL) <> (entity_id IS NULL))
+);
+
+CREATE INDEX comments_article_id_idx ON comments(article_id, created);
+CREATE INDEX comments_entity_id_idx ON comments(entity_id, created);
+CREATE INDEX comments_parent_id_idx ON comments(parent_id, created);
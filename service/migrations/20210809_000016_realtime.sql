@@ -0,0 +1,51 @@
+-- Publish every change to a resource within a world, so that everyone watching the world can be
+-- kept up to date. The arguments are the kind of resource and the column that holds its ID, and
+-- optionally a column to take the kind of resource from instead.
+CREATE FUNCTION notify_world_change() RETURNS TRIGGER AS $$
+DECLARE
+  resource JSONB;
+BEGIN
+  IF TG_OP = 'DELETE' THEN
+    resource := to_jsonb(OLD);
+  ELSE
+    resource := to_jsonb(NEW);
+  END IF;
+
+  -- Some resources, such as the avatars of users, don't belong to any world.
+  IF resource ->> 'world_id' IS NULL THEN
+    RETURN NULL;
+  END IF;
+
+  PERFORM pg_notify('world_events', json_build_object(
+    'type', 'change',
+    'worldId', resource ->> 'world_id',
+    'action', CASE TG_OP WHEN 'INSERT' THEN 'created' WHEN 'UPDATE' THEN 'updated' ELSE 'deleted' END,
+    'resource', CASE WHEN TG_NARGS > 2 THEN resource ->> TG_ARGV[2] ELSE TG_ARGV[0] END,
+    'resourceId', resource ->> TG_ARGV[1],
+    'version', CASE WHEN TG_OP = 'DELETE' THEN NULL ELSE resource ->> 'version' END
+  )::TEXT);
+
+  RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER articles_notify AFTER INSERT OR UPDATE OR DELETE ON articles
+  FOR EACH ROW EXECUTE FUNCTION notify_world_change('article', 'article_id');
+CREATE TRIGGER entities_notify AFTER INSERT OR UPDATE OR DELETE ON entities
+  FOR EACH ROW EXECUTE FUNCTION notify_world_change('entity', 'entity_id', 'kind');
+CREATE TRIGGER relationships_notify AFTER INSERT OR UPDATE OR DELETE ON relationships
+  FOR EACH ROW EXECUTE FUNCTION notify_world_change('relationship', 'relationship_id');
+CREATE TRIGGER calendars_notify AFTER INSERT OR UPDATE OR DELETE ON calendars
+  FOR EACH ROW EXECUTE FUNCTION notify_world_change('calendar', 'calendar_id');
+CREATE TRIGGER events_notify AFTER INSERT OR UPDATE OR DELETE ON events
+  FOR EACH ROW EXECUTE FUNCTION notify_world_change('event', 'event_id');
+CREATE TRIGGER uploads_notify AFTER INSERT OR UPDATE OR DELETE ON uploads
+  FOR EACH ROW EXECUTE FUNCTION notify_world_change('upload', 'upload_id');
+CREATE TRIGGER maps_notify AFTER INSERT OR UPDATE OR DELETE ON maps
+  FOR EACH ROW EXECUTE FUNCTION notify_world_change('map', 'map_id');
+CREATE TRIGGER tags_notify AFTER INSERT OR UPDATE OR DELETE ON tags
+  FOR EACH ROW EXECUTE FUNCTION notify_world_change('tag', 'tag_id');
+CREATE TRIGGER categories_notify AFTER INSERT OR UPDATE OR DELETE ON categories
+  FOR EACH ROW EXECUTE FUNCTION notify_world_change('category', 'category_id');
+CREATE TRIGGER comments_notify AFTER INSERT OR UPDATE OR DELETE ON comments
+  FOR EACH ROW EXECUTE FUNCTION notify_world_change('comment', 'comment_id');
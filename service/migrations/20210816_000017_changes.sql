@@ -0,0 +1,57 @@
+-- The log of every change to a resource within a world, so that clients can catch up on whatever
+-- they missed. Changes are ordered by the transaction that made them and then by the order that they
+-- were made in, and are only read once every older transaction has finished. This means that a
+-- change can never appear before one that has already been read.
+--
+-- The log isn't tied to the world, since the changes to its resources are still being recorded
+-- while it is deleted.
+CREATE TABLE changes (
+  change_id BIGSERIAL PRIMARY KEY,
+  transaction_id BIGINT NOT NULL DEFAULT txid_current(),
+  world_id UUID NOT NULL,
+  action TEXT NOT NULL,
+  resource TEXT NOT NULL,
+  resource_id TEXT NOT NULL,
+  version UUID NULL,
+  occurred TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX changes_world_id_idx ON changes(world_id, transaction_id, change_id);
+
+-- Record every change in the log as well as publishing it.
+CREATE OR REPLACE FUNCTION notify_world_change() RETURNS TRIGGER AS $$
+DECLARE
+  data JSONB;
+  change changes%ROWTYPE;
+BEGIN
+  IF TG_OP = 'DELETE' THEN
+    data := to_jsonb(OLD);
+  ELSE
+    data := to_jsonb(NEW);
+  END IF;
+
+  -- Some resources, such as the avatars of users, don't belong to any world.
+  IF data ->> 'world_id' IS NULL THEN
+    RETURN NULL;
+  END IF;
+
+  INSERT INTO changes(world_id, action, resource, resource_id, version) VALUES (
+    (data ->> 'world_id')::UUID,
+    CASE TG_OP WHEN 'INSERT' THEN 'created' WHEN 'UPDATE' THEN 'updated' ELSE 'deleted' END,
+    CASE WHEN TG_NARGS > 2 THEN data ->> TG_ARGV[2] ELSE TG_ARGV[0] END,
+    data ->> TG_ARGV[1],
+    CASE WHEN TG_OP = 'DELETE' THEN NULL ELSE (data ->> 'version')::UUID END
+  ) RETURNING * INTO change;
+
+  PERFORM pg_notify('world_events', json_build_object(
+    'type', 'change',
+    'worldId', change.world_id,
+    'action', change.action,
+    'resource', change.resource,
+    'resourceId', change.resource_id,
+    'version', change.version
+  )::TEXT);
+
+  RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
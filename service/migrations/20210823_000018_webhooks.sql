@@ -0,0 +1,75 @@
+-- Webhooks that are sent the changes to a world as they happen.
+CREATE TABLE webhooks (
+  webhook_id UUID PRIMARY KEY,
+  version UUID NOT NULL,
+  created TIMESTAMP WITH TIME ZONE NOT NULL,
+  updated TIMESTAMP WITH TIME ZONE NOT NULL,
+  world_id UUID NOT NULL REFERENCES worlds(world_id) ON DELETE CASCADE,
+  created_by UUID NULL REFERENCES users(user_id) ON DELETE SET NULL,
+  url TEXT NOT NULL,
+  -- Stored as it is rather than hashed, since payloads have to be signed with it.
+  secret TEXT NOT NULL,
+  events TEXT[] NOT NULL,
+  active BOOLEAN NOT NULL DEFAULT TRUE
+);
+
+CREATE INDEX webhooks_world_id_idx ON webhooks(world_id, created);
+
+-- The queue of changes to deliver to webhooks, which is kept afterwards as the log of what was sent.
+CREATE TABLE webhook_deliveries (
+  delivery_id BIGSERIAL PRIMARY KEY,
+  webhook_id UUID NOT NULL REFERENCES webhooks(webhook_id) ON DELETE CASCADE,
+  event TEXT NOT NULL,
+  payload JSONB NOT NULL,
+  status TEXT NOT NULL DEFAULT 'pending',
+  attempts INTEGER NOT NULL DEFAULT 0,
+  next_attempt TIMESTAMP WITH TIME ZONE NULL DEFAULT NOW(),
+  created TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX webhook_deliveries_webhook_id_idx ON webhook_deliveries(webhook_id, delivery_id);
+CREATE INDEX webhook_deliveries_due_idx ON webhook_deliveries(next_attempt) WHERE status = 'pending';
+
+-- Every attempt that has been made at a delivery, and how the webhook responded.
+CREATE TABLE webhook_attempts (
+  delivery_id BIGINT NOT NULL REFERENCES webhook_deliveries(delivery_id) ON DELETE CASCADE,
+  attempted TIMESTAMP WITH TIME ZONE NOT NULL,
+  status_code INTEGER NULL,
+  error TEXT NULL,
+  duration_ms INTEGER NOT NULL
+);
+
+CREATE INDEX webhook_attempts_delivery_id_idx ON webhook_attempts(delivery_id, attempted);
+
+-- Queue a delivery of every change that is recorded to each active webhook in the world that is
+-- interested in it. This happens in the same transaction as the change, so nothing is ever missed.
+CREATE OR REPLACE FUNCTION enqueue_webhook_deliveries() RETURNS TRIGGER AS $$
+DECLARE
+  event TEXT := NEW.resource || '.' || NEW.action;
+BEGIN
+  INSERT INTO webhook_deliveries(webhook_id, event, payload)
+    SELECT w.webhook_id, event, jsonb_build_object(
+      'event', event,
+      'worldId', NEW.world_id,
+      'changeId', NEW.transaction_id || '-' || NEW.change_id,
+      'action', NEW.action,
+      'resource', NEW.resource,
+      'resourceId', NEW.resource_id,
+      'version', NEW.version,
+      'occurred', NEW.occurred
+    )
+    FROM webhooks w
+    WHERE w.world_id = NEW.world_id
+      AND w.active
+      AND EXISTS (
+        SELECT 1 FROM unnest(w.events) f
+        WHERE split_part(f, '.', 1) IN ('*', NEW.resource)
+          AND split_part(f, '.', 2) IN ('*', NEW.action)
+      );
+
+  RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER enqueue_webhook_deliveries AFTER INSERT ON changes
+  FOR EACH ROW EXECUTE FUNCTION enqueue_webhook_deliveries();
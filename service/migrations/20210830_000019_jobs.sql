@@ -0,0 +1,26 @@
+-- The queue of work to be done in the background. Finished jobs are kept for a while afterwards,
+-- including the ones that were given up on, so that they can be looked into.
+CREATE TABLE jobs (
+  job_id BIGSERIAL PRIMARY KEY,
+  kind TEXT NOT NULL,
+  payload JSONB NOT NULL,
+  status TEXT NOT NULL DEFAULT 'pending',
+  attempts INTEGER NOT NULL DEFAULT 0,
+  run_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  last_error TEXT NULL,
+  created TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  finished TIMESTAMP WITH TIME ZONE NULL
+);
+
+CREATE INDEX jobs_due_idx ON jobs(run_at) WHERE status IN ('pending', 'running');
+CREATE INDEX jobs_finished_idx ON jobs(finished) WHERE finished IS NOT NULL;
+
+-- The schedules that recurring jobs are queued on, shared between every instance of the service so
+-- that each run is only queued once.
+CREATE TABLE job_schedules (
+  name TEXT PRIMARY KEY,
+  kind TEXT NOT NULL,
+  payload JSONB NOT NULL,
+  schedule TEXT NOT NULL,
+  next_run TIMESTAMP WITH TIME ZONE NOT NULL
+);
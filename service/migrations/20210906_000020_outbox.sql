@@ -0,0 +1,26 @@
+-- Domain events, written in the same transaction as the change that they describe, waiting to be
+-- published to the sinks that other services consume them from.
+CREATE TABLE outbox_events (
+  event_id BIGSERIAL PRIMARY KEY,
+  aggregate_type TEXT NOT NULL,
+  aggregate_id TEXT NOT NULL,
+  event_type TEXT NOT NULL,
+  payload JSONB NOT NULL,
+  created TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+-- Which events have been published to each sink.
+CREATE TABLE outbox_publications (
+  sink TEXT NOT NULL,
+  event_id BIGINT NOT NULL REFERENCES outbox_events(event_id) ON DELETE CASCADE,
+  published TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  PRIMARY KEY (sink, event_id)
+);
+
+-- The relay that currently has the right to publish to each sink, so that only one instance of the
+-- service publishes to a sink at a time.
+CREATE TABLE outbox_relays (
+  sink TEXT PRIMARY KEY,
+  holder UUID NOT NULL,
+  leased_until TIMESTAMP WITH TIME ZONE NOT NULL
+);
@@ -0,0 +1,16 @@
+-- The idempotency keys that clients sent with unsafe requests, and the responses that were sent back
+-- for them, so that retries of those requests can be replayed. Keys are scoped to the credentials
+-- that the request was made with, and are kept for a limited window.
+CREATE TABLE idempotency_keys (
+  scope TEXT NOT NULL,
+  idempotency_key TEXT NOT NULL,
+  fingerprint TEXT NOT NULL,
+  status_code INTEGER NULL,
+  headers JSONB NULL,
+  body BYTEA NULL,
+  created TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  completed TIMESTAMP WITH TIME ZONE NULL,
+  PRIMARY KEY (scope, idempotency_key)
+);
+
+CREATE INDEX idempotency_keys_created_idx ON idempotency_keys(created);
@@ -0,0 +1,43 @@
+-- Deliveries to webhooks are attempted by the job queue, rather than by polling for the ones that
+-- are due, so every delivery that is queued also queues a job to attempt it.
+CREATE OR REPLACE FUNCTION enqueue_webhook_deliveries() RETURNS TRIGGER AS $$
+DECLARE
+  event TEXT := NEW.resource || '.' || NEW.action;
+BEGIN
+  WITH deliveries AS (
+    INSERT INTO webhook_deliveries(webhook_id, event, payload)
+      SELECT w.webhook_id, event, jsonb_build_object(
+        'event', event,
+        'worldId', NEW.world_id,
+        'changeId', NEW.transaction_id || '-' || NEW.change_id,
+        'action', NEW.action,
+        'resource', NEW.resource,
+        'resourceId', NEW.resource_id,
+        'version', NEW.version,
+        'occurred', NEW.occurred
+      )
+      FROM webhooks w
+      WHERE w.world_id = NEW.world_id
+        AND w.active
+        AND EXISTS (
+          SELECT 1 FROM unnest(w.events) f
+          WHERE split_part(f, '.', 1) IN ('*', NEW.resource)
+            AND split_part(f, '.', 2) IN ('*', NEW.action)
+        )
+      RETURNING webhook_id, delivery_id
+  )
+  INSERT INTO jobs(kind, payload)
+    SELECT 'webhooks.deliver', jsonb_build_object('webhookId', webhook_id, 'deliveryId', delivery_id)
+    FROM deliveries;
+
+  RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Hand the deliveries that are still pending over to the job queue.
+INSERT INTO jobs(kind, payload, run_at)
+  SELECT 'webhooks.deliver', jsonb_build_object('webhookId', webhook_id, 'deliveryId', delivery_id), COALESCE(next_attempt, NOW())
+  FROM webhook_deliveries
+  WHERE status = 'pending';
+
+DROP INDEX webhook_deliveries_due_idx;
@@ -0,0 +1,6 @@
+-- Sessions end when the access token issued for them expires, which is 30 days after they start.
+ALTER TABLE sessions ADD COLUMN expires TIMESTAMP WITH TIME ZONE NULL;
+UPDATE sessions SET expires = created + INTERVAL '30 days';
+ALTER TABLE sessions ALTER COLUMN expires SET NOT NULL;
+
+CREATE INDEX sessions_expires_idx ON sessions(expires);
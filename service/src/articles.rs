@@ -0,0 +1,11 @@
+pub mod component;
+mod endpoints;
+mod model;
+mod rendering;
+mod repository;
+mod service;
+
+pub use model::*;
+pub use rendering::*;
+pub use repository::*;
+pub use service::*;
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use actix_web::web::{delete, get, patch, post, resource, ServiceConfig};
+
+use super::{repository::ArticleRepository, service::ArticleService};
+use crate::{revisions::RevisionService, search::SearchService, server::RouteConfigurer};
+
+/// Component for working with the articles within worlds.
+pub struct Component {
+    pub service: Arc<ArticleService>,
+}
+
+impl Component {
+    /// Create a new articles component.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store articles in
+    /// - `revisions` - The service to record the revision history of articles with
+    /// - `search` - The service to keep the search index of articles up to date with
+    pub fn new(repository: Arc<dyn ArticleRepository>, revisions: Arc<RevisionService>, search: Arc<SearchService>) -> Arc<Self> {
+        let service = Arc::new(ArticleService::new(repository, revisions, search));
+
+        Arc::new(Self { service })
+    }
+}
+
+impl RouteConfigurer for Component {
+    fn configure_routes(&self, config: &mut ServiceConfig) {
+        config.data(self.service.clone());
+
+        config.service(
+            resource("/worlds/{id}/articles")
+                .route(post().to(super::endpoints::create_article::handle))
+                .route(get().to(super::endpoints::list_articles::handle)),
+        );
+        config.service(
+            resource("/worlds/{id}/articles/{article}")
+                .route(get().to(super::endpoints::get_article::handle))
+                .route(patch().to(super::endpoints::patch_article::handle))
+                .route(delete().to(super::endpoints::delete_article::handle)),
+        );
+        config.service(resource("/worlds/{id}/articles/{article}/backlinks").route(get().to(super::endpoints::get_backlinks::handle)));
+        config.service(resource("/worlds/{id}/articles/{article}/revisions").route(get().to(super::endpoints::list_revisions::handle)));
+        config.service(
+            resource("/worlds/{id}/articles/{article}/revisions/{version}").route(get().to(super::endpoints::get_revision::handle)),
+        );
+        config.service(
+            resource("/worlds/{id}/articles/{article}/revisions/{version}/diff").route(get().to(super::endpoints::diff_revisions::handle)),
+        );
+        config.service(
+            resource("/worlds/{id}/articles/{article}/revisions/{version}/restore")
+                .route(post().to(super::endpoints::restore_revision::handle)),
+        );
+    }
+}
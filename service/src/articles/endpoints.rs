@@ -0,0 +1,33 @@
+pub(super) mod create_article;
+pub(super) mod delete_article;
+pub(super) mod diff_revisions;
+pub(super) mod get_article;
+pub(super) mod get_backlinks;
+pub(super) mod get_revision;
+pub(super) mod list_articles;
+pub(super) mod list_revisions;
+mod model;
+pub(super) mod patch_article;
+mod problems;
+pub(super) mod restore_revision;
+
+use crate::{
+    articles::{ArticleResource, ArticleService},
+    worlds::WorldId,
+};
+
+/// Find the article that is referred to in the URL, which can be either its ID or its slug.
+///
+/// # Parameters
+/// - `service` - The article service to load the article from
+/// - `world_id` - The ID of the world that the article belongs to
+/// - `article` - The ID or slug of the article
+///
+/// # Returns
+/// The article, or `None` if it doesn't exist.
+async fn find_article(service: &ArticleService, world_id: &WorldId, article: &str) -> Option<ArticleResource> {
+    match article.parse() {
+        Ok(article_id) => service.get_article_by_id(world_id, &article_id).await,
+        Err(_) => service.get_article_by_slug(world_id, article).await,
+    }
+}
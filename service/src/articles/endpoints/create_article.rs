@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use actix_http::http::StatusCode;
+use actix_web::web::{Data, Path};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{
+    model::{ArticleModel, ArticleResponse},
+    problems::DUPLICATE_TITLE,
+};
+use crate::{
+    articles::{ArticleService, NewArticle, SaveArticleError},
+    authorization::Authentication,
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+        valid::{Valid, Validatable},
+    },
+    tokens::Scope,
+    worlds::{Role, WorldId, WorldService},
+};
+
+/// Create a new article within a world.
+pub async fn handle(
+    service: Data<Arc<ArticleService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<String>,
+    request: Valid<CreateArticleRequest>,
+    authentication: Authentication,
+) -> Result<ArticleResponse, Problem> {
+    let world_id: WorldId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+    let user_id = authentication.require_user()?;
+
+    let request = request.unwrap();
+
+    let article = service
+        .create_article(
+            &world_id,
+            &user_id,
+            NewArticle {
+                title:    request.title,
+                body:     request.body,
+                category: request.category,
+            },
+        )
+        .await
+        .map_err(|e| match e {
+            SaveArticleError::DuplicateTitle => DUPLICATE_TITLE,
+            SaveArticleError::UnknownArticle | SaveArticleError::UnknownError => INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok(ArticleModel::build(&service, article)
+        .await
+        .with_status_code(StatusCode::CREATED)
+        .into())
+}
+
+/// The incoming request to create an article.
+#[derive(Deserialize)]
+pub struct CreateArticleRequest {
+    pub title:    String,
+    pub body:     String,
+    pub category: Option<String>,
+}
+
+impl Validatable for CreateArticleRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "title": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 200
+                },
+                "body": {
+                    "type": "string"
+                },
+                "category": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 100
+                }
+            },
+            "required": [
+                "title",
+                "body"
+            ]
+        })
+    }
+}
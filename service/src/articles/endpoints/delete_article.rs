@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use actix_web::{
+    web::{Data, Path},
+    HttpResponse,
+};
+
+use super::find_article;
+use crate::{
+    articles::ArticleService,
+    authorization::Authentication,
+    http::problem::{Problem, NOT_FOUND},
+    tokens::Scope,
+    worlds::{Role, WorldId, WorldService},
+};
+
+pub async fn handle(
+    service: Data<Arc<ArticleService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<HttpResponse, Problem> {
+    let (world_id, article) = path.into_inner();
+
+    let world_id: WorldId = world_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, world_id = ?world_id, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let article = find_article(&service, &world_id, &article).await.ok_or(NOT_FOUND)?;
+
+    if service.delete_article(&world_id, &article.identity.id).await {
+        Ok(HttpResponse::NoContent().finish())
+    } else {
+        Err(NOT_FOUND.into())
+    }
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path, Query};
+
+use super::find_article;
+use crate::{
+    articles::{ArticleData, ArticleService},
+    authorization::Authentication,
+    http::{
+        problem::{Problem, NOT_FOUND},
+        response::Response,
+    },
+    revisions::{
+        endpoints::{DiffModel, DiffQuery},
+        RevisionService,
+    },
+    tokens::Scope,
+    worlds::{Role, WorldId, WorldService},
+};
+
+/// Show the changes that were made in a revision of an article.
+pub async fn handle(
+    service: Data<Arc<ArticleService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    revisions: Data<Arc<RevisionService>>,
+    path: Path<(String, String, String)>,
+    query: Query<DiffQuery>,
+    authentication: Authentication,
+) -> Result<Response<DiffModel>, Problem> {
+    let (world_id, article, version) = path.into_inner();
+
+    let world_id: WorldId = world_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, world_id = ?world_id, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let article = find_article(&service, &world_id, &article).await.ok_or(NOT_FOUND)?;
+
+    crate::revisions::endpoints::diff_revisions::<ArticleData>(&revisions, &article.identity.id, &version, &query).await
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::{
+    find_article,
+    model::{ArticleSummaryModel, ArticlesModel},
+};
+use crate::{
+    articles::ArticleService,
+    authorization::Authentication,
+    http::{
+        problem::{Problem, NOT_FOUND},
+        response::Response,
+    },
+    tokens::Scope,
+    worlds::{Role, WorldId, WorldService},
+};
+
+/// Get every article that links to this one - i.e. "What links here".
+pub async fn handle(
+    service: Data<Arc<ArticleService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<Response<ArticlesModel>, Problem> {
+    let (world_id, article) = path.into_inner();
+
+    let world_id: WorldId = world_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, world_id = ?world_id, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let article = find_article(&service, &world_id, &article).await.ok_or(NOT_FOUND)?;
+
+    let articles = service
+        .get_backlinks(&article)
+        .await
+        .into_iter()
+        .map(ArticleSummaryModel::from)
+        .collect();
+
+    Ok(Response(ArticlesModel { articles }))
+}
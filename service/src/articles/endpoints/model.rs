@@ -0,0 +1,100 @@
+use actix_web::http::header::{CacheControl, CacheDirective, ETag, EntityTag};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::{
+    articles::{ArticleId, ArticleResource, ArticleService},
+    http::response::{Response, SimpleRespondable},
+};
+
+/// Produce the URL of an article on the HTTP API.
+///
+/// # Parameters
+/// - `article` - The article to produce the URL for
+pub fn article_href(article: &ArticleResource) -> String {
+    format!("/worlds/{}/articles/{}", article.data.world_id, article.identity.id)
+}
+
+/// Summary representation of an article on the HTTP API, used when listing many of them.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleSummaryModel {
+    pub article_id: ArticleId,
+    pub title:      String,
+    pub slug:       String,
+    pub category:   Option<String>,
+    pub created:    DateTime<Utc>,
+    pub updated:    DateTime<Utc>,
+}
+
+impl From<ArticleResource> for ArticleSummaryModel {
+    fn from(article: ArticleResource) -> Self {
+        Self {
+            article_id: article.identity.id,
+            title:      article.data.title,
+            slug:       article.data.slug,
+            category:   article.data.category,
+            created:    article.identity.created,
+            updated:    article.identity.updated,
+        }
+    }
+}
+
+/// Representation of a list of articles on the HTTP API.
+#[derive(Serialize)]
+pub struct ArticlesModel {
+    pub articles: Vec<ArticleSummaryModel>,
+}
+
+/// Representation of a wiki link from an article on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkModel {
+    pub title:      String,
+    pub label:      String,
+    pub article_id: Option<ArticleId>,
+}
+
+/// Full representation of an article on the HTTP API, including the rendered HTML.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleModel {
+    #[serde(flatten)]
+    pub summary: ArticleSummaryModel,
+    pub body:    String,
+    pub html:    String,
+    pub links:   Vec<LinkModel>,
+}
+
+pub type ArticleResponse = Response<SimpleRespondable<ArticleModel>>;
+
+impl ArticleModel {
+    /// Build the full HTTP representation of an article, rendering it in the process.
+    ///
+    /// # Parameters
+    /// - `service` - The article service to render the article with
+    /// - `article` - The article to represent
+    pub async fn build(service: &ArticleService, article: ArticleResource) -> SimpleRespondable<Self> {
+        let rendered = service.render_article(&article, article_href).await;
+        let etag = EntityTag::strong(article.identity.version.to_string());
+
+        let model = Self {
+            body:    article.data.body.clone(),
+            html:    rendered.html,
+            links:   rendered
+                .links
+                .into_iter()
+                .map(|link| LinkModel {
+                    title:      link.link.title,
+                    label:      link.link.label,
+                    article_id: link.target.map(|t| t.identity.id),
+                })
+                .collect(),
+            summary: article.into(),
+        };
+
+        SimpleRespondable::new(model)
+            .with_header(ETag(etag))
+            .with_header(CacheControl(vec![CacheDirective::Private, CacheDirective::NoCache]))
+    }
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::{
+    find_article,
+    model::{ArticleModel, ArticleResponse},
+    problems::DUPLICATE_TITLE,
+};
+use crate::{
+    articles::{ArticleData, ArticleService, UpdateArticleError},
+    authorization::Authentication,
+    http::{
+        patch::Patch,
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+        valid::Validatable,
+    },
+    tokens::Scope,
+    worlds::{Role, WorldId, WorldService},
+};
+
+pub async fn handle(
+    service: Data<Arc<ArticleService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    request: Patch,
+    authentication: Authentication,
+) -> Result<ArticleResponse, Problem> {
+    let (world_id, article) = path.into_inner();
+
+    let world_id: WorldId = world_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, world_id = ?world_id, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+    let user_id = authentication.require_user()?;
+
+    let article = find_article(&service, &world_id, &article).await.ok_or(NOT_FOUND)?;
+
+    let article = service
+        .update_article_by_id(
+            &world_id,
+            &article.identity.id,
+            &user_id,
+            move |article| -> Result<ArticleData, Problem> {
+                let changes = request.apply_to(&PatchModel::from(&article))?;
+
+                Ok(ArticleData {
+                    title: changes.title,
+                    body: changes.body,
+                    category: changes.category,
+                    ..article
+                })
+            },
+        )
+        .await
+        .map_err(|e| match e {
+            UpdateArticleError::UpdateError(p) => p,
+            UpdateArticleError::UnknownArticle => NOT_FOUND.into(),
+            UpdateArticleError::DuplicateTitle => DUPLICATE_TITLE.into(),
+            UpdateArticleError::UnknownError => INTERNAL_SERVER_ERROR.into(),
+        })?;
+
+    Ok(ArticleModel::build(&service, article).await.into())
+}
+
+/// The details of an article that a patch is applied to.
+#[derive(Serialize, Deserialize)]
+pub struct PatchModel {
+    pub title:    String,
+    pub body:     String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+}
+
+impl From<&ArticleData> for PatchModel {
+    fn from(article: &ArticleData) -> Self {
+        Self {
+            title:    article.title.clone(),
+            body:     article.body.clone(),
+            category: article.category.clone(),
+        }
+    }
+}
+
+impl Validatable for PatchModel {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "required": ["title", "body"],
+            "properties": {
+                "title": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 200
+                },
+                "body": {
+                    "type": "string"
+                },
+                "category": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 100
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,10 @@
+use actix_http::http::StatusCode;
+
+use crate::http::problem::SimpleProblemType;
+
+/// Problem to indicate that another article in the world already has the same title.
+pub const DUPLICATE_TITLE: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/articles/duplicate_title",
+    problem_title: "Duplicate Article Title",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
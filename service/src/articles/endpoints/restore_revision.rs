@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::{
+    find_article,
+    model::{ArticleModel, ArticleResponse},
+    problems::DUPLICATE_TITLE,
+};
+use crate::{
+    articles::{ArticleData, ArticleService, UpdateArticleError},
+    authorization::Authentication,
+    http::problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+    revisions::{endpoints::parse_version, Revisable, RevisionService},
+    tokens::Scope,
+    worlds::{Role, WorldId, WorldService},
+};
+
+/// Restore an article to the state that it was in at a previous revision.
+/// This is recorded as a new revision, so that the restore itself can be undone.
+pub async fn handle(
+    service: Data<Arc<ArticleService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    revisions: Data<Arc<RevisionService>>,
+    path: Path<(String, String, String)>,
+    authentication: Authentication,
+) -> Result<ArticleResponse, Problem> {
+    let (world_id, article, version) = path.into_inner();
+
+    let world_id: WorldId = world_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, world_id = ?world_id, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+    let user_id = authentication.require_user()?;
+
+    let article = find_article(&service, &world_id, &article).await.ok_or(NOT_FOUND)?;
+
+    let version = parse_version(&version)?;
+    let snapshot = revisions
+        .get_snapshot::<ArticleData>(&article.identity.id, &version)
+        .await
+        .ok_or(NOT_FOUND)?;
+
+    let article = service
+        .update_article_by_id(&world_id, &article.identity.id, &user_id, move |article| {
+            Ok::<_, Problem>(article.restore(snapshot))
+        })
+        .await
+        .map_err(|e| match e {
+            UpdateArticleError::UpdateError(p) => p,
+            UpdateArticleError::UnknownArticle => NOT_FOUND.into(),
+            UpdateArticleError::DuplicateTitle => DUPLICATE_TITLE.into(),
+            UpdateArticleError::UnknownError => INTERNAL_SERVER_ERROR.into(),
+        })?;
+
+    Ok(ArticleModel::build(&service, article).await.into())
+}
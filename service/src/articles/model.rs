@@ -0,0 +1,69 @@
+mod article_id;
+mod title;
+
+pub use article_id::*;
+use serde::{Deserialize, Serialize};
+pub use title::*;
+
+use super::plain_text;
+use crate::{model::Resource, revisions::Revisable, search::Searchable, worlds::WorldId};
+
+/// The data representing an article within a world.
+#[derive(Debug, Clone)]
+pub struct ArticleData {
+    pub world_id: WorldId,
+    pub title:    String,
+    pub slug:     String,
+    pub body:     String,
+    pub category: Option<String>,
+}
+
+/// Type representing a persisted article.
+pub type ArticleResource = Resource<ArticleId, ArticleData>;
+
+/// The parts of an article that are kept in its revision history.
+/// The slug and the links are derived from these, so don't need keeping separately.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArticleSnapshot {
+    pub title:    String,
+    pub body:     String,
+    pub category: Option<String>,
+}
+
+impl Revisable for ArticleData {
+    const RESOURCE_TYPE: &'static str = "article";
+    type Snapshot = ArticleSnapshot;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        ArticleSnapshot {
+            title:    self.title.clone(),
+            body:     self.body.clone(),
+            category: self.category.clone(),
+        }
+    }
+
+    fn restore(self, snapshot: Self::Snapshot) -> Self {
+        Self {
+            title: snapshot.title,
+            body: snapshot.body,
+            category: snapshot.category,
+            ..self
+        }
+    }
+}
+
+impl Searchable for ArticleData {
+    const SEARCH_KIND: &'static str = "article";
+
+    fn search_world(&self) -> &WorldId {
+        &self.world_id
+    }
+
+    fn search_title(&self) -> &str {
+        &self.title
+    }
+
+    fn search_body(&self) -> String {
+        plain_text(&self.body)
+    }
+}
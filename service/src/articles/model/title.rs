@@ -0,0 +1,56 @@
+use unicode_normalization::UnicodeNormalization;
+
+/// Generate the key to use when comparing article titles, both for uniqueness and for resolving
+/// wiki links to the articles that they refer to.
+///
+/// # Parameters
+/// - `title` - The title of the article
+///
+/// # Returns
+/// The case-folded form of the title, with all whitespace collapsed.
+pub fn title_key(title: &str) -> String {
+    let normalized: String = title.nfkc().collect::<String>().to_lowercase();
+
+    normalized.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Generate the URL slug to use for an article with the provided title.
+///
+/// # Parameters
+/// - `title` - The title of the article
+///
+/// # Returns
+/// The slug, consisting only of lower case alphanumeric characters separated by single hyphens.
+pub fn slugify(title: &str) -> String {
+    let key = title_key(title);
+
+    key.split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("Gondor", "gondor" ; "Simple")]
+    #[test_case("  The   White  City ", "the white city" ; "Whitespace")]
+    #[test_case("MINAS TIRITH", "minas tirith" ; "Upper case")]
+    #[test_case("ｆｕｌｌ ｗｉｄｔｈ", "full width" ; "Normalized")]
+    fn test_title_key(input: &str, expected: &str) {
+        check!(title_key(input) == expected);
+    }
+
+    #[test_case("Gondor", "gondor" ; "Simple")]
+    #[test_case("The White City", "the-white-city" ; "Spaces")]
+    #[test_case("Minas Tirith: The Tower of Guard!", "minas-tirith-the-tower-of-guard" ; "Punctuation")]
+    #[test_case("Lothlórien", "lothlórien" ; "Unicode")]
+    #[test_case("--Edge--Case--", "edge-case" ; "Hyphens")]
+    fn test_slugify(input: &str, expected: &str) {
+        check!(slugify(input) == expected);
+    }
+}
@@ -0,0 +1,345 @@
+use std::ops::Range;
+
+use pulldown_cmark::{
+    escape::{escape_href, escape_html},
+    html, CowStr, Event, Options, Parser, Tag,
+};
+
+/// A wiki link to another article, written as `[[Title]]` or `[[Title|Label]]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WikiLink {
+    /// The title of the article that is linked to.
+    pub title: String,
+    /// The text to display for the link.
+    pub label: String,
+}
+
+/// Find all of the wiki links in some Markdown.
+/// Anything that appears inside code or inside another link is not treated as a wiki link.
+///
+/// # Parameters
+/// - `markdown` - The Markdown to search
+///
+/// # Returns
+/// The wiki links, in the order that they appear.
+pub fn wiki_links(markdown: &str) -> Vec<WikiLink> {
+    let mut result = vec![];
+
+    for_each_text(markdown, |text| {
+        result.extend(find_wiki_links(text).into_iter().map(|(_, link)| link));
+    });
+
+    result
+}
+
+/// Render some Markdown into sanitized HTML, converting wiki links into HTML links.
+///
+/// # Parameters
+/// - `markdown` - The Markdown to render
+/// - `resolve` - Function to resolve a wiki link into the URL of the article it refers to, or
+///   `None` if there is no such article
+///
+/// # Returns
+/// The HTML, which is safe to include directly in a page.
+pub fn render_html<F>(markdown: &str, resolve: F) -> String
+where
+    F: Fn(&WikiLink) -> Option<String>,
+{
+    let mut events = vec![];
+    let mut nesting = Nesting::default();
+
+    for event in merge_text(Parser::new_ext(markdown, options())) {
+        nesting.track(&event);
+
+        match event {
+            Event::Text(text) if nesting.allows_links() => {
+                let mut last = 0;
+                for (range, link) in find_wiki_links(&text) {
+                    if range.start > last {
+                        events.push(Event::Text(CowStr::from(text[last..range.start].to_owned())));
+                    }
+                    events.push(Event::Html(CowStr::from(link_html(&link, resolve(&link)))));
+                    last = range.end;
+                }
+                if last < text.len() {
+                    events.push(Event::Text(CowStr::from(text[last..].to_owned())));
+                }
+            },
+            event => events.push(event),
+        }
+    }
+
+    let mut unsafe_html = String::new();
+    html::push_html(&mut unsafe_html, events.into_iter());
+
+    ammonia::Builder::default()
+        .add_allowed_classes("a", &["wiki-link"])
+        .add_allowed_classes("span", &["wiki-link-missing"])
+        .clean(&unsafe_html)
+        .to_string()
+}
+
+/// Extract the text of some Markdown without any of its formatting, so that it can be searched.
+/// Wiki links are replaced by their labels, and each block of text is put on its own line.
+///
+/// # Parameters
+/// - `markdown` - The Markdown to extract the text from
+///
+/// # Returns
+/// The plain text.
+pub fn plain_text(markdown: &str) -> String {
+    let mut result = String::new();
+    let mut nesting = Nesting::default();
+
+    for event in merge_text(Parser::new_ext(markdown, options())) {
+        nesting.track(&event);
+
+        match event {
+            Event::Text(text) if nesting.allows_links() => {
+                let mut last = 0;
+                for (range, link) in find_wiki_links(&text) {
+                    result.push_str(&text[last..range.start]);
+                    result.push_str(&link.label);
+                    last = range.end;
+                }
+                result.push_str(&text[last..]);
+            },
+            Event::Text(text) | Event::Code(text) => result.push_str(&text),
+            Event::SoftBreak => result.push(' '),
+            Event::HardBreak
+            | Event::End(Tag::Paragraph | Tag::Heading(_) | Tag::Item | Tag::CodeBlock(_) | Tag::TableCell | Tag::BlockQuote)
+                if !result.is_empty() && !result.ends_with('\n') =>
+            {
+                result.push('\n');
+            },
+            _ => {},
+        }
+    }
+
+    result.trim_end().to_owned()
+}
+
+/// The Markdown extensions that are supported.
+fn options() -> Options {
+    Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS
+}
+
+/// Tracking of whether the parser is currently inside elements that wiki links can't appear in.
+#[derive(Default)]
+struct Nesting {
+    code:  usize,
+    links: usize,
+}
+
+impl Nesting {
+    fn track(&mut self, event: &Event) {
+        match event {
+            Event::Start(tag) => {
+                if let Some(count) = self.counter(tag) {
+                    *count += 1;
+                }
+            },
+            Event::End(tag) => {
+                if let Some(count) = self.counter(tag) {
+                    *count -= 1;
+                }
+            },
+            _ => {},
+        }
+    }
+
+    /// The counter that tracks how deeply nested the parser is in the given type of element, if
+    /// any.
+    fn counter(&mut self, tag: &Tag) -> Option<&mut usize> {
+        match tag {
+            Tag::CodeBlock(_) => Some(&mut self.code),
+            Tag::Link(..) | Tag::Image(..) => Some(&mut self.links),
+            _ => None,
+        }
+    }
+
+    fn allows_links(&self) -> bool {
+        self.code == 0 && self.links == 0
+    }
+}
+
+/// Call the provided function for every block of text in the Markdown that can contain wiki links.
+fn for_each_text<F>(markdown: &str, mut f: F)
+where
+    F: FnMut(&str),
+{
+    let mut nesting = Nesting::default();
+
+    for event in merge_text(Parser::new_ext(markdown, options())) {
+        nesting.track(&event);
+
+        if let Event::Text(text) = &event {
+            if nesting.allows_links() {
+                f(text);
+            }
+        }
+    }
+}
+
+/// Merge adjacent text events together.
+/// The parser splits text at any character that might be significant - including square brackets -
+/// so this is needed for the wiki link syntax to be seen as a whole.
+fn merge_text<'a, I>(events: I) -> Vec<Event<'a>>
+where
+    I: Iterator<Item = Event<'a>>,
+{
+    let mut result: Vec<Event<'a>> = vec![];
+
+    for event in events {
+        match (result.last_mut(), event) {
+            (Some(Event::Text(previous)), Event::Text(text)) => {
+                *previous = CowStr::from(format!("{}{}", previous, text));
+            },
+            (_, event) => result.push(event),
+        }
+    }
+
+    result
+}
+
+/// Find all of the wiki links in a single block of text.
+///
+/// # Parameters
+/// - `text` - The text to search
+///
+/// # Returns
+/// The wiki links, along with the range of the text that each one occupies.
+fn find_wiki_links(text: &str) -> Vec<(Range<usize>, WikiLink)> {
+    let mut result = vec![];
+    let mut offset = 0;
+
+    while let Some(start) = text[offset..].find("[[").map(|i| i + offset) {
+        let end = match text[start + 2..].find("]]") {
+            Some(end) => start + 2 + end,
+            None => break,
+        };
+        let inner = &text[start + 2..end];
+
+        if let Some(nested) = inner.rfind("[[") {
+            // Something like "[[a [[b]]", where only the innermost one can be a link.
+            offset = start + 2 + nested;
+            continue;
+        }
+
+        let mut parts = inner.splitn(2, '|');
+        let title = parts.next().unwrap_or_default().trim();
+        let label = parts.next().map_or(title, str::trim);
+
+        if !title.is_empty() {
+            result.push((
+                start..end + 2,
+                WikiLink {
+                    title: title.to_owned(),
+                    label: if label.is_empty() { title } else { label }.to_owned(),
+                },
+            ));
+        }
+
+        offset = end + 2;
+    }
+
+    result
+}
+
+/// Produce the HTML for a single wiki link.
+///
+/// # Parameters
+/// - `link` - The wiki link
+/// - `href` - The URL that the link resolved to, if any
+fn link_html(link: &WikiLink, href: Option<String>) -> String {
+    let mut label = String::new();
+    escape_html(&mut label, &link.label).unwrap();
+
+    match href {
+        Some(href) => {
+            let mut escaped = String::new();
+            escape_href(&mut escaped, &href).unwrap();
+            format!(r#"<a class="wiki-link" href="{}">{}</a>"#, escaped, label)
+        },
+        None => format!(r#"<span class="wiki-link-missing">{}</span>"#, label),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use test_case::test_case;
+
+    use super::*;
+
+    fn link(title: &str, label: &str) -> WikiLink {
+        WikiLink {
+            title: title.to_owned(),
+            label: label.to_owned(),
+        }
+    }
+
+    #[test_case("No links here", &[] ; "No links")]
+    #[test_case("See [[Gondor]].", &[link("Gondor", "Gondor")] ; "Simple")]
+    #[test_case("See [[ Gondor | the kingdom ]].", &[link("Gondor", "the kingdom")] ; "Labelled")]
+    #[test_case("[[Gondor]] and [[Rohan]]", &[link("Gondor", "Gondor"), link("Rohan", "Rohan")] ; "Multiple")]
+    #[test_case("[[a [[Gondor]]", &[link("Gondor", "Gondor")] ; "Nested")]
+    #[test_case("[[]] and [[Gondor", &[] ; "Incomplete")]
+    #[test_case("`[[Gondor]]`", &[] ; "Inline code")]
+    #[test_case("```\n[[Gondor]]\n```", &[] ; "Code block")]
+    #[test_case("*[[Gondor]]*", &[link("Gondor", "Gondor")] ; "Emphasis")]
+    fn test_wiki_links(input: &str, expected: &[WikiLink]) {
+        check!(wiki_links(input).as_slice() == expected);
+    }
+
+    #[test]
+    fn render_links() {
+        let html = render_html("See [[Gondor]] and [[Mordor|the dark land]].", |link| {
+            if link.title == "Gondor" {
+                Some("/articles/gondor".to_owned())
+            } else {
+                None
+            }
+        });
+
+        check!(
+            html == "<p>See <a class=\"wiki-link\" href=\"/articles/gondor\" rel=\"noopener noreferrer\">Gondor</a> and <span \
+                     class=\"wiki-link-missing\">the dark land</span>.</p>\n"
+        );
+    }
+
+    #[test]
+    fn render_markdown() {
+        let html = render_html("# Gondor\n\nA *great* kingdom.", |_| None);
+
+        check!(html == "<h1>Gondor</h1>\n<p>A <em>great</em> kingdom.</p>\n");
+    }
+
+    #[test]
+    fn render_escapes_labels() {
+        let html = render_html("[[Gondor|Fish & Chips < Mash]]", |_| None);
+
+        check!(html == "<p><span class=\"wiki-link-missing\">Fish &amp; Chips &lt; Mash</span></p>\n");
+    }
+
+    #[test]
+    fn render_sanitizes() {
+        let html = render_html(
+            "<script>alert('hi')</script>\n\n<a href=\"javascript:alert(1)\" onclick=\"evil()\">Click</a>",
+            |_| None,
+        );
+
+        check!(!html.contains("script"));
+        check!(!html.contains("javascript"));
+        check!(!html.contains("onclick"));
+    }
+
+    #[test_case("# Gondor\n\nA *great* kingdom.", "Gondor\nA great kingdom." ; "Formatting")]
+    #[test_case("Ruled from [[Minas Tirith|the White City]].", "Ruled from the White City." ; "Wiki links")]
+    #[test_case("Line one\nline two", "Line one line two" ; "Soft breaks")]
+    #[test_case("- Rohan\n- Gondor", "Rohan\nGondor" ; "Lists")]
+    #[test_case("Use `[[Gondor]]` to link", "Use [[Gondor]] to link" ; "Code is left alone")]
+    fn test_plain_text(markdown: &str, expected: &str) {
+        check!(plain_text(markdown) == expected);
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod memory;
+mod parse;
+mod postgres;
+mod save_article;
+
+#[cfg(test)]
+pub use memory::MemoryArticleRepository;
+pub use postgres::PostgresArticleRepository;
+pub use save_article::SaveArticleError;
+
+use crate::{
+    articles::{ArticleData, ArticleId, ArticleResource},
+    worlds::WorldId,
+};
+
+/// Repository of article records.
+/// Alongside each article is stored the title keys of every article that it links to, so that the
+/// links can be followed backwards.
+#[async_trait::async_trait]
+pub trait ArticleRepository: Send + Sync {
+    /// Create a new article record from the provided Article data.
+    ///
+    /// # Parameters
+    /// - `article` - The details of the article to create.
+    /// - `links` - The title keys of the articles that this article links to.
+    ///
+    /// # Returns
+    /// The created article resource.
+    async fn create_article(&self, article: &ArticleData, links: &[String]) -> Result<ArticleResource, SaveArticleError>;
+
+    /// Get the article that has the provided ID.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the article belongs to.
+    /// - `article_id` - The ID of the article.
+    ///
+    /// # Returns
+    /// The article resource, or `None` if it doesn't exist.
+    async fn get_article_by_id(&self, world_id: &WorldId, article_id: &ArticleId) -> Option<ArticleResource>;
+
+    /// Get the article that has the provided slug.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the article belongs to.
+    /// - `slug` - The slug of the article.
+    ///
+    /// # Returns
+    /// The article resource, or `None` if it doesn't exist.
+    async fn get_article_by_slug(&self, world_id: &WorldId, slug: &str) -> Option<ArticleResource>;
+
+    /// Get all of the articles in a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world.
+    ///
+    /// # Returns
+    /// The article resources, ordered by title.
+    async fn get_articles_for_world(&self, world_id: &WorldId) -> Vec<ArticleResource>;
+
+    /// Get all of the articles in a world that have any of the provided title keys.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world.
+    /// - `title_keys` - The title keys to look for.
+    ///
+    /// # Returns
+    /// The article resources that were found.
+    async fn get_articles_by_title_keys(&self, world_id: &WorldId, title_keys: &[String]) -> Vec<ArticleResource>;
+
+    /// Get all of the articles in a world that link to the provided title key.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world.
+    /// - `title_key` - The title key that is linked to.
+    ///
+    /// # Returns
+    /// The article resources, ordered by title.
+    async fn get_articles_linking_to(&self, world_id: &WorldId, title_key: &str) -> Vec<ArticleResource>;
+
+    /// Update an existing article record.
+    ///
+    /// # Parameters
+    /// - `article_id` - The ID of the article to update.
+    /// - `article` - The new details of the article.
+    /// - `links` - The title keys of the articles that this article now links to.
+    ///
+    /// # Returns
+    /// The updated article resource.
+    async fn update_article(
+        &self,
+        article_id: &ArticleId,
+        article: &ArticleData,
+        links: &[String],
+    ) -> Result<ArticleResource, SaveArticleError>;
+
+    /// Delete an article.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the article belongs to.
+    /// - `article_id` - The ID of the article.
+    ///
+    /// # Returns
+    /// True if the article existed and was deleted. False if not.
+    async fn delete_article(&self, world_id: &WorldId, article_id: &ArticleId) -> bool;
+}
@@ -0,0 +1,136 @@
+use std::sync::Mutex;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::{ArticleRepository, SaveArticleError};
+use crate::{
+    articles::{title_key, ArticleData, ArticleId, ArticleResource},
+    model::Identity,
+    worlds::WorldId,
+};
+
+/// An article that is stored in memory, along with the title keys that it links to.
+struct StoredArticle {
+    article: ArticleResource,
+    links:   Vec<String>,
+}
+
+/// Repository of article records that are stored in memory.
+#[derive(Default)]
+pub struct MemoryArticleRepository {
+    articles: Mutex<Vec<StoredArticle>>,
+}
+
+impl MemoryArticleRepository {
+    /// Create a new, empty article repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get all of the articles that match a predicate, ordered by title.
+    fn find<F>(&self, f: F) -> Vec<ArticleResource>
+    where
+        F: Fn(&StoredArticle) -> bool,
+    {
+        let articles = self.articles.lock().unwrap();
+
+        let mut result: Vec<ArticleResource> = articles.iter().filter(|a| f(a)).map(|a| a.article.clone()).collect();
+        result.sort_by_key(|a| title_key(&a.data.title));
+
+        result
+    }
+}
+
+/// Check that no other article in the same world has the same title or slug.
+fn check_unique(articles: &[StoredArticle], article_id: &ArticleId, article: &ArticleData) -> Result<(), SaveArticleError> {
+    let key = title_key(&article.title);
+
+    let duplicate = articles.iter().any(|a| {
+        &a.article.identity.id != article_id
+            && a.article.data.world_id == article.world_id
+            && (title_key(&a.article.data.title) == key || a.article.data.slug == article.slug)
+    });
+
+    if duplicate {
+        Err(SaveArticleError::DuplicateTitle)
+    } else {
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ArticleRepository for MemoryArticleRepository {
+    async fn create_article(&self, article: &ArticleData, links: &[String]) -> Result<ArticleResource, SaveArticleError> {
+        let mut articles = self.articles.lock().unwrap();
+
+        let created = ArticleResource {
+            identity: Identity::default(),
+            data:     article.clone(),
+        };
+        check_unique(&articles, &created.identity.id, article)?;
+
+        articles.push(StoredArticle {
+            article: created.clone(),
+            links:   links.to_vec(),
+        });
+
+        Ok(created)
+    }
+
+    async fn get_article_by_id(&self, world_id: &WorldId, article_id: &ArticleId) -> Option<ArticleResource> {
+        self.find(|a| &a.article.data.world_id == world_id && &a.article.identity.id == article_id)
+            .into_iter()
+            .next()
+    }
+
+    async fn get_article_by_slug(&self, world_id: &WorldId, slug: &str) -> Option<ArticleResource> {
+        self.find(|a| &a.article.data.world_id == world_id && a.article.data.slug == slug)
+            .into_iter()
+            .next()
+    }
+
+    async fn get_articles_for_world(&self, world_id: &WorldId) -> Vec<ArticleResource> {
+        self.find(|a| &a.article.data.world_id == world_id)
+    }
+
+    async fn get_articles_by_title_keys(&self, world_id: &WorldId, title_keys: &[String]) -> Vec<ArticleResource> {
+        self.find(|a| &a.article.data.world_id == world_id && title_keys.contains(&title_key(&a.article.data.title)))
+    }
+
+    async fn get_articles_linking_to(&self, world_id: &WorldId, title_key: &str) -> Vec<ArticleResource> {
+        self.find(|a| &a.article.data.world_id == world_id && a.links.iter().any(|l| l == title_key))
+    }
+
+    async fn update_article(
+        &self,
+        article_id: &ArticleId,
+        article: &ArticleData,
+        links: &[String],
+    ) -> Result<ArticleResource, SaveArticleError> {
+        let mut articles = self.articles.lock().unwrap();
+
+        check_unique(&articles, article_id, article)?;
+
+        let stored = articles
+            .iter_mut()
+            .find(|a| &a.article.identity.id == article_id && a.article.data.world_id == article.world_id)
+            .ok_or(SaveArticleError::UnknownArticle)?;
+
+        stored.article.identity.version = Uuid::new_v4();
+        stored.article.identity.updated = Utc::now();
+        stored.article.data = article.clone();
+        stored.links = links.to_vec();
+
+        Ok(stored.article.clone())
+    }
+
+    async fn delete_article(&self, world_id: &WorldId, article_id: &ArticleId) -> bool {
+        let mut articles = self.articles.lock().unwrap();
+
+        let before = articles.len();
+        articles.retain(|a| &a.article.identity.id != article_id || &a.article.data.world_id != world_id);
+
+        articles.len() != before
+    }
+}
@@ -0,0 +1,26 @@
+use tokio_postgres::Row;
+
+use crate::{
+    articles::{ArticleData, ArticleResource},
+    model::Identity,
+};
+
+impl From<Row> for ArticleResource {
+    fn from(row: Row) -> Self {
+        ArticleResource {
+            identity: Identity {
+                id:      row.get("article_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data:     ArticleData {
+                world_id: row.get("world_id"),
+                title:    row.get("title"),
+                slug:     row.get("slug"),
+                body:     row.get("body"),
+                category: row.get("category"),
+            },
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::{ArticleRepository, SaveArticleError};
+use crate::{
+    articles::{title_key, ArticleData, ArticleId, ArticleResource},
+    database::{Database, Transaction},
+    model::Identity,
+    worlds::WorldId,
+};
+
+/// Repository of article records that are stored in Postgres.
+pub struct PostgresArticleRepository {
+    database: Arc<Database>,
+}
+
+impl PostgresArticleRepository {
+    /// Create a new article repository.
+    pub fn new(database: Arc<Database>) -> Self {
+        Self { database }
+    }
+
+    /// Load many articles with the provided query.
+    async fn query(&self, sql: &str, params: &[&(dyn postgres_types::ToSql + Sync)]) -> Vec<ArticleResource> {
+        let conn = self.database.connect().await;
+        conn.query(sql, params).await.map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to load articles");
+                vec![]
+            },
+            |rows| rows.into_iter().map(ArticleResource::from).collect(),
+        )
+    }
+}
+
+/// Replace the links that are stored for an article.
+///
+/// # Parameters
+/// - `tx` - The transaction to work within
+/// - `article_id` - The ID of the article
+/// - `links` - The title keys of the articles that this article links to
+async fn save_links(tx: &Transaction<'_>, article_id: &ArticleId, links: &[String]) -> Result<(), SaveArticleError> {
+    tx.execute("DELETE FROM article_links WHERE article_id = $1", &[&article_id])
+        .await?;
+
+    for link in links {
+        tx.execute(
+            "INSERT INTO article_links(article_id, target_key) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+            &[&article_id, &link],
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+#[async_trait::async_trait]
+impl ArticleRepository for PostgresArticleRepository {
+    #[tracing::instrument(skip(self))]
+    async fn create_article(&self, article: &ArticleData, links: &[String]) -> Result<ArticleResource, SaveArticleError> {
+        let mut conn = self.database.connect().await;
+        let tx = conn.begin().await;
+
+        let identity = Identity::<ArticleId>::default();
+
+        let created: ArticleResource = tx.query("INSERT INTO articles(article_id, version, created, updated, world_id, title, title_key, slug, body, category) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) RETURNING *",
+        &[
+          &identity.id,
+          &identity.version,
+          &identity.created,
+          &identity.updated,
+          &article.world_id,
+          &article.title,
+          &title_key(&article.title),
+          &article.slug,
+          &article.body,
+          &article.category,
+          ])
+            .await?
+            .into_iter()
+            .map(ArticleResource::from)
+            .next()
+            .ok_or(SaveArticleError::UnknownError)?;
+
+        save_links(&tx, &identity.id, links).await?;
+
+        tx.commit().await?;
+
+        Ok(created)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_article_by_id(&self, world_id: &WorldId, article_id: &ArticleId) -> Option<ArticleResource> {
+        self.query(
+            "SELECT * FROM articles WHERE world_id = $1 AND article_id = $2",
+            &[&world_id, &article_id],
+        )
+        .await
+        .into_iter()
+        .next()
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_article_by_slug(&self, world_id: &WorldId, slug: &str) -> Option<ArticleResource> {
+        self.query("SELECT * FROM articles WHERE world_id = $1 AND slug = $2", &[&world_id, &slug])
+            .await
+            .into_iter()
+            .next()
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_articles_for_world(&self, world_id: &WorldId) -> Vec<ArticleResource> {
+        self.query("SELECT * FROM articles WHERE world_id = $1 ORDER BY title_key", &[&world_id])
+            .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_articles_by_title_keys(&self, world_id: &WorldId, title_keys: &[String]) -> Vec<ArticleResource> {
+        self.query(
+            "SELECT * FROM articles WHERE world_id = $1 AND title_key = ANY($2)",
+            &[&world_id, &title_keys],
+        )
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_articles_linking_to(&self, world_id: &WorldId, title_key: &str) -> Vec<ArticleResource> {
+        self.query(
+            "SELECT a.* FROM articles a JOIN article_links l ON l.article_id = a.article_id WHERE a.world_id = $1 AND \
+             l.target_key = $2 ORDER BY a.title_key",
+            &[&world_id, &title_key],
+        )
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn update_article(
+        &self,
+        article_id: &ArticleId,
+        article: &ArticleData,
+        links: &[String],
+    ) -> Result<ArticleResource, SaveArticleError> {
+        let mut conn = self.database.connect().await;
+        let tx = conn.begin().await;
+
+        let version = Uuid::new_v4();
+        let updated = Utc::now();
+
+        let result: ArticleResource = tx.query("UPDATE articles SET version = $3, updated = $4, title = $5, title_key = $6, slug = $7, body = $8, category = $9 WHERE article_id = $1 AND world_id = $2 RETURNING *",
+        &[
+          &article_id,
+          &article.world_id,
+          &version,
+          &updated,
+          &article.title,
+          &title_key(&article.title),
+          &article.slug,
+          &article.body,
+          &article.category,
+          ])
+            .await?
+            .into_iter()
+            .map(ArticleResource::from)
+            .next()
+            .ok_or(SaveArticleError::UnknownArticle)?;
+
+        save_links(&tx, article_id, links).await?;
+
+        tx.commit().await?;
+
+        Ok(result)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_article(&self, world_id: &WorldId, article_id: &ArticleId) -> bool {
+        let conn = self.database.connect().await;
+        conn.execute(
+            "DELETE FROM articles WHERE world_id = $1 AND article_id = $2",
+            &[&world_id, &article_id],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to delete article");
+                false
+            },
+            |count| count == 1,
+        )
+    }
+}
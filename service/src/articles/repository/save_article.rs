@@ -0,0 +1,40 @@
+use tokio_postgres::error::{DbError, SqlState};
+
+/// Errors that can occur when saving an article record.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveArticleError {
+    #[error("Duplicate title")]
+    DuplicateTitle,
+
+    #[error("The article or world was not found")]
+    UnknownArticle,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl From<tokio_postgres::Error> for SaveArticleError {
+    fn from(e: tokio_postgres::Error) -> Self {
+        let mut result = None;
+
+        if e.code() == Some(&SqlState::UNIQUE_VIOLATION) {
+            let db_error: Option<DbError> = e.into_source().and_then(|e| e.downcast_ref::<DbError>().cloned());
+
+            result = db_error
+                .and_then(|e| e.constraint().map(std::borrow::ToOwned::to_owned))
+                .map(|constraint| match constraint.as_str() {
+                    "articles_title_key" | "articles_slug_key" => SaveArticleError::DuplicateTitle,
+                    _ => {
+                        tracing::warn!("Unexpected constraint violation error: {:?}", constraint);
+                        SaveArticleError::UnknownError
+                    },
+                });
+        } else if e.code() == Some(&SqlState::FOREIGN_KEY_VIOLATION) {
+            result = Some(SaveArticleError::UnknownArticle);
+        } else {
+            tracing::warn!("Unexpected database error: {:?}", e);
+        }
+
+        result.unwrap_or(SaveArticleError::UnknownError)
+    }
+}
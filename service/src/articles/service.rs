@@ -0,0 +1,56 @@
+mod create_article;
+mod delete_article;
+mod get_article;
+mod render_article;
+mod update_article;
+
+use std::sync::Arc;
+
+pub use create_article::NewArticle;
+pub use update_article::UpdateArticleError;
+
+use super::{repository::ArticleRepository, title_key, wiki_links};
+use crate::{revisions::RevisionService, search::SearchService};
+
+/// Service layer for working with articles.
+pub struct ArticleService {
+    repository: Arc<dyn ArticleRepository>,
+    revisions:  Arc<RevisionService>,
+    search:     Arc<SearchService>,
+}
+
+impl ArticleService {
+    /// Create a new article service.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store articles in
+    /// - `revisions` - The service to record the revision history of articles with
+    /// - `search` - The service to keep the search index of articles up to date with
+    pub fn new(repository: Arc<dyn ArticleRepository>, revisions: Arc<RevisionService>, search: Arc<SearchService>) -> Self {
+        Self {
+            repository,
+            revisions,
+            search,
+        }
+    }
+}
+
+/// Determine the title keys of all the articles that some Markdown links to.
+///
+/// # Parameters
+/// - `body` - The Markdown to find the links in
+///
+/// # Returns
+/// The distinct title keys, in the order that they are first linked to.
+fn link_keys(body: &str) -> Vec<String> {
+    let mut result: Vec<String> = vec![];
+
+    for link in wiki_links(body) {
+        let key = title_key(&link.title);
+        if !result.contains(&key) {
+            result.push(key);
+        }
+    }
+
+    result
+}
@@ -0,0 +1,113 @@
+use super::{link_keys, ArticleService};
+use crate::{
+    articles::{slugify, ArticleData, ArticleResource, SaveArticleError},
+    users::UserId,
+    worlds::WorldId,
+};
+
+/// Details needed to create a new article.
+#[derive(Debug)]
+pub struct NewArticle {
+    pub title:    String,
+    pub body:     String,
+    pub category: Option<String>,
+}
+
+impl ArticleService {
+    /// Create a new article within a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world to create the article in
+    /// - `changed_by` - The ID of the user that is creating the article
+    /// - `article` - The details of the article to create
+    ///
+    /// # Returns
+    /// The newly created article.
+    pub async fn create_article(
+        &self,
+        world_id: &WorldId,
+        changed_by: &UserId,
+        article: NewArticle,
+    ) -> Result<ArticleResource, SaveArticleError> {
+        let links = link_keys(&article.body);
+
+        let created = self
+            .repository
+            .create_article(
+                &ArticleData {
+                    world_id: world_id.clone(),
+                    slug:     slugify(&article.title),
+                    title:    article.title,
+                    body:     article.body,
+                    category: article.category,
+                },
+                &links,
+            )
+            .await?;
+        self.revisions.record(&created, changed_by).await;
+        self.search.index(&created).await;
+
+        Ok(created)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::{
+        articles::MemoryArticleRepository,
+        revisions::{MemoryRevisionRepository, RevisionService},
+        search::{MemorySearchRepository, SearchService},
+    };
+
+    fn new_article(title: &str) -> NewArticle {
+        NewArticle {
+            title:    title.to_owned(),
+            body:     "Some text".to_owned(),
+            category: None,
+        }
+    }
+
+    #[actix_rt::test]
+    async fn create_article() {
+        let sut = ArticleService::new(
+            Arc::new(MemoryArticleRepository::new()),
+            Arc::new(RevisionService::new(Arc::new(MemoryRevisionRepository::new()))),
+            Arc::new(SearchService::new(Arc::new(MemorySearchRepository::new()))),
+        );
+        let world_id = WorldId::default();
+
+        let_assert!(
+            Ok(created) = sut
+                .create_article(&world_id, &UserId::default(), new_article("The White City"))
+                .await
+        );
+        check!(created.data.title == "The White City");
+        check!(created.data.slug == "the-white-city");
+        check!(created.data.world_id == world_id);
+    }
+
+    #[actix_rt::test]
+    async fn create_duplicate_title() {
+        let sut = ArticleService::new(
+            Arc::new(MemoryArticleRepository::new()),
+            Arc::new(RevisionService::new(Arc::new(MemoryRevisionRepository::new()))),
+            Arc::new(SearchService::new(Arc::new(MemorySearchRepository::new()))),
+        );
+        let world_id = WorldId::default();
+
+        let_assert!(Ok(_) = sut.create_article(&world_id, &UserId::default(), new_article("Gondor")).await);
+        let_assert!(Err(e) = sut.create_article(&world_id, &UserId::default(), new_article("GONDOR")).await);
+        check!(e == SaveArticleError::DuplicateTitle);
+
+        let_assert!(
+            Ok(_) = sut
+                .create_article(&WorldId::default(), &UserId::default(), new_article("Gondor"))
+                .await
+        );
+    }
+}
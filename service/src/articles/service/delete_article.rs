@@ -0,0 +1,21 @@
+use super::ArticleService;
+use crate::{articles::ArticleId, worlds::WorldId};
+
+impl ArticleService {
+    /// Delete an article.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the article belongs to
+    /// - `article_id` - The ID of the article
+    ///
+    /// # Returns
+    /// True if the article was deleted. False if it didn't exist.
+    pub async fn delete_article(&self, world_id: &WorldId, article_id: &ArticleId) -> bool {
+        let deleted = self.repository.delete_article(world_id, article_id).await;
+        if deleted {
+            self.search.remove(article_id).await;
+        }
+
+        deleted
+    }
+}
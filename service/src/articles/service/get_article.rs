@@ -0,0 +1,138 @@
+use super::ArticleService;
+use crate::{
+    articles::{title_key, ArticleId, ArticleResource},
+    worlds::WorldId,
+};
+
+impl ArticleService {
+    /// Get the article that has the provided ID.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the article belongs to
+    /// - `article_id` - The ID of the article
+    ///
+    /// # Returns
+    /// The article, or `None` if it doesn't exist.
+    pub async fn get_article_by_id(&self, world_id: &WorldId, article_id: &ArticleId) -> Option<ArticleResource> {
+        self.repository.get_article_by_id(world_id, article_id).await
+    }
+
+    /// Get the article that has the provided slug.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the article belongs to
+    /// - `slug` - The slug of the article
+    ///
+    /// # Returns
+    /// The article, or `None` if it doesn't exist.
+    pub async fn get_article_by_slug(&self, world_id: &WorldId, slug: &str) -> Option<ArticleResource> {
+        self.repository.get_article_by_slug(world_id, slug).await
+    }
+
+    /// Get all of the articles in a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    ///
+    /// # Returns
+    /// The articles, ordered by title.
+    pub async fn get_articles(&self, world_id: &WorldId) -> Vec<ArticleResource> {
+        self.repository.get_articles_for_world(world_id).await
+    }
+
+    /// Get all of the articles that link to the provided article - i.e. "What links here".
+    ///
+    /// # Parameters
+    /// - `article` - The article to get the backlinks for
+    ///
+    /// # Returns
+    /// The articles that link to this one, ordered by title.
+    pub async fn get_backlinks(&self, article: &ArticleResource) -> Vec<ArticleResource> {
+        self.repository
+            .get_articles_linking_to(&article.data.world_id, &title_key(&article.data.title))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::{
+        articles::{MemoryArticleRepository, NewArticle},
+        revisions::{MemoryRevisionRepository, RevisionService},
+        search::{MemorySearchRepository, SearchService},
+        users::UserId,
+    };
+
+    fn new_article(title: &str, body: &str) -> NewArticle {
+        NewArticle {
+            title:    title.to_owned(),
+            body:     body.to_owned(),
+            category: None,
+        }
+    }
+
+    #[actix_rt::test]
+    async fn get_article() {
+        let sut = ArticleService::new(
+            Arc::new(MemoryArticleRepository::new()),
+            Arc::new(RevisionService::new(Arc::new(MemoryRevisionRepository::new()))),
+            Arc::new(SearchService::new(Arc::new(MemorySearchRepository::new()))),
+        );
+        let world_id = WorldId::default();
+        let_assert!(Ok(created) = sut.create_article(&world_id, &UserId::default(), new_article("Gondor", "")).await);
+
+        let_assert!(Some(by_id) = sut.get_article_by_id(&world_id, &created.identity.id).await);
+        check!(by_id.identity.id == created.identity.id);
+
+        let_assert!(Some(by_slug) = sut.get_article_by_slug(&world_id, "gondor").await);
+        check!(by_slug.identity.id == created.identity.id);
+
+        check!(sut.get_article_by_id(&WorldId::default(), &created.identity.id).await.is_none());
+        check!(sut.get_article_by_slug(&world_id, "rohan").await.is_none());
+    }
+
+    #[actix_rt::test]
+    async fn get_backlinks() {
+        let sut = ArticleService::new(
+            Arc::new(MemoryArticleRepository::new()),
+            Arc::new(RevisionService::new(Arc::new(MemoryRevisionRepository::new()))),
+            Arc::new(SearchService::new(Arc::new(MemorySearchRepository::new()))),
+        );
+        let world_id = WorldId::default();
+        let_assert!(
+            Ok(gondor) = sut
+                .create_article(&world_id, &UserId::default(), new_article("Gondor", "Allied with [[Rohan]]."))
+                .await
+        );
+        let_assert!(
+            Ok(rohan) = sut
+                .create_article(&world_id, &UserId::default(), new_article("Rohan", "Allied with [[gondor]]."))
+                .await
+        );
+        let_assert!(
+            Ok(_) = sut
+                .create_article(
+                    &world_id,
+                    &UserId::default(),
+                    new_article("Mordor", "Enemy of [[Gondor|the west]].")
+                )
+                .await
+        );
+        let_assert!(
+            Ok(_) = sut
+                .create_article(&world_id, &UserId::default(), new_article("Shire", "Far away."))
+                .await
+        );
+
+        let backlinks: Vec<String> = sut.get_backlinks(&gondor).await.into_iter().map(|a| a.data.title).collect();
+        check!(backlinks == vec!["Mordor".to_owned(), "Rohan".to_owned()]);
+
+        let backlinks: Vec<String> = sut.get_backlinks(&rohan).await.into_iter().map(|a| a.data.title).collect();
+        check!(backlinks == vec!["Gondor".to_owned()]);
+    }
+}
@@ -0,0 +1,121 @@
+use super::{link_keys, ArticleService};
+use crate::articles::{render_html, title_key, wiki_links, ArticleResource, WikiLink};
+
+/// An article that has been rendered to HTML.
+#[derive(Debug)]
+pub struct RenderedArticle {
+    /// The sanitized HTML of the article body.
+    pub html:  String,
+    /// Every wiki link in the article, along with the article that it resolves to, if any.
+    pub links: Vec<RenderedLink>,
+}
+
+/// A wiki link from a rendered article.
+#[derive(Debug)]
+pub struct RenderedLink {
+    pub link:   WikiLink,
+    pub target: Option<ArticleResource>,
+}
+
+impl ArticleService {
+    /// Render the body of an article to HTML, resolving all of its wiki links to the articles that
+    /// they refer to.
+    ///
+    /// # Parameters
+    /// - `article` - The article to render
+    /// - `href` - Function to produce the URL to link to for an article
+    ///
+    /// # Returns
+    /// The rendered article.
+    pub async fn render_article<F>(&self, article: &ArticleResource, href: F) -> RenderedArticle
+    where
+        F: Fn(&ArticleResource) -> String,
+    {
+        let targets = self
+            .repository
+            .get_articles_by_title_keys(&article.data.world_id, &link_keys(&article.data.body))
+            .await;
+        let resolve = |link: &WikiLink| {
+            let key = title_key(&link.title);
+            targets.iter().find(|t| title_key(&t.data.title) == key)
+        };
+
+        let html = render_html(&article.data.body, |link| resolve(link).map(&href));
+
+        let links = wiki_links(&article.data.body)
+            .into_iter()
+            .map(|link| RenderedLink {
+                target: resolve(&link).cloned(),
+                link,
+            })
+            .collect();
+
+        RenderedArticle { html, links }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::{
+        articles::{MemoryArticleRepository, NewArticle},
+        revisions::{MemoryRevisionRepository, RevisionService},
+        search::{MemorySearchRepository, SearchService},
+        users::UserId,
+        worlds::WorldId,
+    };
+
+    #[actix_rt::test]
+    async fn render_article() {
+        let sut = ArticleService::new(
+            Arc::new(MemoryArticleRepository::new()),
+            Arc::new(RevisionService::new(Arc::new(MemoryRevisionRepository::new()))),
+            Arc::new(SearchService::new(Arc::new(MemorySearchRepository::new()))),
+        );
+        let world_id = WorldId::default();
+        let_assert!(
+            Ok(gondor) = sut
+                .create_article(
+                    &world_id,
+                    &UserId::default(),
+                    NewArticle {
+                        title:    "Gondor".to_owned(),
+                        body:     "Allied with [[rohan|the horse lords]], enemy of [[Mordor]].".to_owned(),
+                        category: None,
+                    },
+                )
+                .await
+        );
+        let_assert!(
+            Ok(rohan) = sut
+                .create_article(
+                    &world_id,
+                    &UserId::default(),
+                    NewArticle {
+                        title:    "Rohan".to_owned(),
+                        body:     String::new(),
+                        category: None,
+                    },
+                )
+                .await
+        );
+
+        let rendered = sut.render_article(&gondor, |a| format!("/articles/{}", a.data.slug)).await;
+
+        check!(
+            rendered.html
+                == "<p>Allied with <a class=\"wiki-link\" href=\"/articles/rohan\" rel=\"noopener noreferrer\">the horse lords</a>, \
+                    enemy of <span class=\"wiki-link-missing\">Mordor</span>.</p>\n"
+        );
+
+        check!(rendered.links.len() == 2);
+        check!(rendered.links[0].link.title == "rohan");
+        check!(rendered.links[0].target.as_ref().map(|t| &t.identity.id) == Some(&rohan.identity.id));
+        check!(rendered.links[1].link.title == "Mordor");
+        check!(rendered.links[1].target.is_none());
+    }
+}
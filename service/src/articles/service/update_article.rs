@@ -0,0 +1,196 @@
+use super::{link_keys, ArticleService};
+use crate::{
+    articles::{slugify, ArticleData, ArticleId, ArticleResource, SaveArticleError},
+    users::UserId,
+    worlds::WorldId,
+};
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum UpdateArticleError<E>
+where
+    E: std::fmt::Debug,
+{
+    #[error("Unknown article")]
+    UnknownArticle,
+
+    #[error("Duplicate title")]
+    DuplicateTitle,
+
+    #[error("An error occurred updating the article data")]
+    UpdateError(E),
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl ArticleService {
+    /// Update the article that has the provided ID, using the provided lambda to perform the
+    /// updates. The slug and the links of the article are kept in step with any changes to the
+    /// title and body.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the article belongs to
+    /// - `article_id` - The ID of the article to update
+    /// - `changed_by` - The ID of the user that is making the change
+    /// - `f` - The function to update the article details
+    ///
+    /// # Returns
+    /// The newly updated article.
+    pub async fn update_article_by_id<F, E>(
+        &self,
+        world_id: &WorldId,
+        article_id: &ArticleId,
+        changed_by: &UserId,
+        f: F,
+    ) -> Result<ArticleResource, UpdateArticleError<E>>
+    where
+        F: FnOnce(ArticleData) -> Result<ArticleData, E>,
+        E: std::fmt::Debug,
+    {
+        let article = self
+            .repository
+            .get_article_by_id(world_id, article_id)
+            .await
+            .ok_or(UpdateArticleError::UnknownArticle)?;
+
+        let data = f(article.data).map_err(UpdateArticleError::UpdateError)?;
+        let data = ArticleData {
+            world_id: world_id.clone(),
+            slug: slugify(&data.title),
+            ..data
+        };
+        let links = link_keys(&data.body);
+
+        let result = self.repository.update_article(article_id, &data, &links).await?;
+        self.revisions.record(&result, changed_by).await;
+        self.search.index(&result).await;
+
+        Ok(result)
+    }
+}
+
+impl<E> From<SaveArticleError> for UpdateArticleError<E>
+where
+    E: std::fmt::Debug,
+{
+    fn from(e: SaveArticleError) -> Self {
+        match e {
+            SaveArticleError::DuplicateTitle => Self::DuplicateTitle,
+            SaveArticleError::UnknownArticle => Self::UnknownArticle,
+            SaveArticleError::UnknownError => Self::UnknownError,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::{
+        articles::{MemoryArticleRepository, NewArticle},
+        revisions::{MemoryRevisionRepository, RevisionService},
+        search::{MemorySearchRepository, SearchService},
+    };
+
+    async fn build_service() -> (ArticleService, WorldId, ArticleResource) {
+        let sut = ArticleService::new(
+            Arc::new(MemoryArticleRepository::new()),
+            Arc::new(RevisionService::new(Arc::new(MemoryRevisionRepository::new()))),
+            Arc::new(SearchService::new(Arc::new(MemorySearchRepository::new()))),
+        );
+        let world_id = WorldId::default();
+
+        let article = sut
+            .create_article(
+                &world_id,
+                &UserId::default(),
+                NewArticle {
+                    title:    "Gondor".to_owned(),
+                    body:     "See [[Rohan]].".to_owned(),
+                    category: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        (sut, world_id, article)
+    }
+
+    #[actix_rt::test]
+    async fn update_article() {
+        let (sut, world_id, article) = build_service().await;
+        let rohan = sut
+            .create_article(
+                &world_id,
+                &UserId::default(),
+                NewArticle {
+                    title:    "Rohan".to_owned(),
+                    body:     String::new(),
+                    category: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        let result = sut
+            .update_article_by_id(
+                &world_id,
+                &article.identity.id,
+                &UserId::default(),
+                |data| -> Result<ArticleData, ()> {
+                    Ok(ArticleData {
+                        title: "The South Kingdom".to_owned(),
+                        body: "No links".to_owned(),
+                        category: Some("Places".to_owned()),
+                        ..data
+                    })
+                },
+            )
+            .await;
+
+        let_assert!(Ok(updated) = result);
+        check!(updated.identity.version != article.identity.version);
+        check!(updated.data.title == "The South Kingdom");
+        check!(updated.data.slug == "the-south-kingdom");
+        check!(updated.data.category == Some("Places".to_owned()));
+
+        check!(sut.get_backlinks(&rohan).await.is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn update_unknown_article() {
+        let (sut, world_id, _) = build_service().await;
+
+        let result = sut
+            .update_article_by_id(
+                &world_id,
+                &ArticleId::default(),
+                &UserId::default(),
+                |data| -> Result<ArticleData, ()> { Ok(data) },
+            )
+            .await;
+
+        let_assert!(Err(e) = result);
+        check!(e == UpdateArticleError::UnknownArticle);
+    }
+
+    #[actix_rt::test]
+    async fn update_fails() {
+        let (sut, world_id, article) = build_service().await;
+
+        let result = sut
+            .update_article_by_id(
+                &world_id,
+                &article.identity.id,
+                &UserId::default(),
+                |_| -> Result<ArticleData, &str> { Err("Oops") },
+            )
+            .await;
+
+        let_assert!(Err(e) = result);
+        check!(e == UpdateArticleError::UpdateError("Oops"));
+    }
+}
@@ -3,7 +3,12 @@ use std::sync::Arc;
 use actix_web::web::{post, resource, ServiceConfig};
 
 use super::AuthenticationService;
-use crate::{authorization::AuthorizationService, server::RouteConfigurer, users::UserService};
+use crate::{
+    authorization::AuthorizationService,
+    server::RouteConfigurer,
+    sessions::SessionService,
+    users::{PasswordHasher, UserService},
+};
 
 /// Component for authentication.
 pub struct Component {
@@ -12,8 +17,18 @@ pub struct Component {
 
 impl Component {
     /// Create a new authentication component.
-    pub fn new(users_service: Arc<UserService>, authorization_service: Arc<AuthorizationService>) -> Arc<Self> {
-        let service = Arc::new(AuthenticationService::new(users_service, authorization_service));
+    pub fn new(
+        users_service: Arc<UserService>,
+        password_hasher: Arc<PasswordHasher>,
+        sessions_service: Arc<SessionService>,
+        authorization_service: Arc<AuthorizationService>,
+    ) -> Arc<Self> {
+        let service = Arc::new(AuthenticationService::new(
+            users_service,
+            password_hasher,
+            sessions_service,
+            authorization_service,
+        ));
 
         Arc::new(Self { service })
     }
@@ -1,17 +1,18 @@
 use std::sync::Arc;
 
-use actix_web::web::{Data, Json};
+use actix_web::{web::Data, HttpRequest};
 use serde::Deserialize;
 use serde_json::{json, Value};
 
-use super::model::AuthenticatedModel;
+use super::model::{AuthenticatedModel, AuthenticatedResponse};
 use crate::{
-    authentication::AuthenticationService,
+    authentication::{AuthenticateError, AuthenticationService},
     authorization::Principal,
     http::{
-        problem::{Problem, UNAUTHORIZED},
+        problem::{Problem, INTERNAL_SERVER_ERROR, UNAUTHORIZED},
         valid::{Valid, Validatable},
     },
+    sessions::{DeviceDetails, TrustedProxies},
     users::Username,
 };
 
@@ -19,27 +20,38 @@ use crate::{
 pub async fn handle(
     req: Valid<AuthenticateRequest>,
     service: Data<Arc<AuthenticationService>>,
-) -> Result<Json<AuthenticatedModel>, Problem> {
-    let (security_context, token) = service.authenticate(&req.username, &req.password).await.map_err(|e| {
+    http_request: HttpRequest,
+    trusted_proxies: Data<TrustedProxies>,
+) -> Result<AuthenticatedResponse, Problem> {
+    let req = req.unwrap();
+    let device = DeviceDetails::from_request(&http_request, req.device_name.clone(), &trusted_proxies);
+
+    let (security_context, token) = service.authenticate(&req.username, &req.password, device).await.map_err(|e| {
         tracing::warn!(username = ?req.username, e = ?e, "Authentication failed");
 
-        UNAUTHORIZED
+        match e {
+            AuthenticateError::UnknownUser | AuthenticateError::InvalidPassword => UNAUTHORIZED,
+            AuthenticateError::UnknownError => INTERNAL_SERVER_ERROR,
+        }
     })?;
 
-    Ok(Json(AuthenticatedModel {
+    Ok(AuthenticatedModel {
         token,
         user_id: match security_context.principal {
             Principal::User(user_id) => Some(user_id),
         },
         expires_at: security_context.expires,
-    }))
+    }
+    .into())
 }
 
 /// The incoming request to authenticate.
 #[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct AuthenticateRequest {
-    pub username: Username,
-    pub password: String,
+    pub username:    Username,
+    pub password:    String,
+    pub device_name: Option<String>,
 }
 
 impl Validatable for AuthenticateRequest {
@@ -51,6 +63,11 @@ impl Validatable for AuthenticateRequest {
                 "password": {
                     "type": "string",
                     "minLength": 1
+                },
+                "deviceName": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 100
                 }
             },
             "required": [
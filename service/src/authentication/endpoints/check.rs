@@ -1,22 +1,23 @@
 use std::sync::Arc;
 
-use actix_web::web::{Data, Json};
+use actix_web::web::Data;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 
 use crate::{
     http::{
         problem::Problem,
+        response::Response,
         valid::{Valid, Validatable},
     },
     users::{UserService, Username},
 };
 
 /// Handle the authentication request.
-pub async fn handle(service: Data<Arc<UserService>>, req: Valid<CheckRequest>) -> Result<Json<CheckModel>, Problem> {
+pub async fn handle(service: Data<Arc<UserService>>, req: Valid<CheckRequest>) -> Result<Response<CheckModel>, Problem> {
     let user = service.get_user_by_username(&req.username).await;
 
-    Ok(Json(CheckModel { known: user.is_some() }))
+    Ok(Response(CheckModel { known: user.is_some() }))
 }
 
 /// The incoming request to check if a username is know or not.
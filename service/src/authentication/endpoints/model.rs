@@ -1,7 +1,11 @@
+use actix_web::http::header::{CacheControl, CacheDirective};
 use chrono::{DateTime, Utc};
 use serde::Serialize;
 
-use crate::authorization::AccessToken;
+use crate::{
+    authorization::AccessToken,
+    http::response::{Response, SimpleRespondable},
+};
 
 /// Model to return if authentication was a success
 #[derive(Debug, Serialize)]
@@ -11,3 +15,14 @@ pub struct AuthenticatedModel {
     pub user_id:    Option<String>,
     pub expires_at: DateTime<Utc>,
 }
+
+pub type AuthenticatedResponse = Response<SimpleRespondable<AuthenticatedModel>>;
+
+impl From<AuthenticatedModel> for AuthenticatedResponse {
+    /// The access token mustn't be kept anywhere, including for replaying to retries.
+    fn from(model: AuthenticatedModel) -> Self {
+        SimpleRespondable::new(model)
+            .with_header(CacheControl(vec![CacheDirective::NoStore]))
+            .into()
+    }
+}
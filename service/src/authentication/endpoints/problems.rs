@@ -8,3 +8,10 @@ pub const DUPLICATE_USERNAME: SimpleProblemType = SimpleProblemType {
     problem_title: "Duplicate Username",
     status_code:   StatusCode::UNPROCESSABLE_ENTITY,
 };
+
+/// Problem to indicate registering a new user with a duplicate email address.
+pub const DUPLICATE_EMAIL: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/authentication/register/duplicate_email",
+    problem_title: "Duplicate Email Address",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
@@ -1,10 +1,13 @@
 use std::sync::Arc;
 
-use actix_web::web::{Data, Json};
+use actix_web::{web::Data, HttpRequest};
 use serde::Deserialize;
 use serde_json::{json, Value};
 
-use super::{model::AuthenticatedModel, problems::DUPLICATE_USERNAME};
+use super::{
+    model::{AuthenticatedModel, AuthenticatedResponse},
+    problems::{DUPLICATE_EMAIL, DUPLICATE_USERNAME},
+};
 use crate::{
     authentication::{AuthenticationService, Registration, RegistrationError},
     authorization::Principal,
@@ -12,33 +15,47 @@ use crate::{
         problem::{Problem, INTERNAL_SERVER_ERROR},
         valid::{Valid, Validatable},
     },
-    users::{Email, Password, Username},
+    sessions::{DeviceDetails, TrustedProxies},
+    users::{Email, Username},
 };
 
 /// Handle the authentication request.
-pub async fn handle(req: Valid<RegisterRequest>, service: Data<Arc<AuthenticationService>>) -> Result<Json<AuthenticatedModel>, Problem> {
+pub async fn handle(
+    req: Valid<RegisterRequest>,
+    service: Data<Arc<AuthenticationService>>,
+    http_request: HttpRequest,
+    trusted_proxies: Data<TrustedProxies>,
+) -> Result<AuthenticatedResponse, Problem> {
     let req = req.unwrap();
 
+    let device = DeviceDetails::from_request(&http_request, req.device_name, &trusted_proxies);
+
     let (security_context, token) = service
-        .register(Registration {
-            username:     req.username,
-            email:        req.email,
-            display_name: req.display_name,
-            password:     Password::from_plaintext(&req.password),
-        })
+        .register(
+            Registration {
+                username:     req.username,
+                email:        req.email,
+                display_name: req.display_name,
+                password:     req.password,
+            },
+            device,
+        )
         .await
         .map_err(|e| match e {
-            RegistrationError::DuplicateUsername => DUPLICATE_USERNAME,
-            RegistrationError::UnknownError => INTERNAL_SERVER_ERROR,
+            RegistrationError::InvalidPassword(e) => Problem::from(e),
+            RegistrationError::DuplicateUsername => DUPLICATE_USERNAME.into(),
+            RegistrationError::DuplicateEmail => DUPLICATE_EMAIL.into(),
+            RegistrationError::UnknownError => INTERNAL_SERVER_ERROR.into(),
         })?;
 
-    Ok(Json(AuthenticatedModel {
+    Ok(AuthenticatedModel {
         token,
         user_id: match security_context.principal {
             Principal::User(user_id) => Some(user_id),
         },
         expires_at: security_context.expires,
-    }))
+    }
+    .into())
 }
 
 /// The incoming request to authenticate.
@@ -49,6 +66,7 @@ pub struct RegisterRequest {
     pub email:        Email,
     pub display_name: String,
     pub password:     String,
+    pub device_name:  Option<String>,
 }
 
 impl Validatable for RegisterRequest {
@@ -65,6 +83,11 @@ impl Validatable for RegisterRequest {
                 "password": {
                     "type": "string",
                     "minLength": 1
+                },
+                "deviceName": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 100
                 }
             },
             "required": [
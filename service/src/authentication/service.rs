@@ -6,20 +6,51 @@ use std::sync::Arc;
 pub use authenticate::*;
 pub use register::*;
 
-use crate::{authorization::AuthorizationService, users::UserService};
+use crate::{
+    authorization::{AccessToken, AuthorizationService, SecurityContext},
+    sessions::{DeviceDetails, SaveSessionError, SessionService},
+    users::{PasswordHasher, UserId, UserService},
+};
 
 /// Service layer for authenticating users.
 pub struct AuthenticationService {
     users_service:         Arc<UserService>,
+    password_hasher:       Arc<PasswordHasher>,
+    sessions_service:      Arc<SessionService>,
     authorization_service: Arc<AuthorizationService>,
 }
 
 impl AuthenticationService {
     /// Create a new authentication service.
-    pub fn new(users_service: Arc<UserService>, authorization_service: Arc<AuthorizationService>) -> Self {
+    pub fn new(
+        users_service: Arc<UserService>,
+        password_hasher: Arc<PasswordHasher>,
+        sessions_service: Arc<SessionService>,
+        authorization_service: Arc<AuthorizationService>,
+    ) -> Self {
         Self {
             users_service,
+            password_hasher,
+            sessions_service,
             authorization_service,
         }
     }
 }
+
+impl AuthenticationService {
+    /// Start a new session for a user that has just authenticated.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user
+    /// - `device` - The details of the device that the user authenticated from
+    ///
+    /// # Returns
+    /// A security context and access token for the new session.
+    async fn start_session(&self, user_id: &UserId, device: DeviceDetails) -> Result<(SecurityContext, AccessToken), SaveSessionError> {
+        let session = self.sessions_service.start_session(user_id, device).await?;
+
+        Ok(self
+            .authorization_service
+            .generate_security_context(user_id.into(), session.identity.id, session.data.expires))
+    }
+}
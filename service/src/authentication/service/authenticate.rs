@@ -1,7 +1,8 @@
 use super::AuthenticationService;
 use crate::{
     authorization::{AccessToken, SecurityContext},
-    users::Username,
+    sessions::DeviceDetails,
+    users::{PasswordMatch, UserResource, Username},
 };
 
 #[derive(Debug, PartialEq, thiserror::Error)]
@@ -11,6 +12,9 @@ pub enum AuthenticateError {
 
     #[error("Invaid password")]
     InvalidPassword,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
 }
 
 impl AuthenticationService {
@@ -19,21 +23,199 @@ impl AuthenticationService {
     /// # Parameters
     /// - `username` - The username to authenticate
     /// - `password` - The password to authenticate
+    /// - `device` - The details of the device that the user is authenticating from
     ///
     /// # Returns
-    /// A security context and access token for the credentials, or else an error if authentication
+    /// A security context and access token for a new session, or else an error if authentication
     /// failed.
-    pub async fn authenticate(&self, username: &Username, password: &str) -> Result<(SecurityContext, AccessToken), AuthenticateError> {
-        let user = self.users_service.get_user_by_username(username).await;
+    pub async fn authenticate(
+        &self,
+        username: &Username,
+        password: &str,
+        device: DeviceDetails,
+    ) -> Result<(SecurityContext, AccessToken), AuthenticateError> {
+        let user = self
+            .users_service
+            .get_user_by_username(username)
+            .await
+            .ok_or(AuthenticateError::UnknownUser)?;
+
+        match self.password_hasher.verify(&user.data.password, password).await {
+            PasswordMatch::Mismatch => return Err(AuthenticateError::InvalidPassword),
+            PasswordMatch::Outdated => self.rehash_password(&user, password).await,
+            PasswordMatch::Match => {},
+        }
 
-        match user {
-            None => Err(AuthenticateError::UnknownUser),
-            Some(u) if u.data.password != password => Err(AuthenticateError::InvalidPassword),
-            Some(u) => {
-                let (security_context, access_token) = self.authorization_service.generate_security_context(u.identity.id.into());
+        let (security_context, access_token) = self.start_session(&user.identity.id, device).await.map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to start session");
+            AuthenticateError::UnknownError
+        })?;
 
-                Ok((security_context, access_token))
-            },
+        Ok((security_context, access_token))
+    }
+
+    /// Re-hash the password of the given user with the current settings, and store the new hash.
+    /// Failure to do so is logged but otherwise ignored, since the user has still authenticated
+    /// correctly.
+    ///
+    /// # Parameters
+    /// - `user` - The user to re-hash the password for
+    /// - `password` - The plaintext password that the user authenticated with
+    async fn rehash_password(&self, user: &UserResource, password: &str) {
+        let new_password = self.password_hasher.hash(password).await;
+
+        // The stored hash is only replaced if it's still the one that was verified, so that a
+        // password changed concurrently isn't overwritten.
+        if !self
+            .users_service
+            .rehash_password(&user.identity.id, &user.data.password, &new_password)
+            .await
+        {
+            tracing::warn!(user_id = ?user.identity.id, "Failed to re-hash password");
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::{
+        authorization::{AuthorizationService, Principal},
+        revisions::{MemoryRevisionRepository, RevisionService},
+        sessions::{MemorySessionRepository, SessionService},
+        tokens::{MemoryTokenRepository, TokenService},
+        users::{MemoryUserRepository, NewUser, PasswordHasher, PasswordPolicy, UserService},
+    };
+
+    async fn build_service_with_hashers(
+        original_hasher: PasswordHasher,
+        password_hasher: PasswordHasher,
+    ) -> (AuthenticationService, Arc<MemoryUserRepository>, Arc<UserService>, String) {
+        let repository = Arc::new(MemoryUserRepository::new());
+        let users_service = Arc::new(UserService::new(
+            repository.clone(),
+            Arc::new(RevisionService::new(Arc::new(MemoryRevisionRepository::new()))),
+            Arc::new(PasswordPolicy::new(8, 3)),
+            Arc::new(original_hasher),
+        ));
+        let user = users_service
+            .create_user(NewUser {
+                username:     "testuser".parse().unwrap(),
+                email:        "testuser@example.com".parse().unwrap(),
+                display_name: "Test User".to_owned(),
+                password:     "Hedgehog-Lantern-47".to_owned(),
+            })
+            .await
+            .unwrap();
+
+        let sessions_service = Arc::new(SessionService::new(Arc::new(MemorySessionRepository::new())));
+        let sut = AuthenticationService::new(
+            users_service.clone(),
+            Arc::new(password_hasher),
+            sessions_service.clone(),
+            Arc::new(AuthorizationService::new(
+                "secret",
+                sessions_service,
+                Arc::new(TokenService::new(Arc::new(MemoryTokenRepository::new()))),
+            )),
+        );
+
+        let Principal::User(user_id) = user.identity.id.into();
+
+        (sut, repository, users_service, user_id)
+    }
+
+    async fn build_service() -> (AuthenticationService, String) {
+        let (sut, _, _, user_id) = build_service_with_hashers(PasswordHasher::new(64, 1, 1), PasswordHasher::new(64, 1, 1)).await;
+
+        (sut, user_id)
+    }
+
+    #[actix_rt::test]
+    async fn correct_password() {
+        let (sut, user_id) = build_service().await;
+
+        let result = sut
+            .authenticate(&"testuser".parse().unwrap(), "Hedgehog-Lantern-47", DeviceDetails::default())
+            .await;
+
+        let_assert!(Ok((security_context, _)) = result);
+        check!(security_context.principal == Principal::User(user_id.clone()));
+
+        let sessions = sut.sessions_service.get_sessions_for_user(&user_id.parse().unwrap()).await;
+        check!(sessions.len() == 1);
+        check!(security_context.session_id() == Some(&sessions[0].identity.id));
+    }
+
+    #[actix_rt::test]
+    async fn wrong_password() {
+        let (sut, _) = build_service().await;
+
+        let result = sut
+            .authenticate(&"testuser".parse().unwrap(), "wrong", DeviceDetails::default())
+            .await;
+
+        let_assert!(Err(e) = result);
+        check!(e == AuthenticateError::InvalidPassword);
+    }
+
+    #[actix_rt::test]
+    async fn unknown_user() {
+        let (sut, _) = build_service().await;
+
+        let result = sut
+            .authenticate(&"unknown".parse().unwrap(), "Hedgehog-Lantern-47", DeviceDetails::default())
+            .await;
+
+        let_assert!(Err(e) = result);
+        check!(e == AuthenticateError::UnknownUser);
+    }
+
+    #[actix_rt::test]
+    async fn outdated_password_rehashed() {
+        let (sut, _, users_service, user_id) = build_service_with_hashers(
+            PasswordHasher::new(64, 1, 1),
+            PasswordHasher::new(64, 2, 1).with_secret_key("pepper"),
+        )
+        .await;
+
+        let result = sut
+            .authenticate(&"testuser".parse().unwrap(), "Hedgehog-Lantern-47", DeviceDetails::default())
+            .await;
+        check!(result.is_ok());
+
+        let_assert!(Some(user) = users_service.get_user_by_id(&user_id.parse().unwrap()).await);
+        let hasher = PasswordHasher::new(64, 2, 1).with_secret_key("pepper");
+        check!(hasher.verify(&user.data.password, "Hedgehog-Lantern-47").await == PasswordMatch::Match);
+
+        let result = sut
+            .authenticate(&"testuser".parse().unwrap(), "Hedgehog-Lantern-47", DeviceDetails::default())
+            .await;
+        check!(result.is_ok());
+    }
+
+    #[actix_rt::test]
+    async fn outdated_password_rehashed_without_events() {
+        let (sut, repository, users_service, user_id) = build_service_with_hashers(
+            PasswordHasher::new(64, 1, 1),
+            PasswordHasher::new(64, 2, 1).with_secret_key("pepper"),
+        )
+        .await;
+        let_assert!(Some(before) = users_service.get_user_by_id(&user_id.parse().unwrap()).await);
+        let registered = repository.events();
+
+        let result = sut
+            .authenticate(&"testuser".parse().unwrap(), "Hedgehog-Lantern-47", DeviceDetails::default())
+            .await;
+        check!(result.is_ok());
+
+        let_assert!(Some(after) = users_service.get_user_by_id(&user_id.parse().unwrap()).await);
+        check!(after.data.password != before.data.password);
+        check!(after.identity.version == before.identity.version);
+        check!(repository.events() == registered);
+    }
+}
@@ -1,7 +1,8 @@
 use super::AuthenticationService;
 use crate::{
     authorization::{AccessToken, SecurityContext},
-    users::{CreateUserError, Email, Password, UserData, Username},
+    sessions::{DeviceDetails, SaveSessionError},
+    users::{CreateUserError, Email, NewUser, PasswordPolicyError, Username},
 };
 
 /// Details needed to register a new user.
@@ -10,15 +11,22 @@ pub struct Registration {
     pub username:     Username,
     pub email:        Email,
     pub display_name: String,
-    pub password:     Password,
+    /// The plaintext password, which must satisfy the password policy.
+    pub password:     String,
 }
 
 /// Errors that can happen when registering a user
 #[derive(Debug, PartialEq, thiserror::Error)]
 pub enum RegistrationError {
+    #[error("The password failed to satisfy the password policy")]
+    InvalidPassword(PasswordPolicyError),
+
     #[error("The username is already registered")]
     DuplicateUsername,
 
+    #[error("The email address is already registered")]
+    DuplicateEmail,
+
     #[error("An unknown error occured")]
     UnknownError,
 }
@@ -28,19 +36,24 @@ impl AuthenticationService {
     ///
     /// # Parameters
     /// - `registration` - The details to register the user with
+    /// - `device` - The details of the device that the user is registering from
     ///
     /// # Returns
     /// The authentication details for the new user.
-    pub async fn register(&self, registration: Registration) -> Result<(SecurityContext, AccessToken), RegistrationError> {
+    pub async fn register(
+        &self,
+        registration: Registration,
+        device: DeviceDetails,
+    ) -> Result<(SecurityContext, AccessToken), RegistrationError> {
         let user = self.users_service.create_user(registration.into()).await?;
 
-        let (security_context, access_token) = self.authorization_service.generate_security_context(user.identity.id.into());
+        let (security_context, access_token) = self.start_session(&user.identity.id, device).await?;
 
         Ok((security_context, access_token))
     }
 }
 
-impl From<Registration> for UserData {
+impl From<Registration> for NewUser {
     fn from(registration: Registration) -> Self {
         Self {
             username:     registration.username,
@@ -51,11 +64,138 @@ impl From<Registration> for UserData {
     }
 }
 
+impl From<SaveSessionError> for RegistrationError {
+    fn from(e: SaveSessionError) -> Self {
+        tracing::warn!(e = ?e, "Failed to start session for new user");
+
+        Self::UnknownError
+    }
+}
+
 impl From<CreateUserError> for RegistrationError {
     fn from(e: CreateUserError) -> Self {
         match e {
+            CreateUserError::InvalidPassword(e) => Self::InvalidPassword(e),
             CreateUserError::DuplicateUsername => Self::DuplicateUsername,
+            CreateUserError::DuplicateEmail => Self::DuplicateEmail,
             CreateUserError::UnknownError => Self::UnknownError,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::{
+        authorization::AuthorizationService,
+        revisions::{MemoryRevisionRepository, RevisionService},
+        sessions::{MemorySessionRepository, SessionService},
+        tokens::{MemoryTokenRepository, TokenService},
+        users::{MemoryUserRepository, PasswordHasher, PasswordPolicy, UserService},
+    };
+
+    fn build_service() -> AuthenticationService {
+        let users_service = Arc::new(UserService::new(
+            Arc::new(MemoryUserRepository::new()),
+            Arc::new(RevisionService::new(Arc::new(MemoryRevisionRepository::new()))),
+            Arc::new(PasswordPolicy::new(8, 3)),
+            Arc::new(PasswordHasher::new(64, 1, 1)),
+        ));
+        let sessions_service = Arc::new(SessionService::new(Arc::new(MemorySessionRepository::new())));
+
+        AuthenticationService::new(
+            users_service,
+            Arc::new(PasswordHasher::new(64, 1, 1)),
+            sessions_service.clone(),
+            Arc::new(AuthorizationService::new(
+                "secret",
+                sessions_service,
+                Arc::new(TokenService::new(Arc::new(MemoryTokenRepository::new()))),
+            )),
+        )
+    }
+
+    fn registration(username: &str, email: &str) -> Registration {
+        Registration {
+            username:     username.parse().unwrap(),
+            email:        email.parse().unwrap(),
+            display_name: "Test User".to_owned(),
+            password:     "Hedgehog-Lantern-47".to_owned(),
+        }
+    }
+
+    #[actix_rt::test]
+    async fn register() {
+        let sut = build_service();
+
+        let result = sut
+            .register(registration("testuser", "testuser@example.com"), DeviceDetails::default())
+            .await;
+
+        let_assert!(Ok((security_context, _)) = result);
+
+        let user = sut.users_service.get_user_by_username(&"testuser".parse().unwrap()).await;
+        let_assert!(Some(user) = user);
+        check!(security_context.principal == (&user.identity.id).into());
+
+        let sessions = sut.sessions_service.get_sessions_for_user(&user.identity.id).await;
+        check!(sessions.len() == 1);
+        check!(security_context.session_id() == Some(&sessions[0].identity.id));
+    }
+
+    #[actix_rt::test]
+    async fn register_duplicate_username() {
+        let sut = build_service();
+
+        let_assert!(
+            Ok(_) = sut
+                .register(registration("testuser", "testuser@example.com"), DeviceDetails::default())
+                .await
+        );
+        let_assert!(
+            Err(e) = sut
+                .register(registration("TestUser", "other@example.com"), DeviceDetails::default())
+                .await
+        );
+        check!(e == RegistrationError::DuplicateUsername);
+    }
+
+    #[actix_rt::test]
+    async fn register_duplicate_email() {
+        let sut = build_service();
+
+        let_assert!(
+            Ok(_) = sut
+                .register(registration("testuser", "testuser@example.com"), DeviceDetails::default())
+                .await
+        );
+        let_assert!(
+            Err(e) = sut
+                .register(registration("otheruser", "TestUser@example.com"), DeviceDetails::default())
+                .await
+        );
+        check!(e == RegistrationError::DuplicateEmail);
+    }
+
+    #[actix_rt::test]
+    async fn register_weak_password() {
+        let sut = build_service();
+
+        let result = sut
+            .register(
+                Registration {
+                    password: "password".to_owned(),
+                    ..registration("testuser", "testuser@example.com")
+                },
+                DeviceDetails::default(),
+            )
+            .await;
+
+        let_assert!(Err(RegistrationError::InvalidPassword(_)) = result);
+        check!(sut.users_service.get_user_by_username(&"testuser".parse().unwrap()).await.is_none());
+    }
+}
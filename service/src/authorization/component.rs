@@ -3,7 +3,7 @@ use std::sync::Arc;
 use actix_web::web::ServiceConfig;
 
 use super::service::AuthorizationService;
-use crate::server::RouteConfigurer;
+use crate::{server::RouteConfigurer, sessions::SessionService, tokens::TokenService};
 
 /// Component for authorization.
 pub struct Component {
@@ -13,8 +13,13 @@ pub struct Component {
 
 impl Component {
     /// Create a new authorization component.
-    pub fn new(secret: &str) -> Arc<Self> {
-        let service = Arc::new(AuthorizationService::new(secret));
+    ///
+    /// # Parameters
+    /// - `secret` - The secret to sign access tokens with
+    /// - `sessions_service` - The service to check that sessions are still active with
+    /// - `tokens_service` - The service to authenticate personal access tokens with
+    pub fn new(secret: &str, sessions_service: Arc<SessionService>, tokens_service: Arc<TokenService>) -> Arc<Self> {
+        let service = Arc::new(AuthorizationService::new(secret, sessions_service, tokens_service));
         Arc::new(Self { service })
     }
 }
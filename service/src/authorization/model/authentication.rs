@@ -7,7 +7,10 @@ use futures::Future;
 use super::{Principal, SecurityContext};
 use crate::{
     authorization::{service::AuthorizationService, AccessToken},
-    http::problem::{Problem, FORBIDDEN, UNAUTHORIZED},
+    http::problem::{Problem, FORBIDDEN, NOT_FOUND, UNAUTHORIZED},
+    tokens::Scope,
+    users::UserId,
+    worlds::{Role, WorldId, WorldService},
 };
 
 /// Enumeration of possible authentication states.
@@ -46,6 +49,57 @@ impl Authentication {
             Some(_) => Ok(()),
         }
     }
+
+    /// Get the ID of the authenticated user, failing if the request is not authenticated as a user.
+    pub fn require_user(&self) -> Result<UserId, Problem> {
+        match self.principal() {
+            None => Err(Problem::from(UNAUTHORIZED)),
+            Some(Principal::User(user_id)) => user_id.parse().map_err(|e| {
+                tracing::warn!(e = ?e, user_id = ?user_id, "Failed to parse User ID of principal");
+                Problem::from(FORBIDDEN)
+            }),
+        }
+    }
+
+    /// Check if the authenticated principal is a member of a world, with at least the role that is
+    /// required. Worlds that the principal is not a member of are reported as not existing, so that
+    /// their existence isn't revealed.
+    ///
+    /// # Parameters
+    /// - `worlds` - The world service to look up the membership with
+    /// - `world_id` - The ID of the world
+    /// - `required` - The least privileged role that is acceptable
+    ///
+    /// # Returns
+    /// The role that the principal actually has within the world.
+    pub async fn require_world_role(&self, worlds: &WorldService, world_id: &WorldId, required: Role) -> Result<Role, Problem> {
+        let user_id = self.require_user()?;
+
+        match worlds.get_role(world_id, &user_id).await {
+            None => Err(Problem::from(NOT_FOUND)),
+            Some(role) if !role.includes(required) => Err(Problem::from(FORBIDDEN)),
+            Some(role) => Ok(role),
+        }
+    }
+
+    /// Check if the request has been granted the scope that is required.
+    pub fn require_scope(&self, scope: Scope) -> Result<(), Problem> {
+        match self.security_context() {
+            None => Err(Problem::from(UNAUTHORIZED)),
+            Some(sc) if !sc.has_scope(scope) => Err(Problem::from(FORBIDDEN)),
+            Some(_) => Ok(()),
+        }
+    }
+
+    /// Check if the request was authenticated with an interactive session, rather than with a
+    /// personal access token.
+    pub fn require_session(&self) -> Result<(), Problem> {
+        match self.security_context() {
+            None => Err(Problem::from(UNAUTHORIZED)),
+            Some(sc) if sc.session_id().is_none() => Err(Problem::from(FORBIDDEN)),
+            Some(_) => Ok(()),
+        }
+    }
 }
 
 impl FromRequest for Authentication {
@@ -74,6 +128,7 @@ impl FromRequest for Authentication {
 
                 authorizer
                     .authorize(&token)
+                    .await
                     .map_err(|e| {
                         tracing::warn!(e = ?e, authorization = ?authorization, "Failed to authorize access token");
                         Problem::from(UNAUTHORIZED)
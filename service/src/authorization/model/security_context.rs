@@ -1,14 +1,53 @@
 use chrono::{DateTime, Utc};
 
 use super::Principal;
+use crate::{
+    sessions::SessionId,
+    tokens::{Scope, TokenId},
+};
+
+/// The credential that a security context was authenticated with.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Credential {
+    /// An interactive session, which has every scope.
+    Session(SessionId),
+    /// A personal access token, which has only the scopes it was created with.
+    PersonalAccessToken { token_id: TokenId, scopes: Vec<Scope> },
+}
 
 /// An authenticated security context.
 #[derive(Debug)]
 pub struct SecurityContext {
     /// The principal that was authenticated.
-    pub principal: Principal,
+    pub principal:  Principal,
+    /// The credential that was used to authenticate.
+    pub credential: Credential,
     /// When the security context was issued.
-    pub issued:    DateTime<Utc>,
+    pub issued:     DateTime<Utc>,
     /// When the security context expires.
-    pub expires:   DateTime<Utc>,
+    pub expires:    DateTime<Utc>,
+}
+
+impl SecurityContext {
+    /// Get the ID of the session that the security context belongs to, if it belongs to one.
+    pub fn session_id(&self) -> Option<&SessionId> {
+        match &self.credential {
+            Credential::Session(session_id) => Some(session_id),
+            Credential::PersonalAccessToken { .. } => None,
+        }
+    }
+
+    /// Determine if the security context has been granted the provided scope.
+    ///
+    /// # Parameters
+    /// - `scope` - The scope to check
+    ///
+    /// # Returns
+    /// True if the scope has been granted. False if not.
+    pub fn has_scope(&self, scope: Scope) -> bool {
+        match &self.credential {
+            Credential::Session(_) => true,
+            Credential::PersonalAccessToken { scopes, .. } => scopes.contains(&scope),
+        }
+    }
 }
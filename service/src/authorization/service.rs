@@ -2,18 +2,31 @@ mod authorize;
 mod constants;
 mod generate;
 
+use std::sync::Arc;
+
 use biscuit::jws::Secret;
 
+use crate::{sessions::SessionService, tokens::TokenService};
+
 /// Service for authorizing users.
 pub struct AuthorizationService {
-    secret: Secret,
+    secret:           Secret,
+    sessions_service: Arc<SessionService>,
+    tokens_service:   Arc<TokenService>,
 }
 
 impl AuthorizationService {
     /// Create a new authorization service.
-    pub fn new(secret: &str) -> Self {
+    ///
+    /// # Parameters
+    /// - `secret` - The secret to sign access tokens with
+    /// - `sessions_service` - The service to check that sessions are still active with
+    /// - `tokens_service` - The service to authenticate personal access tokens with
+    pub fn new(secret: &str, sessions_service: Arc<SessionService>, tokens_service: Arc<TokenService>) -> Self {
         Self {
             secret: Secret::Bytes(secret.to_owned().into_bytes()),
+            sessions_service,
+            tokens_service,
         }
     }
 }
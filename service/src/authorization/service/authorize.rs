@@ -1,12 +1,18 @@
 use std::ops::Deref;
 
 use biscuit::{jws::Compact, ClaimsSet, Validation, ValidationOptions};
+use chrono::Utc;
 
 use super::{
     constants::{ALGORITHM, AUDIENCE, ISSUER},
     AuthorizationService,
 };
-use crate::authorization::{AccessToken, Principal, SecurityContext};
+use crate::{
+    authorization::{AccessToken, Credential, Principal, SecurityContext},
+    sessions::SessionId,
+    tokens::TokenSecret,
+    users::UserId,
+};
 
 /// Errors from authorizing an access token.
 #[derive(Debug, PartialEq, thiserror::Error)]
@@ -17,13 +23,76 @@ pub enum AuthorizeError {
 
 impl AuthorizationService {
     /// Authorize an access token, producing the Security Context that it represents.
+    /// This can be either a personal access token, or a signed token for a session. In the latter
+    /// case, the session must still be active for this to succeed.
+    ///
+    /// # Parameters
+    /// - `access_token` - The access token to authorize
+    ///
+    /// # Returns
+    /// The security context, or an error if it can't be parsed or is no longer valid.
+    pub async fn authorize(&self, access_token: &AccessToken) -> Result<SecurityContext, AuthorizeError> {
+        if TokenSecret::is_token_secret(&access_token.0) {
+            self.authorize_personal_access_token(&access_token.0).await
+        } else {
+            self.authorize_session_token(access_token).await
+        }
+    }
+
+    /// Check that a credential that was authorized earlier is still active, so that connections
+    /// which outlive a single request can be closed once it has been revoked.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user that the credential belongs to
+    /// - `credential` - The credential
+    ///
+    /// # Returns
+    /// True if the session hasn't ended, or the personal access token hasn't been deleted or
+    /// expired.
+    pub async fn is_active(&self, user_id: &UserId, credential: &Credential) -> bool {
+        match credential {
+            Credential::Session(session_id) => self.sessions_service.check_session(user_id, session_id).await,
+            Credential::PersonalAccessToken { token_id, .. } => self
+                .tokens_service
+                .get_tokens_for_user(user_id)
+                .await
+                .iter()
+                .any(|token| &token.identity.id == token_id && token.data.expires > Utc::now()),
+        }
+    }
+
+    /// Authorize a personal access token.
+    ///
+    /// # Parameters
+    /// - `secret` - The secret value of the personal access token
+    ///
+    /// # Returns
+    /// The security context, or an error if the token is unknown or has expired.
+    async fn authorize_personal_access_token(&self, secret: &str) -> Result<SecurityContext, AuthorizeError> {
+        let token = self.tokens_service.authenticate_token(secret).await.ok_or_else(|| {
+            tracing::warn!("Unknown or expired personal access token");
+            AuthorizeError::InvalidToken
+        })?;
+
+        Ok(SecurityContext {
+            principal:  (&token.data.user_id).into(),
+            credential: Credential::PersonalAccessToken {
+                token_id: token.identity.id,
+                scopes:   token.data.scopes,
+            },
+            issued:     token.identity.created,
+            expires:    token.data.expires,
+        })
+    }
+
+    /// Authorize a signed token for a session.
     ///
     /// # Parameters
     /// - `access_token` - The access token to authorize
     ///
     /// # Returns
-    /// The security context, or an error if it can't be parsed.
-    pub fn authorize(&self, access_token: &AccessToken) -> Result<SecurityContext, AuthorizeError> {
+    /// The security context, or an error if it can't be parsed or the session has ended.
+    async fn authorize_session_token(&self, access_token: &AccessToken) -> Result<SecurityContext, AuthorizeError> {
         let encoded = Compact::<ClaimsSet<()>, ()>::new_encoded(&access_token.0);
         let decoded = encoded.decode(&self.secret, ALGORITHM).map_err(|e| {
             tracing::warn!(e = ?e, access_token = ?access_token, "Failed to decode access token");
@@ -55,17 +124,38 @@ impl AuthorizationService {
             tracing::warn!(token = ?decoded, field = "exp", "Missing field");
             AuthorizeError::InvalidToken
         })?;
+        let jti = payload.registered.id.as_ref().ok_or_else(|| {
+            tracing::warn!(token = ?decoded, field = "jti", "Missing field");
+            AuthorizeError::InvalidToken
+        })?;
+
+        let user_id: UserId = sub.parse().map_err(|e| {
+            tracing::warn!(e = ?e, token = ?decoded, field = "sub", "Malformed field");
+            AuthorizeError::InvalidToken
+        })?;
+        let session_id: SessionId = jti.parse().map_err(|e| {
+            tracing::warn!(e = ?e, token = ?decoded, field = "jti", "Malformed field");
+            AuthorizeError::InvalidToken
+        })?;
+
+        if !self.sessions_service.check_session(&user_id, &session_id).await {
+            tracing::warn!(token = ?decoded, "Session is no longer active");
+            return Err(AuthorizeError::InvalidToken);
+        }
 
         Ok(SecurityContext {
-            principal: Principal::User(sub),
-            issued:    *iat.deref(),
-            expires:   *exp.deref(),
+            principal:  Principal::User(sub),
+            credential: Credential::Session(session_id),
+            issued:     *iat.deref(),
+            expires:    *exp.deref(),
         })
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use assert2::{check, let_assert};
     use biscuit::{
         jws::{RegisteredHeader, Secret},
@@ -75,9 +165,40 @@ mod tests {
     use test_case::test_case;
 
     use super::*;
+    use crate::{
+        model::Identity,
+        sessions::{DeviceDetails, MemorySessionRepository, SessionData, SessionResource, SessionService},
+        tokens::{MemoryTokenRepository, NewToken, Scope, TokenService},
+    };
+
+    const USER_ID: &str = "4ea96dc3-df11-43c0-8a33-a0813f03937f";
+    const SESSION_ID: &str = "d61dac0c-45f2-49ed-85cc-f24bbe939404";
+
+    fn build_sut() -> AuthorizationService {
+        let repository = MemorySessionRepository::new();
+        repository.insert_session(SessionResource {
+            identity: Identity {
+                id: SESSION_ID.parse().unwrap(),
+                ..Identity::default()
+            },
+            data:     SessionData {
+                user_id:   USER_ID.parse().unwrap(),
+                device:    DeviceDetails::default(),
+                last_seen: Utc::now(),
+                expires:   Utc::now() + Duration::days(30),
+            },
+        });
+
+        AuthorizationService::new(
+            "secret",
+            Arc::new(SessionService::new(Arc::new(repository))),
+            Arc::new(TokenService::new(Arc::new(MemoryTokenRepository::new()))),
+        )
+    }
 
     fn build_token(
         sub: Option<&str>,
+        jti: Option<&str>,
         iss: Option<&str>,
         aud: Option<&str>,
         iat: Option<DateTime<Utc>>,
@@ -97,6 +218,7 @@ mod tests {
                     audience: aud.map(|s| SingleOrMultiple::Single(s.parse().unwrap())),
                     issued_at: iat.map(|t| t.into()),
                     expiry: exp.map(|t| t.into()),
+                    id: jti.map(ToOwned::to_owned),
                     ..RegisteredClaims::default()
                 },
                 private:    (),
@@ -112,12 +234,13 @@ mod tests {
         AccessToken(token)
     }
 
-    #[test]
-    fn authorize_valid_token() {
+    #[actix_rt::test]
+    async fn authorize_valid_token() {
         let now = Utc::now().round_subsecs(0);
 
         let token = build_token(
-            Some("userId"),
+            Some(USER_ID),
+            Some(SESSION_ID),
             Some(ISSUER),
             Some(AUDIENCE),
             Some(now - Duration::days(5)),
@@ -125,28 +248,133 @@ mod tests {
             "secret",
         );
 
-        let sut = AuthorizationService::new("secret");
+        let sut = build_sut();
 
-        let result = sut.authorize(&token);
+        let result = sut.authorize(&token).await;
 
         let_assert!(Ok(token) = result);
-        check!(token.principal == Principal::User("userId".to_owned()));
+        check!(token.principal == Principal::User(USER_ID.to_owned()));
+        check!(token.credential == Credential::Session(SESSION_ID.parse().unwrap()));
         check!(token.issued == now - Duration::days(5));
         check!(token.expires == now + Duration::days(5));
     }
 
-    #[test_case(&build_token(Some("userId"), Some(ISSUER), Some(AUDIENCE), Some(Utc::now() - Duration::days(5)), Some(Utc::now() - Duration::days(2)), "secret") ; "Expired")]
-    #[test_case(&build_token(Some("userId"), Some(ISSUER), Some(AUDIENCE), Some(Utc::now() + Duration::days(2)), Some(Utc::now() + Duration::days(5)), "secret") ; "Not Issued Yet")]
-    #[test_case(&build_token(Some("userId"), Some("wrong"), Some(AUDIENCE), Some(Utc::now() - Duration::days(5)), Some(Utc::now() + Duration::days(5)), "secret") ; "Wrong Issuer")]
-    #[test_case(&build_token(Some("userId"), Some(ISSUER), Some("wrong"), Some(Utc::now() - Duration::days(5)), Some(Utc::now() + Duration::days(5)), "secret") ; "Wrong Audience")]
-    #[test_case(&build_token(None, Some(ISSUER), Some(AUDIENCE), Some(Utc::now() - Duration::days(5)), Some(Utc::now() + Duration::days(5)), "secret") ; "No Subject")]
-    #[test_case(&build_token(Some("userId"), Some(ISSUER), Some(AUDIENCE), None, Some(Utc::now() + Duration::days(5)), "secret") ; "No Issued Time")]
-    #[test_case(&build_token(Some("userId"), Some(ISSUER), Some(AUDIENCE), Some(Utc::now() - Duration::days(5)), None, "secret") ; "No Expiry Time")]
-    #[test_case(&build_token(Some("userId"), Some(ISSUER), Some(AUDIENCE), Some(Utc::now() - Duration::days(5)), Some(Utc::now() + Duration::days(5)), "wrong") ; "Wrong Secret")]
-    fn authorize_invalid_token(token: &AccessToken) {
-        let sut = AuthorizationService::new("secret");
-
-        let result = sut.authorize(&token);
+    #[test_case(&build_token(Some(USER_ID), Some(SESSION_ID), Some(ISSUER), Some(AUDIENCE), Some(Utc::now() - Duration::days(5)), Some(Utc::now() - Duration::days(2)), "secret") ; "Expired")]
+    #[test_case(&build_token(Some(USER_ID), Some(SESSION_ID), Some(ISSUER), Some(AUDIENCE), Some(Utc::now() + Duration::days(2)), Some(Utc::now() + Duration::days(5)), "secret") ; "Not Issued Yet")]
+    #[test_case(&build_token(Some(USER_ID), Some(SESSION_ID), Some("wrong"), Some(AUDIENCE), Some(Utc::now() - Duration::days(5)), Some(Utc::now() + Duration::days(5)), "secret") ; "Wrong Issuer")]
+    #[test_case(&build_token(Some(USER_ID), Some(SESSION_ID), Some(ISSUER), Some("wrong"), Some(Utc::now() - Duration::days(5)), Some(Utc::now() + Duration::days(5)), "secret") ; "Wrong Audience")]
+    #[test_case(&build_token(None, Some(SESSION_ID), Some(ISSUER), Some(AUDIENCE), Some(Utc::now() - Duration::days(5)), Some(Utc::now() + Duration::days(5)), "secret") ; "No Subject")]
+    #[test_case(&build_token(Some(USER_ID), Some(SESSION_ID), Some(ISSUER), Some(AUDIENCE), None, Some(Utc::now() + Duration::days(5)), "secret") ; "No Issued Time")]
+    #[test_case(&build_token(Some(USER_ID), Some(SESSION_ID), Some(ISSUER), Some(AUDIENCE), Some(Utc::now() - Duration::days(5)), None, "secret") ; "No Expiry Time")]
+    #[test_case(&build_token(Some(USER_ID), Some(SESSION_ID), Some(ISSUER), Some(AUDIENCE), Some(Utc::now() - Duration::days(5)), Some(Utc::now() + Duration::days(5)), "wrong") ; "Wrong Secret")]
+    #[test_case(&build_token(Some(USER_ID), None, Some(ISSUER), Some(AUDIENCE), Some(Utc::now() - Duration::days(5)), Some(Utc::now() + Duration::days(5)), "secret") ; "No Session")]
+    #[test_case(&build_token(Some(USER_ID), Some("wrong"), Some(ISSUER), Some(AUDIENCE), Some(Utc::now() - Duration::days(5)), Some(Utc::now() + Duration::days(5)), "secret") ; "Malformed Session")]
+    #[test_case(&build_token(Some(USER_ID), Some("8d3a8f51-2b4c-4a0e-9f53-64f4c2e1d1b7"), Some(ISSUER), Some(AUDIENCE), Some(Utc::now() - Duration::days(5)), Some(Utc::now() + Duration::days(5)), "secret") ; "Unknown Session")]
+    #[test_case(&build_token(Some("userId"), Some(SESSION_ID), Some(ISSUER), Some(AUDIENCE), Some(Utc::now() - Duration::days(5)), Some(Utc::now() + Duration::days(5)), "secret") ; "Malformed Subject")]
+    #[test_case(&build_token(Some("8d3a8f51-2b4c-4a0e-9f53-64f4c2e1d1b7"), Some(SESSION_ID), Some(ISSUER), Some(AUDIENCE), Some(Utc::now() - Duration::days(5)), Some(Utc::now() + Duration::days(5)), "secret") ; "Other Users Session")]
+    #[actix_rt::test]
+    async fn authorize_invalid_token(token: &AccessToken) {
+        let sut = build_sut();
+
+        let result = sut.authorize(&token).await;
+
+        let_assert!(Err(err) = result);
+        check!(err == AuthorizeError::InvalidToken);
+    }
+
+    #[actix_rt::test]
+    async fn authorize_ended_session() {
+        let token = build_token(
+            Some(USER_ID),
+            Some(SESSION_ID),
+            Some(ISSUER),
+            Some(AUDIENCE),
+            Some(Utc::now() - Duration::days(5)),
+            Some(Utc::now() + Duration::days(5)),
+            "secret",
+        );
+
+        let sut = build_sut();
+        check!(sut.authorize(&token).await.is_ok());
+
+        sut.sessions_service.end_all_sessions(&USER_ID.parse().unwrap()).await;
+
+        let result = sut.authorize(&token).await;
+        let_assert!(Err(err) = result);
+        check!(err == AuthorizeError::InvalidToken);
+    }
+
+    #[actix_rt::test]
+    async fn authorize_personal_access_token() {
+        let sut = build_sut();
+        let (created, secret) = sut
+            .tokens_service
+            .create_token(
+                &USER_ID.parse().unwrap(),
+                NewToken {
+                    name:    "Test Token".to_owned(),
+                    scopes:  vec![Scope::UsersRead],
+                    expires: Utc::now() + Duration::days(5),
+                },
+            )
+            .await
+            .unwrap();
+
+        let result = sut.authorize(&AccessToken(secret.value().to_owned())).await;
+
+        let_assert!(Ok(security_context) = result);
+        check!(security_context.principal == Principal::User(USER_ID.to_owned()));
+        check!(security_context.session_id() == None);
+        check!(security_context.has_scope(Scope::UsersRead));
+        check!(!security_context.has_scope(Scope::UsersWrite));
+        check!(security_context.expires == created.data.expires);
+    }
+
+    #[actix_rt::test]
+    async fn session_is_active() {
+        let sut = build_sut();
+        let user_id = USER_ID.parse().unwrap();
+        let credential = Credential::Session(SESSION_ID.parse().unwrap());
+
+        check!(sut.is_active(&user_id, &credential).await);
+        check!(!sut.is_active(&UserId::default(), &credential).await);
+
+        sut.sessions_service.end_all_sessions(&user_id).await;
+        check!(!sut.is_active(&user_id, &credential).await);
+    }
+
+    #[actix_rt::test]
+    async fn personal_access_token_is_active() {
+        let sut = build_sut();
+        let user_id = USER_ID.parse().unwrap();
+        let (created, _) = sut
+            .tokens_service
+            .create_token(
+                &user_id,
+                NewToken {
+                    name:    "Test Token".to_owned(),
+                    scopes:  vec![Scope::WorldsRead],
+                    expires: Utc::now() + Duration::days(5),
+                },
+            )
+            .await
+            .unwrap();
+        let credential = Credential::PersonalAccessToken {
+            token_id: created.identity.id.clone(),
+            scopes:   created.data.scopes.clone(),
+        };
+
+        check!(sut.is_active(&user_id, &credential).await);
+
+        sut.tokens_service.delete_token(&user_id, &created.identity.id).await;
+        check!(!sut.is_active(&user_id, &credential).await);
+    }
+
+    #[actix_rt::test]
+    async fn authorize_unknown_personal_access_token() {
+        let sut = build_sut();
+
+        let result = sut.authorize(&AccessToken("wpat_unknown".to_owned())).await;
 
         let_assert!(Err(err) = result);
         check!(err == AuthorizeError::InvalidToken);
@@ -2,20 +2,39 @@ use biscuit::{
     jws::{Compact, RegisteredHeader},
     ClaimsSet, RegisteredClaims, SingleOrMultiple,
 };
-use chrono::{Duration, SubsecRound, Utc};
+use chrono::{DateTime, Duration, SubsecRound, Utc};
 
 use super::{
     constants::{ALGORITHM, AUDIENCE, ISSUER},
     AuthorizationService,
 };
-use crate::authorization::{AccessToken, Principal, SecurityContext};
+use crate::{
+    authorization::{AccessToken, Credential, Principal, SecurityContext},
+    sessions::SessionId,
+};
 
 impl AuthorizationService {
-    pub fn generate_security_context(&self, principal: Principal) -> (SecurityContext, AccessToken) {
+    /// Generate a security context, and the access token representing it, for a session.
+    ///
+    /// # Parameters
+    /// - `principal` - The principal that the session belongs to
+    /// - `session_id` - The ID of the session
+    /// - `expires` - When the session expires, which the access token expires with
+    ///
+    /// # Returns
+    /// The security context and access token.
+    pub fn generate_security_context(
+        &self,
+        principal: Principal,
+        session_id: SessionId,
+        expires: DateTime<Utc>,
+    ) -> (SecurityContext, AccessToken) {
         let issued = Utc::now().round_subsecs(0) - Duration::seconds(1); // Needs to be in the past, so deduct one second from it.
-        let expires = issued + Duration::days(30);
+        let expires = expires.round_subsecs(0);
+        let token_id = session_id.to_string();
         let security_context = SecurityContext {
             principal,
+            credential: Credential::Session(session_id),
             issued,
             expires,
         };
@@ -35,6 +54,7 @@ impl AuthorizationService {
                     audience: Some(SingleOrMultiple::Single(AUDIENCE.to_owned())),
                     issued_at: Some(security_context.issued.into()),
                     expiry: Some(security_context.expires.into()),
+                    id: Some(token_id),
                     ..RegisteredClaims::default()
                 },
                 private:    (),
@@ -50,23 +70,41 @@ impl AuthorizationService {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use assert2::{check, let_assert};
 
     use super::*;
+    use crate::{
+        sessions::{DeviceDetails, MemorySessionRepository, SessionService},
+        tokens::{MemoryTokenRepository, TokenService},
+        users::UserId,
+    };
 
-    #[test]
-    fn generate_security_context() {
-        let sut = AuthorizationService::new("secret");
+    #[actix_rt::test]
+    async fn generate_security_context() {
+        let sessions_service = Arc::new(SessionService::new(Arc::new(MemorySessionRepository::new())));
+        let user_id = UserId::default();
+        let session = sessions_service.start_session(&user_id, DeviceDetails::default()).await.unwrap();
+
+        let sut = AuthorizationService::new(
+            "secret",
+            sessions_service,
+            Arc::new(TokenService::new(Arc::new(MemoryTokenRepository::new()))),
+        );
 
-        let (security_context, access_token) = sut.generate_security_context(Principal::User("user_id".to_owned()));
+        let (security_context, access_token) =
+            sut.generate_security_context(Principal::from(&user_id), session.identity.id.clone(), session.data.expires);
 
-        check!(security_context.issued + Duration::days(30) == security_context.expires);
-        check!(security_context.principal == Principal::User("user_id".to_owned()));
+        check!(security_context.expires == session.data.expires.round_subsecs(0));
+        check!(security_context.principal == Principal::from(&user_id));
+        check!(security_context.session_id() == Some(&session.identity.id));
 
-        let authorized = sut.authorize(&access_token);
+        let authorized = sut.authorize(&access_token).await;
         let_assert!(Ok(authorized_security_token) = authorized);
         check!(authorized_security_token.issued == security_context.issued);
         check!(authorized_security_token.expires == security_context.expires);
         check!(authorized_security_token.principal == security_context.principal);
+        check!(authorized_security_token.credential == security_context.credential);
     }
 }
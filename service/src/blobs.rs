@@ -0,0 +1,80 @@
+pub mod component;
+mod filesystem;
+#[cfg(test)]
+mod memory;
+mod model;
+mod s3;
+
+use bytes::Bytes;
+#[cfg(test)]
+pub use memory::MemoryBlobStore;
+pub use model::*;
+
+/// Storage for binary objects, such as uploaded files.
+///
+/// Blobs are addressed by keys made up of lowercase letters, digits, hyphens, dots and slashes, so
+/// that every implementation is able to use them as file paths or object names directly.
+#[async_trait::async_trait]
+pub trait BlobStore: Send + Sync {
+    /// Store a blob, replacing any blob that already has the same key.
+    ///
+    /// # Parameters
+    /// - `key` - The key to store the blob under
+    /// - `content_type` - The media type of the blob
+    /// - `data` - The contents of the blob
+    async fn put(&self, key: &str, content_type: &str, data: Bytes) -> Result<(), BlobError>;
+
+    /// Retrieve a blob.
+    ///
+    /// # Parameters
+    /// - `key` - The key of the blob
+    /// - `range` - The range of bytes to retrieve, or `None` for the entire blob
+    ///
+    /// # Returns
+    /// The requested bytes of the blob, or `None` if there is no blob with this key.
+    async fn get(&self, key: &str, range: Option<&ByteRange>) -> Result<Option<Bytes>, BlobError>;
+
+    /// Delete a blob. Deleting a blob that doesn't exist is not an error.
+    ///
+    /// # Parameters
+    /// - `key` - The key of the blob
+    async fn delete(&self, key: &str) -> Result<(), BlobError>;
+}
+
+/// Check that a blob key is one that every store can safely use.
+///
+/// # Parameters
+/// - `key` - The key to check
+fn check_key(key: &str) -> Result<(), BlobError> {
+    let valid_chars = key
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '.' || c == '/');
+    let valid_segments = key
+        .split('/')
+        .all(|segment| !segment.is_empty() && segment != "." && segment != "..");
+
+    if valid_chars && valid_segments {
+        Ok(())
+    } else {
+        Err(BlobError::InvalidKey(key.to_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("uploads/50b44401-a345-419d-a8a8-baf22df76c05", true ; "Simple")]
+    #[test_case("uploads/50b44401.thumbnail", true ; "Extension")]
+    #[test_case("", false ; "Blank")]
+    #[test_case("uploads//thing", false ; "Empty segment")]
+    #[test_case("/etc/passwd", false ; "Absolute")]
+    #[test_case("uploads/../../etc/passwd", false ; "Parent")]
+    #[test_case("uploads/Thing", false ; "Capitals")]
+    fn test_check_key(key: &str, expected: bool) {
+        check!(check_key(key).is_ok() == expected);
+    }
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use super::{
+    filesystem::FilesystemBlobStore,
+    s3::{S3BlobStore, S3Settings},
+    BlobStore,
+};
+use crate::settings::Settings;
+
+/// Component for the storage of binary objects.
+pub struct Component {
+    pub store: Arc<dyn BlobStore>,
+}
+
+impl Component {
+    /// Create a new blob storage component, using whichever store is configured in the settings.
+    ///
+    /// # Parameters
+    /// - `settings` - The settings of the service
+    pub fn new(settings: &Settings) -> Self {
+        let store: Arc<dyn BlobStore> = match settings.blob_store.as_str() {
+            "filesystem" => {
+                tracing::info!(path = ?settings.blob_path, "Storing blobs on the filesystem");
+
+                Arc::new(FilesystemBlobStore::new(&settings.blob_path))
+            },
+            "s3" => {
+                let s3 = S3Settings {
+                    bucket:     settings.s3_bucket.clone().expect("No S3 bucket configured"),
+                    region:     settings.s3_region.clone(),
+                    endpoint:   settings.s3_endpoint.clone(),
+                    access_key: settings.s3_access_key.clone().expect("No S3 access key configured"),
+                    secret_key: settings.s3_secret_key.clone().expect("No S3 secret key configured"),
+                };
+                tracing::info!(bucket = ?s3.bucket, endpoint = ?s3.endpoint, "Storing blobs in S3");
+
+                Arc::new(S3BlobStore::new(s3))
+            },
+            other => panic!("Unknown blob store: {}", other),
+        };
+
+        Self { store }
+    }
+}
@@ -0,0 +1,156 @@
+use std::{
+    fs::{self, File},
+    io::{ErrorKind, Read, Seek, SeekFrom},
+    path::PathBuf,
+};
+
+use bytes::Bytes;
+
+use super::{check_key, BlobError, BlobStore, ByteRange};
+
+/// Blob store that keeps blobs as files within a directory on the local filesystem.
+pub struct FilesystemBlobStore {
+    root: PathBuf,
+}
+
+impl FilesystemBlobStore {
+    /// Create a new filesystem blob store.
+    ///
+    /// # Parameters
+    /// - `root` - The directory to store blobs within. This is created if it doesn't exist.
+    pub fn new<P>(root: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self { root: root.into() }
+    }
+
+    /// Determine the path of the file to store a blob in.
+    fn path(&self, key: &str) -> Result<PathBuf, BlobError> {
+        check_key(key)?;
+
+        Ok(self.root.join(key))
+    }
+}
+
+impl From<std::io::Error> for BlobError {
+    fn from(e: std::io::Error) -> Self {
+        tracing::warn!(e = ?e, "Unexpected filesystem error");
+
+        Self::StoreError(e.to_string())
+    }
+}
+
+/// Run some filesystem access on a separate thread pool, so as not to block the async executor.
+async fn run<F, R>(f: F) -> Result<R, BlobError>
+where
+    F: FnOnce() -> Result<R, BlobError> + Send + 'static,
+    R: Send + 'static,
+{
+    actix_web::web::block(f).await.map_err(|e| BlobError::StoreError(e.to_string()))?
+}
+
+#[async_trait::async_trait]
+impl BlobStore for FilesystemBlobStore {
+    #[tracing::instrument(skip(self, data))]
+    async fn put(&self, key: &str, _: &str, data: Bytes) -> Result<(), BlobError> {
+        let path = self.path(key)?;
+
+        run(move || {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            // Write to a temporary file first, so that a partially written blob is never visible.
+            let temporary = path.with_extension("partial");
+            fs::write(&temporary, &data)?;
+            fs::rename(&temporary, &path)?;
+
+            Ok(())
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get(&self, key: &str, range: Option<&ByteRange>) -> Result<Option<Bytes>, BlobError> {
+        let path = self.path(key)?;
+        let range = range.cloned();
+
+        run(move || {
+            let mut file = match File::open(&path) {
+                Ok(file) => file,
+                Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+                Err(e) => return Err(e.into()),
+            };
+
+            let mut data = vec![];
+            match range {
+                Some(range) => {
+                    file.seek(SeekFrom::Start(range.start))?;
+                    file.take(range.len()).read_to_end(&mut data)?;
+                },
+                None => {
+                    file.read_to_end(&mut data)?;
+                },
+            }
+
+            Ok(Some(Bytes::from(data)))
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete(&self, key: &str) -> Result<(), BlobError> {
+        let path = self.path(key)?;
+
+        run(move || match fs::remove_file(&path) {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn build_store() -> (FilesystemBlobStore, PathBuf) {
+        let root = std::env::temp_dir().join(format!("worlds-blobs-{}", Uuid::new_v4()));
+
+        (FilesystemBlobStore::new(&root), root)
+    }
+
+    #[actix_rt::test]
+    async fn round_trip() {
+        let (sut, root) = build_store();
+
+        let_assert!(Ok(()) = sut.put("uploads/first", "text/plain", Bytes::from_static(b"Hello, World")).await);
+
+        let_assert!(Ok(Some(data)) = sut.get("uploads/first", None).await);
+        check!(data.as_ref() == b"Hello, World");
+
+        let_assert!(Ok(Some(data)) = sut.get("uploads/first", Some(&ByteRange { start: 7, end: 20 })).await);
+        check!(data.as_ref() == b"World");
+
+        let_assert!(Ok(()) = sut.delete("uploads/first").await);
+        let_assert!(Ok(found) = sut.get("uploads/first", None).await);
+        check!(found.is_none());
+        let_assert!(Ok(()) = sut.delete("uploads/first").await);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[actix_rt::test]
+    async fn invalid_key() {
+        let (sut, _) = build_store();
+
+        let result = sut.get("../secrets", None).await;
+
+        let_assert!(Err(e) = result);
+        check!(e == BlobError::InvalidKey("../secrets".to_owned()));
+    }
+}
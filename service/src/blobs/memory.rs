@@ -0,0 +1,54 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use bytes::Bytes;
+
+use super::{check_key, BlobError, BlobStore, ByteRange};
+
+/// Blob store that keeps blobs in memory.
+/// Used for testing the layers above the blob store without needing real storage.
+#[derive(Default)]
+pub struct MemoryBlobStore {
+    blobs: Mutex<HashMap<String, Bytes>>,
+}
+
+impl MemoryBlobStore {
+    /// Create a new, empty blob store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Determine whether a blob exists.
+    pub fn contains(&self, key: &str) -> bool {
+        self.blobs.lock().unwrap().contains_key(key)
+    }
+}
+
+#[async_trait::async_trait]
+impl BlobStore for MemoryBlobStore {
+    async fn put(&self, key: &str, _: &str, data: Bytes) -> Result<(), BlobError> {
+        check_key(key)?;
+
+        self.blobs.lock().unwrap().insert(key.to_owned(), data);
+
+        Ok(())
+    }
+
+    async fn get(&self, key: &str, range: Option<&ByteRange>) -> Result<Option<Bytes>, BlobError> {
+        check_key(key)?;
+
+        let blobs = self.blobs.lock().unwrap();
+
+        Ok(blobs.get(key).map(|data| match range {
+            Some(range) => Bytes::copy_from_slice(range.slice(data)),
+            None => data.clone(),
+        }))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), BlobError> {
+        check_key(key)?;
+
+        self.blobs.lock().unwrap().remove(key);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+use std::convert::TryFrom;
+
+/// Errors that can occur when working with a blob store.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum BlobError {
+    #[error("The blob key is not valid: {0}")]
+    InvalidKey(String),
+
+    #[error("The blob store failed: {0}")]
+    StoreError(String),
+}
+
+/// A range of bytes within a blob. Both ends of the range are inclusive, as in HTTP.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ByteRange {
+    pub start: u64,
+    pub end:   u64,
+}
+
+/// Error to indicate that a requested range lies entirely outside of a blob.
+#[derive(Debug, PartialEq, thiserror::Error)]
+#[error("The requested range can't be satisfied")]
+pub struct UnsatisfiableRange;
+
+impl ByteRange {
+    /// The number of bytes in the range.
+    pub fn len(&self) -> u64 {
+        self.end - self.start + 1
+    }
+
+    /// Parse the value of an HTTP `Range` header for a blob of a known size.
+    ///
+    /// Only single byte ranges are supported. Anything else - multiple ranges, other units or
+    /// syntax that can't be understood - is treated as though no range was requested, which is
+    /// always permitted by RFC 7233.
+    ///
+    /// # Parameters
+    /// - `header` - The value of the header
+    /// - `size` - The size of the blob, in bytes
+    ///
+    /// # Returns
+    /// The range to return, `None` to return the entire blob, or an error if the range lies outside
+    /// of the blob.
+    pub fn parse(header: &str, size: u64) -> Result<Option<Self>, UnsatisfiableRange> {
+        let spec = match header.trim().strip_prefix("bytes=") {
+            Some(spec) if !spec.contains(',') => spec.trim(),
+            _ => return Ok(None),
+        };
+
+        let Some((first, last)) = spec.split_once('-') else {
+            return Ok(None);
+        };
+
+        let range = if first.is_empty() {
+            // A suffix range, requesting the last bytes of the blob.
+            let suffix: u64 = match last.parse() {
+                Ok(suffix) => suffix,
+                Err(_) => return Ok(None),
+            };
+            if suffix == 0 || size == 0 {
+                return Err(UnsatisfiableRange);
+            }
+
+            Self {
+                start: size.saturating_sub(suffix),
+                end:   size - 1,
+            }
+        } else {
+            let start: u64 = match first.parse() {
+                Ok(start) => start,
+                Err(_) => return Ok(None),
+            };
+            let end: Option<u64> = if last.is_empty() {
+                None
+            } else {
+                match last.parse() {
+                    Ok(end) => Some(end),
+                    Err(_) => return Ok(None),
+                }
+            };
+
+            if end.is_some_and(|end| end < start) {
+                return Ok(None);
+            }
+            if start >= size {
+                return Err(UnsatisfiableRange);
+            }
+
+            Self {
+                start,
+                end: end.map_or(size - 1, |end| end.min(size - 1)),
+            }
+        };
+
+        Ok(Some(range))
+    }
+
+    /// Extract the bytes in the range from the contents of an entire blob.
+    ///
+    /// # Parameters
+    /// - `data` - The contents of the blob
+    ///
+    /// # Returns
+    /// The bytes within the range, truncated to the end of the blob.
+    pub fn slice<'a>(&self, data: &'a [u8]) -> &'a [u8] {
+        let start = usize::try_from(self.start).unwrap_or(usize::MAX).min(data.len());
+        let end = usize::try_from(self.end).unwrap_or(usize::MAX).saturating_add(1).min(data.len());
+
+        &data[start..end.max(start)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("bytes=0-99", 1000, &Ok(Some(ByteRange { start: 0, end: 99 })) ; "Simple")]
+    #[test_case("bytes=900-", 1000, &Ok(Some(ByteRange { start: 900, end: 999 })) ; "Open ended")]
+    #[test_case("bytes=-100", 1000, &Ok(Some(ByteRange { start: 900, end: 999 })) ; "Suffix")]
+    #[test_case("bytes=-2000", 1000, &Ok(Some(ByteRange { start: 0, end: 999 })) ; "Suffix longer than blob")]
+    #[test_case("bytes=900-2000", 1000, &Ok(Some(ByteRange { start: 900, end: 999 })) ; "End past blob")]
+    #[test_case("bytes=1000-", 1000, &Err(UnsatisfiableRange) ; "Start past blob")]
+    #[test_case("bytes=-0", 1000, &Err(UnsatisfiableRange) ; "Empty suffix")]
+    #[test_case("bytes=0-", 0, &Err(UnsatisfiableRange) ; "Empty blob")]
+    #[test_case("bytes=0-9,20-29", 1000, &Ok(None) ; "Multiple ranges")]
+    #[test_case("bytes=99-0", 1000, &Ok(None) ; "Backwards")]
+    #[test_case("items=0-9", 1000, &Ok(None) ; "Other unit")]
+    #[test_case("bytes=a-b", 1000, &Ok(None) ; "Malformed")]
+    fn test_parse(header: &str, size: u64, expected: &Result<Option<ByteRange>, UnsatisfiableRange>) {
+        check!(&ByteRange::parse(header, size) == expected);
+    }
+
+    #[test_case(0, 2, b"abc" ; "Start")]
+    #[test_case(3, 5, b"def" ; "Middle")]
+    #[test_case(8, 20, b"ij" ; "Past end")]
+    #[test_case(20, 30, b"" ; "Outside")]
+    fn test_slice(start: u64, end: u64, expected: &[u8]) {
+        check!(ByteRange { start, end }.slice(b"abcdefghij") == expected);
+    }
+}
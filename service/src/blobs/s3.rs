@@ -0,0 +1,135 @@
+use std::convert::TryFrom;
+
+use bytes::Bytes;
+use futures::TryStreamExt;
+use rusoto_core::{credential::StaticProvider, HttpClient, Region, RusotoError};
+use rusoto_s3::{DeleteObjectRequest, GetObjectError, GetObjectRequest, PutObjectRequest, S3Client, S3};
+
+use super::{check_key, BlobError, BlobStore, ByteRange};
+
+/// The details needed to connect to an S3-compatible object store.
+#[derive(Debug, Clone)]
+pub struct S3Settings {
+    /// The bucket to store blobs in.
+    pub bucket:     String,
+    /// The name of the region that the bucket is in.
+    pub region:     String,
+    /// The endpoint to connect to, for S3-compatible object stores other than AWS itself.
+    pub endpoint:   Option<String>,
+    /// The access key to authenticate with.
+    pub access_key: String,
+    /// The secret key to authenticate with.
+    pub secret_key: String,
+}
+
+/// Blob store that keeps blobs in a bucket of an S3-compatible object store.
+pub struct S3BlobStore {
+    client: S3Client,
+    bucket: String,
+}
+
+impl S3BlobStore {
+    /// Create a new S3 blob store.
+    ///
+    /// # Parameters
+    /// - `settings` - The details of the object store to connect to
+    pub fn new(settings: S3Settings) -> Self {
+        let region = match settings.endpoint {
+            Some(endpoint) => Region::Custom {
+                name: settings.region,
+                endpoint,
+            },
+            None => settings.region.parse().expect("Unknown S3 region"),
+        };
+
+        let client = S3Client::new_with(
+            HttpClient::new().expect("Failed to create HTTP Client for S3"),
+            StaticProvider::new_minimal(settings.access_key, settings.secret_key),
+            region,
+        );
+
+        Self {
+            client,
+            bucket: settings.bucket,
+        }
+    }
+}
+
+impl<E> From<RusotoError<E>> for BlobError
+where
+    E: std::error::Error + 'static,
+{
+    fn from(e: RusotoError<E>) -> Self {
+        tracing::warn!(e = ?e, "Unexpected S3 error");
+
+        Self::StoreError(e.to_string())
+    }
+}
+
+#[async_trait::async_trait]
+impl BlobStore for S3BlobStore {
+    #[tracing::instrument(skip(self, data))]
+    async fn put(&self, key: &str, content_type: &str, data: Bytes) -> Result<(), BlobError> {
+        check_key(key)?;
+
+        self.client
+            .put_object(PutObjectRequest {
+                bucket: self.bucket.clone(),
+                key: key.to_owned(),
+                content_type: Some(content_type.to_owned()),
+                content_length: i64::try_from(data.len()).ok(),
+                body: Some(data.to_vec().into()),
+                ..PutObjectRequest::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get(&self, key: &str, range: Option<&ByteRange>) -> Result<Option<Bytes>, BlobError> {
+        check_key(key)?;
+
+        let result = self
+            .client
+            .get_object(GetObjectRequest {
+                bucket: self.bucket.clone(),
+                key: key.to_owned(),
+                range: range.map(|range| format!("bytes={}-{}", range.start, range.end)),
+                ..GetObjectRequest::default()
+            })
+            .await;
+
+        let output = match result {
+            Ok(output) => output,
+            Err(RusotoError::Service(GetObjectError::NoSuchKey(_))) => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+
+        let data = match output.body {
+            Some(body) => body
+                .map_ok(|chunk| chunk.to_vec())
+                .try_concat()
+                .await
+                .map_err(|e| BlobError::StoreError(e.to_string()))?,
+            None => vec![],
+        };
+
+        Ok(Some(Bytes::from(data)))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete(&self, key: &str) -> Result<(), BlobError> {
+        check_key(key)?;
+
+        self.client
+            .delete_object(DeleteObjectRequest {
+                bucket: self.bucket.clone(),
+                key: key.to_owned(),
+                ..DeleteObjectRequest::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
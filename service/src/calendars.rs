@@ -0,0 +1,9 @@
+pub mod component;
+pub mod endpoints;
+mod model;
+mod repository;
+mod service;
+
+pub use model::*;
+pub use repository::*;
+pub use service::*;
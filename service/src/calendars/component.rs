@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use actix_web::web::{delete, get, patch, post, resource, ServiceConfig};
+
+use super::{repository::CalendarRepository, service::CalendarService};
+use crate::server::RouteConfigurer;
+
+/// Component for working with the calendars of worlds.
+pub struct Component {
+    pub service: Arc<CalendarService>,
+}
+
+impl Component {
+    /// Create a new calendars component.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store calendars in
+    pub fn new(repository: Arc<dyn CalendarRepository>) -> Arc<Self> {
+        let service = Arc::new(CalendarService::new(repository));
+
+        Arc::new(Self { service })
+    }
+}
+
+impl RouteConfigurer for Component {
+    fn configure_routes(&self, config: &mut ServiceConfig) {
+        config.data(self.service.clone());
+
+        config.service(
+            resource("/worlds/{id}/calendars")
+                .route(post().to(super::endpoints::create_calendar::handle))
+                .route(get().to(super::endpoints::list_calendars::handle)),
+        );
+        config.service(
+            resource("/worlds/{id}/calendars/{calendar}")
+                .route(get().to(super::endpoints::get_calendar::handle))
+                .route(patch().to(super::endpoints::patch_calendar::handle))
+                .route(delete().to(super::endpoints::delete_calendar::handle)),
+        );
+        config.service(resource("/worlds/{id}/calendars/{calendar}/days/{day}").route(get().to(super::endpoints::format_day::handle)));
+        config.service(resource("/worlds/{id}/calendars/{calendar}/dates").route(get().to(super::endpoints::resolve_date::handle)));
+    }
+}
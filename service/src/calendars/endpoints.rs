@@ -0,0 +1,44 @@
+//! Endpoints for working with calendars, along with the building blocks that other components use
+//! to describe days in terms of a calendar.
+
+pub(super) mod create_calendar;
+pub(super) mod delete_calendar;
+pub(super) mod format_day;
+pub(super) mod get_calendar;
+pub(super) mod list_calendars;
+mod model;
+pub(super) mod patch_calendar;
+mod problems;
+pub(super) mod resolve_date;
+
+pub use model::FormattedDateModel;
+pub use problems::invalid_date;
+
+use crate::{
+    calendars::CalendarId,
+    http::problem::{Problem, NOT_FOUND},
+    worlds::WorldId,
+};
+
+/// Parse the world and calendar IDs from the URL.
+///
+/// # Parameters
+/// - `world_id` - The ID of the world
+/// - `calendar_id` - The ID of the calendar
+///
+/// # Returns
+/// The parsed IDs, or a Not Found problem if either of them isn't valid.
+fn parse_calendar_path(world_id: &str, calendar_id: &str) -> Result<(WorldId, CalendarId), Problem> {
+    let world_id: WorldId = world_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, world_id = ?world_id, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+    let calendar_id: CalendarId = calendar_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, calendar_id = ?calendar_id, "Failed to parse Calendar ID");
+
+        NOT_FOUND
+    })?;
+
+    Ok((world_id, calendar_id))
+}
@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use actix_http::http::StatusCode;
+use actix_web::web::{Data, Path};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{
+    model::{CalendarModel, CalendarResponse},
+    problems::invalid_calendar,
+};
+use crate::{
+    authorization::Authentication,
+    calendars::{CalendarDefinition, CalendarService, CreateCalendarError, NewCalendar},
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+        response::SimpleRespondable,
+        valid::{Valid, Validatable},
+    },
+    tokens::Scope,
+    worlds::{Role, WorldId, WorldService},
+};
+
+/// Create a new calendar within a world.
+pub async fn handle(
+    service: Data<Arc<CalendarService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<String>,
+    request: Valid<CreateCalendarRequest>,
+    authentication: Authentication,
+) -> Result<CalendarResponse, Problem> {
+    let world_id: WorldId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let request = request.unwrap();
+
+    let calendar = service
+        .create_calendar(
+            &world_id,
+            NewCalendar {
+                name:       request.name,
+                definition: request.definition,
+            },
+        )
+        .await
+        .map_err(|e| match e {
+            CreateCalendarError::InvalidCalendar(e) => invalid_calendar(&e),
+            CreateCalendarError::UnknownError => INTERNAL_SERVER_ERROR.into(),
+        })?;
+
+    Ok(SimpleRespondable::<CalendarModel>::from(calendar)
+        .with_status_code(StatusCode::CREATED)
+        .into())
+}
+
+/// The incoming request to create a calendar.
+#[derive(Deserialize)]
+pub struct CreateCalendarRequest {
+    pub name:       String,
+    pub definition: CalendarDefinition,
+}
+
+impl Validatable for CreateCalendarRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 200
+                },
+                "definition": CalendarDefinition::schema()
+            },
+            "required": [
+                "name",
+                "definition"
+            ]
+        })
+    }
+}
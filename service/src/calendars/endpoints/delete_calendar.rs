@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use actix_web::{
+    web::{Data, Path},
+    HttpResponse,
+};
+
+use super::parse_calendar_path;
+use crate::{
+    authorization::Authentication,
+    calendars::CalendarService,
+    http::problem::{Problem, NOT_FOUND},
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+pub async fn handle(
+    service: Data<Arc<CalendarService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<HttpResponse, Problem> {
+    let (world_id, calendar_id) = path.into_inner();
+    let (world_id, calendar_id) = parse_calendar_path(&world_id, &calendar_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    if service.delete_calendar(&world_id, &calendar_id).await {
+        Ok(HttpResponse::NoContent().finish())
+    } else {
+        Err(NOT_FOUND.into())
+    }
+}
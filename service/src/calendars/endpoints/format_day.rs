@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::{model::FormattedDateModel, parse_calendar_path, problems::invalid_date};
+use crate::{
+    authorization::Authentication,
+    calendars::CalendarService,
+    http::{
+        problem::{Problem, NOT_FOUND},
+        response::Response,
+    },
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Describe an absolute day in terms of a calendar.
+pub async fn handle(
+    service: Data<Arc<CalendarService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String, String)>,
+    authentication: Authentication,
+) -> Result<Response<FormattedDateModel>, Problem> {
+    let (world_id, calendar_id, day) = path.into_inner();
+    let (world_id, calendar_id) = parse_calendar_path(&world_id, &calendar_id)?;
+    let day: i64 = day.parse().map_err(|e| {
+        tracing::warn!(e = ?e, day = ?day, "Failed to parse day");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let calendar = service.get_calendar(&world_id, &calendar_id).await.ok_or(NOT_FOUND)?;
+
+    let formatted = calendar.data.definition.format(day).map_err(|e| invalid_date(&e))?;
+
+    Ok(Response(formatted.into()))
+}
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::{model::CalendarResponse, parse_calendar_path};
+use crate::{
+    authorization::Authentication,
+    calendars::CalendarService,
+    http::problem::{Problem, NOT_FOUND},
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+pub async fn handle(
+    service: Data<Arc<CalendarService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<CalendarResponse, Problem> {
+    let (world_id, calendar_id) = path.into_inner();
+    let (world_id, calendar_id) = parse_calendar_path(&world_id, &calendar_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let calendar = service.get_calendar(&world_id, &calendar_id).await.ok_or(NOT_FOUND)?;
+
+    Ok(calendar.into())
+}
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::model::{CalendarModel, CalendarsModel};
+use crate::{
+    authorization::Authentication,
+    calendars::CalendarService,
+    http::{
+        problem::{Problem, NOT_FOUND},
+        response::Response,
+    },
+    tokens::Scope,
+    worlds::{Role, WorldId, WorldService},
+};
+
+pub async fn handle(
+    service: Data<Arc<CalendarService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<String>,
+    authentication: Authentication,
+) -> Result<Response<CalendarsModel>, Problem> {
+    let world_id: WorldId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let calendars = service
+        .get_calendars(&world_id)
+        .await
+        .into_iter()
+        .map(CalendarModel::from)
+        .collect();
+
+    Ok(Response(CalendarsModel { calendars }))
+}
@@ -0,0 +1,97 @@
+use actix_web::http::header::CacheDirective;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::{
+    calendars::{CalendarDefinition, CalendarId, CalendarResource, Era, FormattedDate, MoonPhase},
+    http::{
+        model::ResourceResponse,
+        response::{Response, SimpleRespondable},
+    },
+};
+
+/// Representation of a calendar on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarModel {
+    pub calendar_id: CalendarId,
+    pub name:        String,
+    pub definition:  CalendarDefinition,
+    pub created:     DateTime<Utc>,
+    pub updated:     DateTime<Utc>,
+}
+
+impl From<CalendarResource> for CalendarModel {
+    fn from(calendar: CalendarResource) -> Self {
+        Self {
+            calendar_id: calendar.identity.id,
+            name:        calendar.data.name,
+            definition:  calendar.data.definition,
+            created:     calendar.identity.created,
+            updated:     calendar.identity.updated,
+        }
+    }
+}
+
+impl ResourceResponse for CalendarResource {
+    fn cache_control(&self) -> Option<Vec<CacheDirective>> {
+        Some(vec![CacheDirective::Private, CacheDirective::NoCache])
+    }
+}
+
+pub type CalendarResponse = Response<SimpleRespondable<CalendarModel>>;
+
+/// Representation of a list of calendars on the HTTP API.
+#[derive(Serialize)]
+pub struct CalendarsModel {
+    pub calendars: Vec<CalendarModel>,
+}
+
+/// Representation of a day described in terms of a calendar.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FormattedDateModel {
+    pub day:          i64,
+    pub year:         i64,
+    pub era:          Option<Era>,
+    pub year_of_era:  i64,
+    pub month:        String,
+    pub month_number: usize,
+    pub day_of_month: i64,
+    pub weekday:      Option<String>,
+    pub moons:        Vec<MoonModel>,
+    pub text:         String,
+}
+
+/// Representation of the state of a moon on a day.
+#[derive(Serialize)]
+pub struct MoonModel {
+    pub name:  String,
+    pub phase: MoonPhase,
+    pub age:   f64,
+}
+
+impl From<FormattedDate> for FormattedDateModel {
+    fn from(date: FormattedDate) -> Self {
+        Self {
+            day:          date.day,
+            year:         date.year,
+            era:          date.era,
+            year_of_era:  date.year_of_era,
+            month:        date.month,
+            month_number: date.month_number,
+            day_of_month: date.day_of_month,
+            weekday:      date.weekday,
+            moons:        date
+                .moons
+                .into_iter()
+                .map(|moon| MoonModel {
+                    name:  moon.name,
+                    phase: moon.phase,
+                    age:   moon.age,
+                })
+                .collect(),
+            text:         date.text,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{model::CalendarResponse, parse_calendar_path, problems::invalid_calendar};
+use crate::{
+    authorization::Authentication,
+    calendars::{CalendarData, CalendarDefinition, CalendarService, UpdateCalendarError},
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+        valid::{Valid, Validatable},
+    },
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+pub async fn handle(
+    service: Data<Arc<CalendarService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    request: Valid<PatchRequest>,
+    authentication: Authentication,
+) -> Result<CalendarResponse, Problem> {
+    let (world_id, calendar_id) = path.into_inner();
+    let (world_id, calendar_id) = parse_calendar_path(&world_id, &calendar_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let request = request.unwrap();
+
+    let calendar = service
+        .update_calendar_by_id(&world_id, &calendar_id, move |calendar| -> Result<CalendarData, Problem> {
+            Ok(CalendarData {
+                name: request.name.unwrap_or(calendar.name),
+                definition: request.definition.unwrap_or(calendar.definition),
+                ..calendar
+            })
+        })
+        .await
+        .map_err(|e| match e {
+            UpdateCalendarError::UpdateError(p) => p,
+            UpdateCalendarError::UnknownCalendar => NOT_FOUND.into(),
+            UpdateCalendarError::InvalidCalendar(e) => invalid_calendar(&e),
+            UpdateCalendarError::UnknownError => INTERNAL_SERVER_ERROR.into(),
+        })?;
+
+    Ok(calendar.into())
+}
+
+/// The incoming request to patch a calendar. The definition is replaced as a whole.
+#[derive(Deserialize)]
+pub struct PatchRequest {
+    pub name:       Option<String>,
+    pub definition: Option<CalendarDefinition>,
+}
+
+impl Validatable for PatchRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 200
+                },
+                "definition": CalendarDefinition::schema()
+            }
+        })
+    }
+}
@@ -0,0 +1,36 @@
+use actix_http::http::StatusCode;
+
+use crate::{
+    calendars::{DateError, InvalidCalendar},
+    http::problem::{Problem, SimpleProblemType},
+};
+
+/// Problem to indicate that a calendar definition doesn't make sense.
+pub const INVALID_CALENDAR: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/calendars/invalid_calendar",
+    problem_title: "Invalid Calendar",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate that a date doesn't exist within a calendar.
+pub const INVALID_DATE: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/calendars/invalid_date",
+    problem_title: "Invalid Date",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Build the problem for a calendar definition that doesn't make sense.
+///
+/// # Parameters
+/// - `e` - The reason that the calendar is invalid
+pub fn invalid_calendar(e: &InvalidCalendar) -> Problem {
+    Problem::from(INVALID_CALENDAR).with_detail(e.to_string())
+}
+
+/// Build the problem for a date that doesn't exist within a calendar.
+///
+/// # Parameters
+/// - `e` - The reason that the date is invalid
+pub fn invalid_date(e: &DateError) -> Problem {
+    Problem::from(INVALID_DATE).with_detail(e.to_string())
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path, Query};
+use serde::Deserialize;
+
+use super::{model::FormattedDateModel, parse_calendar_path, problems::invalid_date};
+use crate::{
+    authorization::Authentication,
+    calendars::{CalendarService, DateSpec, MonthRef},
+    http::{
+        problem::{Problem, NOT_FOUND},
+        response::Response,
+    },
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Query parameters for finding the absolute day of a date.
+#[derive(Deserialize)]
+pub struct DateQuery {
+    pub era:   Option<String>,
+    pub year:  i64,
+    /// Either the number of the month within the year, or its name.
+    pub month: String,
+    pub day:   i64,
+}
+
+/// Find the absolute day of a date that is written in terms of a calendar.
+pub async fn handle(
+    service: Data<Arc<CalendarService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    query: Query<DateQuery>,
+    authentication: Authentication,
+) -> Result<Response<FormattedDateModel>, Problem> {
+    let (world_id, calendar_id) = path.into_inner();
+    let (world_id, calendar_id) = parse_calendar_path(&world_id, &calendar_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let calendar = service.get_calendar(&world_id, &calendar_id).await.ok_or(NOT_FOUND)?;
+
+    let query = query.into_inner();
+    let spec = DateSpec {
+        era:   query.era,
+        year:  query.year,
+        month: query.month.parse().map_or(MonthRef::Name(query.month), MonthRef::Number),
+        day:   query.day,
+    };
+
+    let day = calendar.data.definition.resolve(&spec).map_err(|e| invalid_date(&e))?;
+
+    let formatted = calendar.data.definition.format(day).map_err(|e| invalid_date(&e))?;
+
+    Ok(Response(formatted.into()))
+}
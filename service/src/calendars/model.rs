@@ -0,0 +1,18 @@
+mod calendar_id;
+mod definition;
+
+pub use calendar_id::*;
+pub use definition::*;
+
+use crate::{model::Resource, worlds::WorldId};
+
+/// The data representing a calendar within a world.
+#[derive(Debug, Clone)]
+pub struct CalendarData {
+    pub world_id:   WorldId,
+    pub name:       String,
+    pub definition: CalendarDefinition,
+}
+
+/// Type representing a persisted calendar.
+pub type CalendarResource = Resource<CalendarId, CalendarData>;
@@ -0,0 +1,951 @@
+use std::{convert::TryFrom, fmt::Write};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::http::valid::Validatable;
+
+/// The most years that the leap year rules of a calendar can take to repeat.
+const MAX_LEAP_CYCLE: i64 = 10_000;
+
+/// The definition of a calendar, describing how days are grouped into months and years.
+///
+/// Every calendar within a world counts the same absolute days, so that the same day can be
+/// described in each of them. The `epoch` of a calendar is the absolute day on which the first day
+/// of year 1 falls in that calendar.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarDefinition {
+    /// The absolute day on which year 1 of the calendar begins.
+    #[serde(default)]
+    pub epoch:         i64,
+    /// The months of the year, in order.
+    pub months:        Vec<Month>,
+    /// The days of the week, in order. Empty if the calendar has no weeks.
+    #[serde(default)]
+    pub weekdays:      Vec<String>,
+    /// The index of the weekday that year 1 begins on.
+    #[serde(default)]
+    pub epoch_weekday: usize,
+    /// The eras that years are counted within.
+    #[serde(default)]
+    pub eras:          Vec<Era>,
+    /// The rule for which years are leap years, if the calendar has any.
+    #[serde(default)]
+    pub leap_years:    Option<LeapRule>,
+    /// The moons of the world.
+    #[serde(default)]
+    pub moons:         Vec<Moon>,
+}
+
+/// A single month of a calendar.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Month {
+    pub name:      String,
+    /// The number of days in the month in a normal year.
+    pub days:      u32,
+    /// The number of extra days that the month has in a leap year.
+    #[serde(default)]
+    pub leap_days: u32,
+}
+
+/// An era that years are counted within, such as "Third Age".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Era {
+    pub name:         String,
+    pub abbreviation: String,
+    /// The year of the calendar that is the first year of the era.
+    pub starts:       i64,
+}
+
+/// The rule for which years are leap years. A year is a leap year if it's divisible by `every`,
+/// except if it's also divisible by `except_every`, unless it's also divisible by `unless_every`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeapRule {
+    pub every:        u32,
+    #[serde(default)]
+    pub except_every: Option<u32>,
+    #[serde(default)]
+    pub unless_every: Option<u32>,
+}
+
+/// A moon of the world.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Moon {
+    pub name:   String,
+    /// The number of days from one new moon to the next.
+    pub cycle:  f64,
+    /// How many days after a new moon the calendar's epoch falls.
+    #[serde(default)]
+    pub offset: f64,
+}
+
+/// A date within a calendar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarDate {
+    /// The year, counted from the calendar's epoch rather than within an era.
+    pub year:  i64,
+    /// The index of the month within the year.
+    pub month: usize,
+    /// The day of the month, starting from 1.
+    pub day:   i64,
+}
+
+/// A reference to a month, either by its number within the year starting from 1, or by its name.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum MonthRef {
+    Number(usize),
+    Name(String),
+}
+
+/// A date as it is written by people, with the year optionally counted within an era.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DateSpec {
+    /// The name or abbreviation of the era that the year is counted within.
+    pub era:   Option<String>,
+    pub year:  i64,
+    pub month: MonthRef,
+    pub day:   i64,
+}
+
+/// The phase of a moon.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MoonPhase {
+    New,
+    WaxingCrescent,
+    FirstQuarter,
+    WaxingGibbous,
+    Full,
+    WaningGibbous,
+    LastQuarter,
+    WaningCrescent,
+}
+
+/// The state of a moon on a given day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MoonState {
+    pub name:  String,
+    pub phase: MoonPhase,
+    /// The number of days since the last new moon.
+    pub age:   f64,
+}
+
+/// A day described in terms of a calendar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormattedDate {
+    /// The absolute day.
+    pub day:          i64,
+    /// The year, counted from the calendar's epoch.
+    pub year:         i64,
+    /// The era that the year falls within, if any.
+    pub era:          Option<Era>,
+    /// The year within the era, or the year from the calendar's epoch if there is no era.
+    pub year_of_era:  i64,
+    pub month:        String,
+    /// The number of the month within the year, starting from 1.
+    pub month_number: usize,
+    pub day_of_month: i64,
+    pub weekday:      Option<String>,
+    pub moons:        Vec<MoonState>,
+    /// The date written out in full.
+    pub text:         String,
+}
+
+/// Errors that can occur when interpreting a date within a calendar.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum DateError {
+    #[error("Unknown era: {0}")]
+    UnknownEra(String),
+
+    #[error("Unknown month")]
+    UnknownMonth,
+
+    #[error("The day is not within the month")]
+    InvalidDay,
+
+    #[error("The date is too far from the calendar's epoch")]
+    OutOfRange,
+}
+
+/// Errors that can occur when checking that a calendar definition makes sense.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum InvalidCalendar {
+    #[error("The calendar has no months")]
+    NoMonths,
+
+    #[error("The weekday that the calendar starts on doesn't exist")]
+    UnknownEpochWeekday,
+
+    #[error("More than one era has the same name or abbreviation: {0}")]
+    DuplicateEra(String),
+
+    #[error("The leap year rules take too many years to repeat")]
+    LeapCycleTooLong,
+}
+
+/// Greatest common divisor of two positive numbers.
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+impl LeapRule {
+    /// Determine whether a year is a leap year according to this rule.
+    pub fn applies(&self, year: i64) -> bool {
+        let divides = |n: u32| n != 0 && year.rem_euclid(i64::from(n)) == 0;
+
+        if !divides(self.every) {
+            return false;
+        }
+
+        let excepted = self.except_every.is_some_and(divides);
+        let reinstated = self.unless_every.is_some_and(divides);
+
+        !excepted || reinstated
+    }
+
+    /// The number of years after which the pattern of leap years repeats.
+    fn cycle(&self) -> i64 {
+        [Some(self.every), self.except_every, self.unless_every]
+            .iter()
+            .flatten()
+            .map(|n| i64::from(*n))
+            .filter(|n| *n > 0)
+            .fold(1, |a, b| a / gcd(a, b) * b)
+    }
+}
+
+impl MoonPhase {
+    /// Every phase, in order from one new moon to the next.
+    const ALL: [MoonPhase; 8] = [
+        Self::New,
+        Self::WaxingCrescent,
+        Self::FirstQuarter,
+        Self::WaxingGibbous,
+        Self::Full,
+        Self::WaningGibbous,
+        Self::LastQuarter,
+        Self::WaningCrescent,
+    ];
+
+    /// Determine the phase of a moon from how far it is through its cycle.
+    ///
+    /// # Parameters
+    /// - `fraction` - How far through the cycle the moon is, from 0 up to but not including 1
+    fn from_fraction(fraction: f64) -> Self {
+        // Each phase is centered on its eighth of the cycle, so a new moon covers both the very
+        // start and the very end of the cycle.
+        let eighths = fraction * 8.0;
+        let index = (1..8_u32).take_while(|i| eighths >= f64::from(*i) - 0.5).count();
+
+        if eighths >= 7.5 {
+            Self::New
+        } else {
+            Self::ALL[index]
+        }
+    }
+}
+
+impl CalendarDefinition {
+    /// Check that the calendar definition makes sense, beyond what the schema is able to check.
+    pub fn validate(&self) -> Result<(), InvalidCalendar> {
+        if self.months.is_empty() {
+            return Err(InvalidCalendar::NoMonths);
+        }
+
+        if !self.weekdays.is_empty() && self.epoch_weekday >= self.weekdays.len() {
+            return Err(InvalidCalendar::UnknownEpochWeekday);
+        }
+
+        for (index, era) in self.eras.iter().enumerate() {
+            let duplicate = self.eras[..index].iter().any(|other| {
+                other.name.eq_ignore_ascii_case(&era.name)
+                    || other.abbreviation.eq_ignore_ascii_case(&era.abbreviation)
+                    || other.starts == era.starts
+            });
+            if duplicate {
+                return Err(InvalidCalendar::DuplicateEra(era.name.clone()));
+            }
+        }
+
+        if self.cycle_years() > MAX_LEAP_CYCLE {
+            return Err(InvalidCalendar::LeapCycleTooLong);
+        }
+
+        Ok(())
+    }
+
+    /// Determine whether a year is a leap year.
+    pub fn is_leap_year(&self, year: i64) -> bool {
+        self.leap_years.as_ref().is_some_and(|rule| rule.applies(year))
+    }
+
+    /// The number of days in a month of a given year.
+    fn month_length(&self, year: i64, month: &Month) -> i64 {
+        if self.is_leap_year(year) {
+            i64::from(month.days) + i64::from(month.leap_days)
+        } else {
+            i64::from(month.days)
+        }
+    }
+
+    /// The number of days in a given year.
+    pub fn year_length(&self, year: i64) -> i64 {
+        self.months.iter().map(|m| self.month_length(year, m)).sum()
+    }
+
+    /// The number of years after which the pattern of year lengths repeats.
+    fn cycle_years(&self) -> i64 {
+        self.leap_years.as_ref().map_or(1, LeapRule::cycle)
+    }
+
+    /// The absolute day on which a year begins.
+    ///
+    /// # Parameters
+    /// - `year` - The year, counted from the calendar's epoch
+    ///
+    /// # Returns
+    /// The absolute day, or an error if it is too far from the epoch to be represented.
+    pub fn year_start(&self, year: i64) -> Result<i64, DateError> {
+        let cycle_years = self.cycle_years();
+        let cycle_days: i64 = (1..=cycle_years).map(|y| self.year_length(y)).sum();
+
+        let index = year.checked_sub(1).ok_or(DateError::OutOfRange)?;
+        let cycles = index.div_euclid(cycle_years);
+        let remainder: i64 = (1..=index.rem_euclid(cycle_years)).map(|y| self.year_length(y)).sum();
+
+        cycles
+            .checked_mul(cycle_days)
+            .and_then(|days| days.checked_add(remainder))
+            .and_then(|days| days.checked_add(self.epoch))
+            .ok_or(DateError::OutOfRange)
+    }
+
+    /// Convert a date in this calendar to an absolute day.
+    ///
+    /// # Parameters
+    /// - `date` - The date to convert
+    ///
+    /// # Returns
+    /// The absolute day, or an error if the date doesn't exist in this calendar.
+    pub fn to_day(&self, date: &CalendarDate) -> Result<i64, DateError> {
+        let month = self.months.get(date.month).ok_or(DateError::UnknownMonth)?;
+        if date.day < 1 || date.day > self.month_length(date.year, month) {
+            return Err(DateError::InvalidDay);
+        }
+
+        let preceding: i64 = self.months[..date.month].iter().map(|m| self.month_length(date.year, m)).sum();
+
+        self.year_start(date.year)?
+            .checked_add(preceding + date.day - 1)
+            .ok_or(DateError::OutOfRange)
+    }
+
+    /// Convert an absolute day to a date in this calendar.
+    ///
+    /// # Parameters
+    /// - `day` - The absolute day
+    ///
+    /// # Returns
+    /// The date in this calendar, or an error if it is too far from the epoch to be represented.
+    pub fn date_of(&self, day: i64) -> Result<CalendarDate, DateError> {
+        let cycle_years = self.cycle_years();
+        let cycle_days: i64 = (1..=cycle_years).map(|y| self.year_length(y)).sum();
+
+        let offset = day.checked_sub(self.epoch).ok_or(DateError::OutOfRange)?;
+        let cycles = offset.div_euclid(cycle_days);
+        let mut remaining = offset.rem_euclid(cycle_days);
+
+        let mut year = 1;
+        while remaining >= self.year_length(year) {
+            remaining -= self.year_length(year);
+            year += 1;
+        }
+
+        let mut month = 0;
+        while month + 1 < self.months.len() && remaining >= self.month_length(year, &self.months[month]) {
+            remaining -= self.month_length(year, &self.months[month]);
+            month += 1;
+        }
+
+        let year = cycles
+            .checked_mul(cycle_years)
+            .and_then(|years| years.checked_add(year))
+            .ok_or(DateError::OutOfRange)?;
+
+        Ok(CalendarDate {
+            year,
+            month,
+            day: remaining + 1,
+        })
+    }
+
+    /// Find the era with the given name or abbreviation.
+    fn find_era(&self, era: &str) -> Option<&Era> {
+        self.eras
+            .iter()
+            .find(|e| e.abbreviation.eq_ignore_ascii_case(era) || e.name.eq_ignore_ascii_case(era))
+    }
+
+    /// Find the era that a year falls within.
+    ///
+    /// # Parameters
+    /// - `year` - The year, counted from the calendar's epoch
+    pub fn era_of(&self, year: i64) -> Option<&Era> {
+        self.eras.iter().filter(|e| e.starts <= year).max_by_key(|e| e.starts)
+    }
+
+    /// Determine the range of absolute days that an era covers.
+    ///
+    /// # Parameters
+    /// - `era` - The name or abbreviation of the era
+    ///
+    /// # Returns
+    /// The first day of the era and, unless it's the latest era, the last day of the era.
+    pub fn era_range(&self, era: &str) -> Result<(i64, Option<i64>), DateError> {
+        let era = self.find_era(era).ok_or_else(|| DateError::UnknownEra(era.to_owned()))?;
+
+        let next = self.eras.iter().filter(|e| e.starts > era.starts).map(|e| e.starts).min();
+
+        let starts = self.year_start(era.starts)?;
+        let ends = next.map(|year| self.year_start(year).map(|start| start - 1)).transpose()?;
+
+        Ok((starts, ends))
+    }
+
+    /// Convert a date as written by people to an absolute day.
+    ///
+    /// # Parameters
+    /// - `spec` - The date to convert
+    ///
+    /// # Returns
+    /// The absolute day, or an error if the date doesn't exist in this calendar.
+    pub fn resolve(&self, spec: &DateSpec) -> Result<i64, DateError> {
+        let year = match &spec.era {
+            Some(era) => {
+                let era = self.find_era(era).ok_or_else(|| DateError::UnknownEra(era.clone()))?;
+                spec.year
+                    .checked_sub(1)
+                    .and_then(|year| year.checked_add(era.starts))
+                    .ok_or(DateError::OutOfRange)?
+            },
+            None => spec.year,
+        };
+
+        let month = match &spec.month {
+            MonthRef::Number(number) => number.checked_sub(1).filter(|m| *m < self.months.len()),
+            MonthRef::Name(name) => self.months.iter().position(|m| m.name.eq_ignore_ascii_case(name)),
+        }
+        .ok_or(DateError::UnknownMonth)?;
+
+        self.to_day(&CalendarDate {
+            year,
+            month,
+            day: spec.day,
+        })
+    }
+
+    /// Determine the day of the week of an absolute day.
+    ///
+    /// # Returns
+    /// The name of the weekday, or `None` if the calendar has no weeks.
+    pub fn weekday(&self, day: i64) -> Option<&str> {
+        let length = i64::try_from(self.weekdays.len()).ok().filter(|l| *l > 0)?;
+        let start = i64::try_from(self.epoch_weekday).ok()?;
+        // Reduce each part first, so that days far from the epoch can't overflow.
+        let index = usize::try_from((day.rem_euclid(length) - self.epoch.rem_euclid(length) + start).rem_euclid(length)).ok()?;
+
+        self.weekdays.get(index).map(String::as_str)
+    }
+
+    /// Determine the state of every moon on an absolute day.
+    #[allow(clippy::cast_precision_loss)] // Days that are too far from the epoch to be exact are only approximate anyway.
+    pub fn moons(&self, day: i64) -> Vec<MoonState> {
+        let since_epoch = (i128::from(day) - i128::from(self.epoch)) as f64;
+
+        self.moons
+            .iter()
+            .map(|moon| {
+                let age = (since_epoch + moon.offset).rem_euclid(moon.cycle);
+
+                MoonState {
+                    name: moon.name.clone(),
+                    phase: MoonPhase::from_fraction(age / moon.cycle),
+                    age,
+                }
+            })
+            .collect()
+    }
+
+    /// Describe an absolute day in terms of this calendar.
+    ///
+    /// # Returns
+    /// The description of the day, or an error if it is too far from the epoch to be represented.
+    pub fn format(&self, day: i64) -> Result<FormattedDate, DateError> {
+        let date = self.date_of(day)?;
+        let era = self.era_of(date.year).cloned();
+        let year_of_era = match &era {
+            Some(era) => date
+                .year
+                .checked_sub(era.starts)
+                .and_then(|year| year.checked_add(1))
+                .ok_or(DateError::OutOfRange)?,
+            None => date.year,
+        };
+        let month = self.months.get(date.month).map(|m| m.name.clone()).unwrap_or_default();
+        let weekday = self.weekday(day).map(ToOwned::to_owned);
+
+        let mut text = String::new();
+        if let Some(weekday) = &weekday {
+            write!(text, "{}, ", weekday).unwrap();
+        }
+        write!(text, "{} {} {}", date.day, month, year_of_era).unwrap();
+        if let Some(era) = &era {
+            write!(text, " {}", era.abbreviation).unwrap();
+        }
+
+        Ok(FormattedDate {
+            day,
+            year: date.year,
+            era,
+            year_of_era,
+            month,
+            month_number: date.month + 1,
+            day_of_month: date.day,
+            weekday,
+            moons: self.moons(day),
+            text,
+        })
+    }
+}
+
+impl Validatable for DateSpec {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "era": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 100
+                },
+                "year": {
+                    "type": "integer"
+                },
+                "month": {
+                    "type": ["integer", "string"]
+                },
+                "day": {
+                    "type": "integer"
+                }
+            },
+            "required": ["year", "month", "day"],
+            "additionalProperties": false
+        })
+    }
+}
+
+impl Validatable for CalendarDefinition {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "epoch": {
+                    "type": "integer"
+                },
+                "months": {
+                    "type": "array",
+                    "minItems": 1,
+                    "maxItems": 100,
+                    "items": Month::schema()
+                },
+                "weekdays": {
+                    "type": "array",
+                    "maxItems": 100,
+                    "items": {
+                        "type": "string",
+                        "minLength": 1,
+                        "maxLength": 100
+                    }
+                },
+                "epochWeekday": {
+                    "type": "integer",
+                    "minimum": 0
+                },
+                "eras": {
+                    "type": "array",
+                    "maxItems": 100,
+                    "items": Era::schema()
+                },
+                "leapYears": {
+                    "type": "object",
+                    "properties": {
+                        "every": {
+                            "type": "integer",
+                            "minimum": 1
+                        },
+                        "exceptEvery": {
+                            "type": "integer",
+                            "minimum": 1
+                        },
+                        "unlessEvery": {
+                            "type": "integer",
+                            "minimum": 1
+                        }
+                    },
+                    "required": ["every"],
+                    "additionalProperties": false
+                },
+                "moons": {
+                    "type": "array",
+                    "maxItems": 20,
+                    "items": Moon::schema()
+                }
+            },
+            "required": ["months"],
+            "additionalProperties": false
+        })
+    }
+}
+
+impl Validatable for Month {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 100
+                },
+                "days": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "maximum": 1000
+                },
+                "leapDays": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "maximum": 1000
+                }
+            },
+            "required": ["name", "days"],
+            "additionalProperties": false
+        })
+    }
+}
+
+impl Validatable for Era {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 100
+                },
+                "abbreviation": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 20
+                },
+                "starts": {
+                    "type": "integer"
+                }
+            },
+            "required": ["name", "abbreviation", "starts"],
+            "additionalProperties": false
+        })
+    }
+}
+
+impl Validatable for Moon {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 100
+                },
+                "cycle": {
+                    "type": "number",
+                    "exclusiveMinimum": 0
+                },
+                "offset": {
+                    "type": "number",
+                    "minimum": 0
+                }
+            },
+            "required": ["name", "cycle"],
+            "additionalProperties": false
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use test_case::test_case;
+
+    use super::*;
+
+    /// A calendar that behaves exactly like the Gregorian calendar, with 1 January 2001 as day 0.
+    fn gregorian() -> CalendarDefinition {
+        let month = |name: &str, days: u32, leap_days: u32| Month {
+            name: name.to_owned(),
+            days,
+            leap_days,
+        };
+
+        CalendarDefinition {
+            epoch:         -730_485,
+            months:        vec![
+                month("January", 31, 0),
+                month("February", 28, 1),
+                month("March", 31, 0),
+                month("April", 30, 0),
+                month("May", 31, 0),
+                month("June", 30, 0),
+                month("July", 31, 0),
+                month("August", 31, 0),
+                month("September", 30, 0),
+                month("October", 31, 0),
+                month("November", 30, 0),
+                month("December", 31, 0),
+            ],
+            weekdays:      vec!["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"]
+                .into_iter()
+                .map(ToOwned::to_owned)
+                .collect(),
+            epoch_weekday: 0,
+            eras:          vec![Era {
+                name:         "Anno Domini".to_owned(),
+                abbreviation: "AD".to_owned(),
+                starts:       1,
+            }],
+            leap_years:    Some(LeapRule {
+                every:        4,
+                except_every: Some(100),
+                unless_every: Some(400),
+            }),
+            moons:         vec![Moon {
+                name:   "Moon".to_owned(),
+                cycle:  29.530_588,
+                offset: 19.4,
+            }],
+        }
+    }
+
+    /// A calendar with three ten day months, no weeks and no leap years, where the second age
+    /// begins in year 100.
+    fn simple() -> CalendarDefinition {
+        CalendarDefinition {
+            epoch:         0,
+            months:        vec!["Frost", "Bloom", "Harvest"]
+                .into_iter()
+                .map(|name| Month {
+                    name:      name.to_owned(),
+                    days:      10,
+                    leap_days: 0,
+                })
+                .collect(),
+            weekdays:      vec![],
+            epoch_weekday: 0,
+            eras:          vec![
+                Era {
+                    name:         "First Age".to_owned(),
+                    abbreviation: "FA".to_owned(),
+                    starts:       1,
+                },
+                Era {
+                    name:         "Second Age".to_owned(),
+                    abbreviation: "SA".to_owned(),
+                    starts:       100,
+                },
+            ],
+            leap_years:    None,
+            moons:         vec![],
+        }
+    }
+
+    #[test_case(1900, false ; "Century")]
+    #[test_case(2000, true ; "Fourth century")]
+    #[test_case(2004, true ; "Leap year")]
+    #[test_case(2021, false ; "Normal year")]
+    #[test_case(0, true ; "Year zero")]
+    #[test_case(-4, true ; "Negative year")]
+    fn test_leap_years(year: i64, expected: bool) {
+        check!(gregorian().is_leap_year(year) == expected);
+    }
+
+    #[test_case(2001, 0, 1, 0 ; "Epoch")]
+    #[test_case(2000, 11, 31, -1 ; "Day before epoch")]
+    #[test_case(2001, 11, 31, 364 ; "End of first year")]
+    #[test_case(2004, 1, 29, 1154 ; "Leap day")]
+    #[test_case(2021, 5, 14, 7469 ; "Recent")]
+    #[test_case(1, 0, 1, -730_485 ; "Year one")]
+    #[test_case(1900, 2, 1, -36_831 ; "Long ago")]
+    fn test_round_trip(year: i64, month: usize, day: i64, expected: i64) {
+        let calendar = gregorian();
+        let date = CalendarDate { year, month, day };
+
+        let_assert!(Ok(absolute) = calendar.to_day(&date));
+        check!(absolute == expected);
+        check!(calendar.date_of(absolute) == Ok(date));
+    }
+
+    #[test_case(2021, 1, 29, &DateError::InvalidDay ; "Not a leap year")]
+    #[test_case(2021, 0, 0, &DateError::InvalidDay ; "Day zero")]
+    #[test_case(2021, 12, 1, &DateError::UnknownMonth ; "Thirteenth month")]
+    fn test_invalid_date(year: i64, month: usize, day: i64, expected: &DateError) {
+        let result = gregorian().to_day(&CalendarDate { year, month, day });
+
+        let_assert!(Err(e) = result);
+        check!(&e == expected);
+    }
+
+    #[test]
+    fn test_format() {
+        let_assert!(Ok(formatted) = gregorian().format(7469));
+
+        check!(formatted.text == "Monday, 14 June 2021 AD");
+        check!(formatted.year == 2021);
+        check!(formatted.month_number == 6);
+        check!(formatted.moons.len() == 1);
+        check!(formatted.moons[0].phase == MoonPhase::WaxingCrescent);
+    }
+
+    #[test]
+    fn test_format_eras() {
+        let calendar = simple();
+
+        let text = |day| calendar.format(day).map(|formatted| formatted.text);
+
+        check!(text(0) == Ok("1 Frost 1 FA".to_owned()));
+        check!(text(29) == Ok("10 Harvest 1 FA".to_owned()));
+        check!(text(99 * 30) == Ok("1 Frost 1 SA".to_owned()));
+        check!(text(-1) == Ok("10 Harvest 0".to_owned()));
+    }
+
+    #[test_case(0, i64::MAX, true ; "Latest day")]
+    #[test_case(0, i64::MIN, true ; "Earliest day")]
+    #[test_case(i64::MAX, i64::MIN, false ; "Earliest day after latest epoch")]
+    #[test_case(i64::MIN, i64::MAX, false ; "Latest day before earliest epoch")]
+    fn test_format_extreme_days(epoch: i64, day: i64, representable: bool) {
+        let mut calendar = gregorian();
+        calendar.epoch = epoch;
+
+        check!(calendar.weekday(day).is_some());
+        check!(calendar.moons(day).len() == 1);
+        check!(calendar.format(day).is_ok() == representable);
+    }
+
+    #[test_case(None, 2021, MonthRef::Name("june".to_owned()), 14, &Ok(7469) ; "By name")]
+    #[test_case(Some("AD"), 2021, MonthRef::Number(6), 14, &Ok(7469) ; "By number")]
+    #[test_case(Some("BC"), 1, MonthRef::Number(1), 1, &Err(DateError::UnknownEra("BC".to_owned())) ; "Unknown era")]
+    #[test_case(None, 2021, MonthRef::Number(0), 1, &Err(DateError::UnknownMonth) ; "Month zero")]
+    #[test_case(None, i64::MAX, MonthRef::Number(1), 1, &Err(DateError::OutOfRange) ; "Latest year")]
+    #[test_case(None, i64::MIN, MonthRef::Number(1), 1, &Err(DateError::OutOfRange) ; "Earliest year")]
+    #[test_case(Some("AD"), i64::MAX, MonthRef::Number(1), 1, &Err(DateError::OutOfRange) ; "Latest year of era")]
+    #[test_case(Some("AD"), i64::MIN, MonthRef::Number(1), 1, &Err(DateError::OutOfRange) ; "Earliest year of era")]
+    #[test_case(None, 2021, MonthRef::Number(6), i64::MAX, &Err(DateError::InvalidDay) ; "Latest day of month")]
+    #[test_case(None, 2021, MonthRef::Number(6), i64::MIN, &Err(DateError::InvalidDay) ; "Earliest day of month")]
+    #[test_case(None, 2021, MonthRef::Name("Smarch".to_owned()), 1, &Err(DateError::UnknownMonth) ; "Unknown month")]
+    fn test_resolve(era: Option<&str>, year: i64, month: MonthRef, day: i64, expected: &Result<i64, DateError>) {
+        let spec = DateSpec {
+            era: era.map(ToOwned::to_owned),
+            year,
+            month,
+            day,
+        };
+
+        check!(&gregorian().resolve(&spec) == expected);
+    }
+
+    #[test]
+    fn test_resolve_era() {
+        let calendar = simple();
+        let spec = DateSpec {
+            era:   Some("Second Age".to_owned()),
+            year:  1,
+            month: MonthRef::Number(1),
+            day:   1,
+        };
+
+        check!(calendar.resolve(&spec) == Ok(99 * 30));
+    }
+
+    #[test]
+    fn test_era_range() {
+        let calendar = simple();
+
+        check!(calendar.era_range("FA") == Ok((0, Some(99 * 30 - 1))));
+        check!(calendar.era_range("sa") == Ok((99 * 30, None)));
+        check!(calendar.era_range("TA") == Err(DateError::UnknownEra("TA".to_owned())));
+
+        let mut calendar = simple();
+        calendar.eras[1].starts = i64::MAX;
+        check!(calendar.era_range("FA") == Err(DateError::OutOfRange));
+
+        let mut calendar = simple();
+        calendar.epoch = i64::MIN;
+        calendar.eras[0].starts = i64::MIN;
+        check!(calendar.era_range("FA") == Err(DateError::OutOfRange));
+    }
+
+    #[test_case(0.0, MoonPhase::New ; "New")]
+    #[test_case(0.05, MoonPhase::New ; "Just after new")]
+    #[test_case(0.1, MoonPhase::WaxingCrescent ; "Waxing crescent")]
+    #[test_case(0.5, MoonPhase::Full ; "Full")]
+    #[test_case(0.75, MoonPhase::LastQuarter ; "Last quarter")]
+    #[test_case(0.95, MoonPhase::New ; "Almost new")]
+    fn test_moon_phase(fraction: f64, expected: MoonPhase) {
+        check!(MoonPhase::from_fraction(fraction) == expected);
+    }
+
+    #[test]
+    fn test_validate() {
+        check!(gregorian().validate() == Ok(()));
+        check!(simple().validate() == Ok(()));
+
+        let mut calendar = gregorian();
+        calendar.epoch_weekday = 7;
+        check!(calendar.validate() == Err(InvalidCalendar::UnknownEpochWeekday));
+
+        let mut calendar = simple();
+        calendar.eras[1].abbreviation = "fa".to_owned();
+        check!(calendar.validate() == Err(InvalidCalendar::DuplicateEra("Second Age".to_owned())));
+
+        let mut calendar = gregorian();
+        calendar.leap_years = Some(LeapRule {
+            every:        997,
+            except_every: Some(991),
+            unless_every: None,
+        });
+        check!(calendar.validate() == Err(InvalidCalendar::LeapCycleTooLong));
+    }
+
+    #[test_case(&json!({"months": [{"name": "Frost", "days": 30}]}), true ; "Minimal")]
+    #[test_case(&json!({"months": []}), false ; "No months")]
+    #[test_case(&json!({"months": [{"name": "Frost", "days": 0}]}), false ; "Empty month")]
+    #[test_case(&json!({"months": [{"name": "Frost", "days": 30}], "moons": [{"name": "Luna", "cycle": 0}]}), false ; "Moon never moves")]
+    #[test_case(&json!({"months": [{"name": "Frost", "days": 30}], "leapYears": {"every": 4, "exceptEvery": 100, "unlessEvery": 400}}), true ; "Leap years")]
+    fn test_schema(input: &Value, expected: bool) {
+        let mut scope = valico::json_schema::Scope::new();
+        let schema = scope.compile_and_return(CalendarDefinition::schema(), false).unwrap();
+
+        check!(schema.validate(input).is_valid() == expected);
+    }
+}
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod memory;
+mod parse;
+mod postgres;
+mod save_calendar;
+
+#[cfg(test)]
+pub use memory::MemoryCalendarRepository;
+pub use postgres::PostgresCalendarRepository;
+pub use save_calendar::SaveCalendarError;
+
+use super::{CalendarData, CalendarId, CalendarResource};
+use crate::worlds::WorldId;
+
+/// Repository of calendars.
+#[async_trait::async_trait]
+pub trait CalendarRepository: Send + Sync {
+    /// Create a new calendar.
+    ///
+    /// # Parameters
+    /// - `calendar` - The details of the calendar to create.
+    ///
+    /// # Returns
+    /// The created calendar.
+    async fn create_calendar(&self, calendar: &CalendarData) -> Result<CalendarResource, SaveCalendarError>;
+
+    /// Get a single calendar.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the calendar belongs to.
+    /// - `calendar_id` - The ID of the calendar.
+    ///
+    /// # Returns
+    /// The calendar, or `None` if it couldn't be found.
+    async fn get_calendar(&self, world_id: &WorldId, calendar_id: &CalendarId) -> Option<CalendarResource>;
+
+    /// Get every calendar within a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world.
+    ///
+    /// # Returns
+    /// The calendars, sorted by name.
+    async fn get_calendars(&self, world_id: &WorldId) -> Vec<CalendarResource>;
+
+    /// Update an existing calendar.
+    ///
+    /// # Parameters
+    /// - `calendar_id` - The ID of the calendar.
+    /// - `calendar` - The new details of the calendar.
+    ///
+    /// # Returns
+    /// The updated calendar.
+    async fn update_calendar(&self, calendar_id: &CalendarId, calendar: &CalendarData) -> Result<CalendarResource, SaveCalendarError>;
+
+    /// Delete a single calendar.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the calendar belongs to.
+    /// - `calendar_id` - The ID of the calendar.
+    ///
+    /// # Returns
+    /// True if the calendar existed and was deleted. False if not.
+    async fn delete_calendar(&self, world_id: &WorldId, calendar_id: &CalendarId) -> bool;
+}
@@ -0,0 +1,81 @@
+use std::sync::Mutex;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::{CalendarRepository, SaveCalendarError};
+use crate::{
+    calendars::{CalendarData, CalendarId, CalendarResource},
+    model::Identity,
+    worlds::WorldId,
+};
+
+/// Repository of calendars that are stored in memory.
+#[derive(Default)]
+pub struct MemoryCalendarRepository {
+    calendars: Mutex<Vec<CalendarResource>>,
+}
+
+impl MemoryCalendarRepository {
+    /// Create a new, empty calendar repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl CalendarRepository for MemoryCalendarRepository {
+    async fn create_calendar(&self, calendar: &CalendarData) -> Result<CalendarResource, SaveCalendarError> {
+        let mut calendars = self.calendars.lock().unwrap();
+
+        let created = CalendarResource {
+            identity: Identity::default(),
+            data:     calendar.clone(),
+        };
+        calendars.push(created.clone());
+
+        Ok(created)
+    }
+
+    async fn get_calendar(&self, world_id: &WorldId, calendar_id: &CalendarId) -> Option<CalendarResource> {
+        let calendars = self.calendars.lock().unwrap();
+
+        calendars
+            .iter()
+            .find(|c| &c.data.world_id == world_id && &c.identity.id == calendar_id)
+            .cloned()
+    }
+
+    async fn get_calendars(&self, world_id: &WorldId) -> Vec<CalendarResource> {
+        let calendars = self.calendars.lock().unwrap();
+
+        let mut result: Vec<CalendarResource> = calendars.iter().filter(|c| &c.data.world_id == world_id).cloned().collect();
+        result.sort_by_key(|c| c.data.name.to_lowercase());
+
+        result
+    }
+
+    async fn update_calendar(&self, calendar_id: &CalendarId, calendar: &CalendarData) -> Result<CalendarResource, SaveCalendarError> {
+        let mut calendars = self.calendars.lock().unwrap();
+
+        let existing = calendars
+            .iter_mut()
+            .find(|c| &c.identity.id == calendar_id && c.data.world_id == calendar.world_id)
+            .ok_or(SaveCalendarError::UnknownCalendar)?;
+
+        existing.identity.version = Uuid::new_v4();
+        existing.identity.updated = Utc::now();
+        existing.data = calendar.clone();
+
+        Ok(existing.clone())
+    }
+
+    async fn delete_calendar(&self, world_id: &WorldId, calendar_id: &CalendarId) -> bool {
+        let mut calendars = self.calendars.lock().unwrap();
+
+        let before = calendars.len();
+        calendars.retain(|c| !(&c.data.world_id == world_id && &c.identity.id == calendar_id));
+
+        calendars.len() != before
+    }
+}
@@ -0,0 +1,27 @@
+use postgres_types::Json;
+use tokio_postgres::Row;
+
+use crate::{
+    calendars::{CalendarData, CalendarDefinition, CalendarResource},
+    model::Identity,
+};
+
+impl From<Row> for CalendarResource {
+    fn from(row: Row) -> Self {
+        let definition: Json<CalendarDefinition> = row.get("definition");
+
+        CalendarResource {
+            identity: Identity {
+                id:      row.get("calendar_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data:     CalendarData {
+                world_id:   row.get("world_id"),
+                name:       row.get("name"),
+                definition: definition.0,
+            },
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use postgres_types::Json;
+use uuid::Uuid;
+
+use super::{CalendarRepository, SaveCalendarError};
+use crate::{
+    calendars::{CalendarData, CalendarId, CalendarResource},
+    database::Database,
+    model::Identity,
+    worlds::WorldId,
+};
+
+/// Repository of calendars that are stored in Postgres.
+pub struct PostgresCalendarRepository {
+    database: Arc<Database>,
+}
+
+impl PostgresCalendarRepository {
+    /// Create a new calendar repository.
+    pub fn new(database: Arc<Database>) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait::async_trait]
+impl CalendarRepository for PostgresCalendarRepository {
+    #[tracing::instrument(skip(self))]
+    async fn create_calendar(&self, calendar: &CalendarData) -> Result<CalendarResource, SaveCalendarError> {
+        let conn = self.database.connect().await;
+
+        let identity = Identity::<CalendarId>::default();
+
+        let created: CalendarResource = conn
+            .query_one(
+                "INSERT INTO calendars(calendar_id, version, created, updated, world_id, name, definition) VALUES ($1, $2, $3, $4, $5, $6, $7) RETURNING *",
+                &[
+                    &identity.id,
+                    &identity.version,
+                    &identity.created,
+                    &identity.updated,
+                    &calendar.world_id,
+                    &calendar.name,
+                    &Json(&calendar.definition),
+                ],
+            )
+            .await
+            .map(CalendarResource::from)?;
+
+        Ok(created)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_calendar(&self, world_id: &WorldId, calendar_id: &CalendarId) -> Option<CalendarResource> {
+        let conn = self.database.connect().await;
+        conn.query_opt(
+            "SELECT * FROM calendars WHERE world_id = $1 AND calendar_id = $2",
+            &[&world_id, &calendar_id],
+        )
+        .await
+        .map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to load calendar");
+        })
+        .ok()
+        .flatten()
+        .map(CalendarResource::from)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_calendars(&self, world_id: &WorldId) -> Vec<CalendarResource> {
+        let conn = self.database.connect().await;
+        conn.query("SELECT * FROM calendars WHERE world_id = $1 ORDER BY LOWER(name)", &[&world_id])
+            .await
+            .map_or_else(
+                |e| {
+                    tracing::warn!(e = ?e, "Failed to load calendars");
+                    vec![]
+                },
+                |rows| rows.into_iter().map(CalendarResource::from).collect(),
+            )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn update_calendar(&self, calendar_id: &CalendarId, calendar: &CalendarData) -> Result<CalendarResource, SaveCalendarError> {
+        let conn = self.database.connect().await;
+
+        let version = Uuid::new_v4();
+        let updated = Utc::now();
+
+        conn.query_opt(
+            "UPDATE calendars SET version = $3, updated = $4, name = $5, definition = $6 WHERE calendar_id = $1 AND world_id = $2 RETURNING *",
+            &[
+                &calendar_id,
+                &calendar.world_id,
+                &version,
+                &updated,
+                &calendar.name,
+                &Json(&calendar.definition),
+            ],
+        )
+        .await?
+        .map(CalendarResource::from)
+        .ok_or(SaveCalendarError::UnknownCalendar)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_calendar(&self, world_id: &WorldId, calendar_id: &CalendarId) -> bool {
+        let conn = self.database.connect().await;
+        conn.execute(
+            "DELETE FROM calendars WHERE world_id = $1 AND calendar_id = $2",
+            &[&world_id, &calendar_id],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to delete calendar");
+                false
+            },
+            |count| count == 1,
+        )
+    }
+}
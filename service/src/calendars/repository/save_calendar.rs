@@ -0,0 +1,17 @@
+/// Errors that can occur when saving a calendar.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveCalendarError {
+    #[error("The calendar was not found")]
+    UnknownCalendar,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl From<tokio_postgres::Error> for SaveCalendarError {
+    fn from(e: tokio_postgres::Error) -> Self {
+        tracing::warn!("Unexpected database error: {:?}", e);
+
+        SaveCalendarError::UnknownError
+    }
+}
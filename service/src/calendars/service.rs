@@ -0,0 +1,26 @@
+mod create_calendar;
+mod delete_calendar;
+mod get_calendar;
+mod update_calendar;
+
+use std::sync::Arc;
+
+pub use create_calendar::{CreateCalendarError, NewCalendar};
+pub use update_calendar::UpdateCalendarError;
+
+use super::repository::CalendarRepository;
+
+/// Service layer for working with the calendars of worlds.
+pub struct CalendarService {
+    repository: Arc<dyn CalendarRepository>,
+}
+
+impl CalendarService {
+    /// Create a new calendar service.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store calendars in
+    pub fn new(repository: Arc<dyn CalendarRepository>) -> Self {
+        Self { repository }
+    }
+}
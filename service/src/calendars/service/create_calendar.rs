@@ -0,0 +1,118 @@
+use super::CalendarService;
+use crate::{
+    calendars::{CalendarData, CalendarDefinition, CalendarResource, InvalidCalendar, SaveCalendarError},
+    worlds::WorldId,
+};
+
+/// Details needed to create a new calendar.
+#[derive(Debug)]
+pub struct NewCalendar {
+    pub name:       String,
+    pub definition: CalendarDefinition,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum CreateCalendarError {
+    #[error("Invalid calendar: {0}")]
+    InvalidCalendar(InvalidCalendar),
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl CalendarService {
+    /// Create a new calendar within a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world to create the calendar in
+    /// - `calendar` - The details of the calendar to create
+    ///
+    /// # Returns
+    /// The newly created calendar.
+    pub async fn create_calendar(&self, world_id: &WorldId, calendar: NewCalendar) -> Result<CalendarResource, CreateCalendarError> {
+        calendar.definition.validate().map_err(CreateCalendarError::InvalidCalendar)?;
+
+        let created = self
+            .repository
+            .create_calendar(&CalendarData {
+                world_id:   world_id.clone(),
+                name:       calendar.name,
+                definition: calendar.definition,
+            })
+            .await?;
+
+        Ok(created)
+    }
+}
+
+impl From<SaveCalendarError> for CreateCalendarError {
+    fn from(_: SaveCalendarError) -> Self {
+        Self::UnknownError
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::calendars::{MemoryCalendarRepository, Month};
+
+    fn definition(weekdays: Vec<String>, epoch_weekday: usize) -> CalendarDefinition {
+        CalendarDefinition {
+            epoch: 0,
+            months: vec![Month {
+                name:      "Frost".to_owned(),
+                days:      30,
+                leap_days: 0,
+            }],
+            weekdays,
+            epoch_weekday,
+            eras: vec![],
+            leap_years: None,
+            moons: vec![],
+        }
+    }
+
+    #[actix_rt::test]
+    async fn create_calendar() {
+        let sut = CalendarService::new(Arc::new(MemoryCalendarRepository::new()));
+        let world_id = WorldId::default();
+
+        let result = sut
+            .create_calendar(
+                &world_id,
+                NewCalendar {
+                    name:       "Shire Reckoning".to_owned(),
+                    definition: definition(vec!["Sterday".to_owned(), "Sunday".to_owned()], 1),
+                },
+            )
+            .await;
+
+        let_assert!(Ok(calendar) = result);
+        check!(calendar.data.name == "Shire Reckoning");
+
+        let_assert!(Some(loaded) = sut.get_calendar(&world_id, &calendar.identity.id).await);
+        check!(loaded.data.definition == calendar.data.definition);
+    }
+
+    #[actix_rt::test]
+    async fn create_invalid_calendar() {
+        let sut = CalendarService::new(Arc::new(MemoryCalendarRepository::new()));
+
+        let result = sut
+            .create_calendar(
+                &WorldId::default(),
+                NewCalendar {
+                    name:       "Shire Reckoning".to_owned(),
+                    definition: definition(vec!["Sterday".to_owned()], 3),
+                },
+            )
+            .await;
+
+        let_assert!(Err(e) = result);
+        check!(e == CreateCalendarError::InvalidCalendar(InvalidCalendar::UnknownEpochWeekday));
+    }
+}
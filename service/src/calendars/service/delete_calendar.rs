@@ -0,0 +1,17 @@
+use super::CalendarService;
+use crate::{calendars::CalendarId, worlds::WorldId};
+
+impl CalendarService {
+    /// Delete a single calendar. Events are recorded against absolute days rather than calendars,
+    /// so they are unaffected.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the calendar belongs to
+    /// - `calendar_id` - The ID of the calendar
+    ///
+    /// # Returns
+    /// True if the calendar existed and was deleted. False if not.
+    pub async fn delete_calendar(&self, world_id: &WorldId, calendar_id: &CalendarId) -> bool {
+        self.repository.delete_calendar(world_id, calendar_id).await
+    }
+}
@@ -0,0 +1,30 @@
+use super::CalendarService;
+use crate::{
+    calendars::{CalendarId, CalendarResource},
+    worlds::WorldId,
+};
+
+impl CalendarService {
+    /// Get a single calendar.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the calendar belongs to
+    /// - `calendar_id` - The ID of the calendar
+    ///
+    /// # Returns
+    /// The calendar, or `None` if it doesn't exist.
+    pub async fn get_calendar(&self, world_id: &WorldId, calendar_id: &CalendarId) -> Option<CalendarResource> {
+        self.repository.get_calendar(world_id, calendar_id).await
+    }
+
+    /// Get every calendar within a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    ///
+    /// # Returns
+    /// The calendars, sorted by name.
+    pub async fn get_calendars(&self, world_id: &WorldId) -> Vec<CalendarResource> {
+        self.repository.get_calendars(world_id).await
+    }
+}
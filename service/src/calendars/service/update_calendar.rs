@@ -0,0 +1,75 @@
+use super::CalendarService;
+use crate::{
+    calendars::{CalendarData, CalendarId, CalendarResource, InvalidCalendar, SaveCalendarError},
+    worlds::WorldId,
+};
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum UpdateCalendarError<E>
+where
+    E: std::fmt::Debug,
+{
+    #[error("Unknown calendar")]
+    UnknownCalendar,
+
+    #[error("Invalid calendar: {0}")]
+    InvalidCalendar(InvalidCalendar),
+
+    #[error("An error occurred updating the calendar data")]
+    UpdateError(E),
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl CalendarService {
+    /// Update the calendar that has the provided ID, using the provided lambda to perform the
+    /// updates.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the calendar belongs to
+    /// - `calendar_id` - The ID of the calendar to update
+    /// - `f` - The function to update the calendar details
+    ///
+    /// # Returns
+    /// The newly updated calendar.
+    pub async fn update_calendar_by_id<F, E>(
+        &self,
+        world_id: &WorldId,
+        calendar_id: &CalendarId,
+        f: F,
+    ) -> Result<CalendarResource, UpdateCalendarError<E>>
+    where
+        F: FnOnce(CalendarData) -> Result<CalendarData, E>,
+        E: std::fmt::Debug,
+    {
+        let calendar = self
+            .repository
+            .get_calendar(world_id, calendar_id)
+            .await
+            .ok_or(UpdateCalendarError::UnknownCalendar)?;
+
+        let data = f(calendar.data).map_err(UpdateCalendarError::UpdateError)?;
+        let data = CalendarData {
+            world_id: world_id.clone(),
+            ..data
+        };
+        data.definition.validate().map_err(UpdateCalendarError::InvalidCalendar)?;
+
+        let result = self.repository.update_calendar(calendar_id, &data).await?;
+
+        Ok(result)
+    }
+}
+
+impl<E> From<SaveCalendarError> for UpdateCalendarError<E>
+where
+    E: std::fmt::Debug,
+{
+    fn from(e: SaveCalendarError) -> Self {
+        match e {
+            SaveCalendarError::UnknownCalendar => Self::UnknownCalendar,
+            SaveCalendarError::UnknownError => Self::UnknownError,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use actix_web::{
+    guard,
+    web::{get, resource, ServiceConfig},
+};
+
+use super::{repository::ChangeRepository, service::ChangeService};
+use crate::{realtime::RealtimeService, server::RouteConfigurer};
+
+/// Component for reading the log of the changes to the resources within worlds.
+pub struct Component {
+    pub service: Arc<ChangeService>,
+}
+
+impl Component {
+    /// Create a new changes component.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load the changes from
+    /// - `realtime` - The service to hear about new changes from as they happen
+    pub fn new(repository: Arc<dyn ChangeRepository>, realtime: Arc<RealtimeService>) -> Arc<Self> {
+        let service = Arc::new(ChangeService::new(repository, realtime));
+
+        Arc::new(Self { service })
+    }
+}
+
+impl RouteConfigurer for Component {
+    fn configure_routes(&self, config: &mut ServiceConfig) {
+        config.data(self.service.clone());
+
+        config.service(resource("/worlds/{id}/changes").route(get().to(super::endpoints::list_changes::handle)));
+        // Events on the timeline are created at the same URL, so this has to be configured before the
+        // timeline and only claim the requests that it handles. Everything else falls through to it.
+        config.service(
+            resource("/worlds/{id}/events")
+                .guard(guard::Get())
+                .route(get().to(super::endpoints::stream_changes::handle)),
+        );
+    }
+}
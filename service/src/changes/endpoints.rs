@@ -0,0 +1,58 @@
+pub(super) mod list_changes;
+mod model;
+mod problems;
+pub(super) mod stream_changes;
+
+use crate::{
+    changes::ChangeId,
+    http::{
+        pagination::{CursorPagination, CursorQuery},
+        problem::{Problem, NOT_FOUND},
+    },
+    worlds::WorldId,
+};
+
+/// Parse the ID of a world from the URL.
+///
+/// # Parameters
+/// - `world_id` - The ID of the world
+///
+/// # Returns
+/// The parsed ID, or a Not Found problem if it isn't valid.
+fn parse_world_id(world_id: &str) -> Result<WorldId, Problem> {
+    world_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, world_id = ?world_id, "Failed to parse World ID");
+
+        NOT_FOUND.into()
+    })
+}
+
+/// Parse the ID of the change that the client wants to continue on from.
+///
+/// # Parameters
+/// - `change_id` - The ID of the change
+///
+/// # Returns
+/// The parsed ID, or an Invalid Cursor problem if it isn't valid.
+fn parse_change_id(change_id: &str) -> Result<ChangeId, Problem> {
+    change_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, change_id = ?change_id, "Failed to parse Change ID");
+
+        problems::INVALID_CURSOR.into()
+    })
+}
+
+/// Determine the page of changes that was requested.
+///
+/// # Parameters
+/// - `query` - The query parameters for the page
+///
+/// # Returns
+/// The page, or an Invalid Cursor problem if the cursor isn't the ID of a change.
+fn parse_pagination(query: &CursorQuery) -> Result<CursorPagination<ChangeId>, Problem> {
+    query.pagination().map_err(|e| {
+        tracing::warn!(e = ?e, after = ?query.after, "Failed to parse change cursor");
+
+        problems::INVALID_CURSOR.into()
+    })
+}
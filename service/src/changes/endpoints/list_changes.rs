@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path, Query};
+
+use super::{
+    model::{ChangeModel, ChangesModel},
+    parse_pagination, parse_world_id,
+};
+use crate::{
+    authorization::Authentication,
+    changes::ChangeService,
+    http::{
+        pagination::{CursorModel, CursorQuery},
+        problem::Problem,
+        response::Response,
+    },
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// List the changes to a world, oldest first, for clients that poll for them.
+pub async fn handle(
+    service: Data<Arc<ChangeService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<String>,
+    query: Query<CursorQuery>,
+    authentication: Authentication,
+) -> Result<Response<ChangesModel>, Problem> {
+    let world_id = parse_world_id(&path)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let pagination = parse_pagination(&query)?;
+    let page = service.get_changes(&world_id, &pagination).await;
+
+    Ok(Response(ChangesModel {
+        page:    CursorModel::new(&pagination, page.next.as_ref()),
+        changes: page.items.into_iter().map(ChangeModel::from).collect(),
+    }))
+}
@@ -0,0 +1,86 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{changes::ChangeRecord, http::pagination::CursorModel, realtime::ChangeAction};
+
+/// Representation of a single change to a world on the HTTP API.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeModel {
+    pub change_id:   String,
+    pub action:      ChangeAction,
+    /// The kind of resource that changed, such as `article` or `character`.
+    pub resource:    String,
+    pub resource_id: String,
+    /// The new version of the resource, or `None` if it was deleted.
+    pub version:     Option<Uuid>,
+    pub occurred:    DateTime<Utc>,
+}
+
+impl From<ChangeRecord> for ChangeModel {
+    fn from(record: ChangeRecord) -> Self {
+        Self {
+            change_id:   record.change_id.to_string(),
+            action:      record.change.action,
+            resource:    record.change.resource,
+            resource_id: record.change.resource_id,
+            version:     record.change.version,
+            occurred:    record.occurred,
+        }
+    }
+}
+
+/// Representation of a page of the changes to a world on the HTTP API.
+///
+/// The last page has no cursor for the next one. A client that is polling for new changes continues
+/// on from the ID of the last change that it received instead.
+#[derive(Serialize)]
+pub struct ChangesModel {
+    pub changes: Vec<ChangeModel>,
+    pub page:    CursorModel,
+}
+
+/// Query parameters for following the changes to a world.
+#[derive(Debug, Deserialize)]
+pub struct FollowQuery {
+    /// The ID of the change to start after. Without this, only changes made from now on are sent.
+    pub after: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_json_snapshot;
+
+    use super::*;
+    use crate::{changes::ChangeId, realtime::Change, worlds::WorldId};
+
+    #[test]
+    fn serialize_change() {
+        let record = ChangeRecord {
+            change_id: ChangeId {
+                transaction: 1234,
+                sequence:    56,
+            },
+            change:    Change {
+                world_id:    WorldId::default(),
+                action:      ChangeAction::Updated,
+                resource:    "character".to_owned(),
+                resource_id: "0c9b8a7d-6e5f-4a3b-8c2d-1e0f9a8b7c6d".to_owned(),
+                version:     Some("5e4d3c2b-1a09-4f8e-b7d6-c5b4a3928170".parse().unwrap()),
+            },
+            occurred:  "2021-08-16T10:20:30Z".parse().unwrap(),
+        };
+
+        assert_json_snapshot!(ChangeModel::from(record), @r###"
+        {
+          "changeId": "1234-56",
+          "action": "updated",
+          "resource": "character",
+          "resourceId": "0c9b8a7d-6e5f-4a3b-8c2d-1e0f9a8b7c6d",
+          "version": "5e4d3c2b-1a09-4f8e-b7d6-c5b4a3928170",
+          "occurred": "2021-08-16T10:20:30Z"
+        }
+        "###);
+    }
+}
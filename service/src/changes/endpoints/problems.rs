@@ -0,0 +1,10 @@
+use actix_http::http::StatusCode;
+
+use crate::http::problem::SimpleProblemType;
+
+/// Problem to indicate that the position to continue on from in the log of changes isn't valid.
+pub const INVALID_CURSOR: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/changes/invalid_cursor",
+    problem_title: "Invalid Cursor",
+    status_code:   StatusCode::BAD_REQUEST,
+};
@@ -0,0 +1,250 @@
+use std::{sync::Arc, time::Duration};
+
+use actix_web::{
+    http::header::{CacheControl, CacheDirective},
+    web::{Bytes, Data, Path, Query},
+    HttpRequest, HttpResponse,
+};
+use chrono::{DateTime, Utc};
+use futures::{stream, StreamExt};
+
+use super::{
+    model::{ChangeModel, FollowQuery},
+    parse_change_id, parse_world_id,
+};
+use crate::{
+    authorization::{Authentication, AuthorizationService, Credential},
+    changes::ChangeService,
+    http::problem::{Problem, UNAUTHORIZED},
+    tokens::Scope,
+    users::UserId,
+    worlds::{Role, WorldId, WorldService},
+};
+
+/// The header that a client reconnects with to continue on from the last event that it received.
+const LAST_EVENT_ID: &str = "Last-Event-ID";
+
+/// How often to send something to the client while nothing in the world is changing, so that the
+/// connection isn't closed for being idle.
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Stream the changes to a world as Server-Sent Events, for clients that can't use the real-time
+/// connection.
+///
+/// A client that reconnects with the `Last-Event-ID` header continues on from the last change that
+/// it received. Otherwise the stream starts after the change in the `after` query parameter, or
+/// with the changes that are made from now on.
+///
+/// The stream ends once the access token that it was opened with expires or is revoked, or the user
+/// is no longer a member of the world.
+pub async fn handle(
+    service: Data<Arc<ChangeService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    authorizer: Data<Arc<AuthorizationService>>,
+    path: Path<String>,
+    query: Query<FollowQuery>,
+    request: HttpRequest,
+    authentication: Authentication,
+) -> Result<HttpResponse, Problem> {
+    let world_id = parse_world_id(&path)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+    let user_id = authentication.require_user()?;
+    let security_context = authentication.security_context().ok_or_else(|| Problem::from(UNAUTHORIZED))?;
+
+    let last_event_id = request.headers().get(LAST_EVENT_ID).and_then(|value| value.to_str().ok());
+    let after = last_event_id.or_else(|| query.after.as_deref()).map(parse_change_id).transpose()?;
+
+    let changes = service
+        .follow(&world_id, after)
+        .await
+        .map(|change| render_event(&ChangeModel::from(change)));
+    let keep_alive = stream::unfold((), |()| async {
+        actix_rt::time::sleep(KEEP_ALIVE_INTERVAL).await;
+        Some((Bytes::from_static(b": keep-alive\n\n"), ()))
+    });
+    let revoked = revoked(
+        authorizer.get_ref().clone(),
+        worlds_service.get_ref().clone(),
+        world_id,
+        user_id,
+        security_context.credential.clone(),
+        security_context.expires,
+    );
+    let body = stream::select(changes, keep_alive)
+        .take_until(revoked)
+        .map(Ok::<_, actix_web::Error>);
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header(CacheControl(vec![CacheDirective::Private, CacheDirective::NoCache]))
+        .streaming(Box::pin(body)))
+}
+
+/// Wait until a user is no longer allowed to follow the changes to a world, checking again after
+/// every keep-alive.
+///
+/// # Parameters
+/// - `authorizer` - The service to check that the credential is still active with
+/// - `worlds` - The service to check that the user is still a member of the world with
+/// - `world_id` - The ID of the world
+/// - `user_id` - The ID of the user
+/// - `credential` - The credential that the user authenticated with
+/// - `expires` - When the access token that the user authenticated with expires
+async fn revoked(
+    authorizer: Arc<AuthorizationService>,
+    worlds: Arc<WorldService>,
+    world_id: WorldId,
+    user_id: UserId,
+    credential: Credential,
+    expires: DateTime<Utc>,
+) {
+    loop {
+        let Ok(remaining) = (expires - Utc::now()).to_std() else {
+            tracing::debug!(user_id = ?user_id, world_id = ?world_id, "Access token has expired");
+            return;
+        };
+
+        if !authorizer.is_active(&user_id, &credential).await || worlds.get_role(&world_id, &user_id).await.is_none() {
+            tracing::debug!(user_id = ?user_id, world_id = ?world_id, "No longer allowed to follow the world");
+            return;
+        }
+
+        actix_rt::time::sleep(remaining.min(KEEP_ALIVE_INTERVAL)).await;
+    }
+}
+
+/// Render a change as a Server-Sent Event.
+///
+/// # Parameters
+/// - `change` - The change
+fn render_event(change: &ChangeModel) -> Bytes {
+    let data = serde_json::to_string(change).expect("Failed to serialize change");
+
+    Bytes::from(format!("id: {}\nevent: change\ndata: {}\n\n", change.change_id, data))
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use chrono::Duration;
+    use futures::FutureExt;
+
+    use super::*;
+    use crate::{
+        realtime::ChangeAction,
+        sessions::{DeviceDetails, MemorySessionRepository, SessionId, SessionService},
+        tokens::{MemoryTokenRepository, TokenService},
+        worlds::{Language, MemoryWorldRepository, WorldData, WorldRepository},
+    };
+
+    struct Fixture {
+        authorizer: Arc<AuthorizationService>,
+        sessions:   Arc<SessionService>,
+        worlds:     Arc<WorldService>,
+        repository: Arc<MemoryWorldRepository>,
+        world_id:   WorldId,
+        user_id:    UserId,
+        session_id: SessionId,
+    }
+
+    impl Fixture {
+        /// Start waiting for the user to no longer be allowed to follow the world.
+        ///
+        /// # Parameters
+        /// - `expires` - When the access token that the user authenticated with expires
+        fn revoked(&self, expires: DateTime<Utc>) -> impl std::future::Future<Output = ()> {
+            revoked(
+                self.authorizer.clone(),
+                self.worlds.clone(),
+                self.world_id.clone(),
+                self.user_id.clone(),
+                Credential::Session(self.session_id.clone()),
+                expires,
+            )
+        }
+    }
+
+    async fn build_fixture() -> Fixture {
+        let sessions = Arc::new(SessionService::new(Arc::new(MemorySessionRepository::new())));
+        let authorizer = Arc::new(AuthorizationService::new(
+            "secret",
+            sessions.clone(),
+            Arc::new(TokenService::new(Arc::new(MemoryTokenRepository::new()))),
+        ));
+        let repository = Arc::new(MemoryWorldRepository::new());
+        let worlds = Arc::new(WorldService::new(repository.clone()));
+
+        let user_id = UserId::default();
+        let session = sessions.start_session(&user_id, DeviceDetails::default()).await.unwrap();
+        let world = worlds
+            .create_world(
+                &user_id,
+                WorldData {
+                    name:        "Middle Earth".to_owned(),
+                    description: None,
+                    language:    Language::English,
+                },
+            )
+            .await
+            .unwrap();
+
+        Fixture {
+            authorizer,
+            sessions,
+            worlds,
+            repository,
+            world_id: world.identity.id,
+            user_id,
+            session_id: session.identity.id,
+        }
+    }
+
+    #[actix_rt::test]
+    async fn still_allowed() {
+        let f = build_fixture().await;
+
+        check!(f.revoked(Utc::now() + Duration::hours(1)).now_or_never().is_none());
+    }
+
+    #[actix_rt::test]
+    async fn revoked_when_expired() {
+        let f = build_fixture().await;
+
+        check!(f.revoked(Utc::now() - Duration::seconds(1)).now_or_never().is_some());
+    }
+
+    #[actix_rt::test]
+    async fn revoked_when_session_ended() {
+        let f = build_fixture().await;
+        check!(f.sessions.end_session(&f.user_id, &f.session_id).await);
+
+        check!(f.revoked(Utc::now() + Duration::hours(1)).now_or_never().is_some());
+    }
+
+    #[actix_rt::test]
+    async fn revoked_when_removed_from_world() {
+        let f = build_fixture().await;
+        f.repository.remove_member(&f.world_id, &f.user_id).await;
+
+        check!(f.revoked(Utc::now() + Duration::hours(1)).now_or_never().is_some());
+    }
+
+    #[test]
+    fn render_change() {
+        let change = ChangeModel {
+            change_id:   "1234-56".to_owned(),
+            action:      ChangeAction::Deleted,
+            resource:    "article".to_owned(),
+            resource_id: "gondor".to_owned(),
+            version:     None,
+            occurred:    "2021-08-16T10:20:30Z".parse().unwrap(),
+        };
+
+        check!(
+            render_event(&change)
+                == "id: 1234-56\nevent: change\ndata: {\"changeId\":\"1234-56\",\"action\":\"deleted\",\"resource\":\"article\",\"resourceId\":\"gondor\",\"version\":null,\"occurred\":\"2021-08-16T10:20:30Z\"}\n\n"
+        );
+    }
+}
@@ -0,0 +1,15 @@
+mod change_id;
+
+pub use change_id::*;
+use chrono::{DateTime, Utc};
+
+use crate::realtime::Change;
+
+/// A single entry in the log of the changes to a world.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangeRecord {
+    pub change_id: ChangeId,
+    pub change:    Change,
+    /// When the change was made.
+    pub occurred:  DateTime<Utc>,
+}
@@ -0,0 +1,96 @@
+use std::{convert::TryFrom, str::FromStr};
+
+/// The position of a change within the log of the changes to a world.
+///
+/// Changes are ordered by the transaction that made them, and then by the order that they were made
+/// in within it. Clients treat the ID as opaque, and only ever pass it back to continue on from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ChangeId {
+    /// The ID of the database transaction that made the change.
+    pub transaction: i64,
+    /// The position of the change amongst every change that has been made.
+    pub sequence:    i64,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ParseChangeIdError {
+    #[error("The Change ID was blank")]
+    Blank,
+
+    #[error("The Change ID was malformed")]
+    Malformed,
+}
+
+impl std::fmt::Display for ChangeId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.transaction, self.sequence)
+    }
+}
+
+impl FromStr for ChangeId {
+    type Err = ParseChangeIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            return Err(ParseChangeIdError::Blank);
+        }
+
+        let (transaction, sequence) = trimmed.split_once('-').ok_or(ParseChangeIdError::Malformed)?;
+        let parse = |part: &str| {
+            part.parse::<u64>()
+                .ok()
+                .and_then(|value| i64::try_from(value).ok())
+                .ok_or(ParseChangeIdError::Malformed)
+        };
+
+        Ok(ChangeId {
+            transaction: parse(transaction)?,
+            sequence:    parse(sequence)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("1234-56", 1234, 56 ; "Simple")]
+    #[test_case("  1234-56  ", 1234, 56 ; "Padded")]
+    #[test_case("0-0", 0, 0 ; "Start")]
+    fn test_parse_success(input: &str, transaction: i64, sequence: i64) {
+        let_assert!(Ok(change_id) = input.parse::<ChangeId>());
+        check!(change_id == ChangeId { transaction, sequence });
+        check!(change_id.to_string() == input.trim());
+    }
+
+    #[test_case("", &ParseChangeIdError::Blank ; "Blank")]
+    #[test_case("   ", &ParseChangeIdError::Blank ; "Whitespace")]
+    #[test_case("1234", &ParseChangeIdError::Malformed ; "No sequence")]
+    #[test_case("1234-", &ParseChangeIdError::Malformed ; "Empty sequence")]
+    #[test_case("1234--56", &ParseChangeIdError::Malformed ; "Negative")]
+    #[test_case("1234-56-78", &ParseChangeIdError::Malformed ; "Too many parts")]
+    #[test_case("abc-56", &ParseChangeIdError::Malformed ; "Not a number")]
+    #[test_case("99999999999999999999-1", &ParseChangeIdError::Malformed ; "Too large")]
+    fn test_parse_fail(input: &str, expected: &ParseChangeIdError) {
+        let_assert!(Err(e) = input.parse::<ChangeId>());
+        check!(&e == expected);
+    }
+
+    #[test]
+    fn test_order() {
+        let earlier = ChangeId {
+            transaction: 10,
+            sequence:    20,
+        };
+        let later = ChangeId {
+            transaction: 11,
+            sequence:    5,
+        };
+
+        check!(earlier < later);
+    }
+}
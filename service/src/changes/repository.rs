@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod memory;
+mod parse;
+mod postgres;
+
+#[cfg(test)]
+pub use memory::MemoryChangeRepository;
+pub use postgres::PostgresChangeRepository;
+
+use super::{ChangeId, ChangeRecord};
+use crate::worlds::WorldId;
+
+/// Repository of the log of the changes to the resources within worlds.
+///
+/// The log itself is written by the database whenever a resource changes, so there is nothing here
+/// to add to it.
+#[async_trait::async_trait]
+pub trait ChangeRepository: Send + Sync {
+    /// Get the changes to a world that come after a point in its log, oldest first.
+    ///
+    /// A change is only included once every change that comes before it is too, so continuing on
+    /// from the last change that was returned never skips any.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world.
+    /// - `after` - The ID of the change to start after, or `None` to start from the beginning.
+    /// - `limit` - The most changes to return.
+    ///
+    /// # Returns
+    /// The changes.
+    async fn get_changes(&self, world_id: &WorldId, after: Option<&ChangeId>, limit: u32) -> Vec<ChangeRecord>;
+
+    /// Get the latest change to a world that would be included by `get_changes`.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world.
+    ///
+    /// # Returns
+    /// The ID of the change, or `None` if nothing in the world has changed.
+    async fn get_latest(&self, world_id: &WorldId) -> Option<ChangeId>;
+}
@@ -0,0 +1,67 @@
+use std::{convert::TryFrom, sync::Mutex};
+
+use chrono::Utc;
+
+use super::ChangeRepository;
+use crate::{
+    changes::{ChangeId, ChangeRecord},
+    realtime::Change,
+    worlds::WorldId,
+};
+
+/// Repository of the log of changes that is stored in memory.
+#[derive(Default)]
+pub struct MemoryChangeRepository {
+    changes: Mutex<Vec<ChangeRecord>>,
+}
+
+impl MemoryChangeRepository {
+    /// Create a new, empty change repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a change to the log, as the database would whenever a resource changes.
+    ///
+    /// # Parameters
+    /// - `change` - The change
+    ///
+    /// # Returns
+    /// The ID of the change.
+    pub fn record(&self, change: Change) -> ChangeId {
+        let mut changes = self.changes.lock().unwrap();
+
+        let position = i64::try_from(changes.len()).unwrap() + 1;
+        let change_id = ChangeId {
+            transaction: position,
+            sequence:    position,
+        };
+        changes.push(ChangeRecord {
+            change_id,
+            change,
+            occurred: Utc::now(),
+        });
+
+        change_id
+    }
+}
+
+#[async_trait::async_trait]
+impl ChangeRepository for MemoryChangeRepository {
+    async fn get_changes(&self, world_id: &WorldId, after: Option<&ChangeId>, limit: u32) -> Vec<ChangeRecord> {
+        let changes = self.changes.lock().unwrap();
+
+        changes
+            .iter()
+            .filter(|c| &c.change.world_id == world_id && after.is_none_or(|after| &c.change_id > after))
+            .take(usize::try_from(limit).unwrap())
+            .cloned()
+            .collect()
+    }
+
+    async fn get_latest(&self, world_id: &WorldId) -> Option<ChangeId> {
+        let changes = self.changes.lock().unwrap();
+
+        changes.iter().rev().find(|c| &c.change.world_id == world_id).map(|c| c.change_id)
+    }
+}
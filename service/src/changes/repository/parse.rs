@@ -0,0 +1,25 @@
+use tokio_postgres::Row;
+
+use crate::{
+    changes::{ChangeId, ChangeRecord},
+    realtime::Change,
+};
+
+impl From<Row> for ChangeRecord {
+    fn from(row: Row) -> Self {
+        Self {
+            change_id: ChangeId {
+                transaction: row.get("transaction_id"),
+                sequence:    row.get("change_id"),
+            },
+            change:    Change {
+                world_id:    row.get("world_id"),
+                action:      row.get("action"),
+                resource:    row.get("resource"),
+                resource_id: row.get("resource_id"),
+                version:     row.get("version"),
+            },
+            occurred:  row.get("occurred"),
+        }
+    }
+}
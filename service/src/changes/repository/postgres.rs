@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use super::ChangeRepository;
+use crate::{
+    changes::{ChangeId, ChangeRecord},
+    database::Database,
+    worlds::WorldId,
+};
+
+/// Repository of the log of changes that is stored in Postgres.
+///
+/// Changes are only read once the transaction that made them is older than every transaction that
+/// is still running. Until then a transaction that started earlier could still add a change that
+/// comes before them.
+pub struct PostgresChangeRepository {
+    database: Arc<Database>,
+}
+
+impl PostgresChangeRepository {
+    /// Create a new change repository.
+    pub fn new(database: Arc<Database>) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait::async_trait]
+impl ChangeRepository for PostgresChangeRepository {
+    #[tracing::instrument(skip(self))]
+    async fn get_changes(&self, world_id: &WorldId, after: Option<&ChangeId>, limit: u32) -> Vec<ChangeRecord> {
+        let after = after.copied().unwrap_or_default();
+
+        let conn = self.database.connect().await;
+        conn.query(
+            "SELECT * FROM changes
+              WHERE world_id = $1
+                AND (transaction_id, change_id) > ($2, $3)
+                AND transaction_id < txid_snapshot_xmin(txid_current_snapshot())
+              ORDER BY transaction_id ASC, change_id ASC
+              LIMIT $4",
+            &[&world_id, &after.transaction, &after.sequence, &i64::from(limit)],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to load changes");
+                vec![]
+            },
+            |rows| rows.into_iter().map(ChangeRecord::from).collect(),
+        )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_latest(&self, world_id: &WorldId) -> Option<ChangeId> {
+        let conn = self.database.connect().await;
+        conn.query_opt(
+            "SELECT transaction_id, change_id FROM changes
+              WHERE world_id = $1
+                AND transaction_id < txid_snapshot_xmin(txid_current_snapshot())
+              ORDER BY transaction_id DESC, change_id DESC
+              LIMIT 1",
+            &[&world_id],
+        )
+        .await
+        .map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to load latest change");
+        })
+        .ok()?
+        .map(|row| ChangeId {
+            transaction: row.get("transaction_id"),
+            sequence:    row.get("change_id"),
+        })
+    }
+}
@@ -0,0 +1,235 @@
+use std::{collections::VecDeque, sync::Arc, time::Duration};
+
+use futures::{
+    future,
+    stream::{self, BoxStream},
+    FutureExt, Stream, StreamExt,
+};
+
+use super::{repository::ChangeRepository, ChangeId, ChangeRecord};
+use crate::{
+    http::pagination::{CursorPage, CursorPagination},
+    realtime::{RealtimeService, WorldEvent},
+    worlds::WorldId,
+};
+
+/// How often to look for new changes while following a world that nothing has been heard about.
+/// This picks up the changes that were held back behind an older transaction, and any that were
+/// missed while the connection to the broker was being restored.
+const POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The most changes to load at once while following a world.
+const BATCH_SIZE: u32 = 100;
+
+/// Service layer for reading the log of the changes to the resources within worlds.
+pub struct ChangeService {
+    repository: Arc<dyn ChangeRepository>,
+    realtime:   Arc<RealtimeService>,
+}
+
+impl ChangeService {
+    /// Create a new change service.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load the changes from
+    /// - `realtime` - The service to hear about new changes from as they happen
+    pub fn new(repository: Arc<dyn ChangeRepository>, realtime: Arc<RealtimeService>) -> Self {
+        Self { repository, realtime }
+    }
+
+    /// Get a page of the changes to a world, oldest first.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    /// - `pagination` - The page of changes to get
+    ///
+    /// # Returns
+    /// The page of changes.
+    #[tracing::instrument(skip(self))]
+    pub async fn get_changes(&self, world_id: &WorldId, pagination: &CursorPagination<ChangeId>) -> CursorPage<ChangeRecord, ChangeId> {
+        let changes = self
+            .repository
+            .get_changes(world_id, pagination.after.as_ref(), pagination.fetch_limit())
+            .await;
+
+        pagination.page(changes, |change| change.change_id)
+    }
+
+    /// Follow the changes to a world, for as long as the returned stream is kept.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    /// - `after` - The ID of the change to start after, or `None` to only include changes that are
+    ///   made from now on
+    ///
+    /// # Returns
+    /// The stream of changes, which never ends.
+    #[tracing::instrument(skip(self))]
+    pub async fn follow(&self, world_id: &WorldId, after: Option<ChangeId>) -> impl Stream<Item = ChangeRecord> {
+        // Start watching before looking for the latest change, so that nothing in between is missed.
+        let changed = self.realtime.watch(world_id).filter_map(|event| {
+            future::ready(match event {
+                WorldEvent::Change(_) => Some(()),
+                WorldEvent::Presence(_) => None,
+            })
+        });
+        let polls = stream::unfold((), |()| async {
+            actix_rt::time::sleep(POLL_INTERVAL).await;
+            Some(((), ()))
+        });
+
+        let after = match after {
+            Some(after) => Some(after),
+            None => self.repository.get_latest(world_id).await,
+        };
+
+        let follower = Follower {
+            repository: self.repository.clone(),
+            world_id: world_id.clone(),
+            after,
+            pending: VecDeque::new(),
+            wakeups: stream::select(changed, polls).boxed(),
+        };
+
+        stream::unfold(follower, |mut follower| async move {
+            let change = follower.next().await?;
+            Some((change, follower))
+        })
+    }
+}
+
+/// The state of following the changes to a single world.
+struct Follower {
+    repository: Arc<dyn ChangeRepository>,
+    world_id:   WorldId,
+    /// The ID of the last change that has been returned.
+    after:      Option<ChangeId>,
+    /// The changes that have been loaded but not yet returned.
+    pending:    VecDeque<ChangeRecord>,
+    /// Signals that there might be new changes to load.
+    wakeups:    BoxStream<'static, ()>,
+}
+
+impl Follower {
+    /// Get the next change, waiting for one to be made if there aren't any yet.
+    async fn next(&mut self) -> Option<ChangeRecord> {
+        loop {
+            if let Some(change) = self.pending.pop_front() {
+                self.after = Some(change.change_id);
+                return Some(change);
+            }
+
+            let changes = self.repository.get_changes(&self.world_id, self.after.as_ref(), BATCH_SIZE).await;
+            if changes.is_empty() {
+                self.wakeups.next().await?;
+                // A burst of changes only needs to be loaded once.
+                while let Some(Some(())) = self.wakeups.next().now_or_never() {}
+            } else {
+                self.pending.extend(changes);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use futures::StreamExt;
+
+    use super::*;
+    use crate::{
+        authorization::AuthorizationService,
+        changes::MemoryChangeRepository,
+        realtime::{Change, ChangeAction, MemoryEventBroker},
+        sessions::{MemorySessionRepository, SessionService},
+        tokens::{MemoryTokenRepository, TokenService},
+        worlds::{MemoryWorldRepository, WorldService},
+    };
+
+    struct Fixture {
+        sut:        ChangeService,
+        repository: Arc<MemoryChangeRepository>,
+        realtime:   Arc<RealtimeService>,
+        world_id:   WorldId,
+    }
+
+    fn build_fixture() -> Fixture {
+        let repository = Arc::new(MemoryChangeRepository::new());
+        let worlds = Arc::new(WorldService::new(Arc::new(MemoryWorldRepository::new())));
+        let authorizer = Arc::new(AuthorizationService::new(
+            "secret",
+            Arc::new(SessionService::new(Arc::new(MemorySessionRepository::new()))),
+            Arc::new(TokenService::new(Arc::new(MemoryTokenRepository::new()))),
+        ));
+        let realtime = Arc::new(RealtimeService::new(Arc::new(MemoryEventBroker::new()), worlds, authorizer));
+
+        Fixture {
+            sut: ChangeService::new(repository.clone(), realtime.clone()),
+            repository,
+            realtime,
+            world_id: WorldId::default(),
+        }
+    }
+
+    fn change(world_id: &WorldId, resource_id: &str) -> Change {
+        Change {
+            world_id:    world_id.clone(),
+            action:      ChangeAction::Created,
+            resource:    "article".to_owned(),
+            resource_id: resource_id.to_owned(),
+            version:     Some(uuid::Uuid::new_v4()),
+        }
+    }
+
+    #[actix_rt::test]
+    async fn get_changes() {
+        let f = build_fixture();
+        let first = f.repository.record(change(&f.world_id, "gondor"));
+        f.repository.record(change(&WorldId::default(), "shire"));
+        let second = f.repository.record(change(&f.world_id, "rohan"));
+        let third = f.repository.record(change(&f.world_id, "mordor"));
+
+        let pagination = CursorPagination { after: None, limit: 2 };
+        let page = f.sut.get_changes(&f.world_id, &pagination).await;
+        check!(page.items.iter().map(|c| c.change_id).collect::<Vec<_>>() == vec![first, second]);
+        check!(page.next == Some(second));
+
+        let pagination = CursorPagination {
+            after: Some(second),
+            limit: 2,
+        };
+        let page = f.sut.get_changes(&f.world_id, &pagination).await;
+        check!(page.items.iter().map(|c| c.change_id).collect::<Vec<_>>() == vec![third]);
+        check!(page.next == None);
+    }
+
+    #[actix_rt::test]
+    async fn follow_new_changes() {
+        let f = build_fixture();
+        f.repository.record(change(&f.world_id, "gondor"));
+
+        let changes = f.sut.follow(&f.world_id, None).await;
+        futures::pin_mut!(changes);
+
+        let made = change(&f.world_id, "rohan");
+        let change_id = f.repository.record(made.clone());
+        f.realtime.dispatch(&WorldEvent::Change(made.clone()));
+
+        let received = changes.next().await.unwrap();
+        check!(received.change_id == change_id);
+        check!(received.change == made);
+    }
+
+    #[actix_rt::test]
+    async fn follow_after_change() {
+        let f = build_fixture();
+        let first = f.repository.record(change(&f.world_id, "gondor"));
+        let second = f.repository.record(change(&f.world_id, "rohan"));
+        let third = f.repository.record(change(&f.world_id, "mordor"));
+
+        let changes = f.sut.follow(&f.world_id, Some(first)).await;
+        let received: Vec<ChangeId> = changes.take(2).map(|c| c.change_id).collect().await;
+
+        check!(received == vec![second, third]);
+    }
+}
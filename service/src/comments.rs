@@ -0,0 +1,11 @@
+pub mod component;
+mod endpoints;
+mod mentions;
+mod model;
+mod repository;
+mod service;
+
+pub use mentions::*;
+pub use model::*;
+pub use repository::*;
+pub use service::*;
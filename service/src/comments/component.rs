@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use actix_web::web::{delete, get, patch, post, resource, ServiceConfig};
+
+use super::{repository::CommentRepository, service::CommentService};
+use crate::{server::RouteConfigurer, taxonomy::TaxonomyService, users::UserService};
+
+/// Component for working with the comments on the content of worlds.
+pub struct Component {
+    pub service: Arc<CommentService>,
+}
+
+impl Component {
+    /// Create a new comments component.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store comments in
+    /// - `users` - The service to look up the users that are mentioned in comments with
+    /// - `taxonomy` - The service to check that the content being commented on exists with
+    pub fn new(repository: Arc<dyn CommentRepository>, users: Arc<UserService>, taxonomy: Arc<TaxonomyService>) -> Arc<Self> {
+        let service = Arc::new(CommentService::new(repository, users, taxonomy));
+
+        Arc::new(Self { service })
+    }
+}
+
+impl RouteConfigurer for Component {
+    fn configure_routes(&self, config: &mut ServiceConfig) {
+        config.data(self.service.clone());
+
+        config.service(
+            resource("/worlds/{id}/discussions/{kind}/{resource}")
+                .route(post().to(super::endpoints::create_thread::handle))
+                .route(get().to(super::endpoints::list_threads::handle)),
+        );
+        config.service(
+            resource("/worlds/{id}/comments/{comment}")
+                .route(get().to(super::endpoints::get_comment::handle))
+                .route(patch().to(super::endpoints::patch_comment::handle))
+                .route(delete().to(super::endpoints::delete_comment::handle)),
+        );
+        config.service(
+            resource("/worlds/{id}/comments/{comment}/replies")
+                .route(post().to(super::endpoints::create_reply::handle))
+                .route(get().to(super::endpoints::list_replies::handle)),
+        );
+        config.service(resource("/worlds/{id}/comments/{comment}/resolve").route(post().to(super::endpoints::resolve_thread::handle)));
+        config.service(resource("/worlds/{id}/comments/{comment}/unresolve").route(post().to(super::endpoints::unresolve_thread::handle)));
+    }
+}
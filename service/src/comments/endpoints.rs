@@ -0,0 +1,96 @@
+pub(super) mod create_reply;
+pub(super) mod create_thread;
+pub(super) mod delete_comment;
+pub(super) mod get_comment;
+pub(super) mod list_replies;
+pub(super) mod list_threads;
+mod model;
+pub(super) mod patch_comment;
+mod problems;
+pub(super) mod resolve_thread;
+pub(super) mod unresolve_thread;
+
+use crate::{
+    comments::{CommentId, UpdateCommentError},
+    http::{
+        pagination::{CursorPagination, CursorQuery},
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+    },
+    taxonomy::Content,
+    worlds::WorldId,
+};
+
+/// Parse the ID of a world from the URL.
+///
+/// # Parameters
+/// - `world_id` - The ID of the world
+///
+/// # Returns
+/// The parsed ID, or a Not Found problem if it isn't valid.
+fn parse_world_id(world_id: &str) -> Result<WorldId, Problem> {
+    world_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, world_id = ?world_id, "Failed to parse World ID");
+
+        NOT_FOUND.into()
+    })
+}
+
+/// Parse the ID of a comment from the URL.
+///
+/// # Parameters
+/// - `comment_id` - The ID of the comment
+///
+/// # Returns
+/// The parsed ID, or a Not Found problem if it isn't valid.
+fn parse_comment_id(comment_id: &str) -> Result<CommentId, Problem> {
+    comment_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, comment_id = ?comment_id, "Failed to parse Comment ID");
+
+        NOT_FOUND.into()
+    })
+}
+
+/// Parse a reference to some content from the URL.
+///
+/// # Parameters
+/// - `kind` - The kind of content
+/// - `resource_id` - The ID of the article or entity
+///
+/// # Returns
+/// The content, or a Not Found problem if the reference isn't valid.
+fn parse_content(kind: &str, resource_id: &str) -> Result<Content, Problem> {
+    Content::parse(kind, resource_id).map_err(|e| {
+        tracing::warn!(e = ?e, kind = ?kind, resource_id = ?resource_id, "Failed to parse content reference");
+
+        NOT_FOUND.into()
+    })
+}
+
+/// Determine the page of comments that was requested.
+///
+/// # Parameters
+/// - `query` - The query parameters for the page
+///
+/// # Returns
+/// The page, or an Invalid Cursor problem if the cursor isn't the ID of a comment.
+fn parse_pagination(query: &CursorQuery) -> Result<CursorPagination<CommentId>, Problem> {
+    query.pagination().map_err(|e| {
+        tracing::warn!(e = ?e, after = ?query.after, "Failed to parse comment cursor");
+
+        problems::INVALID_CURSOR.into()
+    })
+}
+
+/// Convert an error from updating a comment into the problem to return for it.
+///
+/// # Parameters
+/// - `e` - The error
+fn update_problem(e: &UpdateCommentError) -> Problem {
+    match e {
+        UpdateCommentError::UnknownComment => NOT_FOUND.into(),
+        UpdateCommentError::NotAuthor => problems::NOT_AUTHOR.into(),
+        UpdateCommentError::Removed => problems::COMMENT_REMOVED.into(),
+        UpdateCommentError::NotAThread => problems::NOT_A_THREAD.into(),
+        UpdateCommentError::UnknownError => INTERNAL_SERVER_ERROR.into(),
+    }
+}
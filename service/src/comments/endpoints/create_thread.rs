@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use actix_http::http::StatusCode;
+use actix_web::web::{Data, Path};
+
+use super::{
+    model::{respond_comment, CommentRequest, CommentResponse},
+    parse_content, parse_world_id,
+};
+use crate::{
+    authorization::Authentication,
+    comments::{CommentService, CreateCommentError},
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+        valid::Valid,
+    },
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Start a new thread of comments about an article or entity.
+pub async fn handle(
+    service: Data<Arc<CommentService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String, String)>,
+    request: Valid<CommentRequest>,
+    authentication: Authentication,
+) -> Result<CommentResponse, Problem> {
+    let (world_id, kind, resource_id) = path.into_inner();
+    let world_id = parse_world_id(&world_id)?;
+    let content = parse_content(&kind, &resource_id)?;
+
+    authentication
+        .require_world_role(&worlds_service, &world_id, Role::Commenter)
+        .await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+    let user_id = authentication.require_user()?;
+
+    let request = request.unwrap();
+
+    let comment = service
+        .start_thread(&world_id, &user_id, &content, &request.body)
+        .await
+        .map_err(|e| match e {
+            CreateCommentError::UnknownContent | CreateCommentError::UnknownParent => NOT_FOUND,
+            CreateCommentError::UnknownError => INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok(respond_comment(comment).with_status_code(StatusCode::CREATED).into())
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use actix_web::{
+    web::{Data, Path},
+    HttpResponse,
+};
+
+use super::{parse_comment_id, parse_world_id, update_problem};
+use crate::{
+    authorization::Authentication,
+    comments::CommentService,
+    http::problem::Problem,
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Remove a comment. Authors can remove their own comments, and editors can remove anybody's.
+/// The comment keeps its place in its thread, but loses its body.
+pub async fn handle(
+    service: Data<Arc<CommentService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<HttpResponse, Problem> {
+    let (world_id, comment_id) = path.into_inner();
+    let world_id = parse_world_id(&world_id)?;
+    let comment_id = parse_comment_id(&comment_id)?;
+
+    let role = authentication
+        .require_world_role(&worlds_service, &world_id, Role::Commenter)
+        .await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+    let user_id = authentication.require_user()?;
+
+    service
+        .remove_comment(&world_id, &comment_id, &user_id, role.includes(Role::Editor))
+        .await
+        .map_err(|e| update_problem(&e))?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::{
+    model::{respond_comment, CommentResponse},
+    parse_comment_id, parse_world_id,
+};
+use crate::{
+    authorization::Authentication,
+    comments::CommentService,
+    http::problem::{Problem, NOT_FOUND},
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+pub async fn handle(
+    service: Data<Arc<CommentService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<CommentResponse, Problem> {
+    let (world_id, comment_id) = path.into_inner();
+    let world_id = parse_world_id(&world_id)?;
+    let comment_id = parse_comment_id(&comment_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let comment = service.get_comment(&world_id, &comment_id).await.ok_or(NOT_FOUND)?;
+
+    Ok(respond_comment(comment).into())
+}
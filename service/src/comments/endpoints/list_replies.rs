@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path, Query};
+
+use super::{
+    model::{CommentThreadModel, CommentsModel},
+    parse_comment_id, parse_pagination, parse_world_id,
+};
+use crate::{
+    authorization::Authentication,
+    comments::CommentService,
+    http::{
+        pagination::{CursorModel, CursorQuery},
+        problem::{Problem, NOT_FOUND},
+        response::Response,
+    },
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// List the replies to a comment, oldest first.
+pub async fn handle(
+    service: Data<Arc<CommentService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    query: Query<CursorQuery>,
+    authentication: Authentication,
+) -> Result<Response<CommentsModel>, Problem> {
+    let (world_id, comment_id) = path.into_inner();
+    let world_id = parse_world_id(&world_id)?;
+    let comment_id = parse_comment_id(&comment_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let pagination = parse_pagination(&query)?;
+    let page = service.get_replies(&world_id, &comment_id, &pagination).await.ok_or(NOT_FOUND)?;
+
+    Ok(Response(CommentsModel {
+        page:     CursorModel::new(&pagination, page.next.as_ref()),
+        comments: page.items.into_iter().map(CommentThreadModel::from).collect(),
+    }))
+}
@@ -0,0 +1,146 @@
+use actix_web::http::header::{CacheControl, CacheDirective, ETag, EntityTag};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    articles::render_html,
+    comments::{CommentId, CommentResource, CommentThread, Removal, Resolution},
+    http::{
+        pagination::CursorModel,
+        response::{Response, SimpleRespondable},
+        valid::Validatable,
+    },
+    users::UserId,
+};
+
+/// Representation of a comment on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentModel {
+    pub comment_id:  CommentId,
+    pub kind:        String,
+    pub resource_id: String,
+    pub parent_id:   Option<CommentId>,
+    pub author_id:   UserId,
+    /// The body of the comment as Markdown, or `None` if the comment has been removed.
+    pub body:        Option<String>,
+    /// The body of the comment rendered as HTML, or `None` if the comment has been removed.
+    pub html:        Option<String>,
+    pub mentions:    Vec<UserId>,
+    pub resolved:    Option<ResolutionModel>,
+    pub removed:     Option<RemovalModel>,
+    pub created:     DateTime<Utc>,
+    pub updated:     DateTime<Utc>,
+}
+
+/// Representation on the HTTP API of who resolved a thread.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolutionModel {
+    pub resolved_by: UserId,
+    pub resolved_at: DateTime<Utc>,
+}
+
+/// Representation on the HTTP API of who removed a comment.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemovalModel {
+    pub removed_by: UserId,
+    pub removed_at: DateTime<Utc>,
+}
+
+impl From<CommentResource> for CommentModel {
+    fn from(comment: CommentResource) -> Self {
+        let data = comment.data;
+        let (body, html) = if data.removal.is_some() {
+            (None, None)
+        } else {
+            let html = render_html(&data.body, |_| None);
+            (Some(data.body), Some(html))
+        };
+
+        Self {
+            comment_id: comment.identity.id,
+            kind: data.target.kind().to_owned(),
+            resource_id: data.target.to_string(),
+            parent_id: data.parent_id,
+            author_id: data.author_id,
+            body,
+            html,
+            mentions: data.mentions,
+            resolved: data
+                .resolution
+                .map(|Resolution { resolved_by, resolved_at }| ResolutionModel { resolved_by, resolved_at }),
+            removed: data
+                .removal
+                .map(|Removal { removed_by, removed_at }| RemovalModel { removed_by, removed_at }),
+            created: comment.identity.created,
+            updated: comment.identity.updated,
+        }
+    }
+}
+
+pub type CommentResponse = Response<SimpleRespondable<CommentModel>>;
+
+/// Build the response for a single comment.
+///
+/// # Parameters
+/// - `comment` - The comment
+pub fn respond_comment(comment: CommentResource) -> SimpleRespondable<CommentModel> {
+    let etag = EntityTag::strong(comment.identity.version.to_string());
+
+    SimpleRespondable::new(CommentModel::from(comment))
+        .with_header(ETag(etag))
+        .with_header(CacheControl(vec![CacheDirective::Private, CacheDirective::NoCache]))
+}
+
+/// Representation of a comment on the HTTP API, along with how many replies it has.
+#[derive(Serialize)]
+pub struct CommentThreadModel {
+    #[serde(flatten)]
+    pub comment: CommentModel,
+    pub replies: u64,
+}
+
+impl From<CommentThread> for CommentThreadModel {
+    fn from(thread: CommentThread) -> Self {
+        Self {
+            comment: CommentModel::from(thread.comment),
+            replies: thread.replies,
+        }
+    }
+}
+
+/// Representation of a page of comments on the HTTP API.
+#[derive(Serialize)]
+pub struct CommentsModel {
+    pub comments: Vec<CommentThreadModel>,
+    pub page:     CursorModel,
+}
+
+/// The incoming request to write a comment, or to edit one.
+#[derive(Deserialize)]
+pub struct CommentRequest {
+    /// The body of the comment, as Markdown.
+    pub body: String,
+}
+
+impl Validatable for CommentRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "body": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 10000,
+                    "pattern": "\\S"
+                }
+            },
+            "required": [
+                "body"
+            ]
+        })
+    }
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::{
+    model::{respond_comment, CommentRequest, CommentResponse},
+    parse_comment_id, parse_world_id, update_problem,
+};
+use crate::{
+    authorization::Authentication,
+    comments::CommentService,
+    http::{problem::Problem, valid::Valid},
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Edit the body of a comment. Only the author of a comment can edit it.
+pub async fn handle(
+    service: Data<Arc<CommentService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    request: Valid<CommentRequest>,
+    authentication: Authentication,
+) -> Result<CommentResponse, Problem> {
+    let (world_id, comment_id) = path.into_inner();
+    let world_id = parse_world_id(&world_id)?;
+    let comment_id = parse_comment_id(&comment_id)?;
+
+    authentication
+        .require_world_role(&worlds_service, &world_id, Role::Commenter)
+        .await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+    let user_id = authentication.require_user()?;
+
+    let request = request.unwrap();
+
+    let comment = service
+        .edit_comment(&world_id, &comment_id, &user_id, &request.body)
+        .await
+        .map_err(|e| update_problem(&e))?;
+
+    Ok(respond_comment(comment).into())
+}
@@ -0,0 +1,31 @@
+use actix_http::http::StatusCode;
+
+use crate::http::problem::SimpleProblemType;
+
+/// Problem to indicate that the cursor for a page of comments isn't valid.
+pub const INVALID_CURSOR: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/comments/invalid_cursor",
+    problem_title: "Invalid Cursor",
+    status_code:   StatusCode::BAD_REQUEST,
+};
+
+/// Problem to indicate that only the author of a comment can change it.
+pub const NOT_AUTHOR: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/comments/not_author",
+    problem_title: "Only the author of a comment can change it",
+    status_code:   StatusCode::FORBIDDEN,
+};
+
+/// Problem to indicate that a comment has been removed, and so can't be changed.
+pub const COMMENT_REMOVED: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/comments/removed",
+    problem_title: "The comment has been removed",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate that a reply was resolved, rather than the comment that starts its thread.
+pub const NOT_A_THREAD: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/comments/not_a_thread",
+    problem_title: "Only the comment that starts a thread can be resolved",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::{
+    model::{respond_comment, CommentResponse},
+    parse_comment_id, parse_world_id, update_problem,
+};
+use crate::{
+    authorization::Authentication,
+    comments::CommentService,
+    http::problem::Problem,
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Mark a thread of comments as resolved.
+pub async fn handle(
+    service: Data<Arc<CommentService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<CommentResponse, Problem> {
+    let (world_id, comment_id) = path.into_inner();
+    let world_id = parse_world_id(&world_id)?;
+    let comment_id = parse_comment_id(&comment_id)?;
+
+    authentication
+        .require_world_role(&worlds_service, &world_id, Role::Commenter)
+        .await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+    let user_id = authentication.require_user()?;
+
+    let comment = service
+        .set_resolved(&world_id, &comment_id, &user_id, true)
+        .await
+        .map_err(|e| update_problem(&e))?;
+
+    Ok(respond_comment(comment).into())
+}
@@ -0,0 +1,97 @@
+use pulldown_cmark::{Event, Parser, Tag};
+
+/// Find all of the usernames that are mentioned in some Markdown, written as `@username`.
+/// Anything that appears inside code is not treated as a mention, and neither is an `@` within a
+/// word, such as in an email address.
+///
+/// # Parameters
+/// - `markdown` - The Markdown to search
+///
+/// # Returns
+/// The usernames that are mentioned, without the `@`, in the order that they first appear.
+pub fn mentioned_usernames(markdown: &str) -> Vec<String> {
+    let mut result: Vec<String> = vec![];
+    let mut code = 0_usize;
+    let mut previous: Option<char> = None;
+
+    for event in Parser::new(markdown) {
+        match event {
+            Event::Start(Tag::CodeBlock(_)) => code += 1,
+            Event::End(Tag::CodeBlock(_)) => code -= 1,
+            Event::Text(text) if code == 0 => {
+                for username in find_mentions(&text, previous) {
+                    if !result.contains(&username) {
+                        result.push(username);
+                    }
+                }
+                previous = text.chars().last();
+            },
+            _ => previous = None,
+        }
+    }
+
+    result
+}
+
+/// Check if the given character can appear within a mentioned username.
+fn is_username_character(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '.' || c == '-'
+}
+
+/// Find the mentions in a single block of text.
+///
+/// # Parameters
+/// - `text` - The text to search
+/// - `previous` - The character immediately before the text, if it continues on from some more text
+fn find_mentions(text: &str, previous: Option<char>) -> Vec<String> {
+    let mut result = vec![];
+    let mut previous = previous;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        if c == '@' && !previous.is_some_and(is_username_character) {
+            let start = index + c.len_utf8();
+            let mut end = start;
+            while let Some((i, c)) = chars.peek().copied() {
+                if !is_username_character(c) {
+                    break;
+                }
+                end = i + c.len_utf8();
+                chars.next();
+            }
+
+            // Punctuation at the end of a sentence isn't part of the username.
+            let username = text[start..end].trim_end_matches(['.', '-']);
+            if !username.is_empty() {
+                result.push(username.to_owned());
+            }
+
+            previous = text[..end].chars().last();
+        } else {
+            previous = Some(c);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("No mentions here", &[] ; "No mentions")]
+    #[test_case("Thoughts, @frodo?", &["frodo"] ; "Simple")]
+    #[test_case("@frodo and @sam.gamgee.", &["frodo", "sam.gamgee"] ; "Trailing punctuation")]
+    #[test_case("@frodo, @frodo and @sam", &["frodo", "sam"] ; "Repeated")]
+    #[test_case("Email frodo@shire.me", &[] ; "Email address")]
+    #[test_case("Ask *@gandalf*", &["gandalf"] ; "Emphasis")]
+    #[test_case("Use `@frodo` to mention", &[] ; "Inline code")]
+    #[test_case("```\n@frodo\n```", &[] ; "Code block")]
+    #[test_case("Just an @ sign", &[] ; "Bare at sign")]
+    fn test_mentioned_usernames(input: &str, expected: &[&str]) {
+        check!(mentioned_usernames(input) == expected);
+    }
+}
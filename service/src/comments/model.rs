@@ -0,0 +1,61 @@
+mod comment_id;
+
+use chrono::{DateTime, Utc};
+pub use comment_id::*;
+
+use crate::{model::Resource, taxonomy::Content, users::UserId, worlds::WorldId};
+
+/// The data representing a comment on some content within a world.
+#[derive(Debug, Clone)]
+pub struct CommentData {
+    pub world_id:   WorldId,
+    /// The content that the comment is about.
+    pub target:     Content,
+    /// The comment that this one is a reply to, or `None` if it starts a new thread.
+    pub parent_id:  Option<CommentId>,
+    pub author_id:  UserId,
+    /// The body of the comment, as Markdown.
+    pub body:       String,
+    /// The users that are mentioned in the body of the comment.
+    pub mentions:   Vec<UserId>,
+    /// Details of who resolved the thread, if it has been resolved.
+    pub resolution: Option<Resolution>,
+    /// Details of who removed the comment, if it has been removed.
+    pub removal:    Option<Removal>,
+}
+
+/// Type representing a persisted comment.
+pub type CommentResource = Resource<CommentId, CommentData>;
+
+/// Details of who resolved a thread of comments, and when.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Resolution {
+    pub resolved_by: UserId,
+    pub resolved_at: DateTime<Utc>,
+}
+
+/// Details of who removed a comment, and when.
+///
+/// Removed comments keep their place in the thread so that the replies to them still make sense,
+/// but lose their body.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Removal {
+    pub removed_by: UserId,
+    pub removed_at: DateTime<Utc>,
+}
+
+/// What a list of comments belongs to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommentParent {
+    /// The comments that start threads about some content.
+    Content(Content),
+    /// The replies to a comment.
+    Comment(CommentId),
+}
+
+/// A comment, along with how many replies it has.
+#[derive(Debug, Clone)]
+pub struct CommentThread {
+    pub comment: CommentResource,
+    pub replies: u64,
+}
@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod memory;
+mod parse;
+mod postgres;
+mod save_comment;
+
+#[cfg(test)]
+pub use memory::MemoryCommentRepository;
+pub use postgres::PostgresCommentRepository;
+pub use save_comment::SaveCommentError;
+
+use super::{CommentData, CommentId, CommentParent, CommentResource, CommentThread};
+use crate::worlds::WorldId;
+
+/// Repository of the comments on the content of worlds.
+#[async_trait::async_trait]
+pub trait CommentRepository: Send + Sync {
+    /// Create a new comment.
+    ///
+    /// # Parameters
+    /// - `comment` - The details of the comment to create.
+    ///
+    /// # Returns
+    /// The created comment.
+    async fn create_comment(&self, comment: &CommentData) -> Result<CommentResource, SaveCommentError>;
+
+    /// Get a single comment.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the comment belongs to.
+    /// - `comment_id` - The ID of the comment.
+    ///
+    /// # Returns
+    /// The comment, or `None` if it couldn't be found.
+    async fn get_comment(&self, world_id: &WorldId, comment_id: &CommentId) -> Option<CommentResource>;
+
+    /// Get a page of the comments that belong to something, oldest first.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the comments belong to.
+    /// - `parent` - What the comments belong to.
+    /// - `after` - The ID of the comment that the page starts after, if any.
+    /// - `limit` - The most comments to return.
+    ///
+    /// # Returns
+    /// The comments, each with the number of replies that it has.
+    async fn get_comments(&self, world_id: &WorldId, parent: &CommentParent, after: Option<&CommentId>, limit: u32) -> Vec<CommentThread>;
+
+    /// Update an existing comment.
+    ///
+    /// # Parameters
+    /// - `comment_id` - The ID of the comment.
+    /// - `comment` - The new details of the comment.
+    ///
+    /// # Returns
+    /// The updated comment.
+    async fn update_comment(&self, comment_id: &CommentId, comment: &CommentData) -> Result<CommentResource, SaveCommentError>;
+}
@@ -0,0 +1,104 @@
+use std::{convert::TryFrom, sync::Mutex};
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::{CommentRepository, SaveCommentError};
+use crate::{
+    comments::{CommentData, CommentId, CommentParent, CommentResource, CommentThread},
+    model::Identity,
+    worlds::WorldId,
+};
+
+/// Repository of comments that are stored in memory.
+#[derive(Default)]
+pub struct MemoryCommentRepository {
+    /// The comments, in the order that they were created.
+    comments: Mutex<Vec<CommentResource>>,
+}
+
+impl MemoryCommentRepository {
+    /// Create a new, empty comment repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl CommentRepository for MemoryCommentRepository {
+    async fn create_comment(&self, comment: &CommentData) -> Result<CommentResource, SaveCommentError> {
+        let mut comments = self.comments.lock().unwrap();
+        if let Some(parent_id) = &comment.parent_id {
+            if !comments.iter().any(|c| &c.identity.id == parent_id) {
+                return Err(SaveCommentError::UnknownReference);
+            }
+        }
+
+        let created = CommentResource {
+            identity: Identity::default(),
+            data:     comment.clone(),
+        };
+        comments.push(created.clone());
+
+        Ok(created)
+    }
+
+    async fn get_comment(&self, world_id: &WorldId, comment_id: &CommentId) -> Option<CommentResource> {
+        let comments = self.comments.lock().unwrap();
+
+        comments
+            .iter()
+            .find(|c| &c.data.world_id == world_id && &c.identity.id == comment_id)
+            .cloned()
+    }
+
+    async fn get_comments(&self, world_id: &WorldId, parent: &CommentParent, after: Option<&CommentId>, limit: u32) -> Vec<CommentThread> {
+        let comments = self.comments.lock().unwrap();
+
+        let matching = comments.iter().filter(|c| {
+            &c.data.world_id == world_id
+                && match parent {
+                    CommentParent::Content(content) => c.data.parent_id.is_none() && &c.data.target == content,
+                    CommentParent::Comment(comment_id) => c.data.parent_id.as_ref() == Some(comment_id),
+                }
+        });
+
+        let start = match after {
+            Some(after) => match matching.clone().position(|c| &c.identity.id == after) {
+                Some(index) => index + 1,
+                None => return vec![],
+            },
+            None => 0,
+        };
+
+        matching
+            .skip(start)
+            .take(usize::try_from(limit).unwrap_or(usize::MAX))
+            .map(|c| CommentThread {
+                comment: c.clone(),
+                replies: u64::try_from(
+                    comments
+                        .iter()
+                        .filter(|r| r.data.parent_id.as_ref() == Some(&c.identity.id))
+                        .count(),
+                )
+                .unwrap_or_default(),
+            })
+            .collect()
+    }
+
+    async fn update_comment(&self, comment_id: &CommentId, comment: &CommentData) -> Result<CommentResource, SaveCommentError> {
+        let mut comments = self.comments.lock().unwrap();
+
+        let existing = comments
+            .iter_mut()
+            .find(|c| &c.identity.id == comment_id && c.data.world_id == comment.world_id)
+            .ok_or(SaveCommentError::UnknownComment)?;
+
+        existing.identity.version = Uuid::new_v4();
+        existing.identity.updated = Utc::now();
+        existing.data = comment.clone();
+
+        Ok(existing.clone())
+    }
+}
@@ -0,0 +1,57 @@
+use chrono::{DateTime, Utc};
+use tokio_postgres::Row;
+
+use crate::{
+    comments::{CommentData, CommentResource, CommentThread, Removal, Resolution},
+    model::Identity,
+    taxonomy::parse_content,
+    users::UserId,
+};
+
+impl From<Row> for CommentResource {
+    fn from(row: Row) -> Self {
+        parse_comment(&row)
+    }
+}
+
+impl From<Row> for CommentThread {
+    fn from(row: Row) -> Self {
+        let replies: i64 = row.get("replies");
+
+        CommentThread {
+            comment: parse_comment(&row),
+            replies: replies.unsigned_abs(),
+        }
+    }
+}
+
+/// Interpret a row that holds a comment.
+fn parse_comment(row: &Row) -> CommentResource {
+    let resolved_by: Option<UserId> = row.get("resolved_by");
+    let resolved_at: Option<DateTime<Utc>> = row.get("resolved_at");
+    let removed_by: Option<UserId> = row.get("removed_by");
+    let removed_at: Option<DateTime<Utc>> = row.get("removed_at");
+
+    CommentResource {
+        identity: Identity {
+            id:      row.get("comment_id"),
+            version: row.get("version"),
+            created: row.get("created"),
+            updated: row.get("updated"),
+        },
+        data:     CommentData {
+            world_id:   row.get("world_id"),
+            target:     parse_content(row).expect("Comments always refer to some content"),
+            parent_id:  row.get("parent_id"),
+            author_id:  row.get("author_id"),
+            body:       row.get("body"),
+            mentions:   row.get("mentions"),
+            resolution: resolved_by
+                .zip(resolved_at)
+                .map(|(resolved_by, resolved_at)| Resolution { resolved_by, resolved_at }),
+            removal:    removed_by
+                .zip(removed_at)
+                .map(|(removed_by, removed_at)| Removal { removed_by, removed_at }),
+        },
+    }
+}
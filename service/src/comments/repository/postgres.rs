@@ -0,0 +1,156 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::{CommentRepository, SaveCommentError};
+use crate::{
+    comments::{CommentData, CommentId, CommentParent, CommentResource, CommentThread},
+    database::Database,
+    model::Identity,
+    taxonomy::content_columns,
+    worlds::WorldId,
+};
+
+/// Select a page of comments along with the number of replies to each, with `$1` as the world ID,
+/// `$2` as the ID of the comment that the page starts after and `$3` as the size of the page. The
+/// comments to include are filtered by the `{filter}` placeholder.
+const PAGE_OF_COMMENTS: &str = "SELECT c.*, (SELECT COUNT(*) FROM comments r WHERE r.parent_id = c.comment_id) AS replies
+    FROM comments c
+    WHERE c.world_id = $1
+        AND {filter}
+        AND ($2::UUID IS NULL OR (c.created, c.comment_id) > (SELECT a.created, a.comment_id FROM comments a WHERE a.comment_id = $2))
+    ORDER BY c.created, c.comment_id
+    LIMIT $3";
+
+/// Repository of comments that are stored in Postgres.
+pub struct PostgresCommentRepository {
+    database: Arc<Database>,
+}
+
+impl PostgresCommentRepository {
+    /// Create a new comment repository.
+    pub fn new(database: Arc<Database>) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommentRepository for PostgresCommentRepository {
+    #[tracing::instrument(skip(self))]
+    async fn create_comment(&self, comment: &CommentData) -> Result<CommentResource, SaveCommentError> {
+        let conn = self.database.connect().await;
+
+        let identity = Identity::<CommentId>::default();
+        let (article_id, entity_id) = content_columns(&comment.target);
+
+        let created: CommentResource = conn
+            .query_one(
+                "INSERT INTO comments(comment_id, version, created, updated, world_id, kind, article_id, entity_id, parent_id, author_id, body, mentions)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+                RETURNING *",
+                &[
+                    &identity.id,
+                    &identity.version,
+                    &identity.created,
+                    &identity.updated,
+                    &comment.world_id,
+                    &comment.target.kind(),
+                    &article_id,
+                    &entity_id,
+                    &comment.parent_id,
+                    &comment.author_id,
+                    &comment.body,
+                    &comment.mentions,
+                ],
+            )
+            .await
+            .map(CommentResource::from)?;
+
+        Ok(created)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_comment(&self, world_id: &WorldId, comment_id: &CommentId) -> Option<CommentResource> {
+        let conn = self.database.connect().await;
+        conn.query_opt(
+            "SELECT * FROM comments WHERE world_id = $1 AND comment_id = $2",
+            &[&world_id, &comment_id],
+        )
+        .await
+        .map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to load comment");
+        })
+        .ok()
+        .flatten()
+        .map(CommentResource::from)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_comments(&self, world_id: &WorldId, parent: &CommentParent, after: Option<&CommentId>, limit: u32) -> Vec<CommentThread> {
+        let conn = self.database.connect().await;
+        let limit = i64::from(limit);
+
+        let rows = match parent {
+            CommentParent::Content(content) => {
+                let (article_id, entity_id) = content_columns(content);
+
+                conn.query(
+                    PAGE_OF_COMMENTS.replace(
+                        "{filter}",
+                        "c.parent_id IS NULL AND c.kind = $4 AND (c.article_id = $5 OR c.entity_id = $6)",
+                    ),
+                    &[&world_id, &after, &limit, &content.kind(), &article_id, &entity_id],
+                )
+                .await
+            },
+            CommentParent::Comment(comment_id) => {
+                conn.query(
+                    PAGE_OF_COMMENTS.replace("{filter}", "c.parent_id = $4"),
+                    &[&world_id, &after, &limit, &comment_id],
+                )
+                .await
+            },
+        };
+
+        rows.map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to load comments");
+                vec![]
+            },
+            |rows| rows.into_iter().map(CommentThread::from).collect(),
+        )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn update_comment(&self, comment_id: &CommentId, comment: &CommentData) -> Result<CommentResource, SaveCommentError> {
+        let conn = self.database.connect().await;
+
+        let version = Uuid::new_v4();
+        let updated = Utc::now();
+        let resolution = comment.resolution.as_ref();
+        let removal = comment.removal.as_ref();
+
+        conn.query_opt(
+            "UPDATE comments
+            SET version = $3, updated = $4, body = $5, mentions = $6, resolved_by = $7, resolved_at = $8, removed_by = $9, removed_at = $10
+            WHERE comment_id = $1 AND world_id = $2
+            RETURNING *",
+            &[
+                &comment_id,
+                &comment.world_id,
+                &version,
+                &updated,
+                &comment.body,
+                &comment.mentions,
+                &resolution.map(|r| &r.resolved_by),
+                &resolution.map(|r| r.resolved_at),
+                &removal.map(|r| &r.removed_by),
+                &removal.map(|r| r.removed_at),
+            ],
+        )
+        .await?
+        .map(CommentResource::from)
+        .ok_or(SaveCommentError::UnknownComment)
+    }
+}
@@ -0,0 +1,27 @@
+use tokio_postgres::error::SqlState;
+
+/// Errors that can occur when saving a comment.
+#[derive(Debug, PartialEq, thiserror::Error)]
+#[allow(clippy::enum_variant_names)] // Matches the naming of the errors from the other repositories.
+pub enum SaveCommentError {
+    #[error("The comment was not found")]
+    UnknownComment,
+
+    #[error("Something that the comment refers to was not found")]
+    UnknownReference,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl From<tokio_postgres::Error> for SaveCommentError {
+    fn from(e: tokio_postgres::Error) -> Self {
+        if e.code() == Some(&SqlState::FOREIGN_KEY_VIOLATION) {
+            SaveCommentError::UnknownReference
+        } else {
+            tracing::warn!("Unexpected database error: {:?}", e);
+
+            SaveCommentError::UnknownError
+        }
+    }
+}
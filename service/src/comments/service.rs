@@ -0,0 +1,157 @@
+mod create;
+mod list;
+mod update;
+
+use std::sync::Arc;
+
+pub use create::CreateCommentError;
+pub use update::UpdateCommentError;
+
+use super::{mentioned_usernames, repository::CommentRepository};
+use crate::{
+    taxonomy::TaxonomyService,
+    users::{UserId, UserService, Username},
+};
+
+/// The most users that can be mentioned in a single comment.
+const MAX_MENTIONS: usize = 20;
+
+/// Service layer for working with the comments on the content of worlds.
+pub struct CommentService {
+    repository: Arc<dyn CommentRepository>,
+    users:      Arc<UserService>,
+    taxonomy:   Arc<TaxonomyService>,
+}
+
+impl CommentService {
+    /// Create a new comment service.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store comments in
+    /// - `users` - The service to look up the users that are mentioned in comments with
+    /// - `taxonomy` - The service to check that the content being commented on exists with
+    pub fn new(repository: Arc<dyn CommentRepository>, users: Arc<UserService>, taxonomy: Arc<TaxonomyService>) -> Self {
+        Self {
+            repository,
+            users,
+            taxonomy,
+        }
+    }
+
+    /// Find the users that are mentioned in the body of a comment.
+    ///
+    /// # Parameters
+    /// - `body` - The body of the comment, as Markdown
+    ///
+    /// # Returns
+    /// The IDs of the users that are mentioned. Mentions of usernames that don't belong to
+    /// anybody are ignored.
+    async fn resolve_mentions(&self, body: &str) -> Vec<UserId> {
+        let mut result = vec![];
+
+        for username in mentioned_usernames(body).into_iter().take(MAX_MENTIONS) {
+            let username: Username = match username.parse() {
+                Ok(username) => username,
+                Err(_) => continue,
+            };
+
+            if let Some(user) = self.users.get_user_by_username(&username).await {
+                if !result.contains(&user.identity.id) {
+                    result.push(user.identity.id);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        articles::{ArticleService, MemoryArticleRepository, NewArticle},
+        comments::MemoryCommentRepository,
+        entities::{EntityService, MemoryEntityRepository},
+        revisions::{MemoryRevisionRepository, RevisionService},
+        search::{MemorySearchRepository, SearchService},
+        taxonomy::{Content, MemoryTaxonomyRepository},
+        users::{MemoryUserRepository, NewUser, PasswordHasher, PasswordPolicy},
+        worlds::WorldId,
+    };
+
+    /// Fixture for testing the comment service, with an article to comment on and some users to
+    /// comment as.
+    pub struct Fixture {
+        pub sut:      CommentService,
+        pub world_id: WorldId,
+        pub article:  Content,
+        pub frodo:    UserId,
+        pub sam:      UserId,
+    }
+
+    pub async fn build_fixture() -> Fixture {
+        let revisions = Arc::new(RevisionService::new(Arc::new(MemoryRevisionRepository::new())));
+        let search = Arc::new(SearchService::new(Arc::new(MemorySearchRepository::new())));
+        let users = Arc::new(UserService::new(
+            Arc::new(MemoryUserRepository::new()),
+            revisions.clone(),
+            Arc::new(PasswordPolicy::new(8, 3)),
+            Arc::new(PasswordHasher::new(64, 1, 1)),
+        ));
+        let articles = Arc::new(ArticleService::new(
+            Arc::new(MemoryArticleRepository::new()),
+            revisions.clone(),
+            search.clone(),
+        ));
+        let entities = Arc::new(EntityService::new(
+            Arc::new(MemoryEntityRepository::new()),
+            revisions,
+            search.clone(),
+        ));
+        let taxonomy = Arc::new(TaxonomyService::new(
+            Arc::new(MemoryTaxonomyRepository::new()),
+            articles.clone(),
+            entities,
+            search,
+        ));
+        let world_id = WorldId::default();
+
+        let mut user_ids = vec![];
+        for username in &["frodo", "sam"] {
+            let user = users
+                .create_user(NewUser {
+                    username:     username.parse().unwrap(),
+                    email:        format!("{}@example.com", username).parse().unwrap(),
+                    display_name: (*username).to_owned(),
+                    password:     "Hedgehog-Lantern-47".to_owned(),
+                })
+                .await
+                .unwrap();
+            user_ids.push(user.identity.id);
+        }
+
+        let article = articles
+            .create_article(
+                &world_id,
+                &user_ids[0],
+                NewArticle {
+                    title:    "The Fellowship".to_owned(),
+                    body:     "Nine walkers".to_owned(),
+                    category: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        Fixture {
+            sut: CommentService::new(Arc::new(MemoryCommentRepository::new()), users, taxonomy),
+            world_id,
+            article: Content::Article(article.identity.id),
+            sam: user_ids.pop().unwrap(),
+            frodo: user_ids.pop().unwrap(),
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use super::CommentService;
+use crate::{
+    comments::{CommentData, CommentId, CommentResource, SaveCommentError},
+    taxonomy::Content,
+    users::UserId,
+    worlds::WorldId,
+};
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+#[allow(clippy::enum_variant_names)] // Matches the naming of the errors from the other services.
+pub enum CreateCommentError {
+    #[error("Unknown content")]
+    UnknownContent,
+
+    #[error("Unknown parent comment")]
+    UnknownParent,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl CommentService {
+    /// Start a new thread of comments about some content.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the content belongs to
+    /// - `author_id` - The ID of the user that is writing the comment
+    /// - `target` - The content that the comment is about
+    /// - `body` - The body of the comment, as Markdown
+    ///
+    /// # Returns
+    /// The newly created comment.
+    pub async fn start_thread(
+        &self,
+        world_id: &WorldId,
+        author_id: &UserId,
+        target: &Content,
+        body: &str,
+    ) -> Result<CommentResource, CreateCommentError> {
+        if !self.taxonomy.content_exists(world_id, target).await {
+            return Err(CreateCommentError::UnknownContent);
+        }
+
+        self.create(world_id, author_id, target.clone(), None, body).await
+    }
+
+    /// Reply to an existing comment.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the comment belongs to
+    /// - `author_id` - The ID of the user that is writing the reply
+    /// - `parent_id` - The ID of the comment to reply to
+    /// - `body` - The body of the reply, as Markdown
+    ///
+    /// # Returns
+    /// The newly created reply.
+    pub async fn reply(
+        &self,
+        world_id: &WorldId,
+        author_id: &UserId,
+        parent_id: &CommentId,
+        body: &str,
+    ) -> Result<CommentResource, CreateCommentError> {
+        let parent = self
+            .repository
+            .get_comment(world_id, parent_id)
+            .await
+            .ok_or(CreateCommentError::UnknownParent)?;
+
+        self.create(world_id, author_id, parent.data.target, Some(parent.identity.id), body)
+            .await
+    }
+
+    /// Create a new comment.
+    async fn create(
+        &self,
+        world_id: &WorldId,
+        author_id: &UserId,
+        target: Content,
+        parent_id: Option<CommentId>,
+        body: &str,
+    ) -> Result<CommentResource, CreateCommentError> {
+        let mentions = self.resolve_mentions(body).await;
+
+        let comment = self
+            .repository
+            .create_comment(&CommentData {
+                world_id: world_id.clone(),
+                target,
+                parent_id,
+                author_id: author_id.clone(),
+                body: body.to_owned(),
+                mentions,
+                resolution: None,
+                removal: None,
+            })
+            .await?;
+
+        Ok(comment)
+    }
+}
+
+impl From<SaveCommentError> for CreateCommentError {
+    fn from(e: SaveCommentError) -> Self {
+        match e {
+            SaveCommentError::UnknownReference => Self::UnknownContent,
+            SaveCommentError::UnknownComment | SaveCommentError::UnknownError => Self::UnknownError,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::comments::service::tests::build_fixture;
+
+    #[actix_rt::test]
+    async fn start_thread() {
+        let f = build_fixture().await;
+
+        let_assert!(
+            Ok(comment) = f
+                .sut
+                .start_thread(&f.world_id, &f.frodo, &f.article, "What do you think, @sam and @Gandalf?")
+                .await
+        );
+        check!(comment.data.target == f.article);
+        check!(comment.data.parent_id.is_none());
+        check!(comment.data.author_id == f.frodo);
+        check!(comment.data.mentions == vec![f.sam.clone()]);
+
+        let_assert!(Ok(reply) = f.sut.reply(&f.world_id, &f.sam, &comment.identity.id, "Looks good to me").await);
+        check!(reply.data.target == f.article);
+        check!(reply.data.parent_id == Some(comment.identity.id));
+        check!(reply.data.mentions.is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn comment_on_unknown() {
+        let f = build_fixture().await;
+
+        let missing = Content::Article(crate::articles::ArticleId::default());
+        let_assert!(Err(e) = f.sut.start_thread(&f.world_id, &f.frodo, &missing, "Hello").await);
+        check!(e == CreateCommentError::UnknownContent);
+
+        let_assert!(Err(e) = f.sut.start_thread(&WorldId::default(), &f.frodo, &f.article, "Hello").await);
+        check!(e == CreateCommentError::UnknownContent);
+
+        let_assert!(Err(e) = f.sut.reply(&f.world_id, &f.frodo, &CommentId::default(), "Hello").await);
+        check!(e == CreateCommentError::UnknownParent);
+    }
+}
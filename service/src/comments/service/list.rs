@@ -0,0 +1,148 @@
+use super::CommentService;
+use crate::{
+    comments::{CommentId, CommentParent, CommentResource, CommentThread},
+    http::pagination::{CursorPage, CursorPagination},
+    taxonomy::Content,
+    worlds::WorldId,
+};
+
+impl CommentService {
+    /// Get a single comment.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the comment belongs to
+    /// - `comment_id` - The ID of the comment
+    ///
+    /// # Returns
+    /// The comment, or `None` if it doesn't exist.
+    pub async fn get_comment(&self, world_id: &WorldId, comment_id: &CommentId) -> Option<CommentResource> {
+        self.repository.get_comment(world_id, comment_id).await
+    }
+
+    /// Get a page of the threads of comments about some content, oldest first.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the content belongs to
+    /// - `target` - The content
+    /// - `pagination` - Which page of threads to return
+    ///
+    /// # Returns
+    /// The comment that starts each thread, or `None` if the content doesn't exist.
+    pub async fn get_threads(
+        &self,
+        world_id: &WorldId,
+        target: &Content,
+        pagination: &CursorPagination<CommentId>,
+    ) -> Option<CursorPage<CommentThread, CommentId>> {
+        if !self.taxonomy.content_exists(world_id, target).await {
+            return None;
+        }
+
+        Some(self.get_page(world_id, &CommentParent::Content(target.clone()), pagination).await)
+    }
+
+    /// Get a page of the replies to a comment, oldest first.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the comment belongs to
+    /// - `comment_id` - The ID of the comment
+    /// - `pagination` - Which page of replies to return
+    ///
+    /// # Returns
+    /// The replies, or `None` if the comment doesn't exist.
+    pub async fn get_replies(
+        &self,
+        world_id: &WorldId,
+        comment_id: &CommentId,
+        pagination: &CursorPagination<CommentId>,
+    ) -> Option<CursorPage<CommentThread, CommentId>> {
+        let comment = self.repository.get_comment(world_id, comment_id).await?;
+
+        Some(
+            self.get_page(world_id, &CommentParent::Comment(comment.identity.id), pagination)
+                .await,
+        )
+    }
+
+    /// Get a page of the comments that belong to something.
+    async fn get_page(
+        &self,
+        world_id: &WorldId,
+        parent: &CommentParent,
+        pagination: &CursorPagination<CommentId>,
+    ) -> CursorPage<CommentThread, CommentId> {
+        let comments = self
+            .repository
+            .get_comments(world_id, parent, pagination.after.as_ref(), pagination.fetch_limit())
+            .await;
+
+        pagination.page(comments, |c| c.comment.identity.id.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::comments::service::tests::build_fixture;
+
+    #[actix_rt::test]
+    async fn page_through_threads() {
+        let f = build_fixture().await;
+        for body in &["One", "Two", "Three"] {
+            f.sut.start_thread(&f.world_id, &f.frodo, &f.article, body).await.unwrap();
+        }
+        let first = f
+            .sut
+            .get_threads(&f.world_id, &f.article, &CursorPagination::default())
+            .await
+            .unwrap();
+        f.sut
+            .reply(&f.world_id, &f.sam, &first.items[0].comment.identity.id, "Reply")
+            .await
+            .unwrap();
+
+        let pagination = CursorPagination { after: None, limit: 2 };
+        let_assert!(Some(page) = f.sut.get_threads(&f.world_id, &f.article, &pagination).await);
+        let bodies: Vec<&str> = page.items.iter().map(|c| c.comment.data.body.as_str()).collect();
+        check!(bodies == vec!["One", "Two"]);
+        check!(page.items[0].replies == 1);
+        let_assert!(Some(next) = page.next);
+
+        let pagination = CursorPagination {
+            after: Some(next),
+            limit: 2,
+        };
+        let_assert!(Some(page) = f.sut.get_threads(&f.world_id, &f.article, &pagination).await);
+        let bodies: Vec<&str> = page.items.iter().map(|c| c.comment.data.body.as_str()).collect();
+        check!(bodies == vec!["Three"]);
+        check!(page.next.is_none());
+
+        let_assert!(
+            Some(replies) = f
+                .sut
+                .get_replies(&f.world_id, &first.items[0].comment.identity.id, &CursorPagination::default())
+                .await
+        );
+        check!(replies.items.len() == 1);
+        check!(replies.items[0].comment.data.body == "Reply");
+    }
+
+    #[actix_rt::test]
+    async fn list_unknown() {
+        let f = build_fixture().await;
+
+        let missing = Content::Article(crate::articles::ArticleId::default());
+        check!(f
+            .sut
+            .get_threads(&f.world_id, &missing, &CursorPagination::default())
+            .await
+            .is_none());
+        check!(f
+            .sut
+            .get_replies(&f.world_id, &CommentId::default(), &CursorPagination::default())
+            .await
+            .is_none());
+    }
+}
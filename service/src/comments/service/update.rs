@@ -0,0 +1,249 @@
+use chrono::Utc;
+
+use super::CommentService;
+use crate::{
+    comments::{CommentData, CommentId, CommentResource, Removal, Resolution, SaveCommentError},
+    users::UserId,
+    worlds::WorldId,
+};
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum UpdateCommentError {
+    #[error("Unknown comment")]
+    UnknownComment,
+
+    #[error("Only the author of a comment can do that")]
+    NotAuthor,
+
+    #[error("The comment has been removed")]
+    Removed,
+
+    #[error("Only the comment that starts a thread can be resolved")]
+    NotAThread,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl CommentService {
+    /// Edit the body of a comment. Only the author of a comment can edit it.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the comment belongs to
+    /// - `comment_id` - The ID of the comment
+    /// - `user_id` - The ID of the user that is editing the comment
+    /// - `body` - The new body of the comment, as Markdown
+    ///
+    /// # Returns
+    /// The edited comment.
+    pub async fn edit_comment(
+        &self,
+        world_id: &WorldId,
+        comment_id: &CommentId,
+        user_id: &UserId,
+        body: &str,
+    ) -> Result<CommentResource, UpdateCommentError> {
+        let comment = self.load_for_update(world_id, comment_id).await?;
+        if &comment.data.author_id != user_id {
+            return Err(UpdateCommentError::NotAuthor);
+        }
+
+        let mentions = self.resolve_mentions(body).await;
+
+        self.save(
+            comment_id,
+            CommentData {
+                body: body.to_owned(),
+                mentions,
+                ..comment.data
+            },
+        )
+        .await
+    }
+
+    /// Remove a comment, leaving a placeholder in its thread so that the replies to it still make
+    /// sense. Authors can remove their own comments, and moderators can remove anybody's.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the comment belongs to
+    /// - `comment_id` - The ID of the comment
+    /// - `user_id` - The ID of the user that is removing the comment
+    /// - `moderator` - Whether the user is allowed to moderate the comments in the world
+    ///
+    /// # Returns
+    /// The removed comment.
+    pub async fn remove_comment(
+        &self,
+        world_id: &WorldId,
+        comment_id: &CommentId,
+        user_id: &UserId,
+        moderator: bool,
+    ) -> Result<CommentResource, UpdateCommentError> {
+        let comment = self.load_for_update(world_id, comment_id).await?;
+        if &comment.data.author_id != user_id && !moderator {
+            return Err(UpdateCommentError::NotAuthor);
+        }
+
+        self.save(
+            comment_id,
+            CommentData {
+                body: String::new(),
+                mentions: vec![],
+                removal: Some(Removal {
+                    removed_by: user_id.clone(),
+                    removed_at: Utc::now(),
+                }),
+                ..comment.data
+            },
+        )
+        .await
+    }
+
+    /// Mark a thread of comments as resolved or not. Resolving a thread that is already resolved
+    /// keeps the original resolution.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the thread belongs to
+    /// - `comment_id` - The ID of the comment that starts the thread
+    /// - `user_id` - The ID of the user that is resolving the thread
+    /// - `resolved` - Whether the thread should be resolved
+    ///
+    /// # Returns
+    /// The comment that starts the thread.
+    pub async fn set_resolved(
+        &self,
+        world_id: &WorldId,
+        comment_id: &CommentId,
+        user_id: &UserId,
+        resolved: bool,
+    ) -> Result<CommentResource, UpdateCommentError> {
+        let comment = self
+            .repository
+            .get_comment(world_id, comment_id)
+            .await
+            .ok_or(UpdateCommentError::UnknownComment)?;
+        if comment.data.parent_id.is_some() {
+            return Err(UpdateCommentError::NotAThread);
+        }
+
+        let resolution = match (resolved, comment.data.resolution.clone()) {
+            (true, Some(resolution)) => Some(resolution),
+            (true, None) => Some(Resolution {
+                resolved_by: user_id.clone(),
+                resolved_at: Utc::now(),
+            }),
+            (false, _) => None,
+        };
+
+        self.save(
+            comment_id,
+            CommentData {
+                resolution,
+                ..comment.data
+            },
+        )
+        .await
+    }
+
+    /// Load a comment that is about to be changed, making sure that it hasn't been removed.
+    async fn load_for_update(&self, world_id: &WorldId, comment_id: &CommentId) -> Result<CommentResource, UpdateCommentError> {
+        let comment = self
+            .repository
+            .get_comment(world_id, comment_id)
+            .await
+            .ok_or(UpdateCommentError::UnknownComment)?;
+
+        if comment.data.removal.is_some() {
+            Err(UpdateCommentError::Removed)
+        } else {
+            Ok(comment)
+        }
+    }
+
+    /// Save the new details of a comment.
+    async fn save(&self, comment_id: &CommentId, data: CommentData) -> Result<CommentResource, UpdateCommentError> {
+        let comment = self.repository.update_comment(comment_id, &data).await?;
+
+        Ok(comment)
+    }
+}
+
+impl From<SaveCommentError> for UpdateCommentError {
+    fn from(e: SaveCommentError) -> Self {
+        match e {
+            SaveCommentError::UnknownComment => Self::UnknownComment,
+            SaveCommentError::UnknownReference | SaveCommentError::UnknownError => Self::UnknownError,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::comments::service::tests::{build_fixture, Fixture};
+
+    async fn start_thread(f: &Fixture) -> CommentResource {
+        f.sut.start_thread(&f.world_id, &f.frodo, &f.article, "First draft").await.unwrap()
+    }
+
+    #[actix_rt::test]
+    async fn edit_comment() {
+        let f = build_fixture().await;
+        let comment = start_thread(&f).await;
+
+        let_assert!(
+            Ok(edited) = f
+                .sut
+                .edit_comment(&f.world_id, &comment.identity.id, &f.frodo, "Second draft, cc @sam")
+                .await
+        );
+        check!(edited.data.body == "Second draft, cc @sam");
+        check!(edited.data.mentions == vec![f.sam.clone()]);
+
+        let_assert!(Err(e) = f.sut.edit_comment(&f.world_id, &comment.identity.id, &f.sam, "Mine now").await);
+        check!(e == UpdateCommentError::NotAuthor);
+        let_assert!(Err(e) = f.sut.edit_comment(&f.world_id, &CommentId::default(), &f.frodo, "Hello").await);
+        check!(e == UpdateCommentError::UnknownComment);
+    }
+
+    #[actix_rt::test]
+    async fn remove_comment() {
+        let f = build_fixture().await;
+        let comment = start_thread(&f).await;
+
+        let_assert!(Err(e) = f.sut.remove_comment(&f.world_id, &comment.identity.id, &f.sam, false).await);
+        check!(e == UpdateCommentError::NotAuthor);
+
+        let_assert!(Ok(removed) = f.sut.remove_comment(&f.world_id, &comment.identity.id, &f.sam, true).await);
+        check!(removed.data.body.is_empty());
+        let_assert!(Some(removal) = removed.data.removal);
+        check!(removal.removed_by == f.sam);
+
+        let_assert!(Err(e) = f.sut.remove_comment(&f.world_id, &comment.identity.id, &f.frodo, false).await);
+        check!(e == UpdateCommentError::Removed);
+        let_assert!(Err(e) = f.sut.edit_comment(&f.world_id, &comment.identity.id, &f.frodo, "Back").await);
+        check!(e == UpdateCommentError::Removed);
+    }
+
+    #[actix_rt::test]
+    async fn resolve_thread() {
+        let f = build_fixture().await;
+        let comment = start_thread(&f).await;
+        let reply = f.sut.reply(&f.world_id, &f.sam, &comment.identity.id, "Done").await.unwrap();
+
+        let_assert!(Ok(resolved) = f.sut.set_resolved(&f.world_id, &comment.identity.id, &f.sam, true).await);
+        let_assert!(Some(resolution) = resolved.data.resolution);
+        check!(resolution.resolved_by == f.sam);
+
+        let_assert!(Ok(again) = f.sut.set_resolved(&f.world_id, &comment.identity.id, &f.frodo, true).await);
+        check!(again.data.resolution == Some(resolution));
+
+        let_assert!(Ok(reopened) = f.sut.set_resolved(&f.world_id, &comment.identity.id, &f.frodo, false).await);
+        check!(reopened.data.resolution.is_none());
+
+        let_assert!(Err(e) = f.sut.set_resolved(&f.world_id, &reply.identity.id, &f.frodo, true).await);
+        check!(e == UpdateCommentError::NotAThread);
+    }
+}
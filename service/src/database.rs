@@ -1,5 +1,7 @@
 pub mod component;
+mod listen;
 mod migrate;
 mod postgres;
 
+pub use listen::*;
 pub use postgres::*;
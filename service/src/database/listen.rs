@@ -0,0 +1,53 @@
+use std::str::FromStr;
+
+use futures::{channel::mpsc, stream, Stream, StreamExt};
+use tokio_postgres::AsyncMessage;
+
+use super::postgres::tls_connector;
+
+/// Listen for notifications that are sent to a channel with `NOTIFY`.
+///
+/// This uses a dedicated connection rather than one from the pool, since the connection has to stay
+/// open for as long as the notifications are wanted.
+///
+/// # Parameters
+/// - `url` - The URL to connect to
+/// - `channel` - The name of the channel to listen to
+///
+/// # Returns
+/// The payloads of the notifications, in the order that they are received. The stream ends if the
+/// connection to the database is lost.
+pub async fn listen(url: &str, channel: &str) -> Result<impl Stream<Item = String>, tokio_postgres::Error> {
+    let pg_config = tokio_postgres::Config::from_str(url)?;
+    let (client, mut connection) = pg_config.connect(tls_connector()).await?;
+
+    // The connection has to be polled for anything to happen on it, including the LISTEN itself.
+    let (sender, receiver) = mpsc::unbounded();
+    actix_rt::spawn(async move {
+        let mut messages = stream::poll_fn(move |cx| connection.poll_message(cx));
+
+        while let Some(message) = messages.next().await {
+            match message {
+                Ok(AsyncMessage::Notification(notification)) => {
+                    if sender.unbounded_send(notification.payload().to_owned()).is_err() {
+                        break;
+                    }
+                },
+                Ok(_) => {},
+                Err(e) => {
+                    tracing::warn!(e = ?e, "Lost connection to the database while listening for notifications");
+                    break;
+                },
+            }
+        }
+    });
+
+    client.batch_execute(&format!("LISTEN \"{}\"", channel)).await?;
+    tracing::debug!(channel = ?channel, "Listening for notifications");
+
+    // The client is kept alive for as long as the stream, since dropping it closes the connection.
+    Ok(receiver.map(move |payload| {
+        let _ = &client;
+        payload
+    }))
+}
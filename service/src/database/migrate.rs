@@ -1,6 +1,9 @@
 use rust_embed::RustEmbed;
+use uuid::Uuid;
 
 use super::{Database, Transaction};
+use crate::users::{Email, Username};
+
 /// The embedded migrations files to apply.
 #[derive(RustEmbed)]
 #[folder = "migrations/"]
@@ -29,6 +32,7 @@ pub async fn migrate(db: &Database) {
             tx.batch_execute(std::str::from_utf8(&contents).expect("Failed to load migration"))
                 .await
                 .expect("Failed to apply migration");
+            after_migration(&tx, migration).await;
             tx.execute("INSERT INTO __migrations(migration_file) VALUES ($1)", &[migration])
                 .await
                 .expect("Failed to record applied migration");
@@ -41,6 +45,76 @@ pub async fn migrate(db: &Database) {
     tracing::info!(count = ?count, total = ?(available.len()), "Applied migrations");
 }
 
+/// Apply any changes that can't be expressed in SQL, immediately after the named migration.
+///
+/// # Parameters
+/// - `tx` - The transaction that the migrations are being applied in
+/// - `migration` - The name of the migration file that has just been applied
+async fn after_migration(tx: &Transaction<'_>, migration: &str) {
+    if migration == "20210503_000002_unique_users.sql" {
+        backfill_username_keys(tx).await;
+        backfill_emails(tx).await;
+    }
+}
+
+/// Populate the `username_key` column of every existing user, and then make it unique.
+///
+/// Postgres can't produce the same keys as `Username::key()` - it has no NFKC normalization before
+/// version 13, and its case-folding depends on the database locale - so this is done in Rust.
+async fn backfill_username_keys(tx: &Transaction<'_>) {
+    tracing::debug!("Populating username keys");
+
+    let users = tx
+        .query("SELECT user_id, username FROM users", &[])
+        .await
+        .expect("Failed to list users");
+
+    for row in &users {
+        let user_id: Uuid = row.get("user_id");
+        let username: Username = row.get("username");
+
+        tx.execute(
+            "UPDATE users SET username_key = $1 WHERE user_id = $2",
+            &[&username.key(), &user_id],
+        )
+        .await
+        .expect("Failed to populate username key");
+    }
+
+    tx.batch_execute(
+        "ALTER TABLE users ALTER COLUMN username_key SET NOT NULL;
+        ALTER TABLE users ADD CONSTRAINT users_username_key UNIQUE (username_key);",
+    )
+    .await
+    .expect("Failed to make username keys unique");
+}
+
+/// Normalize the email address of every existing user, and then make them unique.
+///
+/// This is done in Rust for the same reasons as `backfill_username_keys`, so that the stored
+/// addresses match what `Email::from_str()` produces.
+async fn backfill_emails(tx: &Transaction<'_>) {
+    tracing::debug!("Normalizing email addresses");
+
+    let users = tx
+        .query("SELECT user_id, email FROM users", &[])
+        .await
+        .expect("Failed to list users");
+
+    for row in &users {
+        let user_id: Uuid = row.get("user_id");
+        let email: Email = row.get::<_, &str>("email").parse().expect("Failed to normalize email address");
+
+        tx.execute("UPDATE users SET email = $1 WHERE user_id = $2", &[&email, &user_id])
+            .await
+            .expect("Failed to normalize email address");
+    }
+
+    tx.batch_execute("ALTER TABLE users ADD CONSTRAINT users_email_key UNIQUE (email);")
+        .await
+        .expect("Failed to make email addresses unique");
+}
+
 async fn lock_migrations_table(tx: &Transaction<'_>) {
     tracing::trace!("Ensuring the migrations table exists");
     tx.execute(
@@ -30,11 +30,7 @@ impl Database {
             recycling_method: RecyclingMethod::Fast,
         };
 
-        let mut builder = SslConnector::builder(SslMethod::tls()).unwrap();
-        builder.set_verify(SslVerifyMode::NONE);
-        let connector = MakeTlsConnector::new(builder.build());
-
-        let mgr = Manager::from_config(pg_config, connector, mgr_config);
+        let mgr = Manager::from_config(pg_config, tls_connector(), mgr_config);
         let pool = Pool::new(mgr, 16);
 
         pool.get().await.expect("Unable to open database connection");
@@ -51,6 +47,14 @@ impl Database {
     }
 }
 
+/// Build the connector to use for TLS connections to the database.
+pub(super) fn tls_connector() -> MakeTlsConnector {
+    let mut builder = SslConnector::builder(SslMethod::tls()).unwrap();
+    builder.set_verify(SslVerifyMode::NONE);
+
+    MakeTlsConnector::new(builder.build())
+}
+
 impl Connection {
     /// Begin a database transaction
     pub async fn begin(&mut self) -> Transaction<'_> {
@@ -102,7 +106,7 @@ impl Connection {
                 span.record("error", &true);
                 tracing::warn!(e = ?e, "Error executing query");
             },
-        };
+        }
 
         result
     }
@@ -134,13 +138,89 @@ impl Connection {
                 span.record("error", &true);
                 tracing::warn!(e = ?e, "Error executing query");
             },
-        };
+        }
+
+        result
+    }
+
+    /// Perform a SQL query on the connection, expecting any number of rows.
+    ///
+    /// # Parameters
+    /// - `sql` - The SQL query to perform
+    /// - `params` - Any bind parameters for the SQL query
+    ///
+    /// # Returns
+    /// The rows that were returned from the database.
+    pub async fn query<S>(&self, sql: S, params: &[&(dyn ToSql + Sync)]) -> Result<Vec<Row>, tokio_postgres::Error>
+    where
+        S: Into<String>,
+    {
+        let sql = sql.into();
+
+        let span = tracing::trace_span!(
+            "database::Connection::query",
+            sql = sql.as_str(),
+            rows = tracing::field::Empty,
+            error = tracing::field::Empty,
+        );
+        let _enter = span.enter();
+
+        let result = self.0.query(sql.as_str(), params).await;
+
+        match &result {
+            Ok(r) => {
+                span.record("rows", &r.len());
+                span.record("error", &false);
+            },
+            Err(e) => {
+                span.record("error", &true);
+                tracing::warn!(e = ?e, "Error executing query");
+            },
+        }
+
+        result
+    }
+
+    /// Execute a SQL statement on the connection.
+    ///
+    /// # Parameters
+    /// - `sql` - The SQL statement to execute
+    /// - `params` - Any bind parameters for the SQL statement
+    ///
+    /// # Returns
+    /// The number of rows that were modified in the database.
+    pub async fn execute<S>(&self, sql: S, params: &[&(dyn ToSql + Sync)]) -> Result<u64, tokio_postgres::Error>
+    where
+        S: Into<String>,
+    {
+        let sql = sql.into();
+
+        let span = tracing::trace_span!(
+            "database::Connection::execute",
+            sql = sql.as_str(),
+            result = tracing::field::Empty,
+            error = tracing::field::Empty,
+        );
+        let _enter = span.enter();
+
+        let result = self.0.execute(sql.as_str(), params).await;
+
+        match &result {
+            Ok(r) => {
+                span.record("result", &r);
+                span.record("error", &false);
+            },
+            Err(e) => {
+                span.record("error", &true);
+                tracing::warn!(e = ?e, "Error executing query");
+            },
+        }
 
         result
     }
 }
 
-impl<'a> Transaction<'a> {
+impl Transaction<'_> {
     /// Execute a SQL statement within the transaction
     ///
     /// # Parameters
@@ -175,7 +255,7 @@ impl<'a> Transaction<'a> {
                 span.record("error", &true);
                 tracing::warn!(e = ?e, "Error executing query");
             },
-        };
+        }
 
         result
     }
@@ -241,7 +321,7 @@ impl<'a> Transaction<'a> {
                 span.record("error", &true);
                 tracing::warn!(e = ?e, "Error executing query");
             },
-        };
+        }
 
         result
     }
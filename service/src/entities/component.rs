@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use actix_web::web::{delete, get, patch, post, resource, ServiceConfig};
+
+use super::{
+    repository::EntityRepository, service::EntityService, CharacterAttributes, EntityType, FactionAttributes, ItemAttributes,
+    LocationAttributes,
+};
+use crate::{revisions::RevisionService, search::SearchService, server::RouteConfigurer};
+
+/// Component for working with the typed entities within worlds.
+pub struct Component {
+    pub service: Arc<EntityService>,
+}
+
+impl Component {
+    /// Create a new entities component.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store entities in
+    /// - `revisions` - The service to record the revision history of entities with
+    /// - `search` - The service to keep the search index of entities up to date with
+    pub fn new(repository: Arc<dyn EntityRepository>, revisions: Arc<RevisionService>, search: Arc<SearchService>) -> Arc<Self> {
+        let service = Arc::new(EntityService::new(repository, revisions, search));
+
+        Arc::new(Self { service })
+    }
+}
+
+/// Configure the routes for a single type of entity.
+fn configure_entity_routes<A>(config: &mut ServiceConfig)
+where
+    A: EntityType,
+{
+    config.service(
+        resource(format!("/worlds/{{id}}/{}", A::COLLECTION))
+            .route(post().to(super::endpoints::create_entity::handle::<A>))
+            .route(get().to(super::endpoints::list_entities::handle::<A>)),
+    );
+    config.service(
+        resource(format!("/worlds/{{id}}/{}/{{entity}}", A::COLLECTION))
+            .route(get().to(super::endpoints::get_entity::handle::<A>))
+            .route(patch().to(super::endpoints::patch_entity::handle::<A>))
+            .route(delete().to(super::endpoints::delete_entity::handle::<A>)),
+    );
+    config.service(
+        resource(format!("/worlds/{{id}}/{}/{{entity}}/revisions", A::COLLECTION))
+            .route(get().to(super::endpoints::list_revisions::handle::<A>)),
+    );
+    config.service(
+        resource(format!("/worlds/{{id}}/{}/{{entity}}/revisions/{{version}}", A::COLLECTION))
+            .route(get().to(super::endpoints::get_revision::handle::<A>)),
+    );
+    config.service(
+        resource(format!("/worlds/{{id}}/{}/{{entity}}/revisions/{{version}}/diff", A::COLLECTION))
+            .route(get().to(super::endpoints::diff_revisions::handle::<A>)),
+    );
+    config.service(
+        resource(format!("/worlds/{{id}}/{}/{{entity}}/revisions/{{version}}/restore", A::COLLECTION))
+            .route(post().to(super::endpoints::restore_revision::handle::<A>)),
+    );
+}
+
+impl RouteConfigurer for Component {
+    fn configure_routes(&self, config: &mut ServiceConfig) {
+        config.data(self.service.clone());
+
+        configure_entity_routes::<CharacterAttributes>(config);
+        configure_entity_routes::<LocationAttributes>(config);
+        configure_entity_routes::<FactionAttributes>(config);
+        configure_entity_routes::<ItemAttributes>(config);
+    }
+}
@@ -0,0 +1,14 @@
+//! Endpoints for working with entities. These are generic over the type of entity, and are
+//! registered once for each type.
+
+pub(super) mod create_entity;
+pub(super) mod delete_entity;
+pub(super) mod diff_revisions;
+pub(super) mod get_entity;
+pub(super) mod get_revision;
+pub(super) mod list_entities;
+pub(super) mod list_revisions;
+mod model;
+pub(super) mod patch_entity;
+mod problems;
+pub(super) mod restore_revision;
@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use actix_http::http::StatusCode;
+use actix_web::web::{Data, Path};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{
+    model::{EntityModel, EntityResponse},
+    problems::invalid_reference,
+};
+use crate::{
+    authorization::Authentication,
+    entities::{CreateEntityError, CustomFields, EntityService, EntityType, NewEntity},
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+        response::SimpleRespondable,
+        valid::{Valid, Validatable},
+    },
+    tokens::Scope,
+    worlds::{Role, WorldId, WorldService},
+};
+
+/// Create a new entity of some type within a world.
+pub async fn handle<A>(
+    service: Data<Arc<EntityService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<String>,
+    request: Valid<CreateEntityRequest<A>>,
+    authentication: Authentication,
+) -> Result<EntityResponse<A>, Problem>
+where
+    A: EntityType,
+{
+    let world_id: WorldId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+    let user_id = authentication.require_user()?;
+
+    let request = request.unwrap();
+
+    let entity = service
+        .create_entity(
+            &world_id,
+            &user_id,
+            NewEntity {
+                name:       request.name,
+                summary:    request.summary,
+                attributes: request.attributes,
+                custom:     request.custom,
+            },
+        )
+        .await
+        .map_err(|e| match e {
+            CreateEntityError::InvalidReference(entity_id) => invalid_reference(&entity_id),
+            CreateEntityError::UnknownError => INTERNAL_SERVER_ERROR.into(),
+        })?;
+
+    Ok(SimpleRespondable::<EntityModel<A>>::from(entity)
+        .with_status_code(StatusCode::CREATED)
+        .into())
+}
+
+/// The incoming request to create an entity.
+#[derive(Deserialize)]
+#[serde(bound = "A: EntityType")]
+pub struct CreateEntityRequest<A> {
+    pub name:       String,
+    pub summary:    Option<String>,
+    #[serde(default)]
+    pub attributes: A,
+    #[serde(default)]
+    pub custom:     CustomFields,
+}
+
+impl<A> Validatable for CreateEntityRequest<A>
+where
+    A: EntityType,
+{
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 200
+                },
+                "summary": {
+                    "type": "string",
+                    "maxLength": 1000
+                },
+                "attributes": A::schema(),
+                "custom": {
+                    "type": "object"
+                }
+            },
+            "required": [
+                "name"
+            ]
+        })
+    }
+}
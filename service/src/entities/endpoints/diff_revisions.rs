@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path, Query};
+
+use crate::{
+    authorization::Authentication,
+    entities::{EntityData, EntityId, EntityService, EntityType},
+    http::{
+        problem::{Problem, NOT_FOUND},
+        response::Response,
+    },
+    revisions::{
+        endpoints::{DiffModel, DiffQuery},
+        RevisionService,
+    },
+    tokens::Scope,
+    worlds::{Role, WorldId, WorldService},
+};
+
+/// Show the changes that were made in a revision of an entity.
+pub async fn handle<A>(
+    service: Data<Arc<EntityService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    revisions: Data<Arc<RevisionService>>,
+    path: Path<(String, String, String)>,
+    query: Query<DiffQuery>,
+    authentication: Authentication,
+) -> Result<Response<DiffModel>, Problem>
+where
+    A: EntityType,
+{
+    let (world_id, entity_id, version) = path.into_inner();
+
+    let world_id: WorldId = world_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, world_id = ?world_id, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+    let entity_id: EntityId = entity_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, entity_id = ?entity_id, "Failed to parse Entity ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let entity = service.get_entity::<A>(&world_id, &entity_id).await.ok_or(NOT_FOUND)?;
+
+    crate::revisions::endpoints::diff_revisions::<EntityData<A>>(&revisions, &entity.identity.id, &version, &query).await
+}
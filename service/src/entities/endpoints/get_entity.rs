@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::model::EntityResponse;
+use crate::{
+    authorization::Authentication,
+    entities::{EntityId, EntityService, EntityType},
+    http::problem::{Problem, NOT_FOUND},
+    tokens::Scope,
+    worlds::{Role, WorldId, WorldService},
+};
+
+pub async fn handle<A>(
+    service: Data<Arc<EntityService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<EntityResponse<A>, Problem>
+where
+    A: EntityType,
+{
+    let (world_id, entity_id) = path.into_inner();
+
+    let world_id: WorldId = world_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, world_id = ?world_id, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+    let entity_id: EntityId = entity_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, entity_id = ?entity_id, "Failed to parse Entity ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let entity = service.get_entity::<A>(&world_id, &entity_id).await.ok_or(NOT_FOUND)?;
+
+    Ok(entity.into())
+}
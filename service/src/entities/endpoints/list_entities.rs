@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::model::{EntitiesModel, EntityModel};
+use crate::{
+    authorization::Authentication,
+    entities::{EntityService, EntityType},
+    http::{
+        problem::{Problem, NOT_FOUND},
+        response::Response,
+    },
+    tokens::Scope,
+    worlds::{Role, WorldId, WorldService},
+};
+
+pub async fn handle<A>(
+    service: Data<Arc<EntityService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<String>,
+    authentication: Authentication,
+) -> Result<Response<EntitiesModel<A>>, Problem>
+where
+    A: EntityType,
+{
+    let world_id: WorldId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let entities = service
+        .get_entities::<A>(&world_id)
+        .await
+        .into_iter()
+        .map(EntityModel::from)
+        .collect();
+
+    Ok(Response(EntitiesModel { entities }))
+}
@@ -0,0 +1,58 @@
+use actix_web::http::header::CacheDirective;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::{
+    entities::{CustomFields, EntityData, EntityId, EntityResource, EntityType},
+    http::{
+        model::ResourceResponse,
+        response::{Response, SimpleRespondable},
+    },
+    model::Resource,
+};
+
+/// Representation of an entity on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityModel<A> {
+    pub entity_id:  EntityId,
+    pub kind:       &'static str,
+    pub name:       String,
+    pub summary:    Option<String>,
+    pub attributes: A,
+    pub custom:     CustomFields,
+    pub created:    DateTime<Utc>,
+    pub updated:    DateTime<Utc>,
+}
+
+impl<A> From<EntityResource<A>> for EntityModel<A>
+where
+    A: EntityType,
+{
+    fn from(entity: EntityResource<A>) -> Self {
+        Self {
+            entity_id:  entity.identity.id,
+            kind:       A::KIND,
+            name:       entity.data.name,
+            summary:    entity.data.summary,
+            attributes: entity.data.attributes,
+            custom:     entity.data.custom,
+            created:    entity.identity.created,
+            updated:    entity.identity.updated,
+        }
+    }
+}
+
+impl<A> ResourceResponse for Resource<EntityId, EntityData<A>> {
+    fn cache_control(&self) -> Option<Vec<CacheDirective>> {
+        Some(vec![CacheDirective::Private, CacheDirective::NoCache])
+    }
+}
+
+pub type EntityResponse<A> = Response<SimpleRespondable<EntityModel<A>>>;
+
+/// Representation of a list of entities on the HTTP API.
+#[derive(Serialize)]
+pub struct EntitiesModel<A> {
+    pub entities: Vec<EntityModel<A>>,
+}
@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{model::EntityResponse, problems::invalid_reference};
+use crate::{
+    authorization::Authentication,
+    entities::{CustomFields, EntityData, EntityId, EntityService, EntityType, UpdateEntityError},
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+        valid::{Valid, Validatable},
+    },
+    tokens::Scope,
+    worlds::{Role, WorldId, WorldService},
+};
+
+pub async fn handle<A>(
+    service: Data<Arc<EntityService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    request: Valid<PatchRequest<A>>,
+    authentication: Authentication,
+) -> Result<EntityResponse<A>, Problem>
+where
+    A: EntityType,
+{
+    let (world_id, entity_id) = path.into_inner();
+
+    let world_id: WorldId = world_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, world_id = ?world_id, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+    let entity_id: EntityId = entity_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, entity_id = ?entity_id, "Failed to parse Entity ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+    let user_id = authentication.require_user()?;
+
+    let request = request.unwrap();
+
+    let entity = service
+        .update_entity_by_id(&world_id, &entity_id, &user_id, move |entity| -> Result<EntityData<A>, Problem> {
+            let mut custom = entity.custom;
+            for (key, value) in request.custom {
+                if value.is_null() {
+                    custom.remove(&key);
+                } else {
+                    custom.insert(key, value);
+                }
+            }
+
+            Ok(EntityData {
+                name: request.name.unwrap_or(entity.name),
+                summary: request.summary.or(entity.summary),
+                attributes: request.attributes.unwrap_or(entity.attributes),
+                custom,
+                ..entity
+            })
+        })
+        .await
+        .map_err(|e| match e {
+            UpdateEntityError::UpdateError(p) => p,
+            UpdateEntityError::UnknownEntity => NOT_FOUND.into(),
+            UpdateEntityError::InvalidReference(entity_id) => invalid_reference(&entity_id),
+            UpdateEntityError::UnknownError => INTERNAL_SERVER_ERROR.into(),
+        })?;
+
+    Ok(entity.into())
+}
+
+/// The incoming request to patch an entity.
+/// The attributes are replaced as a whole, whereas custom fields are merged in - with a value of
+/// `null` removing that field.
+#[derive(Deserialize)]
+#[serde(bound = "A: EntityType")]
+pub struct PatchRequest<A> {
+    pub name:       Option<String>,
+    pub summary:    Option<String>,
+    pub attributes: Option<A>,
+    #[serde(default)]
+    pub custom:     CustomFields,
+}
+
+impl<A> Validatable for PatchRequest<A>
+where
+    A: EntityType,
+{
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 200
+                },
+                "summary": {
+                    "type": "string",
+                    "maxLength": 1000
+                },
+                "attributes": A::schema(),
+                "custom": {
+                    "type": "object"
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,22 @@
+use actix_http::http::StatusCode;
+
+use crate::{
+    entities::EntityId,
+    http::problem::{Problem, SimpleProblemType},
+};
+
+/// Problem to indicate that an entity refers to another entity that it can't refer to - because it
+/// doesn't exist, is the wrong type of entity, or would end up containing itself.
+pub const INVALID_REFERENCE: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/entities/invalid_reference",
+    problem_title: "Invalid Entity Reference",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Build the problem for an invalid reference to an entity.
+///
+/// # Parameters
+/// - `entity_id` - The ID of the entity that can't be referred to
+pub fn invalid_reference(entity_id: &EntityId) -> Problem {
+    Problem::from(INVALID_REFERENCE).with_extra("entityId", entity_id)
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::{model::EntityResponse, problems::invalid_reference};
+use crate::{
+    authorization::Authentication,
+    entities::{EntityData, EntityId, EntityService, EntityType, UpdateEntityError},
+    http::problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+    revisions::{endpoints::parse_version, Revisable, RevisionService},
+    tokens::Scope,
+    worlds::{Role, WorldId, WorldService},
+};
+
+/// Restore an entity to the state that it was in at a previous revision.
+/// This is recorded as a new revision, so that the restore itself can be undone.
+pub async fn handle<A>(
+    service: Data<Arc<EntityService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    revisions: Data<Arc<RevisionService>>,
+    path: Path<(String, String, String)>,
+    authentication: Authentication,
+) -> Result<EntityResponse<A>, Problem>
+where
+    A: EntityType,
+{
+    let (world_id, entity_id, version) = path.into_inner();
+
+    let world_id: WorldId = world_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, world_id = ?world_id, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+    let entity_id: EntityId = entity_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, entity_id = ?entity_id, "Failed to parse Entity ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+    let user_id = authentication.require_user()?;
+
+    let version = parse_version(&version)?;
+    let snapshot = revisions
+        .get_snapshot::<EntityData<A>>(&entity_id, &version)
+        .await
+        .ok_or(NOT_FOUND)?;
+
+    let entity = service
+        .update_entity_by_id(&world_id, &entity_id, &user_id, move |entity: EntityData<A>| {
+            Ok::<_, Problem>(entity.restore(snapshot))
+        })
+        .await
+        .map_err(|e| match e {
+            UpdateEntityError::UpdateError(p) => p,
+            UpdateEntityError::UnknownEntity => NOT_FOUND.into(),
+            UpdateEntityError::InvalidReference(entity_id) => invalid_reference(&entity_id),
+            UpdateEntityError::UnknownError => INTERNAL_SERVER_ERROR.into(),
+        })?;
+
+    Ok(entity.into())
+}
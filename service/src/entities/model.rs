@@ -0,0 +1,104 @@
+mod character;
+mod entity_id;
+mod entity_type;
+mod faction;
+mod item;
+mod location;
+
+pub use character::*;
+pub use entity_id::*;
+pub use entity_type::*;
+pub use faction::*;
+pub use item::*;
+pub use location::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    model::Resource,
+    revisions::Revisable,
+    search::{json_text, Searchable},
+    worlds::WorldId,
+};
+
+/// Any world-specific fields on an entity, beyond those that the type of entity defines.
+pub type CustomFields = serde_json::Map<String, serde_json::Value>;
+
+/// The data representing a typed entity within a world.
+///
+/// # Types
+/// - `<A>` - The type of entity, which determines the attributes that it has.
+#[derive(Debug, Clone)]
+pub struct EntityData<A> {
+    pub world_id:   WorldId,
+    pub name:       String,
+    pub summary:    Option<String>,
+    pub attributes: A,
+    pub custom:     CustomFields,
+}
+
+/// Type representing a persisted entity.
+pub type EntityResource<A> = Resource<EntityId, EntityData<A>>;
+
+pub type CharacterResource = EntityResource<CharacterAttributes>;
+pub type LocationResource = EntityResource<LocationAttributes>;
+pub type FactionResource = EntityResource<FactionAttributes>;
+pub type ItemResource = EntityResource<ItemAttributes>;
+
+/// The parts of an entity that are kept in its revision history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EntitySnapshot<A> {
+    pub name:       String,
+    pub summary:    Option<String>,
+    pub attributes: A,
+    pub custom:     CustomFields,
+}
+
+impl<A> Revisable for EntityData<A>
+where
+    A: EntityType,
+{
+    const RESOURCE_TYPE: &'static str = A::KIND;
+    type Snapshot = EntitySnapshot<A>;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        EntitySnapshot {
+            name:       self.name.clone(),
+            summary:    self.summary.clone(),
+            attributes: self.attributes.clone(),
+            custom:     self.custom.clone(),
+        }
+    }
+
+    fn restore(self, snapshot: Self::Snapshot) -> Self {
+        Self {
+            name: snapshot.name,
+            summary: snapshot.summary,
+            attributes: snapshot.attributes,
+            custom: snapshot.custom,
+            ..self
+        }
+    }
+}
+
+impl<A> Searchable for EntityData<A>
+where
+    A: EntityType,
+{
+    const SEARCH_KIND: &'static str = A::KIND;
+
+    fn search_world(&self) -> &WorldId {
+        &self.world_id
+    }
+
+    fn search_title(&self) -> &str {
+        &self.name
+    }
+
+    fn search_body(&self) -> String {
+        let mut text: Vec<String> = self.summary.iter().cloned().collect();
+        json_text(&serde_json::to_value(&self.attributes).unwrap_or_default(), &mut text);
+        self.custom.values().for_each(|value| json_text(value, &mut text));
+
+        text.join("\n")
+    }
+}
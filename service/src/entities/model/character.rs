@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::{EntityId, EntityReference, EntityType, FactionAttributes};
+use crate::http::valid::Validatable;
+
+/// The attributes of a character.
+/// Dates are free text, since every world measures time in its own way.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterAttributes {
+    pub species:      Option<String>,
+    pub born:         Option<String>,
+    pub died:         Option<String>,
+    /// The factions that the character belongs to.
+    pub affiliations: Vec<EntityId>,
+}
+
+impl EntityType for CharacterAttributes {
+    const COLLECTION: &'static str = "characters";
+    const KIND: &'static str = "character";
+
+    fn references(&self) -> Vec<EntityReference> {
+        self.affiliations.iter().map(EntityReference::to::<FactionAttributes>).collect()
+    }
+}
+
+impl Validatable for CharacterAttributes {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "species": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 100
+                },
+                "born": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 100
+                },
+                "died": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 100
+                },
+                "affiliations": {
+                    "type": "array",
+                    "items": EntityId::schema(),
+                    "uniqueItems": true
+                }
+            },
+            "additionalProperties": false
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use serde_json::json;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(&json!({}), true ; "Empty")]
+    #[test_case(&json!({"species": "Hobbit", "born": "TA 2968", "affiliations": ["4ea96dc3-df11-43c0-8a33-a0813f03937f"]}), true ; "Complete")]
+    #[test_case(&json!({"species": ""}), false ; "Blank species")]
+    #[test_case(&json!({"affiliations": ["fellowship"]}), false ; "Malformed affiliation")]
+    #[test_case(&json!({"affiliations": ["4ea96dc3-df11-43c0-8a33-a0813f03937f", "4ea96dc3-df11-43c0-8a33-a0813f03937f"]}), false ; "Duplicate affiliation")]
+    fn test_schema(input: &Value, expected: bool) {
+        let mut scope = valico::json_schema::Scope::new();
+        let schema = scope.compile_and_return(CharacterAttributes::schema(), false).unwrap();
+
+        check!(schema.validate(input).is_valid() == expected);
+    }
+}
@@ -0,0 +1,56 @@
+use std::fmt::Debug;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::EntityId;
+use crate::http::valid::Validatable;
+
+/// Trait implemented by the attributes of each type of entity.
+/// Every type of entity is stored in the same place, with the attributes held as JSON, so adding a
+/// new type of entity only needs a new implementation of this.
+pub trait EntityType: Debug + Clone + Default + Serialize + DeserializeOwned + Validatable + Send + Sync + 'static {
+    /// The name of this type of entity, as stored in the database.
+    const KIND: &'static str;
+
+    /// The name of a collection of this type of entity, as used in URLs.
+    const COLLECTION: &'static str;
+
+    /// The other entities that these attributes refer to.
+    fn references(&self) -> Vec<EntityReference> {
+        vec![]
+    }
+
+    /// The entity that contains this one, if any. The containing entity must be of the same type,
+    /// and an entity can never end up containing itself.
+    fn parent(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// A reference from one entity to another.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntityReference {
+    /// The ID of the entity that is referred to.
+    pub entity_id: EntityId,
+    /// The type of entity that must be referred to.
+    pub kind:      &'static str,
+}
+
+impl EntityReference {
+    /// Create a new reference to an entity of some type.
+    ///
+    /// # Parameters
+    /// - `entity_id` - The ID of the entity that is referred to
+    ///
+    /// # Types
+    /// - `<A>` - The type of entity that must be referred to
+    pub fn to<A>(entity_id: &EntityId) -> Self
+    where
+        A: EntityType,
+    {
+        Self {
+            entity_id: entity_id.clone(),
+            kind:      A::KIND,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::{CharacterAttributes, EntityId, EntityReference, EntityType, LocationAttributes};
+use crate::http::valid::Validatable;
+
+/// The attributes of a faction, such as a kingdom, a guild or a family.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct FactionAttributes {
+    /// The type of faction - e.g. "Kingdom" or "Guild".
+    #[serde(rename = "type")]
+    pub faction_type: Option<String>,
+    /// The character that leads the faction.
+    pub leader:       Option<EntityId>,
+    /// The location that the faction is based in.
+    pub headquarters: Option<EntityId>,
+}
+
+impl EntityType for FactionAttributes {
+    const COLLECTION: &'static str = "factions";
+    const KIND: &'static str = "faction";
+
+    fn references(&self) -> Vec<EntityReference> {
+        self.leader
+            .iter()
+            .map(EntityReference::to::<CharacterAttributes>)
+            .chain(self.headquarters.iter().map(EntityReference::to::<LocationAttributes>))
+            .collect()
+    }
+}
+
+impl Validatable for FactionAttributes {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "type": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 100
+                },
+                "leader": EntityId::schema(),
+                "headquarters": EntityId::schema()
+            },
+            "additionalProperties": false
+        })
+    }
+}
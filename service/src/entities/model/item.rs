@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::{CharacterAttributes, EntityId, EntityReference, EntityType, LocationAttributes};
+use crate::http::valid::Validatable;
+
+/// The attributes of an item, such as a weapon or an artifact.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ItemAttributes {
+    /// The type of item - e.g. "Sword" or "Ring".
+    #[serde(rename = "type")]
+    pub item_type: Option<String>,
+    /// The character that currently owns the item.
+    pub owner:     Option<EntityId>,
+    /// The location that the item is currently in.
+    pub location:  Option<EntityId>,
+}
+
+impl EntityType for ItemAttributes {
+    const COLLECTION: &'static str = "items";
+    const KIND: &'static str = "item";
+
+    fn references(&self) -> Vec<EntityReference> {
+        self.owner
+            .iter()
+            .map(EntityReference::to::<CharacterAttributes>)
+            .chain(self.location.iter().map(EntityReference::to::<LocationAttributes>))
+            .collect()
+    }
+}
+
+impl Validatable for ItemAttributes {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "type": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 100
+                },
+                "owner": EntityId::schema(),
+                "location": EntityId::schema()
+            },
+            "additionalProperties": false
+        })
+    }
+}
@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::{EntityId, EntityReference, EntityType};
+use crate::http::valid::Validatable;
+
+/// The position of a location, in whatever coordinate system the world uses.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Coordinates {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// The attributes of a location.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LocationAttributes {
+    /// The location that this one is within.
+    pub parent:        Option<EntityId>,
+    pub coordinates:   Option<Coordinates>,
+    /// The type of location - e.g. "City" or "Forest".
+    #[serde(rename = "type")]
+    pub location_type: Option<String>,
+}
+
+impl EntityType for LocationAttributes {
+    const COLLECTION: &'static str = "locations";
+    const KIND: &'static str = "location";
+
+    fn references(&self) -> Vec<EntityReference> {
+        self.parent.iter().map(EntityReference::to::<LocationAttributes>).collect()
+    }
+
+    fn parent(&self) -> Option<&EntityId> {
+        self.parent.as_ref()
+    }
+}
+
+impl Validatable for LocationAttributes {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "parent": EntityId::schema(),
+                "coordinates": {
+                    "type": "object",
+                    "properties": {
+                        "x": {
+                            "type": "number"
+                        },
+                        "y": {
+                            "type": "number"
+                        }
+                    },
+                    "required": [
+                        "x",
+                        "y"
+                    ],
+                    "additionalProperties": false
+                },
+                "type": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 100
+                }
+            },
+            "additionalProperties": false
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use serde_json::json;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(&json!({}), true ; "Empty")]
+    #[test_case(&json!({"parent": "4ea96dc3-df11-43c0-8a33-a0813f03937f", "coordinates": {"x": 1.5, "y": -2}, "type": "City"}), true ; "Complete")]
+    #[test_case(&json!({"parent": "gondor"}), false ; "Malformed parent")]
+    #[test_case(&json!({"coordinates": {"x": 1.5}}), false ; "Incomplete coordinates")]
+    #[test_case(&json!({"population": 100}), false ; "Unknown attribute")]
+    fn test_schema(input: &Value, expected: bool) {
+        let mut scope = valico::json_schema::Scope::new();
+        let schema = scope.compile_and_return(LocationAttributes::schema(), false).unwrap();
+
+        check!(schema.validate(input).is_valid() == expected);
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let attributes: LocationAttributes = serde_json::from_value(json!({
+            "parent": "4ea96dc3-df11-43c0-8a33-a0813f03937f",
+            "type": "City"
+        }))
+        .unwrap();
+
+        check!(attributes.parent == Some("4ea96dc3-df11-43c0-8a33-a0813f03937f".parse().unwrap()));
+        check!(attributes.location_type == Some("City".to_owned()));
+        check!(attributes.coordinates == None);
+    }
+}
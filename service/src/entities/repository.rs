@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod memory;
+mod parse;
+mod postgres;
+mod save_entity;
+
+#[cfg(test)]
+pub use memory::MemoryEntityRepository;
+pub use postgres::PostgresEntityRepository;
+pub use save_entity::SaveEntityError;
+
+use crate::{entities::EntityId, model::Resource, worlds::WorldId};
+
+/// The data of an entity as it is stored, with the attributes not yet interpreted for the type of
+/// entity.
+#[derive(Debug, Clone)]
+pub struct EntityRecord {
+    pub world_id:   WorldId,
+    pub kind:       String,
+    pub name:       String,
+    pub summary:    Option<String>,
+    pub attributes: serde_json::Value,
+    pub custom:     serde_json::Value,
+}
+
+/// Type representing a persisted entity record.
+pub type EntityRecordResource = Resource<EntityId, EntityRecord>;
+
+/// Repository of entity records, of every type.
+#[async_trait::async_trait]
+pub trait EntityRepository: Send + Sync {
+    /// Create a new entity record.
+    ///
+    /// # Parameters
+    /// - `entity` - The details of the entity to create.
+    ///
+    /// # Returns
+    /// The created entity record.
+    async fn create_entity(&self, entity: &EntityRecord) -> Result<EntityRecordResource, SaveEntityError>;
+
+    /// Get a single entity of some type.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the entity belongs to.
+    /// - `kind` - The type of entity.
+    /// - `entity_id` - The ID of the entity.
+    ///
+    /// # Returns
+    /// The entity record, or `None` if it couldn't be found.
+    async fn get_entity(&self, world_id: &WorldId, kind: &str, entity_id: &EntityId) -> Option<EntityRecordResource>;
+
+    /// Get every entity of some type within a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world.
+    /// - `kind` - The type of entity.
+    ///
+    /// # Returns
+    /// The entity records, sorted by name.
+    async fn get_entities(&self, world_id: &WorldId, kind: &str) -> Vec<EntityRecordResource>;
+
+    /// Get the entities with any of the given IDs, of any type, within a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world.
+    /// - `entity_ids` - The IDs of the entities.
+    ///
+    /// # Returns
+    /// The entity records that exist. IDs that don't match an entity in the world are ignored.
+    async fn get_entities_by_ids(&self, world_id: &WorldId, entity_ids: &[EntityId]) -> Vec<EntityRecordResource>;
+
+    /// Update an existing entity record.
+    ///
+    /// # Parameters
+    /// - `entity_id` - The ID of the entity.
+    /// - `entity` - The new details of the entity.
+    ///
+    /// # Returns
+    /// The updated entity record.
+    async fn update_entity(&self, entity_id: &EntityId, entity: &EntityRecord) -> Result<EntityRecordResource, SaveEntityError>;
+
+    /// Delete a single entity.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the entity belongs to.
+    /// - `kind` - The type of entity.
+    /// - `entity_id` - The ID of the entity.
+    ///
+    /// # Returns
+    /// True if the entity existed and was deleted. False if not.
+    async fn delete_entity(&self, world_id: &WorldId, kind: &str, entity_id: &EntityId) -> bool;
+}
@@ -0,0 +1,91 @@
+use std::sync::Mutex;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::{EntityRecord, EntityRecordResource, EntityRepository, SaveEntityError};
+use crate::{entities::EntityId, model::Identity, worlds::WorldId};
+
+/// Repository of entity records that are stored in memory.
+#[derive(Default)]
+pub struct MemoryEntityRepository {
+    entities: Mutex<Vec<EntityRecordResource>>,
+}
+
+impl MemoryEntityRepository {
+    /// Create a new, empty entity repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl EntityRepository for MemoryEntityRepository {
+    async fn create_entity(&self, entity: &EntityRecord) -> Result<EntityRecordResource, SaveEntityError> {
+        let mut entities = self.entities.lock().unwrap();
+
+        let created = EntityRecordResource {
+            identity: Identity::default(),
+            data:     entity.clone(),
+        };
+        entities.push(created.clone());
+
+        Ok(created)
+    }
+
+    async fn get_entity(&self, world_id: &WorldId, kind: &str, entity_id: &EntityId) -> Option<EntityRecordResource> {
+        let entities = self.entities.lock().unwrap();
+
+        entities
+            .iter()
+            .find(|e| &e.data.world_id == world_id && e.data.kind == kind && &e.identity.id == entity_id)
+            .cloned()
+    }
+
+    async fn get_entities(&self, world_id: &WorldId, kind: &str) -> Vec<EntityRecordResource> {
+        let entities = self.entities.lock().unwrap();
+
+        let mut result: Vec<EntityRecordResource> = entities
+            .iter()
+            .filter(|e| &e.data.world_id == world_id && e.data.kind == kind)
+            .cloned()
+            .collect();
+        result.sort_by_key(|e| e.data.name.to_lowercase());
+
+        result
+    }
+
+    async fn get_entities_by_ids(&self, world_id: &WorldId, entity_ids: &[EntityId]) -> Vec<EntityRecordResource> {
+        let entities = self.entities.lock().unwrap();
+
+        entities
+            .iter()
+            .filter(|e| &e.data.world_id == world_id && entity_ids.contains(&e.identity.id))
+            .cloned()
+            .collect()
+    }
+
+    async fn update_entity(&self, entity_id: &EntityId, entity: &EntityRecord) -> Result<EntityRecordResource, SaveEntityError> {
+        let mut entities = self.entities.lock().unwrap();
+
+        let existing = entities
+            .iter_mut()
+            .find(|e| &e.identity.id == entity_id && e.data.world_id == entity.world_id && e.data.kind == entity.kind)
+            .ok_or(SaveEntityError::UnknownEntity)?;
+
+        existing.identity.version = Uuid::new_v4();
+        existing.identity.updated = Utc::now();
+        existing.data = entity.clone();
+
+        Ok(existing.clone())
+    }
+
+    async fn delete_entity(&self, world_id: &WorldId, kind: &str, entity_id: &EntityId) -> bool {
+        let mut entities = self.entities.lock().unwrap();
+
+        let before = entities.len();
+        entities.retain(|e| !(&e.data.world_id == world_id && e.data.kind == kind && &e.identity.id == entity_id));
+
+        entities.len() != before
+    }
+}
@@ -0,0 +1,25 @@
+use tokio_postgres::Row;
+
+use super::{EntityRecord, EntityRecordResource};
+use crate::model::Identity;
+
+impl From<Row> for EntityRecordResource {
+    fn from(row: Row) -> Self {
+        EntityRecordResource {
+            identity: Identity {
+                id:      row.get("entity_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data:     EntityRecord {
+                world_id:   row.get("world_id"),
+                kind:       row.get("kind"),
+                name:       row.get("name"),
+                summary:    row.get("summary"),
+                attributes: row.get("attributes"),
+                custom:     row.get("custom"),
+            },
+        }
+    }
+}
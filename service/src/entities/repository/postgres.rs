@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::{EntityRecord, EntityRecordResource, EntityRepository, SaveEntityError};
+use crate::{database::Database, entities::EntityId, model::Identity, worlds::WorldId};
+
+/// Repository of entity records that are stored in Postgres.
+pub struct PostgresEntityRepository {
+    database: Arc<Database>,
+}
+
+impl PostgresEntityRepository {
+    /// Create a new entity repository.
+    pub fn new(database: Arc<Database>) -> Self {
+        Self { database }
+    }
+
+    /// Load many entities with the provided query.
+    async fn query(&self, sql: &str, params: &[&(dyn postgres_types::ToSql + Sync)]) -> Vec<EntityRecordResource> {
+        let conn = self.database.connect().await;
+        conn.query(sql, params).await.map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to load entities");
+                vec![]
+            },
+            |rows| rows.into_iter().map(EntityRecordResource::from).collect(),
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl EntityRepository for PostgresEntityRepository {
+    #[tracing::instrument(skip(self))]
+    async fn create_entity(&self, entity: &EntityRecord) -> Result<EntityRecordResource, SaveEntityError> {
+        let conn = self.database.connect().await;
+
+        let identity = Identity::<EntityId>::default();
+
+        let created: EntityRecordResource = conn.query_one("INSERT INTO entities(entity_id, version, created, updated, world_id, kind, name, summary, attributes, custom) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) RETURNING *",
+        &[
+          &identity.id,
+          &identity.version,
+          &identity.created,
+          &identity.updated,
+          &entity.world_id,
+          &entity.kind,
+          &entity.name,
+          &entity.summary,
+          &entity.attributes,
+          &entity.custom,
+          ])
+            .await
+            .map(EntityRecordResource::from)?;
+
+        Ok(created)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_entity(&self, world_id: &WorldId, kind: &str, entity_id: &EntityId) -> Option<EntityRecordResource> {
+        self.query(
+            "SELECT * FROM entities WHERE world_id = $1 AND kind = $2 AND entity_id = $3",
+            &[&world_id, &kind, &entity_id],
+        )
+        .await
+        .into_iter()
+        .next()
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_entities(&self, world_id: &WorldId, kind: &str) -> Vec<EntityRecordResource> {
+        self.query(
+            "SELECT * FROM entities WHERE world_id = $1 AND kind = $2 ORDER BY LOWER(name)",
+            &[&world_id, &kind],
+        )
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_entities_by_ids(&self, world_id: &WorldId, entity_ids: &[EntityId]) -> Vec<EntityRecordResource> {
+        self.query(
+            "SELECT * FROM entities WHERE world_id = $1 AND entity_id = ANY($2)",
+            &[&world_id, &entity_ids],
+        )
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn update_entity(&self, entity_id: &EntityId, entity: &EntityRecord) -> Result<EntityRecordResource, SaveEntityError> {
+        let conn = self.database.connect().await;
+
+        let version = Uuid::new_v4();
+        let updated = Utc::now();
+
+        conn.query_opt("UPDATE entities SET version = $4, updated = $5, name = $6, summary = $7, attributes = $8, custom = $9 WHERE entity_id = $1 AND world_id = $2 AND kind = $3 RETURNING *",
+        &[
+          &entity_id,
+          &entity.world_id,
+          &entity.kind,
+          &version,
+          &updated,
+          &entity.name,
+          &entity.summary,
+          &entity.attributes,
+          &entity.custom,
+          ])
+            .await?
+            .map(EntityRecordResource::from)
+            .ok_or(SaveEntityError::UnknownEntity)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_entity(&self, world_id: &WorldId, kind: &str, entity_id: &EntityId) -> bool {
+        let conn = self.database.connect().await;
+        conn.execute(
+            "DELETE FROM entities WHERE world_id = $1 AND kind = $2 AND entity_id = $3",
+            &[&world_id, &kind, &entity_id],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to delete entity");
+                false
+            },
+            |count| count == 1,
+        )
+    }
+}
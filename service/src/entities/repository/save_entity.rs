@@ -0,0 +1,17 @@
+/// Errors that can occur when saving an entity record.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveEntityError {
+    #[error("The entity was not found")]
+    UnknownEntity,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl From<tokio_postgres::Error> for SaveEntityError {
+    fn from(e: tokio_postgres::Error) -> Self {
+        tracing::warn!("Unexpected database error: {:?}", e);
+
+        SaveEntityError::UnknownError
+    }
+}
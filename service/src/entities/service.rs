@@ -0,0 +1,150 @@
+mod create_entity;
+mod delete_entity;
+mod get_entity;
+mod update_entity;
+
+use std::sync::Arc;
+
+pub use create_entity::{CreateEntityError, NewEntity};
+pub use update_entity::UpdateEntityError;
+
+use super::{
+    repository::{EntityRecord, EntityRecordResource, EntityRepository},
+    EntityData, EntityId, EntityResource, EntityType,
+};
+use crate::{model::Resource, revisions::RevisionService, search::SearchService, worlds::WorldId};
+
+/// The most levels of containment that are followed when checking that an entity doesn't end up
+/// containing itself.
+const MAX_NESTING: usize = 100;
+
+/// Service layer for working with the typed entities within worlds.
+pub struct EntityService {
+    repository: Arc<dyn EntityRepository>,
+    revisions:  Arc<RevisionService>,
+    search:     Arc<SearchService>,
+}
+
+impl EntityService {
+    /// Create a new entity service.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store entities in
+    /// - `revisions` - The service to record the revision history of entities with
+    /// - `search` - The service to keep the search index of entities up to date with
+    pub fn new(repository: Arc<dyn EntityRepository>, revisions: Arc<RevisionService>, search: Arc<SearchService>) -> Self {
+        Self {
+            repository,
+            revisions,
+            search,
+        }
+    }
+
+    /// Check that everything an entity refers to exists within the world and is of the correct
+    /// type, and that the entity doesn't end up containing itself.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the entity belongs to
+    /// - `entity_id` - The ID of the entity, if it already exists
+    /// - `attributes` - The attributes of the entity
+    ///
+    /// # Returns
+    /// The ID of the first entity that can't be referred to, if any.
+    async fn check_references<A>(&self, world_id: &WorldId, entity_id: Option<&EntityId>, attributes: &A) -> Result<(), EntityId>
+    where
+        A: EntityType,
+    {
+        let references = attributes.references();
+        let ids: Vec<EntityId> = references.iter().map(|r| r.entity_id.clone()).collect();
+        let found = self.repository.get_entities_by_ids(world_id, &ids).await;
+
+        for reference in references {
+            let valid = found
+                .iter()
+                .any(|e| e.identity.id == reference.entity_id && e.data.kind == reference.kind);
+            if !valid {
+                return Err(reference.entity_id);
+            }
+        }
+
+        if let Some(entity_id) = entity_id {
+            let mut current = attributes.parent().cloned();
+            let mut depth = 0;
+            while let Some(parent) = current {
+                if &parent == entity_id {
+                    return Err(parent);
+                }
+
+                depth += 1;
+                if depth >= MAX_NESTING {
+                    break;
+                }
+
+                current = self
+                    .repository
+                    .get_entity(world_id, A::KIND, &parent)
+                    .await
+                    .and_then(decode::<A>)
+                    .and_then(|p| p.data.attributes.parent().cloned());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Interpret a stored entity record as a specific type of entity.
+///
+/// # Parameters
+/// - `record` - The entity record
+///
+/// # Returns
+/// The typed entity, or `None` if the record is for a different type of entity or its attributes
+/// can't be interpreted.
+fn decode<A>(record: EntityRecordResource) -> Option<EntityResource<A>>
+where
+    A: EntityType,
+{
+    if record.data.kind != A::KIND {
+        return None;
+    }
+
+    let entity_id = &record.identity.id;
+    let attributes = serde_json::from_value(record.data.attributes)
+        .map_err(|e| {
+            tracing::warn!(e = ?e, entity_id = ?entity_id, "Failed to interpret entity attributes");
+        })
+        .ok()?;
+
+    Some(Resource {
+        identity: record.identity,
+        data:     EntityData {
+            world_id: record.data.world_id,
+            name: record.data.name,
+            summary: record.data.summary,
+            attributes,
+            custom: match record.data.custom {
+                serde_json::Value::Object(custom) => custom,
+                _ => serde_json::Map::new(),
+            },
+        },
+    })
+}
+
+/// Produce the record to store for a specific type of entity.
+///
+/// # Parameters
+/// - `data` - The data of the entity
+fn encode<A>(data: &EntityData<A>) -> EntityRecord
+where
+    A: EntityType,
+{
+    EntityRecord {
+        world_id:   data.world_id.clone(),
+        kind:       A::KIND.to_owned(),
+        name:       data.name.clone(),
+        summary:    data.summary.clone(),
+        attributes: serde_json::to_value(&data.attributes).unwrap_or_default(),
+        custom:     serde_json::Value::Object(data.custom.clone()),
+    }
+}
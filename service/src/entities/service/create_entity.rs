@@ -0,0 +1,199 @@
+use super::{decode, encode, EntityService};
+use crate::{
+    entities::{CustomFields, EntityData, EntityId, EntityResource, EntityType},
+    users::UserId,
+    worlds::WorldId,
+};
+
+/// Details needed to create a new entity.
+#[derive(Debug)]
+pub struct NewEntity<A> {
+    pub name:       String,
+    pub summary:    Option<String>,
+    pub attributes: A,
+    pub custom:     CustomFields,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum CreateEntityError {
+    #[error("The entity refers to an entity that can't be referred to: {0:?}")]
+    InvalidReference(EntityId),
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl EntityService {
+    /// Create a new entity within a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world to create the entity in
+    /// - `changed_by` - The ID of the user that is creating the entity
+    /// - `entity` - The details of the entity to create
+    ///
+    /// # Returns
+    /// The newly created entity.
+    pub async fn create_entity<A>(
+        &self,
+        world_id: &WorldId,
+        changed_by: &UserId,
+        entity: NewEntity<A>,
+    ) -> Result<EntityResource<A>, CreateEntityError>
+    where
+        A: EntityType,
+    {
+        self.check_references(world_id, None, &entity.attributes)
+            .await
+            .map_err(CreateEntityError::InvalidReference)?;
+
+        let record = encode(&EntityData {
+            world_id:   world_id.clone(),
+            name:       entity.name,
+            summary:    entity.summary,
+            attributes: entity.attributes,
+            custom:     entity.custom,
+        });
+
+        let created = self
+            .repository
+            .create_entity(&record)
+            .await
+            .ok()
+            .and_then(decode::<A>)
+            .ok_or(CreateEntityError::UnknownError)?;
+        self.revisions.record(&created, changed_by).await;
+        self.search.index(&created).await;
+
+        Ok(created)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::{
+        entities::{CharacterAttributes, FactionAttributes, LocationAttributes, MemoryEntityRepository},
+        revisions::{MemoryRevisionRepository, RevisionService},
+        search::{MemorySearchRepository, SearchService},
+    };
+
+    fn build_service() -> EntityService {
+        EntityService::new(
+            Arc::new(MemoryEntityRepository::new()),
+            Arc::new(RevisionService::new(Arc::new(MemoryRevisionRepository::new()))),
+            Arc::new(SearchService::new(Arc::new(MemorySearchRepository::new()))),
+        )
+    }
+
+    fn new_entity<A>(name: &str, attributes: A) -> NewEntity<A> {
+        NewEntity {
+            name: name.to_owned(),
+            summary: None,
+            attributes,
+            custom: CustomFields::new(),
+        }
+    }
+
+    #[actix_rt::test]
+    async fn create_with_references() {
+        let sut = build_service();
+        let world_id = WorldId::default();
+        let user_id = UserId::default();
+
+        let_assert!(
+            Ok(faction) = sut
+                .create_entity(&world_id, &user_id, new_entity("Rangers", FactionAttributes::default()))
+                .await
+        );
+        let_assert!(
+            Ok(character) = sut
+                .create_entity(
+                    &world_id,
+                    &user_id,
+                    new_entity(
+                        "Aragorn",
+                        CharacterAttributes {
+                            species: Some("Human".to_owned()),
+                            affiliations: vec![faction.identity.id.clone()],
+                            ..CharacterAttributes::default()
+                        }
+                    ),
+                )
+                .await
+        );
+
+        check!(character.data.attributes.affiliations == vec![faction.identity.id.clone()]);
+
+        let_assert!(Some(loaded) = sut.get_entity::<CharacterAttributes>(&world_id, &character.identity.id).await);
+        check!(loaded.data.name == "Aragorn");
+        check!(loaded.data.attributes.species == Some("Human".to_owned()));
+
+        check!(sut
+            .get_entity::<FactionAttributes>(&world_id, &character.identity.id)
+            .await
+            .is_none());
+    }
+
+    #[actix_rt::test]
+    async fn create_with_wrong_type_of_reference() {
+        let sut = build_service();
+        let world_id = WorldId::default();
+        let user_id = UserId::default();
+
+        let_assert!(
+            Ok(location) = sut
+                .create_entity(&world_id, &user_id, new_entity("Gondor", LocationAttributes::default()))
+                .await
+        );
+
+        let result = sut
+            .create_entity(
+                &world_id,
+                &user_id,
+                new_entity(
+                    "Aragorn",
+                    CharacterAttributes {
+                        affiliations: vec![location.identity.id.clone()],
+                        ..CharacterAttributes::default()
+                    },
+                ),
+            )
+            .await;
+
+        let_assert!(Err(e) = result);
+        check!(e == CreateEntityError::InvalidReference(location.identity.id));
+    }
+
+    #[actix_rt::test]
+    async fn create_with_reference_to_other_world() {
+        let sut = build_service();
+        let user_id = UserId::default();
+
+        let_assert!(
+            Ok(faction) = sut
+                .create_entity(&WorldId::default(), &user_id, new_entity("Rangers", FactionAttributes::default()))
+                .await
+        );
+
+        let result = sut
+            .create_entity(
+                &WorldId::default(),
+                &user_id,
+                new_entity(
+                    "Aragorn",
+                    CharacterAttributes {
+                        affiliations: vec![faction.identity.id.clone()],
+                        ..CharacterAttributes::default()
+                    },
+                ),
+            )
+            .await;
+
+        let_assert!(Err(e) = result);
+        check!(e == CreateEntityError::InvalidReference(faction.identity.id));
+    }
+}
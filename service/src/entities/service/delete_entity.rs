@@ -0,0 +1,29 @@
+use super::EntityService;
+use crate::{
+    entities::{EntityId, EntityType},
+    worlds::WorldId,
+};
+
+impl EntityService {
+    /// Delete a single entity.
+    /// Any other entities that refer to it are left alone, and will refer to an entity that no
+    /// longer exists.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the entity belongs to
+    /// - `entity_id` - The ID of the entity
+    ///
+    /// # Returns
+    /// True if the entity existed and was deleted. False if not.
+    pub async fn delete_entity<A>(&self, world_id: &WorldId, entity_id: &EntityId) -> bool
+    where
+        A: EntityType,
+    {
+        let deleted = self.repository.delete_entity(world_id, A::KIND, entity_id).await;
+        if deleted {
+            self.search.remove(entity_id).await;
+        }
+
+        deleted
+    }
+}
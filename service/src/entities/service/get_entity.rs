@@ -0,0 +1,53 @@
+use super::{decode, EntityService};
+use crate::{
+    entities::{EntityId, EntityRecordResource, EntityResource, EntityType},
+    worlds::WorldId,
+};
+
+impl EntityService {
+    /// Get a single entity of some type.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the entity belongs to
+    /// - `entity_id` - The ID of the entity
+    ///
+    /// # Returns
+    /// The entity, or `None` if there is no such entity of this type.
+    pub async fn get_entity<A>(&self, world_id: &WorldId, entity_id: &EntityId) -> Option<EntityResource<A>>
+    where
+        A: EntityType,
+    {
+        self.repository.get_entity(world_id, A::KIND, entity_id).await.and_then(decode)
+    }
+
+    /// Get every entity of some type within a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    ///
+    /// # Returns
+    /// The entities, sorted by name.
+    pub async fn get_entities<A>(&self, world_id: &WorldId) -> Vec<EntityResource<A>>
+    where
+        A: EntityType,
+    {
+        self.repository
+            .get_entities(world_id, A::KIND)
+            .await
+            .into_iter()
+            .filter_map(decode)
+            .collect()
+    }
+
+    /// Get the entities with any of the given IDs, whatever type they are.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    /// - `entity_ids` - The IDs of the entities
+    ///
+    /// # Returns
+    /// The records of the entities that exist, in no particular order.
+    pub async fn get_entities_by_ids(&self, world_id: &WorldId, entity_ids: &[EntityId]) -> Vec<EntityRecordResource> {
+        self.repository.get_entities_by_ids(world_id, entity_ids).await
+    }
+}
@@ -0,0 +1,229 @@
+use super::{decode, encode, EntityService};
+use crate::{
+    entities::{EntityData, EntityId, EntityResource, EntityType, SaveEntityError},
+    users::UserId,
+    worlds::WorldId,
+};
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum UpdateEntityError<E>
+where
+    E: std::fmt::Debug,
+{
+    #[error("Unknown entity")]
+    UnknownEntity,
+
+    #[error("The entity refers to an entity that can't be referred to: {0:?}")]
+    InvalidReference(EntityId),
+
+    #[error("An error occurred updating the entity data")]
+    UpdateError(E),
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl EntityService {
+    /// Update the entity that has the provided ID, using the provided lambda to perform the
+    /// updates.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the entity belongs to
+    /// - `entity_id` - The ID of the entity to update
+    /// - `changed_by` - The ID of the user that is making the change
+    /// - `f` - The function to update the entity details
+    ///
+    /// # Returns
+    /// The newly updated entity.
+    pub async fn update_entity_by_id<A, F, E>(
+        &self,
+        world_id: &WorldId,
+        entity_id: &EntityId,
+        changed_by: &UserId,
+        f: F,
+    ) -> Result<EntityResource<A>, UpdateEntityError<E>>
+    where
+        A: EntityType,
+        F: FnOnce(EntityData<A>) -> Result<EntityData<A>, E>,
+        E: std::fmt::Debug,
+    {
+        let entity = self
+            .get_entity::<A>(world_id, entity_id)
+            .await
+            .ok_or(UpdateEntityError::UnknownEntity)?;
+
+        let data = f(entity.data).map_err(UpdateEntityError::UpdateError)?;
+        let data = EntityData {
+            world_id: world_id.clone(),
+            ..data
+        };
+
+        self.check_references(world_id, Some(entity_id), &data.attributes)
+            .await
+            .map_err(UpdateEntityError::InvalidReference)?;
+
+        let result = self.repository.update_entity(entity_id, &encode(&data)).await?;
+        let result = decode::<A>(result).ok_or(UpdateEntityError::UnknownError)?;
+        self.revisions.record(&result, changed_by).await;
+        self.search.index(&result).await;
+
+        Ok(result)
+    }
+}
+
+impl<E> From<SaveEntityError> for UpdateEntityError<E>
+where
+    E: std::fmt::Debug,
+{
+    fn from(e: SaveEntityError) -> Self {
+        match e {
+            SaveEntityError::UnknownEntity => Self::UnknownEntity,
+            SaveEntityError::UnknownError => Self::UnknownError,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::{
+        entities::{CustomFields, LocationAttributes, MemoryEntityRepository, NewEntity},
+        revisions::{MemoryRevisionRepository, RevisionService},
+        search::{MemorySearchRepository, SearchService},
+    };
+
+    async fn build_service() -> (EntityService, WorldId, Vec<EntityResource<LocationAttributes>>) {
+        let sut = EntityService::new(
+            Arc::new(MemoryEntityRepository::new()),
+            Arc::new(RevisionService::new(Arc::new(MemoryRevisionRepository::new()))),
+            Arc::new(SearchService::new(Arc::new(MemorySearchRepository::new()))),
+        );
+        let world_id = WorldId::default();
+
+        // Middle Earth > Gondor > Minas Tirith
+        let mut locations: Vec<EntityResource<LocationAttributes>> = vec![];
+        for name in &["Middle Earth", "Gondor", "Minas Tirith"] {
+            let location = sut
+                .create_entity(
+                    &world_id,
+                    &UserId::default(),
+                    NewEntity {
+                        name:       (*name).to_owned(),
+                        summary:    None,
+                        attributes: LocationAttributes {
+                            parent: locations.last().map(|l| l.identity.id.clone()),
+                            ..LocationAttributes::default()
+                        },
+                        custom:     CustomFields::new(),
+                    },
+                )
+                .await
+                .unwrap();
+            locations.push(location);
+        }
+
+        (sut, world_id, locations)
+    }
+
+    #[actix_rt::test]
+    async fn update_entity() {
+        let (sut, world_id, locations) = build_service().await;
+
+        let result = sut
+            .update_entity_by_id(
+                &world_id,
+                &locations[2].identity.id,
+                &UserId::default(),
+                |data| -> Result<EntityData<LocationAttributes>, ()> {
+                    let mut custom = CustomFields::new();
+                    custom.insert("population".to_owned(), serde_json::json!(100_000));
+
+                    Ok(EntityData {
+                        name: "The White City".to_owned(),
+                        custom,
+                        ..data
+                    })
+                },
+            )
+            .await;
+
+        let_assert!(Ok(updated) = result);
+        check!(updated.identity.version != locations[2].identity.version);
+        check!(updated.data.name == "The White City");
+        check!(updated.data.attributes.parent == Some(locations[1].identity.id.clone()));
+        check!(updated.data.custom.get("population") == Some(&serde_json::json!(100_000)));
+    }
+
+    #[actix_rt::test]
+    async fn update_unknown_entity() {
+        let (sut, world_id, _) = build_service().await;
+
+        let result = sut
+            .update_entity_by_id(
+                &world_id,
+                &EntityId::default(),
+                &UserId::default(),
+                |data| -> Result<EntityData<LocationAttributes>, ()> { Ok(data) },
+            )
+            .await;
+
+        let_assert!(Err(e) = result);
+        check!(e == UpdateEntityError::UnknownEntity);
+    }
+
+    #[actix_rt::test]
+    async fn update_creates_cycle() {
+        let (sut, world_id, locations) = build_service().await;
+
+        let parent = locations[2].identity.id.clone();
+        let result = sut
+            .update_entity_by_id(
+                &world_id,
+                &locations[0].identity.id,
+                &UserId::default(),
+                move |data| -> Result<EntityData<LocationAttributes>, ()> {
+                    Ok(EntityData {
+                        attributes: LocationAttributes {
+                            parent: Some(parent),
+                            ..data.attributes
+                        },
+                        ..data
+                    })
+                },
+            )
+            .await;
+
+        let_assert!(Err(e) = result);
+        check!(e == UpdateEntityError::InvalidReference(locations[0].identity.id.clone()));
+    }
+
+    #[actix_rt::test]
+    async fn update_own_parent() {
+        let (sut, world_id, locations) = build_service().await;
+
+        let parent = locations[1].identity.id.clone();
+        let result = sut
+            .update_entity_by_id(
+                &world_id,
+                &locations[1].identity.id,
+                &UserId::default(),
+                move |data| -> Result<EntityData<LocationAttributes>, ()> {
+                    Ok(EntityData {
+                        attributes: LocationAttributes {
+                            parent: Some(parent),
+                            ..data.attributes
+                        },
+                        ..data
+                    })
+                },
+            )
+            .await;
+
+        let_assert!(Err(e) = result);
+        check!(e == UpdateEntityError::InvalidReference(locations[1].identity.id.clone()));
+    }
+}
@@ -1,4 +1,9 @@
+pub mod format;
 pub mod model;
+pub mod multipart;
+pub mod negotiation;
+pub mod pagination;
+pub mod patch;
 pub mod problem;
 pub mod response;
 pub mod valid;
@@ -0,0 +1,258 @@
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+/// A format that request and response bodies can be written in.
+///
+/// Every format carries the same data as the JSON representation. Bodies are converted through
+/// JSON on the way to and from the other formats, so that they look the same whichever is used.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Json,
+    Cbor,
+    MessagePack,
+    Yaml,
+}
+
+/// Every supported format, in order of preference when a client is happy with more than one.
+const FORMATS: [Format; 4] = [Format::Json, Format::Cbor, Format::MessagePack, Format::Yaml];
+
+/// Errors that can occur when converting a body to or from a format.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum FormatError {
+    #[error("Failed to encode body: {0}")]
+    Encode(String),
+
+    #[error("Failed to decode body: {0}")]
+    Decode(String),
+}
+
+impl Format {
+    /// The media type to label bodies in this format with.
+    pub fn media_type(self) -> &'static str {
+        self.media_types()[0]
+    }
+
+    /// Every media type that identifies this format, starting with the standard one.
+    fn media_types(self) -> &'static [&'static str] {
+        match self {
+            Format::Json => &["application/json"],
+            Format::Cbor => &["application/cbor"],
+            Format::MessagePack => &["application/msgpack", "application/x-msgpack", "application/vnd.msgpack"],
+            Format::Yaml => &["application/yaml", "application/x-yaml", "text/yaml"],
+        }
+    }
+
+    /// Determine the format of a request body from its content type.
+    ///
+    /// # Parameters
+    /// - `content_type` - The media type of the body, without any parameters
+    ///
+    /// # Returns
+    /// The format, or `None` if the content type isn't one of the supported formats.
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        FORMATS
+            .iter()
+            .copied()
+            .find(|format| format.media_types().iter().any(|m| m.eq_ignore_ascii_case(content_type)))
+    }
+
+    /// Choose the format to respond in, from the `Accept` header of the request.
+    ///
+    /// Each format is given the quality of the most specific media range that matches it, and the
+    /// format with the highest quality wins. Formats of equal quality are chosen in order of
+    /// preference.
+    ///
+    /// # Parameters
+    /// - `accept` - The value of the `Accept` header, if there was one
+    ///
+    /// # Returns
+    /// The format to respond in, or `None` if the client won't accept any of the supported formats.
+    pub fn negotiate(accept: Option<&str>) -> Option<Self> {
+        let accept = match accept.map(str::trim) {
+            None | Some("") => return Some(Format::Json),
+            Some(accept) => accept,
+        };
+
+        let ranges: Vec<(String, f32)> = accept.split(',').filter_map(parse_media_range).collect();
+
+        let mut best: Option<(Format, f32)> = None;
+        for format in &FORMATS {
+            let quality = ranges
+                .iter()
+                .filter_map(|(range, quality)| format.specificity(range).map(|specificity| (specificity, *quality)))
+                .max_by_key(|(specificity, _)| *specificity)
+                .map_or(0.0, |(_, quality)| quality);
+
+            if quality > 0.0 && best.is_none_or(|(_, q)| quality > q) {
+                best = Some((*format, quality));
+            }
+        }
+
+        best.map(|(format, _)| format)
+    }
+
+    /// Determine how specifically a media range matches this format.
+    ///
+    /// # Returns
+    /// Higher numbers for closer matches, or `None` if the range doesn't match at all.
+    fn specificity(self, range: &str) -> Option<u8> {
+        if range == "*/*" {
+            Some(1)
+        } else if self.media_types().iter().any(|m| m.eq_ignore_ascii_case(range)) {
+            Some(3)
+        } else if let Some(prefix) = range.strip_suffix("/*") {
+            self.media_types()
+                .iter()
+                .any(|m| m.split('/').next().is_some_and(|t| t.eq_ignore_ascii_case(prefix)))
+                .then_some(2)
+        } else {
+            None
+        }
+    }
+
+    /// Write a value in this format.
+    ///
+    /// # Parameters
+    /// - `value` - The value to write
+    ///
+    /// # Returns
+    /// The encoded value.
+    pub fn encode<T>(self, value: &T) -> Result<Vec<u8>, FormatError>
+    where
+        T: Serialize,
+    {
+        let encode_error = |e: &dyn std::error::Error| FormatError::Encode(e.to_string());
+        let json = || serde_json::to_value(value).map_err(|e| encode_error(&e));
+
+        match self {
+            Format::Json => serde_json::to_vec(value).map_err(|e| encode_error(&e)),
+            Format::Cbor => serde_cbor::to_vec(&json()?).map_err(|e| encode_error(&e)),
+            Format::MessagePack => rmp_serde::to_vec_named(&json()?).map_err(|e| encode_error(&e)),
+            Format::Yaml => serde_yaml::to_vec(&json()?).map_err(|e| encode_error(&e)),
+        }
+    }
+
+    /// Read a value that was written in this format.
+    ///
+    /// # Parameters
+    /// - `body` - The encoded value
+    ///
+    /// # Returns
+    /// The decoded value.
+    pub fn decode<T>(self, body: &[u8]) -> Result<T, FormatError>
+    where
+        T: DeserializeOwned,
+    {
+        let decode_error = |e: &dyn std::error::Error| FormatError::Decode(e.to_string());
+
+        let value: Value = match self {
+            Format::Json => serde_json::from_slice(body).map_err(|e| decode_error(&e))?,
+            Format::Cbor => serde_cbor::from_slice(body).map_err(|e| decode_error(&e))?,
+            Format::MessagePack => rmp_serde::from_slice(body).map_err(|e| decode_error(&e))?,
+            Format::Yaml => serde_yaml::from_slice(body).map_err(|e| decode_error(&e))?,
+        };
+
+        serde_json::from_value(value).map_err(|e| decode_error(&e))
+    }
+}
+
+/// Parse a single media range from an `Accept` header into the range itself and its quality.
+fn parse_media_range(range: &str) -> Option<(String, f32)> {
+    let mut parts = range.split(';');
+    let media_range = parts.next()?.trim().to_ascii_lowercase();
+    if media_range.is_empty() {
+        return None;
+    }
+
+    let quality = parts
+        .filter_map(|param| param.split_once('='))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("q"))
+        .map_or(Some(1.0), |(_, value)| value.trim().parse::<f32>().ok())?;
+
+    Some((media_range, quality.clamp(0.0, 1.0)))
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use serde::Deserialize;
+    use serde_json::json;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(None, Some(Format::Json) ; "No header")]
+    #[test_case(Some(""), Some(Format::Json) ; "Blank header")]
+    #[test_case(Some("*/*"), Some(Format::Json) ; "Anything")]
+    #[test_case(Some("application/*"), Some(Format::Json) ; "Any application type")]
+    #[test_case(Some("application/json"), Some(Format::Json) ; "JSON")]
+    #[test_case(Some("application/cbor"), Some(Format::Cbor) ; "CBOR")]
+    #[test_case(Some("application/msgpack"), Some(Format::MessagePack) ; "MessagePack")]
+    #[test_case(Some("application/x-msgpack"), Some(Format::MessagePack) ; "MessagePack alias")]
+    #[test_case(Some("application/yaml"), Some(Format::Yaml) ; "YAML")]
+    #[test_case(Some("text/*"), Some(Format::Yaml) ; "Any text type")]
+    #[test_case(Some("Application/CBOR"), Some(Format::Cbor) ; "Case insensitive")]
+    #[test_case(Some("application/yaml, application/json"), Some(Format::Json) ; "Equal quality")]
+    #[test_case(Some("application/json;q=0.5, application/yaml"), Some(Format::Yaml) ; "Higher quality")]
+    #[test_case(Some("application/json; q=0.9, application/cbor; q=0.95"), Some(Format::Cbor) ; "Fractional quality")]
+    #[test_case(Some("*/*;q=0.1, application/json;q=0"), Some(Format::Cbor) ; "Excluded format")]
+    #[test_case(Some("text/html, */*;q=0.8"), Some(Format::Json) ; "Browser")]
+    #[test_case(Some("text/html"), None ; "Unsupported format")]
+    #[test_case(Some("application/json;q=0"), None ; "Everything excluded")]
+    #[test_case(Some("application/json;q=abc"), None ; "Malformed quality")]
+    fn test_negotiate(accept: Option<&str>, expected: Option<Format>) {
+        check!(Format::negotiate(accept) == expected);
+    }
+
+    #[test_case("application/json", Some(Format::Json) ; "JSON")]
+    #[test_case("application/cbor", Some(Format::Cbor) ; "CBOR")]
+    #[test_case("application/vnd.msgpack", Some(Format::MessagePack) ; "MessagePack")]
+    #[test_case("text/yaml", Some(Format::Yaml) ; "YAML")]
+    #[test_case("text/plain", None ; "Unsupported")]
+    fn test_from_content_type(content_type: &str, expected: Option<Format>) {
+        check!(Format::from_content_type(content_type) == expected);
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Body {
+        display_name: String,
+        tags:         Vec<String>,
+        #[serde(flatten)]
+        extra:        std::collections::HashMap<String, u32>,
+    }
+
+    #[test_case(Format::Json ; "JSON")]
+    #[test_case(Format::Cbor ; "CBOR")]
+    #[test_case(Format::MessagePack ; "MessagePack")]
+    #[test_case(Format::Yaml ; "YAML")]
+    fn test_round_trip(format: Format) {
+        let body = Body {
+            display_name: "Test User".to_owned(),
+            tags:         vec!["a".to_owned(), "b".to_owned()],
+            extra:        vec![("count".to_owned(), 3)].into_iter().collect(),
+        };
+
+        let_assert!(Ok(encoded) = format.encode(&body));
+        let_assert!(Ok(decoded) = format.decode::<Body>(&encoded));
+        check!(decoded == body);
+
+        let_assert!(Ok(value) = format.decode::<Value>(&encoded));
+        check!(value == json!({"displayName": "Test User", "tags": ["a", "b"], "count": 3}));
+    }
+
+    #[test]
+    fn test_yaml_output() {
+        let_assert!(Ok(encoded) = Format::Yaml.encode(&json!({"name": "Test", "tags": ["a"]})));
+
+        check!(String::from_utf8(encoded).unwrap() == "---\nname: Test\ntags:\n  - a\n");
+    }
+
+    #[test_case(Format::Json ; "JSON")]
+    #[test_case(Format::Cbor ; "CBOR")]
+    #[test_case(Format::MessagePack ; "MessagePack")]
+    #[test_case(Format::Yaml ; "YAML")]
+    fn test_decode_garbage(format: Format) {
+        let_assert!(Err(FormatError::Decode(_)) = format.decode::<Body>(b"\xff\x00{[garbage"));
+    }
+}
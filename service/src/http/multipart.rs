@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use actix_multipart::{Field, Multipart};
+use bytes::{Bytes, BytesMut};
+use futures::TryStreamExt;
+
+use super::problem::{Problem, BAD_REQUEST, PAYLOAD_TOO_LARGE};
+
+/// The name of the form field that holds the uploaded file.
+pub const FILE_FIELD: &str = "file";
+
+/// The largest value that any other form field can have, in bytes.
+const MAX_FIELD_SIZE: usize = 10 * 1024;
+
+/// A file that was uploaded as part of a multipart form.
+#[derive(Debug)]
+pub struct UploadedFile {
+    /// The filename that the client provided for the file, if any.
+    pub filename: Option<String>,
+    pub data:     Bytes,
+}
+
+/// The contents of a `multipart/form-data` request, consisting of at most one file and any number
+/// of simple text fields.
+///
+/// Unlike `Valid<T>`, this doesn't validate the fields against a schema. The handler is responsible
+/// for checking each field that it uses.
+#[derive(Debug)]
+pub struct MultipartForm {
+    pub file:   Option<UploadedFile>,
+    pub fields: HashMap<String, String>,
+}
+
+impl MultipartForm {
+    /// Read the entire form from the request.
+    ///
+    /// # Parameters
+    /// - `payload` - The multipart payload of the request
+    /// - `max_size` - The largest file that is permitted, in bytes
+    ///
+    /// # Returns
+    /// The form, or a problem if it was malformed or too large.
+    pub async fn read(mut payload: Multipart, max_size: usize) -> Result<Self, Problem> {
+        let mut form = Self {
+            file:   None,
+            fields: HashMap::new(),
+        };
+
+        while let Some(field) = payload.try_next().await.map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to parse multipart form");
+
+            BAD_REQUEST
+        })? {
+            let disposition = field.content_disposition().ok_or(BAD_REQUEST)?;
+            let name = disposition.get_name().ok_or(BAD_REQUEST)?.to_owned();
+
+            if name == FILE_FIELD {
+                if form.file.is_some() {
+                    return Err(Problem::from(BAD_REQUEST).with_detail("Only a single file may be uploaded"));
+                }
+
+                let filename = disposition.get_filename().map(ToOwned::to_owned);
+                let data = read_field(field, max_size).await?;
+                form.file = Some(UploadedFile { filename, data });
+            } else {
+                let data = read_field(field, MAX_FIELD_SIZE).await?;
+                let value = String::from_utf8(data.to_vec()).map_err(|_| BAD_REQUEST)?;
+                form.fields.insert(name, value);
+            }
+        }
+
+        Ok(form)
+    }
+
+    /// Get the value of a text field from the form.
+    ///
+    /// # Parameters
+    /// - `name` - The name of the field
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.fields.get(name).map(String::as_str).filter(|value| !value.trim().is_empty())
+    }
+}
+
+/// Read the entire contents of a single field, stopping as soon as it becomes too large.
+///
+/// # Parameters
+/// - `field` - The field to read
+/// - `max_size` - The largest that the field can be, in bytes
+async fn read_field(mut field: Field, max_size: usize) -> Result<Bytes, Problem> {
+    let mut data = BytesMut::new();
+
+    while let Some(chunk) = field.try_next().await.map_err(|e| {
+        tracing::warn!(e = ?e, "Failed to read multipart field");
+
+        BAD_REQUEST
+    })? {
+        if data.len() + chunk.len() > max_size {
+            return Err(Problem::from(PAYLOAD_TOO_LARGE).with_extra("maxSize", max_size));
+        }
+
+        data.extend_from_slice(&chunk);
+    }
+
+    Ok(data.freeze())
+}
@@ -0,0 +1,118 @@
+use std::pin::Pin;
+
+use actix_http::http::{header, Method};
+use actix_service::{Service, Transform};
+use actix_web::{
+    dev::{Body, MessageBody, ResponseBody, ServiceRequest, ServiceResponse},
+    Error, HttpResponse,
+};
+use futures::{
+    future::{ok, Ready},
+    Future,
+};
+
+use super::{
+    format::Format,
+    problem::{Problem, NOT_ACCEPTABLE},
+};
+
+/// Middleware that rejects `POST`, `PUT` and `PATCH` requests that won't accept any of the
+/// supported response formats, before they are handled.
+///
+/// Responses are only encoded once the handler has finished, by which time any change that the
+/// request makes has already been made. Rejecting these requests up front means that a client is
+/// never told that a request failed when it actually succeeded. Safe requests have nothing to undo,
+/// and `DELETE` requests never respond with a body, so they are left to the handlers.
+pub struct Negotiate;
+
+impl<S, B> Transform<S, ServiceRequest> for Negotiate
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: MessageBody + Unpin + 'static,
+{
+    type Response = ServiceResponse<Body>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = Middleware<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(Middleware { service })
+    }
+}
+
+/// Actual middleware implementation.
+pub struct Middleware<S> {
+    service: S,
+}
+
+impl<S, B> Service<ServiceRequest> for Middleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: MessageBody + Unpin + 'static,
+{
+    type Response = ServiceResponse<Body>;
+    type Error = Error;
+    #[allow(clippy::type_complexity)]
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
+
+    actix_service::forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        if [Method::POST, Method::PUT, Method::PATCH].contains(req.method()) {
+            let accept = req.headers().get(header::ACCEPT).and_then(|value| value.to_str().ok());
+            if Format::negotiate(accept).is_none() {
+                tracing::warn!(accept = ?accept, "No acceptable response format");
+                return Box::pin(ok(req.into_response(HttpResponse::from(Problem::from(NOT_ACCEPTABLE)))));
+            }
+        }
+
+        let fut = self.service.call(req);
+
+        Box::pin(async move {
+            let res = fut.await?;
+
+            Ok(res.map_body(|_, body| ResponseBody::Other(Body::from_message(body))))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use actix_web::{test, web, App};
+    use assert2::check;
+    use test_case::test_case;
+
+    use super::*;
+
+    async fn handler(counter: web::Data<AtomicUsize>) -> HttpResponse {
+        counter.fetch_add(1, Ordering::SeqCst);
+
+        HttpResponse::Created().finish()
+    }
+
+    #[test_case(Method::POST, "text/html", 406, 0 ; "Unsafe request with unsupported format")]
+    #[test_case(Method::PATCH, "application/json;q=0", 406, 0 ; "Unsafe request excluding every format")]
+    #[test_case(Method::PUT, "application/cbor", 201, 1 ; "Unsafe request with supported format")]
+    #[test_case(Method::GET, "text/html", 201, 1 ; "Safe request")]
+    #[test_case(Method::DELETE, "text/html", 201, 1 ; "Delete request")]
+    #[actix_rt::test]
+    async fn reject_before_handling(method: Method, accept: &str, expected_status: u16, expected_calls: usize) {
+        let counter = web::Data::new(AtomicUsize::new(0));
+        let app = test::init_service(App::new().app_data(counter.clone()).wrap(Negotiate).route("/", web::to(handler))).await;
+        let req = test::TestRequest::default()
+            .method(method)
+            .uri("/")
+            .insert_header((header::ACCEPT, accept))
+            .to_request();
+
+        let response = test::call_service(&app, req).await;
+
+        check!(response.status() == expected_status);
+        check!(counter.load(Ordering::SeqCst) == expected_calls);
+    }
+}
@@ -0,0 +1,274 @@
+use std::{convert::TryFrom, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+/// The number of items on a page when the client doesn't ask for a specific number.
+const DEFAULT_LIMIT: u32 = 20;
+
+/// The most items that can be requested on a single page.
+const MAX_LIMIT: u32 = 100;
+
+/// Query parameters for requesting a single page of a longer list.
+///
+/// These are extracted separately from any other query parameters of an endpoint, since
+/// flattening them into another struct stops the numbers from being parsed.
+#[derive(Debug, Default, Deserialize)]
+pub struct PageQuery {
+    /// The number of items to skip before the page starts.
+    pub offset: Option<u32>,
+    /// The most items to include on the page.
+    pub limit:  Option<u32>,
+}
+
+/// The page of a list that should be returned.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pagination {
+    pub offset: u32,
+    pub limit:  u32,
+}
+
+impl PageQuery {
+    /// Determine the page that was requested, filling in the defaults and keeping the size of the
+    /// page within bounds.
+    pub fn pagination(&self) -> Pagination {
+        Pagination {
+            offset: self.offset.unwrap_or_default(),
+            limit:  self.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT),
+        }
+    }
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Self {
+            offset: 0,
+            limit:  DEFAULT_LIMIT,
+        }
+    }
+}
+
+impl Pagination {
+    /// Select the page from a complete list of items.
+    ///
+    /// # Parameters
+    /// - `items` - All of the items, in order
+    ///
+    /// # Returns
+    /// The items on the page.
+    pub fn apply<T, I>(self, items: I) -> Vec<T>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let offset = usize::try_from(self.offset).unwrap_or(usize::MAX);
+        let limit = usize::try_from(self.limit).unwrap_or(usize::MAX);
+
+        items.into_iter().skip(offset).take(limit).collect()
+    }
+}
+
+/// Representation on the HTTP API of where a page sits within the complete list.
+#[derive(Debug, Serialize)]
+pub struct PageModel {
+    pub offset: u32,
+    pub limit:  u32,
+    /// The number of items in the complete list.
+    pub total:  u64,
+}
+
+impl PageModel {
+    /// Build the representation of a page.
+    ///
+    /// # Parameters
+    /// - `pagination` - The page that was returned
+    /// - `total` - The number of items in the complete list
+    pub fn new(pagination: Pagination, total: u64) -> Self {
+        Self {
+            offset: pagination.offset,
+            limit: pagination.limit,
+            total,
+        }
+    }
+}
+
+/// Query parameters for requesting a single page of a list that is paged by cursor, where each
+/// page starts immediately after the last item of the previous one.
+///
+/// Unlike paging by offset, this keeps the pages stable while items are being added to the list.
+#[derive(Debug, Default, Deserialize)]
+pub struct CursorQuery {
+    /// The cursor of the last item on the previous page, if this isn't the first page.
+    pub after: Option<String>,
+    /// The most items to include on the page.
+    pub limit: Option<u32>,
+}
+
+/// The page of a list that is paged by cursor that should be returned.
+///
+/// # Types
+/// - `<C>` - The type of the cursor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CursorPagination<C> {
+    pub after: Option<C>,
+    pub limit: u32,
+}
+
+/// A single page of a list that is paged by cursor.
+///
+/// # Types
+/// - `<T>` - The type of the items.
+/// - `<C>` - The type of the cursor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CursorPage<T, C> {
+    pub items: Vec<T>,
+    /// The cursor to request the next page with, or `None` if this is the last page.
+    pub next:  Option<C>,
+}
+
+impl CursorQuery {
+    /// Determine the page that was requested, filling in the defaults and keeping the size of the
+    /// page within bounds.
+    ///
+    /// # Returns
+    /// The page, or an error if the cursor isn't valid.
+    pub fn pagination<C>(&self) -> Result<CursorPagination<C>, C::Err>
+    where
+        C: FromStr,
+    {
+        let after = self.after.as_deref().map(str::parse).transpose()?;
+
+        Ok(CursorPagination {
+            after,
+            limit: self.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT),
+        })
+    }
+}
+
+impl<C> Default for CursorPagination<C> {
+    fn default() -> Self {
+        Self {
+            after: None,
+            limit: DEFAULT_LIMIT,
+        }
+    }
+}
+
+impl<C> CursorPagination<C> {
+    /// The number of items to load for the page. This is one more than will be returned, so that
+    /// it is known whether there is another page after this one.
+    pub fn fetch_limit(&self) -> u32 {
+        self.limit.saturating_add(1)
+    }
+
+    /// Build the page from the items that were loaded for it.
+    ///
+    /// # Parameters
+    /// - `items` - The items, in order, up to the fetch limit of them
+    /// - `cursor` - Function to get the cursor of an item
+    ///
+    /// # Returns
+    /// The page of items.
+    pub fn page<T, F>(&self, mut items: Vec<T>, cursor: F) -> CursorPage<T, C>
+    where
+        F: Fn(&T) -> C,
+    {
+        let limit = usize::try_from(self.limit).unwrap_or(usize::MAX);
+        let next = if items.len() > limit {
+            items.truncate(limit);
+            items.last().map(cursor)
+        } else {
+            None
+        };
+
+        CursorPage { items, next }
+    }
+}
+
+/// Representation on the HTTP API of how to continue on from a page that is paged by cursor.
+#[derive(Debug, Serialize)]
+pub struct CursorModel {
+    pub limit: u32,
+    /// The cursor to pass as `after` to request the next page, or `None` if this is the last page.
+    pub next:  Option<String>,
+}
+
+impl CursorModel {
+    /// Build the representation of a page.
+    ///
+    /// # Parameters
+    /// - `pagination` - The page that was requested
+    /// - `next` - The cursor of the next page, if there is one
+    pub fn new<C>(pagination: &CursorPagination<C>, next: Option<&C>) -> Self
+    where
+        C: ToString,
+    {
+        Self {
+            limit: pagination.limit,
+            next:  next.map(ToString::to_string),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(None, None, 0, 20 ; "Defaults")]
+    #[test_case(Some(40), Some(10), 40, 10 ; "Provided")]
+    #[test_case(None, Some(0), 0, 1 ; "Empty page")]
+    #[test_case(None, Some(1000), 0, 100 ; "Oversized page")]
+    fn test_pagination(offset: Option<u32>, limit: Option<u32>, expected_offset: u32, expected_limit: u32) {
+        let pagination = PageQuery { offset, limit }.pagination();
+
+        check!(pagination.offset == expected_offset);
+        check!(pagination.limit == expected_limit);
+    }
+
+    #[test_case(0, 2, &[1, 2] ; "First page")]
+    #[test_case(2, 2, &[3, 4] ; "Middle page")]
+    #[test_case(4, 2, &[5] ; "Last page")]
+    #[test_case(10, 2, &[] ; "Beyond the end")]
+    fn test_apply(offset: u32, limit: u32, expected: &[i32]) {
+        let page = Pagination { offset, limit }.apply(vec![1, 2, 3, 4, 5]);
+
+        check!(page == expected);
+    }
+
+    #[test_case(None, None, None, 20 ; "Defaults")]
+    #[test_case(Some("5"), Some(10), Some(5), 10 ; "Provided")]
+    #[test_case(None, Some(1000), None, 100 ; "Oversized page")]
+    fn test_cursor_pagination(after: Option<&str>, limit: Option<u32>, expected_after: Option<u32>, expected_limit: u32) {
+        let query = CursorQuery {
+            after: after.map(str::to_owned),
+            limit,
+        };
+        let pagination = query.pagination::<u32>().unwrap();
+
+        check!(pagination.after == expected_after);
+        check!(pagination.limit == expected_limit);
+    }
+
+    #[test]
+    fn test_cursor_pagination_invalid() {
+        let query = CursorQuery {
+            after: Some("nope".to_owned()),
+            limit: None,
+        };
+
+        check!(query.pagination::<u32>().is_err());
+    }
+
+    #[test_case(vec![1, 2], None ; "Last page")]
+    #[test_case(vec![1, 2, 3], Some(2) ; "More pages")]
+    fn test_cursor_page(items: Vec<u32>, expected_next: Option<u32>) {
+        let pagination = CursorPagination::<u32> { after: None, limit: 2 };
+        check!(pagination.fetch_limit() == 3);
+
+        let page = pagination.page(items, |item| *item);
+
+        check!(page.items == vec![1, 2]);
+        check!(page.next == expected_next);
+    }
+}
@@ -0,0 +1,322 @@
+mod json_patch;
+mod merge_patch;
+
+use std::pin::Pin;
+
+use actix_http::{http::StatusCode, Payload};
+use actix_web::{web::Json, FromRequest, HttpMessage, HttpRequest};
+use futures::{future::err, Future};
+pub use json_patch::{PatchError, PatchOperation};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use super::{
+    problem::{Problem, SimpleProblemType, BAD_REQUEST, UNPROCESSABLE_ENTITY, UNSUPPORTED_MEDIA_TYPE},
+    valid::{validate, Validatable},
+};
+
+/// The media type of a JSON Merge Patch.
+const MERGE_PATCH: &str = "application/merge-patch+json";
+
+/// The media type of a JSON Patch.
+const JSON_PATCH: &str = "application/json-patch+json";
+
+/// The changes to make to a resource, from the body of a `PATCH` request.
+///
+/// Requests can be sent as either `application/merge-patch+json` (RFC 7396) or
+/// `application/json-patch+json` (RFC 6902). Plain `application/json` is treated as a merge patch.
+///
+/// Unlike `Valid<T>`, the request itself isn't validated. The patch is applied to the serialized
+/// resource instead, and it's the result that gets validated.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Patch {
+    /// A JSON Merge Patch.
+    Merge(Value),
+
+    /// A JSON Patch.
+    Json(Vec<PatchOperation>),
+}
+
+impl Patch {
+    /// Apply the patch to a JSON document.
+    ///
+    /// # Parameters
+    /// - `target` - The document to patch
+    ///
+    /// # Errors
+    /// If the patch couldn't be applied. The document is left untouched in this case.
+    pub fn apply(&self, target: &mut Value) -> Result<(), PatchError> {
+        match self {
+            Patch::Merge(patch) => {
+                merge_patch::merge(target, patch);
+                Ok(())
+            },
+            Patch::Json(operations) => json_patch::apply(target, operations),
+        }
+    }
+
+    /// Apply the patch to a resource, by way of its serialized form, and validate the result.
+    ///
+    /// # Parameters
+    /// - `resource` - The resource to patch
+    ///
+    /// # Returns
+    /// The patched resource, or a problem if the patch couldn't be applied or the result wasn't
+    /// valid.
+    pub fn apply_to<T>(&self, resource: &T) -> Result<T, Problem>
+    where
+        T: Serialize + DeserializeOwned + Validatable,
+    {
+        let mut value = serde_json::to_value(resource).map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to serialize resource to patch");
+
+            UNPROCESSABLE_ENTITY
+        })?;
+
+        self.apply(&mut value).map_err(|e| {
+            tracing::debug!(e = ?e, "Failed to apply patch");
+
+            match e {
+                PatchError::TestFailed(_) => Problem::from(PATCH_TEST_FAILED).with_detail(e.to_string()),
+                _ => Problem::from(INVALID_PATCH).with_detail(e.to_string()),
+            }
+        })?;
+
+        validate::<T>(&value)?;
+
+        serde_json::from_value(value).map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to parse patched JSON as correct type");
+
+            Problem::from(UNPROCESSABLE_ENTITY)
+        })
+    }
+}
+
+impl FromRequest for Patch {
+    type Config = ();
+    type Error = Problem;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let content_type = req.content_type();
+
+        if content_type.eq_ignore_ascii_case(JSON_PATCH) {
+            let operations = Json::<Vec<PatchOperation>>::from_request(req, payload);
+
+            Box::pin(async move {
+                let operations = operations.await.map_err(|e| {
+                    tracing::warn!(e = ?e, "Failed to parse request as JSON Patch");
+
+                    BAD_REQUEST
+                })?;
+
+                Ok(Patch::Json(operations.0))
+            })
+        } else if content_type.eq_ignore_ascii_case(MERGE_PATCH) || content_type.eq_ignore_ascii_case("application/json") {
+            let patch = Json::<Value>::from_request(req, payload);
+
+            Box::pin(async move {
+                let patch = patch.await.map_err(|e| {
+                    tracing::warn!(e = ?e, "Failed to parse request as JSON Merge Patch");
+
+                    BAD_REQUEST
+                })?;
+
+                Ok(Patch::Merge(patch.0))
+            })
+        } else {
+            tracing::warn!(content_type = content_type, "Unsupported patch format");
+
+            Box::pin(err(UNSUPPORTED_MEDIA_TYPE.into()))
+        }
+    }
+}
+
+/// Problem to indicate that a patch couldn't be applied to the resource.
+pub const INVALID_PATCH: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/patch/invalid",
+    problem_title: "The patch could not be applied",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate that a `test` operation in a patch didn't match the resource.
+pub const PATCH_TEST_FAILED: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/patch/test_failed",
+    problem_title: "A test operation in the patch failed",
+    status_code:   StatusCode::CONFLICT,
+};
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{test, web, App, HttpResponse};
+    use assert2::check;
+    use insta::assert_json_snapshot;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    pub struct Resource {
+        pub name:    String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub summary: Option<String>,
+    }
+
+    impl Validatable for Resource {
+        fn schema() -> Value {
+            json!({
+              "type": "object",
+              "properties": {
+                "name": {
+                  "type": "string",
+                  "minLength": 1,
+                  "maxLength": 5
+                },
+                "summary": {
+                  "type": "string"
+                }
+              },
+              "required": ["name"]
+            })
+        }
+    }
+
+    async fn test_req(patch: Patch) -> Result<HttpResponse, Problem> {
+        let resource = Resource {
+            name:    "Old".to_owned(),
+            summary: Some("Summary".to_owned()),
+        };
+
+        let patched = patch.apply_to(&resource)?;
+
+        Ok(HttpResponse::Ok().json(patched))
+    }
+
+    async fn send(content_type: &str, body: &Value) -> (StatusCode, Value) {
+        let _ = env_logger::try_init();
+
+        let app = test::init_service(App::new().route("/", web::patch().to(test_req))).await;
+        let req = test::TestRequest::patch()
+            .uri("/")
+            .insert_header(("content-type", content_type))
+            .set_payload(body.to_string())
+            .to_request();
+        let response = test::call_service(&app, req).await;
+        let status = response.status();
+        let body = test::read_body(response).await;
+
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[actix_rt::test]
+    async fn merge_patch() {
+        let (status, body) = send(MERGE_PATCH, &json!({"name": "New", "summary": null})).await;
+
+        check!(status == 200);
+        check!(body == json!({"name": "New"}));
+    }
+
+    #[actix_rt::test]
+    async fn plain_json_is_merge_patch() {
+        let (status, body) = send("application/json", &json!({"summary": "Other"})).await;
+
+        check!(status == 200);
+        check!(body == json!({"name": "Old", "summary": "Other"}));
+    }
+
+    #[actix_rt::test]
+    async fn json_patch() {
+        let (status, body) = send(
+            JSON_PATCH,
+            &json!([
+                {"op": "test", "path": "/name", "value": "Old"},
+                {"op": "replace", "path": "/name", "value": "New"},
+                {"op": "remove", "path": "/summary"}
+            ]),
+        )
+        .await;
+
+        check!(status == 200);
+        check!(body == json!({"name": "New"}));
+    }
+
+    #[actix_rt::test]
+    async fn json_patch_test_failed() {
+        let (status, body) = send(
+            JSON_PATCH,
+            &json!([
+                {"op": "test", "path": "/name", "value": "Other"},
+                {"op": "replace", "path": "/name", "value": "New"}
+            ]),
+        )
+        .await;
+
+        check!(status == 409);
+        assert_json_snapshot!(body, @r###"
+        {
+          "type": "tag:worlds,2021:problems/patch/test_failed",
+          "title": "A test operation in the patch failed",
+          "status": 409,
+          "detail": "The value at the path \"/name\" didn't match"
+        }
+        "###);
+    }
+
+    #[actix_rt::test]
+    async fn json_patch_missing_target() {
+        let (status, body) = send(JSON_PATCH, &json!([{"op": "remove", "path": "/other"}])).await;
+
+        check!(status == 422);
+        assert_json_snapshot!(body, @r###"
+        {
+          "type": "tag:worlds,2021:problems/patch/invalid",
+          "title": "The patch could not be applied",
+          "status": 422,
+          "detail": "Nothing exists at the path \"/other\""
+        }
+        "###);
+    }
+
+    #[actix_rt::test]
+    async fn malformed_json_patch() {
+        let (status, _) = send(JSON_PATCH, &json!({"op": "remove", "path": "/summary"})).await;
+
+        check!(status == 400);
+    }
+
+    #[actix_rt::test]
+    async fn patched_resource_is_validated() {
+        let (status, body) = send(MERGE_PATCH, &json!({"name": null})).await;
+
+        check!(status == 422);
+        assert_json_snapshot!(body, @r###"
+        {
+          "type": "tag:worlds,2021:problems/validation",
+          "title": "Request body failed validation",
+          "status": 422,
+          "validationErrors": [
+            {
+              "code": "required",
+              "title": "This property is required",
+              "path": "/name"
+            }
+          ]
+        }
+        "###);
+    }
+
+    #[actix_rt::test]
+    async fn unsupported_media_type() {
+        let (status, body) = send("text/plain", &json!({"name": "New"})).await;
+
+        check!(status == 415);
+        assert_json_snapshot!(body, @r###"
+        {
+          "type": "about:blank",
+          "title": "Unsupported Media Type",
+          "status": 415
+        }
+        "###);
+    }
+}
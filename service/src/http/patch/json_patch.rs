@@ -0,0 +1,236 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+/// A single operation of a JSON Patch, as described by RFC 6902.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum PatchOperation {
+    Add { path: String, value: Value },
+    Remove { path: String },
+    Replace { path: String, value: Value },
+    Move { from: String, path: String },
+    Copy { from: String, path: String },
+    Test { path: String, value: Value },
+}
+
+/// Errors that can occur when applying a JSON Patch.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum PatchError {
+    #[error("The path \"{0}\" is not a valid JSON Pointer")]
+    InvalidPointer(String),
+
+    #[error("Nothing exists at the path \"{0}\"")]
+    MissingTarget(String),
+
+    #[error("The path \"{0}\" can't be moved into one of its own children")]
+    InvalidMove(String),
+
+    #[error("The value at the path \"{0}\" didn't match")]
+    TestFailed(String),
+}
+
+/// Apply a JSON Patch to a document. Either every operation is applied, or the document is left
+/// untouched.
+///
+/// # Parameters
+/// - `target` - The document to patch
+/// - `operations` - The operations to apply, in order
+///
+/// # Errors
+/// If any of the operations couldn't be applied, or a `test` operation didn't match.
+pub fn apply(target: &mut Value, operations: &[PatchOperation]) -> Result<(), PatchError> {
+    let mut patched = target.clone();
+
+    for operation in operations {
+        match operation {
+            PatchOperation::Add { path, value } => add(&mut patched, path, value.clone())?,
+            PatchOperation::Remove { path } => {
+                remove(&mut patched, path)?;
+            },
+            PatchOperation::Replace { path, value } => {
+                *lookup(&mut patched, path)? = value.clone();
+            },
+            PatchOperation::Move { from, path } => {
+                if path.starts_with(&format!("{from}/")) {
+                    return Err(PatchError::InvalidMove(from.clone()));
+                }
+
+                let value = remove(&mut patched, from)?;
+                add(&mut patched, path, value)?;
+            },
+            PatchOperation::Copy { from, path } => {
+                let value = lookup(&mut patched, from)?.clone();
+                add(&mut patched, path, value)?;
+            },
+            PatchOperation::Test { path, value } => {
+                if !equal(lookup(&mut patched, path)?, value) {
+                    return Err(PatchError::TestFailed(path.clone()));
+                }
+            },
+        }
+    }
+
+    *target = patched;
+
+    Ok(())
+}
+
+/// Find the value at a path within a document.
+fn lookup<'a>(target: &'a mut Value, path: &str) -> Result<&'a mut Value, PatchError> {
+    if !path.is_empty() && !path.starts_with('/') {
+        return Err(PatchError::InvalidPointer(path.to_owned()));
+    }
+
+    target.pointer_mut(path).ok_or_else(|| PatchError::MissingTarget(path.to_owned()))
+}
+
+/// Split a path into the path of its parent, and the unescaped name of the final part.
+fn split(path: &str) -> Result<(&str, String), PatchError> {
+    let (parent, last) = path.rsplit_once('/').ok_or_else(|| PatchError::InvalidPointer(path.to_owned()))?;
+
+    Ok((parent, last.replace("~1", "/").replace("~0", "~")))
+}
+
+/// Parse an index into an array, which can't have any leading zeroes.
+fn index(token: &str, path: &str) -> Result<usize, PatchError> {
+    if token.len() > 1 && token.starts_with('0') {
+        return Err(PatchError::InvalidPointer(path.to_owned()));
+    }
+
+    token.parse().map_err(|_| PatchError::InvalidPointer(path.to_owned()))
+}
+
+/// Add a value at a path, inserting it into an array or replacing any existing member of an object.
+fn add(target: &mut Value, path: &str, value: Value) -> Result<(), PatchError> {
+    if path.is_empty() {
+        *target = value;
+        return Ok(());
+    }
+
+    let (parent, token) = split(path)?;
+
+    match lookup(target, parent)? {
+        Value::Object(object) => {
+            object.insert(token, value);
+        },
+        Value::Array(array) if token == "-" => array.push(value),
+        Value::Array(array) => {
+            let index = index(&token, path)?;
+            if index > array.len() {
+                return Err(PatchError::MissingTarget(path.to_owned()));
+            }
+            array.insert(index, value);
+        },
+        _ => return Err(PatchError::MissingTarget(path.to_owned())),
+    }
+
+    Ok(())
+}
+
+/// Remove the value at a path, returning it.
+fn remove(target: &mut Value, path: &str) -> Result<Value, PatchError> {
+    if path.is_empty() {
+        return Ok(target.take());
+    }
+
+    let (parent, token) = split(path)?;
+
+    match lookup(target, parent)? {
+        Value::Object(object) => object.remove(&token),
+        Value::Array(array) => {
+            let index = index(&token, path)?;
+            if index < array.len() {
+                Some(array.remove(index))
+            } else {
+                None
+            }
+        },
+        _ => None,
+    }
+    .ok_or_else(|| PatchError::MissingTarget(path.to_owned()))
+}
+
+/// Compare two values the way that the `test` operation does, where numbers are equal if they have
+/// the same value however they were written.
+fn equal(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => match (a.as_i64(), b.as_i64(), a.as_u64(), b.as_u64()) {
+            (Some(a), Some(b), ..) => a == b,
+            (_, _, Some(a), Some(b)) => a == b,
+            _ => a.as_f64() == b.as_f64(),
+        },
+        (Value::Array(a), Value::Array(b)) => a.len() == b.len() && a.iter().zip(b).all(|(a, b)| equal(a, b)),
+        (Value::Object(a), Value::Object(b)) => a.len() == b.len() && a.iter().all(|(key, a)| b.get(key).is_some_and(|b| equal(a, b))),
+        (a, b) => a == b,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use serde_json::json;
+    use test_case::test_case;
+
+    use super::*;
+
+    fn patch(target: Value, operations: Value) -> Result<Value, PatchError> {
+        let operations: Vec<PatchOperation> = serde_json::from_value(operations).unwrap();
+        let mut target = target;
+        apply(&mut target, &operations)?;
+
+        Ok(target)
+    }
+
+    // Mostly the examples from Appendix A of RFC 6902.
+    #[test_case(json!({"foo": "bar"}), json!([{"op": "add", "path": "/baz", "value": "qux"}]), &json!({"baz": "qux", "foo": "bar"}) ; "Add member")]
+    #[test_case(json!({"foo": ["bar", "baz"]}), json!([{"op": "add", "path": "/foo/1", "value": "qux"}]), &json!({"foo": ["bar", "qux", "baz"]}) ; "Add array element")]
+    #[test_case(json!({"foo": ["bar"]}), json!([{"op": "add", "path": "/foo/-", "value": ["abc", "def"]}]), &json!({"foo": ["bar", ["abc", "def"]]}) ; "Append array element")]
+    #[test_case(json!({"baz": "qux", "foo": "bar"}), json!([{"op": "remove", "path": "/baz"}]), &json!({"foo": "bar"}) ; "Remove member")]
+    #[test_case(json!({"foo": ["bar", "qux", "baz"]}), json!([{"op": "remove", "path": "/foo/1"}]), &json!({"foo": ["bar", "baz"]}) ; "Remove array element")]
+    #[test_case(json!({"baz": "qux", "foo": "bar"}), json!([{"op": "replace", "path": "/baz", "value": "boo"}]), &json!({"baz": "boo", "foo": "bar"}) ; "Replace value")]
+    #[test_case(json!({"foo": {"bar": "baz", "waldo": "fred"}, "qux": {"corge": "grault"}}), json!([{"op": "move", "from": "/foo/waldo", "path": "/qux/thud"}]), &json!({"foo": {"bar": "baz"}, "qux": {"corge": "grault", "thud": "fred"}}) ; "Move value")]
+    #[test_case(json!({"foo": ["all", "grass", "cows", "eat"]}), json!([{"op": "move", "from": "/foo/1", "path": "/foo/3"}]), &json!({"foo": ["all", "cows", "eat", "grass"]}) ; "Move array element")]
+    #[test_case(json!({"foo": "bar"}), json!([{"op": "copy", "from": "/foo", "path": "/baz"}]), &json!({"foo": "bar", "baz": "bar"}) ; "Copy value")]
+    #[test_case(json!({"baz": "qux", "foo": ["a", 2, "c"]}), json!([{"op": "test", "path": "/baz", "value": "qux"}, {"op": "test", "path": "/foo/1", "value": 2}]), &json!({"baz": "qux", "foo": ["a", 2, "c"]}) ; "Test succeeds")]
+    #[test_case(json!({"foo": 1}), json!([{"op": "test", "path": "/foo", "value": 1.0}]), &json!({"foo": 1}) ; "Test numbers by value")]
+    #[test_case(json!({"/": 9, "~1": 10}), json!([{"op": "test", "path": "/~01", "value": 10}, {"op": "remove", "path": "/~1"}]), &json!({"~1": 10}) ; "Escaped paths")]
+    #[test_case(json!({"foo": "bar"}), json!([{"op": "add", "path": "", "value": {"baz": "qux"}}]), &json!({"baz": "qux"}) ; "Replace document")]
+    #[test_case(json!({"foo": "bar"}), json!([]), &json!({"foo": "bar"}) ; "No operations")]
+    fn test_apply_success(target: Value, operations: Value, expected: &Value) {
+        let_assert!(Ok(output) = patch(target, operations));
+        check!(&output == expected);
+    }
+
+    #[test_case(json!({"baz": "qux"}), json!([{"op": "test", "path": "/baz", "value": "bar"}]), &PatchError::TestFailed("/baz".to_owned()) ; "Test fails")]
+    #[test_case(json!({"foo": "bar"}), json!([{"op": "add", "path": "/baz/bat", "value": "qux"}]), &PatchError::MissingTarget("/baz".to_owned()) ; "Add to missing parent")]
+    #[test_case(json!({"foo": "bar"}), json!([{"op": "remove", "path": "/baz"}]), &PatchError::MissingTarget("/baz".to_owned()) ; "Remove missing member")]
+    #[test_case(json!({"foo": "bar"}), json!([{"op": "replace", "path": "/baz", "value": 1}]), &PatchError::MissingTarget("/baz".to_owned()) ; "Replace missing member")]
+    #[test_case(json!({"foo": ["bar"]}), json!([{"op": "add", "path": "/foo/2", "value": 1}]), &PatchError::MissingTarget("/foo/2".to_owned()) ; "Add beyond end of array")]
+    #[test_case(json!({"foo": ["bar"]}), json!([{"op": "add", "path": "/foo/01", "value": 1}]), &PatchError::InvalidPointer("/foo/01".to_owned()) ; "Leading zero")]
+    #[test_case(json!({"foo": "bar"}), json!([{"op": "add", "path": "foo", "value": 1}]), &PatchError::InvalidPointer("foo".to_owned()) ; "Relative path")]
+    #[test_case(json!({"foo": {"bar": 1}}), json!([{"op": "move", "from": "/foo", "path": "/foo/bar/baz"}]), &PatchError::InvalidMove("/foo".to_owned()) ; "Move into child")]
+    fn test_apply_fail(target: Value, operations: Value, expected: &PatchError) {
+        let_assert!(Err(e) = patch(target, operations));
+        check!(&e == expected);
+    }
+
+    #[test]
+    fn test_apply_atomically() {
+        let mut target = json!({"foo": "bar"});
+        let operations: Vec<PatchOperation> = serde_json::from_value(json!([
+            {"op": "replace", "path": "/foo", "value": "baz"},
+            {"op": "test", "path": "/foo", "value": "bar"}
+        ]))
+        .unwrap();
+
+        check!(apply(&mut target, &operations) == Err(PatchError::TestFailed("/foo".to_owned())));
+        check!(target == json!({"foo": "bar"}));
+    }
+
+    #[test_case(json!({"op": "add", "path": "/a"}) ; "Missing value")]
+    #[test_case(json!({"op": "move", "path": "/a"}) ; "Missing from")]
+    #[test_case(json!({"op": "frobnicate", "path": "/a"}) ; "Unknown operation")]
+    fn test_parse_fail(operation: Value) {
+        check!(serde_json::from_value::<PatchOperation>(operation).is_err());
+    }
+}
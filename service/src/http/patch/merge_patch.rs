@@ -0,0 +1,61 @@
+use serde_json::{Map, Value};
+
+/// Apply a JSON Merge Patch to a document, as described by RFC 7396.
+///
+/// Members of the patch replace the same members of the document, objects are merged recursively,
+/// and members that are `null` in the patch are removed from the document.
+///
+/// # Parameters
+/// - `target` - The document to patch
+/// - `patch` - The patch to apply
+pub fn merge(target: &mut Value, patch: &Value) {
+    if let Value::Object(patch) = patch {
+        if !target.is_object() {
+            *target = Value::Object(Map::new());
+        }
+
+        if let Value::Object(target) = target {
+            for (key, value) in patch {
+                if value.is_null() {
+                    target.remove(key);
+                } else {
+                    merge(target.entry(key.clone()).or_insert(Value::Null), value);
+                }
+            }
+        }
+    } else {
+        *target = patch.clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use serde_json::json;
+    use test_case::test_case;
+
+    use super::*;
+
+    // The examples from Appendix A of RFC 7396.
+    #[test_case(json!({"a": "b"}), &json!({"a": "c"}), &json!({"a": "c"}) ; "Replace member")]
+    #[test_case(json!({"a": "b"}), &json!({"b": "c"}), &json!({"a": "b", "b": "c"}) ; "Add member")]
+    #[test_case(json!({"a": "b"}), &json!({"a": null}), &json!({}) ; "Remove member")]
+    #[test_case(json!({"a": "b", "b": "c"}), &json!({"a": null}), &json!({"b": "c"}) ; "Remove one of many")]
+    #[test_case(json!({"a": ["b"]}), &json!({"a": "c"}), &json!({"a": "c"}) ; "Replace array with string")]
+    #[test_case(json!({"a": "c"}), &json!({"a": ["b"]}), &json!({"a": ["b"]}) ; "Replace string with array")]
+    #[test_case(json!({"a": {"b": "c"}}), &json!({"a": {"b": "d", "c": null}}), &json!({"a": {"b": "d"}}) ; "Nested objects")]
+    #[test_case(json!({"a": [{"b": "c"}]}), &json!({"a": [1]}), &json!({"a": [1]}) ; "Arrays are replaced")]
+    #[test_case(json!(["a", "b"]), &json!(["c", "d"]), &json!(["c", "d"]) ; "Replace array document")]
+    #[test_case(json!({"a": "b"}), &json!(["c"]), &json!(["c"]) ; "Replace object with array")]
+    #[test_case(json!({"a": "foo"}), &json!(null), &json!(null) ; "Replace with null")]
+    #[test_case(json!({"a": "foo"}), &json!("bar"), &json!("bar") ; "Replace with string")]
+    #[test_case(json!({"e": null}), &json!({"a": 1}), &json!({"e": null, "a": 1}) ; "Existing nulls are kept")]
+    #[test_case(json!([1, 2]), &json!({"a": "b", "c": null}), &json!({"a": "b"}) ; "Replace array with object")]
+    #[test_case(json!({}), &json!({"a": {"bb": {"ccc": null}}}), &json!({"a": {"bb": {}}}) ; "Create nested objects")]
+    fn test_merge(target: Value, patch: &Value, expected: &Value) {
+        let mut target = target;
+        merge(&mut target, patch);
+
+        check!(&target == expected);
+    }
+}
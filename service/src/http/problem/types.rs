@@ -76,9 +76,30 @@ pub const UNPROCESSABLE_ENTITY: SimpleProblemType = SimpleProblemType {
     status_code:   StatusCode::UNPROCESSABLE_ENTITY,
 };
 
+/// Problem to indicate that the response can't be sent in any format that the client accepts.
+pub const NOT_ACCEPTABLE: SimpleProblemType = SimpleProblemType {
+    problem_type:  "about:blank",
+    problem_title: "Not Acceptable",
+    status_code:   StatusCode::NOT_ACCEPTABLE,
+};
+
+/// Problem to indicate that the request body was in a format that isn't supported.
+pub const UNSUPPORTED_MEDIA_TYPE: SimpleProblemType = SimpleProblemType {
+    problem_type:  "about:blank",
+    problem_title: "Unsupported Media Type",
+    status_code:   StatusCode::UNSUPPORTED_MEDIA_TYPE,
+};
+
 /// Problem to indicate that an internal server error occurred.
 pub const INTERNAL_SERVER_ERROR: SimpleProblemType = SimpleProblemType {
     problem_type:  "about:blank",
     problem_title: "Internal Server Error",
     status_code:   StatusCode::INTERNAL_SERVER_ERROR,
 };
+
+/// Problem to indicate that the request body was larger than is permitted.
+pub const PAYLOAD_TOO_LARGE: SimpleProblemType = SimpleProblemType {
+    problem_type:  "about:blank",
+    problem_title: "Payload Too Large",
+    status_code:   StatusCode::PAYLOAD_TOO_LARGE,
+};
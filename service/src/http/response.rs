@@ -1,13 +1,22 @@
 mod respondable;
 mod simple;
 
+use actix_http::http::header;
 use actix_web::{HttpRequest, HttpResponse, Responder};
 pub use respondable::*;
 use serde::Serialize;
 pub use simple::*;
 
+use super::{
+    format::Format,
+    problem::{Problem, INTERNAL_SERVER_ERROR, NOT_ACCEPTABLE},
+};
+
 /// Wrapper for any HTTP Response, implementing the standard requirements.
 ///
+/// The body is written in whichever supported format the `Accept` header of the request prefers,
+/// defaulting to JSON.
+///
 /// # Types
 /// - `R` - The exact type of `Respondable` to wrap.
 pub struct Response<R>(pub R)
@@ -30,13 +39,78 @@ where
     R: Respondable,
     R::Body: Serialize,
 {
-    fn respond_to(self, _req: &HttpRequest) -> HttpResponse {
+    fn respond_to(self, req: &HttpRequest) -> HttpResponse {
+        let accept = req.headers().get(header::ACCEPT).and_then(|value| value.to_str().ok());
+        let Some(format) = Format::negotiate(accept) else {
+            tracing::warn!(accept = ?accept, "No acceptable response format");
+            return Problem::from(NOT_ACCEPTABLE).into();
+        };
+
         let mut response = HttpResponse::build(self.0.status_code());
 
-        for (key, value) in self.0.headers().iter() {
+        for (key, value) in &self.0.headers() {
             response.insert_header((key, value.clone()));
         }
 
-        response.json(self.0.body())
+        match format.encode(&self.0.body()) {
+            Ok(body) => response
+                .insert_header((header::CONTENT_TYPE, format.media_type()))
+                .append_header((header::VARY, "Accept"))
+                .body(body),
+            Err(e) => {
+                tracing::error!(e = ?e, format = ?format, "Failed to encode response body");
+                Problem::from(INTERNAL_SERVER_ERROR).into()
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{test, web, App};
+    use assert2::check;
+    use serde_json::{json, Value};
+    use test_case::test_case;
+
+    use super::*;
+
+    async fn test_req() -> Response<Value> {
+        json!({"name": "Test", "tags": ["a"]}).into()
+    }
+
+    #[test_case(None, "application/json" ; "Default")]
+    #[test_case(Some("application/json"), "application/json" ; "JSON")]
+    #[test_case(Some("application/cbor"), "application/cbor" ; "CBOR")]
+    #[test_case(Some("application/msgpack"), "application/msgpack" ; "MessagePack")]
+    #[test_case(Some("application/yaml"), "application/yaml" ; "YAML")]
+    #[actix_rt::test]
+    async fn negotiate_format(accept: Option<&str>, expected: &str) {
+        let app = test::init_service(App::new().route("/", web::get().to(test_req))).await;
+        let mut req = test::TestRequest::get().uri("/");
+        if let Some(accept) = accept {
+            req = req.insert_header(("accept", accept));
+        }
+        let response = test::call_service(&app, req.to_request()).await;
+
+        check!(response.status() == 200);
+        check!(response.headers().get("content-type").unwrap() == expected);
+        check!(response.headers().get("vary").unwrap() == "Accept");
+
+        let format = Format::from_content_type(expected).unwrap();
+        let body = test::read_body(response).await;
+        check!(format.decode::<Value>(&body).unwrap() == json!({"name": "Test", "tags": ["a"]}));
+    }
+
+    #[actix_rt::test]
+    async fn not_acceptable() {
+        let app = test::init_service(App::new().route("/", web::get().to(test_req))).await;
+        let req = test::TestRequest::get()
+            .uri("/")
+            .insert_header(("accept", "text/html"))
+            .to_request();
+        let response = test::call_service(&app, req).await;
+
+        check!(response.status() == 406);
+        check!(response.headers().get("content-type").unwrap() == "application/problem+json");
     }
 }
@@ -1,12 +1,15 @@
 use std::{cmp::Ordering, ops::Deref, pin::Pin};
 
 use actix_http::{http::StatusCode, Payload};
-use actix_web::{web::Json, FromRequest, HttpRequest};
+use actix_web::{
+    web::{Bytes, Json},
+    FromRequest, HttpMessage, HttpRequest,
+};
 use futures::Future;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 
-use super::problem::SimpleProblemType;
+use super::{format::Format, problem::SimpleProblemType};
 use crate::http::problem::{Problem, BAD_REQUEST, UNPROCESSABLE_ENTITY};
 
 /// Trait implemented by types that can be validated
@@ -17,6 +20,9 @@ pub trait Validatable {
 
 /// Wrapper around some type that will perform JSON Schema validation when parsing from the HTTP
 /// request.
+///
+/// The request body can be in any supported format, as given by its content type. Anything that
+/// isn't one of the other formats is parsed as JSON.
 pub struct Valid<T>(T)
 where
     T: DeserializeOwned + Validatable;
@@ -40,39 +46,45 @@ where
     type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
 
     fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
-        let value = Json::<Value>::from_request(req, payload);
+        let value: Pin<Box<dyn Future<Output = Result<Value, Problem>>>> = match Format::from_content_type(req.content_type()) {
+            Some(format) if format != Format::Json => {
+                let body = Bytes::from_request(req, payload);
 
-        Box::pin(async move {
-            // First, parse the request as arbitrary JSON.
-            let value = value
-                .await
-                .map_err(|e| {
-                    tracing::warn!(e = ?e, "Failed to parse request as JSON");
+                Box::pin(async move {
+                    let body = body.await.map_err(|e| {
+                        tracing::warn!(e = ?e, "Failed to read request body");
+
+                        BAD_REQUEST
+                    })?;
+
+                    format.decode(&body).map_err(|e| {
+                        tracing::warn!(e = ?e, format = ?format, "Failed to parse request");
+
+                        Problem::from(BAD_REQUEST)
+                    })
+                })
+            },
+            _ => {
+                let value = Json::<Value>::from_request(req, payload);
+
+                Box::pin(async move {
+                    let value = value.await.map_err(|e| {
+                        tracing::warn!(e = ?e, "Failed to parse request as JSON");
+
+                        BAD_REQUEST
+                    })?;
+
+                    Ok(value.0)
+                })
+            },
+        };
 
-                    BAD_REQUEST
-                })?
-                .0;
+        Box::pin(async move {
+            // First, parse the request as arbitrary JSON, whatever format it was sent in.
+            let value = value.await?;
 
             // Validate the JSON against the appropriate schema.
-            let mut scope = valico::json_schema::Scope::new();
-            let schema = scope.compile_and_return(T::schema(), false).unwrap();
-            let validation = schema.validate(&value);
-
-            if !validation.is_valid() {
-                let mut errors = validation.errors;
-                errors.sort_by(|a, b| {
-                    let path = a.get_path().partial_cmp(b.get_path());
-                    let code = a.get_code().partial_cmp(b.get_code());
-
-                    match (path, code) {
-                        (Some(Ordering::Equal), Some(c)) => c,
-                        (Some(p), _) => p,
-                        _ => Ordering::Equal,
-                    }
-                });
-
-                return Err(Problem::from(VALIDATION_ERROR).with_extra("validationErrors", errors));
-            }
+            validate::<T>(&value)?;
 
             // Then attempt to parse the JSON into the target type.
             let result: T = serde_json::from_value(value).map_err(|e| {
@@ -86,6 +98,40 @@ where
     }
 }
 
+/// Validate some JSON against the schema of the type that it is meant to represent.
+///
+/// # Parameters
+/// - `value` - The JSON to validate
+///
+/// # Returns
+/// Nothing if the JSON is valid. A problem listing every validation error if not.
+pub fn validate<T>(value: &Value) -> Result<(), Problem>
+where
+    T: Validatable,
+{
+    let mut scope = valico::json_schema::Scope::new();
+    let schema = scope.compile_and_return(T::schema(), false).unwrap();
+    let validation = schema.validate(value);
+
+    if validation.is_valid() {
+        return Ok(());
+    }
+
+    let mut errors = validation.errors;
+    errors.sort_by(|a, b| {
+        let path = a.get_path().partial_cmp(b.get_path());
+        let code = a.get_code().partial_cmp(b.get_code());
+
+        match (path, code) {
+            (Some(Ordering::Equal), Some(c)) => c,
+            (Some(p), _) => p,
+            _ => Ordering::Equal,
+        }
+    });
+
+    Err(Problem::from(VALIDATION_ERROR).with_extra("validationErrors", errors))
+}
+
 impl<T> Deref for Valid<T>
 where
     T: DeserializeOwned + Validatable,
@@ -98,7 +144,7 @@ where
 }
 
 /// Problem to indicate that a request failed validation.
-const VALIDATION_ERROR: SimpleProblemType = SimpleProblemType {
+pub const VALIDATION_ERROR: SimpleProblemType = SimpleProblemType {
     problem_type:  "tag:worlds,2021:problems/validation",
     problem_title: "Request body failed validation",
     status_code:   StatusCode::UNPROCESSABLE_ENTITY,
@@ -111,6 +157,7 @@ mod tests {
     use insta::assert_json_snapshot;
     use serde::Deserialize;
     use serde_json::json;
+    use test_case::test_case;
 
     use super::*;
 
@@ -286,6 +333,56 @@ mod tests {
         check!(response.status() == 200);
     }
 
+    #[test_case("application/cbor", serde_cbor::to_vec(&json!({"name": "Fine"})).unwrap() ; "CBOR")]
+    #[test_case("application/msgpack", rmp_serde::to_vec_named(&json!({"name": "Fine"})).unwrap() ; "MessagePack")]
+    #[test_case("application/yaml", b"name: Fine".to_vec() ; "YAML")]
+    #[actix_rt::test]
+    async fn post_other_formats(content_type: &str, body: Vec<u8>) {
+        let _ = env_logger::try_init();
+
+        let app = test::init_service(App::new().route("/", web::post().to(test_req))).await;
+        let req = test::TestRequest::post()
+            .uri("/")
+            .insert_header(("content-type", content_type))
+            .set_payload(body)
+            .to_request();
+        let response = test::call_service(&app, req).await;
+
+        check!(response.status() == 200);
+    }
+
+    #[actix_rt::test]
+    async fn post_invalid_yaml() {
+        let _ = env_logger::try_init();
+
+        let app = test::init_service(App::new().route("/", web::post().to(test_req))).await;
+        let req = test::TestRequest::post()
+            .uri("/")
+            .insert_header(("content-type", "application/yaml"))
+            .set_payload("name: Too Long")
+            .to_request();
+        let response = test::call_service(&app, req).await;
+
+        check!(response.status() == 422);
+
+        let body = actix_web::test::read_body(response).await;
+
+        assert_json_snapshot!(serde_json::from_slice::<Value>(&body).unwrap(), @r###"
+        {
+          "type": "tag:worlds,2021:problems/validation",
+          "title": "Request body failed validation",
+          "status": 422,
+          "validationErrors": [
+            {
+              "code": "max_length",
+              "title": "MaxLength condition is not met",
+              "path": "/name"
+            }
+          ]
+        }
+        "###);
+    }
+
     #[actix_rt::test]
     async fn post_extra_fields() {
         let _ = env_logger::try_init();
@@ -0,0 +1,13 @@
+pub mod component;
+mod middleware;
+mod model;
+mod problems;
+mod purge_keys;
+mod repository;
+mod service;
+
+pub use middleware::*;
+pub use model::*;
+pub use purge_keys::*;
+pub use repository::*;
+pub use service::*;
@@ -0,0 +1,35 @@
+use std::{sync::Arc, time::Duration};
+
+use super::{repository::IdempotencyRepository, IdempotencyService, PurgeIdempotencyKeys};
+use crate::jobs::JobRegistry;
+
+/// Component for making unsafe requests safe to retry.
+pub struct Component {
+    pub service: Arc<IdempotencyService>,
+}
+
+impl Component {
+    /// Create a new idempotency component.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to store idempotency keys in
+    /// - `window` - How long to keep idempotency keys for, during which requests are replayed
+    pub fn new(repository: Arc<dyn IdempotencyRepository>, window: Duration) -> Arc<Self> {
+        let service = Arc::new(IdempotencyService::new(repository, window));
+
+        Arc::new(Self { service })
+    }
+
+    /// Register the background jobs that this component needs.
+    ///
+    /// # Parameters
+    /// - `registry` - The registry to add the jobs to
+    ///
+    /// # Returns
+    /// The registry, with the jobs added.
+    pub fn register_jobs(&self, registry: JobRegistry) -> JobRegistry {
+        registry
+            .with_job(PurgeIdempotencyKeys::new(self.service.clone()))
+            .with_schedule::<PurgeIdempotencyKeys>("idempotency.purge", "15 * * * *", &())
+    }
+}
@@ -0,0 +1,558 @@
+use std::{pin::Pin, rc::Rc, sync::Arc};
+
+use actix_http::{
+    error::PayloadError,
+    http::{header, Method, StatusCode},
+};
+use actix_service::{Service, Transform};
+use actix_web::{
+    dev::{Body, MessageBody, Payload, ResponseBody, ServiceRequest, ServiceResponse},
+    web::{Bytes, BytesMut},
+    Error, HttpMessage, HttpResponse,
+};
+use futures::{
+    future::{ok, Ready},
+    Future, StreamExt,
+};
+use sha2::{Digest, Sha256};
+
+use super::{
+    problems::{IDEMPOTENCY_KEY_REUSED, INVALID_IDEMPOTENCY_KEY, REQUEST_IN_PROGRESS},
+    IdempotencyKey, IdempotencyService, Outcome, StoredResponse,
+};
+use crate::http::problem::{Problem, BAD_REQUEST, INTERNAL_SERVER_ERROR, PAYLOAD_TOO_LARGE};
+
+/// The header that clients send the idempotency key in.
+const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// The header that is added to responses that are replayed rather than handled again.
+const REPLAYED_HEADER: &str = "Idempotent-Replayed";
+
+/// Middleware that makes unsafe requests safe to retry, by honouring an `Idempotency-Key` header on
+/// `POST` and `PATCH` requests.
+///
+/// The first request with a key is handled as normal, and the response to it is stored. Retries
+/// with the same key and the same request get the stored response back without being handled
+/// again, and reusing the key for a different request is rejected. Responses to requests that
+/// failed on the server aren't stored, so that those requests can be retried for real, and neither
+/// are responses marked `Cache-Control: no-store`, such as ones that contain secrets.
+///
+/// Keys are scoped to the credentials that the request was made with. Anonymous requests have
+/// nothing to keep one client's responses from another but the request itself, so their keys are
+/// scoped to the fingerprint of the request, and only an identical retry gets the response back.
+/// That retry already holds whatever the response was marked `no-store` for, such as the password
+/// for a new session, so responses to anonymous requests are stored regardless.
+///
+/// Without a service to store the keys in, every request is passed straight through.
+pub struct Idempotency {
+    service:       Option<Arc<IdempotencyService>>,
+    max_body_size: usize,
+}
+
+impl Idempotency {
+    /// Create the middleware.
+    ///
+    /// # Parameters
+    /// - `service` - The service that stores idempotency keys
+    /// - `max_body_size` - The largest request body that can be sent with an idempotency key
+    pub fn new(service: Option<Arc<IdempotencyService>>, max_body_size: usize) -> Self {
+        Self { service, max_body_size }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for Idempotency
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: MessageBody + Unpin + 'static,
+{
+    type Response = ServiceResponse<Body>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = Middleware<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(Middleware {
+            service:       Rc::new(service),
+            idempotency:   self.service.clone(),
+            max_body_size: self.max_body_size,
+        })
+    }
+}
+
+/// Actual middleware implementation.
+pub struct Middleware<S> {
+    service:       Rc<S>,
+    idempotency:   Option<Arc<IdempotencyService>>,
+    max_body_size: usize,
+}
+
+impl<S, B> Service<ServiceRequest> for Middleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: MessageBody + Unpin + 'static,
+{
+    type Response = ServiceResponse<Body>;
+    type Error = Error;
+    #[allow(clippy::type_complexity)]
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
+
+    actix_service::forward_ready!(service);
+
+    fn call(&self, mut req: ServiceRequest) -> Self::Future {
+        let unsafe_method = req.method() == Method::POST || req.method() == Method::PATCH;
+        let key = req
+            .headers()
+            .get(IDEMPOTENCY_KEY_HEADER)
+            .filter(|_| unsafe_method && self.idempotency.is_some())
+            .map(|value| value.to_str().ok().and_then(|value| value.parse::<IdempotencyKey>().ok()));
+
+        let key = match key {
+            Some(Some(key)) => key,
+            Some(None) => return Box::pin(ok(problem(req, Problem::new(INVALID_IDEMPOTENCY_KEY)))),
+            None => {
+                let fut = self.service.call(req);
+                return Box::pin(async move { fut.await.map(boxed) });
+            },
+        };
+        let credentials = scope(&req);
+
+        let service = self.service.clone();
+        let idempotency = self.idempotency.clone().expect("Idempotency service is missing");
+        let max_body_size = self.max_body_size;
+
+        Box::pin(async move {
+            let body = match read_body(&mut req, max_body_size).await {
+                Ok(body) => body,
+                Err(e) => return Ok(problem(req, e)),
+            };
+            let fingerprint = fingerprint(&req, &body);
+            let anonymous = credentials.is_none();
+            let scope = credentials.unwrap_or_else(|| format!("anonymous:{fingerprint}"));
+
+            match idempotency.begin(&key, &scope, &fingerprint).await {
+                Ok(Outcome::Proceed) => {},
+                Ok(Outcome::Replay(stored)) => return Ok(req.into_response(replay(&stored))),
+                Ok(Outcome::Mismatch) => return Ok(problem(req, Problem::new(IDEMPOTENCY_KEY_REUSED))),
+                Ok(Outcome::InProgress) => return Ok(problem(req, Problem::new(REQUEST_IN_PROGRESS))),
+                Err(_) => return Ok(problem(req, Problem::new(INTERNAL_SERVER_ERROR))),
+            }
+
+            req.set_payload(Payload::Stream(Box::pin(futures::stream::once(ok::<_, PayloadError>(body)))));
+
+            let mut res = match service.call(req).await {
+                Ok(res) => res,
+                Err(e) => {
+                    idempotency.release(&key, &scope).await;
+                    return Err(e);
+                },
+            };
+
+            if res.status().is_server_error() || !(anonymous || storable(res.headers())) {
+                idempotency.release(&key, &scope).await;
+                return Ok(boxed(res));
+            }
+
+            let mut response_body = res.take_body();
+            let mut buffer = BytesMut::new();
+            while let Some(chunk) = response_body.next().await {
+                match chunk {
+                    Ok(chunk) => buffer.extend_from_slice(&chunk),
+                    Err(e) => {
+                        idempotency.release(&key, &scope).await;
+                        return Err(e);
+                    },
+                }
+            }
+            let body = buffer.freeze();
+
+            let stored = StoredResponse {
+                status_code: res.status().as_u16(),
+                headers:     res
+                    .headers()
+                    .iter()
+                    .filter(|(name, _)| ![header::DATE, header::CONTENT_LENGTH, header::TRANSFER_ENCODING].contains(name))
+                    .filter_map(|(name, value)| value.to_str().ok().map(|value| (name.as_str().to_owned(), value.to_owned())))
+                    .collect(),
+                body:        body.to_vec(),
+            };
+            idempotency.complete(&key, &scope, &stored).await;
+
+            Ok(res.map_body(|_, _| ResponseBody::Other(Body::from(body))))
+        })
+    }
+}
+
+/// Convert a response into one with a boxed body, so that every response has the same type.
+fn boxed<B>(res: ServiceResponse<B>) -> ServiceResponse<Body>
+where
+    B: MessageBody + Unpin + 'static,
+{
+    res.map_body(|_, body| ResponseBody::Other(Body::from_message(body)))
+}
+
+/// Respond to a request with a problem, without handling it.
+fn problem(req: ServiceRequest, problem: Problem) -> ServiceResponse<Body> {
+    req.into_response(HttpResponse::from(problem))
+}
+
+/// Determine the scope of the idempotency key on a request that was made with credentials. Keys are
+/// scoped to the credentials, so that one client can never see the response to another.
+///
+/// # Parameters
+/// - `req` - The request
+///
+/// # Returns
+/// The scope of the key, or `None` if the request was made without credentials.
+fn scope(req: &ServiceRequest) -> Option<String> {
+    req.headers()
+        .get(header::AUTHORIZATION)
+        .map(|value| format!("{:x}", Sha256::digest(value.as_bytes())))
+}
+
+/// Determine whether a response may be stored to replay, which it may not if it is marked
+/// `Cache-Control: no-store`.
+///
+/// # Parameters
+/// - `headers` - The headers of the response
+fn storable(headers: &header::HeaderMap) -> bool {
+    !headers
+        .get_all(header::CACHE_CONTROL)
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|directive| directive.trim().eq_ignore_ascii_case("no-store"))
+}
+
+/// Read the entire body of a request, so that it can be fingerprinted.
+///
+/// # Parameters
+/// - `req` - The request, which the body is taken from
+/// - `max_body_size` - The largest body that can be read
+///
+/// # Returns
+/// The body of the request.
+async fn read_body(req: &mut ServiceRequest, max_body_size: usize) -> Result<Bytes, Problem> {
+    let mut payload = req.take_payload();
+    let mut body = BytesMut::new();
+
+    while let Some(chunk) = payload.next().await {
+        let chunk = chunk.map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to read request body");
+            Problem::new(BAD_REQUEST)
+        })?;
+
+        if body.len() + chunk.len() > max_body_size {
+            return Err(Problem::new(PAYLOAD_TOO_LARGE));
+        }
+        body.extend_from_slice(&chunk);
+    }
+
+    Ok(body.freeze())
+}
+
+/// Generate a fingerprint of a request, to tell whether a retry is for the same request.
+///
+/// # Parameters
+/// - `req` - The request
+/// - `body` - The body of the request
+///
+/// # Returns
+/// The fingerprint.
+fn fingerprint(req: &ServiceRequest, body: &[u8]) -> String {
+    let content_type = req.headers().get(header::CONTENT_TYPE).map(header::HeaderValue::as_bytes);
+    // The response is encoded in the format that was asked for, so asking for another one is a
+    // different request.
+    let accept = req.headers().get(header::ACCEPT).map(header::HeaderValue::as_bytes);
+    let target = req.uri().path_and_query().map_or_else(|| req.path(), |p| p.as_str());
+
+    let mut hasher = Sha256::new();
+    for part in &[
+        req.method().as_str().as_bytes(),
+        target.as_bytes(),
+        content_type.unwrap_or_default(),
+        accept.unwrap_or_default(),
+        body,
+    ] {
+        hasher.update(part);
+        hasher.update([0]);
+    }
+
+    format!("{:x}", hasher.finalize())
+}
+
+/// Build the response to send again for a request that was already handled.
+///
+/// # Parameters
+/// - `stored` - The response that was stored for the request
+///
+/// # Returns
+/// The response.
+fn replay(stored: &StoredResponse) -> HttpResponse {
+    let mut builder = HttpResponse::build(StatusCode::from_u16(stored.status_code).unwrap_or(StatusCode::OK));
+    for (name, value) in &stored.headers {
+        builder.append_header((name.as_str(), value.as_str()));
+    }
+    builder.insert_header((REPLAYED_HEADER, "true"));
+
+    builder.body(stored.body.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+
+    use actix_web::{test, web, App};
+    use assert2::check;
+    use test_case::test_case;
+
+    use super::*;
+    use crate::idempotency::MemoryIdempotencyRepository;
+
+    async fn handler(counter: web::Data<AtomicUsize>, body: Bytes) -> HttpResponse {
+        let count = counter.fetch_add(1, Ordering::SeqCst) + 1;
+
+        HttpResponse::Created()
+            .insert_header(("X-Count", count.to_string()))
+            .body(format!("{}:{}", count, String::from_utf8_lossy(&body)))
+    }
+
+    async fn failing_handler(counter: web::Data<AtomicUsize>) -> HttpResponse {
+        counter.fetch_add(1, Ordering::SeqCst);
+
+        HttpResponse::ServiceUnavailable().finish()
+    }
+
+    async fn secret_handler(counter: web::Data<AtomicUsize>) -> HttpResponse {
+        let count = counter.fetch_add(1, Ordering::SeqCst) + 1;
+
+        HttpResponse::Created()
+            .insert_header((header::CACHE_CONTROL, "private, no-store"))
+            .body(format!("secret {count}"))
+    }
+
+    struct Fixture<S> {
+        app:     S,
+        counter: web::Data<AtomicUsize>,
+    }
+
+    async fn fixture() -> Fixture<impl actix_service::Service<actix_http::Request, Response = ServiceResponse<Body>, Error = Error>> {
+        let counter = web::Data::new(AtomicUsize::new(0));
+        let service = Arc::new(IdempotencyService::new(
+            Arc::new(MemoryIdempotencyRepository::new()),
+            Duration::from_hours(1),
+        ));
+
+        let app = test::init_service(
+            App::new()
+                .app_data(counter.clone())
+                .wrap(Idempotency::new(Some(service), 16))
+                .route("/things", web::post().to(handler))
+                .route("/things", web::patch().to(handler))
+                .route("/things", web::get().to(handler))
+                .route("/failing", web::post().to(failing_handler))
+                .route("/secret", web::post().to(secret_handler)),
+        )
+        .await;
+
+        Fixture { app, counter }
+    }
+
+    async fn send<S>(app: &S, req: test::TestRequest) -> (StatusCode, Option<String>, String)
+    where
+        S: actix_service::Service<actix_http::Request, Response = ServiceResponse<Body>, Error = Error>,
+    {
+        let res = test::call_service(app, req.to_request()).await;
+        let status = res.status();
+        let replayed = res.headers().get(REPLAYED_HEADER).map(|value| value.to_str().unwrap().to_owned());
+        let body = test::read_body(res).await;
+
+        (status, replayed, String::from_utf8_lossy(&body).into_owned())
+    }
+
+    #[test_case(Method::POST ; "POST")]
+    #[test_case(Method::PATCH ; "PATCH")]
+    #[actix_rt::test]
+    async fn replay_retries(method: Method) {
+        let fixture = fixture().await;
+        let request = || {
+            test::TestRequest::default()
+                .method(method.clone())
+                .uri("/things")
+                .insert_header((IDEMPOTENCY_KEY_HEADER, "abc"))
+                .insert_header((header::AUTHORIZATION, "Bearer alice"))
+                .set_payload("hello")
+        };
+
+        check!(send(&fixture.app, request()).await == (StatusCode::CREATED, None, "1:hello".to_owned()));
+
+        let res = test::call_service(&fixture.app, request().to_request()).await;
+        check!(res.status() == StatusCode::CREATED);
+        check!(res.headers().get("X-Count").unwrap() == "1");
+        check!(res.headers().get(REPLAYED_HEADER).unwrap() == "true");
+        check!(test::read_body(res).await == "1:hello");
+
+        check!(fixture.counter.load(Ordering::SeqCst) == 1);
+    }
+
+    #[actix_rt::test]
+    async fn reject_reused_key() {
+        let fixture = fixture().await;
+        let request = |body: &'static str| {
+            test::TestRequest::post()
+                .uri("/things")
+                .insert_header((IDEMPOTENCY_KEY_HEADER, "abc"))
+                .insert_header((header::AUTHORIZATION, "Bearer alice"))
+                .set_payload(body)
+        };
+
+        check!(send(&fixture.app, request("hello")).await.0 == StatusCode::CREATED);
+
+        let (status, _, body) = send(&fixture.app, request("goodbye")).await;
+        check!(status == StatusCode::CONFLICT);
+        check!(body.contains("tag:worlds,2021:problems/idempotency/key_reused"));
+
+        check!(fixture.counter.load(Ordering::SeqCst) == 1);
+    }
+
+    #[actix_rt::test]
+    async fn keys_are_scoped_to_credentials() {
+        let fixture = fixture().await;
+        let request = |token: &str| {
+            test::TestRequest::post()
+                .uri("/things")
+                .insert_header((IDEMPOTENCY_KEY_HEADER, "abc"))
+                .insert_header(("Authorization", format!("Bearer {}", token)))
+                .set_payload("hello")
+        };
+
+        check!(send(&fixture.app, request("alice")).await == (StatusCode::CREATED, None, "1:hello".to_owned()));
+        check!(send(&fixture.app, request("bob")).await == (StatusCode::CREATED, None, "2:hello".to_owned()));
+    }
+
+    #[actix_rt::test]
+    async fn ignore_requests_without_key() {
+        let fixture = fixture().await;
+
+        for expected in &["1:hello", "2:hello"] {
+            let req = test::TestRequest::post().uri("/things").set_payload("hello");
+            check!(send(&fixture.app, req).await == (StatusCode::CREATED, None, (*expected).to_owned()));
+        }
+    }
+
+    #[actix_rt::test]
+    async fn ignore_safe_requests() {
+        let fixture = fixture().await;
+
+        for expected in &["1:", "2:"] {
+            let req = test::TestRequest::get()
+                .uri("/things")
+                .insert_header((IDEMPOTENCY_KEY_HEADER, "abc"))
+                .insert_header((header::AUTHORIZATION, "Bearer alice"));
+            check!(send(&fixture.app, req).await == (StatusCode::CREATED, None, (*expected).to_owned()));
+        }
+    }
+
+    #[actix_rt::test]
+    async fn retry_server_errors() {
+        let fixture = fixture().await;
+
+        for _ in 0..2 {
+            let req = test::TestRequest::post()
+                .uri("/failing")
+                .insert_header((IDEMPOTENCY_KEY_HEADER, "abc"))
+                .insert_header((header::AUTHORIZATION, "Bearer alice"));
+            check!(send(&fixture.app, req).await.0 == StatusCode::SERVICE_UNAVAILABLE);
+        }
+
+        check!(fixture.counter.load(Ordering::SeqCst) == 2);
+    }
+
+    #[test_case("has spaces", StatusCode::BAD_REQUEST ; "Malformed key")]
+    #[test_case("abc", StatusCode::CREATED ; "Valid key")]
+    #[actix_rt::test]
+    async fn validate_key(key: &str, expected: StatusCode) {
+        let fixture = fixture().await;
+        let req = test::TestRequest::post()
+            .uri("/things")
+            .insert_header((IDEMPOTENCY_KEY_HEADER, key))
+            .insert_header((header::AUTHORIZATION, "Bearer alice"));
+
+        check!(send(&fixture.app, req).await.0 == expected);
+    }
+
+    #[actix_rt::test]
+    async fn reject_large_bodies() {
+        let fixture = fixture().await;
+        let req = test::TestRequest::post()
+            .uri("/things")
+            .insert_header((IDEMPOTENCY_KEY_HEADER, "abc"))
+            .insert_header((header::AUTHORIZATION, "Bearer alice"))
+            .set_payload("x".repeat(17));
+
+        check!(send(&fixture.app, req).await.0 == StatusCode::PAYLOAD_TOO_LARGE);
+        check!(fixture.counter.load(Ordering::SeqCst) == 0);
+    }
+
+    #[actix_rt::test]
+    async fn replay_anonymous_retries() {
+        let fixture = fixture().await;
+        let request = |body: &'static str| {
+            test::TestRequest::post()
+                .uri("/things")
+                .insert_header((IDEMPOTENCY_KEY_HEADER, "abc"))
+                .set_payload(body)
+        };
+
+        check!(send(&fixture.app, request("hello")).await == (StatusCode::CREATED, None, "1:hello".to_owned()));
+        check!(send(&fixture.app, request("hello")).await == (StatusCode::CREATED, Some("true".to_owned()), "1:hello".to_owned()));
+
+        // Without credentials, there's no telling whether a different request is from the same client.
+        check!(send(&fixture.app, request("goodbye")).await == (StatusCode::CREATED, None, "2:goodbye".to_owned()));
+    }
+
+    #[actix_rt::test]
+    async fn store_uncacheable_responses_to_anonymous_requests() {
+        let fixture = fixture().await;
+
+        for expected in &[None, Some("true".to_owned())] {
+            let req = test::TestRequest::post()
+                .uri("/secret")
+                .insert_header((IDEMPOTENCY_KEY_HEADER, "abc"));
+            check!(send(&fixture.app, req).await == (StatusCode::CREATED, expected.clone(), "secret 1".to_owned()));
+        }
+    }
+
+    #[actix_rt::test]
+    async fn dont_store_uncacheable_responses() {
+        let fixture = fixture().await;
+
+        for expected in &["secret 1", "secret 2"] {
+            let req = test::TestRequest::post()
+                .uri("/secret")
+                .insert_header((IDEMPOTENCY_KEY_HEADER, "abc"))
+                .insert_header((header::AUTHORIZATION, "Bearer alice"));
+            check!(send(&fixture.app, req).await == (StatusCode::CREATED, None, (*expected).to_owned()));
+        }
+    }
+
+    #[actix_rt::test]
+    async fn reject_reused_key_with_different_accept() {
+        let fixture = fixture().await;
+        let request = |accept: &'static str| {
+            test::TestRequest::post()
+                .uri("/things")
+                .insert_header((IDEMPOTENCY_KEY_HEADER, "abc"))
+                .insert_header((header::AUTHORIZATION, "Bearer alice"))
+                .insert_header((header::ACCEPT, accept))
+                .set_payload("hello")
+        };
+
+        check!(send(&fixture.app, request("application/json")).await.0 == StatusCode::CREATED);
+        check!(send(&fixture.app, request("application/cbor")).await.0 == StatusCode::CONFLICT);
+    }
+}
@@ -0,0 +1,25 @@
+mod idempotency_key;
+
+use chrono::{DateTime, Utc};
+pub use idempotency_key::*;
+use serde::{Deserialize, Serialize};
+
+/// A response to an unsafe request, kept so that it can be sent again if the request is retried.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoredResponse {
+    pub status_code: u16,
+    /// The headers of the response, as names and values.
+    pub headers:     Vec<(String, String)>,
+    pub body:        Vec<u8>,
+}
+
+/// The record of a request that was made with an idempotency key.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdempotencyRecord {
+    /// The fingerprint of the request, to tell whether a retry is for the same request.
+    pub fingerprint: String,
+    /// The response to the request, or `None` if it is still being handled.
+    pub response:    Option<StoredResponse>,
+    /// When the request was first made.
+    pub created:     DateTime<Utc>,
+}
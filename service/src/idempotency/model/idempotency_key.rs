@@ -0,0 +1,82 @@
+use std::str::FromStr;
+
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, IsNull, ToSql, Type};
+
+/// The longest that an idempotency key can be.
+const MAX_LENGTH: usize = 255;
+
+/// A key that a client sends with an unsafe request, so that retrying the request doesn't repeat
+/// its effects.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdempotencyKey(String);
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ParseIdempotencyKeyError {
+    #[error("The Idempotency Key was blank")]
+    Blank,
+
+    #[error("The Idempotency Key was malformed")]
+    Malformed,
+}
+
+impl std::fmt::Display for IdempotencyKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for IdempotencyKey {
+    type Err = ParseIdempotencyKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+
+        if trimmed.is_empty() {
+            Err(ParseIdempotencyKeyError::Blank)
+        } else if trimmed.len() > MAX_LENGTH || !trimmed.chars().all(|c| c.is_ascii_graphic()) {
+            Err(ParseIdempotencyKeyError::Malformed)
+        } else {
+            Ok(Self(trimmed.to_owned()))
+        }
+    }
+}
+
+impl ToSql for IdempotencyKey {
+    accepts!(TEXT, VARCHAR);
+    to_sql_checked!();
+
+    fn to_sql(&self, t: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.0.to_sql(t, w)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("8e03978e-40d5-43e8-bc93-6894a57f9324", "8e03978e-40d5-43e8-bc93-6894a57f9324" ; "UUID")]
+    #[test_case("  retry-42  ", "retry-42" ; "Padded")]
+    fn test_parse_success(input: &str, expected: &str) {
+        let_assert!(Ok(key) = input.parse::<IdempotencyKey>());
+        check!(key.to_string() == expected);
+    }
+
+    #[test_case("", &ParseIdempotencyKeyError::Blank ; "Blank")]
+    #[test_case("   ", &ParseIdempotencyKeyError::Blank ; "Whitespace")]
+    #[test_case("has spaces", &ParseIdempotencyKeyError::Malformed ; "Spaces")]
+    #[test_case("ключ", &ParseIdempotencyKeyError::Malformed ; "Not ASCII")]
+    fn test_parse_fail(input: &str, expected: &ParseIdempotencyKeyError) {
+        let_assert!(Err(e) = input.parse::<IdempotencyKey>());
+        check!(&e == expected);
+    }
+
+    #[test]
+    fn test_parse_too_long() {
+        let_assert!(Err(e) = "x".repeat(MAX_LENGTH + 1).parse::<IdempotencyKey>());
+        check!(e == ParseIdempotencyKeyError::Malformed);
+    }
+}
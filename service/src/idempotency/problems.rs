@@ -0,0 +1,24 @@
+use actix_http::http::StatusCode;
+
+use crate::http::problem::SimpleProblemType;
+
+/// Problem to indicate that the idempotency key of a request isn't valid.
+pub const INVALID_IDEMPOTENCY_KEY: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/idempotency/invalid_key",
+    problem_title: "Invalid Idempotency Key",
+    status_code:   StatusCode::BAD_REQUEST,
+};
+
+/// Problem to indicate that an idempotency key was already used for a different request.
+pub const IDEMPOTENCY_KEY_REUSED: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/idempotency/key_reused",
+    problem_title: "Idempotency Key Already Used For A Different Request",
+    status_code:   StatusCode::CONFLICT,
+};
+
+/// Problem to indicate that the request for an idempotency key is still being handled.
+pub const REQUEST_IN_PROGRESS: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/idempotency/in_progress",
+    problem_title: "A Request With This Idempotency Key Is Still In Progress",
+    status_code:   StatusCode::CONFLICT,
+};
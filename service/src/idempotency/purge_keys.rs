@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use super::IdempotencyService;
+use crate::jobs::{Job, JobError};
+
+/// Job that deletes the idempotency keys that have expired, so that they don't build up forever.
+pub struct PurgeIdempotencyKeys {
+    service: Arc<IdempotencyService>,
+}
+
+impl PurgeIdempotencyKeys {
+    /// Create the job.
+    ///
+    /// # Parameters
+    /// - `service` - The service that stores idempotency keys
+    pub fn new(service: Arc<IdempotencyService>) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Job for PurgeIdempotencyKeys {
+    type Payload = ();
+
+    const KIND: &'static str = "idempotency.purge";
+
+    async fn run(&self, (): ()) -> Result<(), JobError> {
+        let count = self.service.purge().await;
+        tracing::info!(count = count, "Purged expired idempotency keys");
+
+        Ok(())
+    }
+}
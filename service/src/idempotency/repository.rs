@@ -0,0 +1,72 @@
+mod claim_key;
+#[cfg(test)]
+mod memory;
+mod parse;
+mod postgres;
+
+use chrono::{DateTime, Utc};
+pub use claim_key::{ClaimKeyError, ClaimOutcome};
+#[cfg(test)]
+pub use memory::MemoryIdempotencyRepository;
+pub use postgres::PostgresIdempotencyRepository;
+
+use super::{IdempotencyKey, StoredResponse};
+
+/// Repository of the idempotency keys that clients have sent with unsafe requests, and the
+/// responses that were sent back for them.
+///
+/// Keys are scoped, so that two clients that happen to pick the same key don't see each others
+/// responses.
+#[async_trait::async_trait]
+pub trait IdempotencyRepository: Send + Sync {
+    /// Claim an idempotency key for a request, so that the request can be handled. A key that has
+    /// expired, or that was claimed by a request that was abandoned part way through, can be
+    /// claimed again.
+    ///
+    /// # Parameters
+    /// - `key` - The idempotency key
+    /// - `scope` - The scope that the key belongs to
+    /// - `fingerprint` - The fingerprint of the request
+    /// - `now` - The current time
+    /// - `expired_before` - Keys that were first used before this time have expired
+    /// - `stale_before` - Keys that were claimed before this time and never completed were
+    ///   abandoned
+    ///
+    /// # Returns
+    /// Whether the key was claimed, or the record of the request that already holds it.
+    async fn claim_key(
+        &self,
+        key: &IdempotencyKey,
+        scope: &str,
+        fingerprint: &str,
+        now: DateTime<Utc>,
+        expired_before: DateTime<Utc>,
+        stale_before: DateTime<Utc>,
+    ) -> Result<ClaimOutcome, ClaimKeyError>;
+
+    /// Record the response to the request that claimed an idempotency key.
+    ///
+    /// # Parameters
+    /// - `key` - The idempotency key
+    /// - `scope` - The scope that the key belongs to
+    /// - `response` - The response that was sent
+    /// - `now` - The current time
+    async fn complete_key(&self, key: &IdempotencyKey, scope: &str, response: &StoredResponse, now: DateTime<Utc>);
+
+    /// Release an idempotency key without recording a response, so that the request can be tried
+    /// again.
+    ///
+    /// # Parameters
+    /// - `key` - The idempotency key
+    /// - `scope` - The scope that the key belongs to
+    async fn release_key(&self, key: &IdempotencyKey, scope: &str);
+
+    /// Delete the idempotency keys that have expired.
+    ///
+    /// # Parameters
+    /// - `expired_before` - Keys that were first used before this time have expired
+    ///
+    /// # Returns
+    /// The number of keys that were deleted.
+    async fn purge_keys(&self, expired_before: DateTime<Utc>) -> u64;
+}
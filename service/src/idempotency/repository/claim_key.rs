@@ -0,0 +1,26 @@
+use crate::idempotency::IdempotencyRecord;
+
+/// The outcome of claiming an idempotency key.
+#[derive(Debug, PartialEq)]
+pub enum ClaimOutcome {
+    /// The key was claimed, so the request should be handled.
+    Claimed,
+
+    /// The key is already held by an earlier request.
+    Existing(IdempotencyRecord),
+}
+
+/// Errors that can occur when claiming an idempotency key.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ClaimKeyError {
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl From<tokio_postgres::Error> for ClaimKeyError {
+    fn from(e: tokio_postgres::Error) -> Self {
+        tracing::warn!("Unexpected database error: {:?}", e);
+
+        ClaimKeyError::UnknownError
+    }
+}
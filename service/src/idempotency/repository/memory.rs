@@ -0,0 +1,87 @@
+use std::{collections::HashMap, convert::TryFrom, sync::Mutex};
+
+use chrono::{DateTime, Utc};
+
+use super::{ClaimKeyError, ClaimOutcome, IdempotencyRepository};
+use crate::idempotency::{IdempotencyKey, IdempotencyRecord, StoredResponse};
+
+/// Repository of idempotency keys that are stored in memory.
+#[derive(Default)]
+pub struct MemoryIdempotencyRepository {
+    keys: Mutex<HashMap<(String, String), IdempotencyRecord>>,
+}
+
+impl MemoryIdempotencyRepository {
+    /// Create a new, empty idempotency key repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the record that is held for an idempotency key.
+    ///
+    /// # Parameters
+    /// - `key` - The idempotency key
+    /// - `scope` - The scope that the key belongs to
+    pub fn get(&self, key: &str, scope: &str) -> Option<IdempotencyRecord> {
+        self.keys.lock().unwrap().get(&(scope.to_owned(), key.to_owned())).cloned()
+    }
+}
+
+#[async_trait::async_trait]
+impl IdempotencyRepository for MemoryIdempotencyRepository {
+    async fn claim_key(
+        &self,
+        key: &IdempotencyKey,
+        scope: &str,
+        fingerprint: &str,
+        now: DateTime<Utc>,
+        expired_before: DateTime<Utc>,
+        stale_before: DateTime<Utc>,
+    ) -> Result<ClaimOutcome, ClaimKeyError> {
+        let mut keys = self.keys.lock().unwrap();
+        let id = (scope.to_owned(), key.to_string());
+
+        if let Some(existing) = keys.get(&id) {
+            let abandoned = existing.response.is_none() && existing.created < stale_before;
+            if existing.created >= expired_before && !abandoned {
+                return Ok(ClaimOutcome::Existing(existing.clone()));
+            }
+        }
+
+        keys.insert(
+            id,
+            IdempotencyRecord {
+                fingerprint: fingerprint.to_owned(),
+                response:    None,
+                created:     now,
+            },
+        );
+
+        Ok(ClaimOutcome::Claimed)
+    }
+
+    async fn complete_key(&self, key: &IdempotencyKey, scope: &str, response: &StoredResponse, _: DateTime<Utc>) {
+        let mut keys = self.keys.lock().unwrap();
+
+        if let Some(record) = keys.get_mut(&(scope.to_owned(), key.to_string())) {
+            record.response = Some(response.clone());
+        }
+    }
+
+    async fn release_key(&self, key: &IdempotencyKey, scope: &str) {
+        let mut keys = self.keys.lock().unwrap();
+        let id = (scope.to_owned(), key.to_string());
+
+        if keys.get(&id).is_some_and(|record| record.response.is_none()) {
+            keys.remove(&id);
+        }
+    }
+
+    async fn purge_keys(&self, expired_before: DateTime<Utc>) -> u64 {
+        let mut keys = self.keys.lock().unwrap();
+        let before = keys.len();
+        keys.retain(|_, record| record.created >= expired_before);
+
+        u64::try_from(before - keys.len()).unwrap_or_default()
+    }
+}
@@ -0,0 +1,25 @@
+use std::convert::TryFrom;
+
+use tokio_postgres::Row;
+
+use crate::idempotency::{IdempotencyRecord, StoredResponse};
+
+impl From<Row> for IdempotencyRecord {
+    fn from(row: Row) -> Self {
+        let status_code: Option<i32> = row.get("status_code");
+        let headers: Option<serde_json::Value> = row.get("headers");
+        let body: Option<Vec<u8>> = row.get("body");
+
+        let response = status_code.map(|status_code| StoredResponse {
+            status_code: u16::try_from(status_code).unwrap_or_default(),
+            headers:     headers.and_then(|h| serde_json::from_value(h).ok()).unwrap_or_default(),
+            body:        body.unwrap_or_default(),
+        });
+
+        IdempotencyRecord {
+            fingerprint: row.get("fingerprint"),
+            response,
+            created: row.get("created"),
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+use super::{ClaimKeyError, ClaimOutcome, IdempotencyRepository};
+use crate::{
+    database::Database,
+    idempotency::{IdempotencyKey, IdempotencyRecord, StoredResponse},
+};
+
+/// Repository of idempotency keys that are stored in Postgres.
+pub struct PostgresIdempotencyRepository {
+    database: Arc<Database>,
+}
+
+impl PostgresIdempotencyRepository {
+    /// Create a new idempotency key repository.
+    pub fn new(database: Arc<Database>) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait::async_trait]
+impl IdempotencyRepository for PostgresIdempotencyRepository {
+    #[tracing::instrument(skip(self))]
+    async fn claim_key(
+        &self,
+        key: &IdempotencyKey,
+        scope: &str,
+        fingerprint: &str,
+        now: DateTime<Utc>,
+        expired_before: DateTime<Utc>,
+        stale_before: DateTime<Utc>,
+    ) -> Result<ClaimOutcome, ClaimKeyError> {
+        let conn = self.database.connect().await;
+
+        // The key can be released between failing to claim it and loading it, in which case it's
+        // free to claim again.
+        for _ in 0..2 {
+            let claimed = conn
+                .query_opt(
+                    "INSERT INTO idempotency_keys(scope, idempotency_key, fingerprint, created) VALUES ($1, $2, $3, $4)
+                     ON CONFLICT (scope, idempotency_key) DO UPDATE
+                        SET fingerprint = EXCLUDED.fingerprint, created = EXCLUDED.created,
+                            status_code = NULL, headers = NULL, body = NULL, completed = NULL
+                      WHERE idempotency_keys.created < $5
+                         OR (idempotency_keys.completed IS NULL AND idempotency_keys.created < $6)
+                     RETURNING scope",
+                    &[&scope, &key, &fingerprint, &now, &expired_before, &stale_before],
+                )
+                .await?;
+            if claimed.is_some() {
+                return Ok(ClaimOutcome::Claimed);
+            }
+
+            let existing = conn
+                .query_opt(
+                    "SELECT * FROM idempotency_keys WHERE scope = $1 AND idempotency_key = $2",
+                    &[&scope, &key],
+                )
+                .await?;
+            if let Some(row) = existing {
+                return Ok(ClaimOutcome::Existing(IdempotencyRecord::from(row)));
+            }
+        }
+
+        Err(ClaimKeyError::UnknownError)
+    }
+
+    #[tracing::instrument(skip(self, response))]
+    async fn complete_key(&self, key: &IdempotencyKey, scope: &str, response: &StoredResponse, now: DateTime<Utc>) {
+        let conn = self.database.connect().await;
+        let headers = serde_json::to_value(&response.headers).expect("Failed to serialize response headers");
+
+        if let Err(e) = conn
+            .execute(
+                "UPDATE idempotency_keys SET status_code = $3, headers = $4, body = $5, completed = $6
+                  WHERE scope = $1 AND idempotency_key = $2",
+                &[&scope, &key, &i32::from(response.status_code), &headers, &response.body, &now],
+            )
+            .await
+        {
+            tracing::warn!(e = ?e, "Failed to record response for idempotency key");
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn release_key(&self, key: &IdempotencyKey, scope: &str) {
+        let conn = self.database.connect().await;
+
+        if let Err(e) = conn
+            .execute(
+                "DELETE FROM idempotency_keys WHERE scope = $1 AND idempotency_key = $2 AND completed IS NULL",
+                &[&scope, &key],
+            )
+            .await
+        {
+            tracing::warn!(e = ?e, "Failed to release idempotency key");
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn purge_keys(&self, expired_before: DateTime<Utc>) -> u64 {
+        let conn = self.database.connect().await;
+        conn.execute("DELETE FROM idempotency_keys WHERE created < $1", &[&expired_before])
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!(e = ?e, "Failed to purge idempotency keys");
+                0
+            })
+    }
+}
@@ -0,0 +1,217 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::{DateTime, Utc};
+
+use super::{repository::IdempotencyRepository, ClaimKeyError, ClaimOutcome, IdempotencyKey, StoredResponse};
+
+/// How long a request can hold an idempotency key without finishing before it is assumed to have
+/// been abandoned, and the key can be claimed again.
+const STALE_AFTER: Duration = Duration::from_mins(5);
+
+/// What to do with a request that was made with an idempotency key.
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    /// This is the first time that the key was used, so the request should be handled.
+    Proceed,
+
+    /// The same request was already handled, so the response to it should be sent again.
+    Replay(StoredResponse),
+
+    /// The key was already used for a different request.
+    Mismatch,
+
+    /// The same request is still being handled.
+    InProgress,
+}
+
+/// Service layer for making unsafe requests safe to retry.
+pub struct IdempotencyService {
+    repository: Arc<dyn IdempotencyRepository>,
+    window:     Duration,
+}
+
+impl IdempotencyService {
+    /// Create a new idempotency service.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to store idempotency keys in
+    /// - `window` - How long to keep idempotency keys for, during which requests are replayed
+    pub fn new(repository: Arc<dyn IdempotencyRepository>, window: Duration) -> Self {
+        Self { repository, window }
+    }
+
+    /// Start handling a request that was made with an idempotency key.
+    ///
+    /// # Parameters
+    /// - `key` - The idempotency key
+    /// - `scope` - The scope that the key belongs to
+    /// - `fingerprint` - The fingerprint of the request
+    ///
+    /// # Returns
+    /// What to do with the request.
+    #[tracing::instrument(skip(self))]
+    pub async fn begin(&self, key: &IdempotencyKey, scope: &str, fingerprint: &str) -> Result<Outcome, ClaimKeyError> {
+        self.begin_at(key, scope, fingerprint, Utc::now()).await
+    }
+
+    /// Start handling a request that was made with an idempotency key, as of a given time.
+    ///
+    /// # Parameters
+    /// - `key` - The idempotency key
+    /// - `scope` - The scope that the key belongs to
+    /// - `fingerprint` - The fingerprint of the request
+    /// - `now` - The current time
+    ///
+    /// # Returns
+    /// What to do with the request.
+    async fn begin_at(&self, key: &IdempotencyKey, scope: &str, fingerprint: &str, now: DateTime<Utc>) -> Result<Outcome, ClaimKeyError> {
+        let expired_before = now - chrono::Duration::from_std(self.window).unwrap();
+        let stale_before = now - chrono::Duration::from_std(STALE_AFTER).unwrap();
+
+        let outcome = match self
+            .repository
+            .claim_key(key, scope, fingerprint, now, expired_before, stale_before)
+            .await?
+        {
+            ClaimOutcome::Claimed => Outcome::Proceed,
+            ClaimOutcome::Existing(record) if record.fingerprint != fingerprint => Outcome::Mismatch,
+            ClaimOutcome::Existing(record) => record.response.map_or(Outcome::InProgress, Outcome::Replay),
+        };
+        tracing::debug!(outcome = ?outcome, "Checked idempotency key");
+
+        Ok(outcome)
+    }
+
+    /// Record the response to a request, so that it can be replayed.
+    ///
+    /// # Parameters
+    /// - `key` - The idempotency key
+    /// - `scope` - The scope that the key belongs to
+    /// - `response` - The response that was sent
+    #[tracing::instrument(skip(self, response))]
+    pub async fn complete(&self, key: &IdempotencyKey, scope: &str, response: &StoredResponse) {
+        self.repository.complete_key(key, scope, response, Utc::now()).await;
+    }
+
+    /// Give up on a request without recording a response, so that it can be retried for real.
+    ///
+    /// # Parameters
+    /// - `key` - The idempotency key
+    /// - `scope` - The scope that the key belongs to
+    #[tracing::instrument(skip(self))]
+    pub async fn release(&self, key: &IdempotencyKey, scope: &str) {
+        self.repository.release_key(key, scope).await;
+    }
+
+    /// Delete the idempotency keys that are older than the window.
+    ///
+    /// # Returns
+    /// The number of keys that were deleted.
+    #[tracing::instrument(skip(self))]
+    pub async fn purge(&self) -> u64 {
+        let expired_before = Utc::now() - chrono::Duration::from_std(self.window).unwrap();
+
+        self.repository.purge_keys(expired_before).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::idempotency::MemoryIdempotencyRepository;
+
+    const WINDOW: Duration = Duration::from_hours(24);
+
+    fn response() -> StoredResponse {
+        StoredResponse {
+            status_code: 201,
+            headers:     vec![("content-type".to_owned(), "application/json".to_owned())],
+            body:        b"{}".to_vec(),
+        }
+    }
+
+    fn service() -> (Arc<MemoryIdempotencyRepository>, IdempotencyService) {
+        let repository = Arc::new(MemoryIdempotencyRepository::new());
+        let sut = IdempotencyService::new(repository.clone(), WINDOW);
+
+        (repository, sut)
+    }
+
+    #[actix_rt::test]
+    async fn replay_completed_request() {
+        let (_, sut) = service();
+        let key: IdempotencyKey = "abc".parse().unwrap();
+
+        check!(sut.begin(&key, "", "fingerprint").await == Ok(Outcome::Proceed));
+        check!(sut.begin(&key, "", "fingerprint").await == Ok(Outcome::InProgress));
+
+        sut.complete(&key, "", &response()).await;
+        check!(sut.begin(&key, "", "fingerprint").await == Ok(Outcome::Replay(response())));
+    }
+
+    #[actix_rt::test]
+    async fn reject_different_request() {
+        let (_, sut) = service();
+        let key: IdempotencyKey = "abc".parse().unwrap();
+
+        check!(sut.begin(&key, "", "fingerprint").await == Ok(Outcome::Proceed));
+        check!(sut.begin(&key, "", "other").await == Ok(Outcome::Mismatch));
+
+        sut.complete(&key, "", &response()).await;
+        check!(sut.begin(&key, "", "other").await == Ok(Outcome::Mismatch));
+    }
+
+    #[actix_rt::test]
+    async fn keys_are_scoped() {
+        let (_, sut) = service();
+        let key: IdempotencyKey = "abc".parse().unwrap();
+
+        check!(sut.begin(&key, "alice", "fingerprint").await == Ok(Outcome::Proceed));
+        check!(sut.begin(&key, "bob", "other").await == Ok(Outcome::Proceed));
+    }
+
+    #[actix_rt::test]
+    async fn release_key() {
+        let (repository, sut) = service();
+        let key: IdempotencyKey = "abc".parse().unwrap();
+
+        check!(sut.begin(&key, "", "fingerprint").await == Ok(Outcome::Proceed));
+        sut.release(&key, "").await;
+        check!(repository.get("abc", "").is_none());
+
+        check!(sut.begin(&key, "", "other").await == Ok(Outcome::Proceed));
+    }
+
+    #[actix_rt::test]
+    async fn reclaim_abandoned_and_expired_keys() {
+        let (_, sut) = service();
+        let key: IdempotencyKey = "abc".parse().unwrap();
+        let start = Utc::now();
+
+        check!(sut.begin_at(&key, "", "fingerprint", start).await == Ok(Outcome::Proceed));
+        let later = start + chrono::Duration::from_std(STALE_AFTER).unwrap() + chrono::Duration::seconds(1);
+        check!(sut.begin_at(&key, "", "fingerprint", later).await == Ok(Outcome::Proceed));
+
+        sut.complete(&key, "", &response()).await;
+        check!(sut.begin_at(&key, "", "other", later).await == Ok(Outcome::Mismatch));
+
+        let expired = later + chrono::Duration::from_std(WINDOW).unwrap() + chrono::Duration::seconds(1);
+        check!(sut.begin_at(&key, "", "other", expired).await == Ok(Outcome::Proceed));
+    }
+
+    #[actix_rt::test]
+    async fn purge_expired_keys() {
+        let (repository, sut) = service();
+        let key: IdempotencyKey = "abc".parse().unwrap();
+        let long_ago = Utc::now() - chrono::Duration::from_std(WINDOW).unwrap() - chrono::Duration::seconds(1);
+
+        check!(sut.begin_at(&key, "", "fingerprint", long_ago).await == Ok(Outcome::Proceed));
+        check!(sut.begin(&key, "other", "fingerprint").await == Ok(Outcome::Proceed));
+
+        check!(sut.purge().await == 1);
+        check!(repository.get("abc", "").is_none());
+        let_assert!(Some(_) = repository.get("abc", "other"));
+    }
+}
@@ -0,0 +1,16 @@
+pub mod component;
+mod job;
+mod model;
+mod purge_jobs;
+mod registry;
+mod repository;
+mod service;
+mod worker;
+
+pub use job::{Job, JobError};
+pub use model::*;
+pub use purge_jobs::*;
+pub use registry::*;
+pub use repository::*;
+pub use service::*;
+pub use worker::*;
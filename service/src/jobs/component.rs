@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use super::{repository::JobRepository, JobRegistry, JobService, JobWorker, PurgeJobs, PurgeJobsPayload};
+
+/// How many days to keep finished jobs for before purging them.
+const RETENTION_DAYS: u32 = 14;
+
+/// Component for queueing work to be done in the background, and for doing it.
+pub struct Component {
+    pub service: Arc<JobService>,
+    pub worker:  Arc<JobWorker>,
+}
+
+impl Component {
+    /// Create a new jobs component.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository that the queue is stored in
+    /// - `registry` - The jobs that other components need the worker to run
+    /// - `concurrency` - The number of jobs that the worker runs at once
+    pub fn new(repository: Arc<dyn JobRepository>, registry: JobRegistry, concurrency: usize) -> Arc<Self> {
+        let registry = registry.with_job(PurgeJobs::new(repository.clone())).with_schedule::<PurgeJobs>(
+            "jobs.purge",
+            "30 3 * * *",
+            &PurgeJobsPayload {
+                retention_days: RETENTION_DAYS,
+            },
+        );
+
+        let service = Arc::new(JobService::new(repository.clone()));
+        let worker = Arc::new(JobWorker::new(repository, registry, concurrency));
+
+        Arc::new(Self { service, worker })
+    }
+
+    /// Start running jobs, in the background.
+    pub fn work(&self) {
+        actix_rt::spawn(self.worker.clone().run());
+    }
+}
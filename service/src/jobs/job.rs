@@ -0,0 +1,139 @@
+use std::{cmp::min, time::Duration};
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+/// How many times a job is attempted, unless it says otherwise.
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 5;
+
+/// How long to wait before retrying a job for the first time, unless it says otherwise. Each retry
+/// after that waits twice as long as the one before it.
+const FIRST_RETRY: Duration = Duration::from_secs(10);
+
+/// The longest to wait between retries of a job, unless it says otherwise.
+const MAX_RETRY: Duration = Duration::from_hours(1);
+
+/// How long a job can run for before it is abandoned, unless it says otherwise.
+const DEFAULT_TIMEOUT: Duration = Duration::from_mins(5);
+
+/// Why a job failed.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum JobError {
+    /// The job failed in a way that might not happen again, so it should be retried.
+    #[error("{0}")]
+    Retry(String),
+
+    /// The job failed in a way that will happen every time, so there's no point retrying it.
+    #[error("{0}")]
+    Fatal(String),
+}
+
+/// A kind of work that is done in the background, outside of any request.
+///
+/// Jobs are queued with a payload, which is stored until a worker runs the job with it. A job can
+/// be run more than once if a worker stops part way through, so running it should be safe to
+/// repeat.
+///
+/// Running a job doesn't need to be `Send`, since the worker runs every job on its own thread and
+/// the HTTP client that some jobs use is tied to the thread that it runs on.
+#[async_trait::async_trait(?Send)]
+pub trait Job: Send + Sync + 'static {
+    /// The name that jobs of this kind are queued under. This must never change once jobs have been
+    /// queued with it.
+    const KIND: &'static str;
+
+    /// The most times to attempt a job before giving up on it.
+    const MAX_ATTEMPTS: u32 = DEFAULT_MAX_ATTEMPTS;
+
+    /// How long a job can run for before it is abandoned, and counted as having failed.
+    const TIMEOUT: Duration = DEFAULT_TIMEOUT;
+
+    /// The details of the work to do.
+    type Payload: Serialize + DeserializeOwned + Send;
+
+    /// Run a single job.
+    ///
+    /// # Parameters
+    /// - `payload` - The details of the work to do
+    async fn run(&self, payload: Self::Payload) -> Result<(), JobError>;
+
+    /// Determine how long to wait before retrying a job that failed.
+    ///
+    /// # Parameters
+    /// - `attempts` - The number of attempts that have failed so far
+    fn retry_delay(attempts: u32) -> Duration {
+        exponential_backoff(attempts, FIRST_RETRY, MAX_RETRY)
+    }
+}
+
+/// A job with the type of its payload erased, so that jobs of every kind can be run alike.
+#[async_trait::async_trait(?Send)]
+pub(super) trait ErasedJob: Send + Sync {
+    /// The most times to attempt a job before giving up on it.
+    fn max_attempts(&self) -> u32;
+
+    /// How long a job can run for before it is abandoned.
+    fn timeout(&self) -> Duration;
+
+    /// Determine how long to wait before retrying a job that failed.
+    fn retry_delay(&self, attempts: u32) -> Duration;
+
+    /// Run a single job with a payload that hasn't been checked yet.
+    async fn run(&self, payload: Value) -> Result<(), JobError>;
+}
+
+#[async_trait::async_trait(?Send)]
+impl<J> ErasedJob for J
+where
+    J: Job,
+{
+    fn max_attempts(&self) -> u32 {
+        J::MAX_ATTEMPTS
+    }
+
+    fn timeout(&self) -> Duration {
+        J::TIMEOUT
+    }
+
+    fn retry_delay(&self, attempts: u32) -> Duration {
+        J::retry_delay(attempts)
+    }
+
+    async fn run(&self, payload: Value) -> Result<(), JobError> {
+        let payload = serde_json::from_value(payload).map_err(|e| JobError::Fatal(format!("Invalid payload: {}", e)))?;
+
+        Job::run(self, payload).await
+    }
+}
+
+/// Determine how long to wait before a retry, doubling the wait each time up to a limit.
+///
+/// # Parameters
+/// - `attempts` - The number of attempts that have failed so far
+/// - `first` - How long to wait before the first retry
+/// - `max` - The longest to wait
+pub fn exponential_backoff(attempts: u32, first: Duration, max: Duration) -> Duration {
+    let delay = 2_u32
+        .checked_pow(attempts.saturating_sub(1))
+        .and_then(|factor| first.checked_mul(factor))
+        .unwrap_or(max);
+
+    min(delay, max)
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(1, 10 ; "First retry")]
+    #[test_case(2, 20 ; "Second retry")]
+    #[test_case(4, 80 ; "Fourth retry")]
+    #[test_case(10, 3600 ; "Capped")]
+    #[test_case(u32::MAX, 3600 ; "Far beyond")]
+    fn test_exponential_backoff(attempts: u32, expected: u64) {
+        check!(exponential_backoff(attempts, FIRST_RETRY, MAX_RETRY) == Duration::from_secs(expected));
+    }
+}
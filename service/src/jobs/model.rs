@@ -0,0 +1,48 @@
+mod cron_schedule;
+mod job_id;
+mod job_status;
+
+use chrono::{DateTime, Utc};
+pub use cron_schedule::*;
+pub use job_id::*;
+pub use job_status::*;
+use serde_json::Value;
+
+/// A job in the queue.
+#[derive(Debug, Clone)]
+pub struct JobRecord {
+    pub job_id:     JobId,
+    /// The kind of job, which determines what runs it.
+    pub kind:       String,
+    /// The details of the work to do, in whatever form the kind of job expects.
+    pub payload:    Value,
+    pub status:     JobStatus,
+    /// The number of times that the job has been claimed by a worker.
+    pub attempts:   u32,
+    /// When the job is next due to run. While it is running, this is when the worker's claim on it
+    /// lapses.
+    pub run_at:     DateTime<Utc>,
+    /// Why the job last failed, if it has.
+    pub last_error: Option<String>,
+    pub created:    DateTime<Utc>,
+    /// When the job succeeded, or was given up on.
+    pub finished:   Option<DateTime<Utc>>,
+}
+
+/// Details needed to queue a new job.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewJob {
+    pub kind:    String,
+    pub payload: Value,
+    pub run_at:  DateTime<Utc>,
+}
+
+/// A job that is queued on a schedule, rather than in response to something happening.
+#[derive(Debug, Clone)]
+pub struct RecurringJob {
+    /// The name of the schedule, which stays the same across restarts of the service.
+    pub name:     String,
+    pub kind:     String,
+    pub payload:  Value,
+    pub schedule: CronSchedule,
+}
@@ -0,0 +1,267 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, Duration, TimeZone, Timelike, Utc};
+
+/// How far ahead to look for the next time that a schedule fires before deciding that it never
+/// will, such as for the 31st of February.
+const MAX_SEARCH_YEARS: i32 = 5;
+
+/// A schedule for recurring work, written in the familiar five field cron format of
+/// `<minute> <hour> <day of month> <month> <day of week>`, and interpreted in UTC.
+///
+/// Each field is either `*`, a single value, a range such as `1-5`, or a comma-separated list of
+/// those. Any of them can be followed by a step such as `*/15`. Days of the week run from 0 for
+/// Sunday to 6 for Saturday, with 7 also meaning Sunday. As with cron, when both the day of the
+/// month and the day of the week are restricted, a day matching either of them is enough.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CronSchedule {
+    source:        String,
+    minutes:       u64,
+    hours:         u64,
+    days_of_month: u64,
+    months:        u64,
+    days_of_week:  u64,
+    /// Whether the day of the month was restricted, rather than being `*`.
+    restrict_dom:  bool,
+    /// Whether the day of the week was restricted, rather than being `*`.
+    restrict_dow:  bool,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ParseCronScheduleError {
+    #[error("The schedule must have exactly five fields")]
+    WrongFieldCount,
+
+    #[error("The {0} field of the schedule is malformed")]
+    Malformed(&'static str),
+
+    #[error("The {0} field of the schedule is out of range")]
+    OutOfRange(&'static str),
+}
+
+/// The name, and range of allowed values, of each field in a schedule.
+const FIELDS: [(&str, u32, u32); 5] = [
+    ("minute", 0, 59),
+    ("hour", 0, 23),
+    ("day of month", 1, 31),
+    ("month", 1, 12),
+    ("day of week", 0, 7),
+];
+
+impl CronSchedule {
+    /// Determine the next time that the schedule fires.
+    ///
+    /// # Parameters
+    /// - `after` - The time to start looking from. This is never returned itself
+    ///
+    /// # Returns
+    /// The next time, to the minute, or `None` if the schedule never fires.
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let mut next = after.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        let limit = after.year() + MAX_SEARCH_YEARS;
+
+        while next.year() <= limit {
+            if !contains(self.months, next.month()) {
+                next = start_of_next_month(next)?;
+            } else if !self.matches_day(next) {
+                next = start_of_day(next)? + Duration::days(1);
+            } else if !contains(self.hours, next.hour()) {
+                next = next.with_minute(0)? + Duration::hours(1);
+            } else if !contains(self.minutes, next.minute()) {
+                next = next + Duration::minutes(1);
+            } else {
+                return Some(next);
+            }
+        }
+
+        None
+    }
+
+    /// Determine if the schedule fires on the day of a time.
+    ///
+    /// # Parameters
+    /// - `time` - The time
+    fn matches_day(&self, time: DateTime<Utc>) -> bool {
+        let dom = contains(self.days_of_month, time.day());
+        let dow = contains(self.days_of_week, time.weekday().num_days_from_sunday());
+
+        match (self.restrict_dom, self.restrict_dow) {
+            (true, true) => dom || dow,
+            (true, false) => dom,
+            (false, true) => dow,
+            (false, false) => true,
+        }
+    }
+}
+
+impl std::fmt::Display for CronSchedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+impl FromStr for CronSchedule {
+    type Err = ParseCronScheduleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split_whitespace().collect();
+        if parts.len() != FIELDS.len() {
+            return Err(ParseCronScheduleError::WrongFieldCount);
+        }
+
+        let mut fields = [0_u64; 5];
+        for (i, (part, &(name, min, max))) in parts.iter().zip(FIELDS.iter()).enumerate() {
+            fields[i] = parse_field(part, name, min, max)?;
+        }
+
+        // Sunday can be written as either 0 or 7.
+        let mut days_of_week = fields[4];
+        if contains(days_of_week, 7) {
+            days_of_week = (days_of_week | 1) & !(1 << 7);
+        }
+
+        Ok(Self {
+            source: parts.join(" "),
+            minutes: fields[0],
+            hours: fields[1],
+            days_of_month: fields[2],
+            months: fields[3],
+            days_of_week,
+            restrict_dom: parts[2] != "*",
+            restrict_dow: parts[4] != "*",
+        })
+    }
+}
+
+/// Parse a single field of a schedule into the set of values that it allows.
+///
+/// # Parameters
+/// - `field` - The field
+/// - `name` - The name of the field, for reporting errors
+/// - `min` - The smallest value that the field allows
+/// - `max` - The largest value that the field allows
+///
+/// # Returns
+/// The allowed values, as a bit set.
+fn parse_field(field: &str, name: &'static str, min: u32, max: u32) -> Result<u64, ParseCronScheduleError> {
+    let malformed = || ParseCronScheduleError::Malformed(name);
+    let number = |s: &str| -> Result<u32, ParseCronScheduleError> {
+        let value = s.parse::<u32>().map_err(|_| malformed())?;
+        if value < min || value > max {
+            Err(ParseCronScheduleError::OutOfRange(name))
+        } else {
+            Ok(value)
+        }
+    };
+
+    let mut values = 0;
+    for item in field.split(',') {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u32>().map_err(|_| malformed())?),
+            None => (item, 1),
+        };
+        if step == 0 {
+            return Err(malformed());
+        }
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (number(start)?, number(end)?)
+        } else {
+            let value = number(range)?;
+            // A single value with a step runs on to the end of the range, as with `5/15`.
+            (value, if step > 1 { max } else { value })
+        };
+        if start > end {
+            return Err(malformed());
+        }
+
+        for value in (start..=end).step_by(step as usize) {
+            values |= 1 << value;
+        }
+    }
+
+    Ok(values)
+}
+
+/// Determine if a set of values contains a value.
+///
+/// # Parameters
+/// - `values` - The set of values, as a bit set
+/// - `value` - The value
+fn contains(values: u64, value: u32) -> bool {
+    values & (1 << value) != 0
+}
+
+/// Get the start of the day of a time.
+fn start_of_day(time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    time.with_hour(0)?.with_minute(0)
+}
+
+/// Get the start of the month after the month of a time.
+fn start_of_next_month(time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let (year, month) = if time.month() == 12 {
+        (time.year() + 1, 1)
+    } else {
+        (time.year(), time.month() + 1)
+    };
+
+    Utc.ymd_opt(year, month, 1).single().map(|date| date.and_hms(0, 0, 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("* * * * *", "2021-08-30T10:15:30Z", "2021-08-30T10:16:00Z" ; "Every minute")]
+    #[test_case("*/15 * * * *", "2021-08-30T10:15:00Z", "2021-08-30T10:30:00Z" ; "Every fifteen minutes")]
+    #[test_case("30 3 * * *", "2021-08-30T10:15:00Z", "2021-08-31T03:30:00Z" ; "Daily")]
+    #[test_case("30 3 * * *", "2021-08-31T01:00:00Z", "2021-08-31T03:30:00Z" ; "Daily later today")]
+    #[test_case("0 0 1 * *", "2021-12-15T00:00:00Z", "2022-01-01T00:00:00Z" ; "Monthly over the year end")]
+    #[test_case("0 9 * * 1-5", "2021-09-03T09:00:00Z", "2021-09-06T09:00:00Z" ; "Weekdays over the weekend")]
+    #[test_case("0 12 * * 7", "2021-08-30T10:00:00Z", "2021-09-05T12:00:00Z" ; "Sunday as seven")]
+    #[test_case("0 0 13 * 5", "2021-08-01T00:00:00Z", "2021-08-06T00:00:00Z" ; "Day of month or day of week")]
+    #[test_case("0 0 29 2 *", "2021-03-01T00:00:00Z", "2024-02-29T00:00:00Z" ; "Leap day")]
+    #[test_case("5,35 1-2 * * *", "2021-08-30T01:35:00Z", "2021-08-30T02:05:00Z" ; "Lists and ranges")]
+    #[test_case("5/20 * * * *", "2021-08-30T10:30:00Z", "2021-08-30T10:45:00Z" ; "Stepped from a value")]
+    fn test_next_after(schedule: &str, after: &str, expected: &str) {
+        let schedule: CronSchedule = schedule.parse().unwrap();
+
+        let_assert!(Some(next) = schedule.next_after(after.parse().unwrap()));
+        check!(next == expected.parse::<DateTime<Utc>>().unwrap());
+    }
+
+    #[test]
+    fn test_never_fires() {
+        let schedule: CronSchedule = "0 0 31 2 *".parse().unwrap();
+
+        check!(schedule.next_after("2021-08-30T10:15:30Z".parse().unwrap()).is_none());
+    }
+
+    #[test]
+    fn test_display() {
+        let schedule: CronSchedule = "  30   3 * *  * ".parse().unwrap();
+
+        check!(schedule.to_string() == "30 3 * * *");
+    }
+
+    #[test_case("" , &ParseCronScheduleError::WrongFieldCount ; "Blank")]
+    #[test_case("* * * *" , &ParseCronScheduleError::WrongFieldCount ; "Too few fields")]
+    #[test_case("* * * * * *" , &ParseCronScheduleError::WrongFieldCount ; "Too many fields")]
+    #[test_case("60 * * * *" , &ParseCronScheduleError::OutOfRange("minute") ; "Minute out of range")]
+    #[test_case("* 24 * * *" , &ParseCronScheduleError::OutOfRange("hour") ; "Hour out of range")]
+    #[test_case("* * 0 * *" , &ParseCronScheduleError::OutOfRange("day of month") ; "Day of month out of range")]
+    #[test_case("* * * 13 *" , &ParseCronScheduleError::OutOfRange("month") ; "Month out of range")]
+    #[test_case("* * * * 8" , &ParseCronScheduleError::OutOfRange("day of week") ; "Day of week out of range")]
+    #[test_case("*/0 * * * *" , &ParseCronScheduleError::Malformed("minute") ; "Zero step")]
+    #[test_case("5-1 * * * *" , &ParseCronScheduleError::Malformed("minute") ; "Backwards range")]
+    #[test_case("* * * JAN *" , &ParseCronScheduleError::Malformed("month") ; "Names")]
+    fn test_parse_fail(input: &str, expected: &ParseCronScheduleError) {
+        let_assert!(Err(e) = input.parse::<CronSchedule>());
+        check!(&e == expected);
+    }
+}
@@ -0,0 +1,94 @@
+use std::str::FromStr;
+
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+/// Where a job in the queue has got to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JobStatus {
+    /// The job is waiting to be run, either for the first time or to be retried.
+    Pending,
+    /// A worker has claimed the job and is running it.
+    Running,
+    /// The job finished successfully.
+    Succeeded,
+    /// The job failed too many times, or in a way that retrying won't help, and has been put aside
+    /// to be looked at.
+    Dead,
+}
+
+/// All of the statuses that a job can have.
+const ALL_STATUSES: &[JobStatus] = &[JobStatus::Pending, JobStatus::Running, JobStatus::Succeeded, JobStatus::Dead];
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ParseJobStatusError {
+    #[error("The job status is not known")]
+    Unknown,
+}
+
+impl JobStatus {
+    /// The name of the status, as used in the database.
+    pub fn name(self) -> &'static str {
+        match self {
+            JobStatus::Pending => "pending",
+            JobStatus::Running => "running",
+            JobStatus::Succeeded => "succeeded",
+            JobStatus::Dead => "dead",
+        }
+    }
+}
+
+impl FromStr for JobStatus {
+    type Err = ParseJobStatusError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ALL_STATUSES
+            .iter()
+            .find(|status| status.name() == s)
+            .copied()
+            .ok_or(ParseJobStatusError::Unknown)
+    }
+}
+
+impl ToSql for JobStatus {
+    accepts!(TEXT, VARCHAR);
+    to_sql_checked!();
+
+    fn to_sql(&self, t: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.name().to_sql(t, w)
+    }
+}
+
+impl<'a> FromSql<'a> for JobStatus {
+    accepts!(TEXT, VARCHAR);
+
+    fn from_sql(t: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        let name = <&str>::from_sql(t, raw)?;
+
+        Ok(name.parse()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("pending", JobStatus::Pending ; "Pending")]
+    #[test_case("running", JobStatus::Running ; "Running")]
+    #[test_case("succeeded", JobStatus::Succeeded ; "Succeeded")]
+    #[test_case("dead", JobStatus::Dead ; "Dead")]
+    fn test_parse_status(input: &str, expected: JobStatus) {
+        let_assert!(Ok(status) = input.parse::<JobStatus>());
+        check!(status == expected);
+        check!(status.name() == input);
+    }
+
+    #[test]
+    fn test_parse_unknown() {
+        let_assert!(Err(e) = "failed".parse::<JobStatus>());
+        check!(e == ParseJobStatusError::Unknown);
+    }
+}
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use super::{repository::JobRepository, Job, JobError};
+
+/// Details of which jobs to purge.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurgeJobsPayload {
+    /// How many days to keep finished jobs for.
+    pub retention_days: u32,
+}
+
+/// Job that deletes the jobs that finished a while ago, so that the queue doesn't grow forever.
+pub struct PurgeJobs {
+    repository: Arc<dyn JobRepository>,
+}
+
+impl PurgeJobs {
+    /// Create the job.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository that the queue is stored in
+    pub fn new(repository: Arc<dyn JobRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Job for PurgeJobs {
+    type Payload = PurgeJobsPayload;
+
+    const KIND: &'static str = "jobs.purge";
+
+    async fn run(&self, payload: PurgeJobsPayload) -> Result<(), JobError> {
+        let finished_before = Utc::now() - chrono::Duration::days(i64::from(payload.retention_days));
+        let count = self.repository.purge_jobs(finished_before).await;
+        tracing::info!(count = count, finished_before = ?finished_before, "Purged finished jobs");
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+
+    use super::*;
+    use crate::jobs::{MemoryJobRepository, NewJob};
+
+    #[actix_rt::test]
+    async fn purge_finished_jobs() {
+        let repository = Arc::new(MemoryJobRepository::new());
+        let new_job = NewJob {
+            kind:    "test.job".to_owned(),
+            payload: serde_json::json!({}),
+            run_at:  Utc::now(),
+        };
+        let finished = repository.enqueue_job(&new_job).await.unwrap();
+        let pending = repository.enqueue_job(&new_job).await.unwrap();
+        repository.complete_job(finished).await;
+
+        let sut = PurgeJobs::new(repository.clone());
+
+        // Nothing has been finished for long enough yet.
+        check!(sut.run(PurgeJobsPayload { retention_days: 1 }).await == Ok(()));
+        check!(repository.all_jobs().len() == 2);
+
+        check!(sut.run(PurgeJobsPayload { retention_days: 0 }).await == Ok(()));
+        let remaining: Vec<_> = repository.all_jobs().iter().map(|j| j.job_id).collect();
+        check!(remaining == vec![pending]);
+    }
+}
@@ -0,0 +1,82 @@
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use super::{job::ErasedJob, Job, RecurringJob};
+
+/// The kinds of job that a worker knows how to run, and the schedules of the recurring ones.
+#[derive(Default)]
+pub struct JobRegistry {
+    jobs:      HashMap<&'static str, Arc<dyn ErasedJob>>,
+    schedules: Vec<RecurringJob>,
+}
+
+impl JobRegistry {
+    /// Register a kind of job, so that the worker runs the jobs of that kind.
+    ///
+    /// # Parameters
+    /// - `job` - The job
+    pub fn with_job<J>(mut self, job: J) -> Self
+    where
+        J: Job,
+    {
+        self.jobs.insert(J::KIND, Arc::new(job));
+
+        self
+    }
+
+    /// Register a schedule to queue a job on.
+    ///
+    /// # Parameters
+    /// - `name` - The name of the schedule, which must stay the same across restarts of the service
+    /// - `schedule` - When to queue the job, in cron format
+    /// - `payload` - The details of the job to queue each time
+    ///
+    /// # Panics
+    /// If the schedule isn't valid, since it is part of the code rather than something a user
+    /// entered.
+    pub fn with_schedule<J>(mut self, name: &str, schedule: &str, payload: &J::Payload) -> Self
+    where
+        J: Job,
+    {
+        let schedule = schedule.parse().unwrap_or_else(|e| panic!("Invalid schedule for {}: {}", name, e));
+
+        self.schedules.push(RecurringJob {
+            name: name.to_owned(),
+            kind: J::KIND.to_owned(),
+            payload: serde_json::to_value(payload).expect("Failed to serialize job payload"),
+            schedule,
+        });
+
+        self
+    }
+
+    /// Get the kinds of job that are registered.
+    pub fn kinds(&self) -> Vec<String> {
+        self.jobs.keys().map(|&kind| kind.to_owned()).collect()
+    }
+
+    /// Get the registered job of a kind.
+    ///
+    /// # Parameters
+    /// - `kind` - The kind of job
+    pub(super) fn get(&self, kind: &str) -> Option<&Arc<dyn ErasedJob>> {
+        self.jobs.get(kind)
+    }
+
+    /// Get the schedules that are registered.
+    pub fn schedules(&self) -> &[RecurringJob] {
+        &self.schedules
+    }
+
+    /// Get the schedule with a name.
+    ///
+    /// # Parameters
+    /// - `name` - The name of the schedule
+    pub fn schedule(&self, name: &str) -> Option<&RecurringJob> {
+        self.schedules.iter().find(|s| s.name == name)
+    }
+
+    /// Get the longest that any of the registered jobs can run for.
+    pub fn longest_timeout(&self) -> Duration {
+        self.jobs.values().map(|job| job.timeout()).max().unwrap_or_default()
+    }
+}
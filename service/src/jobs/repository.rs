@@ -0,0 +1,111 @@
+mod enqueue_job;
+#[cfg(test)]
+mod memory;
+mod parse;
+mod postgres;
+
+use chrono::{DateTime, Utc};
+pub use enqueue_job::EnqueueJobError;
+#[cfg(test)]
+pub use memory::MemoryJobRepository;
+pub use postgres::PostgresJobRepository;
+
+use super::{JobId, JobRecord, NewJob, RecurringJob};
+
+/// Repository of the queue of background jobs, and of the schedules that queue recurring jobs.
+///
+/// Every method is safe to call from any number of workers at once, whichever instance of the
+/// service they belong to.
+#[async_trait::async_trait]
+pub trait JobRepository: Send + Sync {
+    /// Add a new job to the queue.
+    ///
+    /// # Parameters
+    /// - `job` - The details of the job.
+    ///
+    /// # Returns
+    /// The ID of the queued job.
+    async fn enqueue_job(&self, job: &NewJob) -> Result<JobId, EnqueueJobError>;
+
+    /// Get a single job.
+    ///
+    /// # Parameters
+    /// - `job_id` - The ID of the job.
+    ///
+    /// # Returns
+    /// The job, or `None` if it couldn't be found.
+    async fn get_job(&self, job_id: JobId) -> Option<JobRecord>;
+
+    /// Claim the job that has been due the longest, so that no other worker runs it at the same
+    /// time. A job that is still running after its claim lapses is assumed to have been abandoned,
+    /// and can be claimed again.
+    ///
+    /// # Parameters
+    /// - `kinds` - The kinds of job that can be claimed.
+    /// - `now` - The current time.
+    /// - `lease_until` - When the claim lapses, if the job hasn't finished by then.
+    ///
+    /// # Returns
+    /// The claimed job, or `None` if nothing is due.
+    async fn claim_job(&self, kinds: &[String], now: DateTime<Utc>, lease_until: DateTime<Utc>) -> Option<JobRecord>;
+
+    /// Record that a job succeeded.
+    ///
+    /// # Parameters
+    /// - `job_id` - The ID of the job.
+    async fn complete_job(&self, job_id: JobId);
+
+    /// Record that a job failed, and put it back in the queue to be retried.
+    ///
+    /// # Parameters
+    /// - `job_id` - The ID of the job.
+    /// - `run_at` - When to retry the job.
+    /// - `error` - Why the job failed.
+    async fn retry_job(&self, job_id: JobId, run_at: DateTime<Utc>, error: &str);
+
+    /// Record that a job failed, and give up on it.
+    ///
+    /// # Parameters
+    /// - `job_id` - The ID of the job.
+    /// - `error` - Why the job failed.
+    async fn bury_job(&self, job_id: JobId, error: &str);
+
+    /// Delete the jobs that finished a while ago, whether they succeeded or were given up on.
+    ///
+    /// # Parameters
+    /// - `finished_before` - The time that the jobs must have finished before.
+    ///
+    /// # Returns
+    /// The number of jobs that were deleted.
+    async fn purge_jobs(&self, finished_before: DateTime<Utc>) -> u64;
+
+    /// Save a schedule for a recurring job. A schedule that already exists keeps its next run,
+    /// unless the times that it runs at have changed.
+    ///
+    /// # Parameters
+    /// - `recurring` - The recurring job.
+    /// - `next_run` - When the job is next due to be queued.
+    async fn save_schedule(&self, recurring: &RecurringJob, next_run: DateTime<Utc>);
+
+    /// Get the schedules that are due to queue their jobs.
+    ///
+    /// # Parameters
+    /// - `now` - The current time.
+    ///
+    /// # Returns
+    /// The name of each schedule that is due, along with when it was due.
+    async fn get_due_schedules(&self, now: DateTime<Utc>) -> Vec<(String, DateTime<Utc>)>;
+
+    /// Queue the job for a schedule that is due, and move the schedule on to its next run. Only one
+    /// caller can do this for each run of the schedule.
+    ///
+    /// # Parameters
+    /// - `name` - The name of the schedule.
+    /// - `due` - When the schedule was due, as returned by `get_due_schedules`.
+    /// - `next_run` - When the schedule is next due.
+    /// - `job` - The job to queue.
+    ///
+    /// # Returns
+    /// True if the job was queued. False if another caller got there first.
+    async fn fire_schedule(&self, name: &str, due: DateTime<Utc>, next_run: DateTime<Utc>, job: &NewJob) -> bool;
+}
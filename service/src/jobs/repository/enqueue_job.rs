@@ -0,0 +1,14 @@
+/// Errors that can occur when queueing a job.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum EnqueueJobError {
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl From<tokio_postgres::Error> for EnqueueJobError {
+    fn from(e: tokio_postgres::Error) -> Self {
+        tracing::warn!("Unexpected database error: {:?}", e);
+
+        EnqueueJobError::UnknownError
+    }
+}
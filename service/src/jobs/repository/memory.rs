@@ -0,0 +1,164 @@
+use std::{convert::TryFrom, sync::Mutex};
+
+use chrono::{DateTime, Utc};
+
+use super::{EnqueueJobError, JobRepository};
+use crate::jobs::{JobId, JobRecord, JobStatus, NewJob, RecurringJob};
+
+/// Repository of background jobs that are stored in memory.
+#[derive(Default)]
+pub struct MemoryJobRepository {
+    jobs:      Mutex<Vec<JobRecord>>,
+    schedules: Mutex<Vec<(RecurringJob, DateTime<Utc>)>>,
+}
+
+impl MemoryJobRepository {
+    /// Create a new, empty job repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get every job that has been queued, oldest first.
+    pub fn all_jobs(&self) -> Vec<JobRecord> {
+        self.jobs.lock().unwrap().clone()
+    }
+
+    /// Get when a schedule is next due.
+    ///
+    /// # Parameters
+    /// - `name` - The name of the schedule
+    pub fn next_run(&self, name: &str) -> Option<DateTime<Utc>> {
+        let schedules = self.schedules.lock().unwrap();
+
+        schedules.iter().find(|(s, _)| s.name == name).map(|(_, next_run)| *next_run)
+    }
+
+    /// Queue a job, returning its ID.
+    fn push(jobs: &mut Vec<JobRecord>, job: &NewJob) -> JobId {
+        let job_id = JobId::from(i64::try_from(jobs.len()).unwrap() + 1);
+        jobs.push(JobRecord {
+            job_id,
+            kind: job.kind.clone(),
+            payload: job.payload.clone(),
+            status: JobStatus::Pending,
+            attempts: 0,
+            run_at: job.run_at,
+            last_error: None,
+            created: Utc::now(),
+            finished: None,
+        });
+
+        job_id
+    }
+
+    /// Update a single job.
+    fn update<F>(&self, job_id: JobId, f: F)
+    where
+        F: FnOnce(&mut JobRecord),
+    {
+        let mut jobs = self.jobs.lock().unwrap();
+
+        if let Some(job) = jobs.iter_mut().find(|j| j.job_id == job_id) {
+            f(job);
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl JobRepository for MemoryJobRepository {
+    async fn enqueue_job(&self, job: &NewJob) -> Result<JobId, EnqueueJobError> {
+        let mut jobs = self.jobs.lock().unwrap();
+
+        Ok(Self::push(&mut jobs, job))
+    }
+
+    async fn get_job(&self, job_id: JobId) -> Option<JobRecord> {
+        let jobs = self.jobs.lock().unwrap();
+
+        jobs.iter().find(|j| j.job_id == job_id).cloned()
+    }
+
+    async fn claim_job(&self, kinds: &[String], now: DateTime<Utc>, lease_until: DateTime<Utc>) -> Option<JobRecord> {
+        let mut jobs = self.jobs.lock().unwrap();
+
+        let job = jobs
+            .iter_mut()
+            .filter(|j| matches!(j.status, JobStatus::Pending | JobStatus::Running) && j.run_at <= now && kinds.contains(&j.kind))
+            .min_by_key(|j| j.run_at)?;
+        job.status = JobStatus::Running;
+        job.attempts += 1;
+        job.run_at = lease_until;
+
+        Some(job.clone())
+    }
+
+    async fn complete_job(&self, job_id: JobId) {
+        self.update(job_id, |job| {
+            job.status = JobStatus::Succeeded;
+            job.last_error = None;
+            job.finished = Some(Utc::now());
+        });
+    }
+
+    async fn retry_job(&self, job_id: JobId, run_at: DateTime<Utc>, error: &str) {
+        self.update(job_id, |job| {
+            job.status = JobStatus::Pending;
+            job.run_at = run_at;
+            job.last_error = Some(error.to_owned());
+        });
+    }
+
+    async fn bury_job(&self, job_id: JobId, error: &str) {
+        self.update(job_id, |job| {
+            job.status = JobStatus::Dead;
+            job.last_error = Some(error.to_owned());
+            job.finished = Some(Utc::now());
+        });
+    }
+
+    async fn purge_jobs(&self, finished_before: DateTime<Utc>) -> u64 {
+        let mut jobs = self.jobs.lock().unwrap();
+
+        let before = jobs.len();
+        jobs.retain(|j| j.finished.is_none_or(|finished| finished >= finished_before));
+
+        u64::try_from(before - jobs.len()).unwrap()
+    }
+
+    async fn save_schedule(&self, recurring: &RecurringJob, next_run: DateTime<Utc>) {
+        let mut schedules = self.schedules.lock().unwrap();
+
+        match schedules.iter_mut().find(|(s, _)| s.name == recurring.name) {
+            Some((existing, existing_next_run)) => {
+                if existing.schedule != recurring.schedule {
+                    *existing_next_run = next_run;
+                }
+                *existing = recurring.clone();
+            },
+            None => schedules.push((recurring.clone(), next_run)),
+        }
+    }
+
+    async fn get_due_schedules(&self, now: DateTime<Utc>) -> Vec<(String, DateTime<Utc>)> {
+        let schedules = self.schedules.lock().unwrap();
+
+        schedules
+            .iter()
+            .filter(|(_, next_run)| *next_run <= now)
+            .map(|(s, next_run)| (s.name.clone(), *next_run))
+            .collect()
+    }
+
+    async fn fire_schedule(&self, name: &str, due: DateTime<Utc>, next_run: DateTime<Utc>, job: &NewJob) -> bool {
+        let mut schedules = self.schedules.lock().unwrap();
+
+        match schedules.iter_mut().find(|(s, existing)| s.name == name && *existing == due) {
+            Some((_, existing)) => {
+                *existing = next_run;
+                Self::push(&mut self.jobs.lock().unwrap(), job);
+                true
+            },
+            None => false,
+        }
+    }
+}
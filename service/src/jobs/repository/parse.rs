@@ -0,0 +1,23 @@
+use std::convert::TryFrom;
+
+use tokio_postgres::Row;
+
+use crate::jobs::JobRecord;
+
+impl From<Row> for JobRecord {
+    fn from(row: Row) -> Self {
+        let attempts: i32 = row.get("attempts");
+
+        JobRecord {
+            job_id:     row.get("job_id"),
+            kind:       row.get("kind"),
+            payload:    row.get("payload"),
+            status:     row.get("status"),
+            attempts:   u32::try_from(attempts).unwrap_or_default(),
+            run_at:     row.get("run_at"),
+            last_error: row.get("last_error"),
+            created:    row.get("created"),
+            finished:   row.get("finished"),
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+use super::{EnqueueJobError, JobRepository};
+use crate::{
+    database::Database,
+    jobs::{JobId, JobRecord, JobStatus, NewJob, RecurringJob},
+};
+
+/// Repository of background jobs that are stored in Postgres.
+pub struct PostgresJobRepository {
+    database: Arc<Database>,
+}
+
+impl PostgresJobRepository {
+    /// Create a new job repository.
+    pub fn new(database: Arc<Database>) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait::async_trait]
+impl JobRepository for PostgresJobRepository {
+    #[tracing::instrument(skip(self))]
+    async fn enqueue_job(&self, job: &NewJob) -> Result<JobId, EnqueueJobError> {
+        let conn = self.database.connect().await;
+
+        let row = conn
+            .query_one(
+                "INSERT INTO jobs(kind, payload, run_at) VALUES ($1, $2, $3) RETURNING job_id",
+                &[&job.kind, &job.payload, &job.run_at],
+            )
+            .await?;
+
+        Ok(row.get("job_id"))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_job(&self, job_id: JobId) -> Option<JobRecord> {
+        let conn = self.database.connect().await;
+        conn.query_opt("SELECT * FROM jobs WHERE job_id = $1", &[&job_id])
+            .await
+            .map_err(|e| {
+                tracing::warn!(e = ?e, "Failed to load job");
+            })
+            .ok()
+            .flatten()
+            .map(JobRecord::from)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn claim_job(&self, kinds: &[String], now: DateTime<Utc>, lease_until: DateTime<Utc>) -> Option<JobRecord> {
+        let conn = self.database.connect().await;
+        conn.query_opt(
+            "UPDATE jobs SET status = $4, attempts = attempts + 1, run_at = $3
+              WHERE job_id = (
+                SELECT job_id FROM jobs
+                 WHERE status IN ('pending', 'running') AND run_at <= $2 AND kind = ANY($1)
+                 ORDER BY run_at
+                 LIMIT 1
+                   FOR UPDATE SKIP LOCKED
+              )
+              RETURNING *",
+            &[&kinds, &now, &lease_until, &JobStatus::Running],
+        )
+        .await
+        .map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to claim job");
+        })
+        .ok()
+        .flatten()
+        .map(JobRecord::from)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn complete_job(&self, job_id: JobId) {
+        let conn = self.database.connect().await;
+        if let Err(e) = conn
+            .execute(
+                "UPDATE jobs SET status = $2, last_error = NULL, finished = NOW() WHERE job_id = $1",
+                &[&job_id, &JobStatus::Succeeded],
+            )
+            .await
+        {
+            tracing::warn!(e = ?e, "Failed to record job success");
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn retry_job(&self, job_id: JobId, run_at: DateTime<Utc>, error: &str) {
+        let conn = self.database.connect().await;
+        if let Err(e) = conn
+            .execute(
+                "UPDATE jobs SET status = $2, run_at = $3, last_error = $4 WHERE job_id = $1",
+                &[&job_id, &JobStatus::Pending, &run_at, &error],
+            )
+            .await
+        {
+            tracing::warn!(e = ?e, "Failed to record job for retry");
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn bury_job(&self, job_id: JobId, error: &str) {
+        let conn = self.database.connect().await;
+        if let Err(e) = conn
+            .execute(
+                "UPDATE jobs SET status = $2, last_error = $3, finished = NOW() WHERE job_id = $1",
+                &[&job_id, &JobStatus::Dead, &error],
+            )
+            .await
+        {
+            tracing::warn!(e = ?e, "Failed to record dead job");
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn purge_jobs(&self, finished_before: DateTime<Utc>) -> u64 {
+        let conn = self.database.connect().await;
+        conn.execute("DELETE FROM jobs WHERE finished < $1", &[&finished_before])
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!(e = ?e, "Failed to purge jobs");
+                0
+            })
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn save_schedule(&self, recurring: &RecurringJob, next_run: DateTime<Utc>) {
+        let conn = self.database.connect().await;
+        if let Err(e) = conn
+            .execute(
+                "INSERT INTO job_schedules(name, kind, payload, schedule, next_run) VALUES ($1, $2, $3, $4, $5)
+                 ON CONFLICT (name) DO UPDATE SET
+                   kind = EXCLUDED.kind,
+                   payload = EXCLUDED.payload,
+                   schedule = EXCLUDED.schedule,
+                   next_run = CASE WHEN job_schedules.schedule = EXCLUDED.schedule THEN job_schedules.next_run ELSE EXCLUDED.next_run END",
+                &[
+                    &recurring.name,
+                    &recurring.kind,
+                    &recurring.payload,
+                    &recurring.schedule.to_string(),
+                    &next_run,
+                ],
+            )
+            .await
+        {
+            tracing::warn!(e = ?e, "Failed to save job schedule");
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_due_schedules(&self, now: DateTime<Utc>) -> Vec<(String, DateTime<Utc>)> {
+        let conn = self.database.connect().await;
+        conn.query("SELECT name, next_run FROM job_schedules WHERE next_run <= $1", &[&now])
+            .await
+            .map_or_else(
+                |e| {
+                    tracing::warn!(e = ?e, "Failed to load due job schedules");
+                    vec![]
+                },
+                |rows| rows.into_iter().map(|row| (row.get("name"), row.get("next_run"))).collect(),
+            )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn fire_schedule(&self, name: &str, due: DateTime<Utc>, next_run: DateTime<Utc>, job: &NewJob) -> bool {
+        let mut conn = self.database.connect().await;
+        let tx = conn.begin().await;
+
+        let result = async move {
+            let moved = tx
+                .execute(
+                    "UPDATE job_schedules SET next_run = $3 WHERE name = $1 AND next_run = $2",
+                    &[&name, &due, &next_run],
+                )
+                .await?;
+            if moved == 1 {
+                tx.execute(
+                    "INSERT INTO jobs(kind, payload, run_at) VALUES ($1, $2, $3)",
+                    &[&job.kind, &job.payload, &job.run_at],
+                )
+                .await?;
+            }
+            tx.commit().await?;
+
+            Ok::<_, tokio_postgres::Error>(moved == 1)
+        }
+        .await;
+
+        result.unwrap_or_else(|e| {
+            tracing::warn!(e = ?e, "Failed to queue job for schedule");
+            false
+        })
+    }
+}
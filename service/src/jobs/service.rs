@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+use super::{repository::JobRepository, EnqueueJobError, Job, JobId, JobRecord, NewJob};
+
+/// Service layer for queueing work to be done in the background.
+pub struct JobService {
+    repository: Arc<dyn JobRepository>,
+}
+
+impl JobService {
+    /// Create a new job service.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to queue jobs in
+    pub fn new(repository: Arc<dyn JobRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Queue a job to be run as soon as possible.
+    ///
+    /// # Parameters
+    /// - `payload` - The details of the work to do
+    ///
+    /// # Returns
+    /// The ID of the queued job.
+    pub async fn enqueue<J>(&self, payload: &J::Payload) -> Result<JobId, EnqueueJobError>
+    where
+        J: Job,
+    {
+        self.enqueue_at::<J>(payload, Utc::now()).await
+    }
+
+    /// Queue a job to be run at a later time.
+    ///
+    /// # Parameters
+    /// - `payload` - The details of the work to do
+    /// - `run_at` - The earliest time to run the job
+    ///
+    /// # Returns
+    /// The ID of the queued job.
+    #[tracing::instrument(skip(self, payload), fields(kind = J::KIND))]
+    pub async fn enqueue_at<J>(&self, payload: &J::Payload, run_at: DateTime<Utc>) -> Result<JobId, EnqueueJobError>
+    where
+        J: Job,
+    {
+        let job_id = self
+            .repository
+            .enqueue_job(&NewJob {
+                kind: J::KIND.to_owned(),
+                payload: serde_json::to_value(payload).expect("Failed to serialize job payload"),
+                run_at,
+            })
+            .await?;
+        tracing::debug!(job_id = %job_id, "Queued job");
+
+        Ok(job_id)
+    }
+
+    /// Get a single job.
+    ///
+    /// # Parameters
+    /// - `job_id` - The ID of the job
+    ///
+    /// # Returns
+    /// The job, or `None` if it doesn't exist.
+    pub async fn get_job(&self, job_id: JobId) -> Option<JobRecord> {
+        self.repository.get_job(job_id).await
+    }
+}
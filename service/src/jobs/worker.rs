@@ -0,0 +1,422 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::{DateTime, Utc};
+
+use super::{repository::JobRepository, JobError, JobRecord, JobRegistry, NewJob};
+
+/// How long each runner waits before looking for work again, once the queue is empty.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How often to look for schedules that are due to queue their jobs.
+const SCHEDULE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// How much longer than a job is allowed to run for that the claim on it lasts, so that a slow job
+/// isn't claimed by another runner while it is being recorded.
+const LEASE_MARGIN: Duration = Duration::from_mins(1);
+
+/// Worker that runs the jobs in the queue, along with queueing the recurring ones on their
+/// schedules.
+pub struct JobWorker {
+    repository:  Arc<dyn JobRepository>,
+    registry:    JobRegistry,
+    /// The number of jobs to run at once.
+    concurrency: usize,
+}
+
+impl JobWorker {
+    /// Create a new job worker.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository that the queue is stored in
+    /// - `registry` - The jobs that the worker knows how to run
+    /// - `concurrency` - The number of jobs to run at once
+    pub fn new(repository: Arc<dyn JobRepository>, registry: JobRegistry, concurrency: usize) -> Self {
+        Self {
+            repository,
+            registry,
+            concurrency: concurrency.max(1),
+        }
+    }
+
+    /// Run jobs for as long as the service is running.
+    pub async fn run(self: Arc<Self>) {
+        tracing::info!(concurrency = self.concurrency, "Starting job worker");
+        self.save_schedules(Utc::now()).await;
+
+        let runners = (0..self.concurrency).map(|_| {
+            let worker = self.clone();
+            async move {
+                loop {
+                    if !worker.run_next(Utc::now()).await {
+                        actix_rt::time::sleep(POLL_INTERVAL).await;
+                    }
+                }
+            }
+        });
+        let scheduler = async {
+            let mut interval = actix_rt::time::interval(SCHEDULE_INTERVAL);
+            loop {
+                interval.tick().await;
+                self.queue_scheduled(Utc::now()).await;
+            }
+        };
+
+        futures::join!(futures::future::join_all(runners), scheduler);
+    }
+
+    /// Save the schedules of the recurring jobs, so that every instance of the service queues them
+    /// from the same place.
+    ///
+    /// # Parameters
+    /// - `now` - The current time
+    pub async fn save_schedules(&self, now: DateTime<Utc>) {
+        for recurring in self.registry.schedules() {
+            if let Some(next_run) = recurring.schedule.next_after(now) {
+                self.repository.save_schedule(recurring, next_run).await;
+            } else {
+                tracing::warn!(name = ?recurring.name, schedule = %recurring.schedule, "Schedule never runs");
+            }
+        }
+    }
+
+    /// Queue the jobs for every schedule that is due.
+    ///
+    /// # Parameters
+    /// - `now` - The current time
+    ///
+    /// # Returns
+    /// The number of jobs that were queued.
+    #[tracing::instrument(skip(self))]
+    pub async fn queue_scheduled(&self, now: DateTime<Utc>) -> usize {
+        let mut queued = 0;
+
+        for (name, due) in self.repository.get_due_schedules(now).await {
+            // Schedules that are no longer registered are left alone, in case an instance of the
+            // service that still has them is running alongside this one.
+            let Some(recurring) = self.registry.schedule(&name) else {
+                continue;
+            };
+            let Some(next_run) = recurring.schedule.next_after(now) else {
+                continue;
+            };
+
+            let job = NewJob {
+                kind:    recurring.kind.clone(),
+                payload: recurring.payload.clone(),
+                run_at:  now,
+            };
+            if self.repository.fire_schedule(&name, due, next_run, &job).await {
+                tracing::debug!(name = ?name, next_run = ?next_run, "Queued scheduled job");
+                queued += 1;
+            }
+        }
+
+        queued
+    }
+
+    /// Claim the next job that is due and run it, recording how it went.
+    ///
+    /// # Parameters
+    /// - `now` - The current time
+    ///
+    /// # Returns
+    /// True if a job was run. False if nothing was due.
+    pub async fn run_next(&self, now: DateTime<Utc>) -> bool {
+        let lease = self.registry.longest_timeout() + LEASE_MARGIN;
+        let lease_until = now + chrono::Duration::from_std(lease).unwrap();
+
+        match self.repository.claim_job(&self.registry.kinds(), now, lease_until).await {
+            Some(job) => {
+                self.run_job(job, now).await;
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Run a single job that has been claimed, and record how it went.
+    ///
+    /// # Parameters
+    /// - `job` - The job
+    /// - `now` - The current time, which any retry is scheduled from
+    #[tracing::instrument(skip(self, job, now), fields(job_id = %job.job_id, kind = ?job.kind, attempts = job.attempts))]
+    async fn run_job(&self, job: JobRecord, now: DateTime<Utc>) {
+        let Some(handler) = self.registry.get(&job.kind) else {
+            // Only the registered kinds are ever claimed, so this can't happen.
+            tracing::error!("Claimed a job of an unknown kind");
+            return;
+        };
+
+        // A job that was claimed too many times without finishing has probably been taking its
+        // worker down with it, so it isn't run again.
+        if job.attempts > handler.max_attempts() {
+            tracing::warn!("Job was abandoned too many times");
+            self.repository
+                .bury_job(job.job_id, "The job was abandoned too many times without finishing")
+                .await;
+            return;
+        }
+
+        let result = actix_rt::time::timeout(handler.timeout(), handler.run(job.payload))
+            .await
+            .unwrap_or_else(|_| Err(JobError::Retry("The job took too long".to_owned())));
+
+        match result {
+            Ok(()) => {
+                tracing::debug!("Job succeeded");
+                self.repository.complete_job(job.job_id).await;
+            },
+            Err(JobError::Retry(e)) if job.attempts < handler.max_attempts() => {
+                let delay = handler.retry_delay(job.attempts);
+                tracing::info!(e = ?e, delay = ?delay, "Job failed and will be retried");
+                let run_at = now + chrono::Duration::from_std(delay).unwrap();
+                self.repository.retry_job(job.job_id, run_at, &e).await;
+            },
+            Err(JobError::Retry(e) | JobError::Fatal(e)) => {
+                tracing::warn!(e = ?e, "Job failed and has been given up on");
+                self.repository.bury_job(job.job_id, &e).await;
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::VecDeque, sync::Mutex};
+
+    use assert2::{check, let_assert};
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::jobs::{Job, JobService, JobStatus, MemoryJobRepository};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Greeting {
+        name: String,
+    }
+
+    /// Job that records what it was run with, and fails as many times as it is told to.
+    #[derive(Default)]
+    struct GreetJob {
+        outcomes: Mutex<VecDeque<Result<(), JobError>>>,
+        greeted:  Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait::async_trait(?Send)]
+    impl Job for GreetJob {
+        type Payload = Greeting;
+
+        const KIND: &'static str = "test.greet";
+        const MAX_ATTEMPTS: u32 = 3;
+
+        async fn run(&self, payload: Greeting) -> Result<(), JobError> {
+            self.greeted.lock().unwrap().push(payload.name);
+
+            self.outcomes.lock().unwrap().pop_front().unwrap_or(Ok(()))
+        }
+
+        fn retry_delay(attempts: u32) -> Duration {
+            Duration::from_secs(u64::from(attempts) * 60)
+        }
+    }
+
+    struct Fixture {
+        repository: Arc<MemoryJobRepository>,
+        service:    JobService,
+        worker:     JobWorker,
+        greeted:    Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Fixture {
+        fn new(outcomes: Vec<Result<(), JobError>>) -> Self {
+            let repository = Arc::new(MemoryJobRepository::new());
+            let job = GreetJob {
+                outcomes: Mutex::new(outcomes.into()),
+                ..GreetJob::default()
+            };
+            let greeted = job.greeted.clone();
+            let registry = JobRegistry::default().with_job(job).with_schedule::<GreetJob>(
+                "greet-hourly",
+                "0 * * * *",
+                &Greeting { name: "Hourly".to_owned() },
+            );
+
+            Self {
+                service: JobService::new(repository.clone()),
+                worker: JobWorker::new(repository.clone(), registry, 2),
+                repository,
+                greeted,
+            }
+        }
+
+        async fn enqueue(&self, name: &str) -> crate::jobs::JobId {
+            self.service.enqueue::<GreetJob>(&Greeting { name: name.to_owned() }).await.unwrap()
+        }
+    }
+
+    #[actix_rt::test]
+    async fn run_job() {
+        let fixture = Fixture::new(vec![]);
+        let job_id = fixture.enqueue("Frodo").await;
+
+        check!(fixture.worker.run_next(Utc::now()).await);
+        check!(!fixture.worker.run_next(Utc::now()).await);
+
+        check!(*fixture.greeted.lock().unwrap() == vec!["Frodo".to_owned()]);
+        let_assert!(Some(job) = fixture.service.get_job(job_id).await);
+        check!(job.status == JobStatus::Succeeded);
+        check!(job.attempts == 1);
+        check!(job.finished.is_some());
+    }
+
+    #[actix_rt::test]
+    async fn run_job_when_due() {
+        let fixture = Fixture::new(vec![]);
+        let now = Utc::now();
+        fixture
+            .service
+            .enqueue_at::<GreetJob>(&Greeting { name: "Sam".to_owned() }, now + chrono::Duration::minutes(5))
+            .await
+            .unwrap();
+
+        check!(!fixture.worker.run_next(now).await);
+        check!(fixture.worker.run_next(now + chrono::Duration::minutes(5)).await);
+        check!(*fixture.greeted.lock().unwrap() == vec!["Sam".to_owned()]);
+    }
+
+    #[actix_rt::test]
+    async fn retry_with_backoff() {
+        let fixture = Fixture::new(vec![Err(JobError::Retry("Try again".to_owned()))]);
+        let job_id = fixture.enqueue("Merry").await;
+        let now = Utc::now();
+
+        check!(fixture.worker.run_next(now).await);
+        let_assert!(Some(job) = fixture.service.get_job(job_id).await);
+        check!(job.status == JobStatus::Pending);
+        check!(job.last_error == Some("Try again".to_owned()));
+        check!(job.run_at == now + chrono::Duration::minutes(1));
+
+        check!(!fixture.worker.run_next(now).await);
+        check!(fixture.worker.run_next(job.run_at).await);
+
+        let_assert!(Some(job) = fixture.service.get_job(job_id).await);
+        check!(job.status == JobStatus::Succeeded);
+        check!(job.attempts == 2);
+        check!(job.last_error.is_none());
+    }
+
+    #[actix_rt::test]
+    async fn dead_letter_after_max_attempts() {
+        let fixture = Fixture::new(vec![
+            Err(JobError::Retry("First".to_owned())),
+            Err(JobError::Retry("Second".to_owned())),
+            Err(JobError::Retry("Third".to_owned())),
+        ]);
+        let job_id = fixture.enqueue("Pippin").await;
+
+        let mut now = Utc::now();
+        for _ in 0..3 {
+            check!(fixture.worker.run_next(now).await);
+            now = now + chrono::Duration::hours(1);
+        }
+        check!(!fixture.worker.run_next(now).await);
+
+        let_assert!(Some(job) = fixture.service.get_job(job_id).await);
+        check!(job.status == JobStatus::Dead);
+        check!(job.attempts == 3);
+        check!(job.last_error == Some("Third".to_owned()));
+        check!(job.finished.is_some());
+    }
+
+    #[actix_rt::test]
+    async fn dead_letter_fatal_error() {
+        let fixture = Fixture::new(vec![Err(JobError::Fatal("Never going to work".to_owned()))]);
+        let job_id = fixture.enqueue("Gollum").await;
+
+        check!(fixture.worker.run_next(Utc::now()).await);
+
+        let_assert!(Some(job) = fixture.service.get_job(job_id).await);
+        check!(job.status == JobStatus::Dead);
+        check!(job.attempts == 1);
+        check!(job.last_error == Some("Never going to work".to_owned()));
+    }
+
+    #[actix_rt::test]
+    async fn dead_letter_invalid_payload() {
+        let fixture = Fixture::new(vec![]);
+        let job_id = fixture
+            .repository
+            .enqueue_job(&NewJob {
+                kind:    GreetJob::KIND.to_owned(),
+                payload: serde_json::json!({ "unexpected": true }),
+                run_at:  Utc::now(),
+            })
+            .await
+            .unwrap();
+
+        check!(fixture.worker.run_next(Utc::now()).await);
+
+        let_assert!(Some(job) = fixture.service.get_job(job_id).await);
+        check!(job.status == JobStatus::Dead);
+        check!(fixture.greeted.lock().unwrap().is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn reclaim_abandoned_job() {
+        let fixture = Fixture::new(vec![]);
+        let job_id = fixture.enqueue("Bilbo").await;
+        let now = Utc::now();
+
+        // Claimed by a worker that then went away without recording anything.
+        let_assert!(
+            Some(_) = fixture
+                .repository
+                .claim_job(&[GreetJob::KIND.to_owned()], now, now + chrono::Duration::minutes(1))
+                .await
+        );
+        check!(!fixture.worker.run_next(now).await);
+
+        check!(fixture.worker.run_next(now + chrono::Duration::minutes(1)).await);
+        let_assert!(Some(job) = fixture.service.get_job(job_id).await);
+        check!(job.status == JobStatus::Succeeded);
+        check!(job.attempts == 2);
+    }
+
+    #[actix_rt::test]
+    async fn ignore_unknown_kinds() {
+        let fixture = Fixture::new(vec![]);
+        fixture
+            .repository
+            .enqueue_job(&NewJob {
+                kind:    "test.unknown".to_owned(),
+                payload: serde_json::json!({}),
+                run_at:  Utc::now(),
+            })
+            .await
+            .unwrap();
+
+        check!(!fixture.worker.run_next(Utc::now()).await);
+    }
+
+    #[actix_rt::test]
+    async fn queue_scheduled_jobs() {
+        let fixture = Fixture::new(vec![]);
+        let start: DateTime<Utc> = "2021-08-30T10:15:00Z".parse().unwrap();
+
+        fixture.worker.save_schedules(start).await;
+        let_assert!(Some(next_run) = fixture.repository.next_run("greet-hourly"));
+        check!(next_run == "2021-08-30T11:00:00Z".parse::<DateTime<Utc>>().unwrap());
+
+        check!(fixture.worker.queue_scheduled(start).await == 0);
+        check!(fixture.worker.queue_scheduled(next_run).await == 1);
+        check!(fixture.worker.queue_scheduled(next_run).await == 0);
+        check!(fixture.repository.next_run("greet-hourly") == Some("2021-08-30T12:00:00Z".parse().unwrap()));
+
+        // Restarting keeps the schedule where it had got to.
+        fixture.worker.save_schedules(start).await;
+        check!(fixture.repository.next_run("greet-hourly") == Some("2021-08-30T12:00:00Z".parse().unwrap()));
+
+        check!(fixture.worker.run_next(next_run).await);
+        check!(*fixture.greeted.lock().unwrap() == vec!["Hourly".to_owned()]);
+    }
+}
@@ -1,17 +1,38 @@
 #![deny(clippy::all, clippy::pedantic)]
 #![allow(clippy::module_name_repetitions, dead_code)]
 
+mod articles;
 mod authentication;
 mod authorization;
+mod blobs;
+mod calendars;
+mod changes;
+mod comments;
 mod database;
+mod entities;
 mod http;
+mod idempotency;
+mod jobs;
+mod maps;
 mod model;
+mod outbox;
+mod realtime;
+mod relationships;
+mod revisions;
+mod search;
 mod server;
 mod service;
+mod sessions;
 mod settings;
+mod taxonomy;
 #[cfg(test)]
 mod tests;
+mod timeline;
+mod tokens;
+mod uploads;
 mod users;
+mod webhooks;
+mod worlds;
 
 use config::{Config, Environment};
 use dotenv::dotenv;
@@ -45,6 +66,35 @@ async fn main() {
 fn load_settings() -> settings::Settings {
     let mut s = Config::new();
     s.set_default("port", 8000).expect("Failed to set default value for 'port'");
+    s.set_default("password_min_length", 8)
+        .expect("Failed to set default value for 'password_min_length'");
+    s.set_default("password_min_strength", 3)
+        .expect("Failed to set default value for 'password_min_strength'");
+    s.set_default("password_memory_size", 19456)
+        .expect("Failed to set default value for 'password_memory_size'");
+    s.set_default("password_iterations", 2)
+        .expect("Failed to set default value for 'password_iterations'");
+    s.set_default("password_lanes", 1)
+        .expect("Failed to set default value for 'password_lanes'");
+    s.set_default("blob_store", "filesystem")
+        .expect("Failed to set default value for 'blob_store'");
+    s.set_default("blob_path", "uploads")
+        .expect("Failed to set default value for 'blob_path'");
+    s.set_default("s3_region", "us-east-1")
+        .expect("Failed to set default value for 's3_region'");
+    s.set_default("upload_max_size", 10 * 1024 * 1024)
+        .expect("Failed to set default value for 'upload_max_size'");
+    s.set_default("mode", "all").expect("Failed to set default value for 'mode'");
+    s.set_default("job_concurrency", 4)
+        .expect("Failed to set default value for 'job_concurrency'");
+    s.set_default("outbox_sinks", "log")
+        .expect("Failed to set default value for 'outbox_sinks'");
+    s.set_default("outbox_nats_prefix", "worlds")
+        .expect("Failed to set default value for 'outbox_nats_prefix'");
+    s.set_default("outbox_kafka_topic", "worlds-events")
+        .expect("Failed to set default value for 'outbox_kafka_topic'");
+    s.set_default("idempotency_window_hours", 24)
+        .expect("Failed to set default value for 'idempotency_window_hours'");
 
     s.merge(Environment::default()).expect("Failed to load environment properties");
 
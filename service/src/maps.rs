@@ -0,0 +1,10 @@
+pub mod component;
+mod endpoints;
+mod model;
+mod repository;
+mod service;
+mod tiles;
+
+pub use model::*;
+pub use repository::*;
+pub use service::*;
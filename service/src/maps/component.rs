@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use actix_web::web::{delete, get, patch, post, resource, ServiceConfig};
+
+use super::{repository::MapRepository, service::MapService};
+use crate::{articles::ArticleService, blobs::BlobStore, entities::EntityService, server::RouteConfigurer, uploads::UploadService};
+
+/// Component for working with the maps of worlds.
+pub struct Component {
+    pub service: Arc<MapService>,
+}
+
+impl Component {
+    /// Create a new maps component.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store maps, layers and markers in
+    /// - `uploads` - The service to load the base images of maps from
+    /// - `store` - The blob store to store the tiles of maps in
+    /// - `entities` - The service to check the locations that markers link to against
+    /// - `articles` - The service to check the articles that markers link to against
+    pub fn new(
+        repository: Arc<dyn MapRepository>,
+        uploads: Arc<UploadService>,
+        store: Arc<dyn BlobStore>,
+        entities: Arc<EntityService>,
+        articles: Arc<ArticleService>,
+    ) -> Arc<Self> {
+        let service = Arc::new(MapService::new(repository, uploads, store, entities, articles));
+
+        Arc::new(Self { service })
+    }
+}
+
+impl RouteConfigurer for Component {
+    fn configure_routes(&self, config: &mut ServiceConfig) {
+        config.data(self.service.clone());
+
+        config.service(
+            resource("/worlds/{id}/maps")
+                .route(post().to(super::endpoints::create_map::handle))
+                .route(get().to(super::endpoints::list_maps::handle)),
+        );
+        config.service(
+            resource("/worlds/{id}/maps/{map}")
+                .route(get().to(super::endpoints::get_map::handle))
+                .route(patch().to(super::endpoints::patch_map::handle))
+                .route(delete().to(super::endpoints::delete_map::handle)),
+        );
+        config.service(resource("/worlds/{id}/maps/{map}/tiles/{z}/{x}/{y}").route(get().to(super::endpoints::get_tile::handle)));
+        config.service(
+            resource("/worlds/{id}/maps/{map}/layers")
+                .route(post().to(super::endpoints::create_layer::handle))
+                .route(get().to(super::endpoints::list_layers::handle)),
+        );
+        config.service(
+            resource("/worlds/{id}/maps/{map}/layers/{layer}")
+                .route(get().to(super::endpoints::get_layer::handle))
+                .route(patch().to(super::endpoints::patch_layer::handle))
+                .route(delete().to(super::endpoints::delete_layer::handle)),
+        );
+        config.service(
+            resource("/worlds/{id}/maps/{map}/markers")
+                .route(post().to(super::endpoints::create_marker::handle))
+                .route(get().to(super::endpoints::list_markers::handle)),
+        );
+        config.service(
+            resource("/worlds/{id}/maps/{map}/markers/{marker}")
+                .route(get().to(super::endpoints::get_marker::handle))
+                .route(patch().to(super::endpoints::patch_marker::handle))
+                .route(delete().to(super::endpoints::delete_marker::handle)),
+        );
+    }
+}
@@ -0,0 +1,125 @@
+pub(super) mod create_layer;
+pub(super) mod create_map;
+pub(super) mod create_marker;
+pub(super) mod delete_layer;
+pub(super) mod delete_map;
+pub(super) mod delete_marker;
+pub(super) mod get_layer;
+pub(super) mod get_map;
+pub(super) mod get_marker;
+pub(super) mod get_tile;
+pub(super) mod list_layers;
+pub(super) mod list_maps;
+pub(super) mod list_markers;
+mod model;
+pub(super) mod patch_layer;
+pub(super) mod patch_map;
+pub(super) mod patch_marker;
+mod problems;
+
+use self::problems::{unknown_article, unknown_layer, unknown_location, AMBIGUOUS_LINK, OUT_OF_BOUNDS};
+use crate::{
+    articles::ArticleId,
+    entities::EntityId,
+    http::problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+    maps::{CreateMarkerError, LayerId, MapId, MapResource, MapService, MarkerId, MarkerLink},
+    worlds::WorldId,
+};
+
+/// Parse the world and map IDs from the URL.
+///
+/// # Parameters
+/// - `world_id` - The ID of the world
+/// - `map_id` - The ID of the map
+///
+/// # Returns
+/// The parsed IDs, or a Not Found problem if either of them isn't valid.
+fn parse_map_path(world_id: &str, map_id: &str) -> Result<(WorldId, MapId), Problem> {
+    let world_id: WorldId = world_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, world_id = ?world_id, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    let map_id: MapId = map_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, map_id = ?map_id, "Failed to parse Map ID");
+
+        NOT_FOUND
+    })?;
+
+    Ok((world_id, map_id))
+}
+
+/// Parse the ID of a layer from the URL.
+///
+/// # Parameters
+/// - `layer_id` - The ID of the layer
+///
+/// # Returns
+/// The parsed ID, or a Not Found problem if it isn't valid.
+fn parse_layer_id(layer_id: &str) -> Result<LayerId, Problem> {
+    layer_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, layer_id = ?layer_id, "Failed to parse Layer ID");
+
+        NOT_FOUND.into()
+    })
+}
+
+/// Parse the ID of a marker from the URL.
+///
+/// # Parameters
+/// - `marker_id` - The ID of the marker
+///
+/// # Returns
+/// The parsed ID, or a Not Found problem if it isn't valid.
+fn parse_marker_id(marker_id: &str) -> Result<MarkerId, Problem> {
+    marker_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, marker_id = ?marker_id, "Failed to parse Marker ID");
+
+        NOT_FOUND.into()
+    })
+}
+
+/// Load the map that a request is working with.
+///
+/// # Parameters
+/// - `service` - The service to load the map with
+/// - `world_id` - The ID of the world that the map belongs to
+/// - `map_id` - The ID of the map
+///
+/// # Returns
+/// The map, or a Not Found problem if it doesn't exist in the world.
+async fn load_map(service: &MapService, world_id: &WorldId, map_id: &MapId) -> Result<MapResource, Problem> {
+    service.get_map(world_id, map_id).await.ok_or_else(|| NOT_FOUND.into())
+}
+
+/// Build the link of a marker from the IDs provided on the HTTP API.
+///
+/// # Parameters
+/// - `location_id` - The location that the marker links to, if any
+/// - `article_id` - The article that the marker links to, if any
+///
+/// # Returns
+/// The link, or a problem if the marker was linked to both a location and an article.
+fn marker_link(location_id: Option<EntityId>, article_id: Option<ArticleId>) -> Result<Option<MarkerLink>, Problem> {
+    match (location_id, article_id) {
+        (Some(_), Some(_)) => Err(AMBIGUOUS_LINK.into()),
+        (Some(location_id), None) => Ok(Some(MarkerLink::Location(location_id))),
+        (None, Some(article_id)) => Ok(Some(MarkerLink::Article(article_id))),
+        (None, None) => Ok(None),
+    }
+}
+
+/// Convert an error from saving a marker into the problem to return to the client.
+///
+/// # Parameters
+/// - `e` - The error
+fn marker_problem(e: CreateMarkerError) -> Problem {
+    match e {
+        CreateMarkerError::UnknownLayer(layer_id) => unknown_layer(&layer_id),
+        CreateMarkerError::OutOfBounds => OUT_OF_BOUNDS.into(),
+        CreateMarkerError::UnknownLocation(entity_id) => unknown_location(&entity_id),
+        CreateMarkerError::UnknownArticle(article_id) => unknown_article(&article_id),
+        CreateMarkerError::UnknownError => INTERNAL_SERVER_ERROR.into(),
+    }
+}
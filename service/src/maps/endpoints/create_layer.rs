@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use actix_http::http::StatusCode;
+use actix_web::web::{Data, Path};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{
+    load_map,
+    model::{respond_layer, LayerResponse},
+    parse_map_path,
+};
+use crate::{
+    authorization::Authentication,
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR},
+        valid::{Valid, Validatable},
+    },
+    maps::{CreateLayerError, MapService, NewLayer},
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Create a new layer on a map, above all of the existing layers.
+pub async fn handle(
+    service: Data<Arc<MapService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    request: Valid<CreateLayerRequest>,
+    authentication: Authentication,
+) -> Result<LayerResponse, Problem> {
+    let (world_id, map_id) = path.into_inner();
+    let (world_id, map_id) = parse_map_path(&world_id, &map_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let map = load_map(&service, &world_id, &map_id).await?;
+    let request = request.unwrap();
+
+    let layer = service
+        .create_layer(
+            &map,
+            NewLayer {
+                name:    request.name,
+                visible: request.visible,
+            },
+        )
+        .await
+        .map_err(|e| match e {
+            CreateLayerError::UnknownError => INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok(respond_layer(layer).with_status_code(StatusCode::CREATED).into())
+}
+
+/// The incoming request to create a layer.
+#[derive(Deserialize)]
+pub struct CreateLayerRequest {
+    pub name:    String,
+    #[serde(default = "visible_by_default")]
+    pub visible: bool,
+}
+
+/// New layers are shown unless the request says otherwise.
+const fn visible_by_default() -> bool {
+    true
+}
+
+impl Validatable for CreateLayerRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 200
+                },
+                "visible": {
+                    "type": "boolean"
+                }
+            },
+            "required": [
+                "name"
+            ]
+        })
+    }
+}
@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use actix_http::http::StatusCode;
+use actix_web::web::{Data, Path};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{
+    model::{respond_map, MapResponse},
+    problems::{unknown_upload, INVALID_IMAGE, NOT_A_MAP},
+};
+use crate::{
+    authorization::Authentication,
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+        valid::{Valid, Validatable},
+    },
+    maps::{CreateMapError, MapService, NewMap},
+    tokens::Scope,
+    uploads::UploadId,
+    worlds::{Role, WorldId, WorldService},
+};
+
+/// Create a new map in a world from an image that has already been uploaded.
+pub async fn handle(
+    service: Data<Arc<MapService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<String>,
+    request: Valid<CreateMapRequest>,
+    authentication: Authentication,
+) -> Result<MapResponse, Problem> {
+    let world_id: WorldId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let request = request.unwrap();
+
+    let map = service
+        .create_map(
+            &world_id,
+            NewMap {
+                name:      request.name,
+                upload_id: request.upload_id,
+            },
+        )
+        .await
+        .map_err(|e| match e {
+            CreateMapError::UnknownUpload(upload_id) => unknown_upload(&upload_id),
+            CreateMapError::NotAMap => NOT_A_MAP.into(),
+            CreateMapError::InvalidImage => INVALID_IMAGE.into(),
+            CreateMapError::UnknownError => INTERNAL_SERVER_ERROR.into(),
+        })?;
+
+    Ok(respond_map(map).with_status_code(StatusCode::CREATED).into())
+}
+
+/// The incoming request to create a map.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateMapRequest {
+    pub name:      String,
+    pub upload_id: UploadId,
+}
+
+impl Validatable for CreateMapRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 200
+                },
+                "uploadId": UploadId::schema()
+            },
+            "required": [
+                "name",
+                "uploadId"
+            ]
+        })
+    }
+}
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use actix_http::http::StatusCode;
+use actix_web::web::{Data, Path};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{
+    load_map, marker_link, marker_problem,
+    model::{respond_marker, MarkerResponse},
+    parse_map_path,
+};
+use crate::{
+    articles::ArticleId,
+    authorization::Authentication,
+    entities::EntityId,
+    http::{
+        problem::Problem,
+        valid::{Valid, Validatable},
+    },
+    maps::{LayerId, MapService, NewMarker, Shape},
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Create a new marker on a map.
+pub async fn handle(
+    service: Data<Arc<MapService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    request: Valid<CreateMarkerRequest>,
+    authentication: Authentication,
+) -> Result<MarkerResponse, Problem> {
+    let (world_id, map_id) = path.into_inner();
+    let (world_id, map_id) = parse_map_path(&world_id, &map_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let map = load_map(&service, &world_id, &map_id).await?;
+    let request = request.unwrap();
+
+    let marker = service
+        .create_marker(
+            &map,
+            NewMarker {
+                layer_id:    request.layer_id,
+                label:       request.label,
+                description: request.description,
+                shape:       request.shape,
+                link:        marker_link(request.location_id, request.article_id)?,
+            },
+        )
+        .await
+        .map_err(marker_problem)?;
+
+    Ok(respond_marker(marker).with_status_code(StatusCode::CREATED).into())
+}
+
+/// The incoming request to create a marker.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateMarkerRequest {
+    pub layer_id:    LayerId,
+    pub label:       String,
+    pub description: Option<String>,
+    pub shape:       Shape,
+    pub location_id: Option<EntityId>,
+    pub article_id:  Option<ArticleId>,
+}
+
+impl Validatable for CreateMarkerRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "layerId": LayerId::schema(),
+                "label": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 200
+                },
+                "description": {
+                    "type": "string",
+                    "maxLength": 10000
+                },
+                "shape": Shape::schema(),
+                "locationId": EntityId::schema(),
+                "articleId": ArticleId::schema()
+            },
+            "required": [
+                "layerId",
+                "label",
+                "shape"
+            ]
+        })
+    }
+}
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use actix_web::{
+    web::{Data, Path},
+    HttpResponse,
+};
+
+use super::{load_map, parse_layer_id, parse_map_path};
+use crate::{
+    authorization::Authentication,
+    http::problem::{Problem, NOT_FOUND},
+    maps::MapService,
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Delete a layer from a map, along with all of the markers on it.
+pub async fn handle(
+    service: Data<Arc<MapService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String, String)>,
+    authentication: Authentication,
+) -> Result<HttpResponse, Problem> {
+    let (world_id, map_id, layer_id) = path.into_inner();
+    let (world_id, map_id) = parse_map_path(&world_id, &map_id)?;
+    let layer_id = parse_layer_id(&layer_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let map = load_map(&service, &world_id, &map_id).await?;
+
+    if service.delete_layer(&map, &layer_id).await {
+        Ok(HttpResponse::NoContent().finish())
+    } else {
+        Err(NOT_FOUND.into())
+    }
+}
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use actix_web::{
+    web::{Data, Path},
+    HttpResponse,
+};
+
+use super::parse_map_path;
+use crate::{
+    authorization::Authentication,
+    http::problem::{Problem, NOT_FOUND},
+    maps::MapService,
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Delete a map, along with its layers, markers and tiles. The upload that the map was created
+/// from is left alone.
+pub async fn handle(
+    service: Data<Arc<MapService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<HttpResponse, Problem> {
+    let (world_id, map_id) = path.into_inner();
+    let (world_id, map_id) = parse_map_path(&world_id, &map_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    if service.delete_map(&world_id, &map_id).await {
+        Ok(HttpResponse::NoContent().finish())
+    } else {
+        Err(NOT_FOUND.into())
+    }
+}
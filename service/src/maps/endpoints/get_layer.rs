@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::{
+    load_map,
+    model::{respond_layer, LayerResponse},
+    parse_layer_id, parse_map_path,
+};
+use crate::{
+    authorization::Authentication,
+    http::problem::{Problem, NOT_FOUND},
+    maps::MapService,
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+pub async fn handle(
+    service: Data<Arc<MapService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String, String)>,
+    authentication: Authentication,
+) -> Result<LayerResponse, Problem> {
+    let (world_id, map_id, layer_id) = path.into_inner();
+    let (world_id, map_id) = parse_map_path(&world_id, &map_id)?;
+    let layer_id = parse_layer_id(&layer_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let map = load_map(&service, &world_id, &map_id).await?;
+
+    let layer = service.get_layer(&map, &layer_id).await.ok_or(NOT_FOUND)?;
+
+    Ok(respond_layer(layer).into())
+}
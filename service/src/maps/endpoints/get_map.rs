@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::{
+    load_map,
+    model::{respond_map, MapResponse},
+    parse_map_path,
+};
+use crate::{
+    authorization::Authentication,
+    http::problem::Problem,
+    maps::MapService,
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+pub async fn handle(
+    service: Data<Arc<MapService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<MapResponse, Problem> {
+    let (world_id, map_id) = path.into_inner();
+    let (world_id, map_id) = parse_map_path(&world_id, &map_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let map = load_map(&service, &world_id, &map_id).await?;
+
+    Ok(respond_map(map).into())
+}
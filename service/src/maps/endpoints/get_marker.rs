@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::{
+    load_map,
+    model::{respond_marker, MarkerResponse},
+    parse_map_path, parse_marker_id,
+};
+use crate::{
+    authorization::Authentication,
+    http::problem::{Problem, NOT_FOUND},
+    maps::MapService,
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+pub async fn handle(
+    service: Data<Arc<MapService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String, String)>,
+    authentication: Authentication,
+) -> Result<MarkerResponse, Problem> {
+    let (world_id, map_id, marker_id) = path.into_inner();
+    let (world_id, map_id) = parse_map_path(&world_id, &map_id)?;
+    let marker_id = parse_marker_id(&marker_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let map = load_map(&service, &world_id, &map_id).await?;
+
+    let marker = service.get_marker(&map, &marker_id).await.ok_or(NOT_FOUND)?;
+
+    Ok(respond_marker(marker).into())
+}
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use actix_web::{
+    http::header::{CacheControl, CacheDirective, ETag, EntityTag},
+    web::{Data, Path},
+    HttpResponse,
+};
+
+use super::{load_map, parse_map_path};
+use crate::{
+    authorization::Authentication,
+    http::problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+    maps::MapService,
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Download a single tile of a map as a PNG image.
+///
+/// The tiles of a map never change once it has been created, so they can be cached for a long
+/// time.
+pub async fn handle(
+    service: Data<Arc<MapService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String, u32, u32, u32)>,
+    authentication: Authentication,
+) -> Result<HttpResponse, Problem> {
+    let (world_id, map_id, zoom, x, y) = path.into_inner();
+    let (world_id, map_id) = parse_map_path(&world_id, &map_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let map = load_map(&service, &world_id, &map_id).await?;
+
+    let tile = service
+        .get_tile(&map, zoom, x, y)
+        .await
+        .map_err(|e| {
+            tracing::warn!(e = ?e, map_id = ?map.identity.id, zoom = zoom, x = x, y = y, "Failed to read tile");
+
+            INTERNAL_SERVER_ERROR
+        })?
+        .ok_or(NOT_FOUND)?;
+
+    Ok(HttpResponse::Ok()
+        .content_type("image/png")
+        .insert_header(ETag(EntityTag::strong(format!("{}-{}-{}-{}", map.identity.id, zoom, x, y))))
+        .insert_header(CacheControl(vec![CacheDirective::Private, CacheDirective::MaxAge(86_400)]))
+        .body(tile))
+}
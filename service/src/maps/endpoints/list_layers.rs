@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::{
+    load_map,
+    model::{LayerModel, LayersModel},
+    parse_map_path,
+};
+use crate::{
+    authorization::Authentication,
+    http::{problem::Problem, response::Response},
+    maps::MapService,
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Get the layers of a map, from the bottom up.
+pub async fn handle(
+    service: Data<Arc<MapService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<Response<LayersModel>, Problem> {
+    let (world_id, map_id) = path.into_inner();
+    let (world_id, map_id) = parse_map_path(&world_id, &map_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let map = load_map(&service, &world_id, &map_id).await?;
+
+    let layers = service.get_layers(&map).await.into_iter().map(LayerModel::from).collect();
+
+    Ok(Response(LayersModel { layers }))
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::model::{MapModel, MapsModel};
+use crate::{
+    authorization::Authentication,
+    http::{
+        problem::{Problem, NOT_FOUND},
+        response::Response,
+    },
+    maps::MapService,
+    tokens::Scope,
+    worlds::{Role, WorldId, WorldService},
+};
+
+pub async fn handle(
+    service: Data<Arc<MapService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<String>,
+    authentication: Authentication,
+) -> Result<Response<MapsModel>, Problem> {
+    let world_id: WorldId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let maps = service.get_maps(&world_id).await.into_iter().map(MapModel::from).collect();
+
+    Ok(Response(MapsModel { maps }))
+}
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path, Query};
+use serde::Deserialize;
+
+use super::{
+    load_map,
+    model::{MarkerModel, MarkersModel},
+    parse_map_path,
+    problems::INVALID_BOUNDS,
+};
+use crate::{
+    authorization::Authentication,
+    http::{problem::Problem, response::Response},
+    maps::{BoundingBox, LayerId, MapService, MarkerFilter},
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Query parameters for selecting the markers on a map.
+#[derive(Deserialize)]
+pub struct ListMarkersQuery {
+    /// Only include markers that are at least partly within this area, given as
+    /// `minX,minY,maxX,maxY` in map coordinates.
+    pub bbox:  Option<String>,
+    /// Only include markers on this layer.
+    pub layer: Option<String>,
+}
+
+/// Get the markers on a map, in the order that they were created.
+pub async fn handle(
+    service: Data<Arc<MapService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    query: Query<ListMarkersQuery>,
+    authentication: Authentication,
+) -> Result<Response<MarkersModel>, Problem> {
+    let (world_id, map_id) = path.into_inner();
+    let (world_id, map_id) = parse_map_path(&world_id, &map_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let map = load_map(&service, &world_id, &map_id).await?;
+
+    let bounds = query
+        .bbox
+        .as_deref()
+        .map(str::parse::<BoundingBox>)
+        .transpose()
+        .map_err(|e| Problem::from(INVALID_BOUNDS).with_detail(e.to_string()))?;
+
+    // A layer that can't exist has no markers on it.
+    let layer_id = match query.layer.as_deref().map(str::parse::<LayerId>) {
+        Some(Ok(layer_id)) => Some(layer_id),
+        Some(Err(_)) => return Ok(Response(MarkersModel { markers: vec![] })),
+        None => None,
+    };
+
+    let markers = service
+        .get_markers(&map, &MarkerFilter { bounds, layer_id })
+        .await
+        .into_iter()
+        .map(MarkerModel::from)
+        .collect();
+
+    Ok(Response(MarkersModel { markers }))
+}
@@ -0,0 +1,169 @@
+use actix_web::http::header::{CacheControl, CacheDirective, ETag, EntityTag};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::{
+    articles::ArticleId,
+    entities::EntityId,
+    http::response::{Response, SimpleRespondable},
+    maps::{tiles::TILE_SIZE, LayerId, LayerResource, MapId, MapResource, MarkerId, MarkerLink, MarkerResource, Shape},
+    uploads::UploadId,
+};
+
+/// Representation of a map on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MapModel {
+    pub map_id:    MapId,
+    pub name:      String,
+    pub upload_id: Option<UploadId>,
+    pub width:     i32,
+    pub height:    i32,
+    pub max_zoom:  i32,
+    pub tile_size: u32,
+    /// The template for the URLs of the tiles of the map, with `{z}`, `{x}` and `{y}` placeholders.
+    pub tile_url:  String,
+    pub created:   DateTime<Utc>,
+    pub updated:   DateTime<Utc>,
+}
+
+impl From<MapResource> for MapModel {
+    fn from(map: MapResource) -> Self {
+        let tile_url = format!("/worlds/{}/maps/{}/tiles/{{z}}/{{x}}/{{y}}", map.data.world_id, map.identity.id);
+
+        Self {
+            map_id: map.identity.id,
+            name: map.data.name,
+            upload_id: map.data.upload_id,
+            width: map.data.width,
+            height: map.data.height,
+            max_zoom: map.data.max_zoom,
+            tile_size: TILE_SIZE,
+            tile_url,
+            created: map.identity.created,
+            updated: map.identity.updated,
+        }
+    }
+}
+
+pub type MapResponse = Response<SimpleRespondable<MapModel>>;
+
+/// Build the response for a single map.
+///
+/// # Parameters
+/// - `map` - The map
+pub fn respond_map(map: MapResource) -> SimpleRespondable<MapModel> {
+    let etag = EntityTag::strong(map.identity.version.to_string());
+
+    SimpleRespondable::new(MapModel::from(map))
+        .with_header(ETag(etag))
+        .with_header(CacheControl(vec![CacheDirective::Private, CacheDirective::NoCache]))
+}
+
+/// Representation of a list of maps on the HTTP API.
+#[derive(Serialize)]
+pub struct MapsModel {
+    pub maps: Vec<MapModel>,
+}
+
+/// Representation of a layer of a map on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LayerModel {
+    pub layer_id: LayerId,
+    pub name:     String,
+    pub position: i32,
+    pub visible:  bool,
+    pub created:  DateTime<Utc>,
+    pub updated:  DateTime<Utc>,
+}
+
+impl From<LayerResource> for LayerModel {
+    fn from(layer: LayerResource) -> Self {
+        Self {
+            layer_id: layer.identity.id,
+            name:     layer.data.name,
+            position: layer.data.position,
+            visible:  layer.data.visible,
+            created:  layer.identity.created,
+            updated:  layer.identity.updated,
+        }
+    }
+}
+
+pub type LayerResponse = Response<SimpleRespondable<LayerModel>>;
+
+/// Build the response for a single layer.
+///
+/// # Parameters
+/// - `layer` - The layer
+pub fn respond_layer(layer: LayerResource) -> SimpleRespondable<LayerModel> {
+    let etag = EntityTag::strong(layer.identity.version.to_string());
+
+    SimpleRespondable::new(LayerModel::from(layer))
+        .with_header(ETag(etag))
+        .with_header(CacheControl(vec![CacheDirective::Private, CacheDirective::NoCache]))
+}
+
+/// Representation of a list of layers on the HTTP API.
+#[derive(Serialize)]
+pub struct LayersModel {
+    pub layers: Vec<LayerModel>,
+}
+
+/// Representation of a marker on a map on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkerModel {
+    pub marker_id:   MarkerId,
+    pub layer_id:    LayerId,
+    pub label:       String,
+    pub description: Option<String>,
+    pub shape:       Shape,
+    pub location_id: Option<EntityId>,
+    pub article_id:  Option<ArticleId>,
+    pub created:     DateTime<Utc>,
+    pub updated:     DateTime<Utc>,
+}
+
+impl From<MarkerResource> for MarkerModel {
+    fn from(marker: MarkerResource) -> Self {
+        let (location_id, article_id) = match marker.data.link {
+            Some(MarkerLink::Location(entity_id)) => (Some(entity_id), None),
+            Some(MarkerLink::Article(article_id)) => (None, Some(article_id)),
+            None => (None, None),
+        };
+
+        Self {
+            marker_id: marker.identity.id,
+            layer_id: marker.data.layer_id,
+            label: marker.data.label,
+            description: marker.data.description,
+            shape: marker.data.shape,
+            location_id,
+            article_id,
+            created: marker.identity.created,
+            updated: marker.identity.updated,
+        }
+    }
+}
+
+pub type MarkerResponse = Response<SimpleRespondable<MarkerModel>>;
+
+/// Build the response for a single marker.
+///
+/// # Parameters
+/// - `marker` - The marker
+pub fn respond_marker(marker: MarkerResource) -> SimpleRespondable<MarkerModel> {
+    let etag = EntityTag::strong(marker.identity.version.to_string());
+
+    SimpleRespondable::new(MarkerModel::from(marker))
+        .with_header(ETag(etag))
+        .with_header(CacheControl(vec![CacheDirective::Private, CacheDirective::NoCache]))
+}
+
+/// Representation of a list of markers on the HTTP API.
+#[derive(Serialize)]
+pub struct MarkersModel {
+    pub markers: Vec<MarkerModel>,
+}
@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{
+    load_map,
+    model::{respond_layer, LayerResponse},
+    parse_layer_id, parse_map_path,
+};
+use crate::{
+    authorization::Authentication,
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+        valid::{Valid, Validatable},
+    },
+    maps::{LayerData, MapService, UpdateLayerError},
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Patch the details of a layer, including moving it above or below the other layers.
+pub async fn handle(
+    service: Data<Arc<MapService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String, String)>,
+    request: Valid<PatchRequest>,
+    authentication: Authentication,
+) -> Result<LayerResponse, Problem> {
+    let (world_id, map_id, layer_id) = path.into_inner();
+    let (world_id, map_id) = parse_map_path(&world_id, &map_id)?;
+    let layer_id = parse_layer_id(&layer_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let map = load_map(&service, &world_id, &map_id).await?;
+    let request = request.unwrap();
+
+    let layer = service
+        .update_layer_by_id(&map, &layer_id, move |layer| -> Result<LayerData, Problem> {
+            Ok(LayerData {
+                name: request.name.unwrap_or(layer.name),
+                position: request.position.unwrap_or(layer.position),
+                visible: request.visible.unwrap_or(layer.visible),
+                ..layer
+            })
+        })
+        .await
+        .map_err(|e| match e {
+            UpdateLayerError::UpdateError(p) => p,
+            UpdateLayerError::UnknownLayer => NOT_FOUND.into(),
+            UpdateLayerError::UnknownError => INTERNAL_SERVER_ERROR.into(),
+        })?;
+
+    Ok(respond_layer(layer).into())
+}
+
+/// The incoming request to patch a layer.
+#[derive(Deserialize)]
+pub struct PatchRequest {
+    pub name:     Option<String>,
+    pub position: Option<i32>,
+    pub visible:  Option<bool>,
+}
+
+impl Validatable for PatchRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 200
+                },
+                "position": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "maximum": 10000
+                },
+                "visible": {
+                    "type": "boolean"
+                }
+            }
+        })
+    }
+}
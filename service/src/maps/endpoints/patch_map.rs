@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{
+    model::{respond_map, MapResponse},
+    parse_map_path,
+};
+use crate::{
+    authorization::Authentication,
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+        valid::{Valid, Validatable},
+    },
+    maps::{MapService, UpdateMapError},
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Patch the details of a map. The image of a map can't be changed once the map is created.
+pub async fn handle(
+    service: Data<Arc<MapService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    request: Valid<PatchRequest>,
+    authentication: Authentication,
+) -> Result<MapResponse, Problem> {
+    let (world_id, map_id) = path.into_inner();
+    let (world_id, map_id) = parse_map_path(&world_id, &map_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let request = request.unwrap();
+
+    let map = match request.name {
+        Some(name) => service.rename_map(&world_id, &map_id, name).await.map_err(|e| match e {
+            UpdateMapError::UnknownMap => Problem::from(NOT_FOUND),
+            UpdateMapError::UnknownError => INTERNAL_SERVER_ERROR.into(),
+        })?,
+        None => service.get_map(&world_id, &map_id).await.ok_or(NOT_FOUND)?,
+    };
+
+    Ok(respond_map(map).into())
+}
+
+/// The incoming request to patch a map.
+#[derive(Deserialize)]
+pub struct PatchRequest {
+    pub name: Option<String>,
+}
+
+impl Validatable for PatchRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 200
+                }
+            }
+        })
+    }
+}
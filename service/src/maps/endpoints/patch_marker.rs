@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{
+    load_map, marker_link,
+    model::{respond_marker, MarkerResponse},
+    parse_map_path, parse_marker_id,
+    problems::{unknown_article, unknown_layer, unknown_location, OUT_OF_BOUNDS},
+};
+use crate::{
+    articles::ArticleId,
+    authorization::Authentication,
+    entities::EntityId,
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+        valid::{Valid, Validatable},
+    },
+    maps::{LayerId, MapService, MarkerData, Shape, UpdateMarkerError},
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Patch the details of a marker. Providing a location or an article replaces whatever the
+/// marker linked to before.
+pub async fn handle(
+    service: Data<Arc<MapService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String, String)>,
+    request: Valid<PatchRequest>,
+    authentication: Authentication,
+) -> Result<MarkerResponse, Problem> {
+    let (world_id, map_id, marker_id) = path.into_inner();
+    let (world_id, map_id) = parse_map_path(&world_id, &map_id)?;
+    let marker_id = parse_marker_id(&marker_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let map = load_map(&service, &world_id, &map_id).await?;
+    let PatchRequest {
+        layer_id,
+        label,
+        description,
+        shape,
+        location_id,
+        article_id,
+    } = request.unwrap();
+    let link = marker_link(location_id, article_id)?;
+
+    let marker = service
+        .update_marker_by_id(&map, &marker_id, move |marker| -> Result<MarkerData, Problem> {
+            Ok(MarkerData {
+                layer_id: layer_id.unwrap_or(marker.layer_id),
+                label: label.unwrap_or(marker.label),
+                description: description.or(marker.description),
+                shape: shape.unwrap_or(marker.shape),
+                link: link.or(marker.link),
+                ..marker
+            })
+        })
+        .await
+        .map_err(|e| match e {
+            UpdateMarkerError::UpdateError(p) => p,
+            UpdateMarkerError::UnknownMarker => NOT_FOUND.into(),
+            UpdateMarkerError::UnknownLayer(layer_id) => unknown_layer(&layer_id),
+            UpdateMarkerError::OutOfBounds => OUT_OF_BOUNDS.into(),
+            UpdateMarkerError::UnknownLocation(entity_id) => unknown_location(&entity_id),
+            UpdateMarkerError::UnknownArticle(article_id) => unknown_article(&article_id),
+            UpdateMarkerError::UnknownError => INTERNAL_SERVER_ERROR.into(),
+        })?;
+
+    Ok(respond_marker(marker).into())
+}
+
+/// The incoming request to patch a marker.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchRequest {
+    pub layer_id:    Option<LayerId>,
+    pub label:       Option<String>,
+    pub description: Option<String>,
+    pub shape:       Option<Shape>,
+    pub location_id: Option<EntityId>,
+    pub article_id:  Option<ArticleId>,
+}
+
+impl Validatable for PatchRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "layerId": LayerId::schema(),
+                "label": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 200
+                },
+                "description": {
+                    "type": "string",
+                    "maxLength": 10000
+                },
+                "shape": Shape::schema(),
+                "locationId": EntityId::schema(),
+                "articleId": ArticleId::schema()
+            }
+        })
+    }
+}
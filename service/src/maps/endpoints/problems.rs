@@ -0,0 +1,104 @@
+use actix_http::http::StatusCode;
+
+use crate::{
+    articles::ArticleId,
+    entities::EntityId,
+    http::problem::{Problem, SimpleProblemType},
+    maps::LayerId,
+    uploads::UploadId,
+};
+
+/// Problem to indicate that a map was created from an upload that doesn't exist in the world.
+pub const UNKNOWN_UPLOAD: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/maps/unknown_upload",
+    problem_title: "Unknown Upload",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate that a map was created from an upload that wasn't uploaded for a map.
+pub const NOT_A_MAP: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/maps/not_a_map",
+    problem_title: "The upload is not the image of a map",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate that the image of a map couldn't be cut into tiles.
+pub const INVALID_IMAGE: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/maps/invalid_image",
+    problem_title: "The image of the map is not valid",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate that a marker was placed on a layer that isn't part of the map.
+pub const UNKNOWN_LAYER: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/maps/unknown_layer",
+    problem_title: "Unknown Layer",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate that a marker lies outside of the map.
+pub const OUT_OF_BOUNDS: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/maps/out_of_bounds",
+    problem_title: "The marker lies outside of the map",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate that a marker links to a location that doesn't exist in the world.
+pub const UNKNOWN_LOCATION: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/maps/unknown_location",
+    problem_title: "Unknown Location",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate that a marker links to an article that doesn't exist in the world.
+pub const UNKNOWN_ARTICLE: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/maps/unknown_article",
+    problem_title: "Unknown Article",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate that a marker was linked to both a location and an article.
+pub const AMBIGUOUS_LINK: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/maps/ambiguous_link",
+    problem_title: "A marker can link to a location or an article, but not both",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate that the bounding box to search for markers within isn't valid.
+pub const INVALID_BOUNDS: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/maps/invalid_bounds",
+    problem_title: "Invalid bounding box",
+    status_code:   StatusCode::BAD_REQUEST,
+};
+
+/// Build the problem for a map created from an upload that doesn't exist.
+///
+/// # Parameters
+/// - `upload_id` - The ID of the upload that doesn't exist
+pub fn unknown_upload(upload_id: &UploadId) -> Problem {
+    Problem::from(UNKNOWN_UPLOAD).with_extra("uploadId", upload_id)
+}
+
+/// Build the problem for a marker placed on a layer that doesn't exist.
+///
+/// # Parameters
+/// - `layer_id` - The ID of the layer that doesn't exist
+pub fn unknown_layer(layer_id: &LayerId) -> Problem {
+    Problem::from(UNKNOWN_LAYER).with_extra("layerId", layer_id)
+}
+
+/// Build the problem for a marker linked to a location that doesn't exist.
+///
+/// # Parameters
+/// - `entity_id` - The ID of the location that doesn't exist
+pub fn unknown_location(entity_id: &EntityId) -> Problem {
+    Problem::from(UNKNOWN_LOCATION).with_extra("locationId", entity_id)
+}
+
+/// Build the problem for a marker linked to an article that doesn't exist.
+///
+/// # Parameters
+/// - `article_id` - The ID of the article that doesn't exist
+pub fn unknown_article(article_id: &ArticleId) -> Problem {
+    Problem::from(UNKNOWN_ARTICLE).with_extra("articleId", article_id)
+}
@@ -0,0 +1,96 @@
+mod geometry;
+mod layer_id;
+mod map_id;
+mod marker_id;
+
+pub use geometry::*;
+pub use layer_id::*;
+pub use map_id::*;
+pub use marker_id::*;
+
+use crate::{articles::ArticleId, entities::EntityId, model::Resource, uploads::UploadId, worlds::WorldId};
+
+/// The data representing a map of a world.
+///
+/// The base image of the map is cut into a pyramid of tiles when the map is created, so that
+/// viewers only need to load the parts of the map that they are looking at.
+#[derive(Debug, Clone)]
+pub struct MapData {
+    pub world_id:    WorldId,
+    pub name:        String,
+    /// The upload that the map was generated from, if it still exists.
+    pub upload_id:   Option<UploadId>,
+    /// The width of the base image, in pixels.
+    pub width:       i32,
+    /// The height of the base image, in pixels.
+    pub height:      i32,
+    /// The most detailed zoom level, at which the tiles show the base image at full resolution.
+    pub max_zoom:    i32,
+    /// The prefix of the keys of the tiles in the blob store.
+    pub tile_prefix: String,
+}
+
+/// Type representing a persisted map.
+pub type MapResource = Resource<MapId, MapData>;
+
+/// The data representing a layer of markers on a map.
+#[derive(Debug, Clone)]
+pub struct LayerData {
+    pub map_id:   MapId,
+    pub name:     String,
+    /// Where the layer is drawn relative to the other layers of the map. Lower layers are drawn
+    /// first.
+    pub position: i32,
+    /// Whether the layer is shown when the map is first opened.
+    pub visible:  bool,
+}
+
+/// Type representing a persisted layer.
+pub type LayerResource = Resource<LayerId, LayerData>;
+
+/// What a marker on a map refers to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarkerLink {
+    /// A location entity in the same world.
+    Location(EntityId),
+    /// An article in the same world.
+    Article(ArticleId),
+}
+
+/// The data representing a marker on a map.
+#[derive(Debug, Clone)]
+pub struct MarkerData {
+    pub map_id:      MapId,
+    pub layer_id:    LayerId,
+    pub label:       String,
+    pub description: Option<String>,
+    pub shape:       Shape,
+    pub link:        Option<MarkerLink>,
+}
+
+/// Type representing a persisted marker.
+pub type MarkerResource = Resource<MarkerId, MarkerData>;
+
+/// Criteria for selecting markers from a map.
+#[derive(Debug, Clone, Default)]
+pub struct MarkerFilter {
+    /// Only include markers that are at least partly within this area of the map.
+    pub bounds:   Option<BoundingBox>,
+    /// Only include markers on this layer.
+    pub layer_id: Option<LayerId>,
+}
+
+impl MapData {
+    /// Determine whether a point lies on the base image of the map.
+    pub fn contains(&self, point: &Point) -> bool {
+        (0.0..=f64::from(self.width)).contains(&point.x) && (0.0..=f64::from(self.height)).contains(&point.y)
+    }
+}
+
+impl MarkerFilter {
+    /// Determine whether a marker matches the filter.
+    pub fn matches(&self, marker: &MarkerData) -> bool {
+        self.bounds.as_ref().is_none_or(|bounds| bounds.intersects(&marker.shape.bounds()))
+            && self.layer_id.as_ref().is_none_or(|layer_id| &marker.layer_id == layer_id)
+    }
+}
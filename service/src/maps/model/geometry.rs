@@ -0,0 +1,254 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::http::valid::Validatable;
+
+/// A point on a map, measured in pixels of the base image from its top-left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// The shape of a marker on a map.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Shape {
+    /// A single point on the map.
+    Pin(Point),
+    /// An area of the map, outlined by its corners in order. The outline closes back to the first
+    /// point automatically.
+    Polygon { points: Vec<Point> },
+}
+
+/// An axis-aligned rectangle on a map. Both edges are inclusive.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundingBox {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ParseBoundingBoxError {
+    #[error("The bounding box was malformed")]
+    Malformed,
+
+    #[error("The minimum corner of the bounding box was beyond the maximum corner")]
+    Inverted,
+}
+
+impl Shape {
+    /// The points that make up the shape.
+    pub fn points(&self) -> &[Point] {
+        match self {
+            Self::Pin(point) => std::slice::from_ref(point),
+            Self::Polygon { points } => points,
+        }
+    }
+
+    /// The smallest bounding box that contains the entire shape.
+    pub fn bounds(&self) -> BoundingBox {
+        let points = self.points();
+
+        let Some((first, rest)) = points.split_first() else {
+            return BoundingBox {
+                min_x: 0.0,
+                min_y: 0.0,
+                max_x: 0.0,
+                max_y: 0.0,
+            };
+        };
+
+        rest.iter().fold(
+            BoundingBox {
+                min_x: first.x,
+                min_y: first.y,
+                max_x: first.x,
+                max_y: first.y,
+            },
+            |bounds, point| BoundingBox {
+                min_x: bounds.min_x.min(point.x),
+                min_y: bounds.min_y.min(point.y),
+                max_x: bounds.max_x.max(point.x),
+                max_y: bounds.max_y.max(point.y),
+            },
+        )
+    }
+}
+
+impl Validatable for Shape {
+    fn schema() -> Value {
+        let point = json!({
+            "type": "object",
+            "properties": {
+                "x": {
+                    "type": "number"
+                },
+                "y": {
+                    "type": "number"
+                }
+            },
+            "required": [
+                "x",
+                "y"
+            ]
+        });
+
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "type": {
+                            "enum": ["pin"]
+                        },
+                        "x": {
+                            "type": "number"
+                        },
+                        "y": {
+                            "type": "number"
+                        }
+                    },
+                    "required": [
+                        "type",
+                        "x",
+                        "y"
+                    ]
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "type": {
+                            "enum": ["polygon"]
+                        },
+                        "points": {
+                            "type": "array",
+                            "minItems": 3,
+                            "maxItems": 1000,
+                            "items": point
+                        }
+                    },
+                    "required": [
+                        "type",
+                        "points"
+                    ]
+                }
+            ]
+        })
+    }
+}
+
+impl BoundingBox {
+    /// Determine whether this bounding box overlaps another one, including just touching it.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min_x <= other.max_x && other.min_x <= self.max_x && self.min_y <= other.max_y && other.min_y <= self.max_y
+    }
+}
+
+impl FromStr for BoundingBox {
+    type Err = ParseBoundingBoxError;
+
+    /// Parse a bounding box written as `minX,minY,maxX,maxY`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let values = s
+            .split(',')
+            .map(|value| value.trim().parse::<f64>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| ParseBoundingBoxError::Malformed)?;
+
+        match values.as_slice() {
+            [min_x, min_y, max_x, max_y] if values.iter().all(|value| value.is_finite()) => {
+                if min_x > max_x || min_y > max_y {
+                    Err(ParseBoundingBoxError::Inverted)
+                } else {
+                    Ok(Self {
+                        min_x: *min_x,
+                        min_y: *min_y,
+                        max_x: *max_x,
+                        max_y: *max_y,
+                    })
+                }
+            },
+            _ => Err(ParseBoundingBoxError::Malformed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use test_case::test_case;
+
+    use super::*;
+
+    fn bbox(min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> BoundingBox {
+        BoundingBox {
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+        }
+    }
+
+    #[test]
+    fn pin_bounds() {
+        let shape = Shape::Pin(Point { x: 10.0, y: 20.0 });
+
+        check!(shape.bounds() == bbox(10.0, 20.0, 10.0, 20.0));
+    }
+
+    #[test]
+    fn polygon_bounds() {
+        let shape = Shape::Polygon {
+            points: vec![Point { x: 10.0, y: 50.0 }, Point { x: 40.0, y: 20.0 }, Point { x: 25.0, y: 70.0 }],
+        };
+
+        check!(shape.bounds() == bbox(10.0, 20.0, 40.0, 70.0));
+    }
+
+    #[test]
+    fn shape_json() {
+        let pin: Shape = serde_json::from_value(json!({"type": "pin", "x": 1.5, "y": 2})).unwrap();
+        check!(pin == Shape::Pin(Point { x: 1.5, y: 2.0 }));
+
+        let polygon = Shape::Polygon {
+            points: vec![Point { x: 0.0, y: 0.0 }],
+        };
+        check!(serde_json::to_value(&polygon).unwrap() == json!({"type": "polygon", "points": [{"x": 0.0, "y": 0.0}]}));
+    }
+
+    #[test_case(&bbox(0.0, 0.0, 10.0, 10.0), true ; "Same")]
+    #[test_case(&bbox(5.0, 5.0, 20.0, 20.0), true ; "Overlapping")]
+    #[test_case(&bbox(2.0, 2.0, 3.0, 3.0), true ; "Inside")]
+    #[test_case(&bbox(10.0, 10.0, 20.0, 20.0), true ; "Touching corner")]
+    #[test_case(&bbox(11.0, 0.0, 20.0, 10.0), false ; "Right")]
+    #[test_case(&bbox(0.0, -20.0, 10.0, -1.0), false ; "Above")]
+    fn test_intersects(other: &BoundingBox, expected: bool) {
+        let bounds = bbox(0.0, 0.0, 10.0, 10.0);
+
+        check!(bounds.intersects(other) == expected);
+        check!(other.intersects(&bounds) == expected);
+    }
+
+    #[test_case("0,0,100,50", &bbox(0.0, 0.0, 100.0, 50.0) ; "Integers")]
+    #[test_case(" -1.5, 2.25 ,3,4 ", &bbox(-1.5, 2.25, 3.0, 4.0) ; "Decimals")]
+    fn test_parse_success(input: &str, expected: &BoundingBox) {
+        let_assert!(Ok(bounds) = input.parse::<BoundingBox>());
+        check!(&bounds == expected);
+    }
+
+    #[test_case("", &ParseBoundingBoxError::Malformed ; "Blank")]
+    #[test_case("1,2,3", &ParseBoundingBoxError::Malformed ; "Too few")]
+    #[test_case("1,2,3,4,5", &ParseBoundingBoxError::Malformed ; "Too many")]
+    #[test_case("a,b,c,d", &ParseBoundingBoxError::Malformed ; "Not numbers")]
+    #[test_case("0,0,inf,1", &ParseBoundingBoxError::Malformed ; "Infinite")]
+    #[test_case("10,0,5,5", &ParseBoundingBoxError::Inverted ; "Inverted")]
+    fn test_parse_fail(input: &str, expected: &ParseBoundingBoxError) {
+        let_assert!(Err(e) = input.parse::<BoundingBox>());
+        check!(&e == expected);
+    }
+}
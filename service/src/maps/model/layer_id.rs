@@ -0,0 +1,97 @@
+use std::str::FromStr;
+
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+/// The ID of a layer on a map.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromSql)]
+pub struct LayerId(Uuid);
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ParseLayerIdError {
+    #[error("The Layer ID was blank")]
+    Blank,
+
+    #[error("The Layer ID was malformed")]
+    Malformed,
+}
+
+impl LayerId {
+    /// Generate the JSON Schema fragment for a reference to a layer.
+    pub fn schema() -> Value {
+        json!({
+            "type": "string",
+            "format": "uuid"
+        })
+    }
+}
+
+impl Default for LayerId {
+    fn default() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl std::fmt::Display for LayerId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for LayerId {
+    type Err = ParseLayerIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            Err(ParseLayerIdError::Blank)
+        } else {
+            let uuid = Uuid::parse_str(trimmed).map_err(|e| {
+                tracing::warn!(e = ?e, "Failed to parse Layer ID as UUID");
+                ParseLayerIdError::Malformed
+            })?;
+
+            Ok(LayerId(uuid))
+        }
+    }
+}
+
+impl ToSql for LayerId {
+    accepts!(UUID);
+    to_sql_checked!();
+
+    fn to_sql(&self, t: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.0.to_sql(t, w)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("50b44401-a345-419d-a8a8-baf22df76c05", "50b44401-a345-419d-a8a8-baf22df76c05" ; "Simple")]
+    #[test_case("50B44401-A345-419D-A8A8-BAF22DF76C05", "50b44401-a345-419d-a8a8-baf22df76c05" ; "Capitals")]
+    #[test_case("   50b44401-a345-419d-a8a8-baf22df76c05   ", "50b44401-a345-419d-a8a8-baf22df76c05" ; "Padded")]
+    fn test_parse_success(input: &str, expected: &str) {
+        let result: Result<LayerId, ParseLayerIdError> = input.parse();
+
+        let_assert!(Ok(output) = result);
+        check!(output.to_string() == expected);
+    }
+
+    #[test_case("", &ParseLayerIdError::Blank ; "Blank")]
+    #[test_case("   ", &ParseLayerIdError::Blank ; "Whitespace")]
+    #[test_case("xxx", &ParseLayerIdError::Malformed ; "Malformed")]
+    fn test_parse_fail(input: &str, expected: &ParseLayerIdError) {
+        let result: Result<LayerId, ParseLayerIdError> = input.parse();
+
+        let_assert!(Err(e) = result);
+        check!(&e == expected);
+    }
+}
@@ -0,0 +1,86 @@
+use std::str::FromStr;
+
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use serde::Serialize;
+use uuid::Uuid;
+
+/// The ID of a map.
+#[derive(Debug, Clone, PartialEq, Serialize, FromSql)]
+pub struct MapId(Uuid);
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ParseMapIdError {
+    #[error("The Map ID was blank")]
+    Blank,
+
+    #[error("The Map ID was malformed")]
+    Malformed,
+}
+
+impl Default for MapId {
+    fn default() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl std::fmt::Display for MapId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for MapId {
+    type Err = ParseMapIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            Err(ParseMapIdError::Blank)
+        } else {
+            let uuid = Uuid::parse_str(trimmed).map_err(|e| {
+                tracing::warn!(e = ?e, "Failed to parse Map ID as UUID");
+                ParseMapIdError::Malformed
+            })?;
+
+            Ok(MapId(uuid))
+        }
+    }
+}
+
+impl ToSql for MapId {
+    accepts!(UUID);
+    to_sql_checked!();
+
+    fn to_sql(&self, t: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.0.to_sql(t, w)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("50b44401-a345-419d-a8a8-baf22df76c05", "50b44401-a345-419d-a8a8-baf22df76c05" ; "Simple")]
+    #[test_case("50B44401-A345-419D-A8A8-BAF22DF76C05", "50b44401-a345-419d-a8a8-baf22df76c05" ; "Capitals")]
+    #[test_case("   50b44401-a345-419d-a8a8-baf22df76c05   ", "50b44401-a345-419d-a8a8-baf22df76c05" ; "Padded")]
+    fn test_parse_success(input: &str, expected: &str) {
+        let result: Result<MapId, ParseMapIdError> = input.parse();
+
+        let_assert!(Ok(output) = result);
+        check!(output.to_string() == expected);
+    }
+
+    #[test_case("", &ParseMapIdError::Blank ; "Blank")]
+    #[test_case("   ", &ParseMapIdError::Blank ; "Whitespace")]
+    #[test_case("xxx", &ParseMapIdError::Malformed ; "Malformed")]
+    fn test_parse_fail(input: &str, expected: &ParseMapIdError) {
+        let result: Result<MapId, ParseMapIdError> = input.parse();
+
+        let_assert!(Err(e) = result);
+        check!(&e == expected);
+    }
+}
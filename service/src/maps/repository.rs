@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod memory;
+mod parse;
+mod postgres;
+mod save_map;
+
+#[cfg(test)]
+pub use memory::MemoryMapRepository;
+pub use postgres::PostgresMapRepository;
+pub use save_map::SaveMapError;
+
+use super::{LayerData, LayerId, LayerResource, MapData, MapId, MapResource, MarkerData, MarkerFilter, MarkerId, MarkerResource};
+use crate::worlds::WorldId;
+
+/// Repository of maps, along with their layers and markers.
+#[async_trait::async_trait]
+pub trait MapRepository: Send + Sync {
+    /// Create a new map.
+    ///
+    /// # Parameters
+    /// - `map` - The details of the map to create.
+    ///
+    /// # Returns
+    /// The created map.
+    async fn create_map(&self, map: &MapData) -> Result<MapResource, SaveMapError>;
+
+    /// Get a single map.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the map belongs to.
+    /// - `map_id` - The ID of the map.
+    ///
+    /// # Returns
+    /// The map, or `None` if it couldn't be found.
+    async fn get_map(&self, world_id: &WorldId, map_id: &MapId) -> Option<MapResource>;
+
+    /// Get all of the maps in a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world.
+    ///
+    /// # Returns
+    /// The maps, ordered by name.
+    async fn get_maps(&self, world_id: &WorldId) -> Vec<MapResource>;
+
+    /// Update an existing map.
+    ///
+    /// # Parameters
+    /// - `map_id` - The ID of the map.
+    /// - `map` - The new details of the map.
+    ///
+    /// # Returns
+    /// The updated map.
+    async fn update_map(&self, map_id: &MapId, map: &MapData) -> Result<MapResource, SaveMapError>;
+
+    /// Delete a single map, along with all of its layers and markers.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the map belongs to.
+    /// - `map_id` - The ID of the map.
+    ///
+    /// # Returns
+    /// True if the map existed and was deleted. False if not.
+    async fn delete_map(&self, world_id: &WorldId, map_id: &MapId) -> bool;
+
+    /// Create a new layer on a map.
+    ///
+    /// # Parameters
+    /// - `layer` - The details of the layer to create.
+    ///
+    /// # Returns
+    /// The created layer.
+    async fn create_layer(&self, layer: &LayerData) -> Result<LayerResource, SaveMapError>;
+
+    /// Get a single layer.
+    ///
+    /// # Parameters
+    /// - `map_id` - The ID of the map that the layer belongs to.
+    /// - `layer_id` - The ID of the layer.
+    ///
+    /// # Returns
+    /// The layer, or `None` if it couldn't be found.
+    async fn get_layer(&self, map_id: &MapId, layer_id: &LayerId) -> Option<LayerResource>;
+
+    /// Get all of the layers of a map.
+    ///
+    /// # Parameters
+    /// - `map_id` - The ID of the map.
+    ///
+    /// # Returns
+    /// The layers, ordered by position and then by name.
+    async fn get_layers(&self, map_id: &MapId) -> Vec<LayerResource>;
+
+    /// Update an existing layer.
+    ///
+    /// # Parameters
+    /// - `layer_id` - The ID of the layer.
+    /// - `layer` - The new details of the layer.
+    ///
+    /// # Returns
+    /// The updated layer.
+    async fn update_layer(&self, layer_id: &LayerId, layer: &LayerData) -> Result<LayerResource, SaveMapError>;
+
+    /// Delete a single layer, along with all of its markers.
+    ///
+    /// # Parameters
+    /// - `map_id` - The ID of the map that the layer belongs to.
+    /// - `layer_id` - The ID of the layer.
+    ///
+    /// # Returns
+    /// True if the layer existed and was deleted. False if not.
+    async fn delete_layer(&self, map_id: &MapId, layer_id: &LayerId) -> bool;
+
+    /// Create a new marker on a map.
+    ///
+    /// # Parameters
+    /// - `marker` - The details of the marker to create.
+    ///
+    /// # Returns
+    /// The created marker.
+    async fn create_marker(&self, marker: &MarkerData) -> Result<MarkerResource, SaveMapError>;
+
+    /// Get a single marker.
+    ///
+    /// # Parameters
+    /// - `map_id` - The ID of the map that the marker belongs to.
+    /// - `marker_id` - The ID of the marker.
+    ///
+    /// # Returns
+    /// The marker, or `None` if it couldn't be found.
+    async fn get_marker(&self, map_id: &MapId, marker_id: &MarkerId) -> Option<MarkerResource>;
+
+    /// Get the markers on a map that match a filter.
+    ///
+    /// # Parameters
+    /// - `map_id` - The ID of the map.
+    /// - `filter` - The criteria that the markers must match.
+    ///
+    /// # Returns
+    /// The markers, in the order that they were created.
+    async fn get_markers(&self, map_id: &MapId, filter: &MarkerFilter) -> Vec<MarkerResource>;
+
+    /// Update an existing marker.
+    ///
+    /// # Parameters
+    /// - `marker_id` - The ID of the marker.
+    /// - `marker` - The new details of the marker.
+    ///
+    /// # Returns
+    /// The updated marker.
+    async fn update_marker(&self, marker_id: &MarkerId, marker: &MarkerData) -> Result<MarkerResource, SaveMapError>;
+
+    /// Delete a single marker.
+    ///
+    /// # Parameters
+    /// - `map_id` - The ID of the map that the marker belongs to.
+    /// - `marker_id` - The ID of the marker.
+    ///
+    /// # Returns
+    /// True if the marker existed and was deleted. False if not.
+    async fn delete_marker(&self, map_id: &MapId, marker_id: &MarkerId) -> bool;
+}
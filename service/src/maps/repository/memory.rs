@@ -0,0 +1,196 @@
+use std::sync::Mutex;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::{MapRepository, SaveMapError};
+use crate::{
+    maps::{LayerData, LayerId, LayerResource, MapData, MapId, MapResource, MarkerData, MarkerFilter, MarkerId, MarkerResource},
+    model::Identity,
+    worlds::WorldId,
+};
+
+/// Repository of maps that are stored in memory.
+#[derive(Default)]
+pub struct MemoryMapRepository {
+    maps:    Mutex<Vec<MapResource>>,
+    layers:  Mutex<Vec<LayerResource>>,
+    markers: Mutex<Vec<MarkerResource>>,
+}
+
+impl MemoryMapRepository {
+    /// Create a new, empty map repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl MapRepository for MemoryMapRepository {
+    async fn create_map(&self, map: &MapData) -> Result<MapResource, SaveMapError> {
+        let created = MapResource {
+            identity: Identity::default(),
+            data:     map.clone(),
+        };
+        self.maps.lock().unwrap().push(created.clone());
+
+        Ok(created)
+    }
+
+    async fn get_map(&self, world_id: &WorldId, map_id: &MapId) -> Option<MapResource> {
+        let maps = self.maps.lock().unwrap();
+
+        maps.iter()
+            .find(|m| &m.data.world_id == world_id && &m.identity.id == map_id)
+            .cloned()
+    }
+
+    async fn get_maps(&self, world_id: &WorldId) -> Vec<MapResource> {
+        let maps = self.maps.lock().unwrap();
+
+        let mut result: Vec<MapResource> = maps.iter().filter(|m| &m.data.world_id == world_id).cloned().collect();
+        result.sort_by_key(|m| m.data.name.to_lowercase());
+
+        result
+    }
+
+    async fn update_map(&self, map_id: &MapId, map: &MapData) -> Result<MapResource, SaveMapError> {
+        let mut maps = self.maps.lock().unwrap();
+
+        let existing = maps
+            .iter_mut()
+            .find(|m| &m.identity.id == map_id && m.data.world_id == map.world_id)
+            .ok_or(SaveMapError::UnknownMap)?;
+
+        existing.identity.version = Uuid::new_v4();
+        existing.identity.updated = Utc::now();
+        existing.data.name = map.name.clone();
+
+        Ok(existing.clone())
+    }
+
+    async fn delete_map(&self, world_id: &WorldId, map_id: &MapId) -> bool {
+        let mut maps = self.maps.lock().unwrap();
+
+        let before = maps.len();
+        maps.retain(|m| !(&m.data.world_id == world_id && &m.identity.id == map_id));
+
+        let deleted = maps.len() != before;
+        if deleted {
+            self.layers.lock().unwrap().retain(|l| &l.data.map_id != map_id);
+            self.markers.lock().unwrap().retain(|m| &m.data.map_id != map_id);
+        }
+
+        deleted
+    }
+
+    async fn create_layer(&self, layer: &LayerData) -> Result<LayerResource, SaveMapError> {
+        let created = LayerResource {
+            identity: Identity::default(),
+            data:     layer.clone(),
+        };
+        self.layers.lock().unwrap().push(created.clone());
+
+        Ok(created)
+    }
+
+    async fn get_layer(&self, map_id: &MapId, layer_id: &LayerId) -> Option<LayerResource> {
+        let layers = self.layers.lock().unwrap();
+
+        layers
+            .iter()
+            .find(|l| &l.data.map_id == map_id && &l.identity.id == layer_id)
+            .cloned()
+    }
+
+    async fn get_layers(&self, map_id: &MapId) -> Vec<LayerResource> {
+        let layers = self.layers.lock().unwrap();
+
+        let mut result: Vec<LayerResource> = layers.iter().filter(|l| &l.data.map_id == map_id).cloned().collect();
+        result.sort_by_key(|l| (l.data.position, l.data.name.to_lowercase()));
+
+        result
+    }
+
+    async fn update_layer(&self, layer_id: &LayerId, layer: &LayerData) -> Result<LayerResource, SaveMapError> {
+        let mut layers = self.layers.lock().unwrap();
+
+        let existing = layers
+            .iter_mut()
+            .find(|l| &l.identity.id == layer_id && l.data.map_id == layer.map_id)
+            .ok_or(SaveMapError::UnknownLayer)?;
+
+        existing.identity.version = Uuid::new_v4();
+        existing.identity.updated = Utc::now();
+        existing.data = layer.clone();
+
+        Ok(existing.clone())
+    }
+
+    async fn delete_layer(&self, map_id: &MapId, layer_id: &LayerId) -> bool {
+        let mut layers = self.layers.lock().unwrap();
+
+        let before = layers.len();
+        layers.retain(|l| !(&l.data.map_id == map_id && &l.identity.id == layer_id));
+
+        let deleted = layers.len() != before;
+        if deleted {
+            self.markers.lock().unwrap().retain(|m| &m.data.layer_id != layer_id);
+        }
+
+        deleted
+    }
+
+    async fn create_marker(&self, marker: &MarkerData) -> Result<MarkerResource, SaveMapError> {
+        let created = MarkerResource {
+            identity: Identity::default(),
+            data:     marker.clone(),
+        };
+        self.markers.lock().unwrap().push(created.clone());
+
+        Ok(created)
+    }
+
+    async fn get_marker(&self, map_id: &MapId, marker_id: &MarkerId) -> Option<MarkerResource> {
+        let markers = self.markers.lock().unwrap();
+
+        markers
+            .iter()
+            .find(|m| &m.data.map_id == map_id && &m.identity.id == marker_id)
+            .cloned()
+    }
+
+    async fn get_markers(&self, map_id: &MapId, filter: &MarkerFilter) -> Vec<MarkerResource> {
+        let markers = self.markers.lock().unwrap();
+
+        markers
+            .iter()
+            .filter(|m| &m.data.map_id == map_id && filter.matches(&m.data))
+            .cloned()
+            .collect()
+    }
+
+    async fn update_marker(&self, marker_id: &MarkerId, marker: &MarkerData) -> Result<MarkerResource, SaveMapError> {
+        let mut markers = self.markers.lock().unwrap();
+
+        let existing = markers
+            .iter_mut()
+            .find(|m| &m.identity.id == marker_id && m.data.map_id == marker.map_id)
+            .ok_or(SaveMapError::UnknownMarker)?;
+
+        existing.identity.version = Uuid::new_v4();
+        existing.identity.updated = Utc::now();
+        existing.data = marker.clone();
+
+        Ok(existing.clone())
+    }
+
+    async fn delete_marker(&self, map_id: &MapId, marker_id: &MarkerId) -> bool {
+        let mut markers = self.markers.lock().unwrap();
+
+        let before = markers.len();
+        markers.retain(|m| !(&m.data.map_id == map_id && &m.identity.id == marker_id));
+
+        markers.len() != before
+    }
+}
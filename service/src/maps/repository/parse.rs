@@ -0,0 +1,77 @@
+use postgres_types::Json;
+use tokio_postgres::Row;
+
+use crate::{
+    articles::ArticleId,
+    entities::EntityId,
+    maps::{LayerData, LayerResource, MapData, MapResource, MarkerData, MarkerLink, MarkerResource, Shape},
+    model::Identity,
+};
+
+impl From<Row> for MapResource {
+    fn from(row: Row) -> Self {
+        MapResource {
+            identity: Identity {
+                id:      row.get("map_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data:     MapData {
+                world_id:    row.get("world_id"),
+                name:        row.get("name"),
+                upload_id:   row.get("upload_id"),
+                width:       row.get("width"),
+                height:      row.get("height"),
+                max_zoom:    row.get("max_zoom"),
+                tile_prefix: row.get("tile_prefix"),
+            },
+        }
+    }
+}
+
+impl From<Row> for LayerResource {
+    fn from(row: Row) -> Self {
+        LayerResource {
+            identity: Identity {
+                id:      row.get("layer_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data:     LayerData {
+                map_id:   row.get("map_id"),
+                name:     row.get("name"),
+                position: row.get("position"),
+                visible:  row.get("visible"),
+            },
+        }
+    }
+}
+
+impl From<Row> for MarkerResource {
+    fn from(row: Row) -> Self {
+        let shape: Json<Shape> = row.get("shape");
+        let location_id: Option<EntityId> = row.get("location_id");
+        let article_id: Option<ArticleId> = row.get("article_id");
+
+        MarkerResource {
+            identity: Identity {
+                id:      row.get("marker_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data:     MarkerData {
+                map_id:      row.get("map_id"),
+                layer_id:    row.get("layer_id"),
+                label:       row.get("label"),
+                description: row.get("description"),
+                shape:       shape.0,
+                link:        location_id
+                    .map(MarkerLink::Location)
+                    .or_else(|| article_id.map(MarkerLink::Article)),
+            },
+        }
+    }
+}
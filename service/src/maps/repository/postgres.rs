@@ -0,0 +1,342 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use postgres_types::Json;
+use uuid::Uuid;
+
+use super::{MapRepository, SaveMapError};
+use crate::{
+    articles::ArticleId,
+    database::Database,
+    entities::EntityId,
+    maps::{
+        LayerData, LayerId, LayerResource, MapData, MapId, MapResource, MarkerData, MarkerFilter, MarkerId, MarkerLink, MarkerResource,
+    },
+    model::Identity,
+    worlds::WorldId,
+};
+
+/// Repository of maps that are stored in Postgres.
+pub struct PostgresMapRepository {
+    database: Arc<Database>,
+}
+
+impl PostgresMapRepository {
+    /// Create a new map repository.
+    pub fn new(database: Arc<Database>) -> Self {
+        Self { database }
+    }
+}
+
+/// Split the link of a marker into the columns that it is stored in.
+fn link_columns(link: Option<&MarkerLink>) -> (Option<&EntityId>, Option<&ArticleId>) {
+    match link {
+        Some(MarkerLink::Location(entity_id)) => (Some(entity_id), None),
+        Some(MarkerLink::Article(article_id)) => (None, Some(article_id)),
+        None => (None, None),
+    }
+}
+
+#[async_trait::async_trait]
+impl MapRepository for PostgresMapRepository {
+    #[tracing::instrument(skip(self))]
+    async fn create_map(&self, map: &MapData) -> Result<MapResource, SaveMapError> {
+        let conn = self.database.connect().await;
+
+        let identity = Identity::<MapId>::default();
+
+        let created: MapResource = conn.query_one("INSERT INTO maps(map_id, version, created, updated, world_id, name, upload_id, width, height, max_zoom, tile_prefix) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11) RETURNING *",
+        &[
+          &identity.id,
+          &identity.version,
+          &identity.created,
+          &identity.updated,
+          &map.world_id,
+          &map.name,
+          &map.upload_id,
+          &map.width,
+          &map.height,
+          &map.max_zoom,
+          &map.tile_prefix,
+          ])
+            .await
+            .map(MapResource::from)?;
+
+        Ok(created)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_map(&self, world_id: &WorldId, map_id: &MapId) -> Option<MapResource> {
+        let conn = self.database.connect().await;
+        conn.query_opt("SELECT * FROM maps WHERE world_id = $1 AND map_id = $2", &[&world_id, &map_id])
+            .await
+            .map_err(|e| {
+                tracing::warn!(e = ?e, "Failed to load map");
+            })
+            .ok()
+            .flatten()
+            .map(MapResource::from)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_maps(&self, world_id: &WorldId) -> Vec<MapResource> {
+        let conn = self.database.connect().await;
+        conn.query("SELECT * FROM maps WHERE world_id = $1 ORDER BY LOWER(name)", &[&world_id])
+            .await
+            .map_or_else(
+                |e| {
+                    tracing::warn!(e = ?e, "Failed to load maps");
+                    vec![]
+                },
+                |rows| rows.into_iter().map(MapResource::from).collect(),
+            )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn update_map(&self, map_id: &MapId, map: &MapData) -> Result<MapResource, SaveMapError> {
+        let conn = self.database.connect().await;
+
+        let version = Uuid::new_v4();
+        let updated = Utc::now();
+
+        conn.query_opt(
+            "UPDATE maps SET version = $3, updated = $4, name = $5 WHERE map_id = $1 AND world_id = $2 RETURNING *",
+            &[&map_id, &map.world_id, &version, &updated, &map.name],
+        )
+        .await?
+        .map(MapResource::from)
+        .ok_or(SaveMapError::UnknownMap)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_map(&self, world_id: &WorldId, map_id: &MapId) -> bool {
+        let conn = self.database.connect().await;
+        conn.execute("DELETE FROM maps WHERE world_id = $1 AND map_id = $2", &[&world_id, &map_id])
+            .await
+            .map_or_else(
+                |e| {
+                    tracing::warn!(e = ?e, "Failed to delete map");
+                    false
+                },
+                |count| count == 1,
+            )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn create_layer(&self, layer: &LayerData) -> Result<LayerResource, SaveMapError> {
+        let conn = self.database.connect().await;
+
+        let identity = Identity::<LayerId>::default();
+
+        let created: LayerResource = conn.query_one("INSERT INTO map_layers(layer_id, version, created, updated, map_id, name, position, visible) VALUES ($1, $2, $3, $4, $5, $6, $7, $8) RETURNING *",
+        &[
+          &identity.id,
+          &identity.version,
+          &identity.created,
+          &identity.updated,
+          &layer.map_id,
+          &layer.name,
+          &layer.position,
+          &layer.visible,
+          ])
+            .await
+            .map(LayerResource::from)?;
+
+        Ok(created)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_layer(&self, map_id: &MapId, layer_id: &LayerId) -> Option<LayerResource> {
+        let conn = self.database.connect().await;
+        conn.query_opt(
+            "SELECT * FROM map_layers WHERE map_id = $1 AND layer_id = $2",
+            &[&map_id, &layer_id],
+        )
+        .await
+        .map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to load layer");
+        })
+        .ok()
+        .flatten()
+        .map(LayerResource::from)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_layers(&self, map_id: &MapId) -> Vec<LayerResource> {
+        let conn = self.database.connect().await;
+        conn.query(
+            "SELECT * FROM map_layers WHERE map_id = $1 ORDER BY position, LOWER(name)",
+            &[&map_id],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to load layers");
+                vec![]
+            },
+            |rows| rows.into_iter().map(LayerResource::from).collect(),
+        )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn update_layer(&self, layer_id: &LayerId, layer: &LayerData) -> Result<LayerResource, SaveMapError> {
+        let conn = self.database.connect().await;
+
+        let version = Uuid::new_v4();
+        let updated = Utc::now();
+
+        conn.query_opt("UPDATE map_layers SET version = $3, updated = $4, name = $5, position = $6, visible = $7 WHERE layer_id = $1 AND map_id = $2 RETURNING *",
+        &[
+          &layer_id,
+          &layer.map_id,
+          &version,
+          &updated,
+          &layer.name,
+          &layer.position,
+          &layer.visible,
+          ])
+            .await?
+            .map(LayerResource::from)
+            .ok_or(SaveMapError::UnknownLayer)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_layer(&self, map_id: &MapId, layer_id: &LayerId) -> bool {
+        let conn = self.database.connect().await;
+        conn.execute("DELETE FROM map_layers WHERE map_id = $1 AND layer_id = $2", &[&map_id, &layer_id])
+            .await
+            .map_or_else(
+                |e| {
+                    tracing::warn!(e = ?e, "Failed to delete layer");
+                    false
+                },
+                |count| count == 1,
+            )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn create_marker(&self, marker: &MarkerData) -> Result<MarkerResource, SaveMapError> {
+        let conn = self.database.connect().await;
+
+        let identity = Identity::<MarkerId>::default();
+        let (location_id, article_id) = link_columns(marker.link.as_ref());
+        let bounds = marker.shape.bounds();
+
+        let created: MarkerResource = conn.query_one("INSERT INTO map_markers(marker_id, version, created, updated, map_id, layer_id, label, description, shape, location_id, article_id, min_x, min_y, max_x, max_y) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15) RETURNING *",
+        &[
+          &identity.id,
+          &identity.version,
+          &identity.created,
+          &identity.updated,
+          &marker.map_id,
+          &marker.layer_id,
+          &marker.label,
+          &marker.description,
+          &Json(&marker.shape),
+          &location_id,
+          &article_id,
+          &bounds.min_x,
+          &bounds.min_y,
+          &bounds.max_x,
+          &bounds.max_y,
+          ])
+            .await
+            .map(MarkerResource::from)?;
+
+        Ok(created)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_marker(&self, map_id: &MapId, marker_id: &MarkerId) -> Option<MarkerResource> {
+        let conn = self.database.connect().await;
+        conn.query_opt(
+            "SELECT * FROM map_markers WHERE map_id = $1 AND marker_id = $2",
+            &[&map_id, &marker_id],
+        )
+        .await
+        .map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to load marker");
+        })
+        .ok()
+        .flatten()
+        .map(MarkerResource::from)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_markers(&self, map_id: &MapId, filter: &MarkerFilter) -> Vec<MarkerResource> {
+        let conn = self.database.connect().await;
+
+        let bounds = filter.bounds.as_ref();
+        conn.query(
+            "SELECT * FROM map_markers
+            WHERE map_id = $1
+            AND ($2::UUID IS NULL OR layer_id = $2)
+            AND ($3::FLOAT8 IS NULL OR box(point(min_x, min_y), point(max_x, max_y)) && box(point($3, $4), point($5, $6)))
+            ORDER BY created, marker_id",
+            &[
+                &map_id,
+                &filter.layer_id,
+                &bounds.map(|b| b.min_x),
+                &bounds.map(|b| b.min_y),
+                &bounds.map(|b| b.max_x),
+                &bounds.map(|b| b.max_y),
+            ],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to load markers");
+                vec![]
+            },
+            |rows| rows.into_iter().map(MarkerResource::from).collect(),
+        )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn update_marker(&self, marker_id: &MarkerId, marker: &MarkerData) -> Result<MarkerResource, SaveMapError> {
+        let conn = self.database.connect().await;
+
+        let version = Uuid::new_v4();
+        let updated = Utc::now();
+        let (location_id, article_id) = link_columns(marker.link.as_ref());
+        let bounds = marker.shape.bounds();
+
+        conn.query_opt("UPDATE map_markers SET version = $3, updated = $4, layer_id = $5, label = $6, description = $7, shape = $8, location_id = $9, article_id = $10, min_x = $11, min_y = $12, max_x = $13, max_y = $14 WHERE marker_id = $1 AND map_id = $2 RETURNING *",
+        &[
+          &marker_id,
+          &marker.map_id,
+          &version,
+          &updated,
+          &marker.layer_id,
+          &marker.label,
+          &marker.description,
+          &Json(&marker.shape),
+          &location_id,
+          &article_id,
+          &bounds.min_x,
+          &bounds.min_y,
+          &bounds.max_x,
+          &bounds.max_y,
+          ])
+            .await?
+            .map(MarkerResource::from)
+            .ok_or(SaveMapError::UnknownMarker)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_marker(&self, map_id: &MapId, marker_id: &MarkerId) -> bool {
+        let conn = self.database.connect().await;
+        conn.execute(
+            "DELETE FROM map_markers WHERE map_id = $1 AND marker_id = $2",
+            &[&map_id, &marker_id],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to delete marker");
+                false
+            },
+            |count| count == 1,
+        )
+    }
+}
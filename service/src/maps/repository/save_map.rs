@@ -0,0 +1,24 @@
+/// Errors that can occur when saving a map, layer or marker.
+#[derive(Debug, PartialEq, thiserror::Error)]
+#[allow(clippy::enum_variant_names)] // Matches the naming of the errors from the other repositories.
+pub enum SaveMapError {
+    #[error("The map was not found")]
+    UnknownMap,
+
+    #[error("The layer was not found")]
+    UnknownLayer,
+
+    #[error("The marker was not found")]
+    UnknownMarker,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl From<tokio_postgres::Error> for SaveMapError {
+    fn from(e: tokio_postgres::Error) -> Self {
+        tracing::warn!("Unexpected database error: {:?}", e);
+
+        SaveMapError::UnknownError
+    }
+}
@@ -0,0 +1,155 @@
+mod create_map;
+mod delete_map;
+mod get_map;
+mod layers;
+mod markers;
+mod tiles;
+mod update_map;
+
+use std::sync::Arc;
+
+pub use create_map::{CreateMapError, NewMap};
+pub use layers::{CreateLayerError, NewLayer, UpdateLayerError};
+pub use markers::{CreateMarkerError, NewMarker, UpdateMarkerError};
+pub use update_map::UpdateMapError;
+
+use super::repository::MapRepository;
+use crate::{articles::ArticleService, blobs::BlobStore, entities::EntityService, uploads::UploadService};
+
+/// Service layer for working with maps.
+pub struct MapService {
+    repository: Arc<dyn MapRepository>,
+    uploads:    Arc<UploadService>,
+    store:      Arc<dyn BlobStore>,
+    entities:   Arc<EntityService>,
+    articles:   Arc<ArticleService>,
+}
+
+impl MapService {
+    /// Create a new map service.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store maps in
+    /// - `uploads` - The service to load the base images of maps from
+    /// - `store` - The blob store to store the tiles of maps in
+    /// - `entities` - The service to check the locations that markers link to against
+    /// - `articles` - The service to check the articles that markers link to against
+    pub fn new(
+        repository: Arc<dyn MapRepository>,
+        uploads: Arc<UploadService>,
+        store: Arc<dyn BlobStore>,
+        entities: Arc<EntityService>,
+        articles: Arc<ArticleService>,
+    ) -> Self {
+        Self {
+            repository,
+            uploads,
+            store,
+            entities,
+            articles,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use bytes::Bytes;
+
+    use super::*;
+    use crate::{
+        articles::MemoryArticleRepository,
+        blobs::MemoryBlobStore,
+        entities::MemoryEntityRepository,
+        maps::{MapResource, MemoryMapRepository},
+        revisions::{MemoryRevisionRepository, RevisionService},
+        search::{MemorySearchRepository, SearchService},
+        uploads::{MemoryUploadRepository, NewUpload, UploadPurpose, UploadResource},
+        users::UserId,
+        worlds::WorldId,
+    };
+
+    /// Fixture for testing the map service.
+    pub struct Fixture {
+        pub sut:      MapService,
+        pub store:    Arc<MemoryBlobStore>,
+        pub uploads:  Arc<UploadService>,
+        pub entities: Arc<EntityService>,
+        pub articles: Arc<ArticleService>,
+        pub user_id:  UserId,
+        pub world_id: WorldId,
+    }
+
+    pub fn build_fixture() -> Fixture {
+        let revisions = Arc::new(RevisionService::new(Arc::new(MemoryRevisionRepository::new())));
+        let search = Arc::new(SearchService::new(Arc::new(MemorySearchRepository::new())));
+        let entities = Arc::new(EntityService::new(
+            Arc::new(MemoryEntityRepository::new()),
+            revisions.clone(),
+            search.clone(),
+        ));
+        let articles = Arc::new(ArticleService::new(Arc::new(MemoryArticleRepository::new()), revisions, search));
+        let store = Arc::new(MemoryBlobStore::new());
+        let uploads = Arc::new(UploadService::new(
+            Arc::new(MemoryUploadRepository::new()),
+            store.clone(),
+            entities.clone(),
+            10 * 1024 * 1024,
+        ));
+
+        Fixture {
+            sut: MapService::new(
+                Arc::new(MemoryMapRepository::new()),
+                uploads.clone(),
+                store.clone(),
+                entities.clone(),
+                articles.clone(),
+            ),
+            store,
+            uploads,
+            entities,
+            articles,
+            user_id: UserId::default(),
+            world_id: WorldId::default(),
+        }
+    }
+
+    impl Fixture {
+        /// Upload a blank image to use as the base of a map.
+        pub async fn upload_image(&self, width: u32, height: u32) -> UploadResource {
+            let mut data = vec![];
+            image::DynamicImage::ImageRgb8(image::RgbImage::new(width, height))
+                .write_to(&mut data, image::ImageOutputFormat::Png)
+                .unwrap();
+
+            self.uploads
+                .create_upload(NewUpload {
+                    owner_id:  self.user_id.clone(),
+                    world_id:  Some(self.world_id.clone()),
+                    entity_id: None,
+                    purpose:   UploadPurpose::Map,
+                    filename:  None,
+                    data:      Bytes::from(data),
+                })
+                .await
+                .unwrap()
+        }
+
+        /// Create a map with a blank base image.
+        pub async fn create_map(&self, width: u32, height: u32) -> MapResource {
+            let upload = self.upload_image(width, height).await;
+
+            self.sut
+                .create_map(
+                    &self.world_id,
+                    NewMap {
+                        name:      "Middle Earth".to_owned(),
+                        upload_id: upload.identity.id,
+                    },
+                )
+                .await
+                .unwrap()
+        }
+    }
+}
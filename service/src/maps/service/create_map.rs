@@ -0,0 +1,188 @@
+use std::convert::TryFrom;
+
+use uuid::Uuid;
+
+use super::MapService;
+use crate::{
+    maps::{tiles::max_zoom, MapData, MapResource},
+    uploads::{UploadId, UploadPurpose, UploadVariant},
+    worlds::WorldId,
+};
+
+/// The details needed to create a new map.
+#[derive(Debug)]
+pub struct NewMap {
+    pub name:      String,
+    /// The uploaded image to use as the base of the map.
+    pub upload_id: UploadId,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum CreateMapError {
+    #[error("Unknown upload: {0}")]
+    UnknownUpload(UploadId),
+
+    #[error("The upload is not an image that was uploaded for a map")]
+    NotAMap,
+
+    #[error("The image could not be cut into tiles")]
+    InvalidImage,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl MapService {
+    /// Create a new map from an uploaded image, generating the tiles of the map from it.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world to create the map in
+    /// - `map` - The details of the map
+    ///
+    /// # Returns
+    /// The newly created map.
+    pub async fn create_map(&self, world_id: &WorldId, map: NewMap) -> Result<MapResource, CreateMapError> {
+        let upload = self
+            .uploads
+            .get_upload(world_id, &map.upload_id)
+            .await
+            .ok_or_else(|| CreateMapError::UnknownUpload(map.upload_id.clone()))?;
+
+        let (UploadPurpose::Map, Some(width), Some(height)) = (upload.data.purpose, upload.data.width, upload.data.height) else {
+            return Err(CreateMapError::NotAMap);
+        };
+        let max_zoom = max_zoom(
+            u32::try_from(width).map_err(|_| CreateMapError::InvalidImage)?,
+            u32::try_from(height).map_err(|_| CreateMapError::InvalidImage)?,
+        );
+
+        let image = self
+            .uploads
+            .read_upload(&upload, UploadVariant::Original, None)
+            .await
+            .map_err(|e| {
+                tracing::warn!(e = ?e, "Failed to read base image of map");
+
+                CreateMapError::UnknownError
+            })?
+            .ok_or(CreateMapError::UnknownError)?;
+
+        let data = MapData {
+            world_id: world_id.clone(),
+            name: map.name,
+            upload_id: Some(map.upload_id),
+            width,
+            height,
+            max_zoom: i32::try_from(max_zoom).map_err(|_| CreateMapError::InvalidImage)?,
+            tile_prefix: format!("maps/{}", Uuid::new_v4()),
+        };
+
+        if let Err(e) = self.generate_tiles(&data, image).await {
+            self.remove_tiles(&data).await;
+
+            return Err(e);
+        }
+
+        match self.repository.create_map(&data).await {
+            Ok(created) => Ok(created),
+            Err(e) => {
+                tracing::warn!(e = ?e, "Failed to record map");
+                self.remove_tiles(&data).await;
+
+                Err(CreateMapError::UnknownError)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use bytes::Bytes;
+
+    use super::*;
+    use crate::{
+        maps::{service::tests::build_fixture, tiles::tile_key},
+        uploads::NewUpload,
+    };
+
+    #[actix_rt::test]
+    async fn create_map() {
+        let f = build_fixture();
+        let upload = f.upload_image(600, 300).await;
+
+        let result = f
+            .sut
+            .create_map(
+                &f.world_id,
+                NewMap {
+                    name:      "Middle Earth".to_owned(),
+                    upload_id: upload.identity.id.clone(),
+                },
+            )
+            .await;
+
+        let_assert!(Ok(map) = result);
+        check!(map.data.name == "Middle Earth");
+        check!(map.data.upload_id == Some(upload.identity.id));
+        check!(map.data.width == 600);
+        check!(map.data.height == 300);
+        check!(map.data.max_zoom == 2);
+
+        check!(f.store.contains(&tile_key(&map.data.tile_prefix, 0, 0, 0)));
+        check!(f.store.contains(&tile_key(&map.data.tile_prefix, 1, 1, 0)));
+        check!(f.store.contains(&tile_key(&map.data.tile_prefix, 2, 2, 1)));
+        check!(!f.store.contains(&tile_key(&map.data.tile_prefix, 2, 3, 0)));
+    }
+
+    #[actix_rt::test]
+    async fn create_map_from_unknown_upload() {
+        let f = build_fixture();
+        let upload_id = UploadId::default();
+
+        let result = f
+            .sut
+            .create_map(
+                &f.world_id,
+                NewMap {
+                    name:      "Middle Earth".to_owned(),
+                    upload_id: upload_id.clone(),
+                },
+            )
+            .await;
+
+        let_assert!(Err(e) = result);
+        check!(e == CreateMapError::UnknownUpload(upload_id));
+    }
+
+    #[actix_rt::test]
+    async fn create_map_from_handout() {
+        let f = build_fixture();
+        let upload = f
+            .uploads
+            .create_upload(NewUpload {
+                owner_id:  f.user_id.clone(),
+                world_id:  Some(f.world_id.clone()),
+                entity_id: None,
+                purpose:   UploadPurpose::Handout,
+                filename:  None,
+                data:      Bytes::from_static(b"There and back again"),
+            })
+            .await
+            .unwrap();
+
+        let result = f
+            .sut
+            .create_map(
+                &f.world_id,
+                NewMap {
+                    name:      "Middle Earth".to_owned(),
+                    upload_id: upload.identity.id,
+                },
+            )
+            .await;
+
+        let_assert!(Err(e) = result);
+        check!(e == CreateMapError::NotAMap);
+    }
+}
@@ -0,0 +1,52 @@
+use super::MapService;
+use crate::{maps::MapId, worlds::WorldId};
+
+impl MapService {
+    /// Delete a map, along with its layers, markers and tiles. The upload that the map was created
+    /// from is left alone.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the map belongs to
+    /// - `map_id` - The ID of the map
+    ///
+    /// # Returns
+    /// True if the map existed and was deleted. False if not.
+    pub async fn delete_map(&self, world_id: &WorldId, map_id: &MapId) -> bool {
+        let Some(map) = self.repository.get_map(world_id, map_id).await else {
+            return false;
+        };
+
+        let deleted = self.repository.delete_map(world_id, map_id).await;
+        if deleted {
+            self.remove_tiles(&map.data).await;
+        }
+
+        deleted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+
+    use crate::{
+        maps::{service::tests::build_fixture, tiles::tile_key},
+        worlds::WorldId,
+    };
+
+    #[actix_rt::test]
+    async fn delete_map() {
+        let f = build_fixture();
+        let map = f.create_map(300, 300).await;
+        let tile = tile_key(&map.data.tile_prefix, 1, 1, 1);
+        check!(f.store.contains(&tile));
+
+        check!(!f.sut.delete_map(&WorldId::default(), &map.identity.id).await);
+        check!(f.store.contains(&tile));
+
+        check!(f.sut.delete_map(&f.world_id, &map.identity.id).await);
+        check!(!f.store.contains(&tile));
+        check!(!f.store.contains(&tile_key(&map.data.tile_prefix, 0, 0, 0)));
+        check!(f.sut.get_map(&f.world_id, &map.identity.id).await.is_none());
+    }
+}
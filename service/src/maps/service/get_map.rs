@@ -0,0 +1,30 @@
+use super::MapService;
+use crate::{
+    maps::{MapId, MapResource},
+    worlds::WorldId,
+};
+
+impl MapService {
+    /// Get a single map.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world the map belongs to
+    /// - `map_id` - The ID of the map
+    ///
+    /// # Returns
+    /// The map, or `None` if it doesn't exist in this world.
+    pub async fn get_map(&self, world_id: &WorldId, map_id: &MapId) -> Option<MapResource> {
+        self.repository.get_map(world_id, map_id).await
+    }
+
+    /// Get all of the maps in a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    ///
+    /// # Returns
+    /// The maps, ordered by name.
+    pub async fn get_maps(&self, world_id: &WorldId) -> Vec<MapResource> {
+        self.repository.get_maps(world_id).await
+    }
+}
@@ -0,0 +1,212 @@
+use super::MapService;
+use crate::maps::{LayerData, LayerId, LayerResource, MapResource, SaveMapError};
+
+/// The details needed to create a new layer.
+#[derive(Debug)]
+pub struct NewLayer {
+    pub name:    String,
+    pub visible: bool,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum CreateLayerError {
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum UpdateLayerError<E>
+where
+    E: std::fmt::Debug,
+{
+    #[error("Unknown layer")]
+    UnknownLayer,
+
+    #[error("An error occurred updating the layer data")]
+    UpdateError(E),
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl MapService {
+    /// Create a new layer on a map, above all of the existing layers.
+    ///
+    /// # Parameters
+    /// - `map` - The map to create the layer on
+    /// - `layer` - The details of the layer
+    ///
+    /// # Returns
+    /// The newly created layer.
+    pub async fn create_layer(&self, map: &MapResource, layer: NewLayer) -> Result<LayerResource, CreateLayerError> {
+        let position = self
+            .repository
+            .get_layers(&map.identity.id)
+            .await
+            .iter()
+            .map(|l| l.data.position + 1)
+            .max()
+            .unwrap_or_default();
+
+        let data = LayerData {
+            map_id: map.identity.id.clone(),
+            name: layer.name,
+            position,
+            visible: layer.visible,
+        };
+
+        let result = self.repository.create_layer(&data).await?;
+
+        Ok(result)
+    }
+
+    /// Get a single layer of a map.
+    ///
+    /// # Parameters
+    /// - `map` - The map that the layer belongs to
+    /// - `layer_id` - The ID of the layer
+    ///
+    /// # Returns
+    /// The layer, or `None` if it doesn't exist on this map.
+    pub async fn get_layer(&self, map: &MapResource, layer_id: &LayerId) -> Option<LayerResource> {
+        self.repository.get_layer(&map.identity.id, layer_id).await
+    }
+
+    /// Get all of the layers of a map.
+    ///
+    /// # Parameters
+    /// - `map` - The map
+    ///
+    /// # Returns
+    /// The layers, from the bottom up.
+    pub async fn get_layers(&self, map: &MapResource) -> Vec<LayerResource> {
+        self.repository.get_layers(&map.identity.id).await
+    }
+
+    /// Update the layer that has the provided ID, using the provided lambda to perform the updates.
+    ///
+    /// # Parameters
+    /// - `map` - The map that the layer belongs to
+    /// - `layer_id` - The ID of the layer to update
+    /// - `f` - The function to update the layer details
+    ///
+    /// # Returns
+    /// The newly updated layer.
+    pub async fn update_layer_by_id<F, E>(&self, map: &MapResource, layer_id: &LayerId, f: F) -> Result<LayerResource, UpdateLayerError<E>>
+    where
+        F: FnOnce(LayerData) -> Result<LayerData, E>,
+        E: std::fmt::Debug,
+    {
+        let layer = self
+            .repository
+            .get_layer(&map.identity.id, layer_id)
+            .await
+            .ok_or(UpdateLayerError::UnknownLayer)?;
+
+        let data = f(layer.data).map_err(UpdateLayerError::UpdateError)?;
+        let data = LayerData {
+            map_id: map.identity.id.clone(),
+            ..data
+        };
+
+        let result = self.repository.update_layer(layer_id, &data).await?;
+
+        Ok(result)
+    }
+
+    /// Delete a layer from a map, along with all of its markers.
+    ///
+    /// # Parameters
+    /// - `map` - The map that the layer belongs to
+    /// - `layer_id` - The ID of the layer
+    ///
+    /// # Returns
+    /// True if the layer existed and was deleted. False if not.
+    pub async fn delete_layer(&self, map: &MapResource, layer_id: &LayerId) -> bool {
+        self.repository.delete_layer(&map.identity.id, layer_id).await
+    }
+}
+
+impl From<SaveMapError> for CreateLayerError {
+    fn from(_: SaveMapError) -> Self {
+        Self::UnknownError
+    }
+}
+
+impl<E> From<SaveMapError> for UpdateLayerError<E>
+where
+    E: std::fmt::Debug,
+{
+    fn from(e: SaveMapError) -> Self {
+        match e {
+            SaveMapError::UnknownLayer => Self::UnknownLayer,
+            SaveMapError::UnknownMap | SaveMapError::UnknownMarker | SaveMapError::UnknownError => Self::UnknownError,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::maps::service::tests::build_fixture;
+
+    fn new_layer(name: &str) -> NewLayer {
+        NewLayer {
+            name:    name.to_owned(),
+            visible: true,
+        }
+    }
+
+    #[actix_rt::test]
+    async fn create_layers() {
+        let f = build_fixture();
+        let map = f.create_map(100, 100).await;
+
+        let_assert!(Ok(cities) = f.sut.create_layer(&map, new_layer("Cities")).await);
+        let_assert!(Ok(roads) = f.sut.create_layer(&map, new_layer("Roads")).await);
+        check!(cities.data.position == 0);
+        check!(roads.data.position == 1);
+
+        let layers = f.sut.get_layers(&map).await;
+        check!(layers.iter().map(|l| l.data.name.as_str()).collect::<Vec<_>>() == vec!["Cities", "Roads"]);
+    }
+
+    #[actix_rt::test]
+    async fn reorder_layers() {
+        let f = build_fixture();
+        let map = f.create_map(100, 100).await;
+        let cities = f.sut.create_layer(&map, new_layer("Cities")).await.unwrap();
+        f.sut.create_layer(&map, new_layer("Roads")).await.unwrap();
+
+        let result = f
+            .sut
+            .update_layer_by_id(&map, &cities.identity.id, |layer| -> Result<LayerData, ()> {
+                Ok(LayerData {
+                    position: 5,
+                    visible: false,
+                    ..layer
+                })
+            })
+            .await;
+        let_assert!(Ok(updated) = result);
+        check!(!updated.data.visible);
+
+        let layers = f.sut.get_layers(&map).await;
+        check!(layers.iter().map(|l| l.data.name.as_str()).collect::<Vec<_>>() == vec!["Roads", "Cities"]);
+    }
+
+    #[actix_rt::test]
+    async fn layers_belong_to_one_map() {
+        let f = build_fixture();
+        let map = f.create_map(100, 100).await;
+        let other = f.create_map(100, 100).await;
+        let layer = f.sut.create_layer(&map, new_layer("Cities")).await.unwrap();
+
+        check!(f.sut.get_layer(&other, &layer.identity.id).await.is_none());
+        check!(!f.sut.delete_layer(&other, &layer.identity.id).await);
+        check!(f.sut.delete_layer(&map, &layer.identity.id).await);
+        check!(f.sut.get_layer(&map, &layer.identity.id).await.is_none());
+    }
+}
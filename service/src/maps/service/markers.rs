@@ -0,0 +1,487 @@
+use super::MapService;
+use crate::{
+    articles::ArticleId,
+    entities::{EntityId, LocationAttributes},
+    maps::{LayerId, MapResource, MarkerData, MarkerFilter, MarkerId, MarkerLink, MarkerResource, SaveMapError, Shape},
+};
+
+/// The details needed to create a new marker.
+#[derive(Debug)]
+pub struct NewMarker {
+    pub layer_id:    LayerId,
+    pub label:       String,
+    pub description: Option<String>,
+    pub shape:       Shape,
+    pub link:        Option<MarkerLink>,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum CreateMarkerError {
+    #[error("Unknown layer: {0}")]
+    UnknownLayer(LayerId),
+
+    #[error("The marker lies outside of the map")]
+    OutOfBounds,
+
+    #[error("Unknown location: {0}")]
+    UnknownLocation(EntityId),
+
+    #[error("Unknown article: {0}")]
+    UnknownArticle(ArticleId),
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum UpdateMarkerError<E>
+where
+    E: std::fmt::Debug,
+{
+    #[error("Unknown marker")]
+    UnknownMarker,
+
+    #[error("Unknown layer: {0}")]
+    UnknownLayer(LayerId),
+
+    #[error("The marker lies outside of the map")]
+    OutOfBounds,
+
+    #[error("Unknown location: {0}")]
+    UnknownLocation(EntityId),
+
+    #[error("Unknown article: {0}")]
+    UnknownArticle(ArticleId),
+
+    #[error("An error occurred updating the marker data")]
+    UpdateError(E),
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+/// Reasons that the details of a marker can't be saved.
+#[derive(Debug, PartialEq)]
+enum InvalidMarker {
+    /// The marker is on a layer that isn't part of the map.
+    UnknownLayer(LayerId),
+    /// Some of the marker lies outside of the map.
+    OutOfBounds,
+    /// The marker links to a location that isn't part of the world.
+    UnknownLocation(EntityId),
+    /// The marker links to an article that isn't part of the world.
+    UnknownArticle(ArticleId),
+}
+
+impl MapService {
+    /// Create a new marker on a map.
+    ///
+    /// # Parameters
+    /// - `map` - The map to create the marker on
+    /// - `marker` - The details of the marker
+    ///
+    /// # Returns
+    /// The newly created marker.
+    pub async fn create_marker(&self, map: &MapResource, marker: NewMarker) -> Result<MarkerResource, CreateMarkerError> {
+        let data = MarkerData {
+            map_id:      map.identity.id.clone(),
+            layer_id:    marker.layer_id,
+            label:       marker.label,
+            description: marker.description,
+            shape:       marker.shape,
+            link:        marker.link,
+        };
+
+        self.check_marker(map, &data).await?;
+
+        let result = self.repository.create_marker(&data).await?;
+
+        Ok(result)
+    }
+
+    /// Get a single marker on a map.
+    ///
+    /// # Parameters
+    /// - `map` - The map that the marker is on
+    /// - `marker_id` - The ID of the marker
+    ///
+    /// # Returns
+    /// The marker, or `None` if it doesn't exist on this map.
+    pub async fn get_marker(&self, map: &MapResource, marker_id: &MarkerId) -> Option<MarkerResource> {
+        self.repository.get_marker(&map.identity.id, marker_id).await
+    }
+
+    /// Get the markers on a map that match a filter.
+    ///
+    /// # Parameters
+    /// - `map` - The map
+    /// - `filter` - The criteria that the markers must match
+    ///
+    /// # Returns
+    /// The markers, in the order that they were created.
+    pub async fn get_markers(&self, map: &MapResource, filter: &MarkerFilter) -> Vec<MarkerResource> {
+        self.repository.get_markers(&map.identity.id, filter).await
+    }
+
+    /// Update the marker that has the provided ID, using the provided lambda to perform the
+    /// updates.
+    ///
+    /// # Parameters
+    /// - `map` - The map that the marker is on
+    /// - `marker_id` - The ID of the marker to update
+    /// - `f` - The function to update the marker details
+    ///
+    /// # Returns
+    /// The newly updated marker.
+    pub async fn update_marker_by_id<F, E>(
+        &self,
+        map: &MapResource,
+        marker_id: &MarkerId,
+        f: F,
+    ) -> Result<MarkerResource, UpdateMarkerError<E>>
+    where
+        F: FnOnce(MarkerData) -> Result<MarkerData, E>,
+        E: std::fmt::Debug,
+    {
+        let marker = self
+            .repository
+            .get_marker(&map.identity.id, marker_id)
+            .await
+            .ok_or(UpdateMarkerError::UnknownMarker)?;
+
+        let data = f(marker.data).map_err(UpdateMarkerError::UpdateError)?;
+        let data = MarkerData {
+            map_id: map.identity.id.clone(),
+            ..data
+        };
+        self.check_marker(map, &data).await?;
+
+        let result = self.repository.update_marker(marker_id, &data).await?;
+
+        Ok(result)
+    }
+
+    /// Delete a marker from a map.
+    ///
+    /// # Parameters
+    /// - `map` - The map that the marker is on
+    /// - `marker_id` - The ID of the marker
+    ///
+    /// # Returns
+    /// True if the marker existed and was deleted. False if not.
+    pub async fn delete_marker(&self, map: &MapResource, marker_id: &MarkerId) -> bool {
+        self.repository.delete_marker(&map.identity.id, marker_id).await
+    }
+
+    /// Check that the details of a marker make sense.
+    ///
+    /// # Parameters
+    /// - `map` - The map that the marker is on
+    /// - `marker` - The details of the marker
+    ///
+    /// # Returns
+    /// The reason that the marker is invalid, if it is.
+    async fn check_marker(&self, map: &MapResource, marker: &MarkerData) -> Result<(), InvalidMarker> {
+        if self.repository.get_layer(&map.identity.id, &marker.layer_id).await.is_none() {
+            return Err(InvalidMarker::UnknownLayer(marker.layer_id.clone()));
+        }
+
+        if !marker.shape.points().iter().all(|point| map.data.contains(point)) {
+            return Err(InvalidMarker::OutOfBounds);
+        }
+
+        match &marker.link {
+            Some(MarkerLink::Location(entity_id)) => self
+                .entities
+                .get_entity::<LocationAttributes>(&map.data.world_id, entity_id)
+                .await
+                .map(|_| ())
+                .ok_or_else(|| InvalidMarker::UnknownLocation(entity_id.clone())),
+            Some(MarkerLink::Article(article_id)) => self
+                .articles
+                .get_article_by_id(&map.data.world_id, article_id)
+                .await
+                .map(|_| ())
+                .ok_or_else(|| InvalidMarker::UnknownArticle(article_id.clone())),
+            None => Ok(()),
+        }
+    }
+}
+
+impl From<InvalidMarker> for CreateMarkerError {
+    fn from(e: InvalidMarker) -> Self {
+        match e {
+            InvalidMarker::UnknownLayer(layer_id) => Self::UnknownLayer(layer_id),
+            InvalidMarker::OutOfBounds => Self::OutOfBounds,
+            InvalidMarker::UnknownLocation(entity_id) => Self::UnknownLocation(entity_id),
+            InvalidMarker::UnknownArticle(article_id) => Self::UnknownArticle(article_id),
+        }
+    }
+}
+
+impl From<SaveMapError> for CreateMarkerError {
+    fn from(_: SaveMapError) -> Self {
+        Self::UnknownError
+    }
+}
+
+impl<E> From<InvalidMarker> for UpdateMarkerError<E>
+where
+    E: std::fmt::Debug,
+{
+    fn from(e: InvalidMarker) -> Self {
+        match e {
+            InvalidMarker::UnknownLayer(layer_id) => Self::UnknownLayer(layer_id),
+            InvalidMarker::OutOfBounds => Self::OutOfBounds,
+            InvalidMarker::UnknownLocation(entity_id) => Self::UnknownLocation(entity_id),
+            InvalidMarker::UnknownArticle(article_id) => Self::UnknownArticle(article_id),
+        }
+    }
+}
+
+impl<E> From<SaveMapError> for UpdateMarkerError<E>
+where
+    E: std::fmt::Debug,
+{
+    fn from(e: SaveMapError) -> Self {
+        match e {
+            SaveMapError::UnknownMarker => Self::UnknownMarker,
+            SaveMapError::UnknownMap | SaveMapError::UnknownLayer | SaveMapError::UnknownError => Self::UnknownError,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use test_case::test_case;
+
+    use super::*;
+    use crate::{
+        entities::{CharacterAttributes, CustomFields, NewEntity},
+        maps::{service::tests::build_fixture, BoundingBox, LayerResource, MarkerData, NewLayer, Point},
+    };
+
+    fn pin(x: f64, y: f64) -> Shape {
+        Shape::Pin(Point { x, y })
+    }
+
+    fn new_marker(layer: &LayerResource, label: &str, shape: Shape) -> NewMarker {
+        NewMarker {
+            layer_id: layer.identity.id.clone(),
+            label: label.to_owned(),
+            description: None,
+            shape,
+            link: None,
+        }
+    }
+
+    #[actix_rt::test]
+    async fn markers_in_bounding_box() {
+        let f = build_fixture();
+        let map = f.create_map(1000, 1000).await;
+        let cities = f
+            .sut
+            .create_layer(
+                &map,
+                NewLayer {
+                    name:    "Cities".to_owned(),
+                    visible: true,
+                },
+            )
+            .await
+            .unwrap();
+        let regions = f
+            .sut
+            .create_layer(
+                &map,
+                NewLayer {
+                    name:    "Regions".to_owned(),
+                    visible: true,
+                },
+            )
+            .await
+            .unwrap();
+
+        f.sut
+            .create_marker(&map, new_marker(&cities, "Hobbiton", pin(100.0, 100.0)))
+            .await
+            .unwrap();
+        f.sut
+            .create_marker(&map, new_marker(&cities, "Minas Tirith", pin(800.0, 700.0)))
+            .await
+            .unwrap();
+        f.sut
+            .create_marker(
+                &map,
+                new_marker(
+                    &regions,
+                    "Mordor",
+                    Shape::Polygon {
+                        points: vec![
+                            Point { x: 850.0, y: 500.0 },
+                            Point { x: 1000.0, y: 500.0 },
+                            Point { x: 950.0, y: 800.0 },
+                        ],
+                    },
+                ),
+            )
+            .await
+            .unwrap();
+
+        let labels = |markers: Vec<MarkerResource>| markers.into_iter().map(|m| m.data.label).collect::<Vec<_>>();
+
+        let filter = MarkerFilter {
+            bounds:   Some(BoundingBox {
+                min_x: 700.0,
+                min_y: 600.0,
+                max_x: 900.0,
+                max_y: 900.0,
+            }),
+            layer_id: None,
+        };
+        check!(labels(f.sut.get_markers(&map, &filter).await) == vec!["Minas Tirith", "Mordor"]);
+
+        let filter = MarkerFilter {
+            layer_id: Some(cities.identity.id.clone()),
+            ..filter
+        };
+        check!(labels(f.sut.get_markers(&map, &filter).await) == vec!["Minas Tirith"]);
+
+        check!(labels(f.sut.get_markers(&map, &MarkerFilter::default()).await).len() == 3);
+
+        check!(f.sut.delete_layer(&map, &cities.identity.id).await);
+        check!(labels(f.sut.get_markers(&map, &MarkerFilter::default()).await) == vec!["Mordor"]);
+    }
+
+    #[test_case(pin(-1.0, 10.0) ; "Left of the map")]
+    #[test_case(pin(10.0, 101.0) ; "Below the map")]
+    #[test_case(Shape::Polygon { points: vec![Point { x: 0.0, y: 0.0 }, Point { x: 50.0, y: 0.0 }, Point { x: 50.0, y: 200.0 }] } ; "Polygon partly outside")]
+    #[actix_rt::test]
+    async fn marker_out_of_bounds(shape: Shape) {
+        let f = build_fixture();
+        let map = f.create_map(100, 100).await;
+        let layer = f
+            .sut
+            .create_layer(
+                &map,
+                NewLayer {
+                    name:    "Cities".to_owned(),
+                    visible: true,
+                },
+            )
+            .await
+            .unwrap();
+
+        let_assert!(Err(e) = f.sut.create_marker(&map, new_marker(&layer, "Valinor", shape)).await);
+        check!(e == CreateMarkerError::OutOfBounds);
+    }
+
+    #[actix_rt::test]
+    async fn marker_links() {
+        let f = build_fixture();
+        let map = f.create_map(100, 100).await;
+        let other = f.create_map(100, 100).await;
+        let layer = f
+            .sut
+            .create_layer(
+                &map,
+                NewLayer {
+                    name:    "Cities".to_owned(),
+                    visible: true,
+                },
+            )
+            .await
+            .unwrap();
+        let other_layer = f
+            .sut
+            .create_layer(
+                &other,
+                NewLayer {
+                    name:    "Cities".to_owned(),
+                    visible: true,
+                },
+            )
+            .await
+            .unwrap();
+
+        let character = f
+            .entities
+            .create_entity(
+                &f.world_id,
+                &f.user_id,
+                NewEntity {
+                    name:       "Aragorn".to_owned(),
+                    summary:    None,
+                    attributes: CharacterAttributes::default(),
+                    custom:     CustomFields::new(),
+                },
+            )
+            .await
+            .unwrap();
+
+        let mut marker = new_marker(&layer, "Aragorn", pin(10.0, 10.0));
+        marker.link = Some(MarkerLink::Location(character.identity.id.clone()));
+        let_assert!(Err(e) = f.sut.create_marker(&map, marker).await);
+        check!(e == CreateMarkerError::UnknownLocation(character.identity.id));
+
+        let article_id = ArticleId::default();
+        let mut marker = new_marker(&layer, "Rivendell", pin(10.0, 10.0));
+        marker.link = Some(MarkerLink::Article(article_id.clone()));
+        let_assert!(Err(e) = f.sut.create_marker(&map, marker).await);
+        check!(e == CreateMarkerError::UnknownArticle(article_id));
+
+        let marker = new_marker(&other_layer, "Rivendell", pin(10.0, 10.0));
+        let_assert!(Err(e) = f.sut.create_marker(&map, marker).await);
+        check!(e == CreateMarkerError::UnknownLayer(other_layer.identity.id.clone()));
+    }
+
+    #[actix_rt::test]
+    async fn move_marker() {
+        let f = build_fixture();
+        let map = f.create_map(100, 100).await;
+        let layer = f
+            .sut
+            .create_layer(
+                &map,
+                NewLayer {
+                    name:    "Cities".to_owned(),
+                    visible: true,
+                },
+            )
+            .await
+            .unwrap();
+        let marker = f
+            .sut
+            .create_marker(&map, new_marker(&layer, "Bree", pin(10.0, 10.0)))
+            .await
+            .unwrap();
+
+        let result = f
+            .sut
+            .update_marker_by_id(&map, &marker.identity.id, |marker| -> Result<MarkerData, ()> {
+                Ok(MarkerData {
+                    shape: pin(20.0, 30.0),
+                    ..marker
+                })
+            })
+            .await;
+        let_assert!(Ok(updated) = result);
+        check!(updated.data.shape == pin(20.0, 30.0));
+
+        let result = f
+            .sut
+            .update_marker_by_id(&map, &marker.identity.id, |marker| -> Result<MarkerData, ()> {
+                Ok(MarkerData {
+                    shape: pin(200.0, 30.0),
+                    ..marker
+                })
+            })
+            .await;
+        let_assert!(Err(e) = result);
+        check!(e == UpdateMarkerError::OutOfBounds);
+
+        check!(f.sut.delete_marker(&map, &marker.identity.id).await);
+        check!(f.sut.get_marker(&map, &marker.identity.id).await.is_none());
+    }
+}
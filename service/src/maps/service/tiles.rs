@@ -0,0 +1,144 @@
+use std::{convert::TryFrom, sync::Arc};
+
+use actix_web::web::block;
+use bytes::Bytes;
+
+use super::{CreateMapError, MapService};
+use crate::{
+    blobs::BlobError,
+    maps::{
+        tiles::{decode, grid_size, render_row, scale_down, tile_key, TileError},
+        MapData, MapResource,
+    },
+};
+
+/// The size of the base image of a map, and its most detailed zoom level.
+fn pyramid(map: &MapData) -> (u32, u32, u32) {
+    (
+        u32::try_from(map.width).unwrap_or_default(),
+        u32::try_from(map.height).unwrap_or_default(),
+        u32::try_from(map.max_zoom).unwrap_or_default(),
+    )
+}
+
+impl MapService {
+    /// Get a single tile of a map.
+    ///
+    /// # Parameters
+    /// - `map` - The map
+    /// - `zoom` - The zoom level of the tile
+    /// - `x` - The column of the tile
+    /// - `y` - The row of the tile
+    ///
+    /// # Returns
+    /// The PNG encoded tile, or `None` if the map has no such tile.
+    pub async fn get_tile(&self, map: &MapResource, zoom: u32, x: u32, y: u32) -> Result<Option<Bytes>, BlobError> {
+        let (width, height, max_zoom) = pyramid(&map.data);
+        if zoom > max_zoom {
+            return Ok(None);
+        }
+
+        let (columns, rows) = grid_size(width, height, max_zoom, zoom);
+        if x >= columns || y >= rows {
+            return Ok(None);
+        }
+
+        self.store.get(&tile_key(&map.data.tile_prefix, zoom, x, y), None).await
+    }
+
+    /// Cut the base image of a map into tiles at every zoom level, and store them.
+    ///
+    /// The work is split into a row of tiles at a time, so that large maps never need every tile
+    /// held in memory at once.
+    ///
+    /// # Parameters
+    /// - `map` - The details of the map
+    /// - `image` - The contents of the base image of the map
+    pub(super) async fn generate_tiles(&self, map: &MapData, image: Bytes) -> Result<(), CreateMapError> {
+        let (width, height, max_zoom) = pyramid(map);
+
+        let failed = |e: TileError| match e {
+            TileError::Undecodable => CreateMapError::InvalidImage,
+            TileError::Unencodable => CreateMapError::UnknownError,
+        };
+        let blocked = |e| {
+            tracing::warn!(e = ?e, "Failed to schedule tile generation");
+
+            CreateMapError::UnknownError
+        };
+
+        let mut level = Arc::new(block(move || decode(&image)).await.map_err(blocked)?.map_err(failed)?);
+
+        for zoom in (0..=max_zoom).rev() {
+            if zoom < max_zoom {
+                let previous = level.clone();
+                level = Arc::new(block(move || scale_down(&previous)).await.map_err(blocked)?);
+            }
+
+            for y in 0..grid_size(width, height, max_zoom, zoom).1 {
+                let current = level.clone();
+                let row = block(move || render_row(&current, y)).await.map_err(blocked)?.map_err(failed)?;
+
+                for (x, tile) in (0..).zip(row) {
+                    self.store
+                        .put(&tile_key(&map.tile_prefix, zoom, x, y), "image/png", tile)
+                        .await
+                        .map_err(|e| {
+                            tracing::warn!(e = ?e, "Failed to store map tile");
+
+                            CreateMapError::UnknownError
+                        })?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Delete every tile of a map from the blob store.
+    ///
+    /// Failures are logged rather than returned, since a stray tile is harmless once nothing
+    /// refers to it.
+    ///
+    /// # Parameters
+    /// - `map` - The details of the map
+    pub(super) async fn remove_tiles(&self, map: &MapData) {
+        let (width, height, max_zoom) = pyramid(map);
+
+        for zoom in 0..=max_zoom {
+            let (columns, rows) = grid_size(width, height, max_zoom, zoom);
+            for x in 0..columns {
+                for y in 0..rows {
+                    let key = tile_key(&map.tile_prefix, zoom, x, y);
+                    if let Err(e) = self.store.delete(&key).await {
+                        tracing::warn!(e = ?e, key = ?key, "Failed to delete map tile");
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use crate::maps::service::tests::build_fixture;
+
+    #[actix_rt::test]
+    async fn get_tiles() {
+        let f = build_fixture();
+        let map = f.create_map(600, 300).await;
+
+        let_assert!(Ok(Some(tile)) = f.sut.get_tile(&map, 0, 0, 0).await);
+        let_assert!(Ok(image) = image::load_from_memory(&tile));
+        check!(image::GenericImageView::dimensions(&image) == (256, 256));
+
+        let_assert!(Ok(Some(_)) = f.sut.get_tile(&map, 2, 2, 1).await);
+
+        let_assert!(Ok(outside) = f.sut.get_tile(&map, 2, 3, 0).await);
+        check!(outside.is_none());
+        let_assert!(Ok(too_deep) = f.sut.get_tile(&map, 3, 0, 0).await);
+        check!(too_deep.is_none());
+    }
+}
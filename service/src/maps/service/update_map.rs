@@ -0,0 +1,65 @@
+use super::MapService;
+use crate::{
+    maps::{MapId, MapResource, SaveMapError},
+    worlds::WorldId,
+};
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum UpdateMapError {
+    #[error("Unknown map")]
+    UnknownMap,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl MapService {
+    /// Rename a map. The image of a map can't be changed once it has been created.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the map belongs to
+    /// - `map_id` - The ID of the map to rename
+    /// - `name` - The new name of the map
+    ///
+    /// # Returns
+    /// The renamed map.
+    pub async fn rename_map(&self, world_id: &WorldId, map_id: &MapId, name: String) -> Result<MapResource, UpdateMapError> {
+        let mut map = self.repository.get_map(world_id, map_id).await.ok_or(UpdateMapError::UnknownMap)?;
+        map.data.name = name;
+
+        let result = self.repository.update_map(map_id, &map.data).await?;
+
+        Ok(result)
+    }
+}
+
+impl From<SaveMapError> for UpdateMapError {
+    fn from(e: SaveMapError) -> Self {
+        match e {
+            SaveMapError::UnknownMap => Self::UnknownMap,
+            SaveMapError::UnknownLayer | SaveMapError::UnknownMarker | SaveMapError::UnknownError => Self::UnknownError,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::maps::service::tests::build_fixture;
+
+    #[actix_rt::test]
+    async fn rename_map() {
+        let f = build_fixture();
+        let map = f.create_map(100, 100).await;
+
+        let_assert!(Ok(renamed) = f.sut.rename_map(&f.world_id, &map.identity.id, "Arda".to_owned()).await);
+        check!(renamed.data.name == "Arda");
+        check!(renamed.data.tile_prefix == map.data.tile_prefix);
+        check!(renamed.identity.version != map.identity.version);
+
+        let_assert!(Err(e) = f.sut.rename_map(&WorldId::default(), &map.identity.id, "Arda".to_owned()).await);
+        check!(e == UpdateMapError::UnknownMap);
+    }
+}
@@ -0,0 +1,198 @@
+use bytes::Bytes;
+use image::{
+    imageops::{self, FilterType},
+    DynamicImage, GenericImageView, ImageOutputFormat, RgbaImage,
+};
+
+/// The width and height of every tile, in pixels.
+pub const TILE_SIZE: u32 = 256;
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum TileError {
+    #[error("The image could not be decoded")]
+    Undecodable,
+
+    #[error("A tile could not be encoded")]
+    Unencodable,
+}
+
+/// The most detailed zoom level needed for an image, at which the tiles show it at full resolution.
+/// At zoom level 0 the entire image fits within a single tile, and each level above that doubles
+/// the resolution.
+///
+/// # Parameters
+/// - `width` - The width of the image, in pixels
+/// - `height` - The height of the image, in pixels
+pub fn max_zoom(width: u32, height: u32) -> u32 {
+    let mut size = width.max(height);
+    let mut zoom = 0;
+    while size > TILE_SIZE {
+        size = size.div_ceil(2);
+        zoom += 1;
+    }
+
+    zoom
+}
+
+/// The size of an image once it has been scaled down for a zoom level.
+///
+/// # Parameters
+/// - `width` - The width of the image, in pixels
+/// - `height` - The height of the image, in pixels
+/// - `max_zoom` - The most detailed zoom level of the image
+/// - `zoom` - The zoom level
+///
+/// # Returns
+/// The width and height of the image at the zoom level, in pixels.
+pub fn level_size(width: u32, height: u32, max_zoom: u32, zoom: u32) -> (u32, u32) {
+    (zoom..max_zoom).fold((width, height), |(w, h), _| halve(w, h))
+}
+
+/// The number of columns and rows of tiles at a zoom level.
+///
+/// # Parameters
+/// - `width` - The width of the image, in pixels
+/// - `height` - The height of the image, in pixels
+/// - `max_zoom` - The most detailed zoom level of the image
+/// - `zoom` - The zoom level
+pub fn grid_size(width: u32, height: u32, max_zoom: u32, zoom: u32) -> (u32, u32) {
+    let (w, h) = level_size(width, height, max_zoom, zoom);
+
+    (w.div_ceil(TILE_SIZE), h.div_ceil(TILE_SIZE))
+}
+
+/// The key of a single tile in the blob store.
+///
+/// # Parameters
+/// - `prefix` - The prefix of the keys of all the tiles of the map
+/// - `zoom` - The zoom level of the tile
+/// - `x` - The column of the tile
+/// - `y` - The row of the tile
+pub fn tile_key(prefix: &str, zoom: u32, x: u32, y: u32) -> String {
+    format!("{}/{}/{}/{}.png", prefix, zoom, x, y)
+}
+
+/// Decode the base image of a map.
+///
+/// This is expensive, so should not be called on the async executor.
+///
+/// # Parameters
+/// - `data` - The contents of the image file
+pub fn decode(data: &[u8]) -> Result<RgbaImage, TileError> {
+    let image = image::load_from_memory(data).map_err(|e| {
+        tracing::warn!(e = ?e, "Failed to decode map image");
+
+        TileError::Undecodable
+    })?;
+
+    Ok(image.to_rgba8())
+}
+
+/// Scale an image down to the next zoom level.
+///
+/// This is expensive, so should not be called on the async executor.
+///
+/// # Parameters
+/// - `image` - The image at one zoom level
+///
+/// # Returns
+/// The image at the zoom level below.
+pub fn scale_down(image: &RgbaImage) -> RgbaImage {
+    let (width, height) = halve(image.width(), image.height());
+
+    imageops::resize(image, width, height, FilterType::Triangle)
+}
+
+/// Cut a single row of tiles out of an image. Tiles at the right and bottom edges of the image are
+/// padded with transparency, so that every tile is the same size.
+///
+/// This is expensive, so should not be called on the async executor.
+///
+/// # Parameters
+/// - `image` - The image at the zoom level being cut
+/// - `y` - The row of tiles to cut
+///
+/// # Returns
+/// The PNG encoded tiles of the row, from left to right.
+pub fn render_row(image: &RgbaImage, y: u32) -> Result<Vec<Bytes>, TileError> {
+    let top = y * TILE_SIZE;
+    let height = TILE_SIZE.min(image.height().saturating_sub(top));
+
+    (0..image.width().div_ceil(TILE_SIZE))
+        .map(|x| {
+            let left = x * TILE_SIZE;
+            let width = TILE_SIZE.min(image.width() - left);
+
+            let mut tile = RgbaImage::new(TILE_SIZE, TILE_SIZE);
+            imageops::replace(&mut tile, &image.view(left, top, width, height).to_image(), 0, 0);
+
+            let mut data = vec![];
+            DynamicImage::ImageRgba8(tile)
+                .write_to(&mut data, ImageOutputFormat::Png)
+                .map_err(|e| {
+                    tracing::warn!(e = ?e, "Failed to encode map tile");
+
+                    TileError::Unencodable
+                })?;
+
+            Ok(Bytes::from(data))
+        })
+        .collect()
+}
+
+/// Halve the size of an image, rounding up so that nothing is lost at the edges.
+fn halve(width: u32, height: u32) -> (u32, u32) {
+    (width.div_ceil(2), height.div_ceil(2))
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(100, 50, 0 ; "Smaller than a tile")]
+    #[test_case(256, 256, 0 ; "Exactly one tile")]
+    #[test_case(257, 10, 1 ; "Just over one tile")]
+    #[test_case(1000, 600, 2 ; "Landscape")]
+    #[test_case(600, 1025, 3 ; "Portrait")]
+    fn test_max_zoom(width: u32, height: u32, expected: u32) {
+        check!(max_zoom(width, height) == expected);
+    }
+
+    #[test_case(2, (1000, 600), (4, 3) ; "Full resolution")]
+    #[test_case(1, (500, 300), (2, 2) ; "Half resolution")]
+    #[test_case(0, (250, 150), (1, 1) ; "Quarter resolution")]
+    fn test_levels(zoom: u32, expected_size: (u32, u32), expected_grid: (u32, u32)) {
+        check!(level_size(1000, 600, 2, zoom) == expected_size);
+        check!(grid_size(1000, 600, 2, zoom) == expected_grid);
+    }
+
+    #[test]
+    fn test_tile_key() {
+        check!(tile_key("maps/abc", 3, 4, 5) == "maps/abc/3/4/5.png");
+    }
+
+    #[test]
+    fn render_pyramid() {
+        let image = RgbaImage::new(300, 200);
+
+        let_assert!(Ok(row) = render_row(&image, 0));
+        check!(row.len() == 2);
+
+        let_assert!(Ok(edge) = image::load_from_memory(&row[1]));
+        check!(edge.dimensions() == (TILE_SIZE, TILE_SIZE));
+
+        let smaller = scale_down(&image);
+        check!(smaller.dimensions() == (150, 100));
+        let_assert!(Ok(row) = render_row(&smaller, 0));
+        check!(row.len() == 1);
+    }
+
+    #[test]
+    fn decode_invalid() {
+        let_assert!(Err(e) = decode(b"Not an image"));
+        check!(e == TileError::Undecodable);
+    }
+}
@@ -5,7 +5,7 @@ use uuid::Uuid;
 ///
 /// # Types
 /// - `<I>` - The type to use for the ID.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Identity<I> {
     pub id:      I,
     pub version: Uuid,
@@ -18,7 +18,7 @@ pub struct Identity<I> {
 /// # Types
 /// - `<I>` - The type to use for the ID.
 /// - `<D>` - The type to use for the data.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Resource<I, D> {
     pub identity: Identity<I>,
     pub data:     D,
@@ -0,0 +1,12 @@
+pub mod component;
+mod model;
+mod purge_events;
+mod relay;
+mod repository;
+mod sinks;
+
+pub use model::*;
+pub use purge_events::*;
+pub use relay::*;
+pub use repository::*;
+pub use sinks::*;
@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use super::{
+    repository::OutboxRepository, EventSink, KafkaSink, LogSink, NatsSink, OutboxRelay, PurgeOutboxEvents, PurgeOutboxEventsPayload,
+    WebhookSink,
+};
+use crate::{jobs::JobRegistry, settings::Settings};
+
+/// How many days to keep events for after they have been published to every sink.
+const RETENTION_DAYS: u32 = 7;
+
+/// Component for publishing the events in the outbox to the sinks that other services consume them
+/// from.
+pub struct Component {
+    pub relay:  Arc<OutboxRelay>,
+    repository: Arc<dyn OutboxRepository>,
+    /// The names of the sinks that events are published to.
+    sinks:      Vec<String>,
+}
+
+impl Component {
+    /// Create a new outbox component, publishing to whichever sinks are configured in the settings.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository that the outbox is stored in
+    /// - `settings` - The settings of the service
+    pub fn new(repository: Arc<dyn OutboxRepository>, settings: &Settings) -> Arc<Self> {
+        let sinks = settings
+            .outbox_sinks
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(|name| -> Arc<dyn EventSink> {
+                match name {
+                    "log" => Arc::new(LogSink::default()),
+                    "webhook" => Arc::new(WebhookSink::new(
+                        settings.outbox_webhook_url.as_deref().expect("No outbox webhook URL configured"),
+                        settings.outbox_webhook_secret.as_deref().map(Into::into),
+                    )),
+                    "nats" => Arc::new(NatsSink::new(
+                        settings.outbox_nats_url.as_deref().expect("No outbox NATS URL configured"),
+                        &settings.outbox_nats_prefix,
+                    )),
+                    "kafka" => Arc::new(KafkaSink::new(
+                        settings.outbox_kafka_url.as_deref().expect("No outbox Kafka proxy URL configured"),
+                        &settings.outbox_kafka_topic,
+                    )),
+                    other => panic!("Unknown outbox sink: {}", other),
+                }
+            })
+            .collect::<Vec<_>>();
+        tracing::info!(sinks = ?sinks.iter().map(|sink| sink.name()).collect::<Vec<_>>(), "Publishing domain events");
+
+        Arc::new(Self {
+            sinks: sinks.iter().map(|sink| sink.name().to_owned()).collect(),
+            relay: Arc::new(OutboxRelay::new(repository.clone(), sinks)),
+            repository,
+        })
+    }
+
+    /// Register the jobs that the outbox needs to be run in the background.
+    ///
+    /// # Parameters
+    /// - `registry` - The registry to add the jobs to
+    ///
+    /// # Returns
+    /// The registry, with the jobs added.
+    pub fn register_jobs(&self, registry: JobRegistry) -> JobRegistry {
+        registry
+            .with_job(PurgeOutboxEvents::new(self.repository.clone(), self.sinks.clone()))
+            .with_schedule::<PurgeOutboxEvents>(
+                "outbox.purge",
+                "0 4 * * *",
+                &PurgeOutboxEventsPayload {
+                    retention_days: RETENTION_DAYS,
+                },
+            )
+    }
+
+    /// Start publishing events, in the background.
+    pub fn relay(&self) {
+        actix_rt::spawn(self.relay.clone().run());
+    }
+}
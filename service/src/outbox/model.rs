@@ -0,0 +1,116 @@
+mod outbox_event_id;
+
+use chrono::{DateTime, Utc};
+pub use outbox_event_id::*;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Something that happened to a single aggregate, such as a user, that other services might want
+/// to know about.
+pub trait DomainEvent: Serialize {
+    /// The kind of aggregate that the events happen to, such as `user`.
+    const AGGREGATE_TYPE: &'static str;
+
+    /// The name of the event, in the form `<aggregate>.<event>`, such as `user.registered`.
+    fn event_type(&self) -> &'static str;
+}
+
+/// An event that is ready to be written to the outbox.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewOutboxEvent {
+    pub aggregate_type: String,
+    pub aggregate_id:   String,
+    pub event_type:     String,
+    pub payload:        Value,
+}
+
+impl NewOutboxEvent {
+    /// Prepare a domain event to be written to the outbox.
+    ///
+    /// # Parameters
+    /// - `aggregate_id` - The ID of the aggregate that the event happened to
+    /// - `event` - The event
+    pub fn new<E>(aggregate_id: &str, event: &E) -> Self
+    where
+        E: DomainEvent,
+    {
+        Self {
+            aggregate_type: E::AGGREGATE_TYPE.to_owned(),
+            aggregate_id:   aggregate_id.to_owned(),
+            event_type:     event.event_type().to_owned(),
+            payload:        serde_json::to_value(event).expect("Failed to serialize domain event"),
+        }
+    }
+}
+
+/// An event that has been written to the outbox. This is also the envelope that sinks publish the
+/// event in, so that consumers can use the ID to ignore any event that they are sent twice.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutboxEvent {
+    #[serde(rename = "id")]
+    pub event_id:       OutboxEventId,
+    #[serde(rename = "type")]
+    pub event_type:     String,
+    pub aggregate_type: String,
+    pub aggregate_id:   String,
+    /// When the event happened.
+    pub occurred:       DateTime<Utc>,
+    #[serde(rename = "data")]
+    pub payload:        Value,
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Renamed {
+        name: String,
+    }
+
+    impl DomainEvent for Renamed {
+        const AGGREGATE_TYPE: &'static str = "thing";
+
+        fn event_type(&self) -> &'static str {
+            "thing.renamed"
+        }
+    }
+
+    #[test]
+    fn new_event() {
+        let event = NewOutboxEvent::new("abc", &Renamed { name: "New".to_owned() });
+
+        check!(event.aggregate_type == "thing");
+        check!(event.aggregate_id == "abc");
+        check!(event.event_type == "thing.renamed");
+        check!(event.payload == json!({ "name": "New" }));
+    }
+
+    #[test]
+    fn envelope() {
+        let event = OutboxEvent {
+            event_id:       OutboxEventId::from(42),
+            event_type:     "thing.renamed".to_owned(),
+            aggregate_type: "thing".to_owned(),
+            aggregate_id:   "abc".to_owned(),
+            occurred:       "2021-09-06T12:00:00Z".parse().unwrap(),
+            payload:        json!({ "name": "New" }),
+        };
+
+        check!(
+            serde_json::to_value(&event).unwrap()
+                == json!({
+                    "id": 42,
+                    "type": "thing.renamed",
+                    "aggregateType": "thing",
+                    "aggregateId": "abc",
+                    "occurred": "2021-09-06T12:00:00Z",
+                    "data": { "name": "New" }
+                })
+        );
+    }
+}
@@ -0,0 +1,29 @@
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use serde::Serialize;
+
+/// The ID of a single event in the outbox. Events are numbered in the order that they were written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, FromSql)]
+pub struct OutboxEventId(i64);
+
+impl std::fmt::Display for OutboxEventId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl ToSql for OutboxEventId {
+    accepts!(INT8);
+    to_sql_checked!();
+
+    fn to_sql(&self, t: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.0.to_sql(t, w)
+    }
+}
+
+#[cfg(test)]
+impl From<i64> for OutboxEventId {
+    fn from(id: i64) -> Self {
+        Self(id)
+    }
+}
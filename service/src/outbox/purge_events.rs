@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use super::repository::OutboxRepository;
+use crate::jobs::{Job, JobError};
+
+/// Details of which events to purge from the outbox.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurgeOutboxEventsPayload {
+    /// How many days to keep events for after they have been published to every sink.
+    pub retention_days: u32,
+}
+
+/// Job that deletes the events that have been published to every sink, so that the outbox doesn't
+/// grow forever.
+pub struct PurgeOutboxEvents {
+    repository: Arc<dyn OutboxRepository>,
+    sinks:      Vec<String>,
+}
+
+impl PurgeOutboxEvents {
+    /// Create the job.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository that the outbox is stored in
+    /// - `sinks` - The names of the sinks that events are published to
+    pub fn new(repository: Arc<dyn OutboxRepository>, sinks: Vec<String>) -> Self {
+        Self { repository, sinks }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Job for PurgeOutboxEvents {
+    type Payload = PurgeOutboxEventsPayload;
+
+    const KIND: &'static str = "outbox.purge";
+
+    async fn run(&self, payload: PurgeOutboxEventsPayload) -> Result<(), JobError> {
+        let published_before = Utc::now() - chrono::Duration::days(i64::from(payload.retention_days));
+        let count = self.repository.purge_published_events(&self.sinks, published_before).await;
+        tracing::info!(count = count, published_before = ?published_before, "Purged published outbox events");
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+
+    use super::*;
+    use crate::outbox::{MemoryOutboxRepository, NewOutboxEvent};
+
+    #[actix_rt::test]
+    async fn purge_published_events() {
+        let repository = Arc::new(MemoryOutboxRepository::new());
+        let write = || {
+            repository.write(NewOutboxEvent {
+                aggregate_type: "user".to_owned(),
+                aggregate_id:   "user-1".to_owned(),
+                event_type:     "user.registered".to_owned(),
+                payload:        serde_json::json!({}),
+            })
+        };
+        let published = write();
+        let partly_published = write();
+        repository.mark_published("first", published).await;
+        repository.mark_published("second", published).await;
+        repository.mark_published("first", partly_published).await;
+
+        let sut = PurgeOutboxEvents::new(repository.clone(), vec!["first".to_owned(), "second".to_owned()]);
+
+        // Nothing has been published for long enough yet.
+        check!(sut.run(PurgeOutboxEventsPayload { retention_days: 1 }).await == Ok(()));
+        check!(repository.get_unpublished_events("third", 10).await.len() == 2);
+
+        check!(sut.run(PurgeOutboxEventsPayload { retention_days: 0 }).await == Ok(()));
+        let remaining: Vec<_> = repository
+            .get_unpublished_events("third", 10)
+            .await
+            .iter()
+            .map(|e| e.event_id)
+            .collect();
+        check!(remaining == vec![partly_published]);
+
+        // The IDs of purged events aren't reused.
+        check!(write() > partly_published);
+    }
+}
@@ -0,0 +1,214 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::{repository::OutboxRepository, EventSink};
+
+/// How often to look for events to publish.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// The most events to publish to a sink at once.
+const BATCH_SIZE: u32 = 100;
+
+/// How long a relay holds the right to publish to a sink before another relay can take over. This
+/// needs to be comfortably longer than publishing a batch can take.
+const LEASE: Duration = Duration::from_mins(5);
+
+/// Relay that publishes the events in the outbox to every sink.
+///
+/// Every sink is sent every event in the order that they were written, and only one relay
+/// publishes to each sink at a time, whichever instance of the service it belongs to. A sink that
+/// fails is retried from the same event on the next poll, without holding up the other sinks.
+pub struct OutboxRelay {
+    repository: Arc<dyn OutboxRepository>,
+    sinks:      Vec<Arc<dyn EventSink>>,
+    /// The ID of this relay, to tell it apart from the relays of other instances of the service.
+    relay_id:   Uuid,
+}
+
+impl OutboxRelay {
+    /// Create a new outbox relay.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository that the outbox is stored in
+    /// - `sinks` - The sinks to publish events to
+    pub fn new(repository: Arc<dyn OutboxRepository>, sinks: Vec<Arc<dyn EventSink>>) -> Self {
+        Self {
+            repository,
+            sinks,
+            relay_id: Uuid::new_v4(),
+        }
+    }
+
+    /// Publish events for as long as the service is running.
+    pub async fn run(self: Arc<Self>) {
+        let mut interval = actix_rt::time::interval(POLL_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            // Keep going straight away while there's a backlog, rather than waiting for the next tick.
+            while self.relay(Utc::now()).await == BATCH_SIZE as usize {}
+        }
+    }
+
+    /// Publish the next batch of events to every sink.
+    ///
+    /// # Parameters
+    /// - `now` - The current time
+    ///
+    /// # Returns
+    /// The most events that were published to any one sink.
+    pub async fn relay(&self, now: DateTime<Utc>) -> usize {
+        let published = futures::future::join_all(self.sinks.iter().map(|sink| self.relay_to(sink.as_ref(), now))).await;
+
+        published.into_iter().max().unwrap_or_default()
+    }
+
+    /// Publish the next batch of events to a single sink, stopping at the first one that fails so
+    /// that the sink never receives them out of order.
+    ///
+    /// # Parameters
+    /// - `sink` - The sink
+    /// - `now` - The current time
+    ///
+    /// # Returns
+    /// The number of events that were published.
+    #[tracing::instrument(skip(self, sink, now), fields(sink = sink.name()))]
+    async fn relay_to(&self, sink: &dyn EventSink, now: DateTime<Utc>) -> usize {
+        let lease_until = now + chrono::Duration::from_std(LEASE).unwrap();
+        if !self.repository.claim_sink(sink.name(), &self.relay_id, now, lease_until).await {
+            return 0;
+        }
+
+        let mut published = 0;
+        for event in self.repository.get_unpublished_events(sink.name(), BATCH_SIZE).await {
+            if let Err(e) = sink.publish(&event).await {
+                tracing::warn!(e = ?e, event_id = %event.event_id, "Failed to publish event");
+                break;
+            }
+
+            self.repository.mark_published(sink.name(), event.event_id).await;
+            published += 1;
+        }
+
+        published
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::VecDeque, sync::Mutex};
+
+    use assert2::check;
+
+    use super::*;
+    use crate::outbox::{MemoryOutboxRepository, NewOutboxEvent, OutboxEvent, OutboxEventId, PublishError};
+
+    /// Sink that records what it is sent, and fails as many times as it is told to.
+    #[derive(Default)]
+    struct RecordingSink {
+        name:      &'static str,
+        failures:  Mutex<VecDeque<PublishError>>,
+        published: Mutex<Vec<OutboxEventId>>,
+    }
+
+    impl RecordingSink {
+        fn new(name: &'static str, failures: Vec<PublishError>) -> Arc<Self> {
+            Arc::new(Self {
+                name,
+                failures: Mutex::new(failures.into()),
+                ..Self::default()
+            })
+        }
+
+        fn published(&self) -> Vec<OutboxEventId> {
+            self.published.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait::async_trait(?Send)]
+    impl EventSink for RecordingSink {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        async fn publish(&self, event: &OutboxEvent) -> Result<(), PublishError> {
+            if let Some(e) = self.failures.lock().unwrap().pop_front() {
+                return Err(e);
+            }
+
+            self.published.lock().unwrap().push(event.event_id);
+            Ok(())
+        }
+    }
+
+    fn write_events(repository: &MemoryOutboxRepository, count: usize) -> Vec<OutboxEventId> {
+        (0..count)
+            .map(|i| {
+                repository.write(NewOutboxEvent {
+                    aggregate_type: "user".to_owned(),
+                    aggregate_id:   format!("user-{}", i),
+                    event_type:     "user.registered".to_owned(),
+                    payload:        serde_json::json!({}),
+                })
+            })
+            .collect()
+    }
+
+    #[actix_rt::test]
+    async fn publish_in_order() {
+        let repository = Arc::new(MemoryOutboxRepository::new());
+        let sink = RecordingSink::new("first", vec![]);
+        let sut = OutboxRelay::new(repository.clone(), vec![sink.clone()]);
+        let written = write_events(&repository, 3);
+
+        check!(sut.relay(Utc::now()).await == 3);
+        check!(sink.published() == written);
+
+        // Nothing is published twice.
+        check!(sut.relay(Utc::now()).await == 0);
+        let more = write_events(&repository, 1);
+        check!(sut.relay(Utc::now()).await == 1);
+        check!(sink.published() == [written, more].concat());
+    }
+
+    #[actix_rt::test]
+    async fn resume_after_failure() {
+        let repository = Arc::new(MemoryOutboxRepository::new());
+        let failing = RecordingSink::new("failing", vec![PublishError::Unreachable("Down".to_owned())]);
+        let healthy = RecordingSink::new("healthy", vec![]);
+        let sut = OutboxRelay::new(repository.clone(), vec![failing.clone(), healthy.clone()]);
+        let written = write_events(&repository, 2);
+
+        // The failing sink doesn't hold up the healthy one.
+        check!(sut.relay(Utc::now()).await == 2);
+        check!(failing.published().is_empty());
+        check!(healthy.published() == written);
+
+        check!(sut.relay(Utc::now()).await == 2);
+        check!(failing.published() == written);
+        check!(healthy.published() == written);
+    }
+
+    #[actix_rt::test]
+    async fn one_relay_per_sink() {
+        let repository = Arc::new(MemoryOutboxRepository::new());
+        let first_sink = RecordingSink::new("shared", vec![]);
+        let second_sink = RecordingSink::new("shared", vec![]);
+        let first = OutboxRelay::new(repository.clone(), vec![first_sink.clone()]);
+        let second = OutboxRelay::new(repository.clone(), vec![second_sink.clone()]);
+        write_events(&repository, 1);
+
+        let now = Utc::now();
+        check!(first.relay(now).await == 1);
+        write_events(&repository, 1);
+        check!(second.relay(now).await == 0);
+
+        // The other relay takes over once the lease lapses.
+        check!(second.relay(now + chrono::Duration::from_std(LEASE).unwrap()).await == 1);
+        check!(first_sink.published().len() == 1);
+        check!(second_sink.published().len() == 1);
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod memory;
+mod parse;
+mod postgres;
+mod write_events;
+
+use chrono::{DateTime, Utc};
+#[cfg(test)]
+pub use memory::MemoryOutboxRepository;
+pub use postgres::PostgresOutboxRepository;
+use uuid::Uuid;
+pub use write_events::write_outbox_events;
+
+use super::{OutboxEvent, OutboxEventId};
+
+/// Repository of the events in the outbox, and of which sinks they have been published to.
+///
+/// Events are written to the outbox by the repositories of the aggregates that they happen to, in
+/// the same transaction as the change itself, so there is nothing here to add to it.
+#[async_trait::async_trait]
+pub trait OutboxRepository: Send + Sync {
+    /// Claim the right to publish the events to a sink, so that no other relay publishes them to
+    /// the same sink at the same time. A relay that already holds the claim extends it.
+    ///
+    /// # Parameters
+    /// - `sink` - The name of the sink.
+    /// - `holder` - The ID of the relay that is claiming the sink.
+    /// - `now` - The current time.
+    /// - `lease_until` - When the claim lapses, unless it is extended.
+    ///
+    /// # Returns
+    /// True if the relay holds the claim. False if another relay does.
+    async fn claim_sink(&self, sink: &str, holder: &Uuid, now: DateTime<Utc>, lease_until: DateTime<Utc>) -> bool;
+
+    /// Get the events that haven't been published to a sink yet, oldest first.
+    ///
+    /// # Parameters
+    /// - `sink` - The name of the sink.
+    /// - `limit` - The most events to return.
+    ///
+    /// # Returns
+    /// The events.
+    async fn get_unpublished_events(&self, sink: &str, limit: u32) -> Vec<OutboxEvent>;
+
+    /// Record that an event has been published to a sink.
+    ///
+    /// # Parameters
+    /// - `sink` - The name of the sink.
+    /// - `event_id` - The ID of the event.
+    async fn mark_published(&self, sink: &str, event_id: OutboxEventId);
+
+    /// Delete the events that were published to every sink a while ago, along with the record of
+    /// their publications.
+    ///
+    /// # Parameters
+    /// - `sinks` - The names of the sinks that events are published to.
+    /// - `published_before` - The time that the events must have been published to every sink
+    ///   before.
+    ///
+    /// # Returns
+    /// The number of events that were deleted.
+    async fn purge_published_events(&self, sinks: &[String], published_before: DateTime<Utc>) -> u64;
+}
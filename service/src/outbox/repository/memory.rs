@@ -0,0 +1,98 @@
+use std::{collections::HashMap, convert::TryFrom, sync::Mutex};
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::OutboxRepository;
+use crate::outbox::{NewOutboxEvent, OutboxEvent, OutboxEventId};
+
+/// Repository of the outbox that is stored in memory.
+#[derive(Default)]
+pub struct MemoryOutboxRepository {
+    events:       Mutex<Vec<OutboxEvent>>,
+    /// When each event was published to each sink.
+    publications: Mutex<HashMap<(String, OutboxEventId), DateTime<Utc>>>,
+    /// How many events have ever been written, so that their IDs aren't reused once they're purged.
+    written:      Mutex<i64>,
+    relays:       Mutex<HashMap<String, (Uuid, DateTime<Utc>)>>,
+}
+
+impl MemoryOutboxRepository {
+    /// Create a new, empty outbox repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Write an event to the outbox, as the repository of its aggregate would.
+    ///
+    /// # Parameters
+    /// - `event` - The event
+    ///
+    /// # Returns
+    /// The ID of the event.
+    pub fn write(&self, event: NewOutboxEvent) -> OutboxEventId {
+        let mut written = self.written.lock().unwrap();
+        *written += 1;
+
+        let event_id = OutboxEventId::from(*written);
+        self.events.lock().unwrap().push(OutboxEvent {
+            event_id,
+            event_type: event.event_type,
+            aggregate_type: event.aggregate_type,
+            aggregate_id: event.aggregate_id,
+            occurred: Utc::now(),
+            payload: event.payload,
+        });
+
+        event_id
+    }
+}
+
+#[async_trait::async_trait]
+impl OutboxRepository for MemoryOutboxRepository {
+    async fn claim_sink(&self, sink: &str, holder: &Uuid, now: DateTime<Utc>, lease_until: DateTime<Utc>) -> bool {
+        let mut relays = self.relays.lock().unwrap();
+
+        match relays.get(sink) {
+            Some((existing, expires)) if existing != holder && *expires > now => false,
+            _ => {
+                relays.insert(sink.to_owned(), (*holder, lease_until));
+                true
+            },
+        }
+    }
+
+    async fn get_unpublished_events(&self, sink: &str, limit: u32) -> Vec<OutboxEvent> {
+        let events = self.events.lock().unwrap();
+        let publications = self.publications.lock().unwrap();
+
+        events
+            .iter()
+            .filter(|e| !publications.contains_key(&(sink.to_owned(), e.event_id)))
+            .take(limit as usize)
+            .cloned()
+            .collect()
+    }
+
+    async fn mark_published(&self, sink: &str, event_id: OutboxEventId) {
+        self.publications.lock().unwrap().insert((sink.to_owned(), event_id), Utc::now());
+    }
+
+    async fn purge_published_events(&self, sinks: &[String], published_before: DateTime<Utc>) -> u64 {
+        let mut events = self.events.lock().unwrap();
+        let mut publications = self.publications.lock().unwrap();
+
+        let before = events.len();
+        events.retain(|e| {
+            e.occurred >= published_before
+                || !sinks.iter().all(|sink| {
+                    publications
+                        .get(&(sink.clone(), e.event_id))
+                        .is_some_and(|published| *published < published_before)
+                })
+        });
+        publications.retain(|(_, event_id), _| events.iter().any(|e| &e.event_id == event_id));
+
+        u64::try_from(before - events.len()).unwrap()
+    }
+}
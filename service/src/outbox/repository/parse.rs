@@ -0,0 +1,16 @@
+use tokio_postgres::Row;
+
+use crate::outbox::OutboxEvent;
+
+impl From<Row> for OutboxEvent {
+    fn from(row: Row) -> Self {
+        Self {
+            event_id:       row.get("event_id"),
+            event_type:     row.get("event_type"),
+            aggregate_type: row.get("aggregate_type"),
+            aggregate_id:   row.get("aggregate_id"),
+            occurred:       row.get("created"),
+            payload:        row.get("payload"),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::OutboxRepository;
+use crate::{
+    database::Database,
+    outbox::{OutboxEvent, OutboxEventId},
+};
+
+/// Repository of the outbox that is stored in Postgres.
+pub struct PostgresOutboxRepository {
+    database: Arc<Database>,
+}
+
+impl PostgresOutboxRepository {
+    /// Create a new outbox repository.
+    pub fn new(database: Arc<Database>) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait::async_trait]
+impl OutboxRepository for PostgresOutboxRepository {
+    #[tracing::instrument(skip(self))]
+    async fn claim_sink(&self, sink: &str, holder: &Uuid, now: DateTime<Utc>, lease_until: DateTime<Utc>) -> bool {
+        let conn = self.database.connect().await;
+        conn.query_opt(
+            "INSERT INTO outbox_relays(sink, holder, leased_until) VALUES ($1, $2, $3)
+              ON CONFLICT (sink) DO UPDATE SET holder = EXCLUDED.holder, leased_until = EXCLUDED.leased_until
+               WHERE outbox_relays.holder = EXCLUDED.holder OR outbox_relays.leased_until <= $4
+              RETURNING sink",
+            &[&sink, &holder, &lease_until, &now],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to claim outbox sink");
+                false
+            },
+            |row| row.is_some(),
+        )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_unpublished_events(&self, sink: &str, limit: u32) -> Vec<OutboxEvent> {
+        let conn = self.database.connect().await;
+        conn.query(
+            "SELECT e.* FROM outbox_events e
+              WHERE NOT EXISTS (SELECT 1 FROM outbox_publications p WHERE p.sink = $1 AND p.event_id = e.event_id)
+              ORDER BY e.event_id
+              LIMIT $2",
+            &[&sink, &i64::from(limit)],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to load outbox events");
+                vec![]
+            },
+            |rows| rows.into_iter().map(OutboxEvent::from).collect(),
+        )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn mark_published(&self, sink: &str, event_id: OutboxEventId) {
+        let conn = self.database.connect().await;
+        if let Err(e) = conn
+            .execute(
+                "INSERT INTO outbox_publications(sink, event_id) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+                &[&sink, &event_id],
+            )
+            .await
+        {
+            tracing::warn!(e = ?e, "Failed to record outbox publication");
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn purge_published_events(&self, sinks: &[String], published_before: DateTime<Utc>) -> u64 {
+        let conn = self.database.connect().await;
+        conn.execute(
+            "DELETE FROM outbox_events e
+              WHERE e.created < $2
+                AND NOT EXISTS (
+                  SELECT 1 FROM UNNEST($1::TEXT[]) AS s(sink)
+                   WHERE NOT EXISTS (
+                     SELECT 1 FROM outbox_publications p
+                      WHERE p.sink = s.sink AND p.event_id = e.event_id AND p.published < $2))",
+            &[&sinks, &published_before],
+        )
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!(e = ?e, "Failed to purge outbox events");
+            0
+        })
+    }
+}
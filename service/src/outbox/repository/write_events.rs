@@ -0,0 +1,19 @@
+use crate::{database::Transaction, outbox::NewOutboxEvent};
+
+/// Write events to the outbox as part of a transaction, so that they are only ever published if
+/// the change that they describe is committed.
+///
+/// # Parameters
+/// - `tx` - The transaction that the change is being made in
+/// - `events` - The events to write
+pub async fn write_outbox_events(tx: &Transaction<'_>, events: &[NewOutboxEvent]) -> Result<(), tokio_postgres::Error> {
+    for event in events {
+        tx.execute(
+            "INSERT INTO outbox_events(aggregate_type, aggregate_id, event_type, payload) VALUES ($1, $2, $3, $4)",
+            &[&event.aggregate_type, &event.aggregate_id, &event.event_type, &event.payload],
+        )
+        .await?;
+    }
+
+    Ok(())
+}
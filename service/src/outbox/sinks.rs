@@ -0,0 +1,41 @@
+mod kafka;
+mod log;
+mod nats;
+mod webhook;
+
+pub use kafka::*;
+pub use log::*;
+pub use nats::*;
+pub use webhook::*;
+
+use super::OutboxEvent;
+
+/// Why an event couldn't be published to a sink.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum PublishError {
+    #[error("The sink couldn't be reached: {0}")]
+    Unreachable(String),
+
+    #[error("The sink rejected the event: {0}")]
+    Rejected(String),
+}
+
+/// Somewhere that the events in the outbox are published to, for other services to consume.
+///
+/// An event can be published to a sink more than once, if the relay stops after publishing it but
+/// before recording that it did. Consumers use the ID of the event to ignore any repeats.
+///
+/// Publishing doesn't need to be `Send`, since the HTTP client that some sinks use is tied to the
+/// thread that it runs on.
+#[async_trait::async_trait(?Send)]
+pub trait EventSink: Send + Sync {
+    /// The name of the sink, which is used to keep track of which events it has been sent. This
+    /// must never change once events have been published to it.
+    fn name(&self) -> &str;
+
+    /// Publish a single event to the sink.
+    ///
+    /// # Parameters
+    /// - `event` - The event
+    async fn publish(&self, event: &OutboxEvent) -> Result<(), PublishError>;
+}
@@ -0,0 +1,140 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde_json::json;
+
+use super::{EventSink, PublishError};
+use crate::outbox::OutboxEvent;
+
+/// The content type of the records sent to the proxy, with JSON keys and values.
+const RECORDS_CONTENT_TYPE: &str = "application/vnd.kafka.json.v2+json";
+
+/// The content type that the proxy responds with.
+const RESPONSE_CONTENT_TYPE: &str = "application/vnd.kafka.v2+json";
+
+/// How long to wait for the proxy to respond before giving up on publishing an event.
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Sink that produces each event to a topic on a Kafka-compatible broker, through the REST proxy in
+/// front of it, such as the Confluent REST Proxy or Redpanda's.
+///
+/// Events are keyed by the ID of their aggregate, so that every event for an aggregate lands on the
+/// same partition and is consumed in order.
+#[derive(Debug)]
+pub struct KafkaSink {
+    url:   String,
+    topic: String,
+}
+
+/// The response from the proxy, with the outcome of each record that was produced.
+#[derive(Debug, Deserialize)]
+struct ProduceResponse {
+    offsets: Vec<ProduceOffset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ProduceOffset {
+    error: Option<String>,
+}
+
+impl KafkaSink {
+    /// Create a new Kafka sink.
+    ///
+    /// # Parameters
+    /// - `url` - The base URL of the REST proxy
+    /// - `topic` - The topic to produce events to
+    pub fn new(url: &str, topic: &str) -> Self {
+        Self {
+            url:   url.trim_end_matches('/').to_owned(),
+            topic: topic.to_owned(),
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl EventSink for KafkaSink {
+    fn name(&self) -> &'static str {
+        "kafka"
+    }
+
+    #[tracing::instrument(skip(self, event), fields(event_id = %event.event_id))]
+    async fn publish(&self, event: &OutboxEvent) -> Result<(), PublishError> {
+        let body = json!({
+            "records": [{
+                "key": event.aggregate_id,
+                "value": event,
+            }]
+        });
+
+        let client = awc::Client::builder().timeout(TIMEOUT).finish();
+        let mut response = client
+            .post(format!("{}/topics/{}", self.url, self.topic))
+            .content_type(RECORDS_CONTENT_TYPE)
+            .insert_header(("Accept", RESPONSE_CONTENT_TYPE))
+            .send_body(serde_json::to_vec(&body).expect("Failed to serialize event"))
+            .await
+            .map_err(|e| PublishError::Unreachable(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(PublishError::Rejected(format!("The proxy responded with {}", response.status())));
+        }
+
+        // The proxy can accept the request but still fail to produce the record.
+        let body = response.body().await.map_err(|e| PublishError::Unreachable(e.to_string()))?;
+        match serde_json::from_slice::<ProduceResponse>(&body) {
+            Ok(produced) => match produced.offsets.into_iter().find_map(|offset| offset.error) {
+                Some(error) => Err(PublishError::Rejected(error)),
+                None => Ok(()),
+            },
+            Err(e) => {
+                tracing::debug!(e = ?e, "Proxy response wasn't understood");
+                Ok(())
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use chrono::Utc;
+
+    use super::*;
+    use crate::{outbox::OutboxEventId, webhooks::testing::StandInReceiver};
+
+    fn event() -> OutboxEvent {
+        OutboxEvent {
+            event_id:       OutboxEventId::from(3),
+            event_type:     "user.updated".to_owned(),
+            aggregate_type: "user".to_owned(),
+            aggregate_id:   "abc".to_owned(),
+            occurred:       Utc::now(),
+            payload:        json!({ "displayName": "Frodo" }),
+        }
+    }
+
+    #[actix_rt::test]
+    async fn publish() {
+        let receiver = StandInReceiver::start(&[]);
+        let sut = KafkaSink::new(&receiver.url(), "worlds-events");
+
+        check!(sut.publish(&event()).await == Ok(()));
+
+        let received = receiver.received();
+        let_assert!([request] = received.as_slice());
+        check!(request.header("content-type") == Some(RECORDS_CONTENT_TYPE));
+
+        let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+        check!(body["records"][0]["key"] == json!("abc"));
+        check!(body["records"][0]["value"]["id"] == json!(3));
+        check!(body["records"][0]["value"]["type"] == json!("user.updated"));
+    }
+
+    #[actix_rt::test]
+    async fn publish_rejected() {
+        let receiver = StandInReceiver::start(&[404]);
+        let sut = KafkaSink::new(&receiver.url(), "unknown-topic");
+
+        let_assert!(Err(PublishError::Rejected(_)) = sut.publish(&event()).await);
+    }
+}
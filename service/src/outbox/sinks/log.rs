@@ -0,0 +1,27 @@
+use super::{EventSink, PublishError};
+use crate::outbox::OutboxEvent;
+
+/// Sink that writes events to the log of the service, for when nothing else is consuming them.
+#[derive(Debug, Default)]
+pub struct LogSink {}
+
+#[async_trait::async_trait(?Send)]
+impl EventSink for LogSink {
+    fn name(&self) -> &'static str {
+        "log"
+    }
+
+    async fn publish(&self, event: &OutboxEvent) -> Result<(), PublishError> {
+        tracing::info!(
+            sink = self.name(),
+            event_id = %event.event_id,
+            event_type = ?event.event_type,
+            aggregate_type = ?event.aggregate_type,
+            aggregate_id = ?event.aggregate_id,
+            payload = %event.payload,
+            "Domain event"
+        );
+
+        Ok(())
+    }
+}
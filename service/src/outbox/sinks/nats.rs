@@ -0,0 +1,261 @@
+use std::time::Duration;
+
+use futures::lock::Mutex;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::TcpStream,
+};
+
+use super::{EventSink, PublishError};
+use crate::outbox::OutboxEvent;
+
+/// How long to wait for the server to acknowledge an event before giving up on publishing it.
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Sink that publishes each event to a NATS server, on the subject `<prefix>.<event type>`.
+///
+/// Each event carries its ID in the `Nats-Msg-Id` header, so that a `JetStream` stream on the
+/// subjects drops any event that it is sent twice.
+pub struct NatsSink {
+    address:    String,
+    prefix:     String,
+    connection: Mutex<Option<BufReader<TcpStream>>>,
+}
+
+impl NatsSink {
+    /// Create a new NATS sink. The connection to the server isn't made until the first event is
+    /// published.
+    ///
+    /// # Parameters
+    /// - `address` - The address of the server, such as `nats://localhost:4222`
+    /// - `prefix` - The prefix of the subjects to publish events on
+    pub fn new(address: &str, prefix: &str) -> Self {
+        Self {
+            address:    address.trim_start_matches("nats://").to_owned(),
+            prefix:     prefix.to_owned(),
+            connection: Mutex::new(None),
+        }
+    }
+
+    /// Connect to the server, in verbose mode so that it acknowledges every message.
+    async fn connect(&self) -> Result<BufReader<TcpStream>, PublishError> {
+        let stream = TcpStream::connect(&self.address)
+            .await
+            .map_err(|e| PublishError::Unreachable(e.to_string()))?;
+        let mut connection = BufReader::new(stream);
+
+        let info = read_line(&mut connection).await?;
+        if !info.starts_with("INFO") {
+            return Err(PublishError::Unreachable(format!("Unexpected greeting from server: {}", info)));
+        }
+
+        write(
+            &mut connection,
+            br#"CONNECT {"verbose":true,"pedantic":false,"headers":true,"name":"worlds"}"#,
+        )
+        .await?;
+        write(&mut connection, b"\r\n").await?;
+        await_ok(&mut connection).await?;
+
+        Ok(connection)
+    }
+
+    /// Publish an event over a connection, and wait for the server to acknowledge it.
+    async fn send(&self, connection: &mut BufReader<TcpStream>, event: &OutboxEvent) -> Result<(), PublishError> {
+        let subject = format!("{}.{}", self.prefix, event.event_type);
+        let headers = format!("NATS/1.0\r\nNats-Msg-Id: {}\r\n\r\n", event.event_id);
+        let payload = serde_json::to_vec(event).expect("Failed to serialize event");
+
+        let command = format!("HPUB {} {} {}\r\n", subject, headers.len(), headers.len() + payload.len());
+        write(connection, command.as_bytes()).await?;
+        write(connection, headers.as_bytes()).await?;
+        write(connection, &payload).await?;
+        write(connection, b"\r\n").await?;
+
+        await_ok(connection).await
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl EventSink for NatsSink {
+    fn name(&self) -> &'static str {
+        "nats"
+    }
+
+    #[tracing::instrument(skip(self, event), fields(event_id = %event.event_id))]
+    async fn publish(&self, event: &OutboxEvent) -> Result<(), PublishError> {
+        let mut connection = self.connection.lock().await;
+
+        let result = actix_rt::time::timeout(TIMEOUT, async {
+            let mut current = match connection.take() {
+                Some(current) => current,
+                None => self.connect().await?,
+            };
+            self.send(&mut current, event).await?;
+
+            Ok(current)
+        })
+        .await
+        .unwrap_or_else(|_| Err(PublishError::Unreachable("The server took too long to respond".to_owned())));
+
+        // A connection that failed is dropped, and a new one is made for the next event.
+        match result {
+            Ok(current) => {
+                *connection = Some(current);
+                Ok(())
+            },
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Write bytes to the server.
+async fn write(connection: &mut BufReader<TcpStream>, bytes: &[u8]) -> Result<(), PublishError> {
+    connection
+        .get_mut()
+        .write_all(bytes)
+        .await
+        .map_err(|e| PublishError::Unreachable(e.to_string()))
+}
+
+/// Read a single line from the server, without the line ending.
+async fn read_line(connection: &mut BufReader<TcpStream>) -> Result<String, PublishError> {
+    let mut line = String::new();
+    let read = connection
+        .read_line(&mut line)
+        .await
+        .map_err(|e| PublishError::Unreachable(e.to_string()))?;
+
+    if read == 0 {
+        Err(PublishError::Unreachable("The server closed the connection".to_owned()))
+    } else {
+        Ok(line.trim_end().to_owned())
+    }
+}
+
+/// Wait for the server to acknowledge the last command, answering any pings in the meantime.
+async fn await_ok(connection: &mut BufReader<TcpStream>) -> Result<(), PublishError> {
+    loop {
+        let line = read_line(connection).await?;
+
+        if line == "+OK" {
+            return Ok(());
+        } else if line == "PING" {
+            write(connection, b"PONG\r\n").await?;
+        } else if let Some(error) = line.strip_prefix("-ERR") {
+            return Err(PublishError::Rejected(error.trim().trim_matches('\'').to_owned()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex as StdMutex};
+
+    use assert2::{check, let_assert};
+    use chrono::Utc;
+    use serde_json::json;
+    use tokio::{io::AsyncReadExt, net::TcpListener};
+
+    use super::*;
+    use crate::outbox::OutboxEventId;
+
+    /// A message that the stand-in server was sent.
+    #[derive(Debug, Clone)]
+    struct Received {
+        subject: String,
+        headers: String,
+        payload: serde_json::Value,
+    }
+
+    /// Start a local server that speaks just enough of the NATS protocol to accept messages, and
+    /// rejects any on a subject containing `rejected`.
+    async fn stand_in_server() -> (String, Arc<StdMutex<Vec<Received>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = format!("nats://{}", listener.local_addr().unwrap());
+        let received = Arc::new(StdMutex::new(vec![]));
+
+        let recorded = received.clone();
+        actix_rt::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut stream = BufReader::new(stream);
+            stream.get_mut().write_all(b"INFO {\"headers\":true}\r\n").await.unwrap();
+
+            loop {
+                let mut line = String::new();
+                if stream.read_line(&mut line).await.unwrap() == 0 {
+                    break;
+                }
+
+                let parts: Vec<_> = line.split_whitespace().collect();
+                if let ["HPUB", subject, header_len, total_len] = parts.as_slice() {
+                    let header_len: usize = header_len.parse().unwrap();
+                    let mut body = vec![0; total_len.parse::<usize>().unwrap() + 2];
+                    stream.read_exact(&mut body).await.unwrap();
+
+                    recorded.lock().unwrap().push(Received {
+                        subject: (*subject).to_owned(),
+                        headers: String::from_utf8(body[..header_len].to_vec()).unwrap(),
+                        payload: serde_json::from_slice(&body[header_len..body.len() - 2]).unwrap(),
+                    });
+
+                    let reply: &[u8] = if subject.contains("rejected") {
+                        b"-ERR 'Permissions Violation'\r\n"
+                    } else {
+                        b"PING\r\n+OK\r\n"
+                    };
+                    stream.get_mut().write_all(reply).await.unwrap();
+                } else if line.starts_with("CONNECT") {
+                    stream.get_mut().write_all(b"+OK\r\n").await.unwrap();
+                }
+            }
+        });
+
+        (address, received)
+    }
+
+    fn event(event_id: i64, event_type: &str) -> OutboxEvent {
+        OutboxEvent {
+            event_id:       OutboxEventId::from(event_id),
+            event_type:     event_type.to_owned(),
+            aggregate_type: "user".to_owned(),
+            aggregate_id:   "abc".to_owned(),
+            occurred:       Utc::now(),
+            payload:        json!({ "username": "frodo" }),
+        }
+    }
+
+    #[actix_rt::test]
+    async fn publish() {
+        let (address, received) = stand_in_server().await;
+        let sut = NatsSink::new(&address, "worlds");
+
+        check!(sut.publish(&event(1, "user.registered")).await == Ok(()));
+        check!(sut.publish(&event(2, "user.updated")).await == Ok(()));
+
+        let received = received.lock().unwrap().clone();
+        let_assert!([first, second] = received.as_slice());
+        check!(first.subject == "worlds.user.registered");
+        check!(first.headers == "NATS/1.0\r\nNats-Msg-Id: 1\r\n\r\n");
+        check!(first.payload["id"] == json!(1));
+        check!(first.payload["data"] == json!({ "username": "frodo" }));
+        check!(second.subject == "worlds.user.updated");
+    }
+
+    #[actix_rt::test]
+    async fn publish_rejected() {
+        let (address, _) = stand_in_server().await;
+        let sut = NatsSink::new(&address, "worlds");
+
+        let_assert!(Err(e) = sut.publish(&event(1, "user.rejected")).await);
+        check!(e == PublishError::Rejected("Permissions Violation".to_owned()));
+    }
+
+    #[actix_rt::test]
+    async fn publish_unreachable() {
+        let unused = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap();
+        let sut = NatsSink::new(&unused.to_string(), "worlds");
+
+        let_assert!(Err(PublishError::Unreachable(_)) = sut.publish(&event(1, "user.registered")).await);
+    }
+}
@@ -0,0 +1,138 @@
+use std::time::Duration;
+
+use chrono::Utc;
+
+use super::{EventSink, PublishError};
+use crate::{
+    outbox::OutboxEvent,
+    webhooks::{WebhookSecret, EVENT_HEADER, SIGNATURE_HEADER, TIMESTAMP_HEADER},
+};
+
+/// The header that carries the ID of the event, which consumers can use to ignore repeats.
+pub const EVENT_ID_HEADER: &str = "X-Worlds-Event-Id";
+
+/// How long to wait for the endpoint to respond before giving up on publishing an event.
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Sink that sends each event to a single HTTP endpoint, signed in the same way as the payloads
+/// sent to the webhooks of worlds.
+#[derive(Debug)]
+pub struct WebhookSink {
+    url:    String,
+    secret: Option<WebhookSecret>,
+}
+
+impl WebhookSink {
+    /// Create a new webhook sink.
+    ///
+    /// # Parameters
+    /// - `url` - The URL to send events to
+    /// - `secret` - The secret to sign events with, if any
+    pub fn new(url: &str, secret: Option<WebhookSecret>) -> Self {
+        Self {
+            url: url.to_owned(),
+            secret,
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl EventSink for WebhookSink {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    #[tracing::instrument(skip(self, event), fields(event_id = %event.event_id))]
+    async fn publish(&self, event: &OutboxEvent) -> Result<(), PublishError> {
+        let payload = serde_json::to_vec(event).expect("Failed to serialize event");
+        let timestamp = Utc::now().timestamp();
+
+        let client = awc::Client::builder().timeout(TIMEOUT).disable_redirects().finish();
+        let mut request = client
+            .post(&self.url)
+            .content_type("application/json")
+            .insert_header((EVENT_HEADER, event.event_type.as_str()))
+            .insert_header((EVENT_ID_HEADER, event.event_id.to_string()))
+            .insert_header((TIMESTAMP_HEADER, timestamp.to_string()));
+        if let Some(secret) = &self.secret {
+            request = request.insert_header((SIGNATURE_HEADER, format!("sha256={}", secret.sign(timestamp, &payload))));
+        }
+
+        let response = request
+            .send_body(payload)
+            .await
+            .map_err(|e| PublishError::Unreachable(e.to_string()))?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(PublishError::Rejected(format!("The endpoint responded with {}", response.status())))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use serde_json::json;
+
+    use super::*;
+    use crate::{outbox::OutboxEventId, webhooks::testing::StandInReceiver};
+
+    fn event() -> OutboxEvent {
+        OutboxEvent {
+            event_id:       OutboxEventId::from(7),
+            event_type:     "user.registered".to_owned(),
+            aggregate_type: "user".to_owned(),
+            aggregate_id:   "abc".to_owned(),
+            occurred:       Utc::now(),
+            payload:        json!({ "username": "frodo" }),
+        }
+    }
+
+    #[actix_rt::test]
+    async fn publish_signed() {
+        let receiver = StandInReceiver::start(&[]);
+        let secret = WebhookSecret::from("whsec_abc");
+        let sut = WebhookSink::new(&receiver.url(), Some(secret.clone()));
+
+        check!(sut.publish(&event()).await == Ok(()));
+
+        let received = receiver.received();
+        let_assert!([request] = received.as_slice());
+        check!(request.header(EVENT_HEADER) == Some("user.registered"));
+        check!(request.header(EVENT_ID_HEADER) == Some("7"));
+        check!(request.verify(&secret));
+
+        let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+        check!(body["id"] == json!(7));
+        check!(body["data"] == json!({ "username": "frodo" }));
+    }
+
+    #[actix_rt::test]
+    async fn publish_unsigned() {
+        let receiver = StandInReceiver::start(&[]);
+        let sut = WebhookSink::new(&receiver.url(), None);
+
+        check!(sut.publish(&event()).await == Ok(()));
+
+        let received = receiver.received();
+        let_assert!([request] = received.as_slice());
+        check!(request.header(SIGNATURE_HEADER).is_none());
+    }
+
+    #[actix_rt::test]
+    async fn publish_rejected() {
+        let receiver = StandInReceiver::start(&[503]);
+        let sut = WebhookSink::new(&receiver.url(), None);
+
+        let_assert!(Err(PublishError::Rejected(_)) = sut.publish(&event()).await);
+    }
+
+    #[actix_rt::test]
+    async fn publish_unreachable() {
+        let sut = WebhookSink::new(&StandInReceiver::unused_url(), None);
+
+        let_assert!(Err(PublishError::Unreachable(_)) = sut.publish(&event()).await);
+    }
+}
@@ -0,0 +1,10 @@
+mod broker;
+pub mod component;
+mod endpoints;
+mod listener;
+mod model;
+mod service;
+
+pub use broker::*;
+pub use model::*;
+pub use service::*;
@@ -0,0 +1,22 @@
+#[cfg(test)]
+mod memory;
+mod postgres;
+
+#[cfg(test)]
+pub use memory::MemoryEventBroker;
+pub use postgres::{PostgresEventBroker, CHANNEL};
+
+use super::WorldEvent;
+
+/// Broker for sharing the events that happen within worlds between every instance of the service.
+#[async_trait::async_trait]
+pub trait EventBroker: Send + Sync {
+    /// Publish an event to every instance of the service, including this one.
+    ///
+    /// Delivery is best effort. Events are only used to keep clients up to date, and a client that
+    /// misses one can always reload what it is showing.
+    ///
+    /// # Parameters
+    /// - `event` - The event to publish
+    async fn publish(&self, event: &WorldEvent);
+}
@@ -0,0 +1,30 @@
+use std::sync::Mutex;
+
+use super::EventBroker;
+use crate::realtime::WorldEvent;
+
+/// Event broker that keeps the events that are published in memory.
+/// Used for testing the layers above the broker without needing a real database.
+#[derive(Default)]
+pub struct MemoryEventBroker {
+    events: Mutex<Vec<WorldEvent>>,
+}
+
+impl MemoryEventBroker {
+    /// Create a new event broker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Take every event that has been published since the last time this was called.
+    pub fn take_published(&self) -> Vec<WorldEvent> {
+        std::mem::take(&mut *self.events.lock().unwrap())
+    }
+}
+
+#[async_trait::async_trait]
+impl EventBroker for MemoryEventBroker {
+    async fn publish(&self, event: &WorldEvent) {
+        self.events.lock().unwrap().push(event.clone());
+    }
+}
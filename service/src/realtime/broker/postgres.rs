@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use super::EventBroker;
+use crate::{database::Database, realtime::WorldEvent};
+
+/// The name of the channel that events are published to. Changes to resources are also published to
+/// this by the database itself.
+pub const CHANNEL: &str = "world_events";
+
+/// Event broker that uses Postgres `NOTIFY` to publish events.
+pub struct PostgresEventBroker {
+    database: Arc<Database>,
+}
+
+impl PostgresEventBroker {
+    /// Create a new event broker.
+    pub fn new(database: Arc<Database>) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait::async_trait]
+impl EventBroker for PostgresEventBroker {
+    #[tracing::instrument(skip(self))]
+    async fn publish(&self, event: &WorldEvent) {
+        let payload = serde_json::to_string(event).expect("Failed to serialize event");
+
+        let conn = self.database.connect().await;
+        if let Err(e) = conn.execute("SELECT pg_notify($1, $2)", &[&CHANNEL, &payload]).await {
+            tracing::warn!(e = ?e, "Failed to publish event");
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::{sync::Arc, time::Duration};
+
+use actix_web::web::{get, resource, ServiceConfig};
+
+use super::{broker::EventBroker, service::RealtimeService};
+use crate::{authorization::AuthorizationService, server::RouteConfigurer, worlds::WorldService};
+
+/// How often to check that every connection is still allowed to receive the events that it is
+/// subscribed to.
+const REVALIDATE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Component for sending the events within worlds to clients as they happen.
+pub struct Component {
+    pub service: Arc<RealtimeService>,
+}
+
+impl Component {
+    /// Create a new real-time component.
+    ///
+    /// # Parameters
+    /// - `broker` - The broker to share events with the other instances of the service through
+    /// - `worlds` - The service to check who is allowed to watch each world with
+    /// - `authorizer` - The service to check that the credentials of connections are still active
+    ///   with
+    pub fn new(broker: Arc<dyn EventBroker>, worlds: Arc<WorldService>, authorizer: Arc<AuthorizationService>) -> Arc<Self> {
+        let service = Arc::new(RealtimeService::new(broker, worlds, authorizer));
+
+        Arc::new(Self { service })
+    }
+
+    /// Start receiving the events that are published by every instance of the service, in the
+    /// background.
+    ///
+    /// # Parameters
+    /// - `database_url` - The URL of the database that the events are published through
+    pub fn listen(&self, database_url: &str) {
+        actix_rt::spawn(super::listener::listen(database_url.to_owned(), self.service.clone()));
+    }
+
+    /// Check that every connection is still allowed to receive the events that it is subscribed to,
+    /// in the background, so that revoked sessions and removed members stop receiving them.
+    pub fn revalidate(&self) {
+        let service = self.service.clone();
+
+        actix_rt::spawn(async move {
+            let mut interval = actix_rt::time::interval(REVALIDATE_INTERVAL);
+            loop {
+                interval.tick().await;
+                service.revalidate().await;
+            }
+        });
+    }
+}
+
+impl RouteConfigurer for Component {
+    fn configure_routes(&self, config: &mut ServiceConfig) {
+        config.data(self.service.clone());
+
+        config.service(resource("/realtime").route(get().to(super::endpoints::connect::handle)));
+    }
+}
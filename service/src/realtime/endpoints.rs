@@ -0,0 +1,3 @@
+pub(super) mod connect;
+mod model;
+mod session;
@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use actix_codec::Encoder;
+use actix_http::ws::{handshake, CloseCode, Codec, Message};
+use actix_web::{
+    web::{Data, Payload},
+    HttpRequest, HttpResponse,
+};
+use bytes::BytesMut;
+use futures::{channel::mpsc, future, stream, StreamExt};
+
+use super::session::Session;
+use crate::{
+    authorization::{Authentication, AuthorizationService},
+    http::problem::{Problem, BAD_REQUEST},
+    realtime::{RealtimeService, Viewer},
+    tokens::Scope,
+};
+
+/// How many replies can be waiting to be sent to a single connection. A client that falls this far
+/// behind is disconnected, rather than letting its replies build up in memory.
+const REPLY_BUFFER_SIZE: usize = 16;
+
+/// Open a real-time connection over a WebSocket, to receive the events within worlds as they
+/// happen.
+///
+/// The connection can be authenticated with the same Bearer token as any other request, which must
+/// allow reading worlds. Clients that can't send one when connecting can instead send an
+/// `authenticate` message first.
+///
+/// The connection is closed once the access token that it was authenticated with expires or is
+/// revoked, or if the client falls too far behind in receiving events or the replies to its
+/// messages.
+pub async fn handle(
+    service: Data<Arc<RealtimeService>>,
+    authorizer: Data<Arc<AuthorizationService>>,
+    request: HttpRequest,
+    payload: Payload,
+    authentication: Authentication,
+) -> Result<HttpResponse, Problem> {
+    if authentication.is_authenticated() {
+        authentication.require_scope(Scope::WorldsRead)?;
+    }
+
+    let mut response = handshake(request.head()).map_err(|e| {
+        tracing::warn!(e = ?e, "Invalid WebSocket handshake");
+
+        Problem::from(BAD_REQUEST).with_detail(e.to_string())
+    })?;
+
+    let service = service.get_ref().clone();
+    let viewer = authentication.security_context().and_then(Viewer::new);
+    let (connection_id, events) = service.connect(viewer.clone());
+    let (replies, replies_receiver) = mpsc::channel(REPLY_BUFFER_SIZE);
+
+    let session = Session::new(connection_id, service, authorizer.get_ref().clone(), viewer, replies);
+    actix_rt::spawn(session.run(payload));
+
+    // The events only stop when the service has disconnected the client, so the connection is closed
+    // once they do. This includes when the session gives up on a client that stopped reading its
+    // replies.
+    let events = events
+        .map(|event| {
+            let event = serde_json::to_string(&event).expect("Failed to serialize event");
+
+            Message::Text(event.into())
+        })
+        .chain(stream::once(future::ready(Message::Close(Some(CloseCode::Policy.into())))));
+
+    let mut codec = Codec::new();
+    let body = stream::select(events, replies_receiver)
+        .scan(false, |closed, message| {
+            if *closed {
+                return future::ready(None);
+            }
+            *closed = matches!(message, Message::Close(_));
+
+            future::ready(Some(message))
+        })
+        .map(move |message| {
+            let mut buffer = BytesMut::new();
+            codec.encode(message, &mut buffer).map(|()| buffer.freeze())
+        });
+
+    Ok(response.streaming(body))
+}
@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{realtime::Presence, users::UserId, worlds::WorldId};
+
+/// A message that is sent by a client over a real-time connection.
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ClientMessage {
+    /// Authenticate the connection with an access token, for clients that can't send one when
+    /// connecting.
+    Authenticate { token: String },
+
+    /// Start receiving the events within a world.
+    #[serde(rename_all = "camelCase")]
+    Subscribe { world_id: WorldId },
+
+    /// Stop receiving the events within a world.
+    #[serde(rename_all = "camelCase")]
+    Unsubscribe { world_id: WorldId },
+
+    /// Tell everyone else in a world what the user is viewing in it.
+    #[serde(rename_all = "camelCase")]
+    View { world_id: WorldId, resource: Option<String> },
+}
+
+/// A message that is sent to a client over a real-time connection in reply to one of its own.
+/// The events within worlds are sent as they are, alongside these.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ServerMessage {
+    #[serde(rename_all = "camelCase")]
+    Authenticated { user_id: UserId },
+
+    /// The connection is now subscribed to a world, in which these others are already present.
+    #[serde(rename_all = "camelCase")]
+    Subscribed { world_id: WorldId, presence: Vec<Presence> },
+
+    #[serde(rename_all = "camelCase")]
+    Unsubscribed { world_id: WorldId },
+
+    /// The message from the client couldn't be handled.
+    Error { message: String },
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_subscribe() {
+        let message = json!({"type": "subscribe", "worldId": "7a7d2f6e-3c1b-4b8a-9f0e-2d4c6b8a0e1f"});
+
+        let_assert!(Ok(ClientMessage::Subscribe { world_id }) = serde_json::from_value(message));
+        check!(world_id.to_string() == "7a7d2f6e-3c1b-4b8a-9f0e-2d4c6b8a0e1f");
+    }
+
+    #[test]
+    fn parse_view() {
+        let message = json!({"type": "view", "worldId": "7a7d2f6e-3c1b-4b8a-9f0e-2d4c6b8a0e1f", "resource": "article/gondor"});
+
+        let_assert!(Ok(ClientMessage::View { resource, .. }) = serde_json::from_value(message));
+        check!(resource == Some("article/gondor".to_owned()));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let result: Result<ClientMessage, _> = serde_json::from_value(json!({"type": "subscribe", "worldId": "gondor"}));
+        check!(result.is_err());
+    }
+
+    #[test]
+    fn serialize_reply() {
+        let message = ServerMessage::Error {
+            message: "Not authenticated".to_owned(),
+        };
+
+        check!(serde_json::to_value(&message).unwrap() == json!({"type": "error", "message": "Not authenticated"}));
+    }
+}
@@ -0,0 +1,295 @@
+use std::sync::Arc;
+
+use actix_codec::Decoder;
+use actix_http::ws::{CloseCode, Codec, Frame, Message};
+use actix_web::web::Payload;
+use bytes::BytesMut;
+use futures::{channel::mpsc::Sender, StreamExt};
+
+use super::model::{ClientMessage, ServerMessage};
+use crate::{
+    authorization::{AccessToken, AuthorizationService},
+    realtime::{ConnectionId, PresenceError, RealtimeService, SubscribeError, Viewer},
+    users::UserId,
+};
+
+/// The server side of a single real-time connection, which handles the messages from the client.
+pub struct Session {
+    connection_id: ConnectionId,
+    service:       Arc<RealtimeService>,
+    authorizer:    Arc<AuthorizationService>,
+    viewer:        Option<Viewer>,
+    /// Where to send the messages for the client that aren't events within a world.
+    replies:       Sender<Message>,
+}
+
+impl Session {
+    /// Create the session for a new connection.
+    ///
+    /// # Parameters
+    /// - `connection_id` - The ID of the connection
+    /// - `service` - The service to subscribe to the events within worlds with
+    /// - `authorizer` - The service to authorize the access tokens that the client sends with
+    /// - `viewer` - The user that the connection was opened as, if any
+    /// - `replies` - Where to send the messages for the client
+    pub fn new(
+        connection_id: ConnectionId,
+        service: Arc<RealtimeService>,
+        authorizer: Arc<AuthorizationService>,
+        viewer: Option<Viewer>,
+        replies: Sender<Message>,
+    ) -> Self {
+        Self {
+            connection_id,
+            service,
+            authorizer,
+            viewer,
+            replies,
+        }
+    }
+
+    /// Handle everything that the client sends, until it closes the connection.
+    ///
+    /// # Parameters
+    /// - `payload` - The raw stream of data from the client
+    pub async fn run(mut self, mut payload: Payload) {
+        let mut codec = Codec::new();
+        let mut buffer = BytesMut::new();
+
+        'receive: while let Some(chunk) = payload.next().await {
+            match chunk {
+                Ok(chunk) => buffer.extend_from_slice(&chunk),
+                Err(e) => {
+                    tracing::warn!(e = ?e, "Failed to receive from real-time connection");
+                    break;
+                },
+            }
+
+            loop {
+                match codec.decode(&mut buffer) {
+                    Ok(Some(frame)) => {
+                        if !self.handle_frame(frame).await {
+                            break 'receive;
+                        }
+                    },
+                    Ok(None) => break,
+                    Err(e) => {
+                        tracing::warn!(e = ?e, "Received an invalid frame on real-time connection");
+                        self.send(Message::Close(Some(CloseCode::Protocol.into())));
+                        break 'receive;
+                    },
+                }
+            }
+        }
+
+        self.service.disconnect(&self.connection_id).await;
+    }
+
+    /// Handle a single frame from the client.
+    ///
+    /// # Parameters
+    /// - `frame` - The frame
+    ///
+    /// # Returns
+    /// True if the connection should stay open. False if it has been closed.
+    async fn handle_frame(&mut self, frame: Frame) -> bool {
+        match frame {
+            Frame::Text(text) => {
+                let reply = match serde_json::from_slice(&text) {
+                    Ok(message) => self.handle_message(message).await,
+                    Err(e) => {
+                        tracing::warn!(e = ?e, "Received an invalid message on real-time connection");
+                        Some(error("Invalid message"))
+                    },
+                };
+
+                match reply {
+                    Some(reply) => {
+                        let reply = serde_json::to_string(&reply).expect("Failed to serialize message");
+                        self.send(Message::Text(reply.into()))
+                    },
+                    None => true,
+                }
+            },
+            Frame::Ping(data) => self.send(Message::Pong(data)),
+            Frame::Pong(_) => true,
+            Frame::Close(reason) => {
+                self.send(Message::Close(reason));
+                false
+            },
+            Frame::Binary(_) | Frame::Continuation(_) => {
+                self.send(Message::Close(Some(CloseCode::Unsupported.into())));
+                false
+            },
+        }
+    }
+
+    /// Handle a single message from the client.
+    ///
+    /// # Parameters
+    /// - `message` - The message
+    ///
+    /// # Returns
+    /// The reply to send back to the client, if any.
+    async fn handle_message(&mut self, message: ClientMessage) -> Option<ServerMessage> {
+        match message {
+            ClientMessage::Authenticate { token } => Some(self.authenticate(AccessToken(token)).await),
+            ClientMessage::Subscribe { world_id } => {
+                let user_id = match self.require_viewer() {
+                    Ok(user_id) => user_id,
+                    Err(reply) => return Some(reply),
+                };
+
+                Some(match self.service.subscribe(&self.connection_id, &user_id, &world_id).await {
+                    Ok(presence) => ServerMessage::Subscribed { world_id, presence },
+                    Err(SubscribeError::UnknownWorld) => error("Unknown world"),
+                    Err(SubscribeError::UnknownConnection) => error("Unknown connection"),
+                })
+            },
+            ClientMessage::Unsubscribe { world_id } => {
+                if self.service.unsubscribe(&self.connection_id, &world_id).await {
+                    Some(ServerMessage::Unsubscribed { world_id })
+                } else {
+                    Some(error("Not subscribed to the world"))
+                }
+            },
+            ClientMessage::View { world_id, resource } => {
+                let user_id = match self.require_viewer() {
+                    Ok(user_id) => user_id,
+                    Err(reply) => return Some(reply),
+                };
+
+                match self.service.set_viewing(&self.connection_id, &user_id, &world_id, resource).await {
+                    Ok(()) => None,
+                    Err(PresenceError::NotSubscribed) => Some(error("Not subscribed to the world")),
+                    Err(PresenceError::InvalidResource) => Some(error("Invalid resource")),
+                }
+            },
+        }
+    }
+
+    /// Authenticate the connection with an access token. A connection can be authenticated again to
+    /// extend it with a newer token, but only as the same user.
+    ///
+    /// # Parameters
+    /// - `access_token` - The access token
+    async fn authenticate(&mut self, access_token: AccessToken) -> ServerMessage {
+        let viewer = match self.authorizer.authorize(&access_token).await {
+            Ok(security_context) => Viewer::new(&security_context),
+            Err(e) => {
+                tracing::warn!(e = ?e, "Failed to authorize access token for real-time connection");
+                None
+            },
+        };
+
+        match (viewer, &self.viewer) {
+            (None, _) => error("Invalid access token"),
+            (Some(viewer), Some(current)) if viewer.user_id != current.user_id => error("Already authenticated as another user"),
+            (Some(viewer), _) => {
+                let user_id = viewer.user_id.clone();
+                self.service.authenticate(&self.connection_id, viewer.clone());
+                self.viewer = Some(viewer);
+
+                ServerMessage::Authenticated { user_id }
+            },
+        }
+    }
+
+    /// Check that the connection is authenticated as a user that is allowed to read worlds.
+    ///
+    /// # Returns
+    /// The ID of the user, or the reply to send if the connection isn't allowed to read worlds.
+    fn require_viewer(&self) -> Result<UserId, ServerMessage> {
+        match &self.viewer {
+            None => Err(error("Not authenticated")),
+            Some(viewer) if viewer.has_expired() => Err(error("The access token has expired")),
+            Some(viewer) if !viewer.can_read => Err(error("The access token does not allow reading worlds")),
+            Some(viewer) => Ok(viewer.user_id.clone()),
+        }
+    }
+
+    /// Send a message to the client.
+    ///
+    /// # Parameters
+    /// - `message` - The message
+    ///
+    /// # Returns
+    /// False if the client has fallen too far behind in receiving messages, and the connection
+    /// should be closed.
+    fn send(&mut self, message: Message) -> bool {
+        // This also fails if the client has already gone away, in which case there's nobody to tell.
+        match self.replies.try_send(message) {
+            Err(e) if e.is_full() => {
+                tracing::warn!(connection_id = ?self.connection_id, "Real-time connection has fallen too far behind");
+                false
+            },
+            _ => true,
+        }
+    }
+}
+
+/// Build the reply for a message that couldn't be handled.
+///
+/// # Parameters
+/// - `message` - Why the message couldn't be handled
+fn error(message: &str) -> ServerMessage {
+    ServerMessage::Error {
+        message: message.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use bytes::Bytes;
+    use futures::channel::mpsc;
+
+    use super::*;
+    use crate::{
+        realtime::broker::MemoryEventBroker,
+        sessions::{MemorySessionRepository, SessionService},
+        tokens::{MemoryTokenRepository, TokenService},
+        worlds::{MemoryWorldRepository, WorldService},
+    };
+
+    fn build_session(replies: Sender<Message>) -> Session {
+        let authorizer = Arc::new(AuthorizationService::new(
+            "secret",
+            Arc::new(SessionService::new(Arc::new(MemorySessionRepository::new()))),
+            Arc::new(TokenService::new(Arc::new(MemoryTokenRepository::new()))),
+        ));
+        let service = Arc::new(RealtimeService::new(
+            Arc::new(MemoryEventBroker::new()),
+            Arc::new(WorldService::new(Arc::new(MemoryWorldRepository::new()))),
+            authorizer.clone(),
+        ));
+        let (connection_id, _) = service.connect(None);
+
+        Session::new(connection_id, service, authorizer, None, replies)
+    }
+
+    #[actix_rt::test]
+    async fn reply_to_ping() {
+        let (replies, mut receiver) = mpsc::channel(4);
+        let mut session = build_session(replies);
+
+        check!(session.handle_frame(Frame::Ping(Bytes::from_static(b"ping"))).await);
+        check!(receiver.next().await == Some(Message::Pong(Bytes::from_static(b"ping"))));
+    }
+
+    #[actix_rt::test]
+    async fn close_slow_connection() {
+        let (replies, _receiver) = mpsc::channel(4);
+        let mut session = build_session(replies);
+
+        let mut open = true;
+        for _ in 0..16 {
+            open = session.handle_frame(Frame::Ping(Bytes::new())).await;
+            if !open {
+                break;
+            }
+        }
+
+        check!(!open);
+    }
+}
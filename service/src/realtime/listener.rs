@@ -0,0 +1,85 @@
+use std::{sync::Arc, time::Duration};
+
+use futures::StreamExt;
+
+use super::{broker::CHANNEL, RealtimeService, WorldEvent};
+
+/// How long to wait before trying again after the connection to the database is lost.
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// Receive the events that are published by every instance of the service, and pass them on to the
+/// connections that this instance holds. This runs forever, reconnecting whenever the connection to
+/// the database is lost.
+///
+/// # Parameters
+/// - `url` - The URL of the database to receive the events from
+/// - `service` - The service to pass the events on to
+pub async fn listen(url: String, service: Arc<RealtimeService>) {
+    loop {
+        match crate::database::listen(&url, CHANNEL).await {
+            Ok(notifications) => {
+                futures::pin_mut!(notifications);
+
+                while let Some(payload) = notifications.next().await {
+                    if let Some(event) = parse_event(&payload) {
+                        service.dispatch(&event);
+                    }
+                }
+
+                tracing::warn!("Stopped receiving events");
+            },
+            Err(e) => {
+                tracing::warn!(e = ?e, "Failed to listen for events");
+            },
+        }
+
+        actix_rt::time::sleep(RECONNECT_DELAY).await;
+    }
+}
+
+/// Parse the payload of a notification into the event that it represents.
+///
+/// # Parameters
+/// - `payload` - The payload, as JSON
+fn parse_event(payload: &str) -> Option<WorldEvent> {
+    serde_json::from_str(payload)
+        .map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to parse event");
+        })
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::realtime::ChangeAction;
+
+    #[test]
+    fn parse_change_from_database() {
+        // As sent by the trigger on the articles table.
+        let payload = r#"{"type" : "change", "worldId" : "7a7d2f6e-3c1b-4b8a-9f0e-2d4c6b8a0e1f", "action" : "updated", "resource" : "article", "resourceId" : "0c9b8a7d-6e5f-4a3b-8c2d-1e0f9a8b7c6d", "version" : "5e4d3c2b-1a09-4f8e-b7d6-c5b4a3928170"}"#;
+
+        let_assert!(Some(WorldEvent::Change(change)) = parse_event(payload));
+        check!(change.action == ChangeAction::Updated);
+        check!(change.resource == "article");
+        check!(change.resource_id == "0c9b8a7d-6e5f-4a3b-8c2d-1e0f9a8b7c6d");
+        check!(change.version.is_some());
+    }
+
+    #[test]
+    fn parse_deletion_from_database() {
+        let payload = r#"{"type" : "change", "worldId" : "7a7d2f6e-3c1b-4b8a-9f0e-2d4c6b8a0e1f", "action" : "deleted", "resource" : "character", "resourceId" : "0c9b8a7d-6e5f-4a3b-8c2d-1e0f9a8b7c6d", "version" : null}"#;
+
+        let_assert!(Some(WorldEvent::Change(change)) = parse_event(payload));
+        check!(change.action == ChangeAction::Deleted);
+        check!(change.version.is_none());
+    }
+
+    #[test]
+    fn parse_invalid() {
+        check!(parse_event(r#"{"type": "unknown"}"#).is_none());
+        check!(parse_event("not json").is_none());
+    }
+}
@@ -0,0 +1,92 @@
+mod change_action;
+mod connection_id;
+
+pub use change_action::*;
+use chrono::{DateTime, Utc};
+pub use connection_id::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    authorization::{Credential, Principal, SecurityContext},
+    tokens::Scope,
+    users::UserId,
+    worlds::WorldId,
+};
+
+/// A change to a resource within a world.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Change {
+    pub world_id:    WorldId,
+    pub action:      ChangeAction,
+    /// The kind of resource that changed, such as `article` or `character`.
+    pub resource:    String,
+    pub resource_id: String,
+    /// The new version of the resource, or `None` if it was deleted.
+    pub version:     Option<Uuid>,
+}
+
+/// What a user that is connected to a world is doing in it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Presence {
+    pub world_id:      WorldId,
+    pub connection_id: ConnectionId,
+    pub user_id:       UserId,
+    /// The resource that the user is viewing, such as `article/<id>`, or `None` if they aren't
+    /// viewing anything in particular.
+    pub viewing:       Option<String>,
+    /// Whether the user is still in the world. This is false once they have left it.
+    pub present:       bool,
+}
+
+/// The user that a connection is authenticated as, and the credential that they authenticated with.
+#[derive(Debug, Clone)]
+pub struct Viewer {
+    pub user_id:    UserId,
+    pub credential: Credential,
+    /// Whether the user is allowed to read the worlds that they are a member of.
+    pub can_read:   bool,
+    pub expires:    DateTime<Utc>,
+}
+
+impl Viewer {
+    /// Determine the user that a security context is for.
+    ///
+    /// # Parameters
+    /// - `security_context` - The security context
+    pub fn new(security_context: &SecurityContext) -> Option<Self> {
+        let Principal::User(user_id) = &security_context.principal;
+
+        Some(Self {
+            user_id:    user_id.parse().ok()?,
+            credential: security_context.credential.clone(),
+            can_read:   security_context.has_scope(Scope::WorldsRead),
+            expires:    security_context.expires,
+        })
+    }
+
+    /// Determine whether the credential that the viewer authenticated with has expired.
+    pub fn has_expired(&self) -> bool {
+        self.expires <= Utc::now()
+    }
+}
+
+/// Something that happened within a world, which is sent to everyone that is watching it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum WorldEvent {
+    Change(Change),
+    Presence(Presence),
+}
+
+impl WorldEvent {
+    /// Get the ID of the world that the event happened in.
+    pub fn world_id(&self) -> &WorldId {
+        match self {
+            Self::Change(change) => &change.world_id,
+            Self::Presence(presence) => &presence.world_id,
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::str::FromStr;
+
+use postgres_types::{accepts, FromSql, Type};
+use serde::{Deserialize, Serialize};
+
+/// The ways in which a resource can change.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeAction {
+    Created,
+    Updated,
+    Deleted,
+}
+
+/// All of the ways in which a resource can change.
+const ALL_ACTIONS: &[ChangeAction] = &[ChangeAction::Created, ChangeAction::Updated, ChangeAction::Deleted];
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ParseChangeActionError {
+    #[error("The change action is not known")]
+    Unknown,
+}
+
+impl ChangeAction {
+    /// The name of the action, as used on the API and in the database.
+    pub fn name(self) -> &'static str {
+        match self {
+            ChangeAction::Created => "created",
+            ChangeAction::Updated => "updated",
+            ChangeAction::Deleted => "deleted",
+        }
+    }
+}
+
+impl FromStr for ChangeAction {
+    type Err = ParseChangeActionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ALL_ACTIONS
+            .iter()
+            .find(|action| action.name() == s)
+            .copied()
+            .ok_or(ParseChangeActionError::Unknown)
+    }
+}
+
+impl<'a> FromSql<'a> for ChangeAction {
+    accepts!(TEXT, VARCHAR);
+
+    fn from_sql(t: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        let name = <&str>::from_sql(t, raw)?;
+
+        Ok(name.parse()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("created", ChangeAction::Created ; "Created")]
+    #[test_case("updated", ChangeAction::Updated ; "Updated")]
+    #[test_case("deleted", ChangeAction::Deleted ; "Deleted")]
+    fn test_parse_success(input: &str, expected: ChangeAction) {
+        let_assert!(Ok(action) = input.parse::<ChangeAction>());
+        check!(action == expected);
+        check!(serde_json::to_value(action).unwrap() == input);
+    }
+
+    #[test_case("" ; "Blank")]
+    #[test_case("moved" ; "Unknown")]
+    #[test_case("Created" ; "Capitalised")]
+    fn test_parse_fail(input: &str) {
+        let_assert!(Err(e) = input.parse::<ChangeAction>());
+        check!(e == ParseChangeActionError::Unknown);
+    }
+}
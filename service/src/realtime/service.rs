@@ -0,0 +1,658 @@
+use std::sync::{Arc, Mutex};
+
+use futures::channel::mpsc::{self, Receiver, Sender};
+
+use super::{broker::EventBroker, ConnectionId, Presence, Viewer, WorldEvent};
+use crate::{
+    authorization::AuthorizationService,
+    users::UserId,
+    worlds::{WorldId, WorldService},
+};
+
+/// The longest reference to a resource that a user can be viewing. This keeps the events that are
+/// published well within the limits of the broker.
+const MAX_RESOURCE_LENGTH: usize = 200;
+
+/// How many events can be waiting to be sent to a single connection. A connection that falls this
+/// far behind is disconnected, rather than letting its events build up in memory.
+const EVENT_BUFFER_SIZE: usize = 64;
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SubscribeError {
+    #[error("Unknown connection")]
+    UnknownConnection,
+
+    #[error("Unknown world")]
+    UnknownWorld,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum PresenceError {
+    #[error("Not subscribed to the world")]
+    NotSubscribed,
+
+    #[error("The reference to the resource being viewed is too long")]
+    InvalidResource,
+}
+
+/// Service layer for sending the events that happen within worlds to the clients that are watching
+/// them, as they happen.
+///
+/// Every instance of the service shares its events with all of the others through the broker, and
+/// each one passes them on to the connections that it holds itself.
+pub struct RealtimeService {
+    broker:     Arc<dyn EventBroker>,
+    worlds:     Arc<WorldService>,
+    authorizer: Arc<AuthorizationService>,
+    hub:        Mutex<Hub>,
+}
+
+/// The connections that are held by this instance, and who is present in every world.
+#[derive(Default)]
+struct Hub {
+    connections: Vec<Subscriber>,
+    /// Who is present in each world, across every instance of the service.
+    presence:    Vec<Presence>,
+}
+
+/// A single connection that is held by this instance.
+struct Subscriber {
+    connection_id: ConnectionId,
+    /// The user that the connection is authenticated as, if it has been.
+    viewer:        Option<Viewer>,
+    /// The worlds that the connection is subscribed to.
+    worlds:        Vec<WorldId>,
+    sender:        Sender<WorldEvent>,
+}
+
+impl RealtimeService {
+    /// Create a new real-time service.
+    ///
+    /// # Parameters
+    /// - `broker` - The broker to share events with the other instances of the service through
+    /// - `worlds` - The service to check who is allowed to watch each world with
+    /// - `authorizer` - The service to check that the credentials of connections are still active
+    ///   with
+    pub fn new(broker: Arc<dyn EventBroker>, worlds: Arc<WorldService>, authorizer: Arc<AuthorizationService>) -> Self {
+        Self {
+            broker,
+            worlds,
+            authorizer,
+            hub: Mutex::new(Hub::default()),
+        }
+    }
+
+    /// Register a new connection, which isn't subscribed to any worlds yet.
+    ///
+    /// The stream of events ends when the connection is disconnected, including when its credential
+    /// expires or is revoked, or when it falls too far behind.
+    ///
+    /// # Parameters
+    /// - `viewer` - The user that the connection is authenticated as, if it is already
+    ///
+    /// # Returns
+    /// The ID of the connection, and the stream of events for the worlds that it subscribes to.
+    pub fn connect(&self, viewer: Option<Viewer>) -> (ConnectionId, Receiver<WorldEvent>) {
+        let connection_id = ConnectionId::default();
+        let (sender, receiver) = mpsc::channel(EVENT_BUFFER_SIZE);
+
+        self.hub.lock().unwrap().connections.push(Subscriber {
+            connection_id: connection_id.clone(),
+            viewer,
+            worlds: vec![],
+            sender,
+        });
+
+        (connection_id, receiver)
+    }
+
+    /// Record the user that a connection has authenticated as, replacing the credential that it
+    /// authenticated with before.
+    ///
+    /// # Parameters
+    /// - `connection_id` - The ID of the connection
+    /// - `viewer` - The user that the connection is authenticated as
+    pub fn authenticate(&self, connection_id: &ConnectionId, viewer: Viewer) {
+        let mut hub = self.hub.lock().unwrap();
+
+        if let Some(subscriber) = hub.connections.iter_mut().find(|c| &c.connection_id == connection_id) {
+            subscriber.viewer = Some(viewer);
+        }
+    }
+
+    /// Watch the events within a single world, without joining it. Unlike a subscription, this
+    /// isn't seen by anyone else in the world, and it ends as soon as the returned stream is
+    /// dropped.
+    ///
+    /// Nothing is checked here, so the caller must already know that the world may be watched.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    ///
+    /// # Returns
+    /// The stream of events within the world.
+    pub fn watch(&self, world_id: &WorldId) -> Receiver<WorldEvent> {
+        let (sender, receiver) = mpsc::channel(EVENT_BUFFER_SIZE);
+
+        self.hub.lock().unwrap().connections.push(Subscriber {
+            connection_id: ConnectionId::default(),
+            viewer: None,
+            worlds: vec![world_id.clone()],
+            sender,
+        });
+
+        receiver
+    }
+
+    /// Unregister a connection, leaving every world that it is subscribed to.
+    ///
+    /// # Parameters
+    /// - `connection_id` - The ID of the connection
+    pub async fn disconnect(&self, connection_id: &ConnectionId) {
+        let left: Vec<Presence> = {
+            let mut hub = self.hub.lock().unwrap();
+            hub.connections.retain(|c| &c.connection_id != connection_id);
+            hub.presence.iter().filter(|p| &p.connection_id == connection_id).cloned().collect()
+        };
+
+        for presence in left {
+            self.publish_presence(Presence {
+                viewing: None,
+                present: false,
+                ..presence
+            })
+            .await;
+        }
+    }
+
+    /// Subscribe a connection to the events within a world. Only members of the world can do this.
+    ///
+    /// # Parameters
+    /// - `connection_id` - The ID of the connection
+    /// - `user_id` - The ID of the user that the connection belongs to
+    /// - `world_id` - The ID of the world
+    ///
+    /// # Returns
+    /// Who else is currently present in the world.
+    pub async fn subscribe(
+        &self,
+        connection_id: &ConnectionId,
+        user_id: &UserId,
+        world_id: &WorldId,
+    ) -> Result<Vec<Presence>, SubscribeError> {
+        if self.worlds.get_role(world_id, user_id).await.is_none() {
+            return Err(SubscribeError::UnknownWorld);
+        }
+
+        let others = {
+            let mut hub = self.hub.lock().unwrap();
+            let subscriber = hub
+                .connections
+                .iter_mut()
+                .find(|c| &c.connection_id == connection_id)
+                .ok_or(SubscribeError::UnknownConnection)?;
+            if !subscriber.worlds.contains(world_id) {
+                subscriber.worlds.push(world_id.clone());
+            }
+
+            hub.presence
+                .iter()
+                .filter(|p| &p.world_id == world_id && &p.connection_id != connection_id)
+                .cloned()
+                .collect()
+        };
+
+        self.publish_presence(Presence {
+            world_id:      world_id.clone(),
+            connection_id: connection_id.clone(),
+            user_id:       user_id.clone(),
+            viewing:       None,
+            present:       true,
+        })
+        .await;
+
+        Ok(others)
+    }
+
+    /// Unsubscribe a connection from the events within a world, leaving it.
+    ///
+    /// # Parameters
+    /// - `connection_id` - The ID of the connection
+    /// - `world_id` - The ID of the world
+    ///
+    /// # Returns
+    /// True if the connection was unsubscribed. False if it wasn't subscribed to the world.
+    pub async fn unsubscribe(&self, connection_id: &ConnectionId, world_id: &WorldId) -> bool {
+        let left = {
+            let mut hub = self.hub.lock().unwrap();
+            let Some(subscriber) = hub.connections.iter_mut().find(|c| &c.connection_id == connection_id) else {
+                return false;
+            };
+            if !subscriber.worlds.contains(world_id) {
+                return false;
+            }
+            subscriber.worlds.retain(|w| w != world_id);
+
+            hub.presence
+                .iter()
+                .find(|p| &p.connection_id == connection_id && &p.world_id == world_id)
+                .cloned()
+        };
+
+        if let Some(presence) = left {
+            self.publish_presence(Presence {
+                viewing: None,
+                present: false,
+                ..presence
+            })
+            .await;
+        }
+
+        true
+    }
+
+    /// Record which resource a connection is viewing within a world that it is subscribed to.
+    ///
+    /// # Parameters
+    /// - `connection_id` - The ID of the connection
+    /// - `user_id` - The ID of the user that the connection belongs to
+    /// - `world_id` - The ID of the world
+    /// - `viewing` - The resource that is being viewed, or `None` if nothing in particular is
+    pub async fn set_viewing(
+        &self,
+        connection_id: &ConnectionId,
+        user_id: &UserId,
+        world_id: &WorldId,
+        viewing: Option<String>,
+    ) -> Result<(), PresenceError> {
+        if viewing.as_ref().is_some_and(|v| v.len() > MAX_RESOURCE_LENGTH) {
+            return Err(PresenceError::InvalidResource);
+        }
+
+        let subscribed = self
+            .hub
+            .lock()
+            .unwrap()
+            .connections
+            .iter()
+            .any(|c| &c.connection_id == connection_id && c.worlds.contains(world_id));
+        if !subscribed {
+            return Err(PresenceError::NotSubscribed);
+        }
+
+        self.publish_presence(Presence {
+            world_id: world_id.clone(),
+            connection_id: connection_id.clone(),
+            user_id: user_id.clone(),
+            viewing,
+            present: true,
+        })
+        .await;
+
+        Ok(())
+    }
+
+    /// Pass on an event that was published by any instance of the service to the connections that
+    /// are subscribed to its world.
+    ///
+    /// # Parameters
+    /// - `event` - The event
+    pub fn dispatch(&self, event: &WorldEvent) {
+        let mut hub = self.hub.lock().unwrap();
+
+        if let WorldEvent::Presence(presence) = event {
+            hub.presence
+                .retain(|p| !(p.connection_id == presence.connection_id && p.world_id == presence.world_id));
+            if presence.present {
+                hub.presence.push(presence.clone());
+            }
+        }
+
+        // Connections whose receiving end has gone away are dropped here, in case they were never
+        // disconnected cleanly. So are the ones whose credential has expired, and the ones that have
+        // fallen too far behind, which ends their stream of events.
+        hub.connections.retain_mut(|c| {
+            if !c.worlds.contains(event.world_id()) {
+                return true;
+            }
+            if c.viewer.as_ref().is_some_and(Viewer::has_expired) {
+                tracing::info!(connection_id = ?c.connection_id, "Credential of real-time connection has expired");
+                return false;
+            }
+
+            match c.sender.try_send(event.clone()) {
+                Ok(()) => true,
+                Err(e) => {
+                    if e.is_full() {
+                        tracing::warn!(connection_id = ?c.connection_id, "Real-time connection has fallen too far behind");
+                    }
+                    false
+                },
+            }
+        });
+    }
+
+    /// Check that every connection is still allowed to receive the events that it is subscribed to.
+    /// Connections whose credential has been revoked are disconnected, and connections are
+    /// unsubscribed from the worlds that their user is no longer a member of.
+    pub async fn revalidate(&self) {
+        let connections: Vec<(ConnectionId, Viewer, Vec<WorldId>)> = self
+            .hub
+            .lock()
+            .unwrap()
+            .connections
+            .iter()
+            .filter_map(|c| c.viewer.clone().map(|viewer| (c.connection_id.clone(), viewer, c.worlds.clone())))
+            .collect();
+
+        for (connection_id, viewer, worlds) in connections {
+            if viewer.has_expired() || !self.authorizer.is_active(&viewer.user_id, &viewer.credential).await {
+                tracing::info!(connection_id = ?connection_id, "Credential of real-time connection is no longer active");
+                self.disconnect(&connection_id).await;
+                continue;
+            }
+
+            for world_id in worlds {
+                if self.worlds.get_role(&world_id, &viewer.user_id).await.is_none() {
+                    tracing::info!(connection_id = ?connection_id, world_id = ?world_id, "User is no longer a member of the world");
+                    self.unsubscribe(&connection_id, &world_id).await;
+                }
+            }
+        }
+    }
+
+    /// Publish a change to the presence of a connection within a world.
+    async fn publish_presence(&self, presence: Presence) {
+        self.broker.publish(&WorldEvent::Presence(presence)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use chrono::{Duration, Utc};
+    use futures::StreamExt;
+
+    use super::*;
+    use crate::{
+        authorization::Credential,
+        realtime::{broker::MemoryEventBroker, Change, ChangeAction},
+        sessions::{DeviceDetails, MemorySessionRepository, SessionService},
+        tokens::{MemoryTokenRepository, TokenService},
+        worlds::{Language, Membership, MemoryWorldRepository, Role, WorldData, WorldRepository},
+    };
+
+    struct Fixture {
+        sut:         RealtimeService,
+        broker:      Arc<MemoryEventBroker>,
+        sessions:    Arc<SessionService>,
+        worlds:      Arc<MemoryWorldRepository>,
+        world_id:    WorldId,
+        owner:       UserId,
+        /// The owner of the world, signed in with a session.
+        owner_login: Viewer,
+    }
+
+    impl Fixture {
+        /// Deliver everything that has been published back to the service, as the broker would.
+        fn deliver(&self) {
+            for event in self.broker.take_published() {
+                self.sut.dispatch(&event);
+            }
+        }
+    }
+
+    async fn build_fixture() -> Fixture {
+        let broker = Arc::new(MemoryEventBroker::new());
+        let repository = Arc::new(MemoryWorldRepository::new());
+        let worlds = Arc::new(WorldService::new(repository.clone()));
+        let sessions = Arc::new(SessionService::new(Arc::new(MemorySessionRepository::new())));
+        let authorizer = Arc::new(AuthorizationService::new(
+            "secret",
+            sessions.clone(),
+            Arc::new(TokenService::new(Arc::new(MemoryTokenRepository::new()))),
+        ));
+        let owner = UserId::default();
+        let session = sessions.start_session(&owner, DeviceDetails::default()).await.unwrap();
+        let world = worlds
+            .create_world(
+                &owner,
+                WorldData {
+                    name:        "Middle Earth".to_owned(),
+                    description: None,
+                    language:    Language::English,
+                },
+            )
+            .await
+            .unwrap();
+
+        Fixture {
+            sut: RealtimeService::new(broker.clone(), worlds, authorizer),
+            broker,
+            sessions,
+            worlds: repository,
+            world_id: world.identity.id,
+            owner_login: Viewer {
+                user_id:    owner.clone(),
+                credential: Credential::Session(session.identity.id),
+                can_read:   true,
+                expires:    Utc::now() + Duration::hours(1),
+            },
+            owner,
+        }
+    }
+
+    fn change(world_id: &WorldId) -> WorldEvent {
+        WorldEvent::Change(Change {
+            world_id:    world_id.clone(),
+            action:      ChangeAction::Updated,
+            resource:    "article".to_owned(),
+            resource_id: "f2c1b3a4-5d6e-4f70-8192-a3b4c5d6e7f8".to_owned(),
+            version:     Some(uuid::Uuid::new_v4()),
+        })
+    }
+
+    #[actix_rt::test]
+    async fn receive_changes() {
+        let f = build_fixture().await;
+        let (watching, mut watching_events) = f.sut.connect(None);
+        let (_, mut idle_events) = f.sut.connect(None);
+
+        let_assert!(Ok(others) = f.sut.subscribe(&watching, &f.owner, &f.world_id).await);
+        check!(others.is_empty());
+        f.deliver();
+        let_assert!(Some(WorldEvent::Presence(presence)) = watching_events.next().await);
+        check!(presence.user_id == f.owner);
+
+        let event = change(&f.world_id);
+        f.sut.dispatch(&event);
+        f.sut.dispatch(&change(&WorldId::default()));
+        check!(watching_events.next().await == Some(event));
+
+        f.sut.disconnect(&watching).await;
+        drop(f.sut);
+        check!(watching_events.next().await.is_none());
+        check!(idle_events.next().await.is_none());
+    }
+
+    #[actix_rt::test]
+    async fn watch_world() {
+        let f = build_fixture().await;
+        let mut events = f.sut.watch(&f.world_id);
+
+        let event = change(&f.world_id);
+        f.sut.dispatch(&change(&WorldId::default()));
+        f.sut.dispatch(&event);
+        check!(events.next().await == Some(event));
+        check!(f.broker.take_published().is_empty());
+
+        drop(events);
+        f.sut.dispatch(&change(&f.world_id));
+        check!(f.sut.hub.lock().unwrap().connections.is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn subscribe_to_unknown_world() {
+        let f = build_fixture().await;
+        let (connection_id, _events) = f.sut.connect(None);
+
+        let_assert!(Err(e) = f.sut.subscribe(&connection_id, &UserId::default(), &f.world_id).await);
+        check!(e == SubscribeError::UnknownWorld);
+        let_assert!(Err(e) = f.sut.subscribe(&ConnectionId::default(), &f.owner, &f.world_id).await);
+        check!(e == SubscribeError::UnknownConnection);
+        check!(f.broker.take_published().is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn track_presence() {
+        let f = build_fixture().await;
+        let (first, _first_events) = f.sut.connect(None);
+        let (second, _second_events) = f.sut.connect(None);
+
+        f.sut.subscribe(&first, &f.owner, &f.world_id).await.unwrap();
+        f.deliver();
+        let_assert!(
+            Ok(()) = f
+                .sut
+                .set_viewing(&first, &f.owner, &f.world_id, Some("article/gondor".to_owned()))
+                .await
+        );
+        f.deliver();
+
+        let_assert!(Ok(others) = f.sut.subscribe(&second, &f.owner, &f.world_id).await);
+        check!(others.len() == 1);
+        check!(others[0].connection_id == first);
+        check!(others[0].viewing == Some("article/gondor".to_owned()));
+        f.deliver();
+
+        f.sut.disconnect(&first).await;
+        let published = f.broker.take_published();
+        let_assert!([WorldEvent::Presence(left)] = published.as_slice());
+        check!(left.connection_id == first);
+        check!(!left.present);
+        f.sut.dispatch(&WorldEvent::Presence(left.clone()));
+
+        check!(f.sut.unsubscribe(&second, &f.world_id).await);
+        check!(!f.sut.unsubscribe(&second, &f.world_id).await);
+        f.deliver();
+
+        let (third, _third_events) = f.sut.connect(None);
+        let_assert!(Ok(others) = f.sut.subscribe(&third, &f.owner, &f.world_id).await);
+        check!(others.is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn view_without_subscribing() {
+        let f = build_fixture().await;
+        let (connection_id, _events) = f.sut.connect(None);
+
+        let_assert!(Err(e) = f.sut.set_viewing(&connection_id, &f.owner, &f.world_id, None).await);
+        check!(e == PresenceError::NotSubscribed);
+
+        f.sut.subscribe(&connection_id, &f.owner, &f.world_id).await.unwrap();
+        let_assert!(
+            Err(e) = f
+                .sut
+                .set_viewing(&connection_id, &f.owner, &f.world_id, Some("x".repeat(500)))
+                .await
+        );
+        check!(e == PresenceError::InvalidResource);
+    }
+
+    #[actix_rt::test]
+    async fn disconnect_expired_credential() {
+        let f = build_fixture().await;
+        let (connection_id, mut events) = f.sut.connect(Some(f.owner_login.clone()));
+        f.sut.subscribe(&connection_id, &f.owner, &f.world_id).await.unwrap();
+
+        f.sut.authenticate(
+            &connection_id,
+            Viewer {
+                expires: Utc::now() - Duration::seconds(1),
+                ..f.owner_login.clone()
+            },
+        );
+        f.sut.dispatch(&change(&f.world_id));
+
+        check!(events.next().await.is_none());
+        check!(f.sut.hub.lock().unwrap().connections.is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn disconnect_slow_connection() {
+        let f = build_fixture().await;
+        let (connection_id, mut events) = f.sut.connect(Some(f.owner_login.clone()));
+        f.sut.subscribe(&connection_id, &f.owner, &f.world_id).await.unwrap();
+
+        for _ in 0..=EVENT_BUFFER_SIZE + 1 {
+            f.sut.dispatch(&change(&f.world_id));
+        }
+        check!(f.sut.hub.lock().unwrap().connections.is_empty());
+
+        let mut received = 0;
+        while events.next().await.is_some() {
+            received += 1;
+        }
+        check!(received <= EVENT_BUFFER_SIZE + 1);
+    }
+
+    #[actix_rt::test]
+    async fn revalidate_ended_session() {
+        let f = build_fixture().await;
+        let (connection_id, mut events) = f.sut.connect(Some(f.owner_login.clone()));
+        let (anonymous, _anonymous_events) = f.sut.connect(None);
+        f.sut.subscribe(&connection_id, &f.owner, &f.world_id).await.unwrap();
+        f.deliver();
+        let_assert!(Some(WorldEvent::Presence(_)) = events.next().await);
+
+        f.sut.revalidate().await;
+        check!(f.sut.hub.lock().unwrap().connections.len() == 2);
+
+        f.sessions.end_all_sessions(&f.owner).await;
+        f.sut.revalidate().await;
+
+        check!(events.next().await.is_none());
+        let connections: Vec<ConnectionId> = f
+            .sut
+            .hub
+            .lock()
+            .unwrap()
+            .connections
+            .iter()
+            .map(|c| c.connection_id.clone())
+            .collect();
+        check!(connections == vec![anonymous]);
+    }
+
+    #[actix_rt::test]
+    async fn revalidate_removed_member() {
+        let f = build_fixture().await;
+        let member = UserId::default();
+        f.worlds
+            .save_member(&Membership {
+                world_id: f.world_id.clone(),
+                user_id:  member.clone(),
+                role:     Role::Viewer,
+                joined:   Utc::now(),
+            })
+            .await
+            .unwrap();
+        let session = f.sessions.start_session(&member, DeviceDetails::default()).await.unwrap();
+        let (connection_id, _events) = f.sut.connect(Some(Viewer {
+            user_id: member.clone(),
+            credential: Credential::Session(session.identity.id),
+            ..f.owner_login.clone()
+        }));
+        f.sut.subscribe(&connection_id, &member, &f.world_id).await.unwrap();
+        f.deliver();
+
+        f.worlds.remove_member(&f.world_id, &member).await;
+        f.sut.revalidate().await;
+
+        let published = f.broker.take_published();
+        let_assert!([WorldEvent::Presence(left)] = published.as_slice());
+        check!(left.user_id == member);
+        check!(!left.present);
+        check!(f.sut.hub.lock().unwrap().connections[0].worlds.is_empty());
+    }
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use actix_web::web::{delete, get, post, resource, ServiceConfig};
+
+use super::{repository::RelationshipRepository, service::RelationshipService};
+use crate::{entities::EntityService, server::RouteConfigurer};
+
+/// Component for working with the relationships between entities.
+pub struct Component {
+    pub service: Arc<RelationshipService>,
+}
+
+impl Component {
+    /// Create a new relationships component.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store relationships in
+    /// - `entities` - The service to load the entities that are related from
+    pub fn new(repository: Arc<dyn RelationshipRepository>, entities: Arc<EntityService>) -> Arc<Self> {
+        let service = Arc::new(RelationshipService::new(repository, entities));
+
+        Arc::new(Self { service })
+    }
+}
+
+impl RouteConfigurer for Component {
+    fn configure_routes(&self, config: &mut ServiceConfig) {
+        config.data(self.service.clone());
+
+        config.service(resource("/worlds/{id}/relationships").route(post().to(super::endpoints::create_relationship::handle)));
+        config.service(
+            resource("/worlds/{id}/relationships/{relationship}")
+                .route(get().to(super::endpoints::get_relationship::handle))
+                .route(delete().to(super::endpoints::delete_relationship::handle)),
+        );
+        config.service(resource("/worlds/{id}/graph").route(get().to(super::endpoints::get_graph::handle)));
+        config.service(resource("/worlds/{id}/graph/{entity}/neighbours").route(get().to(super::endpoints::get_neighbours::handle)));
+        config.service(resource("/worlds/{id}/graph/{entity}/family").route(get().to(super::endpoints::get_family_tree::handle)));
+        config.service(resource("/worlds/{id}/graph/{entity}/contents").route(get().to(super::endpoints::get_contents::handle)));
+        config.service(resource("/worlds/{id}/graph/{entity}/path/{target}").route(get().to(super::endpoints::find_path::handle)));
+    }
+}
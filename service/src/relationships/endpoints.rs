@@ -0,0 +1,40 @@
+pub(super) mod create_relationship;
+pub(super) mod delete_relationship;
+pub(super) mod find_path;
+pub(super) mod get_contents;
+pub(super) mod get_family_tree;
+pub(super) mod get_graph;
+pub(super) mod get_neighbours;
+pub(super) mod get_relationship;
+mod graph;
+mod model;
+mod problems;
+
+use crate::{
+    entities::EntityId,
+    http::problem::{Problem, NOT_FOUND},
+    worlds::WorldId,
+};
+
+/// Parse the world and entity IDs from the URL.
+///
+/// # Parameters
+/// - `world_id` - The ID of the world
+/// - `entity_id` - The ID of the entity
+///
+/// # Returns
+/// The parsed IDs, or a Not Found problem if either of them isn't valid.
+fn parse_entity_path(world_id: &str, entity_id: &str) -> Result<(WorldId, EntityId), Problem> {
+    let world_id: WorldId = world_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, world_id = ?world_id, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+    let entity_id: EntityId = entity_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, entity_id = ?entity_id, "Failed to parse Entity ID");
+
+        NOT_FOUND
+    })?;
+
+    Ok((world_id, entity_id))
+}
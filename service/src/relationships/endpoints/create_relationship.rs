@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use actix_http::http::StatusCode;
+use actix_web::web::{Data, Path};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{
+    model::{RelationshipModel, RelationshipResponse},
+    problems::{unknown_entity, DUPLICATE_RELATIONSHIP, INVALID_RELATIONSHIP},
+};
+use crate::{
+    authorization::Authentication,
+    entities::EntityId,
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+        response::SimpleRespondable,
+        valid::{Valid, Validatable},
+    },
+    relationships::{CreateRelationshipError, NewRelationship, RelationshipKind, RelationshipService},
+    tokens::Scope,
+    worlds::{Role, WorldId, WorldService},
+};
+
+/// Create a new relationship between two entities within a world.
+pub async fn handle(
+    service: Data<Arc<RelationshipService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<String>,
+    request: Valid<CreateRelationshipRequest>,
+    authentication: Authentication,
+) -> Result<RelationshipResponse, Problem> {
+    let world_id: WorldId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let request = request.unwrap();
+
+    let relationship = service
+        .create_relationship(
+            &world_id,
+            NewRelationship {
+                source_id: request.source_id,
+                target_id: request.target_id,
+                kind:      request.kind,
+                starts:    request.starts,
+                ends:      request.ends,
+            },
+        )
+        .await
+        .map_err(|e| match e {
+            CreateRelationshipError::UnknownEntity(entity_id) => unknown_entity(&entity_id),
+            CreateRelationshipError::InvalidRelationship => INVALID_RELATIONSHIP.into(),
+            CreateRelationshipError::DuplicateRelationship => DUPLICATE_RELATIONSHIP.into(),
+            CreateRelationshipError::UnknownError => INTERNAL_SERVER_ERROR.into(),
+        })?;
+
+    Ok(SimpleRespondable::<RelationshipModel>::from(relationship)
+        .with_status_code(StatusCode::CREATED)
+        .into())
+}
+
+/// The incoming request to create a relationship.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRelationshipRequest {
+    pub source_id: EntityId,
+    pub target_id: EntityId,
+    pub kind:      RelationshipKind,
+    pub starts:    Option<String>,
+    pub ends:      Option<String>,
+}
+
+impl Validatable for CreateRelationshipRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "sourceId": EntityId::schema(),
+                "targetId": EntityId::schema(),
+                "kind": RelationshipKind::schema(),
+                "starts": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 100
+                },
+                "ends": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 100
+                }
+            },
+            "required": [
+                "sourceId",
+                "targetId",
+                "kind"
+            ]
+        })
+    }
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use actix_web::{
+    web::{Data, Path, Query},
+    HttpResponse,
+};
+
+use super::{
+    graph::{respond, GraphQuery},
+    parse_entity_path,
+};
+use crate::{
+    authorization::Authentication,
+    entities::EntityId,
+    http::problem::{Problem, NOT_FOUND},
+    relationships::RelationshipService,
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Find the shortest chain of relationships between two entities.
+pub async fn handle(
+    service: Data<Arc<RelationshipService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String, String)>,
+    query: Query<GraphQuery>,
+    authentication: Authentication,
+) -> Result<HttpResponse, Problem> {
+    let (world_id, entity_id, target_id) = path.into_inner();
+    let (world_id, entity_id) = parse_entity_path(&world_id, &entity_id)?;
+    let target_id: EntityId = target_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, target_id = ?target_id, "Failed to parse Entity ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let graph = service.find_path(&world_id, &entity_id, &target_id).await.ok_or(NOT_FOUND)?;
+
+    Ok(respond(graph, query.format))
+}
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use actix_web::{
+    web::{Data, Path, Query},
+    HttpResponse,
+};
+
+use super::{
+    graph::{respond, GraphQuery},
+    parse_entity_path,
+};
+use crate::{
+    authorization::Authentication,
+    http::problem::{Problem, NOT_FOUND},
+    relationships::RelationshipService,
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Get the graph of everything that is located within a region.
+pub async fn handle(
+    service: Data<Arc<RelationshipService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    query: Query<GraphQuery>,
+    authentication: Authentication,
+) -> Result<HttpResponse, Problem> {
+    let (world_id, entity_id) = path.into_inner();
+    let (world_id, entity_id) = parse_entity_path(&world_id, &entity_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let graph = service.get_contents(&world_id, &entity_id).await.ok_or(NOT_FOUND)?;
+
+    Ok(respond(graph, query.format))
+}
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use actix_web::{
+    web::{Data, Path, Query},
+    HttpResponse,
+};
+
+use super::graph::{respond, GraphQuery};
+use crate::{
+    authorization::Authentication,
+    http::problem::{Problem, NOT_FOUND},
+    relationships::RelationshipService,
+    tokens::Scope,
+    worlds::{Role, WorldId, WorldService},
+};
+
+/// Export the graph of every relationship within a world.
+pub async fn handle(
+    service: Data<Arc<RelationshipService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<String>,
+    query: Query<GraphQuery>,
+    authentication: Authentication,
+) -> Result<HttpResponse, Problem> {
+    let world_id: WorldId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let graph = service.get_graph(&world_id).await;
+
+    Ok(respond(graph, query.format))
+}
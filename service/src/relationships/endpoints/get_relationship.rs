@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::model::RelationshipResponse;
+use crate::{
+    authorization::Authentication,
+    http::problem::{Problem, NOT_FOUND},
+    relationships::{RelationshipId, RelationshipService},
+    tokens::Scope,
+    worlds::{Role, WorldId, WorldService},
+};
+
+pub async fn handle(
+    service: Data<Arc<RelationshipService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<RelationshipResponse, Problem> {
+    let (world_id, relationship_id) = path.into_inner();
+
+    let world_id: WorldId = world_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, world_id = ?world_id, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+    let relationship_id: RelationshipId = relationship_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, relationship_id = ?relationship_id, "Failed to parse Relationship ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let relationship = service.get_relationship(&world_id, &relationship_id).await.ok_or(NOT_FOUND)?;
+
+    Ok(relationship.into())
+}
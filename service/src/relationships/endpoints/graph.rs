@@ -0,0 +1,276 @@
+//! Rendering of relationship graphs, either as a JSON Graph document or as Graphviz DOT.
+
+use std::fmt::{self, Write};
+
+use actix_web::HttpResponse;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    entities::EntityId,
+    relationships::{Graph, RelationshipId, RelationshipKind},
+};
+
+/// The formats that a graph can be exported in.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GraphFormat {
+    /// A JSON Graph Format document.
+    Json,
+    /// A Graphviz DOT document.
+    Dot,
+}
+
+/// Query parameters for the endpoints that return graphs.
+#[derive(Deserialize)]
+pub struct GraphQuery {
+    /// The format to return the graph in, which is JSON if not specified.
+    pub format: Option<GraphFormat>,
+}
+
+/// Representation of a JSON Graph Format document.
+#[derive(Serialize)]
+pub struct GraphDocumentModel {
+    pub graph: GraphModel,
+}
+
+/// Representation of a graph within a JSON Graph Format document.
+#[derive(Serialize)]
+pub struct GraphModel {
+    pub directed: bool,
+    pub nodes:    Vec<NodeModel>,
+    pub edges:    Vec<EdgeModel>,
+}
+
+/// Representation of an entity within a graph.
+#[derive(Serialize)]
+pub struct NodeModel {
+    pub id:       EntityId,
+    pub label:    String,
+    pub metadata: NodeMetadataModel,
+}
+
+#[derive(Serialize)]
+pub struct NodeMetadataModel {
+    pub kind: String,
+}
+
+/// Representation of a relationship within a graph.
+#[derive(Serialize)]
+pub struct EdgeModel {
+    pub id:       RelationshipId,
+    pub source:   EntityId,
+    pub target:   EntityId,
+    pub relation: RelationshipKind,
+    pub label:    &'static str,
+    pub metadata: EdgeMetadataModel,
+}
+
+#[derive(Serialize)]
+pub struct EdgeMetadataModel {
+    pub starts: Option<String>,
+    pub ends:   Option<String>,
+}
+
+impl From<Graph> for GraphDocumentModel {
+    fn from(graph: Graph) -> Self {
+        let nodes = graph
+            .nodes
+            .into_iter()
+            .map(|node| NodeModel {
+                id:       node.identity.id,
+                label:    node.data.name,
+                metadata: NodeMetadataModel { kind: node.data.kind },
+            })
+            .collect();
+        let edges = graph
+            .edges
+            .into_iter()
+            .map(|edge| EdgeModel {
+                id:       edge.identity.id,
+                source:   edge.data.source_id,
+                target:   edge.data.target_id,
+                relation: edge.data.kind,
+                label:    edge.data.kind.label(),
+                metadata: EdgeMetadataModel {
+                    starts: edge.data.starts,
+                    ends:   edge.data.ends,
+                },
+            })
+            .collect();
+
+        Self {
+            graph: GraphModel {
+                directed: true,
+                nodes,
+                edges,
+            },
+        }
+    }
+}
+
+/// Quote a string for use as an ID or attribute value in a DOT document.
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n"))
+}
+
+/// Render a graph as a Graphviz DOT document.
+///
+/// # Parameters
+/// - `graph` - The graph to render
+///
+/// # Returns
+/// The DOT document.
+pub fn render_dot(graph: &Graph) -> String {
+    let mut result = String::new();
+    write_dot(&mut result, graph).expect("Writing to a String can't fail");
+
+    result
+}
+
+/// Write a graph as a Graphviz DOT document.
+///
+/// # Parameters
+/// - `out` - The writer to write the document to
+/// - `graph` - The graph to write
+fn write_dot(out: &mut impl Write, graph: &Graph) -> fmt::Result {
+    writeln!(out, "digraph world {{")?;
+
+    for node in &graph.nodes {
+        writeln!(
+            out,
+            "  {} [label={}, kind={}];",
+            quote(&node.identity.id.to_string()),
+            quote(&node.data.name),
+            quote(&node.data.kind)
+        )?;
+    }
+
+    for edge in &graph.edges {
+        let kind = edge.data.kind.label();
+        let label = match (&edge.data.starts, &edge.data.ends) {
+            (Some(starts), Some(ends)) => format!("{} ({} - {})", kind, starts, ends),
+            (Some(starts), None) => format!("{} (from {})", kind, starts),
+            (None, Some(ends)) => format!("{} (until {})", kind, ends),
+            (None, None) => kind.to_owned(),
+        };
+
+        writeln!(
+            out,
+            "  {} -> {} [label={}, relation={}];",
+            quote(&edge.data.source_id.to_string()),
+            quote(&edge.data.target_id.to_string()),
+            quote(&label),
+            quote(edge.data.kind.as_str())
+        )?;
+    }
+
+    writeln!(out, "}}")
+}
+
+/// Build the HTTP response for a graph in the requested format.
+///
+/// # Parameters
+/// - `graph` - The graph to return
+/// - `format` - The format to return it in, if not JSON
+pub fn respond(graph: Graph, format: Option<GraphFormat>) -> HttpResponse {
+    match format.unwrap_or(GraphFormat::Json) {
+        GraphFormat::Json => HttpResponse::Ok().json(GraphDocumentModel::from(graph)),
+        GraphFormat::Dot => HttpResponse::Ok()
+            .content_type("text/vnd.graphviz; charset=utf-8")
+            .body(render_dot(&graph)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use insta::assert_json_snapshot;
+
+    use super::*;
+    use crate::{
+        entities::EntityRecord,
+        model::{Identity, Resource},
+        relationships::RelationshipData,
+        worlds::WorldId,
+    };
+
+    fn build_graph() -> Graph {
+        let world_id = WorldId::default();
+        let node = |id: &str, name: &str, kind: &str| Resource {
+            identity: Identity {
+                id: id.parse().unwrap(),
+                ..Identity::default()
+            },
+            data:     EntityRecord {
+                world_id:   world_id.clone(),
+                kind:       kind.to_owned(),
+                name:       name.to_owned(),
+                summary:    None,
+                attributes: serde_json::Value::Null,
+                custom:     serde_json::Value::Null,
+            },
+        };
+        let edge = |id: &str, source: &str, target: &str, kind: RelationshipKind, starts: Option<&str>| Resource {
+            identity: Identity {
+                id: id.parse().unwrap(),
+                ..Identity::default()
+            },
+            data:     RelationshipData {
+                world_id: world_id.clone(),
+                source_id: source.parse().unwrap(),
+                target_id: target.parse().unwrap(),
+                kind,
+                starts: starts.map(ToOwned::to_owned),
+                ends: None,
+            },
+        };
+
+        Graph {
+            nodes: vec![
+                node("e7f0ab6c-3d74-4c52-93c5-fa3c0f2c8a57", "Aragorn", "character"),
+                node("1c4a5ea2-7a4f-4d31-9a7e-7c7b7be3d0c0", "Gondor", "location"),
+                node("7b0f1b4e-4d55-4b10-8a0c-2e6d2f0e4b1d", "The \"Steward\"", "character"),
+            ],
+            edges: vec![
+                edge(
+                    "ad6d1a4e-28ae-4b90-9a1b-5d7c9f7f7f10",
+                    "e7f0ab6c-3d74-4c52-93c5-fa3c0f2c8a57",
+                    "1c4a5ea2-7a4f-4d31-9a7e-7c7b7be3d0c0",
+                    RelationshipKind::LocatedIn,
+                    Some("TA 3019"),
+                ),
+                edge(
+                    "0f1d6c8e-9d3a-4a1e-8d4a-3c2e1b0a9f8e",
+                    "7b0f1b4e-4d55-4b10-8a0c-2e6d2f0e4b1d",
+                    "e7f0ab6c-3d74-4c52-93c5-fa3c0f2c8a57",
+                    RelationshipKind::EnemyOf,
+                    None,
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_render_dot() {
+        let dot = render_dot(&build_graph());
+
+        check!(
+            dot == "digraph world {\n  \
+                    \"e7f0ab6c-3d74-4c52-93c5-fa3c0f2c8a57\" [label=\"Aragorn\", kind=\"character\"];\n  \
+                    \"1c4a5ea2-7a4f-4d31-9a7e-7c7b7be3d0c0\" [label=\"Gondor\", kind=\"location\"];\n  \
+                    \"7b0f1b4e-4d55-4b10-8a0c-2e6d2f0e4b1d\" [label=\"The \\\"Steward\\\"\", kind=\"character\"];\n  \
+                    \"e7f0ab6c-3d74-4c52-93c5-fa3c0f2c8a57\" -> \"1c4a5ea2-7a4f-4d31-9a7e-7c7b7be3d0c0\" \
+                    [label=\"located in (from TA 3019)\", relation=\"located_in\"];\n  \
+                    \"7b0f1b4e-4d55-4b10-8a0c-2e6d2f0e4b1d\" -> \"e7f0ab6c-3d74-4c52-93c5-fa3c0f2c8a57\" \
+                    [label=\"enemy of\", relation=\"enemy_of\"];\n\
+                    }\n"
+        );
+    }
+
+    #[test]
+    fn test_render_json() {
+        let json = serde_json::to_value(GraphDocumentModel::from(build_graph())).unwrap();
+
+        assert_json_snapshot!(json);
+    }
+}
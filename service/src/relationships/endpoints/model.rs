@@ -0,0 +1,49 @@
+use actix_web::http::header::CacheDirective;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::{
+    entities::EntityId,
+    http::{
+        model::ResourceResponse,
+        response::{Response, SimpleRespondable},
+    },
+    relationships::{RelationshipId, RelationshipKind, RelationshipResource},
+};
+
+/// Representation of a relationship on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelationshipModel {
+    pub relationship_id: RelationshipId,
+    pub source_id:       EntityId,
+    pub target_id:       EntityId,
+    pub kind:            RelationshipKind,
+    pub starts:          Option<String>,
+    pub ends:            Option<String>,
+    pub created:         DateTime<Utc>,
+    pub updated:         DateTime<Utc>,
+}
+
+impl From<RelationshipResource> for RelationshipModel {
+    fn from(relationship: RelationshipResource) -> Self {
+        Self {
+            relationship_id: relationship.identity.id,
+            source_id:       relationship.data.source_id,
+            target_id:       relationship.data.target_id,
+            kind:            relationship.data.kind,
+            starts:          relationship.data.starts,
+            ends:            relationship.data.ends,
+            created:         relationship.identity.created,
+            updated:         relationship.identity.updated,
+        }
+    }
+}
+
+impl ResourceResponse for RelationshipResource {
+    fn cache_control(&self) -> Option<Vec<CacheDirective>> {
+        Some(vec![CacheDirective::Private, CacheDirective::NoCache])
+    }
+}
+
+pub type RelationshipResponse = Response<SimpleRespondable<RelationshipModel>>;
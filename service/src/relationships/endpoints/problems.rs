@@ -0,0 +1,30 @@
+use actix_http::http::StatusCode;
+
+use crate::{
+    entities::EntityId,
+    http::problem::{Problem, SimpleProblemType},
+};
+
+/// Problem to indicate that a relationship can't exist - because one of the entities doesn't
+/// exist, an entity would be related to itself, or the relationship doesn't make sense for the
+/// types of entity.
+pub const INVALID_RELATIONSHIP: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/relationships/invalid_relationship",
+    problem_title: "Invalid Relationship",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate that the same relationship already exists between the entities.
+pub const DUPLICATE_RELATIONSHIP: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/relationships/duplicate_relationship",
+    problem_title: "Duplicate Relationship",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Build the problem for a relationship with an entity that doesn't exist.
+///
+/// # Parameters
+/// - `entity_id` - The ID of the entity that doesn't exist
+pub fn unknown_entity(entity_id: &EntityId) -> Problem {
+    Problem::from(INVALID_RELATIONSHIP).with_extra("entityId", entity_id)
+}
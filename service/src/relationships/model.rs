@@ -0,0 +1,36 @@
+mod relationship_id;
+mod relationship_kind;
+
+pub use relationship_id::*;
+pub use relationship_kind::*;
+
+use crate::{
+    entities::{EntityId, EntityRecordResource},
+    model::Resource,
+    worlds::WorldId,
+};
+
+/// The data representing a directed relationship from one entity to another.
+#[derive(Debug, Clone)]
+pub struct RelationshipData {
+    pub world_id:  WorldId,
+    pub source_id: EntityId,
+    pub target_id: EntityId,
+    pub kind:      RelationshipKind,
+    /// When the relationship started, in whatever calendar the world uses.
+    pub starts:    Option<String>,
+    /// When the relationship ended, in whatever calendar the world uses.
+    pub ends:      Option<String>,
+}
+
+/// Type representing a persisted relationship.
+pub type RelationshipResource = Resource<RelationshipId, RelationshipData>;
+
+/// A graph of entities and the relationships between them.
+#[derive(Debug, Default)]
+pub struct Graph {
+    /// The entities in the graph.
+    pub nodes: Vec<EntityRecordResource>,
+    /// The relationships between the entities in the graph.
+    pub edges: Vec<RelationshipResource>,
+}
@@ -0,0 +1,148 @@
+use std::str::FromStr;
+
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::entities::{CharacterAttributes, EntityType, FactionAttributes, LocationAttributes};
+
+/// The type of a relationship from one entity to another.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RelationshipKind {
+    /// The source character is a parent of the target character.
+    ParentOf,
+    /// The source entity is a member of the target faction.
+    MemberOf,
+    /// The source entity is located within the target location.
+    LocatedIn,
+    /// The source entity is an enemy of the target entity.
+    EnemyOf,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ParseRelationshipKindError {
+    #[error("Unknown relationship type: {0}")]
+    Unknown(String),
+}
+
+impl RelationshipKind {
+    /// Every type of relationship.
+    pub const ALL: [RelationshipKind; 4] = [Self::ParentOf, Self::MemberOf, Self::LocatedIn, Self::EnemyOf];
+
+    /// The identifier of the relationship type, as used on the API and in the database.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ParentOf => "parent_of",
+            Self::MemberOf => "member_of",
+            Self::LocatedIn => "located_in",
+            Self::EnemyOf => "enemy_of",
+        }
+    }
+
+    /// A human readable label for the relationship type.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::ParentOf => "parent of",
+            Self::MemberOf => "member of",
+            Self::LocatedIn => "located in",
+            Self::EnemyOf => "enemy of",
+        }
+    }
+
+    /// Determine whether this type of relationship can exist between two types of entity.
+    ///
+    /// # Parameters
+    /// - `source` - The type of the entity that the relationship is from
+    /// - `target` - The type of the entity that the relationship is to
+    pub fn allows(self, source: &str, target: &str) -> bool {
+        match self {
+            Self::ParentOf => source == CharacterAttributes::KIND && target == CharacterAttributes::KIND,
+            Self::MemberOf => target == FactionAttributes::KIND,
+            Self::LocatedIn => target == LocationAttributes::KIND,
+            Self::EnemyOf => true,
+        }
+    }
+
+    /// Generate the JSON Schema fragment for a relationship type.
+    pub fn schema() -> Value {
+        let kinds: Vec<&str> = Self::ALL.iter().map(|k| k.as_str()).collect();
+
+        json!({
+            "type": "string",
+            "enum": kinds
+        })
+    }
+}
+
+impl std::fmt::Display for RelationshipKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for RelationshipKind {
+    type Err = ParseRelationshipKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|k| k.as_str() == s)
+            .copied()
+            .ok_or_else(|| ParseRelationshipKindError::Unknown(s.to_owned()))
+    }
+}
+
+impl<'a> FromSql<'a> for RelationshipKind {
+    accepts!(TEXT, VARCHAR);
+
+    fn from_sql(t: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        let value = <&str>::from_sql(t, raw)?;
+
+        Ok(value.parse()?)
+    }
+}
+
+impl ToSql for RelationshipKind {
+    accepts!(TEXT, VARCHAR);
+    to_sql_checked!();
+
+    fn to_sql(&self, t: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.as_str().to_sql(t, w)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(RelationshipKind::ParentOf, "character", "character", true ; "Parent of character")]
+    #[test_case(RelationshipKind::ParentOf, "character", "faction", false ; "Parent of faction")]
+    #[test_case(RelationshipKind::MemberOf, "character", "faction", true ; "Member of faction")]
+    #[test_case(RelationshipKind::MemberOf, "faction", "location", false ; "Member of location")]
+    #[test_case(RelationshipKind::LocatedIn, "item", "location", true ; "Located in location")]
+    #[test_case(RelationshipKind::LocatedIn, "location", "character", false ; "Located in character")]
+    #[test_case(RelationshipKind::EnemyOf, "faction", "character", true ; "Enemy of anything")]
+    fn test_allows(kind: RelationshipKind, source: &str, target: &str, expected: bool) {
+        check!(kind.allows(source, target) == expected);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for kind in &RelationshipKind::ALL {
+            let_assert!(Ok(parsed) = kind.as_str().parse::<RelationshipKind>());
+            check!(&parsed == kind);
+            check!(serde_json::to_value(kind).unwrap() == kind.as_str());
+        }
+    }
+
+    #[test]
+    fn test_parse_unknown() {
+        let_assert!(Err(e) = "friend_of".parse::<RelationshipKind>());
+        check!(e == ParseRelationshipKindError::Unknown("friend_of".to_owned()));
+    }
+}
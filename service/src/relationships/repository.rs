@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod memory;
+mod parse;
+mod postgres;
+mod save_relationship;
+
+#[cfg(test)]
+pub use memory::MemoryRelationshipRepository;
+pub use postgres::PostgresRelationshipRepository;
+pub use save_relationship::SaveRelationshipError;
+
+use super::{RelationshipData, RelationshipId, RelationshipResource};
+use crate::{entities::EntityId, worlds::WorldId};
+
+/// Repository of relationships between entities, which is also able to answer queries about the
+/// graph that the relationships form.
+#[async_trait::async_trait]
+pub trait RelationshipRepository: Send + Sync {
+    /// Create a new relationship.
+    ///
+    /// # Parameters
+    /// - `relationship` - The details of the relationship to create.
+    ///
+    /// # Returns
+    /// The created relationship.
+    async fn create_relationship(&self, relationship: &RelationshipData) -> Result<RelationshipResource, SaveRelationshipError>;
+
+    /// Get a single relationship.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the relationship belongs to.
+    /// - `relationship_id` - The ID of the relationship.
+    ///
+    /// # Returns
+    /// The relationship, or `None` if it couldn't be found.
+    async fn get_relationship(&self, world_id: &WorldId, relationship_id: &RelationshipId) -> Option<RelationshipResource>;
+
+    /// Get every relationship within a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world.
+    ///
+    /// # Returns
+    /// The relationships, oldest first.
+    async fn get_relationships(&self, world_id: &WorldId) -> Vec<RelationshipResource>;
+
+    /// Delete a single relationship.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the relationship belongs to.
+    /// - `relationship_id` - The ID of the relationship.
+    ///
+    /// # Returns
+    /// True if the relationship existed and was deleted. False if not.
+    async fn delete_relationship(&self, world_id: &WorldId, relationship_id: &RelationshipId) -> bool;
+
+    /// Get every relationship from or to an entity.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the entity belongs to.
+    /// - `entity_id` - The ID of the entity.
+    ///
+    /// # Returns
+    /// The relationships, oldest first.
+    async fn get_neighbours(&self, world_id: &WorldId, entity_id: &EntityId) -> Vec<RelationshipResource>;
+
+    /// Get the family tree of an entity - every "parent of" relationship between its ancestors and
+    /// between its descendants.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the entity belongs to.
+    /// - `entity_id` - The ID of the entity.
+    ///
+    /// # Returns
+    /// The relationships, oldest first.
+    async fn get_family_tree(&self, world_id: &WorldId, entity_id: &EntityId) -> Vec<RelationshipResource>;
+
+    /// Get the contents of a region - every "located in" relationship that leads to the region,
+    /// either directly or through other locations.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the region belongs to.
+    /// - `entity_id` - The ID of the region.
+    ///
+    /// # Returns
+    /// The relationships, oldest first.
+    async fn get_contents(&self, world_id: &WorldId, entity_id: &EntityId) -> Vec<RelationshipResource>;
+
+    /// Get every relationship to or from any of a set of entities.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the entities belong to.
+    /// - `entity_ids` - The IDs of the entities.
+    ///
+    /// # Returns
+    /// The relationships, oldest first.
+    async fn get_neighbours_of_all(&self, world_id: &WorldId, entity_ids: &[EntityId]) -> Vec<RelationshipResource>;
+}
@@ -0,0 +1,135 @@
+use std::sync::Mutex;
+
+use super::{RelationshipRepository, SaveRelationshipError};
+use crate::{
+    entities::EntityId,
+    model::Identity,
+    relationships::{RelationshipData, RelationshipId, RelationshipKind, RelationshipResource},
+    worlds::WorldId,
+};
+
+/// Repository of relationships that are stored in memory.
+#[derive(Default)]
+pub struct MemoryRelationshipRepository {
+    relationships: Mutex<Vec<RelationshipResource>>,
+}
+
+impl MemoryRelationshipRepository {
+    /// Create a new, empty relationship repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the relationships within a world that match a predicate.
+    fn filter<F>(&self, world_id: &WorldId, f: F) -> Vec<RelationshipResource>
+    where
+        F: Fn(&RelationshipData) -> bool,
+    {
+        let relationships = self.relationships.lock().unwrap();
+
+        relationships
+            .iter()
+            .filter(|r| &r.data.world_id == world_id && f(&r.data))
+            .cloned()
+            .collect()
+    }
+
+    /// Follow relationships of one type transitively, collecting every relationship that is
+    /// reached along the way.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    /// - `start` - The entity to start from
+    /// - `kind` - The type of relationship to follow
+    /// - `upwards` - True to follow relationships from their target to their source, false to
+    ///   follow them from their source to their target
+    fn walk(&self, world_id: &WorldId, start: &EntityId, kind: RelationshipKind, upwards: bool) -> Vec<RelationshipResource> {
+        let candidates = self.filter(world_id, |r| r.kind == kind);
+
+        let mut result: Vec<RelationshipResource> = vec![];
+        let mut pending = vec![start.clone()];
+        while let Some(current) = pending.pop() {
+            for relationship in &candidates {
+                let (from, to) = if upwards {
+                    (&relationship.data.target_id, &relationship.data.source_id)
+                } else {
+                    (&relationship.data.source_id, &relationship.data.target_id)
+                };
+
+                if from == &current && !result.iter().any(|r| r.identity.id == relationship.identity.id) {
+                    result.push(relationship.clone());
+                    pending.push(to.clone());
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[async_trait::async_trait]
+impl RelationshipRepository for MemoryRelationshipRepository {
+    async fn create_relationship(&self, relationship: &RelationshipData) -> Result<RelationshipResource, SaveRelationshipError> {
+        let mut relationships = self.relationships.lock().unwrap();
+
+        let duplicate = relationships.iter().any(|r| {
+            r.data.source_id == relationship.source_id && r.data.target_id == relationship.target_id && r.data.kind == relationship.kind
+        });
+        if duplicate {
+            return Err(SaveRelationshipError::DuplicateRelationship);
+        }
+
+        let created = RelationshipResource {
+            identity: Identity::default(),
+            data:     relationship.clone(),
+        };
+        relationships.push(created.clone());
+
+        Ok(created)
+    }
+
+    async fn get_relationship(&self, world_id: &WorldId, relationship_id: &RelationshipId) -> Option<RelationshipResource> {
+        let relationships = self.relationships.lock().unwrap();
+
+        relationships
+            .iter()
+            .find(|r| &r.data.world_id == world_id && &r.identity.id == relationship_id)
+            .cloned()
+    }
+
+    async fn get_relationships(&self, world_id: &WorldId) -> Vec<RelationshipResource> {
+        self.filter(world_id, |_| true)
+    }
+
+    async fn delete_relationship(&self, world_id: &WorldId, relationship_id: &RelationshipId) -> bool {
+        let mut relationships = self.relationships.lock().unwrap();
+
+        let before = relationships.len();
+        relationships.retain(|r| !(&r.data.world_id == world_id && &r.identity.id == relationship_id));
+
+        relationships.len() != before
+    }
+
+    async fn get_neighbours(&self, world_id: &WorldId, entity_id: &EntityId) -> Vec<RelationshipResource> {
+        self.filter(world_id, |r| &r.source_id == entity_id || &r.target_id == entity_id)
+    }
+
+    async fn get_family_tree(&self, world_id: &WorldId, entity_id: &EntityId) -> Vec<RelationshipResource> {
+        let mut result = self.walk(world_id, entity_id, RelationshipKind::ParentOf, true);
+        for relationship in self.walk(world_id, entity_id, RelationshipKind::ParentOf, false) {
+            if !result.iter().any(|r| r.identity.id == relationship.identity.id) {
+                result.push(relationship);
+            }
+        }
+
+        result
+    }
+
+    async fn get_contents(&self, world_id: &WorldId, entity_id: &EntityId) -> Vec<RelationshipResource> {
+        self.walk(world_id, entity_id, RelationshipKind::LocatedIn, true)
+    }
+
+    async fn get_neighbours_of_all(&self, world_id: &WorldId, entity_ids: &[EntityId]) -> Vec<RelationshipResource> {
+        self.filter(world_id, |r| entity_ids.contains(&r.source_id) || entity_ids.contains(&r.target_id))
+    }
+}
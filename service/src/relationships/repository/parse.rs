@@ -0,0 +1,27 @@
+use tokio_postgres::Row;
+
+use crate::{
+    model::Identity,
+    relationships::{RelationshipData, RelationshipResource},
+};
+
+impl From<Row> for RelationshipResource {
+    fn from(row: Row) -> Self {
+        RelationshipResource {
+            identity: Identity {
+                id:      row.get("relationship_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data:     RelationshipData {
+                world_id:  row.get("world_id"),
+                source_id: row.get("source_id"),
+                target_id: row.get("target_id"),
+                kind:      row.get("kind"),
+                starts:    row.get("starts"),
+                ends:      row.get("ends"),
+            },
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use super::{RelationshipRepository, SaveRelationshipError};
+use crate::{
+    database::Database,
+    entities::EntityId,
+    model::Identity,
+    relationships::{RelationshipData, RelationshipId, RelationshipKind, RelationshipResource},
+    worlds::WorldId,
+};
+
+/// Repository of relationships that are stored in Postgres.
+pub struct PostgresRelationshipRepository {
+    database: Arc<Database>,
+}
+
+impl PostgresRelationshipRepository {
+    /// Create a new relationship repository.
+    pub fn new(database: Arc<Database>) -> Self {
+        Self { database }
+    }
+
+    /// Load many relationships with the provided query.
+    async fn query(&self, sql: &str, params: &[&(dyn postgres_types::ToSql + Sync)]) -> Vec<RelationshipResource> {
+        let conn = self.database.connect().await;
+        conn.query(sql, params).await.map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to load relationships");
+                vec![]
+            },
+            |rows| rows.into_iter().map(RelationshipResource::from).collect(),
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl RelationshipRepository for PostgresRelationshipRepository {
+    #[tracing::instrument(skip(self))]
+    async fn create_relationship(&self, relationship: &RelationshipData) -> Result<RelationshipResource, SaveRelationshipError> {
+        let conn = self.database.connect().await;
+
+        let identity = Identity::<RelationshipId>::default();
+
+        let created: RelationshipResource = conn.query_one("INSERT INTO relationships(relationship_id, version, created, updated, world_id, source_id, target_id, kind, starts, ends) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) RETURNING *",
+        &[
+          &identity.id,
+          &identity.version,
+          &identity.created,
+          &identity.updated,
+          &relationship.world_id,
+          &relationship.source_id,
+          &relationship.target_id,
+          &relationship.kind,
+          &relationship.starts,
+          &relationship.ends,
+          ])
+            .await
+            .map(RelationshipResource::from)?;
+
+        Ok(created)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_relationship(&self, world_id: &WorldId, relationship_id: &RelationshipId) -> Option<RelationshipResource> {
+        self.query(
+            "SELECT * FROM relationships WHERE world_id = $1 AND relationship_id = $2",
+            &[&world_id, &relationship_id],
+        )
+        .await
+        .into_iter()
+        .next()
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_relationships(&self, world_id: &WorldId) -> Vec<RelationshipResource> {
+        self.query("SELECT * FROM relationships WHERE world_id = $1 ORDER BY created", &[&world_id])
+            .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_relationship(&self, world_id: &WorldId, relationship_id: &RelationshipId) -> bool {
+        let conn = self.database.connect().await;
+        conn.execute(
+            "DELETE FROM relationships WHERE world_id = $1 AND relationship_id = $2",
+            &[&world_id, &relationship_id],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to delete relationship");
+                false
+            },
+            |count| count == 1,
+        )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_neighbours(&self, world_id: &WorldId, entity_id: &EntityId) -> Vec<RelationshipResource> {
+        self.query(
+            "SELECT * FROM relationships WHERE world_id = $1 AND (source_id = $2 OR target_id = $2) ORDER BY created",
+            &[&world_id, &entity_id],
+        )
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_family_tree(&self, world_id: &WorldId, entity_id: &EntityId) -> Vec<RelationshipResource> {
+        self.query(
+            "WITH RECURSIVE ancestors AS (
+                SELECT * FROM relationships WHERE world_id = $1 AND kind = $3 AND target_id = $2
+                UNION
+                SELECT r.* FROM relationships r JOIN ancestors a ON r.target_id = a.source_id WHERE r.kind = $3
+            ), descendants AS (
+                SELECT * FROM relationships WHERE world_id = $1 AND kind = $3 AND source_id = $2
+                UNION
+                SELECT r.* FROM relationships r JOIN descendants d ON r.source_id = d.target_id WHERE r.kind = $3
+            )
+            SELECT * FROM ancestors UNION SELECT * FROM descendants ORDER BY created",
+            &[&world_id, &entity_id, &RelationshipKind::ParentOf],
+        )
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_contents(&self, world_id: &WorldId, entity_id: &EntityId) -> Vec<RelationshipResource> {
+        self.query(
+            "WITH RECURSIVE contents AS (
+                SELECT * FROM relationships WHERE world_id = $1 AND kind = $3 AND target_id = $2
+                UNION
+                SELECT r.* FROM relationships r JOIN contents c ON r.target_id = c.source_id WHERE r.kind = $3
+            )
+            SELECT * FROM contents ORDER BY created",
+            &[&world_id, &entity_id, &RelationshipKind::LocatedIn],
+        )
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_neighbours_of_all(&self, world_id: &WorldId, entity_ids: &[EntityId]) -> Vec<RelationshipResource> {
+        self.query(
+            "SELECT * FROM relationships WHERE world_id = $1 AND (source_id = ANY($2) OR target_id = ANY($2)) ORDER BY created",
+            &[&world_id, &entity_ids],
+        )
+        .await
+    }
+}
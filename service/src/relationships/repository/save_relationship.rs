@@ -0,0 +1,41 @@
+use tokio_postgres::error::{DbError, SqlState};
+
+/// Errors that can occur when saving a relationship record.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveRelationshipError {
+    #[error("The relationship already exists")]
+    DuplicateRelationship,
+
+    #[error("An entity or world was not found")]
+    UnknownEntity,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl From<tokio_postgres::Error> for SaveRelationshipError {
+    fn from(e: tokio_postgres::Error) -> Self {
+        let mut result = None;
+
+        if e.code() == Some(&SqlState::UNIQUE_VIOLATION) {
+            let db_error: Option<DbError> = e.into_source().and_then(|e| e.downcast_ref::<DbError>().cloned());
+
+            result = db_error
+                .and_then(|e| e.constraint().map(std::borrow::ToOwned::to_owned))
+                .map(|constraint| {
+                    if constraint == "relationships_source_id_target_id_kind_key" {
+                        SaveRelationshipError::DuplicateRelationship
+                    } else {
+                        tracing::warn!("Unexpected constraint violation error: {:?}", constraint);
+                        SaveRelationshipError::UnknownError
+                    }
+                });
+        } else if e.code() == Some(&SqlState::FOREIGN_KEY_VIOLATION) {
+            result = Some(SaveRelationshipError::UnknownEntity);
+        } else {
+            tracing::warn!("Unexpected database error: {:?}", e);
+        }
+
+        result.unwrap_or(SaveRelationshipError::UnknownError)
+    }
+}
@@ -0,0 +1,60 @@
+mod create_relationship;
+mod get_graph;
+mod get_relationship;
+
+use std::sync::Arc;
+
+pub use create_relationship::{CreateRelationshipError, NewRelationship};
+
+use super::{repository::RelationshipRepository, Graph, RelationshipResource};
+use crate::{
+    entities::{EntityId, EntityService},
+    worlds::WorldId,
+};
+
+/// The most relationships that are followed when looking for a path between two entities.
+const MAX_PATH_LENGTH: usize = 6;
+
+/// Service layer for working with the relationships between entities.
+pub struct RelationshipService {
+    repository: Arc<dyn RelationshipRepository>,
+    entities:   Arc<EntityService>,
+}
+
+impl RelationshipService {
+    /// Create a new relationship service.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store relationships in
+    /// - `entities` - The service to load the entities that are related from
+    pub fn new(repository: Arc<dyn RelationshipRepository>, entities: Arc<EntityService>) -> Self {
+        Self { repository, entities }
+    }
+
+    /// Build a graph from a set of relationships, loading the entities that they relate.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the relationships belong to
+    /// - `roots` - Any entities that should be in the graph even if nothing is related to them
+    /// - `edges` - The relationships in the graph
+    ///
+    /// # Returns
+    /// The graph, with the entities in the order that they are first mentioned.
+    async fn build_graph(&self, world_id: &WorldId, roots: &[&EntityId], edges: Vec<RelationshipResource>) -> Graph {
+        let mut ids: Vec<EntityId> = vec![];
+        let mentioned = roots
+            .iter()
+            .copied()
+            .chain(edges.iter().flat_map(|e| vec![&e.data.source_id, &e.data.target_id]));
+        for entity_id in mentioned {
+            if !ids.contains(entity_id) {
+                ids.push(entity_id.clone());
+            }
+        }
+
+        let mut nodes = self.entities.get_entities_by_ids(world_id, &ids).await;
+        nodes.sort_by_key(|n| ids.iter().position(|id| id == &n.identity.id));
+
+        Graph { nodes, edges }
+    }
+}
@@ -0,0 +1,278 @@
+use super::RelationshipService;
+use crate::{
+    entities::EntityId,
+    relationships::{RelationshipData, RelationshipKind, RelationshipResource, SaveRelationshipError},
+    worlds::WorldId,
+};
+
+/// The details needed to create a new relationship.
+#[derive(Debug)]
+pub struct NewRelationship {
+    pub source_id: EntityId,
+    pub target_id: EntityId,
+    pub kind:      RelationshipKind,
+    pub starts:    Option<String>,
+    pub ends:      Option<String>,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum CreateRelationshipError {
+    #[error("Unknown entity: {0}")]
+    UnknownEntity(EntityId),
+
+    #[error("The relationship can't exist between these entities")]
+    InvalidRelationship,
+
+    #[error("The relationship already exists")]
+    DuplicateRelationship,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl RelationshipService {
+    /// Create a new relationship between two entities in a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the entities belong to
+    /// - `relationship` - The details of the relationship
+    ///
+    /// # Returns
+    /// The newly created relationship.
+    pub async fn create_relationship(
+        &self,
+        world_id: &WorldId,
+        relationship: NewRelationship,
+    ) -> Result<RelationshipResource, CreateRelationshipError> {
+        if relationship.source_id == relationship.target_id {
+            return Err(CreateRelationshipError::InvalidRelationship);
+        }
+
+        let entities = self
+            .entities
+            .get_entities_by_ids(world_id, &[relationship.source_id.clone(), relationship.target_id.clone()])
+            .await;
+        let kind_of = |entity_id: &EntityId| {
+            entities
+                .iter()
+                .find(|e| &e.identity.id == entity_id)
+                .map(|e| e.data.kind.as_str())
+                .ok_or_else(|| CreateRelationshipError::UnknownEntity(entity_id.clone()))
+        };
+        let source = kind_of(&relationship.source_id)?;
+        let target = kind_of(&relationship.target_id)?;
+
+        if !relationship.kind.allows(source, target) {
+            return Err(CreateRelationshipError::InvalidRelationship);
+        }
+
+        let data = RelationshipData {
+            world_id:  world_id.clone(),
+            source_id: relationship.source_id,
+            target_id: relationship.target_id,
+            kind:      relationship.kind,
+            starts:    relationship.starts,
+            ends:      relationship.ends,
+        };
+
+        let result = self.repository.create_relationship(&data).await?;
+
+        Ok(result)
+    }
+}
+
+impl From<SaveRelationshipError> for CreateRelationshipError {
+    fn from(e: SaveRelationshipError) -> Self {
+        match e {
+            SaveRelationshipError::DuplicateRelationship => Self::DuplicateRelationship,
+            SaveRelationshipError::UnknownEntity | SaveRelationshipError::UnknownError => Self::UnknownError,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert2::{check, let_assert};
+    use test_case::test_case;
+
+    use super::*;
+    use crate::{
+        entities::{
+            CharacterAttributes, CustomFields, EntityService, EntityType, FactionAttributes, LocationAttributes, MemoryEntityRepository,
+            NewEntity,
+        },
+        relationships::MemoryRelationshipRepository,
+        revisions::{MemoryRevisionRepository, RevisionService},
+        search::{MemorySearchRepository, SearchService},
+        users::UserId,
+    };
+
+    async fn create_entity<A>(service: &EntityService, world_id: &WorldId, name: &str) -> EntityId
+    where
+        A: EntityType,
+    {
+        service
+            .create_entity::<A>(
+                world_id,
+                &UserId::default(),
+                NewEntity {
+                    name:       name.to_owned(),
+                    summary:    None,
+                    attributes: A::default(),
+                    custom:     CustomFields::new(),
+                },
+            )
+            .await
+            .unwrap()
+            .identity
+            .id
+    }
+
+    fn new_relationship(source_id: &EntityId, target_id: &EntityId, kind: RelationshipKind) -> NewRelationship {
+        NewRelationship {
+            source_id: source_id.clone(),
+            target_id: target_id.clone(),
+            kind,
+            starts: None,
+            ends: None,
+        }
+    }
+
+    struct Fixture {
+        sut:      RelationshipService,
+        world_id: WorldId,
+        aragorn:  EntityId,
+        arathorn: EntityId,
+        rangers:  EntityId,
+        gondor:   EntityId,
+    }
+
+    async fn build_fixture() -> Fixture {
+        let entities = Arc::new(EntityService::new(
+            Arc::new(MemoryEntityRepository::new()),
+            Arc::new(RevisionService::new(Arc::new(MemoryRevisionRepository::new()))),
+            Arc::new(SearchService::new(Arc::new(MemorySearchRepository::new()))),
+        ));
+        let world_id = WorldId::default();
+
+        Fixture {
+            aragorn: create_entity::<CharacterAttributes>(&entities, &world_id, "Aragorn").await,
+            arathorn: create_entity::<CharacterAttributes>(&entities, &world_id, "Arathorn").await,
+            rangers: create_entity::<FactionAttributes>(&entities, &world_id, "Rangers").await,
+            gondor: create_entity::<LocationAttributes>(&entities, &world_id, "Gondor").await,
+            sut: RelationshipService::new(Arc::new(MemoryRelationshipRepository::new()), entities),
+            world_id,
+        }
+    }
+
+    #[actix_rt::test]
+    async fn create_relationship() {
+        let f = build_fixture().await;
+
+        let result = f
+            .sut
+            .create_relationship(
+                &f.world_id,
+                NewRelationship {
+                    starts: Some("TA 2931".to_owned()),
+                    ..new_relationship(&f.arathorn, &f.aragorn, RelationshipKind::ParentOf)
+                },
+            )
+            .await;
+
+        let_assert!(Ok(relationship) = result);
+        check!(relationship.data.source_id == f.arathorn);
+        check!(relationship.data.target_id == f.aragorn);
+        check!(relationship.data.kind == RelationshipKind::ParentOf);
+        check!(relationship.data.starts == Some("TA 2931".to_owned()));
+        check!(relationship.data.ends == None);
+
+        let_assert!(Some(loaded) = f.sut.get_relationship(&f.world_id, &relationship.identity.id).await);
+        check!(loaded.identity.id == relationship.identity.id);
+    }
+
+    #[test_case(RelationshipKind::ParentOf, false ; "Parent of faction")]
+    #[test_case(RelationshipKind::MemberOf, true ; "Member of faction")]
+    #[test_case(RelationshipKind::LocatedIn, false ; "Located in faction")]
+    #[test_case(RelationshipKind::EnemyOf, true ; "Enemy of faction")]
+    #[actix_rt::test]
+    async fn create_relationship_to_faction(kind: RelationshipKind, allowed: bool) {
+        let f = build_fixture().await;
+
+        let result = f
+            .sut
+            .create_relationship(&f.world_id, new_relationship(&f.aragorn, &f.rangers, kind))
+            .await;
+
+        if allowed {
+            check!(result.is_ok());
+        } else {
+            let_assert!(Err(e) = result);
+            check!(e == CreateRelationshipError::InvalidRelationship);
+        }
+    }
+
+    #[actix_rt::test]
+    async fn create_relationship_to_self() {
+        let f = build_fixture().await;
+
+        let result = f
+            .sut
+            .create_relationship(&f.world_id, new_relationship(&f.aragorn, &f.aragorn, RelationshipKind::EnemyOf))
+            .await;
+
+        let_assert!(Err(e) = result);
+        check!(e == CreateRelationshipError::InvalidRelationship);
+    }
+
+    #[actix_rt::test]
+    async fn create_relationship_to_unknown_entity() {
+        let f = build_fixture().await;
+        let unknown = EntityId::default();
+
+        let result = f
+            .sut
+            .create_relationship(&f.world_id, new_relationship(&f.aragorn, &unknown, RelationshipKind::EnemyOf))
+            .await;
+
+        let_assert!(Err(e) = result);
+        check!(e == CreateRelationshipError::UnknownEntity(unknown));
+    }
+
+    #[actix_rt::test]
+    async fn create_relationship_in_other_world() {
+        let f = build_fixture().await;
+
+        let result = f
+            .sut
+            .create_relationship(
+                &WorldId::default(),
+                new_relationship(&f.aragorn, &f.gondor, RelationshipKind::LocatedIn),
+            )
+            .await;
+
+        let_assert!(Err(e) = result);
+        check!(e == CreateRelationshipError::UnknownEntity(f.aragorn.clone()));
+    }
+
+    #[actix_rt::test]
+    async fn create_duplicate_relationship() {
+        let f = build_fixture().await;
+
+        let_assert!(
+            Ok(_) = f
+                .sut
+                .create_relationship(&f.world_id, new_relationship(&f.aragorn, &f.gondor, RelationshipKind::LocatedIn))
+                .await
+        );
+        let result = f
+            .sut
+            .create_relationship(&f.world_id, new_relationship(&f.aragorn, &f.gondor, RelationshipKind::LocatedIn))
+            .await;
+
+        let_assert!(Err(e) = result);
+        check!(e == CreateRelationshipError::DuplicateRelationship);
+    }
+}
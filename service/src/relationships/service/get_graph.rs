@@ -0,0 +1,370 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{RelationshipService, MAX_PATH_LENGTH};
+use crate::{
+    entities::EntityId,
+    relationships::{Graph, RelationshipResource},
+    worlds::WorldId,
+};
+
+impl RelationshipService {
+    /// Get the graph of every relationship within a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    ///
+    /// # Returns
+    /// The graph of every entity that has a relationship, along with the relationships.
+    pub async fn get_graph(&self, world_id: &WorldId) -> Graph {
+        let edges = self.repository.get_relationships(world_id).await;
+
+        self.build_graph(world_id, &[], edges).await
+    }
+
+    /// Get the graph of the entities that are directly related to an entity.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the entity belongs to
+    /// - `entity_id` - The ID of the entity
+    ///
+    /// # Returns
+    /// The graph, or `None` if the entity doesn't exist.
+    pub async fn get_neighbours(&self, world_id: &WorldId, entity_id: &EntityId) -> Option<Graph> {
+        let edges = self.repository.get_neighbours(world_id, entity_id).await;
+
+        self.rooted_graph(world_id, entity_id, edges).await
+    }
+
+    /// Get the family tree of an entity, made up of its ancestors and descendants.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the entity belongs to
+    /// - `entity_id` - The ID of the entity
+    ///
+    /// # Returns
+    /// The graph, or `None` if the entity doesn't exist.
+    pub async fn get_family_tree(&self, world_id: &WorldId, entity_id: &EntityId) -> Option<Graph> {
+        let edges = self.repository.get_family_tree(world_id, entity_id).await;
+
+        self.rooted_graph(world_id, entity_id, edges).await
+    }
+
+    /// Get everything that is located within a region, however deeply nested.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the region belongs to
+    /// - `entity_id` - The ID of the region
+    ///
+    /// # Returns
+    /// The graph, or `None` if the region doesn't exist.
+    pub async fn get_contents(&self, world_id: &WorldId, entity_id: &EntityId) -> Option<Graph> {
+        let edges = self.repository.get_contents(world_id, entity_id).await;
+
+        self.rooted_graph(world_id, entity_id, edges).await
+    }
+
+    /// Find the shortest path between two entities, following relationships in either direction.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the entities belong to
+    /// - `from` - The ID of the entity to start from
+    /// - `to` - The ID of the entity to finish at
+    ///
+    /// # Returns
+    /// The graph of the path, or `None` if there is no path within a reasonable distance.
+    pub async fn find_path(&self, world_id: &WorldId, from: &EntityId, to: &EntityId) -> Option<Graph> {
+        let edges = self.shortest_path(world_id, from, to).await?;
+
+        let graph = self.build_graph(world_id, &[from, to], edges).await;
+        let complete = [from, to].iter().all(|id| graph.nodes.iter().any(|n| &&n.identity.id == id));
+
+        if complete {
+            Some(graph)
+        } else {
+            None
+        }
+    }
+
+    /// Breadth first search for the shortest path between two entities, loading one level of the
+    /// graph at a time. Each entity is only visited once, so the work is bounded by the size of the
+    /// world rather than the number of different paths through it.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the entities belong to
+    /// - `from` - The ID of the entity to start from
+    /// - `to` - The ID of the entity to finish at
+    ///
+    /// # Returns
+    /// The relationships that make up the path, in order, or `None` if there is no path within
+    /// `MAX_PATH_LENGTH` relationships.
+    async fn shortest_path(&self, world_id: &WorldId, from: &EntityId, to: &EntityId) -> Option<Vec<RelationshipResource>> {
+        // The relationship that each visited entity was first reached by.
+        let mut reached: HashMap<EntityId, Option<RelationshipResource>> = HashMap::new();
+        reached.insert(from.clone(), None);
+
+        let mut frontier = vec![from.clone()];
+        for _ in 0..MAX_PATH_LENGTH {
+            if reached.contains_key(to) || frontier.is_empty() {
+                break;
+            }
+
+            let current: HashSet<&EntityId> = frontier.iter().collect();
+            let mut next = vec![];
+            for relationship in self.repository.get_neighbours_of_all(world_id, &frontier).await {
+                let ends = [
+                    (&relationship.data.source_id, &relationship.data.target_id),
+                    (&relationship.data.target_id, &relationship.data.source_id),
+                ];
+
+                for &(near, far) in &ends {
+                    if current.contains(near) && !reached.contains_key(far) {
+                        reached.insert(far.clone(), Some(relationship.clone()));
+                        next.push(far.clone());
+                    }
+                }
+            }
+
+            frontier = next;
+        }
+
+        // Walk back from the end to the start, following the relationships that each entity was
+        // reached by.
+        let mut path = vec![];
+        let mut current = to.clone();
+        while let Some(relationship) = reached.remove(&current)? {
+            current = if relationship.data.target_id == current {
+                relationship.data.source_id.clone()
+            } else {
+                relationship.data.target_id.clone()
+            };
+            path.push(relationship);
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Build a graph around a single entity, as long as that entity exists.
+    async fn rooted_graph(&self, world_id: &WorldId, entity_id: &EntityId, edges: Vec<RelationshipResource>) -> Option<Graph> {
+        let graph = self.build_graph(world_id, &[entity_id], edges).await;
+
+        if graph.nodes.iter().any(|n| &n.identity.id == entity_id) {
+            Some(graph)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::{
+        entities::{
+            CharacterAttributes, CustomFields, EntityService, EntityType, ItemAttributes, LocationAttributes, MemoryEntityRepository,
+            NewEntity,
+        },
+        relationships::{MemoryRelationshipRepository, NewRelationship, RelationshipKind},
+        revisions::{MemoryRevisionRepository, RevisionService},
+        search::{MemorySearchRepository, SearchService},
+        users::UserId,
+    };
+
+    struct Fixture {
+        sut:      RelationshipService,
+        entities: Arc<EntityService>,
+        world_id: WorldId,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let entities = Arc::new(EntityService::new(
+                Arc::new(MemoryEntityRepository::new()),
+                Arc::new(RevisionService::new(Arc::new(MemoryRevisionRepository::new()))),
+                Arc::new(SearchService::new(Arc::new(MemorySearchRepository::new()))),
+            ));
+
+            Self {
+                sut: RelationshipService::new(Arc::new(MemoryRelationshipRepository::new()), entities.clone()),
+                entities,
+                world_id: WorldId::default(),
+            }
+        }
+
+        async fn entity<A>(&self, name: &str) -> EntityId
+        where
+            A: EntityType,
+        {
+            self.entities
+                .create_entity::<A>(
+                    &self.world_id,
+                    &UserId::default(),
+                    NewEntity {
+                        name:       name.to_owned(),
+                        summary:    None,
+                        attributes: A::default(),
+                        custom:     CustomFields::new(),
+                    },
+                )
+                .await
+                .unwrap()
+                .identity
+                .id
+        }
+
+        async fn relate(&self, source_id: &EntityId, kind: RelationshipKind, target_id: &EntityId) {
+            self.sut
+                .create_relationship(
+                    &self.world_id,
+                    NewRelationship {
+                        source_id: source_id.clone(),
+                        target_id: target_id.clone(),
+                        kind,
+                        starts: None,
+                        ends: None,
+                    },
+                )
+                .await
+                .unwrap();
+        }
+    }
+
+    fn names(graph: &Graph) -> Vec<&str> {
+        graph.nodes.iter().map(|n| n.data.name.as_str()).collect()
+    }
+
+    #[actix_rt::test]
+    async fn family_tree() {
+        let f = Fixture::new();
+        let arador = f.entity::<CharacterAttributes>("Arador").await;
+        let arathorn = f.entity::<CharacterAttributes>("Arathorn").await;
+        let aragorn = f.entity::<CharacterAttributes>("Aragorn").await;
+        let eldarion = f.entity::<CharacterAttributes>("Eldarion").await;
+        let arwen = f.entity::<CharacterAttributes>("Arwen").await;
+        let elrond = f.entity::<CharacterAttributes>("Elrond").await;
+        f.relate(&arador, RelationshipKind::ParentOf, &arathorn).await;
+        f.relate(&arathorn, RelationshipKind::ParentOf, &aragorn).await;
+        f.relate(&aragorn, RelationshipKind::ParentOf, &eldarion).await;
+        f.relate(&arwen, RelationshipKind::ParentOf, &eldarion).await;
+        f.relate(&elrond, RelationshipKind::ParentOf, &arwen).await;
+        f.relate(&aragorn, RelationshipKind::EnemyOf, &elrond).await;
+
+        let_assert!(Some(graph) = f.sut.get_family_tree(&f.world_id, &arathorn).await);
+        check!(graph.edges.len() == 3);
+        check!(names(&graph) == vec!["Arathorn", "Arador", "Aragorn", "Eldarion"]);
+
+        let_assert!(Some(graph) = f.sut.get_family_tree(&f.world_id, &eldarion).await);
+        check!(graph.edges.len() == 5);
+        check!(graph.edges.iter().all(|e| e.data.kind == RelationshipKind::ParentOf));
+    }
+
+    #[actix_rt::test]
+    async fn contents() {
+        let f = Fixture::new();
+        let gondor = f.entity::<LocationAttributes>("Gondor").await;
+        let minas_tirith = f.entity::<LocationAttributes>("Minas Tirith").await;
+        let citadel = f.entity::<LocationAttributes>("Citadel").await;
+        let white_tree = f.entity::<ItemAttributes>("White Tree").await;
+        let rohan = f.entity::<LocationAttributes>("Rohan").await;
+        f.relate(&minas_tirith, RelationshipKind::LocatedIn, &gondor).await;
+        f.relate(&citadel, RelationshipKind::LocatedIn, &minas_tirith).await;
+        f.relate(&white_tree, RelationshipKind::LocatedIn, &citadel).await;
+        f.relate(&rohan, RelationshipKind::EnemyOf, &gondor).await;
+
+        let_assert!(Some(graph) = f.sut.get_contents(&f.world_id, &gondor).await);
+        check!(names(&graph) == vec!["Gondor", "Minas Tirith", "Citadel", "White Tree"]);
+
+        let_assert!(Some(graph) = f.sut.get_contents(&f.world_id, &white_tree).await);
+        check!(names(&graph) == vec!["White Tree"]);
+        check!(graph.edges.is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn neighbours() {
+        let f = Fixture::new();
+        let gondor = f.entity::<LocationAttributes>("Gondor").await;
+        let minas_tirith = f.entity::<LocationAttributes>("Minas Tirith").await;
+        let citadel = f.entity::<LocationAttributes>("Citadel").await;
+        f.relate(&minas_tirith, RelationshipKind::LocatedIn, &gondor).await;
+        f.relate(&citadel, RelationshipKind::LocatedIn, &minas_tirith).await;
+
+        let_assert!(Some(graph) = f.sut.get_neighbours(&f.world_id, &minas_tirith).await);
+        check!(names(&graph) == vec!["Minas Tirith", "Gondor", "Citadel"]);
+        check!(graph.edges.len() == 2);
+
+        check!(f.sut.get_neighbours(&f.world_id, &EntityId::default()).await.is_none());
+    }
+
+    #[actix_rt::test]
+    async fn shortest_path() {
+        let f = Fixture::new();
+        let frodo = f.entity::<CharacterAttributes>("Frodo").await;
+        let bilbo = f.entity::<CharacterAttributes>("Bilbo").await;
+        let gandalf = f.entity::<CharacterAttributes>("Gandalf").await;
+        let saruman = f.entity::<CharacterAttributes>("Saruman").await;
+        let sauron = f.entity::<CharacterAttributes>("Sauron").await;
+        let sam = f.entity::<CharacterAttributes>("Sam").await;
+        f.relate(&frodo, RelationshipKind::EnemyOf, &sauron).await;
+        f.relate(&bilbo, RelationshipKind::EnemyOf, &frodo).await;
+        f.relate(&gandalf, RelationshipKind::EnemyOf, &bilbo).await;
+        f.relate(&saruman, RelationshipKind::EnemyOf, &gandalf).await;
+        f.relate(&saruman, RelationshipKind::EnemyOf, &sauron).await;
+
+        let_assert!(Some(graph) = f.sut.find_path(&f.world_id, &frodo, &saruman).await);
+        check!(names(&graph) == vec!["Frodo", "Saruman", "Sauron"]);
+        check!(graph.edges.len() == 2);
+        check!(graph.edges[0].data.source_id == frodo);
+        check!(graph.edges[1].data.source_id == saruman);
+
+        check!(f.sut.find_path(&f.world_id, &frodo, &sam).await.is_none());
+
+        let_assert!(Some(graph) = f.sut.find_path(&f.world_id, &saruman, &frodo).await);
+        check!(graph.edges.len() == 2);
+
+        let_assert!(Some(graph) = f.sut.find_path(&f.world_id, &frodo, &frodo).await);
+        check!(names(&graph) == vec!["Frodo"]);
+        check!(graph.edges.is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn path_too_long() {
+        let f = Fixture::new();
+        let mut entities = vec![];
+        for name in &["One", "Two", "Three", "Four", "Five", "Six", "Seven", "Eight"] {
+            entities.push(f.entity::<CharacterAttributes>(name).await);
+        }
+        for pair in entities.windows(2) {
+            f.relate(&pair[0], RelationshipKind::EnemyOf, &pair[1]).await;
+        }
+
+        let_assert!(Some(graph) = f.sut.find_path(&f.world_id, &entities[0], &entities[6]).await);
+        check!(graph.edges.len() == 6);
+        check!(f.sut.find_path(&f.world_id, &entities[0], &entities[7]).await.is_none());
+    }
+
+    #[actix_rt::test]
+    async fn path_through_dense_graph() {
+        let f = Fixture::new();
+        let start = f.entity::<CharacterAttributes>("Start").await;
+        let end = f.entity::<CharacterAttributes>("End").await;
+        let mut crowd = vec![];
+        for i in 0..8 {
+            crowd.push(f.entity::<CharacterAttributes>(&format!("Member {}", i)).await);
+        }
+        for (i, a) in crowd.iter().enumerate() {
+            f.relate(&start, RelationshipKind::EnemyOf, a).await;
+            for b in &crowd[i + 1..] {
+                f.relate(a, RelationshipKind::EnemyOf, b).await;
+            }
+        }
+        f.relate(&crowd[7], RelationshipKind::EnemyOf, &end).await;
+
+        let_assert!(Some(graph) = f.sut.find_path(&f.world_id, &start, &end).await);
+        check!(graph.edges.len() == 2);
+        check!(names(&graph) == vec!["Start", "End", "Member 7"]);
+    }
+}
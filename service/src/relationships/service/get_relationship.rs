@@ -0,0 +1,31 @@
+use super::RelationshipService;
+use crate::{
+    relationships::{RelationshipId, RelationshipResource},
+    worlds::WorldId,
+};
+
+impl RelationshipService {
+    /// Get a single relationship.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the relationship belongs to
+    /// - `relationship_id` - The ID of the relationship
+    ///
+    /// # Returns
+    /// The relationship, or `None` if it doesn't exist.
+    pub async fn get_relationship(&self, world_id: &WorldId, relationship_id: &RelationshipId) -> Option<RelationshipResource> {
+        self.repository.get_relationship(world_id, relationship_id).await
+    }
+
+    /// Delete a single relationship.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the relationship belongs to
+    /// - `relationship_id` - The ID of the relationship
+    ///
+    /// # Returns
+    /// True if the relationship existed and was deleted. False if not.
+    pub async fn delete_relationship(&self, world_id: &WorldId, relationship_id: &RelationshipId) -> bool {
+        self.repository.delete_relationship(world_id, relationship_id).await
+    }
+}
@@ -0,0 +1,11 @@
+pub mod component;
+mod diff;
+pub mod endpoints;
+mod model;
+mod repository;
+mod service;
+
+pub use diff::*;
+pub use model::*;
+pub use repository::*;
+pub use service::*;
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use actix_web::web::ServiceConfig;
+
+use super::{repository::RevisionRepository, service::RevisionService};
+use crate::server::RouteConfigurer;
+
+/// Component for tracking the revision history of resources.
+/// The routes for working with revisions belong to the components that own the resources, so this
+/// only makes the service available to them.
+pub struct Component {
+    pub service: Arc<RevisionService>,
+}
+
+impl Component {
+    /// Create a new revisions component.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store revisions in
+    pub fn new(repository: Arc<dyn RevisionRepository>) -> Arc<Self> {
+        let service = Arc::new(RevisionService::new(repository));
+
+        Arc::new(Self { service })
+    }
+}
+
+impl RouteConfigurer for Component {
+    fn configure_routes(&self, config: &mut ServiceConfig) {
+        config.data(self.service.clone());
+    }
+}
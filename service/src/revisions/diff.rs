@@ -0,0 +1,170 @@
+use serde::Serialize;
+use serde_json::Value;
+use similar::{ChangeTag, TextDiff};
+
+/// The kind of change to a single line of text.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LineChange {
+    Equal,
+    Insert,
+    Delete,
+}
+
+/// A single line in a diff.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DiffLine {
+    pub change: LineChange,
+    pub text:   String,
+}
+
+/// The differences in a single field between two snapshots.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldDiff {
+    pub field: String,
+    pub lines: Vec<DiffLine>,
+}
+
+/// Produce a line-level diff between two snapshots of a resource.
+/// Each top-level field is diffed separately, and only the fields that have changed are included.
+///
+/// # Parameters
+/// - `old` - The older snapshot
+/// - `new` - The newer snapshot
+///
+/// # Returns
+/// The differences for each field that changed, in the order that the fields appear.
+pub fn diff_snapshots(old: &Value, new: &Value) -> Vec<FieldDiff> {
+    let mut fields: Vec<&String> = vec![];
+    for snapshot in &[old, new] {
+        if let Value::Object(values) = snapshot {
+            for field in values.keys() {
+                if !fields.contains(&field) {
+                    fields.push(field);
+                }
+            }
+        }
+    }
+
+    fields
+        .into_iter()
+        .filter_map(|field| {
+            let old_text = field_text(old.get(field));
+            let new_text = field_text(new.get(field));
+
+            if old_text == new_text {
+                None
+            } else {
+                Some(FieldDiff {
+                    field: field.clone(),
+                    lines: diff_lines(&old_text, &new_text),
+                })
+            }
+        })
+        .collect()
+}
+
+/// Produce the text of a single field, for diffing.
+/// Strings are used directly so that multi-line text diffs nicely, and anything else is rendered as
+/// JSON.
+fn field_text(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(value) => serde_json::to_string_pretty(value).unwrap_or_default(),
+    }
+}
+
+/// Produce a line-level diff between two strings.
+/// A missing newline at the end of the text is ignored, so that adding a line after it doesn't also
+/// show the previous line as changed.
+fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let old = terminate_line(old);
+    let new = terminate_line(new);
+
+    TextDiff::from_lines(&old, &new)
+        .iter_all_changes()
+        .map(|change| DiffLine {
+            change: match change.tag() {
+                ChangeTag::Equal => LineChange::Equal,
+                ChangeTag::Insert => LineChange::Insert,
+                ChangeTag::Delete => LineChange::Delete,
+            },
+            text:   change.value().trim_end_matches(&['\r', '\n'][..]).to_owned(),
+        })
+        .collect()
+}
+
+/// Ensure that some non-empty text ends with a newline.
+fn terminate_line(text: &str) -> String {
+    if text.is_empty() || text.ends_with('\n') {
+        text.to_owned()
+    } else {
+        format!("{}\n", text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use serde_json::json;
+
+    use super::*;
+
+    fn line(change: LineChange, text: &str) -> DiffLine {
+        DiffLine {
+            change,
+            text: text.to_owned(),
+        }
+    }
+
+    #[test]
+    fn diff_identical() {
+        let snapshot = json!({"title": "Gondor", "body": "A kingdom"});
+
+        check!(diff_snapshots(&snapshot, &snapshot) == vec![]);
+    }
+
+    #[test]
+    fn diff_multiline_text() {
+        let old = json!({"title": "Gondor", "body": "First line\nSecond line\nThird line\n"});
+        let new = json!({"title": "Gondor", "body": "First line\nChanged line\nThird line\n"});
+
+        check!(
+            diff_snapshots(&old, &new)
+                == vec![FieldDiff {
+                    field: "body".to_owned(),
+                    lines: vec![
+                        line(LineChange::Equal, "First line"),
+                        line(LineChange::Delete, "Second line"),
+                        line(LineChange::Insert, "Changed line"),
+                        line(LineChange::Equal, "Third line"),
+                    ],
+                }]
+        );
+    }
+
+    #[test]
+    fn diff_added_and_removed_fields() {
+        let old = json!({"title": "Gondor", "category": "Places"});
+        let new = json!({"title": "Gondor", "category": null, "tags": ["kingdom"]});
+
+        check!(
+            diff_snapshots(&old, &new)
+                == vec![
+                    FieldDiff {
+                        field: "category".to_owned(),
+                        lines: vec![line(LineChange::Delete, "Places")],
+                    },
+                    FieldDiff {
+                        field: "tags".to_owned(),
+                        lines: vec![
+                            line(LineChange::Insert, "["),
+                            line(LineChange::Insert, "  \"kingdom\""),
+                            line(LineChange::Insert, "]"),
+                        ],
+                    },
+                ]
+        );
+    }
+}
@@ -0,0 +1,102 @@
+//! Building blocks for the revision endpoints of the components that own revisable resources.
+
+mod model;
+
+use std::fmt::Display;
+
+pub use model::*;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use super::{Revisable, RevisionService};
+use crate::http::{
+    problem::{Problem, NOT_FOUND},
+    response::Response,
+};
+
+/// Query parameters for diffing two revisions.
+#[derive(Deserialize)]
+pub struct DiffQuery {
+    /// The version to compare against, instead of the previous revision.
+    pub from: Option<String>,
+}
+
+/// Parse a version from the URL.
+///
+/// # Parameters
+/// - `version` - The version to parse
+///
+/// # Returns
+/// The version, or a Not Found problem if it isn't valid.
+pub fn parse_version(version: &str) -> Result<Uuid, Problem> {
+    Uuid::parse_str(version.trim()).map_err(|e| {
+        tracing::warn!(e = ?e, version = ?version, "Failed to parse version");
+
+        NOT_FOUND.into()
+    })
+}
+
+/// List the revisions of a resource.
+///
+/// # Parameters
+/// - `service` - The revision service
+/// - `resource_id` - The ID of the resource
+pub async fn list_revisions<D>(service: &RevisionService, resource_id: &dyn Display) -> Response<RevisionsModel>
+where
+    D: Revisable,
+{
+    let revisions = service
+        .get_revisions::<D>(resource_id)
+        .await
+        .into_iter()
+        .rev()
+        .map(RevisionModel::from)
+        .collect();
+
+    Response(RevisionsModel { revisions })
+}
+
+/// Get a single revision of a resource, including the snapshot of the resource at that point.
+///
+/// # Parameters
+/// - `service` - The revision service
+/// - `resource_id` - The ID of the resource
+/// - `version` - The version from the URL
+pub async fn get_revision<D>(
+    service: &RevisionService,
+    resource_id: &dyn Display,
+    version: &str,
+) -> Result<Response<RevisionDetailModel>, Problem>
+where
+    D: Revisable,
+{
+    let version = parse_version(version)?;
+
+    let revision = service.get_revision::<D>(resource_id, &version).await.ok_or(NOT_FOUND)?;
+
+    Ok(Response(revision.into()))
+}
+
+/// Diff a revision of a resource against either the previous revision or a specific one.
+///
+/// # Parameters
+/// - `service` - The revision service
+/// - `resource_id` - The ID of the resource
+/// - `version` - The version from the URL
+/// - `query` - The query string, optionally containing the version to compare against
+pub async fn diff_revisions<D>(
+    service: &RevisionService,
+    resource_id: &dyn Display,
+    version: &str,
+    query: &DiffQuery,
+) -> Result<Response<DiffModel>, Problem>
+where
+    D: Revisable,
+{
+    let version = parse_version(version)?;
+    let from = query.from.as_deref().map(parse_version).transpose()?;
+
+    let fields = service.diff::<D>(resource_id, &version, from.as_ref()).await.ok_or(NOT_FOUND)?;
+
+    Ok(Response(DiffModel { version, from, fields }))
+}
@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    revisions::{FieldDiff, Revision},
+    users::UserId,
+};
+
+/// Representation of a single revision on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevisionModel {
+    pub version:    Uuid,
+    pub changed_by: Option<UserId>,
+    pub changed_at: DateTime<Utc>,
+}
+
+impl From<Revision> for RevisionModel {
+    fn from(revision: Revision) -> Self {
+        Self {
+            version:    revision.version,
+            changed_by: revision.changed_by,
+            changed_at: revision.changed_at,
+        }
+    }
+}
+
+/// Representation of a list of revisions on the HTTP API.
+#[derive(Serialize)]
+pub struct RevisionsModel {
+    pub revisions: Vec<RevisionModel>,
+}
+
+/// Representation of a single revision on the HTTP API, including the state of the resource.
+#[derive(Serialize)]
+pub struct RevisionDetailModel {
+    #[serde(flatten)]
+    pub revision: RevisionModel,
+    pub snapshot: serde_json::Value,
+}
+
+impl From<Revision> for RevisionDetailModel {
+    fn from(revision: Revision) -> Self {
+        let snapshot = revision.snapshot.clone();
+
+        Self {
+            revision: revision.into(),
+            snapshot,
+        }
+    }
+}
+
+/// Representation of the differences between two revisions on the HTTP API.
+#[derive(Serialize)]
+pub struct DiffModel {
+    pub version: Uuid,
+    pub from:    Option<Uuid>,
+    pub fields:  Vec<FieldDiff>,
+}
@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use serde::{de::DeserializeOwned, Serialize};
+use uuid::Uuid;
+
+use crate::users::UserId;
+
+/// Trait for the data of any resource that should have a history of revisions kept.
+pub trait Revisable {
+    /// The type of resource, which keeps the revisions of different types of resource apart.
+    const RESOURCE_TYPE: &'static str;
+
+    /// The representation of the resource that is stored in each revision. This should contain
+    /// only the fields that are edited by users, and nothing sensitive.
+    type Snapshot: Serialize + DeserializeOwned;
+
+    /// Produce the snapshot of the resource to store in a revision.
+    fn snapshot(&self) -> Self::Snapshot;
+
+    /// Restore the resource to the state in a previous snapshot.
+    ///
+    /// # Parameters
+    /// - `snapshot` - The snapshot to restore
+    ///
+    /// # Returns
+    /// The resource data with the snapshot applied to it.
+    fn restore(self, snapshot: Self::Snapshot) -> Self;
+}
+
+/// A single revision of some resource, recording the state of it at a particular version.
+#[derive(Debug, Clone)]
+pub struct Revision {
+    pub resource_type: String,
+    pub resource_id:   String,
+    pub version:       Uuid,
+    pub changed_by:    Option<UserId>,
+    pub changed_at:    DateTime<Utc>,
+    pub snapshot:      serde_json::Value,
+}
@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod memory;
+mod parse;
+mod postgres;
+
+#[cfg(test)]
+pub use memory::MemoryRevisionRepository;
+pub use postgres::PostgresRevisionRepository;
+use uuid::Uuid;
+
+use super::Revision;
+
+/// Repository of resource revisions.
+#[async_trait::async_trait]
+pub trait RevisionRepository: Send + Sync {
+    /// Record a new revision. Recording the same version of the same resource again does nothing.
+    ///
+    /// # Parameters
+    /// - `revision` - The revision to record.
+    async fn record_revision(&self, revision: &Revision);
+
+    /// Get all of the revisions of a single resource.
+    ///
+    /// # Parameters
+    /// - `resource_type` - The type of the resource.
+    /// - `resource_id` - The ID of the resource.
+    ///
+    /// # Returns
+    /// The revisions, oldest first.
+    async fn get_revisions(&self, resource_type: &str, resource_id: &str) -> Vec<Revision>;
+
+    /// Get a single revision of a resource.
+    ///
+    /// # Parameters
+    /// - `resource_type` - The type of the resource.
+    /// - `resource_id` - The ID of the resource.
+    /// - `version` - The version of the resource.
+    ///
+    /// # Returns
+    /// The revision, or `None` if it couldn't be found.
+    async fn get_revision(&self, resource_type: &str, resource_id: &str, version: &Uuid) -> Option<Revision>;
+}
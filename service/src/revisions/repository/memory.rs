@@ -0,0 +1,55 @@
+use std::sync::Mutex;
+
+use uuid::Uuid;
+
+use super::RevisionRepository;
+use crate::revisions::Revision;
+
+/// Repository of resource revisions that are stored in memory.
+#[derive(Default)]
+pub struct MemoryRevisionRepository {
+    revisions: Mutex<Vec<Revision>>,
+}
+
+impl MemoryRevisionRepository {
+    /// Create a new, empty revision repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl RevisionRepository for MemoryRevisionRepository {
+    async fn record_revision(&self, revision: &Revision) {
+        let mut revisions = self.revisions.lock().unwrap();
+
+        let exists = revisions
+            .iter()
+            .any(|r| r.resource_type == revision.resource_type && r.resource_id == revision.resource_id && r.version == revision.version);
+        if !exists {
+            revisions.push(revision.clone());
+        }
+    }
+
+    async fn get_revisions(&self, resource_type: &str, resource_id: &str) -> Vec<Revision> {
+        let revisions = self.revisions.lock().unwrap();
+
+        let mut result: Vec<Revision> = revisions
+            .iter()
+            .filter(|r| r.resource_type == resource_type && r.resource_id == resource_id)
+            .cloned()
+            .collect();
+        result.sort_by_key(|r| r.changed_at);
+
+        result
+    }
+
+    async fn get_revision(&self, resource_type: &str, resource_id: &str, version: &Uuid) -> Option<Revision> {
+        let revisions = self.revisions.lock().unwrap();
+
+        revisions
+            .iter()
+            .find(|r| r.resource_type == resource_type && r.resource_id == resource_id && &r.version == version)
+            .cloned()
+    }
+}
@@ -0,0 +1,16 @@
+use tokio_postgres::Row;
+
+use crate::revisions::Revision;
+
+impl From<Row> for Revision {
+    fn from(row: Row) -> Self {
+        Revision {
+            resource_type: row.get("resource_type"),
+            resource_id:   row.get("resource_id"),
+            version:       row.get("version"),
+            changed_by:    row.get("changed_by"),
+            changed_at:    row.get("changed_at"),
+            snapshot:      row.get("snapshot"),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use super::RevisionRepository;
+use crate::{database::Database, revisions::Revision};
+
+/// Repository of resource revisions that are stored in Postgres.
+pub struct PostgresRevisionRepository {
+    database: Arc<Database>,
+}
+
+impl PostgresRevisionRepository {
+    /// Create a new revision repository.
+    pub fn new(database: Arc<Database>) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait::async_trait]
+impl RevisionRepository for PostgresRevisionRepository {
+    #[tracing::instrument(skip(self))]
+    async fn record_revision(&self, revision: &Revision) {
+        let conn = self.database.connect().await;
+        if let Err(e) = conn
+            .execute(
+                "INSERT INTO revisions(resource_type, resource_id, version, changed_by, changed_at, snapshot) VALUES ($1, $2, $3, $4, $5, $6) ON CONFLICT DO NOTHING",
+                &[
+                    &revision.resource_type,
+                    &revision.resource_id,
+                    &revision.version,
+                    &revision.changed_by,
+                    &revision.changed_at,
+                    &revision.snapshot,
+                ],
+            )
+            .await
+        {
+            tracing::warn!(e = ?e, "Failed to record revision");
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_revisions(&self, resource_type: &str, resource_id: &str) -> Vec<Revision> {
+        let conn = self.database.connect().await;
+        conn.query(
+            "SELECT * FROM revisions WHERE resource_type = $1 AND resource_id = $2 ORDER BY changed_at ASC",
+            &[&resource_type, &resource_id],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to load revisions");
+                vec![]
+            },
+            |rows| rows.into_iter().map(Revision::from).collect(),
+        )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_revision(&self, resource_type: &str, resource_id: &str, version: &Uuid) -> Option<Revision> {
+        let conn = self.database.connect().await;
+        conn.query_opt(
+            "SELECT * FROM revisions WHERE resource_type = $1 AND resource_id = $2 AND version = $3",
+            &[&resource_type, &resource_id, &version],
+        )
+        .await
+        .ok()?
+        .map(Revision::from)
+    }
+}
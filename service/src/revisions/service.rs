@@ -0,0 +1,271 @@
+use std::{fmt::Display, sync::Arc};
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::{diff_snapshots, repository::RevisionRepository, FieldDiff, Revisable, Revision};
+use crate::{model::Resource, users::UserId};
+
+/// Service layer for working with the revision history of resources.
+pub struct RevisionService {
+    repository: Arc<dyn RevisionRepository>,
+}
+
+impl RevisionService {
+    /// Create a new revision service.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store revisions in
+    pub fn new(repository: Arc<dyn RevisionRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Record the current state of a resource as a new revision.
+    /// If nothing in the snapshot has changed since the latest revision then nothing is recorded,
+    /// so that changes to fields outside of the snapshot don't clutter the history.
+    ///
+    /// # Parameters
+    /// - `resource` - The resource to record
+    /// - `changed_by` - The user that made the change
+    #[tracing::instrument(skip(self, resource))]
+    pub async fn record<I, D>(&self, resource: &Resource<I, D>, changed_by: &UserId)
+    where
+        I: Display,
+        D: Revisable,
+    {
+        let resource_id = resource.identity.id.to_string();
+        let snapshot = match serde_json::to_value(resource.data.snapshot()) {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                tracing::warn!(e = ?e, resource_id = ?resource_id, "Failed to serialize snapshot");
+                return;
+            },
+        };
+
+        let latest = self.repository.get_revisions(D::RESOURCE_TYPE, &resource_id).await.pop();
+        if latest.map(|r| r.snapshot) == Some(snapshot.clone()) {
+            return;
+        }
+
+        self.repository
+            .record_revision(&Revision {
+                resource_type: D::RESOURCE_TYPE.to_owned(),
+                resource_id,
+                version: resource.identity.version,
+                changed_by: Some(changed_by.clone()),
+                changed_at: Utc::now(),
+                snapshot,
+            })
+            .await;
+    }
+
+    /// Get all of the revisions of a resource.
+    ///
+    /// # Parameters
+    /// - `resource_id` - The ID of the resource
+    ///
+    /// # Returns
+    /// The revisions, oldest first.
+    pub async fn get_revisions<D>(&self, resource_id: &dyn Display) -> Vec<Revision>
+    where
+        D: Revisable,
+    {
+        self.repository.get_revisions(D::RESOURCE_TYPE, &resource_id.to_string()).await
+    }
+
+    /// Get a single revision of a resource.
+    ///
+    /// # Parameters
+    /// - `resource_id` - The ID of the resource
+    /// - `version` - The version of the resource
+    ///
+    /// # Returns
+    /// The revision, or `None` if there is no such revision.
+    pub async fn get_revision<D>(&self, resource_id: &dyn Display, version: &Uuid) -> Option<Revision>
+    where
+        D: Revisable,
+    {
+        self.repository
+            .get_revision(D::RESOURCE_TYPE, &resource_id.to_string(), version)
+            .await
+    }
+
+    /// Get the snapshot that was stored in a single revision of a resource, ready to restore.
+    ///
+    /// # Parameters
+    /// - `resource_id` - The ID of the resource
+    /// - `version` - The version of the resource
+    ///
+    /// # Returns
+    /// The snapshot, or `None` if there is no such revision.
+    pub async fn get_snapshot<D>(&self, resource_id: &dyn Display, version: &Uuid) -> Option<D::Snapshot>
+    where
+        D: Revisable,
+    {
+        let revision = self.get_revision::<D>(resource_id, version).await?;
+
+        serde_json::from_value(revision.snapshot)
+            .map_err(|e| {
+                tracing::warn!(e = ?e, version = ?version, "Failed to deserialize snapshot");
+            })
+            .ok()
+    }
+
+    /// Produce the differences between two revisions of a resource.
+    ///
+    /// # Parameters
+    /// - `resource_id` - The ID of the resource
+    /// - `version` - The version of the resource to show the changes in
+    /// - `from` - The version of the resource to compare against. If not provided then the revision
+    ///   before `version` is used, so that the diff shows what changed in that revision.
+    ///
+    /// # Returns
+    /// The differences, or `None` if either of the revisions doesn't exist.
+    pub async fn diff<D>(&self, resource_id: &dyn Display, version: &Uuid, from: Option<&Uuid>) -> Option<Vec<FieldDiff>>
+    where
+        D: Revisable,
+    {
+        let revisions = self.get_revisions::<D>(resource_id).await;
+
+        let index = revisions.iter().position(|r| &r.version == version)?;
+        let old = match from {
+            Some(from) => revisions.iter().find(|r| &r.version == from)?.snapshot.clone(),
+            None if index > 0 => revisions[index - 1].snapshot.clone(),
+            None => serde_json::Value::Null,
+        };
+
+        Some(diff_snapshots(&old, &revisions[index].snapshot))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert2::{check, let_assert};
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::{
+        model::Identity,
+        revisions::{DiffLine, LineChange, MemoryRevisionRepository},
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Note {
+        text:   String,
+        secret: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct NoteSnapshot {
+        text: String,
+    }
+
+    impl Revisable for Note {
+        const RESOURCE_TYPE: &'static str = "note";
+        type Snapshot = NoteSnapshot;
+
+        fn snapshot(&self) -> Self::Snapshot {
+            NoteSnapshot { text: self.text.clone() }
+        }
+
+        fn restore(self, snapshot: Self::Snapshot) -> Self {
+            Self {
+                text: snapshot.text,
+                ..self
+            }
+        }
+    }
+
+    fn note(id: &str, text: &str, secret: &str) -> Resource<String, Note> {
+        Resource {
+            identity: Identity {
+                id: id.to_owned(),
+                ..Identity::default()
+            },
+            data:     Note {
+                text:   text.to_owned(),
+                secret: secret.to_owned(),
+            },
+        }
+    }
+
+    #[actix_rt::test]
+    async fn record_and_list() {
+        let sut = RevisionService::new(Arc::new(MemoryRevisionRepository::new()));
+        let user_id = UserId::default();
+
+        let first = note("a", "Hello", "1");
+        let second = note("a", "Hello\nWorld", "1");
+        sut.record(&first, &user_id).await;
+        sut.record(&second, &user_id).await;
+        sut.record(&note("b", "Other", "1"), &user_id).await;
+
+        let revisions = sut.get_revisions::<Note>(&"a").await;
+        check!(revisions.len() == 2);
+        check!(revisions[0].version == first.identity.version);
+        check!(revisions[1].version == second.identity.version);
+        check!(revisions[1].changed_by == Some(user_id));
+        check!(revisions[1].snapshot == serde_json::json!({"text": "Hello\nWorld"}));
+    }
+
+    #[actix_rt::test]
+    async fn record_unchanged_snapshot() {
+        let sut = RevisionService::new(Arc::new(MemoryRevisionRepository::new()));
+        let user_id = UserId::default();
+
+        sut.record(&note("a", "Hello", "1"), &user_id).await;
+        sut.record(&note("a", "Hello", "2"), &user_id).await;
+
+        check!(sut.get_revisions::<Note>(&"a").await.len() == 1);
+    }
+
+    #[actix_rt::test]
+    async fn snapshot_and_restore() {
+        let sut = RevisionService::new(Arc::new(MemoryRevisionRepository::new()));
+        let first = note("a", "Hello", "1");
+        sut.record(&first, &UserId::default()).await;
+
+        let_assert!(Some(snapshot) = sut.get_snapshot::<Note>(&"a", &first.identity.version).await);
+        let restored = note("a", "Changed", "2").data.restore(snapshot);
+        check!(
+            restored
+                == Note {
+                    text:   "Hello".to_owned(),
+                    secret: "2".to_owned(),
+                }
+        );
+    }
+
+    #[actix_rt::test]
+    async fn diff_previous() {
+        let sut = RevisionService::new(Arc::new(MemoryRevisionRepository::new()));
+        let user_id = UserId::default();
+        let first = note("a", "Hello", "1");
+        let second = note("a", "Hello\nWorld", "1");
+        sut.record(&first, &user_id).await;
+        sut.record(&second, &user_id).await;
+
+        let_assert!(Some(diff) = sut.diff::<Note>(&"a", &second.identity.version, None).await);
+        check!(diff.len() == 1);
+        check!(
+            diff[0].lines
+                == vec![
+                    DiffLine {
+                        change: LineChange::Equal,
+                        text:   "Hello".to_owned(),
+                    },
+                    DiffLine {
+                        change: LineChange::Insert,
+                        text:   "World".to_owned(),
+                    },
+                ]
+        );
+
+        let_assert!(Some(diff) = sut.diff::<Note>(&"a", &first.identity.version, None).await);
+        check!(diff[0].lines[0].change == LineChange::Insert);
+
+        check!(sut.diff::<Note>(&"a", &first.identity.version, Some(&Uuid::new_v4())).await == None);
+    }
+}
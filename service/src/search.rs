@@ -0,0 +1,10 @@
+pub mod component;
+mod endpoints;
+mod highlight;
+mod model;
+mod repository;
+mod service;
+
+pub use model::*;
+pub use repository::*;
+pub use service::*;
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use actix_web::web::{get, resource, ServiceConfig};
+
+use super::{repository::SearchRepository, service::SearchService};
+use crate::server::RouteConfigurer;
+
+/// Component for searching the content of worlds.
+pub struct Component {
+    pub service: Arc<SearchService>,
+}
+
+impl Component {
+    /// Create a new search component.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository that holds the search index
+    pub fn new(repository: Arc<dyn SearchRepository>) -> Arc<Self> {
+        let service = Arc::new(SearchService::new(repository));
+
+        Arc::new(Self { service })
+    }
+}
+
+impl RouteConfigurer for Component {
+    fn configure_routes(&self, config: &mut ServiceConfig) {
+        config.data(self.service.clone());
+
+        config.service(resource("/worlds/{id}/search").route(get().to(super::endpoints::search::handle)));
+    }
+}
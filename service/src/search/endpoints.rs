@@ -0,0 +1,3 @@
+mod model;
+mod problems;
+pub(super) mod search;
@@ -0,0 +1,61 @@
+use serde::Serialize;
+
+use crate::{
+    http::pagination::PageModel,
+    search::{Facet, SearchHit},
+};
+
+/// Representation of a single search hit on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchHitModel {
+    pub kind:        String,
+    pub resource_id: String,
+    pub title:       String,
+    /// An excerpt of the resource around the matching text, as HTML.
+    pub snippet:     String,
+    pub rank:        f32,
+}
+
+impl From<SearchHit> for SearchHitModel {
+    fn from(hit: SearchHit) -> Self {
+        Self {
+            kind:        hit.kind,
+            resource_id: hit.resource_id,
+            title:       hit.title,
+            snippet:     hit.snippet,
+            rank:        hit.rank,
+        }
+    }
+}
+
+/// Representation of the number of hits that share a value of a facet on the HTTP API.
+#[derive(Serialize)]
+pub struct FacetModel {
+    pub value: String,
+    pub count: u64,
+}
+
+impl From<Facet> for FacetModel {
+    fn from(facet: Facet) -> Self {
+        Self {
+            value: facet.value,
+            count: facet.count,
+        }
+    }
+}
+
+/// Representation of the facets of a search on the HTTP API.
+#[derive(Serialize)]
+pub struct FacetsModel {
+    pub kind: Vec<FacetModel>,
+    pub tag:  Vec<FacetModel>,
+}
+
+/// Representation of the results of a search on the HTTP API.
+#[derive(Serialize)]
+pub struct SearchResultsModel {
+    pub results: Vec<SearchHitModel>,
+    pub facets:  FacetsModel,
+    pub page:    PageModel,
+}
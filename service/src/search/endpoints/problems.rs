@@ -0,0 +1,10 @@
+use actix_http::http::StatusCode;
+
+use crate::http::problem::SimpleProblemType;
+
+/// Problem to indicate that a search was requested without anything to search for.
+pub const QUERY_REQUIRED: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/search/query_required",
+    problem_title: "Text to search for is required",
+    status_code:   StatusCode::BAD_REQUEST,
+};
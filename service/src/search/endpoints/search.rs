@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path, Query};
+use serde::Deserialize;
+
+use super::{
+    model::{FacetModel, FacetsModel, SearchHitModel, SearchResultsModel},
+    problems::QUERY_REQUIRED,
+};
+use crate::{
+    authorization::Authentication,
+    http::{
+        pagination::{PageModel, PageQuery},
+        problem::{Problem, NOT_FOUND},
+        response::Response,
+    },
+    search::{SearchQuery, SearchService},
+    tokens::Scope,
+    worlds::{Role, WorldId, WorldService},
+};
+
+/// Query parameters for searching a world.
+#[derive(Deserialize)]
+pub struct SearchRequest {
+    /// The text to search for. Quoted phrases, `or` and `-` to exclude words are supported.
+    pub q:    Option<String>,
+    /// Only include these kinds of resource, separated by commas.
+    pub kind: Option<String>,
+    /// Only include resources with all of these tags, separated by commas.
+    pub tag:  Option<String>,
+}
+
+/// Search the articles and entities of a world.
+pub async fn handle(
+    service: Data<Arc<SearchService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<String>,
+    query: Query<SearchRequest>,
+    page: Query<PageQuery>,
+    authentication: Authentication,
+) -> Result<Response<SearchResultsModel>, Problem> {
+    let world_id: WorldId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let query = query.into_inner();
+    let text = query.q.as_deref().map(str::trim).filter(|q| !q.is_empty()).ok_or(QUERY_REQUIRED)?;
+    let kinds = split_list(query.kind.as_deref());
+    let tags = split_list(query.tag.as_deref());
+
+    let pagination = page.pagination();
+    let results = service
+        .search(
+            &world_id,
+            &SearchQuery {
+                text: text.to_owned(),
+                kinds,
+                tags,
+            },
+            pagination,
+        )
+        .await;
+
+    Ok(Response(SearchResultsModel {
+        results: results.hits.into_iter().map(SearchHitModel::from).collect(),
+        facets:  FacetsModel {
+            kind: results.kinds.into_iter().map(FacetModel::from).collect(),
+            tag:  results.tags.into_iter().map(FacetModel::from).collect(),
+        },
+        page:    PageModel::new(pagination, results.total),
+    }))
+}
+
+/// Split a comma-separated list from the query string.
+///
+/// # Parameters
+/// - `list` - The list, if it was provided
+///
+/// # Returns
+/// The non-blank values in the list.
+fn split_list(list: Option<&str>) -> Vec<String> {
+    list.map(|list| {
+        list.split(',')
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_owned)
+            .collect()
+    })
+    .unwrap_or_default()
+}
@@ -0,0 +1,72 @@
+use pulldown_cmark::escape::escape_html;
+
+/// Marks the start of a match within a snippet, before the snippet is converted to HTML.
+/// This is a private use character, so it never appears in real text.
+pub const START_MATCH: char = '\u{e000}';
+
+/// Marks the end of a match within a snippet, before the snippet is converted to HTML.
+pub const END_MATCH: char = '\u{e001}';
+
+/// Remove anything from some text that would be confused with the markers around matches.
+///
+/// # Parameters
+/// - `text` - The text to clean
+pub fn clean(text: &str) -> String {
+    text.chars().filter(|c| *c != START_MATCH && *c != END_MATCH).collect()
+}
+
+/// Convert a snippet, in which the matches are surrounded by the match markers, into HTML.
+///
+/// # Parameters
+/// - `snippet` - The snippet
+///
+/// # Returns
+/// The HTML, which is safe to include directly in a page.
+pub fn to_html(snippet: &str) -> String {
+    let mut escaped = String::new();
+    escape_html(&mut escaped, snippet).unwrap();
+
+    let mut result = String::new();
+    let mut open = false;
+    for c in escaped.chars() {
+        match c {
+            START_MATCH if !open => {
+                result.push_str("<mark>");
+                open = true;
+            },
+            END_MATCH if open => {
+                result.push_str("</mark>");
+                open = false;
+            },
+            START_MATCH | END_MATCH => {},
+            c => result.push(c),
+        }
+    }
+    if open {
+        result.push_str("</mark>");
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("No matches", "No matches" ; "Plain")]
+    #[test_case("The \u{e000}White\u{e001} City", "The <mark>White</mark> City" ; "Single match")]
+    #[test_case("<b>\u{e000}Fish & Chips\u{e001}</b>", "&lt;b&gt;<mark>Fish &amp; Chips</mark>&lt;/b&gt;" ; "Escaped")]
+    #[test_case("\u{e000}Unclosed", "<mark>Unclosed</mark>" ; "Unclosed")]
+    #[test_case("Stray\u{e001} end", "Stray end" ; "Stray end")]
+    fn test_to_html(snippet: &str, expected: &str) {
+        check!(to_html(snippet) == expected);
+    }
+
+    #[test]
+    fn test_clean() {
+        check!(clean("A \u{e000}sneaky\u{e001} title") == "A sneaky title");
+    }
+}
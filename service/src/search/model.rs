@@ -0,0 +1,114 @@
+use uuid::Uuid;
+
+use crate::worlds::WorldId;
+
+/// Trait for the data of any resource that can be found by searching the world that it belongs to.
+pub trait Searchable {
+    /// The kind of resource, which search results are faceted and filtered by.
+    const SEARCH_KIND: &'static str;
+
+    /// The world that the resource belongs to.
+    fn search_world(&self) -> &WorldId;
+
+    /// The title of the resource, which is weighted above the rest of the text.
+    fn search_title(&self) -> &str;
+
+    /// All of the rest of the text of the resource, as plain text.
+    fn search_body(&self) -> String;
+}
+
+/// A single resource, as it is stored in the search index.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchDocument {
+    pub world_id:    WorldId,
+    pub kind:        String,
+    pub resource_id: String,
+    pub title:       String,
+    pub body:        String,
+}
+
+/// A search of the content of a world.
+#[derive(Debug, Clone, Default)]
+pub struct SearchQuery {
+    /// The text to search for, using the syntax of web search engines.
+    pub text:  String,
+    /// Only include resources of these kinds. All kinds are included if this is empty.
+    pub kinds: Vec<String>,
+    /// Only include resources that have every one of these tags, ignoring case.
+    pub tags:  Vec<String>,
+}
+
+/// A single resource that matched a search.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit {
+    pub kind:        String,
+    pub resource_id: String,
+    pub title:       String,
+    /// An excerpt of the resource around the matching text, as HTML in which the matches are
+    /// wrapped in `<mark>` elements.
+    pub snippet:     String,
+    /// How well the resource matched the search, relative to the other hits.
+    pub rank:        f32,
+}
+
+/// The number of hits that share a single value of some facet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Facet {
+    pub value: String,
+    pub count: u64,
+}
+
+/// The results of a search.
+#[derive(Debug, Clone, Default)]
+pub struct SearchResults {
+    /// The requested page of hits, with the best matches first.
+    pub hits:  Vec<SearchHit>,
+    /// The number of hits across all pages.
+    pub total: u64,
+    /// The number of hits of each kind of resource, regardless of which kinds were searched for.
+    pub kinds: Vec<Facet>,
+    /// The number of hits with each tag, regardless of which tags were searched for.
+    pub tags:  Vec<Facet>,
+}
+
+/// Collect all of the text within some JSON, so that it can be searched.
+/// Strings that are IDs of other resources aren't text that anybody would search for, so are
+/// skipped.
+///
+/// # Parameters
+/// - `value` - The JSON to collect the text from
+/// - `into` - The list to add the text to
+pub fn json_text(value: &serde_json::Value, into: &mut Vec<String>) {
+    match value {
+        serde_json::Value::String(text) if !text.trim().is_empty() && text.parse::<Uuid>().is_err() => into.push(text.clone()),
+        serde_json::Value::Array(values) => values.iter().for_each(|value| json_text(value, into)),
+        serde_json::Value::Object(values) => values.values().for_each(|value| json_text(value, into)),
+        _ => {},
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn collect_json_text() {
+        let value = json!({
+            "title": "King of Gondor",
+            "parent": "0d6b0bb8-1f5f-4b3c-9f4b-1c1f3e0e2a8f",
+            "aliases": ["Strider", "Elessar", ""],
+            "born": {
+                "date": "TA 2931",
+                "age": 87
+            }
+        });
+
+        let mut text = vec![];
+        json_text(&value, &mut text);
+
+        check!(text == vec!["King of Gondor", "Strider", "Elessar", "TA 2931"]);
+    }
+}
@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod memory;
+mod parse;
+mod postgres;
+
+#[cfg(test)]
+pub use memory::MemorySearchRepository;
+pub use postgres::PostgresSearchRepository;
+
+use super::{SearchDocument, SearchQuery, SearchResults};
+use crate::{http::pagination::Pagination, worlds::WorldId};
+
+/// Repository of the search index of the content of worlds.
+#[async_trait::async_trait]
+pub trait SearchRepository: Send + Sync {
+    /// Add a document to the index, replacing any previous version of it.
+    ///
+    /// # Parameters
+    /// - `document` - The document to index.
+    async fn index_document(&self, document: &SearchDocument);
+
+    /// Remove a document from the index.
+    ///
+    /// # Parameters
+    /// - `resource_id` - The ID of the resource that the document is for.
+    async fn remove_document(&self, resource_id: &str);
+
+    /// Replace the names of the tags that a document is filed under.
+    /// These are kept when the document itself is indexed again.
+    ///
+    /// # Parameters
+    /// - `resource_id` - The ID of the resource that the document is for.
+    /// - `tags` - The names of the tags.
+    async fn tag_document(&self, resource_id: &str, tags: &[String]);
+
+    /// Search the documents of a world.
+    ///
+    /// The snippets of the hits have the matching text surrounded by the markers from the
+    /// `highlight` module, rather than being HTML.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world.
+    /// - `query` - The search to perform.
+    /// - `pagination` - The page of hits to return.
+    ///
+    /// # Returns
+    /// The results of the search.
+    async fn search(&self, world_id: &WorldId, query: &SearchQuery, pagination: Pagination) -> SearchResults;
+}
@@ -0,0 +1,145 @@
+use std::{collections::HashMap, convert::TryFrom, sync::Mutex};
+
+use super::SearchRepository;
+use crate::{
+    http::pagination::Pagination,
+    search::{
+        highlight::{END_MATCH, START_MATCH},
+        Facet, SearchDocument, SearchHit, SearchQuery, SearchResults,
+    },
+    worlds::WorldId,
+};
+
+/// Repository of the search index that is stored in memory.
+///
+/// This matches documents that contain every word of the query, ignoring case, without any
+/// stemming or query syntax.
+#[derive(Default)]
+pub struct MemorySearchRepository {
+    documents: Mutex<Vec<SearchDocument>>,
+    /// The names of the tags of each document, by resource ID.
+    tags:      Mutex<HashMap<String, Vec<String>>>,
+}
+
+impl MemorySearchRepository {
+    /// Create a new, empty search repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl SearchRepository for MemorySearchRepository {
+    async fn index_document(&self, document: &SearchDocument) {
+        let mut documents = self.documents.lock().unwrap();
+
+        documents.retain(|d| d.resource_id != document.resource_id);
+        documents.push(document.clone());
+    }
+
+    async fn remove_document(&self, resource_id: &str) {
+        self.documents.lock().unwrap().retain(|d| d.resource_id != resource_id);
+        self.tags.lock().unwrap().remove(resource_id);
+    }
+
+    async fn tag_document(&self, resource_id: &str, tags: &[String]) {
+        self.tags.lock().unwrap().insert(resource_id.to_owned(), tags.to_vec());
+    }
+
+    async fn search(&self, world_id: &WorldId, query: &SearchQuery, pagination: Pagination) -> SearchResults {
+        let documents = self.documents.lock().unwrap();
+        let words: Vec<String> = query.text.split_whitespace().map(str::to_lowercase).collect();
+        if words.is_empty() {
+            return SearchResults::default();
+        }
+
+        let mut matches: Vec<(&SearchDocument, f32)> = documents
+            .iter()
+            .filter(|d| &d.world_id == world_id)
+            .filter_map(|d| {
+                let title = d.title.to_lowercase();
+                let body = d.body.to_lowercase();
+                if !words.iter().all(|w| title.contains(w) || body.contains(w)) {
+                    return None;
+                }
+
+                let rank = words.iter().map(|w| if title.contains(w) { 2.0 } else { 1.0 }).sum();
+                Some((d, rank))
+            })
+            .collect();
+        matches.sort_by(|(a, a_rank), (b, b_rank)| b_rank.partial_cmp(a_rank).unwrap().then_with(|| a.title.cmp(&b.title)));
+
+        let tags = self.tags.lock().unwrap();
+        let no_tags = vec![];
+        let tags_of = |d: &SearchDocument| tags.get(&d.resource_id).unwrap_or(&no_tags);
+        let wanted: Vec<String> = query.tags.iter().map(|t| t.to_lowercase()).collect();
+        let has_kind = |d: &SearchDocument| query.kinds.is_empty() || query.kinds.contains(&d.kind);
+        let has_tags = |d: &SearchDocument| wanted.iter().all(|w| tags_of(d).iter().any(|t| &t.to_lowercase() == w));
+
+        let kinds = facets(matches.iter().filter(|(d, _)| has_tags(d)).map(|(d, _)| vec![d.kind.clone()]));
+        let tag_facets = facets(matches.iter().filter(|(d, _)| has_kind(d)).map(|(d, _)| tags_of(d).clone()));
+
+        let matches: Vec<_> = matches.into_iter().filter(|(d, _)| has_kind(d) && has_tags(d)).collect();
+        let total = u64::try_from(matches.len()).unwrap_or_default();
+
+        let hits = pagination
+            .apply(matches)
+            .into_iter()
+            .map(|(d, rank)| SearchHit {
+                kind: d.kind.clone(),
+                resource_id: d.resource_id.clone(),
+                title: d.title.clone(),
+                snippet: mark(&d.body, &words),
+                rank,
+            })
+            .collect();
+
+        SearchResults {
+            hits,
+            total,
+            kinds,
+            tags: tag_facets,
+        }
+    }
+}
+
+/// Count how many documents share each value of a facet.
+///
+/// # Parameters
+/// - `values` - The values of the facet for each document
+///
+/// # Returns
+/// The facets, with the most common values first.
+fn facets<T>(values: T) -> Vec<Facet>
+where
+    T: Iterator<Item = Vec<String>>,
+{
+    let mut result: Vec<Facet> = vec![];
+    for value in values.flatten() {
+        match result.iter_mut().find(|f| f.value == value) {
+            Some(facet) => facet.count += 1,
+            None => result.push(Facet { value, count: 1 }),
+        }
+    }
+    result.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.value.cmp(&b.value)));
+
+    result
+}
+
+/// Surround every occurrence of the words within some text with the match markers.
+///
+/// # Parameters
+/// - `text` - The text
+/// - `words` - The lowercase words to mark
+fn mark(text: &str, words: &[String]) -> String {
+    text.split(' ')
+        .map(|token| {
+            if words.iter().any(|w| token.to_lowercase().contains(w)) {
+                format!("{}{}{}", START_MATCH, token, END_MATCH)
+            } else {
+                token.to_owned()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,26 @@
+use tokio_postgres::Row;
+
+use crate::search::{Facet, SearchHit};
+
+impl From<Row> for SearchHit {
+    fn from(row: Row) -> Self {
+        SearchHit {
+            kind:        row.get("kind"),
+            resource_id: row.get("resource_id"),
+            title:       row.get("title"),
+            snippet:     row.get("snippet"),
+            rank:        row.get("rank"),
+        }
+    }
+}
+
+impl From<Row> for Facet {
+    fn from(row: Row) -> Self {
+        let count: i64 = row.get("count");
+
+        Facet {
+            value: row.get("value"),
+            count: count.unsigned_abs(),
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use std::sync::Arc;
+
+use super::SearchRepository;
+use crate::{
+    database::Database,
+    http::pagination::Pagination,
+    search::{
+        highlight::{END_MATCH, START_MATCH},
+        Facet, SearchDocument, SearchHit, SearchQuery, SearchResults,
+    },
+    worlds::WorldId,
+};
+
+/// Repository of the search index that is stored in Postgres.
+///
+/// The text is stemmed using the text search configuration named after the language of the world
+/// that it belongs to.
+pub struct PostgresSearchRepository {
+    database: Arc<Database>,
+}
+
+impl PostgresSearchRepository {
+    /// Create a new search repository.
+    pub fn new(database: Arc<Database>) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait::async_trait]
+impl SearchRepository for PostgresSearchRepository {
+    #[tracing::instrument(skip(self))]
+    async fn index_document(&self, document: &SearchDocument) {
+        let conn = self.database.connect().await;
+        if let Err(e) = conn
+            .execute(
+                "INSERT INTO search_documents(resource_id, world_id, kind, title, body, document, updated)
+                SELECT $1, w.world_id, $3, $4, $5,
+                    setweight(to_tsvector(w.language::REGCONFIG, $4), 'A') || setweight(to_tsvector(w.language::REGCONFIG, $5), 'B'),
+                    now()
+                FROM worlds w WHERE w.world_id = $2
+                ON CONFLICT (resource_id) DO UPDATE SET
+                    kind = EXCLUDED.kind, title = EXCLUDED.title, body = EXCLUDED.body, document = EXCLUDED.document, updated = EXCLUDED.updated",
+                &[
+                    &document.resource_id,
+                    &document.world_id,
+                    &document.kind,
+                    &document.title,
+                    &document.body,
+                ],
+            )
+            .await
+        {
+            tracing::warn!(e = ?e, "Failed to index document");
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn remove_document(&self, resource_id: &str) {
+        let conn = self.database.connect().await;
+        if let Err(e) = conn
+            .execute("DELETE FROM search_documents WHERE resource_id = $1", &[&resource_id])
+            .await
+        {
+            tracing::warn!(e = ?e, "Failed to remove document from the search index");
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn tag_document(&self, resource_id: &str, tags: &[String]) {
+        let conn = self.database.connect().await;
+        if let Err(e) = conn
+            .execute(
+                "UPDATE search_documents SET tags = $2 WHERE resource_id = $1",
+                &[&resource_id, &tags],
+            )
+            .await
+        {
+            tracing::warn!(e = ?e, "Failed to tag document");
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn search(&self, world_id: &WorldId, query: &SearchQuery, pagination: Pagination) -> SearchResults {
+        let conn = self.database.connect().await;
+
+        let kinds = if query.kinds.is_empty() { None } else { Some(&query.kinds) };
+        let tags: Vec<String> = query.tags.iter().map(|t| t.to_lowercase()).collect();
+        let tags = if tags.is_empty() { None } else { Some(tags) };
+        let headline = format!(
+            "StartSel=\"{}\", StopSel=\"{}\", MaxFragments=2, MaxWords=30, MinWords=10, FragmentDelimiter=\" … \"",
+            START_MATCH, END_MATCH
+        );
+
+        // Each facet is counted with every filter except its own applied.
+        let kind_facets = conn
+            .query(
+                "SELECT d.kind AS value, COUNT(*) AS count
+                FROM search_documents d, worlds w
+                WHERE w.world_id = $1 AND d.world_id = w.world_id AND d.document @@ websearch_to_tsquery(w.language::REGCONFIG, $2)
+                    AND ($3::TEXT[] IS NULL OR ARRAY(SELECT LOWER(t) FROM unnest(d.tags) t) @> $3)
+                GROUP BY d.kind
+                ORDER BY count DESC, value",
+                &[&world_id, &query.text, &tags],
+            )
+            .await;
+        let tag_facets = conn
+            .query(
+                "SELECT t.tag AS value, COUNT(*) AS count
+                FROM worlds w
+                JOIN search_documents d ON d.world_id = w.world_id
+                CROSS JOIN LATERAL unnest(d.tags) AS t(tag)
+                WHERE w.world_id = $1 AND d.document @@ websearch_to_tsquery(w.language::REGCONFIG, $2)
+                    AND ($3::TEXT[] IS NULL OR d.kind = ANY($3))
+                GROUP BY t.tag
+                ORDER BY count DESC, value",
+                &[&world_id, &query.text, &kinds],
+            )
+            .await;
+        let (kind_facets, tag_facets): (Vec<Facet>, Vec<Facet>) = match (kind_facets, tag_facets) {
+            (Ok(kind_rows), Ok(tag_rows)) => (
+                kind_rows.into_iter().map(Facet::from).collect(),
+                tag_rows.into_iter().map(Facet::from).collect(),
+            ),
+            (Err(e), _) | (_, Err(e)) => {
+                tracing::warn!(e = ?e, "Failed to count search hits");
+
+                return SearchResults::default();
+            },
+        };
+
+        // The snippets are only generated for the page of hits, since they're expensive.
+        let hits = conn
+            .query(
+                "SELECT h.kind, h.resource_id, h.title, h.rank, ts_headline(h.config, h.body, h.query, $6) AS snippet
+                FROM (
+                    SELECT d.kind, d.resource_id, d.title, d.body, q.config, q.query, ts_rank_cd(d.document, q.query) AS rank
+                    FROM search_documents d,
+                        (SELECT w.language::REGCONFIG AS config, websearch_to_tsquery(w.language::REGCONFIG, $2) AS query FROM worlds w WHERE w.world_id = $1) q
+                    WHERE d.world_id = $1 AND d.document @@ q.query AND ($3::TEXT[] IS NULL OR d.kind = ANY($3))
+                        AND ($7::TEXT[] IS NULL OR ARRAY(SELECT LOWER(t) FROM unnest(d.tags) t) @> $7)
+                    ORDER BY rank DESC, d.title
+                    LIMIT $4 OFFSET $5
+                ) h
+                ORDER BY h.rank DESC, h.title",
+                &[
+                    &world_id,
+                    &query.text,
+                    &kinds,
+                    &i64::from(pagination.limit),
+                    &i64::from(pagination.offset),
+                    &headline,
+                    &tags,
+                ],
+            )
+            .await;
+        let hits = match hits {
+            Ok(rows) => rows.into_iter().map(SearchHit::from).collect(),
+            Err(e) => {
+                tracing::warn!(e = ?e, "Failed to search");
+
+                return SearchResults::default();
+            },
+        };
+
+        let total = kind_facets
+            .iter()
+            .filter(|facet| query.kinds.is_empty() || query.kinds.contains(&facet.value))
+            .map(|facet| facet.count)
+            .sum();
+
+        SearchResults {
+            hits,
+            total,
+            kinds: kind_facets,
+            tags: tag_facets,
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use std::{fmt::Display, sync::Arc};
+
+use super::{highlight, repository::SearchRepository, SearchDocument, SearchQuery, SearchResults, Searchable};
+use crate::{http::pagination::Pagination, model::Resource, worlds::WorldId};
+
+/// Service layer for searching the content of worlds.
+pub struct SearchService {
+    repository: Arc<dyn SearchRepository>,
+}
+
+impl SearchService {
+    /// Create a new search service.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository that holds the search index
+    pub fn new(repository: Arc<dyn SearchRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Add the current state of a resource to the search index, replacing whatever was indexed
+    /// for it before.
+    ///
+    /// # Parameters
+    /// - `resource` - The resource to index
+    #[tracing::instrument(skip(self, resource))]
+    pub async fn index<I, D>(&self, resource: &Resource<I, D>)
+    where
+        I: Display,
+        D: Searchable,
+    {
+        self.repository
+            .index_document(&SearchDocument {
+                world_id:    resource.data.search_world().clone(),
+                kind:        D::SEARCH_KIND.to_owned(),
+                resource_id: resource.identity.id.to_string(),
+                title:       highlight::clean(resource.data.search_title()),
+                body:        highlight::clean(&resource.data.search_body()),
+            })
+            .await;
+    }
+
+    /// Remove a resource from the search index.
+    ///
+    /// # Parameters
+    /// - `resource_id` - The ID of the resource
+    #[tracing::instrument(skip(self))]
+    pub async fn remove<I>(&self, resource_id: &I)
+    where
+        I: Display + std::fmt::Debug,
+    {
+        self.repository.remove_document(&resource_id.to_string()).await;
+    }
+
+    /// Replace the tags that a resource is filed under in the search index.
+    ///
+    /// # Parameters
+    /// - `resource_id` - The ID of the resource
+    /// - `tags` - The names of all of the tags of the resource
+    #[tracing::instrument(skip(self))]
+    pub async fn tag<I>(&self, resource_id: &I, tags: &[String])
+    where
+        I: Display + std::fmt::Debug,
+    {
+        self.repository.tag_document(&resource_id.to_string(), tags).await;
+    }
+
+    /// Search the content of a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world to search
+    /// - `query` - The search to perform
+    /// - `pagination` - The page of hits to return
+    ///
+    /// # Returns
+    /// The results of the search, with the best matches first.
+    pub async fn search(&self, world_id: &WorldId, query: &SearchQuery, pagination: Pagination) -> SearchResults {
+        let mut results = self.repository.search(world_id, query, pagination).await;
+
+        for hit in &mut results.hits {
+            hit.snippet = highlight::to_html(&hit.snippet);
+        }
+
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+
+    use super::*;
+    use crate::{
+        model::Identity,
+        search::{Facet, MemorySearchRepository},
+    };
+
+    struct Note {
+        world_id: WorldId,
+        title:    String,
+        body:     String,
+    }
+
+    impl Searchable for Note {
+        const SEARCH_KIND: &'static str = "note";
+
+        fn search_world(&self) -> &WorldId {
+            &self.world_id
+        }
+
+        fn search_title(&self) -> &str {
+            &self.title
+        }
+
+        fn search_body(&self) -> String {
+            self.body.clone()
+        }
+    }
+
+    fn note(world_id: &WorldId, title: &str, body: &str) -> Resource<WorldId, Note> {
+        Resource {
+            identity: Identity::default(),
+            data:     Note {
+                world_id: world_id.clone(),
+                title:    title.to_owned(),
+                body:     body.to_owned(),
+            },
+        }
+    }
+
+    fn query(text: &str) -> SearchQuery {
+        SearchQuery {
+            text: text.to_owned(),
+            ..SearchQuery::default()
+        }
+    }
+
+    #[actix_rt::test]
+    async fn search_world() {
+        let sut = SearchService::new(Arc::new(MemorySearchRepository::new()));
+        let world_id = WorldId::default();
+
+        sut.index(&note(&world_id, "Gondor", "The kingdom of <men> in the south")).await;
+        sut.index(&note(&world_id, "Rohan", "Horse lords, allied with Gondor")).await;
+        sut.index(&note(&world_id, "Mordor", "The land of shadow")).await;
+        sut.index(&note(&WorldId::default(), "Gondor", "Somewhere else entirely")).await;
+
+        let results = sut.search(&world_id, &query("gondor"), Pagination::default()).await;
+
+        check!(results.total == 2);
+        check!(results.hits.iter().map(|h| h.title.as_str()).collect::<Vec<_>>() == vec!["Gondor", "Rohan"]);
+        check!(results.hits[0].snippet == "The kingdom of &lt;men&gt; in the south");
+        check!(results.hits[1].snippet == "Horse lords, allied with <mark>Gondor</mark>");
+        check!(
+            results.kinds
+                == vec![Facet {
+                    value: "note".to_owned(),
+                    count: 2,
+                }]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn reindex_and_remove() {
+        let sut = SearchService::new(Arc::new(MemorySearchRepository::new()));
+        let world_id = WorldId::default();
+
+        let mut gondor = note(&world_id, "Gondor", "The kingdom of men");
+        sut.index(&gondor).await;
+
+        gondor.data.body = "The realm of the south".to_owned();
+        sut.index(&gondor).await;
+
+        check!(sut.search(&world_id, &query("kingdom"), Pagination::default()).await.total == 0);
+        check!(sut.search(&world_id, &query("realm"), Pagination::default()).await.total == 1);
+
+        sut.remove(&gondor.identity.id).await;
+
+        check!(sut.search(&world_id, &query("realm"), Pagination::default()).await.total == 0);
+    }
+
+    #[actix_rt::test]
+    async fn filter_and_page() {
+        let sut = SearchService::new(Arc::new(MemorySearchRepository::new()));
+        let world_id = WorldId::default();
+        for i in 0..5 {
+            sut.index(&note(&world_id, &format!("Ring {}", i), "Forged in secret")).await;
+        }
+
+        let results = sut.search(&world_id, &query("ring"), Pagination { offset: 3, limit: 10 }).await;
+        check!(results.total == 5);
+        check!(results.hits.iter().map(|h| h.title.as_str()).collect::<Vec<_>>() == vec!["Ring 3", "Ring 4"]);
+
+        let results = sut
+            .search(
+                &world_id,
+                &SearchQuery {
+                    text: "ring".to_owned(),
+                    kinds: vec!["character".to_owned()],
+                    ..SearchQuery::default()
+                },
+                Pagination::default(),
+            )
+            .await;
+        check!(results.total == 0);
+        check!(results.hits.is_empty());
+        check!(results.kinds.len() == 1);
+    }
+
+    #[actix_rt::test]
+    async fn filter_by_tag() {
+        let sut = SearchService::new(Arc::new(MemorySearchRepository::new()));
+        let world_id = WorldId::default();
+        let gondor = note(&world_id, "Gondor", "A kingdom of men");
+        let rohan = note(&world_id, "Rohan", "A kingdom of horse lords");
+        sut.index(&gondor).await;
+        sut.index(&rohan).await;
+
+        sut.tag(&gondor.identity.id, &["Realms".to_owned(), "Men".to_owned()]).await;
+        sut.tag(&rohan.identity.id, &["Realms".to_owned()]).await;
+        sut.index(&gondor).await;
+
+        let results = sut.search(&world_id, &query("kingdom"), Pagination::default()).await;
+        check!(results.total == 2);
+        check!(
+            results.tags
+                == vec![
+                    Facet {
+                        value: "Realms".to_owned(),
+                        count: 2,
+                    },
+                    Facet {
+                        value: "Men".to_owned(),
+                        count: 1,
+                    },
+                ]
+        );
+
+        let results = sut
+            .search(
+                &world_id,
+                &SearchQuery {
+                    text: "kingdom".to_owned(),
+                    tags: vec!["men".to_owned()],
+                    ..SearchQuery::default()
+                },
+                Pagination::default(),
+            )
+            .await;
+        check!(results.total == 1);
+        check!(results.hits[0].title == "Gondor");
+        check!(results.tags.len() == 2);
+    }
+}
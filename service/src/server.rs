@@ -11,11 +11,18 @@ use actix_web::{
     App, HttpServer,
 };
 
+use crate::{
+    http::negotiation::Negotiate,
+    idempotency::{Idempotency, IdempotencyService},
+};
+
 /// The HTTP Server.
 pub struct Server {
     port: u16,
 
-    pub(super) routes: Vec<Arc<dyn RouteConfigurer>>,
+    pub(super) routes:        Vec<Arc<dyn RouteConfigurer>>,
+    pub(super) idempotency:   Option<Arc<IdempotencyService>>,
+    pub(super) max_body_size: usize,
 }
 
 /// Trait that can be implemented by other components to configure routes into the HTTP Server.
@@ -29,8 +36,18 @@ pub trait RouteConfigurer: Send + Sync {
 
 impl Server {
     /// Create a new instance of the HTTP Server.
-    pub(self) fn new(port: u16, routes: Vec<Arc<dyn RouteConfigurer>>) -> Self {
-        Self { port, routes }
+    pub(self) fn new(
+        port: u16,
+        routes: Vec<Arc<dyn RouteConfigurer>>,
+        idempotency: Option<Arc<IdempotencyService>>,
+        max_body_size: usize,
+    ) -> Self {
+        Self {
+            port,
+            routes,
+            idempotency,
+            max_body_size,
+        }
     }
 
     /// Start the server listening.
@@ -40,18 +57,32 @@ impl Server {
         tracing::info!(address = ?address, "Starting HTTP Server");
 
         let routes = self.routes.clone();
+        let idempotency = self.idempotency.clone();
+        let max_body_size = self.max_body_size;
 
         HttpServer::new(move || {
             let routes = routes.clone();
 
             let mut app = App::new()
+                // Registered first so that it runs innermost, and only ever stores the response from
+                // the routes themselves.
+                .wrap(Idempotency::new(idempotency.clone(), max_body_size))
+                // Outside of idempotency, so that requests which are rejected up front aren't stored.
+                .wrap(Negotiate)
                 .wrap(Logger::default())
                 .wrap(
                     Cors::default()
                         .allow_any_origin()
                         .allow_any_method()
                         .allow_any_header()
-                        .expose_headers(vec![header::ETAG, header::LOCATION, header::LINK]),
+                        .expose_headers(vec![
+                            header::ETAG,
+                            header::LOCATION,
+                            header::LINK,
+                            header::ACCEPT_RANGES,
+                            header::CONTENT_RANGE,
+                            header::CONTENT_DISPOSITION,
+                        ]),
                 )
                 .wrap(span::Span);
 
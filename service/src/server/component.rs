@@ -1,11 +1,13 @@
 use std::sync::Arc;
 
 use super::{RouteConfigurer, Server};
+use crate::idempotency::IdempotencyService;
 
 /// Builder for the HTTP Server component.
 #[derive(Default)]
 pub struct Builder {
-    routes: Vec<Arc<dyn RouteConfigurer>>,
+    routes:      Vec<Arc<dyn RouteConfigurer>>,
+    idempotency: Option<Arc<IdempotencyService>>,
 }
 
 /// The HTTP Server component.
@@ -20,10 +22,20 @@ impl Builder {
         self
     }
 
+    /// Honour idempotency keys on unsafe requests to the server.
+    pub fn with_idempotency(mut self, service: Arc<IdempotencyService>) -> Self {
+        self.idempotency = Some(service);
+        self
+    }
+
     /// Build the HTTP Server component.
-    pub fn build(self, port: u16) -> Component {
+    ///
+    /// # Parameters
+    /// - `port` - The port to listen on
+    /// - `max_body_size` - The largest request body that can be sent with an idempotency key
+    pub fn build(self, port: u16, max_body_size: usize) -> Component {
         Component {
-            server: Server::new(port, self.routes),
+            server: Server::new(port, self.routes, self.idempotency, max_body_size),
         }
     }
 }
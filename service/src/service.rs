@@ -1,43 +1,207 @@
 #[cfg(test)]
 pub mod testing;
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
-use crate::{server::Server, settings::Settings};
+use crate::{
+    articles::PostgresArticleRepository,
+    calendars::PostgresCalendarRepository,
+    changes::PostgresChangeRepository,
+    comments::PostgresCommentRepository,
+    entities::PostgresEntityRepository,
+    idempotency::PostgresIdempotencyRepository,
+    jobs::{JobRegistry, JobService, PostgresJobRepository},
+    maps::PostgresMapRepository,
+    outbox::PostgresOutboxRepository,
+    realtime::PostgresEventBroker,
+    relationships::PostgresRelationshipRepository,
+    revisions::PostgresRevisionRepository,
+    search::PostgresSearchRepository,
+    server::Server,
+    sessions::PostgresSessionRepository,
+    settings::{Mode, Settings},
+    taxonomy::PostgresTaxonomyRepository,
+    timeline::PostgresEventRepository,
+    tokens::PostgresTokenRepository,
+    uploads::PostgresUploadRepository,
+    users::{HashPrefixDataset, PasswordHasher, PasswordPolicy, PostgresUserRepository},
+    webhooks::PostgresWebhookRepository,
+    worlds::PostgresWorldRepository,
+};
 
 /// The actual service.
 pub struct Service {
-    server:                Server,
-    authorization_service: Arc<crate::authorization::AuthorizationService>,
+    mode:          Mode,
+    server:        Server,
+    jobs:          Arc<crate::jobs::component::Component>,
+    outbox:        Arc<crate::outbox::component::Component>,
+    authorization: Arc<crate::authorization::AuthorizationService>,
+    sessions:      Arc<crate::sessions::SessionService>,
 }
 
 impl Service {
     /// Create a new instance of the service.
-    #[tracing::instrument]
+    #[tracing::instrument(skip(settings))]
     pub async fn new(settings: Settings) -> Self {
         tracing::info!("Building Worlds");
 
         let db = crate::database::component::Component::new(&settings.database_url).await;
-        let authorization = crate::authorization::component::Component::new("secret");
-        let users = crate::users::component::Component::new(db.database);
-        let authentication = crate::authentication::component::Component::new(users.service.clone(), authorization.service.clone());
+        let trusted_proxies = settings
+            .trusted_proxies
+            .as_deref()
+            .map(|proxies| proxies.parse().expect("Invalid trusted proxies"))
+            .unwrap_or_default();
+        let sessions =
+            crate::sessions::component::Component::new(Arc::new(PostgresSessionRepository::new(db.database.clone())), trusted_proxies);
+        let tokens = crate::tokens::component::Component::new(Arc::new(PostgresTokenRepository::new(db.database.clone())));
+        let authorization = crate::authorization::component::Component::new("secret", sessions.service.clone(), tokens.service.clone());
+        let mut password_policy = PasswordPolicy::new(settings.password_min_length, settings.password_min_strength);
+        if let Some(path) = &settings.breached_passwords_path {
+            password_policy = password_policy.with_breached_passwords(HashPrefixDataset::new(path));
+        }
+        let mut password_hasher = PasswordHasher::new(settings.password_memory_size, settings.password_iterations, settings.password_lanes);
+        if let Some(pepper) = &settings.password_pepper {
+            password_hasher = password_hasher.with_secret_key(pepper);
+        }
+
+        let revisions = crate::revisions::component::Component::new(Arc::new(PostgresRevisionRepository::new(db.database.clone())));
+        let users = crate::users::component::Component::new(
+            Arc::new(PostgresUserRepository::new(db.database.clone())),
+            password_policy,
+            password_hasher,
+            revisions.service.clone(),
+        );
+        let worlds = crate::worlds::component::Component::new(Arc::new(PostgresWorldRepository::new(db.database.clone())));
+        let search = crate::search::component::Component::new(Arc::new(PostgresSearchRepository::new(db.database.clone())));
+        let articles = crate::articles::component::Component::new(
+            Arc::new(PostgresArticleRepository::new(db.database.clone())),
+            revisions.service.clone(),
+            search.service.clone(),
+        );
+        let entities = crate::entities::component::Component::new(
+            Arc::new(PostgresEntityRepository::new(db.database.clone())),
+            revisions.service.clone(),
+            search.service.clone(),
+        );
+        let relationships = crate::relationships::component::Component::new(
+            Arc::new(PostgresRelationshipRepository::new(db.database.clone())),
+            entities.service.clone(),
+        );
+        let calendars = crate::calendars::component::Component::new(Arc::new(PostgresCalendarRepository::new(db.database.clone())));
+        let timeline = crate::timeline::component::Component::new(
+            Arc::new(PostgresEventRepository::new(db.database.clone())),
+            entities.service.clone(),
+        );
+        let blobs = crate::blobs::component::Component::new(&settings);
+        let uploads = crate::uploads::component::Component::new(
+            Arc::new(PostgresUploadRepository::new(db.database.clone())),
+            blobs.store.clone(),
+            entities.service.clone(),
+            settings.upload_max_size,
+        );
+        let maps = crate::maps::component::Component::new(
+            Arc::new(PostgresMapRepository::new(db.database.clone())),
+            uploads.service.clone(),
+            blobs.store,
+            entities.service.clone(),
+            articles.service.clone(),
+        );
+        let taxonomy = crate::taxonomy::component::Component::new(
+            Arc::new(PostgresTaxonomyRepository::new(db.database.clone())),
+            articles.service.clone(),
+            entities.service.clone(),
+            search.service.clone(),
+        );
+        let comments = crate::comments::component::Component::new(
+            Arc::new(PostgresCommentRepository::new(db.database.clone())),
+            users.service.clone(),
+            taxonomy.service.clone(),
+        );
+        let realtime = crate::realtime::component::Component::new(
+            Arc::new(PostgresEventBroker::new(db.database.clone())),
+            worlds.service.clone(),
+            authorization.service.clone(),
+        );
+        realtime.listen(&settings.database_url);
+        realtime.revalidate();
+        let changes = crate::changes::component::Component::new(
+            Arc::new(PostgresChangeRepository::new(db.database.clone())),
+            realtime.service.clone(),
+        );
+        let job_repository = Arc::new(PostgresJobRepository::new(db.database.clone()));
+        let webhooks = crate::webhooks::component::Component::new(
+            Arc::new(PostgresWebhookRepository::new(db.database.clone())),
+            Arc::new(JobService::new(job_repository.clone())),
+        );
+        let idempotency = crate::idempotency::component::Component::new(
+            Arc::new(PostgresIdempotencyRepository::new(db.database.clone())),
+            Duration::from_secs(settings.idempotency_window_hours * 60 * 60),
+        );
+        let outbox = crate::outbox::component::Component::new(Arc::new(PostgresOutboxRepository::new(db.database)), &settings);
+        let jobs = crate::jobs::component::Component::new(
+            job_repository,
+            outbox.register_jobs(sessions.register_jobs(webhooks.register_jobs(idempotency.register_jobs(JobRegistry::default())))),
+            settings.job_concurrency,
+        );
+        let authentication = crate::authentication::component::Component::new(
+            users.service.clone(),
+            users.password_hasher.clone(),
+            sessions.service.clone(),
+            authorization.service.clone(),
+        );
 
         let server = crate::server::component::Builder::default()
             .with_routes(authorization.clone())
             .with_routes(authentication)
             .with_routes(users)
-            .build(settings.port);
+            .with_routes(sessions.clone())
+            .with_routes(tokens)
+            .with_routes(worlds)
+            .with_routes(articles)
+            .with_routes(entities)
+            .with_routes(relationships)
+            .with_routes(calendars)
+            .with_routes(changes)
+            .with_routes(webhooks)
+            .with_routes(timeline)
+            .with_routes(uploads)
+            .with_routes(maps)
+            .with_routes(taxonomy)
+            .with_routes(comments)
+            .with_routes(realtime)
+            .with_routes(search)
+            .with_routes(revisions)
+            .with_idempotency(idempotency.service.clone())
+            .build(settings.port, settings.upload_max_size);
 
         tracing::info!("Built Worlds");
         Self {
-            server:                server.server,
-            authorization_service: authorization.service.clone(),
+            mode: settings.mode,
+            server: server.server,
+            jobs,
+            outbox,
+            authorization: authorization.service.clone(),
+            sessions: sessions.service.clone(),
         }
     }
 
     /// Start the service running.
+    ///
+    /// Depending on the mode, this serves HTTP requests, runs the background work, or both.
     pub async fn start(self) {
-        tracing::info!("Starting Worlds");
-        self.server.start().await;
+        tracing::info!(mode = ?self.mode, "Starting Worlds");
+
+        match self.mode {
+            Mode::All => {
+                self.jobs.work();
+                self.outbox.relay();
+                self.server.start().await;
+            },
+            Mode::Server => self.server.start().await,
+            Mode::Worker => {
+                self.outbox.relay();
+                self.jobs.worker.clone().run().await;
+            },
+        }
     }
 }
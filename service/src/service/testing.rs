@@ -1,8 +1,14 @@
 use actix_http::{http::header::IntoHeaderPair, Request};
 use actix_web::App;
+use chrono::{Duration, Utc};
 
 use super::Service;
-use crate::authorization::Principal;
+use crate::{
+    http::negotiation::Negotiate,
+    idempotency::Idempotency,
+    sessions::{DeviceDetails, SessionId},
+    users::UserId,
+};
 
 impl Service {
     /// Inject a request into the server. Only used for testing.
@@ -13,7 +19,9 @@ impl Service {
     /// # Returns
     /// The response from injecting the request.
     pub async fn inject(&self, req: Request) -> TestResponse {
-        let mut app = App::new();
+        let mut app = App::new()
+            .wrap(Idempotency::new(self.server.idempotency.clone(), self.server.max_body_size))
+            .wrap(Negotiate);
         for c in &self.server.routes {
             app = app.configure(move |server_config| {
                 c.configure_routes(server_config);
@@ -30,10 +38,23 @@ impl Service {
         TestResponse { status, headers, body }
     }
 
-    pub fn authorize(&self, user_id: &str) -> impl IntoHeaderPair {
-        let (_, token) = self
-            .authorization_service
-            .generate_security_context(Principal::User(user_id.into()));
+    /// Start a new session for a user, and generate the header to authorize requests as them.
+    /// If the user doesn't exist then no session can be started, so the token refers to one that
+    /// never existed. Only used for testing.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user to authorize as
+    ///
+    /// # Returns
+    /// The Authorization header to send.
+    pub async fn authorize(&self, user_id: &str) -> impl IntoHeaderPair {
+        let user_id: UserId = user_id.parse().unwrap();
+        let (session_id, expires) = self.sessions.start_session(&user_id, DeviceDetails::default()).await.map_or_else(
+            |_| (SessionId::default(), Utc::now() + Duration::days(30)),
+            |session| (session.identity.id, session.data.expires),
+        );
+
+        let (_, token) = self.authorization.generate_security_context((&user_id).into(), session_id, expires);
 
         ("Authorization", format!("Bearer {}", token.0))
     }
@@ -0,0 +1,11 @@
+pub mod component;
+mod endpoints;
+mod model;
+mod purge_sessions;
+mod repository;
+mod service;
+
+pub use model::*;
+pub use purge_sessions::*;
+pub use repository::*;
+pub use service::*;
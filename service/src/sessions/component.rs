@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use actix_web::web::{delete, get, resource, ServiceConfig};
+
+use super::{repository::SessionRepository, service::SessionService, PurgeSessions, TrustedProxies};
+use crate::{jobs::JobRegistry, server::RouteConfigurer};
+
+/// Component for working with user sessions.
+pub struct Component {
+    pub service:     Arc<SessionService>,
+    trusted_proxies: TrustedProxies,
+}
+
+impl Component {
+    /// Create a new sessions component.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store sessions in
+    /// - `trusted_proxies` - The proxies that are trusted to report the address of the client that
+    ///   starts a session
+    pub fn new(repository: Arc<dyn SessionRepository>, trusted_proxies: TrustedProxies) -> Arc<Self> {
+        let service = Arc::new(SessionService::new(repository));
+
+        Arc::new(Self { service, trusted_proxies })
+    }
+
+    /// Register the background jobs that this component needs.
+    ///
+    /// # Parameters
+    /// - `registry` - The registry to add the jobs to
+    ///
+    /// # Returns
+    /// The registry, with the jobs added.
+    pub fn register_jobs(&self, registry: JobRegistry) -> JobRegistry {
+        registry
+            .with_job(PurgeSessions::new(self.service.clone()))
+            .with_schedule::<PurgeSessions>("sessions.purge", "45 * * * *", &())
+    }
+}
+
+impl RouteConfigurer for Component {
+    fn configure_routes(&self, config: &mut ServiceConfig) {
+        config.data(self.service.clone());
+        config.data(self.trusted_proxies.clone());
+
+        config.service(
+            resource("/users/{id}/sessions")
+                .route(get().to(super::endpoints::list_sessions::handle))
+                .route(delete().to(super::endpoints::delete_all_sessions::handle)),
+        );
+        config.service(resource("/users/{id}/sessions/{session}").route(delete().to(super::endpoints::delete_session::handle)));
+    }
+}
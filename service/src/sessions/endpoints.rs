@@ -0,0 +1,4 @@
+pub(super) mod delete_all_sessions;
+pub(super) mod delete_session;
+pub(super) mod list_sessions;
+mod model;
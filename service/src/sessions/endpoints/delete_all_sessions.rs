@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use actix_web::{
+    web::{Data, Path},
+    HttpResponse,
+};
+
+use crate::{
+    authorization::{Authentication, Principal},
+    http::problem::{Problem, FORBIDDEN},
+    sessions::SessionService,
+    users::UserId,
+};
+
+/// Sign the user out everywhere, by ending every one of their sessions - including the current one.
+pub async fn handle(
+    service: Data<Arc<SessionService>>,
+    path: Path<String>,
+    authentication: Authentication,
+) -> Result<HttpResponse, Problem> {
+    let user_id: UserId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse User ID");
+
+        FORBIDDEN
+    })?;
+
+    authentication.same_principal(&Principal::from(&user_id))?;
+    authentication.require_session()?;
+
+    service.end_all_sessions(&user_id).await;
+
+    Ok(HttpResponse::NoContent().finish())
+}
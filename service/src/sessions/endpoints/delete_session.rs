@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use actix_web::{
+    web::{Data, Path},
+    HttpResponse,
+};
+
+use crate::{
+    authorization::{Authentication, Principal},
+    http::problem::{Problem, FORBIDDEN, NOT_FOUND},
+    sessions::{SessionId, SessionService},
+    users::UserId,
+};
+
+pub async fn handle(
+    service: Data<Arc<SessionService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<HttpResponse, Problem> {
+    let (user_id, session_id) = path.into_inner();
+
+    let user_id: UserId = user_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, user_id = ?user_id, "Failed to parse User ID");
+
+        FORBIDDEN
+    })?;
+
+    authentication.same_principal(&Principal::from(&user_id))?;
+    authentication.require_session()?;
+
+    let session_id: SessionId = session_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, session_id = ?session_id, "Failed to parse Session ID");
+
+        NOT_FOUND
+    })?;
+
+    if service.end_session(&user_id, &session_id).await {
+        Ok(HttpResponse::NoContent().finish())
+    } else {
+        Err(NOT_FOUND.into())
+    }
+}
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::model::{SessionModel, SessionsModel};
+use crate::{
+    authorization::{Authentication, Principal, SecurityContext},
+    http::{
+        problem::{Problem, FORBIDDEN},
+        response::Response,
+    },
+    sessions::SessionService,
+    users::UserId,
+};
+
+pub async fn handle(
+    service: Data<Arc<SessionService>>,
+    path: Path<String>,
+    authentication: Authentication,
+) -> Result<Response<SessionsModel>, Problem> {
+    let user_id: UserId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse User ID");
+
+        FORBIDDEN
+    })?;
+
+    authentication.same_principal(&Principal::from(&user_id))?;
+    authentication.require_session()?;
+
+    let current = authentication.security_context().and_then(SecurityContext::session_id);
+
+    let sessions = service
+        .get_sessions_for_user(&user_id)
+        .await
+        .into_iter()
+        .map(|session| SessionModel::new(session, current))
+        .collect();
+
+    Ok(Response(SessionsModel { sessions }))
+}
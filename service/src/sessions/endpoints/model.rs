@@ -0,0 +1,42 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::sessions::{SessionId, SessionResource};
+
+/// Representation of a session on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionModel {
+    pub session_id:  SessionId,
+    pub device_name: Option<String>,
+    pub user_agent:  Option<String>,
+    pub ip_address:  Option<String>,
+    pub created:     DateTime<Utc>,
+    pub last_seen:   DateTime<Utc>,
+    pub current:     bool,
+}
+
+impl SessionModel {
+    /// Build the HTTP representation of a session.
+    ///
+    /// # Parameters
+    /// - `session` - The session to represent
+    /// - `current` - The ID of the session that the request was made with, if any
+    pub fn new(session: SessionResource, current: Option<&SessionId>) -> Self {
+        Self {
+            current:     current == Some(&session.identity.id),
+            session_id:  session.identity.id,
+            device_name: session.data.device.device_name,
+            user_agent:  session.data.device.user_agent,
+            ip_address:  session.data.device.ip_address,
+            created:     session.identity.created,
+            last_seen:   session.data.last_seen,
+        }
+    }
+}
+
+/// Representation of all the sessions for a user on the HTTP API.
+#[derive(Serialize)]
+pub struct SessionsModel {
+    pub sessions: Vec<SessionModel>,
+}
@@ -0,0 +1,21 @@
+mod device_details;
+mod session_id;
+
+use chrono::{DateTime, Utc};
+pub use device_details::*;
+pub use session_id::*;
+
+use crate::{model::Resource, users::UserId};
+
+/// The data representing a session.
+#[derive(Debug, Clone)]
+pub struct SessionData {
+    pub user_id:   UserId,
+    pub device:    DeviceDetails,
+    pub last_seen: DateTime<Utc>,
+    /// When the session ends, which is when the access token issued for it expires.
+    pub expires:   DateTime<Utc>,
+}
+
+/// Type representing a persisted session.
+pub type SessionResource = Resource<SessionId, SessionData>;
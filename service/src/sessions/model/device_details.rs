@@ -0,0 +1,153 @@
+use std::{
+    net::{AddrParseError, IpAddr, SocketAddr},
+    str::FromStr,
+};
+
+use actix_http::http::header;
+use actix_web::HttpRequest;
+
+/// Details of the device that a session was started from.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DeviceDetails {
+    /// The name that the client gave the device, if any.
+    pub device_name: Option<String>,
+    /// The User-Agent header of the request that started the session, if any.
+    pub user_agent:  Option<String>,
+    /// The IP address of the client that started the session, if known.
+    pub ip_address:  Option<String>,
+}
+
+/// The reverse proxies that are trusted to report the address of the clients that they forward
+/// requests for. Anyone can send a `Forwarded` or `X-Forwarded-For` header, so it is only believed
+/// when the request came directly from one of these.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TrustedProxies(Vec<IpAddr>);
+
+impl TrustedProxies {
+    /// Determine whether requests from an address are from a trusted proxy.
+    ///
+    /// # Parameters
+    /// - `addr` - The address that the request came from
+    pub fn trusts(&self, addr: &IpAddr) -> bool {
+        self.0.contains(addr)
+    }
+}
+
+impl FromStr for TrustedProxies {
+    type Err = AddrParseError;
+
+    /// Parse a list of IP addresses, separated by commas.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(str::trim)
+            .filter(|addr| !addr.is_empty())
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+impl DeviceDetails {
+    /// Build the device details for the client making the provided request.
+    ///
+    /// # Parameters
+    /// - `req` - The HTTP request that is starting a session
+    /// - `device_name` - The name that the client gave the device, if any
+    /// - `trusted_proxies` - The proxies that are trusted to report the address of the client
+    ///
+    /// # Returns
+    /// The device details.
+    pub fn from_request(req: &HttpRequest, device_name: Option<String>, trusted_proxies: &TrustedProxies) -> Self {
+        let user_agent = req
+            .headers()
+            .get(header::USER_AGENT)
+            .and_then(|h| h.to_str().ok())
+            .map(ToOwned::to_owned);
+        let ip_address = match req.peer_addr().map(|addr| addr.ip()) {
+            Some(peer) if trusted_proxies.trusts(&peer) => req.connection_info().realip_remote_addr().map(|addr| {
+                // The forwarded address may include the port, which isn't useful to keep.
+                addr.parse::<SocketAddr>()
+                    .map_or_else(|_| addr.to_owned(), |addr| addr.ip().to_string())
+            }),
+            peer => peer.map(|peer| peer.to_string()),
+        };
+
+        Self {
+            device_name,
+            user_agent,
+            ip_address,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::test::TestRequest;
+    use assert2::{check, let_assert};
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test]
+    fn from_request() {
+        let req = TestRequest::default()
+            .insert_header((header::USER_AGENT, "Test Agent/1.0"))
+            .peer_addr("192.0.2.1:12345".parse().unwrap())
+            .to_http_request();
+
+        let sut = DeviceDetails::from_request(&req, Some("Laptop".to_owned()), &TrustedProxies::default());
+
+        check!(sut.device_name == Some("Laptop".to_owned()));
+        check!(sut.user_agent == Some("Test Agent/1.0".to_owned()));
+        check!(sut.ip_address == Some("192.0.2.1".to_owned()));
+    }
+
+    #[test]
+    fn from_forwarded_request() {
+        let req = TestRequest::default()
+            .insert_header((header::FORWARDED, "for=198.51.100.7"))
+            .peer_addr("192.0.2.1:12345".parse().unwrap())
+            .to_http_request();
+
+        let sut = DeviceDetails::from_request(&req, None, &"192.0.2.1".parse().unwrap());
+
+        check!(sut.ip_address == Some("198.51.100.7".to_owned()));
+    }
+
+    #[test]
+    fn from_untrusted_forwarded_request() {
+        let req = TestRequest::default()
+            .insert_header((header::FORWARDED, "for=198.51.100.7"))
+            .insert_header(("X-Forwarded-For", "198.51.100.8"))
+            .peer_addr("192.0.2.1:12345".parse().unwrap())
+            .to_http_request();
+
+        let sut = DeviceDetails::from_request(&req, None, &"192.0.2.2".parse().unwrap());
+
+        check!(sut.ip_address == Some("192.0.2.1".to_owned()));
+    }
+
+    #[test_case("", &[] ; "Empty")]
+    #[test_case("192.0.2.1", &["192.0.2.1"] ; "Single")]
+    #[test_case("192.0.2.1, 2001:db8::1", &["192.0.2.1", "2001:db8::1"] ; "Multiple")]
+    fn parse_trusted_proxies(input: &str, expected: &[&str]) {
+        let_assert!(Ok(TrustedProxies(proxies)) = input.parse::<TrustedProxies>());
+
+        check!(proxies == expected.iter().map(|addr| addr.parse().unwrap()).collect::<Vec<IpAddr>>());
+    }
+
+    #[test]
+    fn parse_invalid_trusted_proxies() {
+        check!("192.0.2.1, proxy.example.com".parse::<TrustedProxies>().is_err());
+    }
+
+    #[test]
+    fn from_empty_request() {
+        let req = TestRequest::default().to_http_request();
+
+        let sut = DeviceDetails::from_request(&req, None, &TrustedProxies::default());
+
+        check!(sut.device_name == None);
+        check!(sut.user_agent == None);
+    }
+}
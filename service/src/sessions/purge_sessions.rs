@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use super::SessionService;
+use crate::jobs::{Job, JobError};
+
+/// Job that deletes the sessions that have expired, so that they don't build up forever.
+pub struct PurgeSessions {
+    service: Arc<SessionService>,
+}
+
+impl PurgeSessions {
+    /// Create the job.
+    ///
+    /// # Parameters
+    /// - `service` - The service that manages sessions
+    pub fn new(service: Arc<SessionService>) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Job for PurgeSessions {
+    type Payload = ();
+
+    const KIND: &'static str = "sessions.purge";
+
+    async fn run(&self, (): ()) -> Result<(), JobError> {
+        let count = self.service.purge().await;
+        tracing::info!(count = count, "Purged expired sessions");
+
+        Ok(())
+    }
+}
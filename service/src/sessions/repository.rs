@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod memory;
+mod parse;
+mod postgres;
+mod save_session;
+
+use chrono::{DateTime, Utc};
+#[cfg(test)]
+pub use memory::MemorySessionRepository;
+pub use postgres::PostgresSessionRepository;
+pub use save_session::SaveSessionError;
+
+use crate::{
+    sessions::{SessionData, SessionId, SessionResource},
+    users::UserId,
+};
+
+/// Repository of session records.
+#[async_trait::async_trait]
+pub trait SessionRepository: Send + Sync {
+    /// Create a new session record from the provided Session data.
+    ///
+    /// # Parameters
+    /// - `session` - The details of the session to create.
+    ///
+    /// # Returns
+    /// The created session resource.
+    async fn create_session(&self, session: &SessionData) -> Result<SessionResource, SaveSessionError>;
+
+    /// Get all of the sessions that belong to the provided User ID and haven't expired.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user to fetch the sessions for.
+    /// - `now` - The current time, which sessions must expire after.
+    ///
+    /// # Returns
+    /// The session resources, most recently seen first.
+    async fn get_sessions_for_user(&self, user_id: &UserId, now: DateTime<Utc>) -> Vec<SessionResource>;
+
+    /// Record that a session has been seen, if it still exists and hasn't expired.
+    /// The session is only written to if it was last seen before `stale_before`, so that checking a
+    /// session on every request doesn't also write to it on every request.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user that the session belongs to.
+    /// - `session_id` - The ID of the session.
+    /// - `seen` - When the session was seen.
+    /// - `stale_before` - The time before which the last seen time needs updating.
+    ///
+    /// # Returns
+    /// True if the session exists for the user and hasn't expired. False if not.
+    async fn touch_session(&self, user_id: &UserId, session_id: &SessionId, seen: DateTime<Utc>, stale_before: DateTime<Utc>) -> bool;
+
+    /// Delete a single session.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user that the session belongs to.
+    /// - `session_id` - The ID of the session.
+    ///
+    /// # Returns
+    /// True if the session existed and was deleted. False if not.
+    async fn delete_session(&self, user_id: &UserId, session_id: &SessionId) -> bool;
+
+    /// Delete every session that belongs to the provided User ID.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user to delete the sessions for.
+    async fn delete_sessions_for_user(&self, user_id: &UserId);
+
+    /// Delete every session that has expired.
+    ///
+    /// # Parameters
+    /// - `now` - The current time, which sessions expired before.
+    ///
+    /// # Returns
+    /// The number of sessions that were deleted.
+    async fn delete_expired_sessions(&self, now: DateTime<Utc>) -> u64;
+}
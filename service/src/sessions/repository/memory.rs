@@ -0,0 +1,211 @@
+use std::{cmp::Reverse, sync::Mutex};
+
+use chrono::{DateTime, Utc};
+
+use super::{SaveSessionError, SessionRepository};
+use crate::{
+    model::Identity,
+    sessions::{SessionData, SessionId, SessionResource},
+    users::UserId,
+};
+
+/// Repository of session records that are stored in memory.
+#[derive(Default)]
+pub struct MemorySessionRepository {
+    sessions: Mutex<Vec<SessionResource>>,
+}
+
+impl MemorySessionRepository {
+    /// Create a new, empty session repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert an already built session into the repository, so that its ID is known in advance.
+    ///
+    /// # Parameters
+    /// - `session` - The session to insert
+    pub fn insert_session(&self, session: SessionResource) {
+        self.sessions.lock().unwrap().push(session);
+    }
+}
+
+#[async_trait::async_trait]
+impl SessionRepository for MemorySessionRepository {
+    async fn create_session(&self, session: &SessionData) -> Result<SessionResource, SaveSessionError> {
+        let mut sessions = self.sessions.lock().unwrap();
+
+        let created = SessionResource {
+            identity: Identity::default(),
+            data:     session.clone(),
+        };
+        sessions.push(created.clone());
+
+        Ok(created)
+    }
+
+    async fn get_sessions_for_user(&self, user_id: &UserId, now: DateTime<Utc>) -> Vec<SessionResource> {
+        let sessions = self.sessions.lock().unwrap();
+
+        let mut result: Vec<SessionResource> = sessions
+            .iter()
+            .filter(|s| &s.data.user_id == user_id && s.data.expires > now)
+            .cloned()
+            .collect();
+        result.sort_by_key(|s| Reverse(s.data.last_seen));
+
+        result
+    }
+
+    async fn touch_session(&self, user_id: &UserId, session_id: &SessionId, seen: DateTime<Utc>, stale_before: DateTime<Utc>) -> bool {
+        let mut sessions = self.sessions.lock().unwrap();
+
+        sessions
+            .iter_mut()
+            .find(|s| &s.identity.id == session_id && &s.data.user_id == user_id && s.data.expires > seen)
+            .map(|s| {
+                if s.data.last_seen < stale_before {
+                    s.data.last_seen = seen;
+                }
+            })
+            .is_some()
+    }
+
+    async fn delete_session(&self, user_id: &UserId, session_id: &SessionId) -> bool {
+        let mut sessions = self.sessions.lock().unwrap();
+
+        let before = sessions.len();
+        sessions.retain(|s| &s.identity.id != session_id || &s.data.user_id != user_id);
+
+        sessions.len() != before
+    }
+
+    async fn delete_sessions_for_user(&self, user_id: &UserId) {
+        let mut sessions = self.sessions.lock().unwrap();
+
+        sessions.retain(|s| &s.data.user_id != user_id);
+    }
+
+    async fn delete_expired_sessions(&self, now: DateTime<Utc>) -> u64 {
+        let mut sessions = self.sessions.lock().unwrap();
+
+        let before = sessions.len();
+        sessions.retain(|s| s.data.expires > now);
+
+        (before - sessions.len()) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use chrono::Duration;
+
+    use super::*;
+    use crate::sessions::DeviceDetails;
+
+    fn session_data(user_id: &UserId) -> SessionData {
+        SessionData {
+            user_id:   user_id.clone(),
+            device:    DeviceDetails::default(),
+            last_seen: Utc::now(),
+            expires:   Utc::now() + Duration::days(30),
+        }
+    }
+
+    #[actix_rt::test]
+    async fn create_and_list() {
+        let sut = MemorySessionRepository::new();
+        let user_id = UserId::default();
+
+        let_assert!(Ok(first) = sut.create_session(&session_data(&user_id)).await);
+        let_assert!(Ok(_) = sut.create_session(&session_data(&UserId::default())).await);
+        let_assert!(Ok(second) = sut.create_session(&session_data(&user_id)).await);
+
+        let sessions = sut.get_sessions_for_user(&user_id, Utc::now()).await;
+        let ids: Vec<SessionId> = sessions.into_iter().map(|s| s.identity.id).collect();
+        check!(ids == vec![second.identity.id, first.identity.id]);
+    }
+
+    #[actix_rt::test]
+    async fn touch_session() {
+        let sut = MemorySessionRepository::new();
+        let user_id = UserId::default();
+        let_assert!(Ok(created) = sut.create_session(&session_data(&user_id)).await);
+
+        let seen = created.data.last_seen + Duration::minutes(5);
+        check!(
+            sut.touch_session(&user_id, &created.identity.id, seen, seen - Duration::minutes(1))
+                .await
+        );
+        check!(sut.get_sessions_for_user(&user_id, Utc::now()).await[0].data.last_seen == seen);
+
+        check!(!sut.touch_session(&UserId::default(), &created.identity.id, seen, seen).await);
+        check!(!sut.touch_session(&user_id, &SessionId::default(), seen, seen).await);
+    }
+
+    #[actix_rt::test]
+    async fn touch_recent_session() {
+        let sut = MemorySessionRepository::new();
+        let user_id = UserId::default();
+        let_assert!(Ok(created) = sut.create_session(&session_data(&user_id)).await);
+
+        let seen = created.data.last_seen + Duration::seconds(30);
+        check!(
+            sut.touch_session(&user_id, &created.identity.id, seen, seen - Duration::minutes(1))
+                .await
+        );
+        check!(sut.get_sessions_for_user(&user_id, Utc::now()).await[0].data.last_seen == created.data.last_seen);
+    }
+
+    #[actix_rt::test]
+    async fn delete_session() {
+        let sut = MemorySessionRepository::new();
+        let user_id = UserId::default();
+        let_assert!(Ok(created) = sut.create_session(&session_data(&user_id)).await);
+
+        check!(!sut.delete_session(&UserId::default(), &created.identity.id).await);
+        check!(sut.delete_session(&user_id, &created.identity.id).await);
+        check!(!sut.delete_session(&user_id, &created.identity.id).await);
+        check!(!sut.touch_session(&user_id, &created.identity.id, Utc::now(), Utc::now()).await);
+    }
+
+    #[actix_rt::test]
+    async fn delete_sessions_for_user() {
+        let sut = MemorySessionRepository::new();
+        let user_id = UserId::default();
+        let other_user_id = UserId::default();
+        let_assert!(Ok(_) = sut.create_session(&session_data(&user_id)).await);
+        let_assert!(Ok(_) = sut.create_session(&session_data(&user_id)).await);
+        let_assert!(Ok(_) = sut.create_session(&session_data(&other_user_id)).await);
+
+        sut.delete_sessions_for_user(&user_id).await;
+
+        check!(sut.get_sessions_for_user(&user_id, Utc::now()).await.is_empty());
+        check!(sut.get_sessions_for_user(&other_user_id, Utc::now()).await.len() == 1);
+    }
+
+    #[actix_rt::test]
+    async fn expired_sessions() {
+        let sut = MemorySessionRepository::new();
+        let user_id = UserId::default();
+        let_assert!(Ok(active) = sut.create_session(&session_data(&user_id)).await);
+        let_assert!(
+            Ok(expired) = sut
+                .create_session(&SessionData {
+                    expires: Utc::now() - Duration::minutes(1),
+                    ..session_data(&user_id)
+                })
+                .await
+        );
+
+        let sessions = sut.get_sessions_for_user(&user_id, Utc::now()).await;
+        let ids: Vec<SessionId> = sessions.into_iter().map(|s| s.identity.id).collect();
+        check!(ids == vec![active.identity.id.clone()]);
+        check!(!sut.touch_session(&user_id, &expired.identity.id, Utc::now(), Utc::now()).await);
+
+        check!(sut.delete_expired_sessions(Utc::now()).await == 1);
+        check!(sut.delete_expired_sessions(Utc::now()).await == 0);
+        check!(sut.touch_session(&user_id, &active.identity.id, Utc::now(), Utc::now()).await);
+    }
+}
@@ -0,0 +1,29 @@
+use tokio_postgres::Row;
+
+use crate::{
+    model::Identity,
+    sessions::{DeviceDetails, SessionData, SessionResource},
+};
+
+impl From<Row> for SessionResource {
+    fn from(row: Row) -> Self {
+        SessionResource {
+            identity: Identity {
+                id:      row.get("session_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data:     SessionData {
+                user_id:   row.get("user_id"),
+                device:    DeviceDetails {
+                    device_name: row.get("device_name"),
+                    user_agent:  row.get("user_agent"),
+                    ip_address:  row.get("ip_address"),
+                },
+                last_seen: row.get("last_seen"),
+                expires:   row.get("expires"),
+            },
+        }
+    }
+}
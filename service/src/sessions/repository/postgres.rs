@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+use super::{SaveSessionError, SessionRepository};
+use crate::{
+    database::Database,
+    model::Identity,
+    sessions::{SessionData, SessionId, SessionResource},
+    users::UserId,
+};
+
+/// Repository of session records that are stored in Postgres.
+pub struct PostgresSessionRepository {
+    database: Arc<Database>,
+}
+
+impl PostgresSessionRepository {
+    /// Create a new session repository.
+    pub fn new(database: Arc<Database>) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait::async_trait]
+impl SessionRepository for PostgresSessionRepository {
+    #[tracing::instrument(skip(self))]
+    async fn create_session(&self, session: &SessionData) -> Result<SessionResource, SaveSessionError> {
+        let conn = self.database.connect().await;
+
+        let identity = Identity::<SessionId>::default();
+
+        let created: SessionResource = conn.query_one("INSERT INTO sessions(session_id, version, created, updated, user_id, device_name, user_agent, ip_address, last_seen, expires) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) RETURNING *",
+        &[
+          &identity.id,
+          &identity.version,
+          &identity.created,
+          &identity.updated,
+          &session.user_id,
+          &session.device.device_name,
+          &session.device.user_agent,
+          &session.device.ip_address,
+          &session.last_seen,
+          &session.expires,
+          ])
+            .await
+            .map(SessionResource::from)?;
+
+        Ok(created)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_sessions_for_user(&self, user_id: &UserId, now: DateTime<Utc>) -> Vec<SessionResource> {
+        let conn = self.database.connect().await;
+        conn.query(
+            "SELECT * FROM sessions WHERE user_id = $1 AND expires > $2 ORDER BY last_seen DESC",
+            &[&user_id, &now],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to load sessions");
+                vec![]
+            },
+            |rows| rows.into_iter().map(SessionResource::from).collect(),
+        )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn touch_session(&self, user_id: &UserId, session_id: &SessionId, seen: DateTime<Utc>, stale_before: DateTime<Utc>) -> bool {
+        let conn = self.database.connect().await;
+        conn.query_one(
+            "WITH touched AS (
+               UPDATE sessions SET last_seen = $3 WHERE session_id = $1 AND user_id = $2 AND expires > $3 AND last_seen < $4
+             )
+             SELECT EXISTS(SELECT 1 FROM sessions WHERE session_id = $1 AND user_id = $2 AND expires > $3) AS active",
+            &[&session_id, &user_id, &seen, &stale_before],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to update session");
+                false
+            },
+            |row| row.get("active"),
+        )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_session(&self, user_id: &UserId, session_id: &SessionId) -> bool {
+        let conn = self.database.connect().await;
+        conn.execute(
+            "DELETE FROM sessions WHERE session_id = $1 AND user_id = $2",
+            &[&session_id, &user_id],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to delete session");
+                false
+            },
+            |count| count == 1,
+        )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_sessions_for_user(&self, user_id: &UserId) {
+        let conn = self.database.connect().await;
+        if let Err(e) = conn.execute("DELETE FROM sessions WHERE user_id = $1", &[&user_id]).await {
+            tracing::warn!(e = ?e, "Failed to delete sessions");
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_expired_sessions(&self, now: DateTime<Utc>) -> u64 {
+        let conn = self.database.connect().await;
+        conn.execute("DELETE FROM sessions WHERE expires <= $1", &[&now])
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!(e = ?e, "Failed to delete expired sessions");
+                0
+            })
+    }
+}
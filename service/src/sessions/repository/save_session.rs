@@ -0,0 +1,22 @@
+use tokio_postgres::error::SqlState;
+
+/// Errors that can occur when saving a session record.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveSessionError {
+    #[error("The user was not found")]
+    UnknownUser,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl From<tokio_postgres::Error> for SaveSessionError {
+    fn from(e: tokio_postgres::Error) -> Self {
+        if e.code() == Some(&SqlState::FOREIGN_KEY_VIOLATION) {
+            SaveSessionError::UnknownUser
+        } else {
+            tracing::warn!("Unexpected database error: {:?}", e);
+            SaveSessionError::UnknownError
+        }
+    }
+}
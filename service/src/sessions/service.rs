@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+
+use super::{DeviceDetails, SaveSessionError, SessionData, SessionId, SessionRepository, SessionResource};
+use crate::users::UserId;
+
+/// How long after a session was last seen before it is recorded as being seen again.
+const TOUCH_INTERVAL_SECONDS: i64 = 60;
+
+/// How long a session lasts for, which is also how long the access token issued for it is valid.
+const SESSION_LIFETIME_DAYS: i64 = 30;
+
+/// Service layer for working with sessions.
+pub struct SessionService {
+    repository: Arc<dyn SessionRepository>,
+}
+
+impl SessionService {
+    /// Create a new session service.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store sessions in
+    pub fn new(repository: Arc<dyn SessionRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Start a new session for a user.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user that the session is for
+    /// - `device` - The details of the device that the session is being started from
+    ///
+    /// # Returns
+    /// The newly created session.
+    pub async fn start_session(&self, user_id: &UserId, device: DeviceDetails) -> Result<SessionResource, SaveSessionError> {
+        let now = Utc::now();
+
+        self.repository
+            .create_session(&SessionData {
+                user_id: user_id.clone(),
+                device,
+                last_seen: now,
+                expires: now + Duration::days(SESSION_LIFETIME_DAYS),
+            })
+            .await
+    }
+
+    /// Get all of the sessions for a user that haven't expired.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user to get the sessions for
+    ///
+    /// # Returns
+    /// The sessions, most recently seen first.
+    pub async fn get_sessions_for_user(&self, user_id: &UserId) -> Vec<SessionResource> {
+        self.repository.get_sessions_for_user(user_id, Utc::now()).await
+    }
+
+    /// Check that a session is still active, recording that it has been seen if so.
+    /// The last seen time is only updated once a minute at most, so this is usually a read.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user that the session should belong to
+    /// - `session_id` - The ID of the session
+    ///
+    /// # Returns
+    /// True if the session is active. False if it has ended, expired or never existed.
+    pub async fn check_session(&self, user_id: &UserId, session_id: &SessionId) -> bool {
+        let now = Utc::now();
+
+        self.repository
+            .touch_session(user_id, session_id, now, now - Duration::seconds(TOUCH_INTERVAL_SECONDS))
+            .await
+    }
+
+    /// End a single session for a user.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user that the session belongs to
+    /// - `session_id` - The ID of the session
+    ///
+    /// # Returns
+    /// True if the session was ended. False if it didn't exist.
+    pub async fn end_session(&self, user_id: &UserId, session_id: &SessionId) -> bool {
+        self.repository.delete_session(user_id, session_id).await
+    }
+
+    /// End every session for a user, signing them out everywhere.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user to end all sessions for
+    pub async fn end_all_sessions(&self, user_id: &UserId) {
+        self.repository.delete_sessions_for_user(user_id).await;
+    }
+
+    /// Delete the sessions that have expired.
+    ///
+    /// # Returns
+    /// The number of sessions that were deleted.
+    #[tracing::instrument(skip(self))]
+    pub async fn purge(&self) -> u64 {
+        self.repository.delete_expired_sessions(Utc::now()).await
+    }
+}
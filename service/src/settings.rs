@@ -3,6 +3,55 @@ use serde::Deserialize;
 /// The actual settings for the service.
 #[derive(Debug, Deserialize)]
 pub struct Settings {
-    pub port:         u16,
-    pub database_url: String,
+    pub port:                     u16,
+    pub database_url:             String,
+    pub password_min_length:      usize,
+    pub password_min_strength:    u8,
+    pub breached_passwords_path:  Option<String>,
+    pub password_memory_size:     u32,
+    pub password_iterations:      u32,
+    pub password_lanes:           u32,
+    pub password_pepper:          Option<String>,
+    /// Where to store uploaded files - either "filesystem" or "s3".
+    pub blob_store:               String,
+    pub blob_path:                String,
+    pub s3_bucket:                Option<String>,
+    pub s3_region:                String,
+    pub s3_endpoint:              Option<String>,
+    pub s3_access_key:            Option<String>,
+    pub s3_secret_key:            Option<String>,
+    /// The largest file that can be uploaded, in bytes.
+    pub upload_max_size:          usize,
+    /// Which parts of the service to run in this process.
+    pub mode:                     Mode,
+    /// The number of background jobs to run at once.
+    pub job_concurrency:          usize,
+    /// The sinks to publish domain events to, separated by commas - any of "log", "webhook", "nats"
+    /// and "kafka".
+    pub outbox_sinks:             String,
+    pub outbox_webhook_url:       Option<String>,
+    pub outbox_webhook_secret:    Option<String>,
+    pub outbox_nats_url:          Option<String>,
+    /// The prefix of the NATS subjects that domain events are published on.
+    pub outbox_nats_prefix:       String,
+    /// The URL of the REST proxy in front of the Kafka-compatible broker.
+    pub outbox_kafka_url:         Option<String>,
+    pub outbox_kafka_topic:       String,
+    /// How many hours to keep idempotency keys for, during which retried requests are replayed.
+    pub idempotency_window_hours: u64,
+    /// The IP addresses of the reverse proxies, separated by commas, that are trusted to report the
+    /// address of the client in the `Forwarded` or `X-Forwarded-For` header.
+    pub trusted_proxies:          Option<String>,
+}
+
+/// Which parts of the service to run in a single process.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Mode {
+    /// Serve HTTP requests and run background work alongside them.
+    All,
+    /// Only serve HTTP requests, leaving background work to separate worker processes.
+    Server,
+    /// Only run background work, without serving any HTTP requests.
+    Worker,
 }
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use actix_web::web::{delete, get, patch, post, put, resource, ServiceConfig};
+
+use super::{repository::TaxonomyRepository, service::TaxonomyService};
+use crate::{articles::ArticleService, entities::EntityService, search::SearchService, server::RouteConfigurer};
+
+/// Component for working with the tags and categories of worlds.
+pub struct Component {
+    pub service: Arc<TaxonomyService>,
+}
+
+impl Component {
+    /// Create a new taxonomy component.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store tags and categories in
+    /// - `articles` - The service to load the articles that are tagged from
+    /// - `entities` - The service to load the entities that are tagged from
+    /// - `search` - The service to keep the tags in the search index up to date with
+    pub fn new(
+        repository: Arc<dyn TaxonomyRepository>,
+        articles: Arc<ArticleService>,
+        entities: Arc<EntityService>,
+        search: Arc<SearchService>,
+    ) -> Arc<Self> {
+        let service = Arc::new(TaxonomyService::new(repository, articles, entities, search));
+
+        Arc::new(Self { service })
+    }
+}
+
+impl RouteConfigurer for Component {
+    fn configure_routes(&self, config: &mut ServiceConfig) {
+        config.data(self.service.clone());
+
+        config.service(
+            resource("/worlds/{id}/tags")
+                .route(post().to(super::endpoints::create_tag::handle))
+                .route(get().to(super::endpoints::list_tags::handle)),
+        );
+        config.service(
+            resource("/worlds/{id}/tags/{tag}")
+                .route(get().to(super::endpoints::get_tag::handle))
+                .route(patch().to(super::endpoints::patch_tag::handle))
+                .route(delete().to(super::endpoints::delete_tag::handle)),
+        );
+        config.service(resource("/worlds/{id}/tags/{tag}/merge").route(post().to(super::endpoints::merge_tags::handle)));
+        config.service(resource("/worlds/{id}/tagged").route(get().to(super::endpoints::find_content::handle)));
+        config.service(
+            resource("/worlds/{id}/categories")
+                .route(post().to(super::endpoints::create_category::handle))
+                .route(get().to(super::endpoints::list_categories::handle)),
+        );
+        config.service(
+            resource("/worlds/{id}/categories/{category}")
+                .route(get().to(super::endpoints::get_category::handle))
+                .route(patch().to(super::endpoints::patch_category::handle))
+                .route(delete().to(super::endpoints::delete_category::handle)),
+        );
+        config.service(
+            resource("/worlds/{id}/taxonomy/{kind}/{resource}")
+                .route(get().to(super::endpoints::get_taxonomy::handle))
+                .route(put().to(super::endpoints::put_taxonomy::handle)),
+        );
+    }
+}
@@ -0,0 +1,83 @@
+pub(super) mod create_category;
+pub(super) mod create_tag;
+pub(super) mod delete_category;
+pub(super) mod delete_tag;
+pub(super) mod find_content;
+pub(super) mod get_category;
+pub(super) mod get_tag;
+pub(super) mod get_taxonomy;
+pub(super) mod list_categories;
+pub(super) mod list_tags;
+pub(super) mod merge_tags;
+mod model;
+pub(super) mod patch_category;
+pub(super) mod patch_tag;
+mod problems;
+pub(super) mod put_taxonomy;
+
+use crate::{
+    http::problem::{Problem, NOT_FOUND},
+    taxonomy::{CategoryId, Content, TagId},
+    worlds::WorldId,
+};
+
+/// Parse the ID of a world from the URL.
+///
+/// # Parameters
+/// - `world_id` - The ID of the world
+///
+/// # Returns
+/// The parsed ID, or a Not Found problem if it isn't valid.
+fn parse_world_id(world_id: &str) -> Result<WorldId, Problem> {
+    world_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, world_id = ?world_id, "Failed to parse World ID");
+
+        NOT_FOUND.into()
+    })
+}
+
+/// Parse the ID of a tag from the URL.
+///
+/// # Parameters
+/// - `tag_id` - The ID of the tag
+///
+/// # Returns
+/// The parsed ID, or a Not Found problem if it isn't valid.
+fn parse_tag_id(tag_id: &str) -> Result<TagId, Problem> {
+    tag_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, tag_id = ?tag_id, "Failed to parse Tag ID");
+
+        NOT_FOUND.into()
+    })
+}
+
+/// Parse the ID of a category from the URL.
+///
+/// # Parameters
+/// - `category_id` - The ID of the category
+///
+/// # Returns
+/// The parsed ID, or a Not Found problem if it isn't valid.
+fn parse_category_id(category_id: &str) -> Result<CategoryId, Problem> {
+    category_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, category_id = ?category_id, "Failed to parse Category ID");
+
+        NOT_FOUND.into()
+    })
+}
+
+/// Parse a reference to some content from the URL.
+///
+/// # Parameters
+/// - `kind` - The kind of content
+/// - `resource_id` - The ID of the article or entity
+///
+/// # Returns
+/// The content, or a Not Found problem if the reference isn't valid.
+fn parse_content(kind: &str, resource_id: &str) -> Result<Content, Problem> {
+    Content::parse(kind, resource_id).map_err(|e| {
+        tracing::warn!(e = ?e, kind = ?kind, resource_id = ?resource_id, "Failed to parse content reference");
+
+        NOT_FOUND.into()
+    })
+}
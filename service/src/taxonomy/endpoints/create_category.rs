@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use actix_http::http::StatusCode;
+use actix_web::web::{Data, Path};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{
+    model::{respond_category, CategoryResponse},
+    parse_world_id,
+    problems::unknown_category,
+};
+use crate::{
+    authorization::Authentication,
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR},
+        valid::{Valid, Validatable},
+    },
+    taxonomy::{CategoryId, SaveCategoryError, TaxonomyService},
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Create a new category within a world, optionally nested within another category.
+pub async fn handle(
+    service: Data<Arc<TaxonomyService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<String>,
+    request: Valid<CreateCategoryRequest>,
+    authentication: Authentication,
+) -> Result<CategoryResponse, Problem> {
+    let world_id = parse_world_id(&path)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let request = request.unwrap();
+
+    let category = service
+        .create_category(&world_id, &request.name, request.parent_id.clone())
+        .await
+        .map_err(|e| match (e, &request.parent_id) {
+            (SaveCategoryError::UnknownCategory, Some(parent_id)) => unknown_category(parent_id),
+            (SaveCategoryError::UnknownCategory | SaveCategoryError::UnknownError, _) => INTERNAL_SERVER_ERROR.into(),
+        })?;
+
+    Ok(respond_category(category).with_status_code(StatusCode::CREATED).into())
+}
+
+/// The incoming request to create a category.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCategoryRequest {
+    pub name:      String,
+    pub parent_id: Option<CategoryId>,
+}
+
+impl Validatable for CreateCategoryRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 200,
+                    "pattern": "\\S"
+                },
+                "parentId": CategoryId::schema()
+            },
+            "required": [
+                "name"
+            ]
+        })
+    }
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use actix_http::http::StatusCode;
+use actix_web::web::{Data, Path};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{
+    model::{respond_tag, TagResponse},
+    parse_world_id,
+    problems::DUPLICATE_TAG,
+};
+use crate::{
+    authorization::Authentication,
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR},
+        valid::{Valid, Validatable},
+    },
+    taxonomy::{SaveTagError, TaxonomyService},
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Create a new tag within a world.
+pub async fn handle(
+    service: Data<Arc<TaxonomyService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<String>,
+    request: Valid<CreateTagRequest>,
+    authentication: Authentication,
+) -> Result<TagResponse, Problem> {
+    let world_id = parse_world_id(&path)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let request = request.unwrap();
+
+    let tag = service.create_tag(&world_id, &request.name).await.map_err(|e| match e {
+        SaveTagError::DuplicateName => DUPLICATE_TAG,
+        SaveTagError::UnknownTag | SaveTagError::UnknownError => INTERNAL_SERVER_ERROR,
+    })?;
+
+    Ok(respond_tag(tag).with_status_code(StatusCode::CREATED).into())
+}
+
+/// The incoming request to create a tag.
+#[derive(Deserialize)]
+pub struct CreateTagRequest {
+    pub name: String,
+}
+
+impl Validatable for CreateTagRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 100,
+                    "pattern": "\\S"
+                }
+            },
+            "required": [
+                "name"
+            ]
+        })
+    }
+}
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use actix_web::{
+    web::{Data, Path},
+    HttpResponse,
+};
+
+use super::{parse_tag_id, parse_world_id};
+use crate::{
+    authorization::Authentication,
+    http::problem::{Problem, NOT_FOUND},
+    taxonomy::TaxonomyService,
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Delete a tag, removing it from everything that it is assigned to.
+pub async fn handle(
+    service: Data<Arc<TaxonomyService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<HttpResponse, Problem> {
+    let (world_id, tag_id) = path.into_inner();
+    let world_id = parse_world_id(&world_id)?;
+    let tag_id = parse_tag_id(&tag_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    if service.delete_tag(&world_id, &tag_id).await {
+        Ok(HttpResponse::NoContent().finish())
+    } else {
+        Err(NOT_FOUND.into())
+    }
+}
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path, Query};
+use serde::Deserialize;
+
+use super::{
+    model::{TaggedContentModel, TaggedModel},
+    parse_world_id,
+};
+use crate::{
+    authorization::Authentication,
+    http::{
+        pagination::{PageModel, PageQuery},
+        problem::{Problem, BAD_REQUEST},
+        response::Response,
+    },
+    taxonomy::{TagFilter, TagId, TaxonomyService},
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Query parameters for finding tagged content.
+#[derive(Deserialize)]
+pub struct FindRequest {
+    /// The content must have all of these tags, separated by commas.
+    pub all: Option<String>,
+    /// The content must have at least one of these tags, separated by commas.
+    pub any: Option<String>,
+}
+
+/// Find the articles and entities within a world that have a combination of tags.
+pub async fn handle(
+    service: Data<Arc<TaxonomyService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<String>,
+    query: Query<FindRequest>,
+    page: Query<PageQuery>,
+    authentication: Authentication,
+) -> Result<Response<TaggedModel>, Problem> {
+    let world_id = parse_world_id(&path)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let query = query.into_inner();
+    let filter = TagFilter {
+        all: parse_tag_list(query.all.as_deref())?,
+        any: parse_tag_list(query.any.as_deref())?,
+    };
+    if filter.all.is_empty() && filter.any.is_empty() {
+        return Err(Problem::from(BAD_REQUEST).with_detail("At least one tag is required"));
+    }
+
+    let pagination = page.pagination();
+    let (content, total) = service.find_content(&world_id, &filter, pagination).await;
+
+    Ok(Response(TaggedModel {
+        content: content.into_iter().map(TaggedContentModel::from).collect(),
+        page:    PageModel::new(pagination, total),
+    }))
+}
+
+/// Parse a comma-separated list of tag IDs from the query string.
+///
+/// # Parameters
+/// - `list` - The list, if it was provided
+///
+/// # Returns
+/// The tag IDs, or a Bad Request problem if any of them isn't valid.
+fn parse_tag_list(list: Option<&str>) -> Result<Vec<TagId>, Problem> {
+    list.unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| Problem::from(BAD_REQUEST).with_detail(format!("Invalid tag ID: {}", value)))
+        })
+        .collect()
+}
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::{
+    model::{respond_category, CategoryResponse},
+    parse_category_id, parse_world_id,
+};
+use crate::{
+    authorization::Authentication,
+    http::problem::{Problem, NOT_FOUND},
+    taxonomy::TaxonomyService,
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+pub async fn handle(
+    service: Data<Arc<TaxonomyService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<CategoryResponse, Problem> {
+    let (world_id, category_id) = path.into_inner();
+    let world_id = parse_world_id(&world_id)?;
+    let category_id = parse_category_id(&category_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let category = service.get_category(&world_id, &category_id).await.ok_or(NOT_FOUND)?;
+
+    Ok(respond_category(category).into())
+}
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::{model::TaxonomyModel, parse_content, parse_world_id};
+use crate::{
+    authorization::Authentication,
+    http::{
+        problem::{Problem, NOT_FOUND},
+        response::Response,
+    },
+    taxonomy::TaxonomyService,
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Get the tags and categories that are assigned to an article or entity.
+pub async fn handle(
+    service: Data<Arc<TaxonomyService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String, String)>,
+    authentication: Authentication,
+) -> Result<Response<TaxonomyModel>, Problem> {
+    let (world_id, kind, resource_id) = path.into_inner();
+    let world_id = parse_world_id(&world_id)?;
+    let content = parse_content(&kind, &resource_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let taxonomy = service.get_taxonomy(&world_id, &content).await.ok_or(NOT_FOUND)?;
+
+    Ok(Response(TaxonomyModel::from(taxonomy)))
+}
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::{
+    model::{CategoriesModel, CategoryNodeModel},
+    parse_world_id,
+};
+use crate::{
+    authorization::Authentication,
+    http::{problem::Problem, response::Response},
+    taxonomy::TaxonomyService,
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Get the tree of categories within a world.
+pub async fn handle(
+    service: Data<Arc<TaxonomyService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<String>,
+    authentication: Authentication,
+) -> Result<Response<CategoriesModel>, Problem> {
+    let world_id = parse_world_id(&path)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let categories = service
+        .get_category_tree(&world_id)
+        .await
+        .into_iter()
+        .map(CategoryNodeModel::from)
+        .collect();
+
+    Ok(Response(CategoriesModel { categories }))
+}
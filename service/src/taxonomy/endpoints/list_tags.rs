@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::{
+    model::{TagUsageModel, TagsModel},
+    parse_world_id,
+};
+use crate::{
+    authorization::Authentication,
+    http::{problem::Problem, response::Response},
+    taxonomy::TaxonomyService,
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Get every tag within a world, along with how much content each one is assigned to.
+pub async fn handle(
+    service: Data<Arc<TaxonomyService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<String>,
+    authentication: Authentication,
+) -> Result<Response<TagsModel>, Problem> {
+    let world_id = parse_world_id(&path)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let tags = service.get_tags(&world_id).await.into_iter().map(TagUsageModel::from).collect();
+
+    Ok(Response(TagsModel { tags }))
+}
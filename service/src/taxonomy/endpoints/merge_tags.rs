@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{
+    model::{respond_tag, TagResponse},
+    parse_tag_id, parse_world_id,
+    problems::{unknown_tag, SAME_TAG},
+};
+use crate::{
+    authorization::Authentication,
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+        valid::{Valid, Validatable},
+    },
+    taxonomy::{MergeTagsError, TagId, TaxonomyService},
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Merge the tag in the URL into another tag, deleting it and assigning the other tag to
+/// everything that it was assigned to.
+pub async fn handle(
+    service: Data<Arc<TaxonomyService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    request: Valid<MergeRequest>,
+    authentication: Authentication,
+) -> Result<TagResponse, Problem> {
+    let (world_id, tag_id) = path.into_inner();
+    let world_id = parse_world_id(&world_id)?;
+    let tag_id = parse_tag_id(&tag_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    service.get_tag(&world_id, &tag_id).await.ok_or(NOT_FOUND)?;
+    let target_id = request.unwrap().tag_id;
+
+    let tag = service.merge_tags(&world_id, &tag_id, &target_id).await.map_err(|e| match e {
+        MergeTagsError::SameTag => SAME_TAG.into(),
+        MergeTagsError::UnknownTag => unknown_tag(&target_id),
+        MergeTagsError::UnknownError => INTERNAL_SERVER_ERROR.into(),
+    })?;
+
+    Ok(respond_tag(tag).into())
+}
+
+/// The incoming request to merge a tag into another.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeRequest {
+    /// The tag to merge into.
+    pub tag_id: TagId,
+}
+
+impl Validatable for MergeRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "tagId": TagId::schema()
+            },
+            "required": [
+                "tagId"
+            ]
+        })
+    }
+}
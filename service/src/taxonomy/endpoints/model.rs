@@ -0,0 +1,171 @@
+use actix_web::http::header::{CacheControl, CacheDirective, ETag, EntityTag};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::{
+    http::{
+        pagination::PageModel,
+        response::{Response, SimpleRespondable},
+    },
+    taxonomy::{CategoryId, CategoryNode, CategoryResource, ContentSummary, TagId, TagResource, TagUsage, Taxonomy},
+};
+
+/// Representation of a tag on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagModel {
+    pub tag_id:  TagId,
+    pub name:    String,
+    pub created: DateTime<Utc>,
+    pub updated: DateTime<Utc>,
+}
+
+impl From<TagResource> for TagModel {
+    fn from(tag: TagResource) -> Self {
+        Self {
+            tag_id:  tag.identity.id,
+            name:    tag.data.name,
+            created: tag.identity.created,
+            updated: tag.identity.updated,
+        }
+    }
+}
+
+pub type TagResponse = Response<SimpleRespondable<TagModel>>;
+
+/// Build the response for a single tag.
+///
+/// # Parameters
+/// - `tag` - The tag
+pub fn respond_tag(tag: TagResource) -> SimpleRespondable<TagModel> {
+    let etag = EntityTag::strong(tag.identity.version.to_string());
+
+    SimpleRespondable::new(TagModel::from(tag))
+        .with_header(ETag(etag))
+        .with_header(CacheControl(vec![CacheDirective::Private, CacheDirective::NoCache]))
+}
+
+/// Representation of a tag on the HTTP API, along with how much content it is assigned to.
+#[derive(Serialize)]
+pub struct TagUsageModel {
+    #[serde(flatten)]
+    pub tag:   TagModel,
+    pub count: u64,
+}
+
+impl From<TagUsage> for TagUsageModel {
+    fn from(usage: TagUsage) -> Self {
+        Self {
+            tag:   TagModel::from(usage.tag),
+            count: usage.count,
+        }
+    }
+}
+
+/// Representation of a list of tags on the HTTP API.
+#[derive(Serialize)]
+pub struct TagsModel {
+    pub tags: Vec<TagUsageModel>,
+}
+
+/// Representation of a category on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryModel {
+    pub category_id: CategoryId,
+    pub name:        String,
+    pub parent_id:   Option<CategoryId>,
+    pub created:     DateTime<Utc>,
+    pub updated:     DateTime<Utc>,
+}
+
+impl From<CategoryResource> for CategoryModel {
+    fn from(category: CategoryResource) -> Self {
+        Self {
+            category_id: category.identity.id,
+            name:        category.data.name,
+            parent_id:   category.data.parent_id,
+            created:     category.identity.created,
+            updated:     category.identity.updated,
+        }
+    }
+}
+
+pub type CategoryResponse = Response<SimpleRespondable<CategoryModel>>;
+
+/// Build the response for a single category.
+///
+/// # Parameters
+/// - `category` - The category
+pub fn respond_category(category: CategoryResource) -> SimpleRespondable<CategoryModel> {
+    let etag = EntityTag::strong(category.identity.version.to_string());
+
+    SimpleRespondable::new(CategoryModel::from(category))
+        .with_header(ETag(etag))
+        .with_header(CacheControl(vec![CacheDirective::Private, CacheDirective::NoCache]))
+}
+
+/// Representation of a category within the category tree on the HTTP API.
+#[derive(Serialize)]
+pub struct CategoryNodeModel {
+    #[serde(flatten)]
+    pub category: CategoryModel,
+    pub children: Vec<CategoryNodeModel>,
+}
+
+impl From<CategoryNode> for CategoryNodeModel {
+    fn from(node: CategoryNode) -> Self {
+        Self {
+            category: CategoryModel::from(node.category),
+            children: node.children.into_iter().map(Self::from).collect(),
+        }
+    }
+}
+
+/// Representation of the category tree of a world on the HTTP API.
+#[derive(Serialize)]
+pub struct CategoriesModel {
+    pub categories: Vec<CategoryNodeModel>,
+}
+
+/// Representation of the tags and categories assigned to some content on the HTTP API.
+#[derive(Serialize)]
+pub struct TaxonomyModel {
+    pub tags:       Vec<TagModel>,
+    pub categories: Vec<CategoryModel>,
+}
+
+impl From<Taxonomy> for TaxonomyModel {
+    fn from(taxonomy: Taxonomy) -> Self {
+        Self {
+            tags:       taxonomy.tags.into_iter().map(TagModel::from).collect(),
+            categories: taxonomy.categories.into_iter().map(CategoryModel::from).collect(),
+        }
+    }
+}
+
+/// Representation of a piece of tagged content on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaggedContentModel {
+    pub kind:        String,
+    pub resource_id: String,
+    pub title:       String,
+}
+
+impl From<ContentSummary> for TaggedContentModel {
+    fn from(summary: ContentSummary) -> Self {
+        Self {
+            kind:        summary.content.kind().to_owned(),
+            resource_id: summary.content.to_string(),
+            title:       summary.title,
+        }
+    }
+}
+
+/// Representation of a page of tagged content on the HTTP API.
+#[derive(Serialize)]
+pub struct TaggedModel {
+    pub content: Vec<TaggedContentModel>,
+    pub page:    PageModel,
+}
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+use serde::{Deserialize, Deserializer};
+use serde_json::{json, Value};
+
+use super::{
+    model::{respond_category, CategoryResponse},
+    parse_category_id, parse_world_id,
+    problems::{unknown_category, NESTED_WITHIN_ITSELF},
+};
+use crate::{
+    authorization::Authentication,
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+        valid::{Valid, Validatable},
+    },
+    taxonomy::{CategoryData, CategoryId, TaxonomyService, UpdateCategoryError},
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Patch the details of a category, including moving it within the category tree.
+pub async fn handle(
+    service: Data<Arc<TaxonomyService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    request: Valid<PatchRequest>,
+    authentication: Authentication,
+) -> Result<CategoryResponse, Problem> {
+    let (world_id, category_id) = path.into_inner();
+    let world_id = parse_world_id(&world_id)?;
+    let category_id = parse_category_id(&category_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let PatchRequest { name, parent_id } = request.unwrap();
+    let requested_parent = parent_id.clone().flatten();
+
+    let category = service
+        .update_category_by_id(&world_id, &category_id, move |category| -> Result<CategoryData, Problem> {
+            Ok(CategoryData {
+                name: name.unwrap_or(category.name),
+                parent_id: parent_id.unwrap_or(category.parent_id),
+                ..category
+            })
+        })
+        .await
+        .map_err(|e| match (e, &requested_parent) {
+            (UpdateCategoryError::UpdateError(p), _) => p,
+            (UpdateCategoryError::UnknownCategory, _) => NOT_FOUND.into(),
+            (UpdateCategoryError::UnknownParent, Some(parent_id)) => unknown_category(parent_id),
+            (UpdateCategoryError::NestedWithinItself, _) => NESTED_WITHIN_ITSELF.into(),
+            (UpdateCategoryError::UnknownParent | UpdateCategoryError::UnknownError, _) => INTERNAL_SERVER_ERROR.into(),
+        })?;
+
+    Ok(respond_category(category).into())
+}
+
+/// The incoming request to patch a category.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchRequest {
+    pub name:      Option<String>,
+    /// The category to move this one within. Absent to leave it where it is, or `null` to move
+    /// it to the top of the tree.
+    #[serde(default, deserialize_with = "present")]
+    #[allow(clippy::option_option)] // An explicit `null` is different to leaving the field out.
+    pub parent_id: Option<Option<CategoryId>>,
+}
+
+/// Deserialize a field that was present in the request, so that an explicit `null` can be told
+/// apart from the field being missing.
+#[allow(clippy::option_option)]
+fn present<'de, D>(deserializer: D) -> Result<Option<Option<CategoryId>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::deserialize(deserializer).map(Some)
+}
+
+impl Validatable for PatchRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 200,
+                    "pattern": "\\S"
+                },
+                "parentId": {
+                    "oneOf": [
+                        CategoryId::schema(),
+                        { "type": "null" }
+                    ]
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{
+    model::{respond_tag, TagResponse},
+    parse_tag_id, parse_world_id,
+    problems::DUPLICATE_TAG,
+};
+use crate::{
+    authorization::Authentication,
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+        valid::{Valid, Validatable},
+    },
+    taxonomy::{SaveTagError, TaxonomyService},
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Rename a tag. Everything the tag is assigned to keeps it under its new name.
+pub async fn handle(
+    service: Data<Arc<TaxonomyService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    request: Valid<PatchRequest>,
+    authentication: Authentication,
+) -> Result<TagResponse, Problem> {
+    let (world_id, tag_id) = path.into_inner();
+    let world_id = parse_world_id(&world_id)?;
+    let tag_id = parse_tag_id(&tag_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let request = request.unwrap();
+
+    let tag = service.rename_tag(&world_id, &tag_id, &request.name).await.map_err(|e| match e {
+        SaveTagError::DuplicateName => DUPLICATE_TAG,
+        SaveTagError::UnknownTag => NOT_FOUND,
+        SaveTagError::UnknownError => INTERNAL_SERVER_ERROR,
+    })?;
+
+    Ok(respond_tag(tag).into())
+}
+
+/// The incoming request to patch a tag.
+#[derive(Deserialize)]
+pub struct PatchRequest {
+    pub name: String,
+}
+
+impl Validatable for PatchRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 100,
+                    "pattern": "\\S"
+                }
+            },
+            "required": [
+                "name"
+            ]
+        })
+    }
+}
@@ -0,0 +1,57 @@
+use actix_http::http::StatusCode;
+
+use crate::{
+    http::problem::{Problem, SimpleProblemType},
+    taxonomy::{CategoryId, TagId},
+};
+
+/// Problem to indicate that another tag in the world already has the same name.
+pub const DUPLICATE_TAG: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/taxonomy/duplicate_tag",
+    problem_title: "Duplicate Tag Name",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate that a tag was merged into itself.
+pub const SAME_TAG: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/taxonomy/same_tag",
+    problem_title: "A tag can not be merged into itself",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate a reference to a tag that doesn't exist in the world.
+pub const UNKNOWN_TAG: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/taxonomy/unknown_tag",
+    problem_title: "Unknown Tag",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate a reference to a category that doesn't exist in the world.
+pub const UNKNOWN_CATEGORY: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/taxonomy/unknown_category",
+    problem_title: "Unknown Category",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate that a category was moved to within itself.
+pub const NESTED_WITHIN_ITSELF: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/taxonomy/nested_within_itself",
+    problem_title: "A category can not be nested within itself",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Build the problem for a reference to a tag that doesn't exist.
+///
+/// # Parameters
+/// - `tag_id` - The ID of the tag that doesn't exist
+pub fn unknown_tag(tag_id: &TagId) -> Problem {
+    Problem::from(UNKNOWN_TAG).with_extra("tagId", tag_id)
+}
+
+/// Build the problem for a reference to a category that doesn't exist.
+///
+/// # Parameters
+/// - `category_id` - The ID of the category that doesn't exist
+pub fn unknown_category(category_id: &CategoryId) -> Problem {
+    Problem::from(UNKNOWN_CATEGORY).with_extra("categoryId", category_id)
+}
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{
+    model::TaxonomyModel,
+    parse_content, parse_world_id,
+    problems::{unknown_category, unknown_tag},
+};
+use crate::{
+    authorization::Authentication,
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+        response::Response,
+        valid::{Valid, Validatable},
+    },
+    taxonomy::{AssignTaxonomyError, CategoryId, TagId, TaxonomyService},
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+/// Replace the tags and categories that are assigned to an article or entity.
+pub async fn handle(
+    service: Data<Arc<TaxonomyService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String, String)>,
+    request: Valid<PutRequest>,
+    authentication: Authentication,
+) -> Result<Response<TaxonomyModel>, Problem> {
+    let (world_id, kind, resource_id) = path.into_inner();
+    let world_id = parse_world_id(&world_id)?;
+    let content = parse_content(&kind, &resource_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let request = request.unwrap();
+
+    let taxonomy = service
+        .set_taxonomy(&world_id, &content, &request.tags, &request.categories)
+        .await
+        .map_err(|e| match e {
+            AssignTaxonomyError::UnknownContent => NOT_FOUND.into(),
+            AssignTaxonomyError::UnknownTag(tag_id) => unknown_tag(&tag_id),
+            AssignTaxonomyError::UnknownCategory(category_id) => unknown_category(&category_id),
+            AssignTaxonomyError::UnknownError => INTERNAL_SERVER_ERROR.into(),
+        })?;
+
+    Ok(Response(TaxonomyModel::from(taxonomy)))
+}
+
+/// The incoming request to assign tags and categories to some content.
+#[derive(Deserialize)]
+pub struct PutRequest {
+    #[serde(default)]
+    pub tags:       Vec<TagId>,
+    #[serde(default)]
+    pub categories: Vec<CategoryId>,
+}
+
+impl Validatable for PutRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "tags": {
+                    "type": "array",
+                    "items": TagId::schema(),
+                    "maxItems": 100
+                },
+                "categories": {
+                    "type": "array",
+                    "items": CategoryId::schema(),
+                    "maxItems": 100
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,71 @@
+mod category_id;
+mod content;
+mod tag_id;
+
+pub use category_id::*;
+pub use content::*;
+pub use tag_id::*;
+
+use crate::{model::Resource, worlds::WorldId};
+
+/// The data representing a tag within a world.
+#[derive(Debug, Clone)]
+pub struct TagData {
+    pub world_id: WorldId,
+    /// The name of the tag, which is unique within the world ignoring case.
+    pub name:     String,
+}
+
+/// Type representing a persisted tag.
+pub type TagResource = Resource<TagId, TagData>;
+
+/// A tag, along with how many pieces of content it is assigned to.
+#[derive(Debug, Clone)]
+pub struct TagUsage {
+    pub tag:   TagResource,
+    pub count: u64,
+}
+
+/// The data representing a category within a world.
+#[derive(Debug, Clone)]
+pub struct CategoryData {
+    pub world_id:  WorldId,
+    pub name:      String,
+    /// The category that this one is nested within, if any.
+    pub parent_id: Option<CategoryId>,
+}
+
+/// Type representing a persisted category.
+pub type CategoryResource = Resource<CategoryId, CategoryData>;
+
+/// A category within the category tree of a world, along with all of the categories nested
+/// within it.
+#[derive(Debug, Clone)]
+pub struct CategoryNode {
+    pub category: CategoryResource,
+    pub children: Vec<CategoryNode>,
+}
+
+/// The tags and categories that are assigned to a piece of content.
+#[derive(Debug, Clone, Default)]
+pub struct Taxonomy {
+    pub tags:       Vec<TagResource>,
+    pub categories: Vec<CategoryResource>,
+}
+
+/// A combination of tags to find content by.
+///
+/// Content matches if it has every one of the `all` tags, and at least one of the `any` tags.
+/// Either list can be empty, in which case it doesn't restrict the content.
+#[derive(Debug, Clone, Default)]
+pub struct TagFilter {
+    pub all: Vec<TagId>,
+    pub any: Vec<TagId>,
+}
+
+/// A piece of content, along with its title.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContentSummary {
+    pub content: Content,
+    pub title:   String,
+}
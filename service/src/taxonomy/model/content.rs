@@ -0,0 +1,98 @@
+use std::fmt::Display;
+
+use crate::{articles::ArticleId, entities::EntityId};
+
+/// The kind of content that articles are.
+pub const ARTICLE_KIND: &str = "article";
+
+/// A piece of content within a world that tags and categories can be assigned to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Content {
+    Article(ArticleId),
+    Entity { kind: String, entity_id: EntityId },
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ParseContentError {
+    #[error("The kind of content was blank")]
+    BlankKind,
+
+    #[error("The ID of the content was malformed")]
+    MalformedId,
+}
+
+impl Content {
+    /// Parse a reference to some content.
+    ///
+    /// # Parameters
+    /// - `kind` - The kind of content. Either `article`, or the kind of entity
+    /// - `id` - The ID of the article or entity
+    ///
+    /// # Returns
+    /// The content. This is only a reference, so the content might not actually exist.
+    pub fn parse(kind: &str, id: &str) -> Result<Self, ParseContentError> {
+        let kind = kind.trim();
+        if kind.is_empty() {
+            Err(ParseContentError::BlankKind)
+        } else if kind == ARTICLE_KIND {
+            id.parse().map(Content::Article).map_err(|_| ParseContentError::MalformedId)
+        } else {
+            id.parse()
+                .map(|entity_id| Content::Entity {
+                    kind: kind.to_owned(),
+                    entity_id,
+                })
+                .map_err(|_| ParseContentError::MalformedId)
+        }
+    }
+
+    /// The kind of content, as used by the search index.
+    pub fn kind(&self) -> &str {
+        match self {
+            Content::Article(_) => ARTICLE_KIND,
+            Content::Entity { kind, .. } => kind,
+        }
+    }
+}
+
+impl Display for Content {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Content::Article(article_id) => write!(f, "{}", article_id),
+            Content::Entity { entity_id, .. } => write!(f, "{}", entity_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test]
+    fn parse_article() {
+        let_assert!(Ok(content) = Content::parse("article", "50b44401-a345-419d-a8a8-baf22df76c05"));
+
+        check!(content == Content::Article("50b44401-a345-419d-a8a8-baf22df76c05".parse().unwrap()));
+        check!(content.kind() == "article");
+        check!(content.to_string() == "50b44401-a345-419d-a8a8-baf22df76c05");
+    }
+
+    #[test]
+    fn parse_entity() {
+        let_assert!(Ok(content) = Content::parse("character", "50b44401-a345-419d-a8a8-baf22df76c05"));
+
+        check!(content.kind() == "character");
+        check!(content.to_string() == "50b44401-a345-419d-a8a8-baf22df76c05");
+    }
+
+    #[test_case("", "50b44401-a345-419d-a8a8-baf22df76c05", &ParseContentError::BlankKind ; "Blank kind")]
+    #[test_case("article", "xxx", &ParseContentError::MalformedId ; "Malformed article")]
+    #[test_case("location", "", &ParseContentError::MalformedId ; "Blank entity")]
+    fn test_parse_fail(kind: &str, id: &str, expected: &ParseContentError) {
+        let_assert!(Err(e) = Content::parse(kind, id));
+        check!(&e == expected);
+    }
+}
@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod memory;
+mod parse;
+mod postgres;
+mod save_category;
+mod save_tag;
+mod save_taxonomy;
+
+#[cfg(test)]
+pub use memory::MemoryTaxonomyRepository;
+pub use parse::{content_columns, parse_content};
+pub use postgres::PostgresTaxonomyRepository;
+pub use save_category::SaveCategoryError;
+pub use save_tag::SaveTagError;
+pub use save_taxonomy::SaveTaxonomyError;
+
+use super::{CategoryData, CategoryId, CategoryResource, Content, TagData, TagFilter, TagId, TagResource, TagUsage, Taxonomy};
+use crate::{http::pagination::Pagination, worlds::WorldId};
+
+/// Repository of the tags and categories of worlds, and the content that they are assigned to.
+#[async_trait::async_trait]
+pub trait TaxonomyRepository: Send + Sync {
+    /// Create a new tag.
+    ///
+    /// # Parameters
+    /// - `tag` - The details of the tag to create.
+    ///
+    /// # Returns
+    /// The created tag.
+    async fn create_tag(&self, tag: &TagData) -> Result<TagResource, SaveTagError>;
+
+    /// Get a single tag.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the tag belongs to.
+    /// - `tag_id` - The ID of the tag.
+    ///
+    /// # Returns
+    /// The tag, or `None` if it couldn't be found.
+    async fn get_tag(&self, world_id: &WorldId, tag_id: &TagId) -> Option<TagResource>;
+
+    /// Get every tag within a world, along with how much content each one is assigned to.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world.
+    ///
+    /// # Returns
+    /// The tags, sorted by name.
+    async fn get_tags(&self, world_id: &WorldId) -> Vec<TagUsage>;
+
+    /// Update an existing tag.
+    ///
+    /// # Parameters
+    /// - `tag_id` - The ID of the tag.
+    /// - `tag` - The new details of the tag.
+    ///
+    /// # Returns
+    /// The updated tag.
+    async fn update_tag(&self, tag_id: &TagId, tag: &TagData) -> Result<TagResource, SaveTagError>;
+
+    /// Delete a single tag, removing it from all of the content that it is assigned to.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the tag belongs to.
+    /// - `tag_id` - The ID of the tag.
+    ///
+    /// # Returns
+    /// True if the tag existed and was deleted. False if not.
+    async fn delete_tag(&self, world_id: &WorldId, tag_id: &TagId) -> bool;
+
+    /// Merge one tag into another. The content that the source tag is assigned to is given the
+    /// target tag instead, and then the source tag is deleted.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that both tags belong to.
+    /// - `source_id` - The ID of the tag to merge, which is deleted.
+    /// - `target_id` - The ID of the tag to merge into, which is kept.
+    async fn merge_tags(&self, world_id: &WorldId, source_id: &TagId, target_id: &TagId) -> Result<(), SaveTagError>;
+
+    /// Get all of the content that a tag is assigned to.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the tag belongs to.
+    /// - `tag_id` - The ID of the tag.
+    async fn get_tagged_content(&self, world_id: &WorldId, tag_id: &TagId) -> Vec<Content>;
+
+    /// Find the content that has a combination of tags.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world.
+    /// - `filter` - The combination of tags that the content must have.
+    /// - `pagination` - Which page of content to return.
+    ///
+    /// # Returns
+    /// The page of matching content, and the total number of pieces of content that match.
+    async fn find_content(&self, world_id: &WorldId, filter: &TagFilter, pagination: Pagination) -> (Vec<Content>, u64);
+
+    /// Create a new category.
+    ///
+    /// # Parameters
+    /// - `category` - The details of the category to create.
+    ///
+    /// # Returns
+    /// The created category.
+    async fn create_category(&self, category: &CategoryData) -> Result<CategoryResource, SaveCategoryError>;
+
+    /// Get a single category.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the category belongs to.
+    /// - `category_id` - The ID of the category.
+    ///
+    /// # Returns
+    /// The category, or `None` if it couldn't be found.
+    async fn get_category(&self, world_id: &WorldId, category_id: &CategoryId) -> Option<CategoryResource>;
+
+    /// Get every category within a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world.
+    ///
+    /// # Returns
+    /// The categories, sorted by name.
+    async fn get_categories(&self, world_id: &WorldId) -> Vec<CategoryResource>;
+
+    /// Update an existing category.
+    ///
+    /// # Parameters
+    /// - `category_id` - The ID of the category.
+    /// - `category` - The new details of the category.
+    ///
+    /// # Returns
+    /// The updated category.
+    async fn update_category(&self, category_id: &CategoryId, category: &CategoryData) -> Result<CategoryResource, SaveCategoryError>;
+
+    /// Delete a single category. Any categories nested within it are moved up to its parent.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the category belongs to.
+    /// - `category_id` - The ID of the category.
+    ///
+    /// # Returns
+    /// True if the category existed and was deleted. False if not.
+    async fn delete_category(&self, world_id: &WorldId, category_id: &CategoryId) -> bool;
+
+    /// Get the tags and categories that are assigned to some content.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the content belongs to.
+    /// - `content` - The content.
+    ///
+    /// # Returns
+    /// The tags and categories, each sorted by name.
+    async fn get_taxonomy(&self, world_id: &WorldId, content: &Content) -> Taxonomy;
+
+    /// Replace the tags and categories that are assigned to some content.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the content belongs to.
+    /// - `content` - The content.
+    /// - `tags` - The IDs of the tags to assign to the content.
+    /// - `categories` - The IDs of the categories to assign to the content.
+    async fn set_taxonomy(
+        &self,
+        world_id: &WorldId,
+        content: &Content,
+        tags: &[TagId],
+        categories: &[CategoryId],
+    ) -> Result<(), SaveTaxonomyError>;
+}
@@ -0,0 +1,307 @@
+use std::{convert::TryFrom, sync::Mutex};
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::{SaveCategoryError, SaveTagError, SaveTaxonomyError, TaxonomyRepository};
+use crate::{
+    http::pagination::Pagination,
+    model::Identity,
+    taxonomy::{CategoryData, CategoryId, CategoryResource, Content, TagData, TagFilter, TagId, TagResource, TagUsage, Taxonomy},
+    worlds::WorldId,
+};
+
+/// Repository of tags and categories that are stored in memory.
+#[derive(Default)]
+pub struct MemoryTaxonomyRepository {
+    tags:               Mutex<Vec<TagResource>>,
+    categories:         Mutex<Vec<CategoryResource>>,
+    /// The content that each tag is assigned to.
+    content_tags:       Mutex<Vec<(TagId, Content)>>,
+    /// The content that each category is assigned to.
+    content_categories: Mutex<Vec<(CategoryId, Content)>>,
+}
+
+impl MemoryTaxonomyRepository {
+    /// Create a new, empty taxonomy repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl TaxonomyRepository for MemoryTaxonomyRepository {
+    async fn create_tag(&self, tag: &TagData) -> Result<TagResource, SaveTagError> {
+        let mut tags = self.tags.lock().unwrap();
+        if tags
+            .iter()
+            .any(|t| t.data.world_id == tag.world_id && t.data.name.to_lowercase() == tag.name.to_lowercase())
+        {
+            return Err(SaveTagError::DuplicateName);
+        }
+
+        let created = TagResource {
+            identity: Identity::default(),
+            data:     tag.clone(),
+        };
+        tags.push(created.clone());
+
+        Ok(created)
+    }
+
+    async fn get_tag(&self, world_id: &WorldId, tag_id: &TagId) -> Option<TagResource> {
+        let tags = self.tags.lock().unwrap();
+
+        tags.iter()
+            .find(|t| &t.data.world_id == world_id && &t.identity.id == tag_id)
+            .cloned()
+    }
+
+    async fn get_tags(&self, world_id: &WorldId) -> Vec<TagUsage> {
+        let tags = self.tags.lock().unwrap();
+        let content_tags = self.content_tags.lock().unwrap();
+
+        let mut result: Vec<TagUsage> = tags
+            .iter()
+            .filter(|t| &t.data.world_id == world_id)
+            .map(|t| TagUsage {
+                tag:   t.clone(),
+                count: u64::try_from(content_tags.iter().filter(|(tag_id, _)| tag_id == &t.identity.id).count()).unwrap_or_default(),
+            })
+            .collect();
+        result.sort_by_key(|t| t.tag.data.name.to_lowercase());
+
+        result
+    }
+
+    async fn update_tag(&self, tag_id: &TagId, tag: &TagData) -> Result<TagResource, SaveTagError> {
+        let mut tags = self.tags.lock().unwrap();
+        if tags
+            .iter()
+            .any(|t| &t.identity.id != tag_id && t.data.world_id == tag.world_id && t.data.name.to_lowercase() == tag.name.to_lowercase())
+        {
+            return Err(SaveTagError::DuplicateName);
+        }
+
+        let existing = tags
+            .iter_mut()
+            .find(|t| &t.identity.id == tag_id && t.data.world_id == tag.world_id)
+            .ok_or(SaveTagError::UnknownTag)?;
+
+        existing.identity.version = Uuid::new_v4();
+        existing.identity.updated = Utc::now();
+        existing.data = tag.clone();
+
+        Ok(existing.clone())
+    }
+
+    async fn delete_tag(&self, world_id: &WorldId, tag_id: &TagId) -> bool {
+        let mut tags = self.tags.lock().unwrap();
+
+        let before = tags.len();
+        tags.retain(|t| !(&t.data.world_id == world_id && &t.identity.id == tag_id));
+        let deleted = tags.len() != before;
+
+        if deleted {
+            self.content_tags.lock().unwrap().retain(|(t, _)| t != tag_id);
+        }
+
+        deleted
+    }
+
+    async fn merge_tags(&self, world_id: &WorldId, source_id: &TagId, target_id: &TagId) -> Result<(), SaveTagError> {
+        let mut tags = self.tags.lock().unwrap();
+        let found = tags
+            .iter()
+            .filter(|t| &t.data.world_id == world_id && (&t.identity.id == source_id || &t.identity.id == target_id))
+            .count();
+        if found != 2 {
+            return Err(SaveTagError::UnknownTag);
+        }
+
+        let mut content_tags = self.content_tags.lock().unwrap();
+        let moved: Vec<Content> = content_tags
+            .iter()
+            .filter(|(t, _)| t == source_id)
+            .map(|(_, c)| c.clone())
+            .collect();
+        content_tags.retain(|(t, _)| t != source_id);
+        for content in moved {
+            if !content_tags.iter().any(|(t, c)| t == target_id && c == &content) {
+                content_tags.push((target_id.clone(), content));
+            }
+        }
+
+        tags.retain(|t| &t.identity.id != source_id);
+
+        Ok(())
+    }
+
+    async fn get_tagged_content(&self, world_id: &WorldId, tag_id: &TagId) -> Vec<Content> {
+        if self.get_tag(world_id, tag_id).await.is_none() {
+            return vec![];
+        }
+
+        let content_tags = self.content_tags.lock().unwrap();
+
+        content_tags.iter().filter(|(t, _)| t == tag_id).map(|(_, c)| c.clone()).collect()
+    }
+
+    async fn find_content(&self, world_id: &WorldId, filter: &TagFilter, pagination: Pagination) -> (Vec<Content>, u64) {
+        let tags = self.tags.lock().unwrap();
+        let content_tags = self.content_tags.lock().unwrap();
+
+        let in_world: Vec<&(TagId, Content)> = content_tags
+            .iter()
+            .filter(|(t, _)| tags.iter().any(|tag| &tag.identity.id == t && &tag.data.world_id == world_id))
+            .collect();
+
+        let mut matches: Vec<Content> = vec![];
+        for (_, content) in &in_world {
+            if matches.contains(content) {
+                continue;
+            }
+
+            let has = |tag_id: &TagId| in_world.iter().any(|(t, c)| t == tag_id && c == content);
+            if filter.all.iter().all(has) && (filter.any.is_empty() || filter.any.iter().any(has)) {
+                matches.push(content.clone());
+            }
+        }
+        matches.sort_by_key(|c| (c.kind().to_owned(), c.to_string()));
+
+        let total = u64::try_from(matches.len()).unwrap_or_default();
+
+        (pagination.apply(matches), total)
+    }
+
+    async fn create_category(&self, category: &CategoryData) -> Result<CategoryResource, SaveCategoryError> {
+        let mut categories = self.categories.lock().unwrap();
+        if let Some(parent_id) = &category.parent_id {
+            if !categories
+                .iter()
+                .any(|c| &c.identity.id == parent_id && c.data.world_id == category.world_id)
+            {
+                return Err(SaveCategoryError::UnknownCategory);
+            }
+        }
+
+        let created = CategoryResource {
+            identity: Identity::default(),
+            data:     category.clone(),
+        };
+        categories.push(created.clone());
+
+        Ok(created)
+    }
+
+    async fn get_category(&self, world_id: &WorldId, category_id: &CategoryId) -> Option<CategoryResource> {
+        let categories = self.categories.lock().unwrap();
+
+        categories
+            .iter()
+            .find(|c| &c.data.world_id == world_id && &c.identity.id == category_id)
+            .cloned()
+    }
+
+    async fn get_categories(&self, world_id: &WorldId) -> Vec<CategoryResource> {
+        let categories = self.categories.lock().unwrap();
+
+        let mut result: Vec<CategoryResource> = categories.iter().filter(|c| &c.data.world_id == world_id).cloned().collect();
+        result.sort_by_key(|c| c.data.name.to_lowercase());
+
+        result
+    }
+
+    async fn update_category(&self, category_id: &CategoryId, category: &CategoryData) -> Result<CategoryResource, SaveCategoryError> {
+        let mut categories = self.categories.lock().unwrap();
+
+        let existing = categories
+            .iter_mut()
+            .find(|c| &c.identity.id == category_id && c.data.world_id == category.world_id)
+            .ok_or(SaveCategoryError::UnknownCategory)?;
+
+        existing.identity.version = Uuid::new_v4();
+        existing.identity.updated = Utc::now();
+        existing.data = category.clone();
+
+        Ok(existing.clone())
+    }
+
+    async fn delete_category(&self, world_id: &WorldId, category_id: &CategoryId) -> bool {
+        let mut categories = self.categories.lock().unwrap();
+
+        let deleted = match categories
+            .iter()
+            .position(|c| &c.data.world_id == world_id && &c.identity.id == category_id)
+        {
+            Some(index) => categories.remove(index),
+            None => return false,
+        };
+
+        for category in categories.iter_mut() {
+            if category.data.parent_id.as_ref() == Some(category_id) {
+                category.data.parent_id = deleted.data.parent_id.clone();
+            }
+        }
+        self.content_categories.lock().unwrap().retain(|(c, _)| c != category_id);
+
+        true
+    }
+
+    async fn get_taxonomy(&self, world_id: &WorldId, content: &Content) -> Taxonomy {
+        let known_tags = self.tags.lock().unwrap();
+        let known_categories = self.categories.lock().unwrap();
+        let content_tags = self.content_tags.lock().unwrap();
+        let content_categories = self.content_categories.lock().unwrap();
+
+        let mut tags: Vec<TagResource> = known_tags
+            .iter()
+            .filter(|t| &t.data.world_id == world_id)
+            .filter(|t| content_tags.iter().any(|(id, c)| id == &t.identity.id && c == content))
+            .cloned()
+            .collect();
+        tags.sort_by_key(|t| t.data.name.to_lowercase());
+
+        let mut categories: Vec<CategoryResource> = known_categories
+            .iter()
+            .filter(|t| &t.data.world_id == world_id)
+            .filter(|t| content_categories.iter().any(|(id, c)| id == &t.identity.id && c == content))
+            .cloned()
+            .collect();
+        categories.sort_by_key(|c| c.data.name.to_lowercase());
+
+        Taxonomy { tags, categories }
+    }
+
+    async fn set_taxonomy(
+        &self,
+        world_id: &WorldId,
+        content: &Content,
+        tags: &[TagId],
+        categories: &[CategoryId],
+    ) -> Result<(), SaveTaxonomyError> {
+        let known_tags = self.tags.lock().unwrap();
+        let known_categories = self.categories.lock().unwrap();
+        let mut content_tags = self.content_tags.lock().unwrap();
+        let mut content_categories = self.content_categories.lock().unwrap();
+
+        content_tags.retain(|(_, c)| c != content);
+        for tag_id in tags {
+            if known_tags.iter().any(|t| &t.identity.id == tag_id && &t.data.world_id == world_id) {
+                content_tags.push((tag_id.clone(), content.clone()));
+            }
+        }
+
+        content_categories.retain(|(_, c)| c != content);
+        for category_id in categories {
+            if known_categories
+                .iter()
+                .any(|c| &c.identity.id == category_id && &c.data.world_id == world_id)
+            {
+                content_categories.push((category_id.clone(), content.clone()));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use tokio_postgres::Row;
+
+use crate::{
+    articles::ArticleId,
+    entities::EntityId,
+    model::Identity,
+    taxonomy::{CategoryData, CategoryResource, Content, TagData, TagResource, TagUsage},
+};
+
+impl From<Row> for TagResource {
+    fn from(row: Row) -> Self {
+        parse_tag(&row)
+    }
+}
+
+impl From<Row> for TagUsage {
+    fn from(row: Row) -> Self {
+        let count: i64 = row.get("count");
+
+        TagUsage {
+            tag:   parse_tag(&row),
+            count: count.unsigned_abs(),
+        }
+    }
+}
+
+impl From<Row> for CategoryResource {
+    fn from(row: Row) -> Self {
+        CategoryResource {
+            identity: Identity {
+                id:      row.get("category_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data:     CategoryData {
+                world_id:  row.get("world_id"),
+                name:      row.get("name"),
+                parent_id: row.get("parent_id"),
+            },
+        }
+    }
+}
+
+/// Interpret a row that holds a tag.
+fn parse_tag(row: &Row) -> TagResource {
+    TagResource {
+        identity: Identity {
+            id:      row.get("tag_id"),
+            version: row.get("version"),
+            created: row.get("created"),
+            updated: row.get("updated"),
+        },
+        data:     TagData {
+            world_id: row.get("world_id"),
+            name:     row.get("name"),
+        },
+    }
+}
+
+/// Interpret a row that refers to a piece of content.
+///
+/// # Parameters
+/// - `row` - The row, which has `kind`, `article_id` and `entity_id` columns
+///
+/// # Returns
+/// The content, or `None` if the row doesn't refer to any.
+pub fn parse_content(row: &Row) -> Option<Content> {
+    let article_id: Option<ArticleId> = row.get("article_id");
+    let entity_id: Option<EntityId> = row.get("entity_id");
+
+    match (article_id, entity_id) {
+        (Some(article_id), _) => Some(Content::Article(article_id)),
+        (None, Some(entity_id)) => Some(Content::Entity {
+            kind: row.get("kind"),
+            entity_id,
+        }),
+        (None, None) => None,
+    }
+}
+
+/// Split a piece of content into the columns that refer to it.
+///
+/// # Parameters
+/// - `content` - The content
+///
+/// # Returns
+/// The ID of the article and the ID of the entity, exactly one of which is present.
+pub fn content_columns(content: &Content) -> (Option<&ArticleId>, Option<&EntityId>) {
+    match content {
+        Content::Article(article_id) => (Some(article_id), None),
+        Content::Entity { entity_id, .. } => (None, Some(entity_id)),
+    }
+}
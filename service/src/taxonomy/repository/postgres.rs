@@ -0,0 +1,422 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::{
+    parse::{content_columns, parse_content},
+    SaveCategoryError, SaveTagError, SaveTaxonomyError, TaxonomyRepository,
+};
+use crate::{
+    database::Database,
+    http::pagination::Pagination,
+    model::Identity,
+    taxonomy::{CategoryData, CategoryId, CategoryResource, Content, TagData, TagFilter, TagId, TagResource, TagUsage, Taxonomy},
+    worlds::WorldId,
+};
+
+/// The content within a world that matches a combination of tags, with `$1` as the world ID, `$2`
+/// as the tags that must all be present and `$3` as the tags of which at least one must be present.
+const MATCHING_CONTENT: &str = "SELECT c.kind, c.article_id, c.entity_id
+    FROM content_tags c
+    JOIN tags t ON t.tag_id = c.tag_id
+    WHERE t.world_id = $1
+    GROUP BY c.kind, c.article_id, c.entity_id
+    HAVING COUNT(*) FILTER (WHERE c.tag_id = ANY($2)) = cardinality($2::UUID[])
+        AND (cardinality($3::UUID[]) = 0 OR COUNT(*) FILTER (WHERE c.tag_id = ANY($3)) > 0)";
+
+/// Repository of tags and categories that are stored in Postgres.
+pub struct PostgresTaxonomyRepository {
+    database: Arc<Database>,
+}
+
+impl PostgresTaxonomyRepository {
+    /// Create a new taxonomy repository.
+    pub fn new(database: Arc<Database>) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait::async_trait]
+impl TaxonomyRepository for PostgresTaxonomyRepository {
+    #[tracing::instrument(skip(self))]
+    async fn create_tag(&self, tag: &TagData) -> Result<TagResource, SaveTagError> {
+        let conn = self.database.connect().await;
+
+        let identity = Identity::<TagId>::default();
+
+        let created: TagResource = conn
+            .query_one(
+                "INSERT INTO tags(tag_id, version, created, updated, world_id, name) VALUES ($1, $2, $3, $4, $5, $6) RETURNING *",
+                &[
+                    &identity.id,
+                    &identity.version,
+                    &identity.created,
+                    &identity.updated,
+                    &tag.world_id,
+                    &tag.name,
+                ],
+            )
+            .await
+            .map(TagResource::from)?;
+
+        Ok(created)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_tag(&self, world_id: &WorldId, tag_id: &TagId) -> Option<TagResource> {
+        let conn = self.database.connect().await;
+        conn.query_opt("SELECT * FROM tags WHERE world_id = $1 AND tag_id = $2", &[&world_id, &tag_id])
+            .await
+            .map_err(|e| {
+                tracing::warn!(e = ?e, "Failed to load tag");
+            })
+            .ok()
+            .flatten()
+            .map(TagResource::from)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_tags(&self, world_id: &WorldId) -> Vec<TagUsage> {
+        let conn = self.database.connect().await;
+        conn.query(
+            "SELECT t.*, (SELECT COUNT(*) FROM content_tags c WHERE c.tag_id = t.tag_id) AS count
+            FROM tags t
+            WHERE t.world_id = $1
+            ORDER BY LOWER(t.name)",
+            &[&world_id],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to load tags");
+                vec![]
+            },
+            |rows| rows.into_iter().map(TagUsage::from).collect(),
+        )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn update_tag(&self, tag_id: &TagId, tag: &TagData) -> Result<TagResource, SaveTagError> {
+        let conn = self.database.connect().await;
+
+        let version = Uuid::new_v4();
+        let updated = Utc::now();
+
+        conn.query_opt(
+            "UPDATE tags SET version = $3, updated = $4, name = $5 WHERE tag_id = $1 AND world_id = $2 RETURNING *",
+            &[&tag_id, &tag.world_id, &version, &updated, &tag.name],
+        )
+        .await?
+        .map(TagResource::from)
+        .ok_or(SaveTagError::UnknownTag)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_tag(&self, world_id: &WorldId, tag_id: &TagId) -> bool {
+        let conn = self.database.connect().await;
+        conn.execute("DELETE FROM tags WHERE world_id = $1 AND tag_id = $2", &[&world_id, &tag_id])
+            .await
+            .map_or_else(
+                |e| {
+                    tracing::warn!(e = ?e, "Failed to delete tag");
+                    false
+                },
+                |count| count == 1,
+            )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn merge_tags(&self, world_id: &WorldId, source_id: &TagId, target_id: &TagId) -> Result<(), SaveTagError> {
+        let mut conn = self.database.connect().await;
+        let tx = conn.begin().await;
+
+        let found = tx
+            .query(
+                "SELECT tag_id FROM tags WHERE world_id = $1 AND tag_id IN ($2, $3)",
+                &[&world_id, &source_id, &target_id],
+            )
+            .await?;
+        if found.len() != 2 {
+            return Err(SaveTagError::UnknownTag);
+        }
+
+        tx.execute(
+            "INSERT INTO content_tags(tag_id, kind, article_id, entity_id)
+            SELECT $2, kind, article_id, entity_id FROM content_tags WHERE tag_id = $1
+            ON CONFLICT DO NOTHING",
+            &[&source_id, &target_id],
+        )
+        .await?;
+        tx.execute("DELETE FROM tags WHERE tag_id = $1", &[&source_id]).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_tagged_content(&self, world_id: &WorldId, tag_id: &TagId) -> Vec<Content> {
+        let conn = self.database.connect().await;
+        conn.query(
+            "SELECT c.kind, c.article_id, c.entity_id
+            FROM content_tags c
+            JOIN tags t ON t.tag_id = c.tag_id
+            WHERE t.world_id = $1 AND t.tag_id = $2",
+            &[&world_id, &tag_id],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to load tagged content");
+                vec![]
+            },
+            |rows| rows.iter().filter_map(parse_content).collect(),
+        )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_content(&self, world_id: &WorldId, filter: &TagFilter, pagination: Pagination) -> (Vec<Content>, u64) {
+        let conn = self.database.connect().await;
+
+        let total = conn
+            .query_one(
+                format!("SELECT COUNT(*) AS count FROM ({}) m", MATCHING_CONTENT),
+                &[&world_id, &filter.all, &filter.any],
+            )
+            .await;
+        let total: i64 = match total {
+            Ok(row) => row.get("count"),
+            Err(e) => {
+                tracing::warn!(e = ?e, "Failed to count tagged content");
+
+                return (vec![], 0);
+            },
+        };
+
+        let content = conn
+            .query(
+                format!(
+                    "SELECT m.* FROM ({}) m ORDER BY m.kind, COALESCE(m.article_id, m.entity_id) LIMIT $4 OFFSET $5",
+                    MATCHING_CONTENT
+                ),
+                &[
+                    &world_id,
+                    &filter.all,
+                    &filter.any,
+                    &i64::from(pagination.limit),
+                    &i64::from(pagination.offset),
+                ],
+            )
+            .await
+            .map_or_else(
+                |e| {
+                    tracing::warn!(e = ?e, "Failed to find tagged content");
+                    vec![]
+                },
+                |rows| rows.iter().filter_map(parse_content).collect(),
+            );
+
+        (content, total.unsigned_abs())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn create_category(&self, category: &CategoryData) -> Result<CategoryResource, SaveCategoryError> {
+        let conn = self.database.connect().await;
+
+        let identity = Identity::<CategoryId>::default();
+
+        let created: CategoryResource = conn
+            .query_one(
+                "INSERT INTO categories(category_id, version, created, updated, world_id, name, parent_id) VALUES ($1, $2, $3, $4, $5, $6, $7) RETURNING *",
+                &[
+                    &identity.id,
+                    &identity.version,
+                    &identity.created,
+                    &identity.updated,
+                    &category.world_id,
+                    &category.name,
+                    &category.parent_id,
+                ],
+            )
+            .await
+            .map(CategoryResource::from)?;
+
+        Ok(created)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_category(&self, world_id: &WorldId, category_id: &CategoryId) -> Option<CategoryResource> {
+        let conn = self.database.connect().await;
+        conn.query_opt(
+            "SELECT * FROM categories WHERE world_id = $1 AND category_id = $2",
+            &[&world_id, &category_id],
+        )
+        .await
+        .map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to load category");
+        })
+        .ok()
+        .flatten()
+        .map(CategoryResource::from)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_categories(&self, world_id: &WorldId) -> Vec<CategoryResource> {
+        let conn = self.database.connect().await;
+        conn.query("SELECT * FROM categories WHERE world_id = $1 ORDER BY LOWER(name)", &[&world_id])
+            .await
+            .map_or_else(
+                |e| {
+                    tracing::warn!(e = ?e, "Failed to load categories");
+                    vec![]
+                },
+                |rows| rows.into_iter().map(CategoryResource::from).collect(),
+            )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn update_category(&self, category_id: &CategoryId, category: &CategoryData) -> Result<CategoryResource, SaveCategoryError> {
+        let conn = self.database.connect().await;
+
+        let version = Uuid::new_v4();
+        let updated = Utc::now();
+
+        conn.query_opt(
+            "UPDATE categories SET version = $3, updated = $4, name = $5, parent_id = $6 WHERE category_id = $1 AND world_id = $2 RETURNING *",
+            &[
+                &category_id,
+                &category.world_id,
+                &version,
+                &updated,
+                &category.name,
+                &category.parent_id,
+            ],
+        )
+        .await?
+        .map(CategoryResource::from)
+        .ok_or(SaveCategoryError::UnknownCategory)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_category(&self, world_id: &WorldId, category_id: &CategoryId) -> bool {
+        let mut conn = self.database.connect().await;
+        let tx = conn.begin().await;
+
+        let result = tx
+            .execute(
+                "UPDATE categories SET parent_id = (SELECT parent_id FROM categories WHERE world_id = $1 AND category_id = $2)
+                WHERE world_id = $1 AND parent_id = $2",
+                &[&world_id, &category_id],
+            )
+            .await;
+        if let Err(e) = result {
+            tracing::warn!(e = ?e, "Failed to move nested categories");
+            return false;
+        }
+
+        let deleted = tx
+            .execute(
+                "DELETE FROM categories WHERE world_id = $1 AND category_id = $2",
+                &[&world_id, &category_id],
+            )
+            .await;
+        match deleted {
+            Ok(1) => tx.commit().await.map_or_else(
+                |e| {
+                    tracing::warn!(e = ?e, "Failed to delete category");
+                    false
+                },
+                |()| true,
+            ),
+            Ok(_) => false,
+            Err(e) => {
+                tracing::warn!(e = ?e, "Failed to delete category");
+                false
+            },
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_taxonomy(&self, world_id: &WorldId, content: &Content) -> Taxonomy {
+        let conn = self.database.connect().await;
+        let (article_id, entity_id) = content_columns(content);
+
+        let tags = conn
+            .query(
+                "SELECT t.* FROM tags t
+                JOIN content_tags c ON c.tag_id = t.tag_id
+                WHERE t.world_id = $1 AND (c.article_id = $2 OR c.entity_id = $3)
+                ORDER BY LOWER(t.name)",
+                &[&world_id, &article_id, &entity_id],
+            )
+            .await
+            .map_or_else(
+                |e| {
+                    tracing::warn!(e = ?e, "Failed to load the tags of content");
+                    vec![]
+                },
+                |rows| rows.into_iter().map(TagResource::from).collect(),
+            );
+        let categories = conn
+            .query(
+                "SELECT t.* FROM categories t
+                JOIN content_categories c ON c.category_id = t.category_id
+                WHERE t.world_id = $1 AND (c.article_id = $2 OR c.entity_id = $3)
+                ORDER BY LOWER(t.name)",
+                &[&world_id, &article_id, &entity_id],
+            )
+            .await
+            .map_or_else(
+                |e| {
+                    tracing::warn!(e = ?e, "Failed to load the categories of content");
+                    vec![]
+                },
+                |rows| rows.into_iter().map(CategoryResource::from).collect(),
+            );
+
+        Taxonomy { tags, categories }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_taxonomy(
+        &self,
+        world_id: &WorldId,
+        content: &Content,
+        tags: &[TagId],
+        categories: &[CategoryId],
+    ) -> Result<(), SaveTaxonomyError> {
+        let mut conn = self.database.connect().await;
+        let tx = conn.begin().await;
+        let (article_id, entity_id) = content_columns(content);
+        let kind = content.kind();
+
+        tx.execute(
+            "DELETE FROM content_tags WHERE article_id = $1 OR entity_id = $2",
+            &[&article_id, &entity_id],
+        )
+        .await?;
+        tx.execute(
+            "INSERT INTO content_tags(tag_id, kind, article_id, entity_id)
+            SELECT t.tag_id, $3, $4, $5 FROM tags t WHERE t.world_id = $1 AND t.tag_id = ANY($2)",
+            &[&world_id, &tags, &kind, &article_id, &entity_id],
+        )
+        .await?;
+
+        tx.execute(
+            "DELETE FROM content_categories WHERE article_id = $1 OR entity_id = $2",
+            &[&article_id, &entity_id],
+        )
+        .await?;
+        tx.execute(
+            "INSERT INTO content_categories(category_id, kind, article_id, entity_id)
+            SELECT c.category_id, $3, $4, $5 FROM categories c WHERE c.world_id = $1 AND c.category_id = ANY($2)",
+            &[&world_id, &categories, &kind, &article_id, &entity_id],
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
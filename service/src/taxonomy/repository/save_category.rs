@@ -0,0 +1,23 @@
+use tokio_postgres::error::SqlState;
+
+/// Errors that can occur when saving a category.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveCategoryError {
+    #[error("The category, or the category that it is nested within, was not found")]
+    UnknownCategory,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl From<tokio_postgres::Error> for SaveCategoryError {
+    fn from(e: tokio_postgres::Error) -> Self {
+        if e.code() == Some(&SqlState::FOREIGN_KEY_VIOLATION) {
+            SaveCategoryError::UnknownCategory
+        } else {
+            tracing::warn!("Unexpected database error: {:?}", e);
+
+            SaveCategoryError::UnknownError
+        }
+    }
+}
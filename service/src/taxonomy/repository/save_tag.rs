@@ -0,0 +1,28 @@
+use tokio_postgres::error::SqlState;
+
+/// Errors that can occur when saving a tag.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveTagError {
+    #[error("Duplicate name")]
+    DuplicateName,
+
+    #[error("The tag was not found")]
+    UnknownTag,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl From<tokio_postgres::Error> for SaveTagError {
+    fn from(e: tokio_postgres::Error) -> Self {
+        if e.code() == Some(&SqlState::UNIQUE_VIOLATION) {
+            SaveTagError::DuplicateName
+        } else if e.code() == Some(&SqlState::FOREIGN_KEY_VIOLATION) {
+            SaveTagError::UnknownTag
+        } else {
+            tracing::warn!("Unexpected database error: {:?}", e);
+
+            SaveTagError::UnknownError
+        }
+    }
+}
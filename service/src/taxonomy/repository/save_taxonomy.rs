@@ -0,0 +1,23 @@
+use tokio_postgres::error::SqlState;
+
+/// Errors that can occur when assigning tags and categories to content.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveTaxonomyError {
+    #[error("The content, or one of the tags or categories, was not found")]
+    UnknownReference,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl From<tokio_postgres::Error> for SaveTaxonomyError {
+    fn from(e: tokio_postgres::Error) -> Self {
+        if e.code() == Some(&SqlState::FOREIGN_KEY_VIOLATION) {
+            SaveTaxonomyError::UnknownReference
+        } else {
+            tracing::warn!("Unexpected database error: {:?}", e);
+
+            SaveTaxonomyError::UnknownError
+        }
+    }
+}
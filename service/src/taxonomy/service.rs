@@ -0,0 +1,210 @@
+mod categories;
+mod content;
+mod tags;
+
+use std::sync::Arc;
+
+pub use categories::UpdateCategoryError;
+pub use content::AssignTaxonomyError;
+pub use tags::MergeTagsError;
+
+use super::{repository::TaxonomyRepository, Content};
+use crate::{articles::ArticleService, entities::EntityService, search::SearchService, worlds::WorldId};
+
+/// Service layer for working with the tags and categories of worlds.
+pub struct TaxonomyService {
+    repository: Arc<dyn TaxonomyRepository>,
+    articles:   Arc<ArticleService>,
+    entities:   Arc<EntityService>,
+    search:     Arc<SearchService>,
+}
+
+impl TaxonomyService {
+    /// Create a new taxonomy service.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store tags and categories in
+    /// - `articles` - The service to load the articles that are tagged from
+    /// - `entities` - The service to load the entities that are tagged from
+    /// - `search` - The service to keep the tags in the search index up to date with
+    pub fn new(
+        repository: Arc<dyn TaxonomyRepository>,
+        articles: Arc<ArticleService>,
+        entities: Arc<EntityService>,
+        search: Arc<SearchService>,
+    ) -> Self {
+        Self {
+            repository,
+            articles,
+            entities,
+            search,
+        }
+    }
+
+    /// Load the titles of some content.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the content belongs to
+    /// - `content` - The content
+    ///
+    /// # Returns
+    /// The title of each piece of content, or `None` for content that doesn't exist.
+    async fn titles(&self, world_id: &WorldId, content: &[Content]) -> Vec<Option<String>> {
+        let entity_ids: Vec<_> = content
+            .iter()
+            .filter_map(|c| match c {
+                Content::Entity { entity_id, .. } => Some(entity_id.clone()),
+                Content::Article(_) => None,
+            })
+            .collect();
+        let entities = if entity_ids.is_empty() {
+            vec![]
+        } else {
+            self.entities.get_entities_by_ids(world_id, &entity_ids).await
+        };
+
+        let mut result = vec![];
+        for c in content {
+            let title = match c {
+                Content::Article(article_id) => self
+                    .articles
+                    .get_article_by_id(world_id, article_id)
+                    .await
+                    .map(|article| article.data.title),
+                Content::Entity { kind, entity_id } => entities
+                    .iter()
+                    .find(|e| &e.identity.id == entity_id && &e.data.kind == kind)
+                    .map(|e| e.data.name.clone()),
+            };
+            result.push(title);
+        }
+
+        result
+    }
+
+    /// Update the tags that some content is filed under in the search index.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the content belongs to
+    /// - `content` - The content whose tags have changed
+    async fn reindex_tags(&self, world_id: &WorldId, content: &[Content]) {
+        for c in content {
+            let taxonomy = self.repository.get_taxonomy(world_id, c).await;
+            let names: Vec<String> = taxonomy.tags.into_iter().map(|t| t.data.name).collect();
+
+            self.search.tag(c, &names).await;
+        }
+    }
+}
+
+/// Remove any duplicates from a list, keeping the first of each.
+fn distinct<T>(items: &[T]) -> Vec<T>
+where
+    T: PartialEq + Clone,
+{
+    let mut result: Vec<T> = vec![];
+    for item in items {
+        if !result.contains(item) {
+            result.push(item.clone());
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        articles::{MemoryArticleRepository, NewArticle},
+        entities::{CharacterAttributes, CustomFields, MemoryEntityRepository, NewEntity},
+        revisions::{MemoryRevisionRepository, RevisionService},
+        search::{MemorySearchRepository, SearchQuery, SearchService},
+        taxonomy::MemoryTaxonomyRepository,
+        users::UserId,
+    };
+
+    /// Fixture for testing the taxonomy service, with an article and a character in the world.
+    pub struct Fixture {
+        pub sut:       TaxonomyService,
+        pub search:    Arc<SearchService>,
+        pub world_id:  WorldId,
+        pub article:   Content,
+        pub character: Content,
+    }
+
+    pub async fn build_fixture() -> Fixture {
+        let revisions = Arc::new(RevisionService::new(Arc::new(MemoryRevisionRepository::new())));
+        let search = Arc::new(SearchService::new(Arc::new(MemorySearchRepository::new())));
+        let articles = Arc::new(ArticleService::new(
+            Arc::new(MemoryArticleRepository::new()),
+            revisions.clone(),
+            search.clone(),
+        ));
+        let entities = Arc::new(EntityService::new(
+            Arc::new(MemoryEntityRepository::new()),
+            revisions,
+            search.clone(),
+        ));
+        let world_id = WorldId::default();
+
+        let article = articles
+            .create_article(
+                &world_id,
+                &UserId::default(),
+                NewArticle {
+                    title:    "The Fellowship".to_owned(),
+                    body:     "Nine walkers and a ring".to_owned(),
+                    category: None,
+                },
+            )
+            .await
+            .unwrap();
+        let character = entities
+            .create_entity(
+                &world_id,
+                &UserId::default(),
+                NewEntity {
+                    name:       "Frodo".to_owned(),
+                    summary:    Some("Bearer of the ring".to_owned()),
+                    attributes: CharacterAttributes::default(),
+                    custom:     CustomFields::new(),
+                },
+            )
+            .await
+            .unwrap();
+
+        Fixture {
+            sut: TaxonomyService::new(Arc::new(MemoryTaxonomyRepository::new()), articles, entities, search.clone()),
+            search,
+            world_id,
+            article: Content::Article(article.identity.id),
+            character: Content::Entity {
+                kind:      "character".to_owned(),
+                entity_id: character.identity.id,
+            },
+        }
+    }
+
+    impl Fixture {
+        /// Search the world for "ring", filtering to some tags.
+        pub async fn search_tagged(&self, tags: &[&str]) -> Vec<String> {
+            let results = self
+                .search
+                .search(
+                    &self.world_id,
+                    &SearchQuery {
+                        text: "ring".to_owned(),
+                        tags: tags.iter().map(|t| (*t).to_owned()).collect(),
+                        ..SearchQuery::default()
+                    },
+                    crate::http::pagination::Pagination::default(),
+                )
+                .await;
+
+            results.hits.into_iter().map(|h| h.title).collect()
+        }
+    }
+}
@@ -0,0 +1,274 @@
+use super::TaxonomyService;
+use crate::{
+    taxonomy::{CategoryData, CategoryId, CategoryNode, CategoryResource, SaveCategoryError},
+    worlds::WorldId,
+};
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum UpdateCategoryError<E>
+where
+    E: std::fmt::Debug,
+{
+    #[error("Unknown category")]
+    UnknownCategory,
+
+    #[error("Unknown parent category")]
+    UnknownParent,
+
+    #[error("A category can't be nested within itself")]
+    NestedWithinItself,
+
+    #[error("An error occurred updating the category data")]
+    UpdateError(E),
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl TaxonomyService {
+    /// Create a new category within a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world to create the category in
+    /// - `name` - The name of the category
+    /// - `parent_id` - The ID of the category to nest the new one within, if any
+    ///
+    /// # Returns
+    /// The newly created category.
+    pub async fn create_category(
+        &self,
+        world_id: &WorldId,
+        name: &str,
+        parent_id: Option<CategoryId>,
+    ) -> Result<CategoryResource, SaveCategoryError> {
+        if let Some(parent_id) = &parent_id {
+            self.repository
+                .get_category(world_id, parent_id)
+                .await
+                .ok_or(SaveCategoryError::UnknownCategory)?;
+        }
+
+        self.repository
+            .create_category(&CategoryData {
+                world_id: world_id.clone(),
+                name: name.trim().to_owned(),
+                parent_id,
+            })
+            .await
+    }
+
+    /// Get a single category.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the category belongs to
+    /// - `category_id` - The ID of the category
+    ///
+    /// # Returns
+    /// The category, or `None` if it doesn't exist.
+    pub async fn get_category(&self, world_id: &WorldId, category_id: &CategoryId) -> Option<CategoryResource> {
+        self.repository.get_category(world_id, category_id).await
+    }
+
+    /// Get the tree of categories within a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    ///
+    /// # Returns
+    /// The categories that aren't nested within any other, each with the categories nested
+    /// within it. Every level is sorted by name.
+    pub async fn get_category_tree(&self, world_id: &WorldId) -> Vec<CategoryNode> {
+        let categories = self.repository.get_categories(world_id).await;
+
+        build_tree(&categories, None)
+    }
+
+    /// Update the category that has the provided ID, using the provided lambda to perform the
+    /// updates.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the category belongs to
+    /// - `category_id` - The ID of the category to update
+    /// - `f` - The function to update the category details
+    ///
+    /// # Returns
+    /// The newly updated category.
+    pub async fn update_category_by_id<F, E>(
+        &self,
+        world_id: &WorldId,
+        category_id: &CategoryId,
+        f: F,
+    ) -> Result<CategoryResource, UpdateCategoryError<E>>
+    where
+        F: FnOnce(CategoryData) -> Result<CategoryData, E>,
+        E: std::fmt::Debug,
+    {
+        let categories = self.repository.get_categories(world_id).await;
+        let category = categories
+            .iter()
+            .find(|c| &c.identity.id == category_id)
+            .ok_or(UpdateCategoryError::UnknownCategory)?;
+
+        let data = f(category.data.clone()).map_err(UpdateCategoryError::UpdateError)?;
+        let data = CategoryData {
+            world_id: world_id.clone(),
+            name: data.name.trim().to_owned(),
+            ..data
+        };
+
+        // Walk up from the new parent, making sure that the category itself is never reached.
+        let mut ancestor = data.parent_id.as_ref();
+        while let Some(ancestor_id) = ancestor {
+            if ancestor_id == category_id {
+                return Err(UpdateCategoryError::NestedWithinItself);
+            }
+
+            ancestor = categories
+                .iter()
+                .find(|c| &c.identity.id == ancestor_id)
+                .ok_or(UpdateCategoryError::UnknownParent)?
+                .data
+                .parent_id
+                .as_ref();
+        }
+
+        let result = self.repository.update_category(category_id, &data).await?;
+
+        Ok(result)
+    }
+
+    /// Delete a category. Any categories nested within it are moved up to its parent, and any
+    /// content in it is left uncategorised.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the category belongs to
+    /// - `category_id` - The ID of the category
+    ///
+    /// # Returns
+    /// True if the category was deleted. False if it didn't exist.
+    pub async fn delete_category(&self, world_id: &WorldId, category_id: &CategoryId) -> bool {
+        self.repository.delete_category(world_id, category_id).await
+    }
+}
+
+/// Build the part of the category tree that is nested within a category.
+///
+/// # Parameters
+/// - `categories` - Every category within the world, sorted by name
+/// - `parent_id` - The ID of the category to build the tree within, or `None` for the top level
+fn build_tree(categories: &[CategoryResource], parent_id: Option<&CategoryId>) -> Vec<CategoryNode> {
+    categories
+        .iter()
+        .filter(|c| c.data.parent_id.as_ref() == parent_id)
+        .map(|c| CategoryNode {
+            category: c.clone(),
+            children: build_tree(categories, Some(&c.identity.id)),
+        })
+        .collect()
+}
+
+impl<E> From<SaveCategoryError> for UpdateCategoryError<E>
+where
+    E: std::fmt::Debug,
+{
+    fn from(e: SaveCategoryError) -> Self {
+        match e {
+            SaveCategoryError::UnknownCategory => Self::UnknownCategory,
+            SaveCategoryError::UnknownError => Self::UnknownError,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::taxonomy::service::tests::build_fixture;
+
+    fn names(nodes: &[CategoryNode]) -> Vec<String> {
+        nodes.iter().map(|n| n.category.data.name.clone()).collect()
+    }
+
+    #[actix_rt::test]
+    async fn category_tree() {
+        let f = build_fixture().await;
+        let people = f.sut.create_category(&f.world_id, "People", None).await.unwrap();
+        let places = f.sut.create_category(&f.world_id, "Places", None).await.unwrap();
+        let hobbits = f
+            .sut
+            .create_category(&f.world_id, "Hobbits", Some(people.identity.id.clone()))
+            .await
+            .unwrap();
+        f.sut
+            .create_category(&f.world_id, "Elves", Some(people.identity.id.clone()))
+            .await
+            .unwrap();
+        f.sut
+            .create_category(&f.world_id, "Bagginses", Some(hobbits.identity.id.clone()))
+            .await
+            .unwrap();
+
+        let tree = f.sut.get_category_tree(&f.world_id).await;
+        check!(names(&tree) == vec!["People", "Places"]);
+        check!(names(&tree[0].children) == vec!["Elves", "Hobbits"]);
+        check!(names(&tree[0].children[1].children) == vec!["Bagginses"]);
+        check!(tree[1].children.is_empty());
+
+        check!(f.sut.delete_category(&f.world_id, &hobbits.identity.id).await);
+        let tree = f.sut.get_category_tree(&f.world_id).await;
+        check!(names(&tree[0].children) == vec!["Bagginses", "Elves"]);
+
+        let_assert!(
+            Err(e) = f
+                .sut
+                .create_category(&WorldId::default(), "Dwarves", Some(places.identity.id))
+                .await
+        );
+        check!(e == SaveCategoryError::UnknownCategory);
+    }
+
+    #[actix_rt::test]
+    async fn move_category() {
+        let f = build_fixture().await;
+        let people = f.sut.create_category(&f.world_id, "People", None).await.unwrap();
+        let hobbits = f
+            .sut
+            .create_category(&f.world_id, "Hobbits", Some(people.identity.id.clone()))
+            .await
+            .unwrap();
+        let bagginses = f
+            .sut
+            .create_category(&f.world_id, "Bagginses", Some(hobbits.identity.id.clone()))
+            .await
+            .unwrap();
+
+        let result = f
+            .sut
+            .update_category_by_id(&f.world_id, &people.identity.id, |data| -> Result<CategoryData, ()> {
+                Ok(CategoryData {
+                    parent_id: Some(bagginses.identity.id.clone()),
+                    ..data
+                })
+            })
+            .await;
+        let_assert!(Err(e) = result);
+        check!(e == UpdateCategoryError::NestedWithinItself);
+
+        let result = f
+            .sut
+            .update_category_by_id(&f.world_id, &bagginses.identity.id, |data| -> Result<CategoryData, ()> {
+                Ok(CategoryData {
+                    name: "Baggins Family".to_owned(),
+                    parent_id: None,
+                    ..data
+                })
+            })
+            .await;
+        let_assert!(Ok(moved) = result);
+        check!(moved.data.name == "Baggins Family");
+
+        let tree = f.sut.get_category_tree(&f.world_id).await;
+        check!(names(&tree) == vec!["Baggins Family", "People"]);
+    }
+}
@@ -0,0 +1,176 @@
+use super::{distinct, TaxonomyService};
+use crate::{
+    taxonomy::{CategoryId, Content, SaveTaxonomyError, TagId, Taxonomy},
+    worlds::WorldId,
+};
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+#[allow(clippy::enum_variant_names)] // Matches the naming of the errors from the other services.
+pub enum AssignTaxonomyError {
+    #[error("Unknown content")]
+    UnknownContent,
+
+    #[error("Unknown tag: {0}")]
+    UnknownTag(TagId),
+
+    #[error("Unknown category: {0}")]
+    UnknownCategory(CategoryId),
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl TaxonomyService {
+    /// Check whether some content exists within a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    /// - `content` - The content
+    ///
+    /// # Returns
+    /// True if the content exists, and is of the kind that it is referred to as.
+    pub async fn content_exists(&self, world_id: &WorldId, content: &Content) -> bool {
+        matches!(self.titles(world_id, std::slice::from_ref(content)).await.as_slice(), [Some(_)])
+    }
+
+    /// Get the tags and categories that are assigned to some content.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the content belongs to
+    /// - `content` - The content
+    ///
+    /// # Returns
+    /// The tags and categories, or `None` if the content doesn't exist.
+    pub async fn get_taxonomy(&self, world_id: &WorldId, content: &Content) -> Option<Taxonomy> {
+        if !self.content_exists(world_id, content).await {
+            return None;
+        }
+
+        Some(self.repository.get_taxonomy(world_id, content).await)
+    }
+
+    /// Replace the tags and categories that are assigned to some content.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the content belongs to
+    /// - `content` - The content
+    /// - `tags` - The IDs of every tag that the content should have
+    /// - `categories` - The IDs of every category that the content should be in
+    ///
+    /// # Returns
+    /// The tags and categories that are now assigned to the content.
+    pub async fn set_taxonomy(
+        &self,
+        world_id: &WorldId,
+        content: &Content,
+        tags: &[TagId],
+        categories: &[CategoryId],
+    ) -> Result<Taxonomy, AssignTaxonomyError> {
+        if !self.content_exists(world_id, content).await {
+            return Err(AssignTaxonomyError::UnknownContent);
+        }
+
+        let tags = distinct(tags);
+        let known_tags = self.repository.get_tags(world_id).await;
+        if let Some(unknown) = tags.iter().find(|id| !known_tags.iter().any(|t| &t.tag.identity.id == *id)) {
+            return Err(AssignTaxonomyError::UnknownTag(unknown.clone()));
+        }
+
+        let categories = distinct(categories);
+        let known_categories = self.repository.get_categories(world_id).await;
+        if let Some(unknown) = categories.iter().find(|id| !known_categories.iter().any(|c| &c.identity.id == *id)) {
+            return Err(AssignTaxonomyError::UnknownCategory(unknown.clone()));
+        }
+
+        self.repository.set_taxonomy(world_id, content, &tags, &categories).await?;
+        self.reindex_tags(world_id, std::slice::from_ref(content)).await;
+
+        Ok(self.repository.get_taxonomy(world_id, content).await)
+    }
+}
+
+impl From<SaveTaxonomyError> for AssignTaxonomyError {
+    fn from(e: SaveTaxonomyError) -> Self {
+        match e {
+            SaveTaxonomyError::UnknownReference => Self::UnknownContent,
+            SaveTaxonomyError::UnknownError => Self::UnknownError,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::taxonomy::service::tests::build_fixture;
+
+    #[actix_rt::test]
+    async fn assign_taxonomy() {
+        let f = build_fixture().await;
+        let hobbits = f.sut.create_tag(&f.world_id, "Hobbits").await.unwrap();
+        let people = f.sut.create_category(&f.world_id, "People", None).await.unwrap();
+
+        let_assert!(
+            Ok(taxonomy) = f
+                .sut
+                .set_taxonomy(
+                    &f.world_id,
+                    &f.character,
+                    &[hobbits.identity.id.clone(), hobbits.identity.id.clone()],
+                    std::slice::from_ref(&people.identity.id),
+                )
+                .await
+        );
+        check!(taxonomy.tags.len() == 1);
+        check!(taxonomy.categories.len() == 1);
+
+        let_assert!(Some(taxonomy) = f.sut.get_taxonomy(&f.world_id, &f.character).await);
+        check!(taxonomy.tags[0].data.name == "Hobbits");
+        check!(taxonomy.categories[0].data.name == "People");
+
+        let_assert!(Some(taxonomy) = f.sut.get_taxonomy(&f.world_id, &f.article).await);
+        check!(taxonomy.tags.is_empty());
+
+        let_assert!(Ok(taxonomy) = f.sut.set_taxonomy(&f.world_id, &f.character, &[], &[]).await);
+        check!(taxonomy.tags.is_empty());
+        check!(taxonomy.categories.is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn assign_unknown() {
+        let f = build_fixture().await;
+        let elsewhere = f.sut.create_tag(&WorldId::default(), "Hobbits").await.unwrap();
+        let missing = Content::Article(crate::articles::ArticleId::default());
+        let wrong_kind = Content::Entity {
+            kind:      "location".to_owned(),
+            entity_id: match &f.character {
+                Content::Entity { entity_id, .. } => entity_id.clone(),
+                Content::Article(_) => unreachable!(),
+            },
+        };
+
+        let_assert!(Err(e) = f.sut.set_taxonomy(&f.world_id, &missing, &[], &[]).await);
+        check!(e == AssignTaxonomyError::UnknownContent);
+        let_assert!(Err(e) = f.sut.set_taxonomy(&f.world_id, &wrong_kind, &[], &[]).await);
+        check!(e == AssignTaxonomyError::UnknownContent);
+        check!(f.sut.get_taxonomy(&f.world_id, &missing).await.is_none());
+
+        let_assert!(
+            Err(e) = f
+                .sut
+                .set_taxonomy(&f.world_id, &f.article, std::slice::from_ref(&elsewhere.identity.id), &[])
+                .await
+        );
+        check!(e == AssignTaxonomyError::UnknownTag(elsewhere.identity.id));
+
+        let category = CategoryId::default();
+        let_assert!(
+            Err(e) = f
+                .sut
+                .set_taxonomy(&f.world_id, &f.article, &[], std::slice::from_ref(&category))
+                .await
+        );
+        check!(e == AssignTaxonomyError::UnknownCategory(category));
+    }
+}
@@ -0,0 +1,292 @@
+use super::{distinct, TaxonomyService};
+use crate::{
+    http::pagination::Pagination,
+    taxonomy::{ContentSummary, SaveTagError, TagData, TagFilter, TagId, TagResource, TagUsage},
+    worlds::WorldId,
+};
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum MergeTagsError {
+    #[error("A tag can't be merged into itself")]
+    SameTag,
+
+    #[error("Unknown tag")]
+    UnknownTag,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl TaxonomyService {
+    /// Create a new tag within a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world to create the tag in
+    /// - `name` - The name of the tag
+    ///
+    /// # Returns
+    /// The newly created tag.
+    pub async fn create_tag(&self, world_id: &WorldId, name: &str) -> Result<TagResource, SaveTagError> {
+        self.repository
+            .create_tag(&TagData {
+                world_id: world_id.clone(),
+                name:     name.trim().to_owned(),
+            })
+            .await
+    }
+
+    /// Get a single tag.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the tag belongs to
+    /// - `tag_id` - The ID of the tag
+    ///
+    /// # Returns
+    /// The tag, or `None` if it doesn't exist.
+    pub async fn get_tag(&self, world_id: &WorldId, tag_id: &TagId) -> Option<TagResource> {
+        self.repository.get_tag(world_id, tag_id).await
+    }
+
+    /// Get every tag within a world, along with how much content each one is assigned to.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    ///
+    /// # Returns
+    /// The tags, sorted by name.
+    pub async fn get_tags(&self, world_id: &WorldId) -> Vec<TagUsage> {
+        self.repository.get_tags(world_id).await
+    }
+
+    /// Rename a tag.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the tag belongs to
+    /// - `tag_id` - The ID of the tag
+    /// - `name` - The new name of the tag
+    ///
+    /// # Returns
+    /// The renamed tag.
+    pub async fn rename_tag(&self, world_id: &WorldId, tag_id: &TagId, name: &str) -> Result<TagResource, SaveTagError> {
+        let renamed = self
+            .repository
+            .update_tag(
+                tag_id,
+                &TagData {
+                    world_id: world_id.clone(),
+                    name:     name.trim().to_owned(),
+                },
+            )
+            .await?;
+
+        let content = self.repository.get_tagged_content(world_id, tag_id).await;
+        self.reindex_tags(world_id, &content).await;
+
+        Ok(renamed)
+    }
+
+    /// Delete a tag, removing it from all of the content that it is assigned to.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the tag belongs to
+    /// - `tag_id` - The ID of the tag
+    ///
+    /// # Returns
+    /// True if the tag was deleted. False if it didn't exist.
+    pub async fn delete_tag(&self, world_id: &WorldId, tag_id: &TagId) -> bool {
+        let content = self.repository.get_tagged_content(world_id, tag_id).await;
+
+        let deleted = self.repository.delete_tag(world_id, tag_id).await;
+        if deleted {
+            self.reindex_tags(world_id, &content).await;
+        }
+
+        deleted
+    }
+
+    /// Merge one tag into another, so that everything with either tag ends up with only the
+    /// target tag.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that both tags belong to
+    /// - `source_id` - The ID of the tag to merge, which is deleted
+    /// - `target_id` - The ID of the tag to merge into
+    ///
+    /// # Returns
+    /// The tag that was merged into.
+    pub async fn merge_tags(&self, world_id: &WorldId, source_id: &TagId, target_id: &TagId) -> Result<TagResource, MergeTagsError> {
+        if source_id == target_id {
+            return Err(MergeTagsError::SameTag);
+        }
+
+        self.repository.merge_tags(world_id, source_id, target_id).await?;
+
+        let content = self.repository.get_tagged_content(world_id, target_id).await;
+        self.reindex_tags(world_id, &content).await;
+
+        self.repository.get_tag(world_id, target_id).await.ok_or(MergeTagsError::UnknownTag)
+    }
+
+    /// Find the content within a world that has a combination of tags.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    /// - `filter` - The combination of tags that the content must have
+    /// - `pagination` - Which page of content to return
+    ///
+    /// # Returns
+    /// The page of matching content, and the total number of pieces of content that match.
+    pub async fn find_content(&self, world_id: &WorldId, filter: &TagFilter, pagination: Pagination) -> (Vec<ContentSummary>, u64) {
+        let filter = TagFilter {
+            all: distinct(&filter.all),
+            any: distinct(&filter.any),
+        };
+
+        let (content, total) = self.repository.find_content(world_id, &filter, pagination).await;
+        let titles = self.titles(world_id, &content).await;
+
+        let summaries = content
+            .into_iter()
+            .zip(titles)
+            .filter_map(|(content, title)| title.map(|title| ContentSummary { content, title }))
+            .collect();
+
+        (summaries, total)
+    }
+}
+
+impl From<SaveTagError> for MergeTagsError {
+    fn from(e: SaveTagError) -> Self {
+        match e {
+            SaveTagError::UnknownTag => Self::UnknownTag,
+            SaveTagError::DuplicateName | SaveTagError::UnknownError => Self::UnknownError,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::taxonomy::service::tests::build_fixture;
+
+    #[actix_rt::test]
+    async fn create_duplicate_tag() {
+        let f = build_fixture().await;
+
+        let_assert!(Ok(_) = f.sut.create_tag(&f.world_id, "Hobbits").await);
+        let_assert!(Err(e) = f.sut.create_tag(&f.world_id, " hobbits ").await);
+        check!(e == SaveTagError::DuplicateName);
+
+        let_assert!(Ok(_) = f.sut.create_tag(&WorldId::default(), "Hobbits").await);
+    }
+
+    #[actix_rt::test]
+    async fn rename_tag() {
+        let f = build_fixture().await;
+        let tag = f.sut.create_tag(&f.world_id, "Hobits").await.unwrap();
+        f.sut
+            .set_taxonomy(&f.world_id, &f.character, std::slice::from_ref(&tag.identity.id), &[])
+            .await
+            .unwrap();
+
+        let_assert!(Ok(renamed) = f.sut.rename_tag(&f.world_id, &tag.identity.id, "Hobbits").await);
+        check!(renamed.data.name == "Hobbits");
+
+        check!(f.search_tagged(&["hobbits"]).await == vec!["Frodo"]);
+        check!(f.search_tagged(&["hobits"]).await.is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn merge_tags() {
+        let f = build_fixture().await;
+        let quest = f.sut.create_tag(&f.world_id, "Quest").await.unwrap();
+        let quests = f.sut.create_tag(&f.world_id, "Quests").await.unwrap();
+        f.sut
+            .set_taxonomy(
+                &f.world_id,
+                &f.article,
+                &[quest.identity.id.clone(), quests.identity.id.clone()],
+                &[],
+            )
+            .await
+            .unwrap();
+        f.sut
+            .set_taxonomy(&f.world_id, &f.character, std::slice::from_ref(&quests.identity.id), &[])
+            .await
+            .unwrap();
+
+        let_assert!(Ok(merged) = f.sut.merge_tags(&f.world_id, &quests.identity.id, &quest.identity.id).await);
+        check!(merged.identity.id == quest.identity.id);
+
+        let tags = f.sut.get_tags(&f.world_id).await;
+        check!(tags.len() == 1);
+        check!(tags[0].tag.data.name == "Quest");
+        check!(tags[0].count == 2);
+
+        check!(f.search_tagged(&["quest"]).await.len() == 2);
+        check!(f.search_tagged(&["quests"]).await.is_empty());
+
+        let_assert!(Err(e) = f.sut.merge_tags(&f.world_id, &quest.identity.id, &quest.identity.id).await);
+        check!(e == MergeTagsError::SameTag);
+        let_assert!(Err(e) = f.sut.merge_tags(&f.world_id, &quests.identity.id, &quest.identity.id).await);
+        check!(e == MergeTagsError::UnknownTag);
+    }
+
+    #[actix_rt::test]
+    async fn delete_tag() {
+        let f = build_fixture().await;
+        let tag = f.sut.create_tag(&f.world_id, "Hobbits").await.unwrap();
+        f.sut
+            .set_taxonomy(&f.world_id, &f.character, std::slice::from_ref(&tag.identity.id), &[])
+            .await
+            .unwrap();
+
+        check!(f.sut.delete_tag(&f.world_id, &tag.identity.id).await);
+        check!(!f.sut.delete_tag(&f.world_id, &tag.identity.id).await);
+
+        check!(f.search_tagged(&["hobbits"]).await.is_empty());
+        let_assert!(Some(taxonomy) = f.sut.get_taxonomy(&f.world_id, &f.character).await);
+        check!(taxonomy.tags.is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn find_content() {
+        let f = build_fixture().await;
+        let hobbits = f.sut.create_tag(&f.world_id, "Hobbits").await.unwrap().identity.id;
+        let quest = f.sut.create_tag(&f.world_id, "Quest").await.unwrap().identity.id;
+        let war = f.sut.create_tag(&f.world_id, "War").await.unwrap().identity.id;
+        f.sut
+            .set_taxonomy(&f.world_id, &f.article, &[quest.clone(), war.clone()], &[])
+            .await
+            .unwrap();
+        f.sut
+            .set_taxonomy(&f.world_id, &f.character, &[hobbits.clone(), quest.clone()], &[])
+            .await
+            .unwrap();
+
+        let find = |all: Vec<TagId>, any: Vec<TagId>| {
+            let f = &f;
+            async move {
+                let (content, total) = f
+                    .sut
+                    .find_content(&f.world_id, &TagFilter { all, any }, Pagination::default())
+                    .await;
+                check!(usize::try_from(total).unwrap() == content.len());
+
+                let mut titles: Vec<String> = content.into_iter().map(|c| c.title).collect();
+                titles.sort();
+                titles
+            }
+        };
+
+        check!(find(vec![quest.clone()], vec![]).await == vec!["Frodo", "The Fellowship"]);
+        check!(find(vec![quest.clone(), hobbits.clone()], vec![]).await == vec!["Frodo"]);
+        check!(find(vec![], vec![hobbits.clone(), war.clone()]).await == vec!["Frodo", "The Fellowship"]);
+        check!(find(vec![quest.clone()], vec![war.clone()]).await == vec!["The Fellowship"]);
+        check!(find(vec![hobbits], vec![war]).await.is_empty());
+    }
+}
@@ -1,4 +1,19 @@
+mod articles;
 mod authentication;
+mod calendars;
+mod changes;
+mod comments;
 mod database;
+mod entities;
+mod idempotency;
+mod maps;
+mod relationships;
+mod search;
+mod sessions;
 mod suite;
+mod taxonomy;
+mod tokens;
+mod uploads;
 mod users;
+mod webhooks;
+mod worlds;
@@ -0,0 +1,3 @@
+mod backlinks;
+mod create_article;
+mod revisions;
@@ -0,0 +1,86 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use serde_json::json;
+
+use crate::tests::{database::seed::SeedUser, suite::TestSuite};
+
+#[actix_rt::test]
+async fn links_resolve_and_backlinks() {
+    let suite = TestSuite::new().await;
+    suite
+        .seed(&SeedUser {
+            user_id: "4ea96dc3-df11-43c0-8a33-a0813f03937f".parse().unwrap(),
+            ..SeedUser::default()
+        })
+        .await;
+
+    let response = suite
+        .inject(
+            TestRequest::post()
+                .uri("/worlds")
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
+                .set_json(&json!({
+                  "name": "Middle Earth"
+                }))
+                .to_request(),
+        )
+        .await;
+    let world_id = response.to_json().unwrap().get("worldId").unwrap().as_str().unwrap().to_owned();
+
+    // Link to an article before it exists.
+    let response = suite
+        .inject(
+            TestRequest::post()
+                .uri(&format!("/worlds/{}/articles", world_id))
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
+                .set_json(&json!({
+                  "title": "Minas Tirith",
+                  "body": "The capital of [[Gondor]]."
+                }))
+                .to_request(),
+        )
+        .await;
+    let body = response.to_json().unwrap();
+    check!(body.get("links").unwrap() == &json!([{"title": "Gondor", "label": "Gondor", "articleId": null}]));
+    let minas_tirith_id = body.get("articleId").unwrap().as_str().unwrap().to_owned();
+
+    let response = suite
+        .inject(
+            TestRequest::post()
+                .uri(&format!("/worlds/{}/articles", world_id))
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
+                .set_json(&json!({
+                  "title": "Gondor",
+                  "body": "A kingdom of men."
+                }))
+                .to_request(),
+        )
+        .await;
+    let gondor_id = response.to_json().unwrap().get("articleId").unwrap().as_str().unwrap().to_owned();
+
+    let response = suite
+        .inject(
+            TestRequest::get()
+                .uri(&format!("/worlds/{}/articles/{}", world_id, minas_tirith_id))
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
+                .to_request(),
+        )
+        .await;
+    let body = response.to_json().unwrap();
+    check!(body.get("links").unwrap()[0].get("articleId").unwrap() == gondor_id.as_str());
+
+    let response = suite
+        .inject(
+            TestRequest::get()
+                .uri(&format!("/worlds/{}/articles/{}/backlinks", world_id, gondor_id))
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
+                .to_request(),
+        )
+        .await;
+
+    check!(response.status == 200);
+    let articles = response.to_json().unwrap();
+    let articles = articles.get("articles").unwrap().as_array().unwrap();
+    check!(articles.len() == 1);
+    check!(articles[0].get("articleId").unwrap() == minas_tirith_id.as_str());
+}
@@ -0,0 +1,181 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use insta::assert_json_snapshot;
+use serde_json::json;
+
+use crate::tests::suite::{field, TestSuite};
+
+const USER_ID: &str = "4ea96dc3-df11-43c0-8a33-a0813f03937f";
+const OTHER_USER_ID: &str = "37f35c28-1c26-465d-9a45-b87e59a9760a";
+
+/// Build a test suite with a world containing the article "Minas Tirith".
+///
+/// # Returns
+/// The test suite, the ID of the world and the ID of the article.
+async fn build_article() -> (TestSuite, String, String) {
+    let suite = TestSuite::new().await;
+    let world_id = suite.seed_world(USER_ID).await;
+
+    let (_, article) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/articles")),
+            Some(json!({"title": "Minas Tirith", "body": "The capital of *Gondor*.", "category": "Places"})),
+        )
+        .await;
+    let article_id = field(article.as_ref(), "articleId");
+
+    (suite, world_id, article_id)
+}
+
+#[actix_rt::test]
+async fn create_article() {
+    let suite = TestSuite::new().await;
+    let world_id = suite.seed_world(USER_ID).await;
+
+    let (status, article) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/articles")),
+            Some(json!({
+                "title": "Minas Tirith",
+                "body": "The capital of *Gondor*.<script>alert(1)</script>",
+                "category": "Places"
+            })),
+        )
+        .await;
+
+    check!(status == 201);
+    assert_json_snapshot!(article.unwrap(), {
+        ".articleId" => "[article_id]",
+        ".created" => "[created]",
+        ".updated" => "[updated]",
+    }, @r###"
+    {
+      "articleId": "[article_id]",
+      "title": "Minas Tirith",
+      "slug": "minas-tirith",
+      "category": "Places",
+      "created": "[created]",
+      "updated": "[updated]",
+      "body": "The capital of *Gondor*.<script>alert(1)</script>",
+      "html": "<p>The capital of <em>Gondor</em>.</p>\n",
+      "links": []
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn get_article_by_slug() {
+    let (suite, world_id, article_id) = build_article().await;
+
+    let (status, article) = suite
+        .send(
+            USER_ID,
+            TestRequest::get().uri(&format!("/worlds/{world_id}/articles/minas-tirith")),
+            None,
+        )
+        .await;
+
+    check!(status == 200);
+    check!(field(article.as_ref(), "articleId") == article_id);
+}
+
+#[actix_rt::test]
+async fn list_articles() {
+    let (suite, world_id, _) = build_article().await;
+
+    let (status, articles) = suite
+        .send(USER_ID, TestRequest::get().uri(&format!("/worlds/{world_id}/articles")), None)
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(articles.unwrap(), {
+        ".articles[].articleId" => "[article_id]",
+        ".articles[].created" => "[created]",
+        ".articles[].updated" => "[updated]",
+    }, @r###"
+    {
+      "articles": [
+        {
+          "articleId": "[article_id]",
+          "title": "Minas Tirith",
+          "slug": "minas-tirith",
+          "category": "Places",
+          "created": "[created]",
+          "updated": "[updated]"
+        }
+      ]
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn create_article_duplicate_title() {
+    let (suite, world_id, _) = build_article().await;
+
+    let (status, problem) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/articles")),
+            Some(json!({"title": "minas  TIRITH", "body": ""})),
+        )
+        .await;
+
+    check!(status == 422);
+    assert_json_snapshot!(problem.unwrap(), @r###"
+    {
+      "type": "tag:worlds,2021:problems/articles/duplicate_title",
+      "title": "Duplicate Article Title",
+      "status": 422
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn create_article_not_member() {
+    let suite = TestSuite::new().await;
+    let world_id = suite.seed_world(USER_ID).await;
+    suite.seed_user(OTHER_USER_ID).await;
+
+    let (status, _) = suite
+        .send(
+            OTHER_USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/articles")),
+            Some(json!({"title": "Minas Tirith", "body": ""})),
+        )
+        .await;
+
+    check!(status == 404);
+}
+
+#[actix_rt::test]
+async fn create_article_not_acceptable() {
+    let suite = TestSuite::new().await;
+    let world_id = suite.seed_world(USER_ID).await;
+
+    let (status, problem) = suite
+        .send(
+            USER_ID,
+            TestRequest::post()
+                .uri(&format!("/worlds/{world_id}/articles"))
+                .insert_header(("Accept", "text/html")),
+            Some(json!({"title": "Minas Tirith", "body": ""})),
+        )
+        .await;
+
+    check!(status == 406);
+    assert_json_snapshot!(problem.unwrap(), @r###"
+    {
+      "type": "about:blank",
+      "title": "Not Acceptable",
+      "status": 406
+    }
+    "###);
+
+    // The article is rejected before it is created, not just before it is returned.
+    let (_, articles) = suite
+        .send(USER_ID, TestRequest::get().uri(&format!("/worlds/{world_id}/articles")), None)
+        .await;
+    check!(articles.unwrap()["articles"] == json!([]));
+}
@@ -0,0 +1,151 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use insta::assert_json_snapshot;
+use serde_json::json;
+
+use crate::tests::suite::{field, TestSuite};
+
+const USER_ID: &str = "4ea96dc3-df11-43c0-8a33-a0813f03937f";
+
+/// Build a test suite with an article whose body has been edited once.
+///
+/// # Returns
+/// The test suite, the URL of the article, and the versions of its revisions, newest first.
+async fn build_revisions() -> (TestSuite, String, Vec<String>) {
+    let suite = TestSuite::new().await;
+    let world_id = suite.seed_world(USER_ID).await;
+
+    let (_, article) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/articles")),
+            Some(json!({"title": "Gondor", "body": "A kingdom.\nOf men."})),
+        )
+        .await;
+    let article_url = format!("/worlds/{world_id}/articles/{}", field(article.as_ref(), "articleId"));
+
+    let (status, _) = suite
+        .send(
+            USER_ID,
+            TestRequest::patch().uri(&article_url),
+            Some(json!({"body": "A kingdom.\nOf the south."})),
+        )
+        .await;
+    check!(status == 200);
+
+    let (_, revisions) = suite
+        .send(USER_ID, TestRequest::get().uri(&format!("{article_url}/revisions")), None)
+        .await;
+    let versions = revisions.unwrap()["revisions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|revision| revision["version"].as_str().unwrap().to_owned())
+        .collect();
+
+    (suite, article_url, versions)
+}
+
+#[actix_rt::test]
+async fn list_revisions() {
+    let (suite, article_url, _) = build_revisions().await;
+
+    let (status, revisions) = suite
+        .send(USER_ID, TestRequest::get().uri(&format!("{article_url}/revisions")), None)
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(revisions.unwrap(), {
+        ".revisions[].version" => "[version]",
+        ".revisions[].changedAt" => "[changed_at]",
+    }, @r###"
+    {
+      "revisions": [
+        {
+          "version": "[version]",
+          "changedBy": "4ea96dc3-df11-43c0-8a33-a0813f03937f",
+          "changedAt": "[changed_at]"
+        },
+        {
+          "version": "[version]",
+          "changedBy": "4ea96dc3-df11-43c0-8a33-a0813f03937f",
+          "changedAt": "[changed_at]"
+        }
+      ]
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn diff_revision() {
+    let (suite, article_url, versions) = build_revisions().await;
+
+    let (status, diff) = suite
+        .send(
+            USER_ID,
+            TestRequest::get().uri(&format!("{article_url}/revisions/{}/diff", versions[0])),
+            None,
+        )
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(diff.unwrap(), {
+        ".version" => "[version]",
+    }, @r###"
+    {
+      "version": "[version]",
+      "from": null,
+      "fields": [
+        {
+          "field": "body",
+          "lines": [
+            {
+              "change": "equal",
+              "text": "A kingdom."
+            },
+            {
+              "change": "delete",
+              "text": "Of men."
+            },
+            {
+              "change": "insert",
+              "text": "Of the south."
+            }
+          ]
+        }
+      ]
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn restore_revision() {
+    let (suite, article_url, versions) = build_revisions().await;
+
+    let (status, article) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("{article_url}/revisions/{}/restore", versions[1])),
+            None,
+        )
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(article.unwrap(), {
+        ".articleId" => "[article_id]",
+        ".created" => "[created]",
+        ".updated" => "[updated]",
+    }, @r###"
+    {
+      "articleId": "[article_id]",
+      "title": "Gondor",
+      "slug": "gondor",
+      "category": null,
+      "created": "[created]",
+      "updated": "[updated]",
+      "body": "A kingdom.\nOf men.",
+      "html": "<p>A kingdom.\nOf men.</p>\n",
+      "links": []
+    }
+    "###);
+}
@@ -106,6 +106,112 @@ async fn blank_fields() {
     "###);
 }
 
+#[actix_rt::test]
+async fn weak_password() {
+    let suite = TestSuite::new().await;
+
+    let response = suite
+        .inject(
+            TestRequest::post()
+                .uri("/authenticate/register")
+                .set_json(&json!({
+                  "username": "testuser",
+                  "email": "testuser@example.com",
+                  "displayName": "Test User",
+                  "password": "password"
+                }))
+                .to_request(),
+        )
+        .await;
+
+    check!(response.status == 422);
+
+    check!(response.headers.get("content-type").unwrap() == "application/problem+json");
+
+    assert_json_snapshot!(response.to_json().unwrap(), @r###"
+    {
+      "type": "tag:worlds,2021:problems/validation",
+      "title": "Request body failed validation",
+      "status": 422,
+      "validationErrors": [
+        {
+          "code": "password_too_weak",
+          "title": "Password is too easy to guess",
+          "path": "/password",
+          "detail": "This is a top-10 common password."
+        }
+      ]
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn short_password() {
+    let suite = TestSuite::new().await;
+
+    let response = suite
+        .inject(
+            TestRequest::post()
+                .uri("/authenticate/register")
+                .set_json(&json!({
+                  "username": "testuser",
+                  "email": "testuser@example.com",
+                  "displayName": "Test User",
+                  "password": "Pa55wd"
+                }))
+                .to_request(),
+        )
+        .await;
+
+    check!(response.status == 422);
+
+    check!(response.headers.get("content-type").unwrap() == "application/problem+json");
+
+    let body = response.to_json().unwrap();
+    let errors = body.get("validationErrors").unwrap().as_array().unwrap();
+    check!(errors[0].get("code").unwrap() == "password_too_short");
+    check!(errors[0].get("detail").unwrap() == "The password must be at least 8 characters long");
+}
+
+#[actix_rt::test]
+async fn long_password() {
+    let suite = TestSuite::new().await;
+
+    let response = suite
+        .inject(
+            TestRequest::post()
+                .uri("/authenticate/register")
+                .set_json(&json!({
+                  "username": "testuser",
+                  "email": "testuser@example.com",
+                  "displayName": "Test User",
+                  "password": "Hedgehog-Lantern-47".repeat(100)
+                }))
+                .to_request(),
+        )
+        .await;
+
+    check!(response.status == 422);
+
+    check!(response.headers.get("content-type").unwrap() == "application/problem+json");
+
+    assert_json_snapshot!(response.to_json().unwrap(), @r###"
+    {
+      "type": "tag:worlds,2021:problems/validation",
+      "title": "Request body failed validation",
+      "status": 422,
+      "validationErrors": [
+        {
+          "code": "password_too_long",
+          "title": "Password is too long",
+          "path": "/password",
+          "detail": "The password must be at most 128 characters long"
+        }
+      ]
+    }
+    "###);
+}
+
 #[actix_rt::test]
 async fn success() {
     let suite = TestSuite::new().await;
@@ -118,7 +224,7 @@ async fn success() {
                   "username": "testuser",
                   "email": "testuser@example.com",
                   "displayName": "Test User",
-                  "password": "testuser123"
+                  "password": "Hedgehog-Lantern-47"
                 }))
                 .to_request(),
         )
@@ -153,7 +259,7 @@ async fn success_refetch() {
                   "username": "testuser",
                   "email": "testuser@example.com",
                   "displayName": "Test User",
-                  "password": "testuser123"
+                  "password": "Hedgehog-Lantern-47"
                 }))
                 .to_request(),
         )
@@ -207,7 +313,7 @@ async fn duplicate_username() {
                   "username": "testuser",
                   "email": "testuser@example.com",
                   "displayName": "Test User",
-                  "password": "testuser123"
+                  "password": "Hedgehog-Lantern-47"
                 }))
                 .to_request(),
         )
@@ -243,27 +349,60 @@ async fn duplicate_email() {
                 .uri("/authenticate/register")
                 .set_json(&json!({
                   "username": "testuser",
+                  "email": "TestUser@example.com",
+                  "displayName": "Test User",
+                  "password": "Hedgehog-Lantern-47"
+                }))
+                .to_request(),
+        )
+        .await;
+
+    check!(response.status == 422);
+
+    check!(response.headers.get("content-type").unwrap() == "application/problem+json");
+
+    assert_json_snapshot!(response.to_json().unwrap(), @r###"
+    {
+      "type": "tag:worlds,2021:problems/authentication/register/duplicate_email",
+      "title": "Duplicate Email Address",
+      "status": 422
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn duplicate_username_different_case() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        ..SeedUser::default()
+    };
+
+    let suite = TestSuite::new().await;
+    suite.seed(&user).await;
+
+    let response = suite
+        .inject(
+            TestRequest::post()
+                .uri("/authenticate/register")
+                .set_json(&json!({
+                  "username": "TestUser",
                   "email": "testuser@example.com",
                   "displayName": "Test User",
-                  "password": "testuser123"
+                  "password": "Hedgehog-Lantern-47"
                 }))
                 .to_request(),
         )
         .await;
 
-    check!(response.status == 200);
+    check!(response.status == 422);
 
-    check!(response.headers.get("content-type").unwrap() == "application/json");
+    check!(response.headers.get("content-type").unwrap() == "application/problem+json");
 
-    assert_json_snapshot!(response.to_json().unwrap(), {
-        ".userId" => "[user_id]",
-        ".token" => "[token]",
-        ".expiresAt" => "[expires_at]",
-      }, @r###"
+    assert_json_snapshot!(response.to_json().unwrap(), @r###"
     {
-      "token": "[token]",
-      "userId": "[user_id]",
-      "expiresAt": "[expires_at]"
+      "type": "tag:worlds,2021:problems/authentication/register/duplicate_username",
+      "title": "Duplicate Username",
+      "status": 422
     }
     "###);
 }
@@ -280,7 +419,7 @@ async fn success_authenticate() {
                   "username": "testuser",
                   "email": "testuser@example.com",
                   "displayName": "Test User",
-                  "password": "testuser123"
+                  "password": "Hedgehog-Lantern-47"
                 }))
                 .to_request(),
         )
@@ -294,7 +433,7 @@ async fn success_authenticate() {
                 .uri("/authenticate/authenticate")
                 .set_json(&json!({
                   "username": "testuser",
-                  "password": "testuser123"
+                  "password": "Hedgehog-Lantern-47"
                 }))
                 .to_request(),
         )
@@ -0,0 +1,248 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use insta::assert_json_snapshot;
+use serde_json::json;
+
+use crate::tests::suite::{field, TestSuite};
+
+const USER_ID: &str = "4ea96dc3-df11-43c0-8a33-a0813f03937f";
+
+/// Build a test suite with a world that has a calendar counting years in the Second and Third Ages.
+///
+/// # Returns
+/// The test suite, the ID of the world and the ID of the calendar.
+async fn build_calendar() -> (TestSuite, String, String) {
+    let suite = TestSuite::new().await;
+    let world_id = suite.seed_world(USER_ID).await;
+
+    let (_, calendar) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/calendars")),
+            Some(json!({
+                "name": "Reckoning of Years",
+                "definition": {
+                    "months": [{"name": "Frost", "days": 30}, {"name": "Harvest", "days": 30}],
+                    "eras": [
+                        {"name": "Second Age", "abbreviation": "SA", "starts": 1},
+                        {"name": "Third Age", "abbreviation": "TA", "starts": 3442}
+                    ]
+                }
+            })),
+        )
+        .await;
+    let calendar_id = field(calendar.as_ref(), "calendarId");
+
+    (suite, world_id, calendar_id)
+}
+
+#[actix_rt::test]
+async fn create_event_on_date() {
+    let (suite, world_id, calendar_id) = build_calendar().await;
+
+    let (status, event) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/events")),
+            Some(json!({
+                "title": "Coronation of Aragorn",
+                "calendarId": calendar_id,
+                "starts": {"era": "TA", "year": 3019, "month": "Harvest", "day": 1}
+            })),
+        )
+        .await;
+
+    check!(status == 201);
+    assert_json_snapshot!(event.unwrap(), {
+        ".eventId" => "[event_id]",
+        ".created" => "[created]",
+        ".updated" => "[updated]",
+    }, @r###"
+    {
+      "eventId": "[event_id]",
+      "title": "Coronation of Aragorn",
+      "description": null,
+      "starts": 387570,
+      "ends": null,
+      "entityIds": [],
+      "dates": {
+        "starts": {
+          "day": 387570,
+          "year": 6460,
+          "era": {
+            "name": "Third Age",
+            "abbreviation": "TA",
+            "starts": 3442
+          },
+          "yearOfEra": 3019,
+          "month": "Harvest",
+          "monthNumber": 2,
+          "dayOfMonth": 1,
+          "weekday": null,
+          "moons": [],
+          "text": "1 Harvest 3019 TA"
+        },
+        "ends": null
+      },
+      "created": "[created]",
+      "updated": "[updated]"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn timeline_of_era() {
+    let (suite, world_id, calendar_id) = build_calendar().await;
+    for (title, starts) in [
+        ("Forging of the One Ring", json!({"era": "SA", "year": 1600, "month": 1, "day": 1})),
+        (
+            "Coronation of Aragorn",
+            json!({"era": "TA", "year": 3019, "month": "Harvest", "day": 1}),
+        ),
+    ] {
+        let (status, _) = suite
+            .send(
+                USER_ID,
+                TestRequest::post().uri(&format!("/worlds/{world_id}/events")),
+                Some(json!({"title": title, "calendarId": calendar_id, "starts": starts})),
+            )
+            .await;
+        check!(status == 201);
+    }
+
+    let (status, timeline) = suite
+        .send(
+            USER_ID,
+            TestRequest::get().uri(&format!("/worlds/{world_id}/timeline?calendar={calendar_id}&era=TA")),
+            None,
+        )
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(timeline.unwrap(), {
+        ".events[].eventId" => "[event_id]",
+        ".events[].created" => "[created]",
+        ".events[].updated" => "[updated]",
+    }, @r###"
+    {
+      "from": 206460,
+      "to": null,
+      "events": [
+        {
+          "eventId": "[event_id]",
+          "title": "Coronation of Aragorn",
+          "description": null,
+          "starts": 387570,
+          "ends": null,
+          "entityIds": [],
+          "dates": {
+            "starts": {
+              "day": 387570,
+              "year": 6460,
+              "era": {
+                "name": "Third Age",
+                "abbreviation": "TA",
+                "starts": 3442
+              },
+              "yearOfEra": 3019,
+              "month": "Harvest",
+              "monthNumber": 2,
+              "dayOfMonth": 1,
+              "weekday": null,
+              "moons": [],
+              "text": "1 Harvest 3019 TA"
+            },
+            "ends": null
+          },
+          "created": "[created]",
+          "updated": "[updated]"
+        }
+      ]
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn create_event_on_date_without_calendar() {
+    let (suite, world_id, _) = build_calendar().await;
+
+    let (status, problem) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/events")),
+            Some(json!({"title": "Undated", "starts": {"year": 1, "month": 1, "day": 1}})),
+        )
+        .await;
+
+    check!(status == 422);
+    assert_json_snapshot!(problem.unwrap(), @r###"
+    {
+      "type": "tag:worlds,2021:problems/timeline/calendar_required",
+      "title": "A calendar is required to interpret dates",
+      "status": 422
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn create_event_on_date_out_of_range() {
+    let (suite, world_id, calendar_id) = build_calendar().await;
+
+    let (status, problem) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/events")),
+            Some(json!({
+                "title": "The End of Days",
+                "calendarId": calendar_id,
+                "starts": {"era": "TA", "year": i64::MAX, "month": 1, "day": 1}
+            })),
+        )
+        .await;
+
+    check!(status == 422);
+    assert_json_snapshot!(problem.unwrap(), @r###"
+    {
+      "type": "tag:worlds,2021:problems/calendars/invalid_date",
+      "title": "Invalid Date",
+      "status": 422,
+      "detail": "The date is too far from the calendar's epoch"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn format_day_out_of_range() {
+    let (suite, world_id, calendar_id) = build_calendar().await;
+    let (status, _) = suite
+        .send(
+            USER_ID,
+            TestRequest::patch().uri(&format!("/worlds/{world_id}/calendars/{calendar_id}")),
+            Some(json!({
+                "definition": {
+                    "epoch": i64::MAX,
+                    "months": [{"name": "Frost", "days": 30}, {"name": "Harvest", "days": 30}]
+                }
+            })),
+        )
+        .await;
+    check!(status == 200);
+
+    let (status, problem) = suite
+        .send(
+            USER_ID,
+            TestRequest::get().uri(&format!("/worlds/{world_id}/calendars/{calendar_id}/days/{}", i64::MIN)),
+            None,
+        )
+        .await;
+
+    check!(status == 422);
+    assert_json_snapshot!(problem.unwrap(), @r###"
+    {
+      "type": "tag:worlds,2021:problems/calendars/invalid_date",
+      "title": "Invalid Date",
+      "status": 422,
+      "detail": "The date is too far from the calendar's epoch"
+    }
+    "###);
+}
@@ -0,0 +1 @@
+mod poll_changes;
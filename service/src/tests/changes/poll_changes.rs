@@ -0,0 +1,136 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use insta::assert_json_snapshot;
+use serde_json::json;
+
+use crate::tests::suite::{field, TestSuite};
+
+const USER_ID: &str = "4ea96dc3-df11-43c0-8a33-a0813f03937f";
+
+/// Build a test suite with a world that an article has been created in.
+///
+/// # Returns
+/// The test suite, the ID of the world and the ID of the article.
+async fn build_article() -> (TestSuite, String, String) {
+    let suite = TestSuite::new().await;
+    let world_id = suite.seed_world(USER_ID).await;
+
+    let (_, article) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/articles")),
+            Some(json!({"title": "Gondor", "body": "A kingdom of men."})),
+        )
+        .await;
+    let article_id = field(article.as_ref(), "articleId");
+
+    (suite, world_id, article_id)
+}
+
+#[actix_rt::test]
+async fn poll_changes() {
+    let (suite, world_id, article_id) = build_article().await;
+
+    let (status, changes) = suite
+        .send(USER_ID, TestRequest::get().uri(&format!("/worlds/{world_id}/changes")), None)
+        .await;
+
+    check!(status == 200);
+    check!(changes.as_ref().unwrap()["changes"][0]["resourceId"] == article_id.as_str());
+    assert_json_snapshot!(changes.unwrap(), {
+        ".changes[].changeId" => "[change_id]",
+        ".changes[].resourceId" => "[article_id]",
+        ".changes[].version" => "[version]",
+        ".changes[].occurred" => "[occurred]",
+    }, @r###"
+    {
+      "changes": [
+        {
+          "changeId": "[change_id]",
+          "action": "created",
+          "resource": "article",
+          "resourceId": "[article_id]",
+          "version": "[version]",
+          "occurred": "[occurred]"
+        }
+      ],
+      "page": {
+        "limit": 20,
+        "next": null
+      }
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn poll_changes_after() {
+    let (suite, world_id, article_id) = build_article().await;
+    let (_, changes) = suite
+        .send(USER_ID, TestRequest::get().uri(&format!("/worlds/{world_id}/changes")), None)
+        .await;
+    let last_change_id = changes.unwrap()["changes"][0]["changeId"].as_str().unwrap().to_owned();
+
+    let (status, _) = suite
+        .send(
+            USER_ID,
+            TestRequest::delete().uri(&format!("/worlds/{world_id}/articles/{article_id}")),
+            None,
+        )
+        .await;
+    check!(status == 204);
+
+    let (status, changes) = suite
+        .send(
+            USER_ID,
+            TestRequest::get().uri(&format!("/worlds/{world_id}/changes?after={last_change_id}")),
+            None,
+        )
+        .await;
+
+    check!(status == 200);
+    check!(changes.as_ref().unwrap()["changes"][0]["resourceId"] == article_id.as_str());
+    assert_json_snapshot!(changes.unwrap(), {
+        ".changes[].changeId" => "[change_id]",
+        ".changes[].resourceId" => "[article_id]",
+        ".changes[].occurred" => "[occurred]",
+    }, @r###"
+    {
+      "changes": [
+        {
+          "changeId": "[change_id]",
+          "action": "deleted",
+          "resource": "article",
+          "resourceId": "[article_id]",
+          "version": null,
+          "occurred": "[occurred]"
+        }
+      ],
+      "page": {
+        "limit": 20,
+        "next": null
+      }
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn poll_changes_after_invalid_cursor() {
+    let (suite, world_id, _) = build_article().await;
+
+    let (status, problem) = suite
+        .send(
+            USER_ID,
+            TestRequest::get().uri(&format!("/worlds/{world_id}/changes?after=nonsense")),
+            None,
+        )
+        .await;
+
+    check!(status == 400);
+    assert_json_snapshot!(problem.unwrap(), @r###"
+    {
+      "type": "tag:worlds,2021:problems/changes/invalid_cursor",
+      "title": "Invalid Cursor",
+      "status": 400
+    }
+    "###);
+}
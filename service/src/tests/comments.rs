@@ -0,0 +1 @@
+mod discuss_article;
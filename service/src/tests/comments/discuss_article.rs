@@ -0,0 +1,277 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use insta::assert_json_snapshot;
+use serde_json::json;
+
+use crate::tests::{
+    database::seed::SeedUser,
+    suite::{field, TestSuite},
+};
+
+const USER_ID: &str = "9c2d4e6f-1a3b-4c5d-8e7f-0a1b2c3d4e5f";
+const OTHER_USER_ID: &str = "3f5e7d9c-b1a2-4c3d-9e8f-7a6b5c4d3e2f";
+
+/// Build a test suite containing a world with an article in it.
+///
+/// # Returns
+/// The test suite, the ID of the world and the URL of the discussion about the article.
+async fn build_article() -> (TestSuite, String, String) {
+    let suite = TestSuite::new().await;
+    suite
+        .seed(&SeedUser {
+            user_id: USER_ID.parse().unwrap(),
+            username: "frodo".to_owned(),
+            ..SeedUser::default()
+        })
+        .await;
+    suite
+        .seed(&SeedUser {
+            user_id: OTHER_USER_ID.parse().unwrap(),
+            username: "samwise".to_owned(),
+            ..SeedUser::default()
+        })
+        .await;
+
+    let (_, world) = suite
+        .send(USER_ID, TestRequest::post().uri("/worlds"), Some(json!({"name": "Middle Earth"})))
+        .await;
+    let world_id = field(world.as_ref(), "worldId");
+
+    let (_, article) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{}/articles", world_id)),
+            Some(json!({"title": "The Shire", "body": "Home of the hobbits."})),
+        )
+        .await;
+    let discussion = format!("/worlds/{}/discussions/article/{}", world_id, field(article.as_ref(), "articleId"));
+
+    (suite, world_id, discussion)
+}
+
+/// Build a test suite containing a discussion about an article, with a thread that has one reply.
+///
+/// # Returns
+/// The test suite, the ID of the world, the URL of the discussion, the ID of the thread and the ID
+/// of the reply.
+async fn build_thread() -> (TestSuite, String, String, String, String) {
+    let (suite, world_id, discussion) = build_article().await;
+
+    let (_, thread) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&discussion),
+            Some(json!({"body": "Should this mention *Bag End*?"})),
+        )
+        .await;
+    let thread_id = field(thread.as_ref(), "commentId");
+
+    let (_, reply) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{}/comments/{}/replies", world_id, thread_id)),
+            Some(json!({"body": "Added it."})),
+        )
+        .await;
+    let reply_id = field(reply.as_ref(), "commentId");
+
+    (suite, world_id, discussion, thread_id, reply_id)
+}
+
+#[actix_rt::test]
+async fn start_thread() {
+    let (suite, _, discussion) = build_article().await;
+
+    let (status, thread) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&discussion),
+            Some(json!({"body": "Should this mention *Bag End*, @samwise?"})),
+        )
+        .await;
+
+    check!(status == 201);
+    assert_json_snapshot!(thread.unwrap(), {
+        ".commentId" => "[comment_id]",
+        ".resourceId" => "[resource_id]",
+        ".created" => "[created]",
+        ".updated" => "[updated]",
+    }, @r###"
+    {
+      "commentId": "[comment_id]",
+      "kind": "article",
+      "resourceId": "[resource_id]",
+      "parentId": null,
+      "authorId": "9c2d4e6f-1a3b-4c5d-8e7f-0a1b2c3d4e5f",
+      "body": "Should this mention *Bag End*, @samwise?",
+      "html": "<p>Should this mention <em>Bag End</em>, @samwise?</p>\n",
+      "mentions": [
+        "3f5e7d9c-b1a2-4c3d-9e8f-7a6b5c4d3e2f"
+      ],
+      "resolved": null,
+      "removed": null,
+      "created": "[created]",
+      "updated": "[updated]"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn reply_to_thread() {
+    let (suite, world_id, discussion) = build_article().await;
+    let (_, thread) = suite
+        .send(USER_ID, TestRequest::post().uri(&discussion), Some(json!({"body": "Bag End?"})))
+        .await;
+    let thread_id = field(thread.as_ref(), "commentId");
+
+    let (status, reply) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{}/comments/{}/replies", world_id, thread_id)),
+            Some(json!({"body": "Added it."})),
+        )
+        .await;
+
+    check!(status == 201);
+    check!(field(reply.as_ref(), "parentId") == thread_id);
+}
+
+#[actix_rt::test]
+async fn list_threads() {
+    let (suite, _, discussion, ..) = build_thread().await;
+
+    let (status, threads) = suite.send(USER_ID, TestRequest::get().uri(&discussion), None).await;
+
+    check!(status == 200);
+    assert_json_snapshot!(threads.unwrap(), {
+        ".comments[].commentId" => "[comment_id]",
+        ".comments[].resourceId" => "[resource_id]",
+        ".comments[].created" => "[created]",
+        ".comments[].updated" => "[updated]",
+    }, @r###"
+    {
+      "comments": [
+        {
+          "commentId": "[comment_id]",
+          "kind": "article",
+          "resourceId": "[resource_id]",
+          "parentId": null,
+          "authorId": "9c2d4e6f-1a3b-4c5d-8e7f-0a1b2c3d4e5f",
+          "body": "Should this mention *Bag End*?",
+          "html": "<p>Should this mention <em>Bag End</em>?</p>\n",
+          "mentions": [],
+          "resolved": null,
+          "removed": null,
+          "created": "[created]",
+          "updated": "[updated]",
+          "replies": 1
+        }
+      ],
+      "page": {
+        "limit": 20,
+        "next": null
+      }
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn list_threads_invalid_cursor() {
+    let (suite, _, discussion) = build_article().await;
+
+    let (status, _) = suite
+        .send(USER_ID, TestRequest::get().uri(&format!("{}?after=not-a-cursor", discussion)), None)
+        .await;
+
+    check!(status == 400);
+}
+
+#[actix_rt::test]
+async fn list_threads_not_member() {
+    let (suite, _, discussion) = build_article().await;
+
+    let (status, _) = suite.send(OTHER_USER_ID, TestRequest::get().uri(&discussion), None).await;
+
+    check!(status == 404);
+}
+
+#[actix_rt::test]
+async fn resolve_thread() {
+    let (suite, world_id, _, thread_id, _) = build_thread().await;
+
+    let (status, resolved) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{}/comments/{}/resolve", world_id, thread_id)),
+            None,
+        )
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(resolved.unwrap().get("resolved").unwrap(), {
+        ".resolvedAt" => "[resolved_at]",
+    }, @r###"
+    {
+      "resolvedBy": "9c2d4e6f-1a3b-4c5d-8e7f-0a1b2c3d4e5f",
+      "resolvedAt": "[resolved_at]"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn resolve_reply() {
+    let (suite, world_id, _, _, reply_id) = build_thread().await;
+
+    let (status, _) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{}/comments/{}/resolve", world_id, reply_id)),
+            None,
+        )
+        .await;
+
+    check!(status == 422);
+}
+
+#[actix_rt::test]
+async fn delete_thread_with_replies() {
+    let (suite, world_id, _, thread_id, _) = build_thread().await;
+    let thread_url = format!("/worlds/{}/comments/{}", world_id, thread_id);
+
+    let (status, _) = suite.send(USER_ID, TestRequest::delete().uri(&thread_url), None).await;
+    check!(status == 204);
+
+    let (status, removed) = suite.send(USER_ID, TestRequest::get().uri(&thread_url), None).await;
+    check!(status == 200);
+    assert_json_snapshot!(removed.unwrap(), {
+        ".commentId" => "[comment_id]",
+        ".resourceId" => "[resource_id]",
+        ".created" => "[created]",
+        ".updated" => "[updated]",
+        ".removed.removedAt" => "[removed_at]",
+    }, @r###"
+    {
+      "commentId": "[comment_id]",
+      "kind": "article",
+      "resourceId": "[resource_id]",
+      "parentId": null,
+      "authorId": "9c2d4e6f-1a3b-4c5d-8e7f-0a1b2c3d4e5f",
+      "body": null,
+      "html": null,
+      "mentions": [],
+      "resolved": null,
+      "removed": {
+        "removedBy": "9c2d4e6f-1a3b-4c5d-8e7f-0a1b2c3d4e5f",
+        "removedAt": "[removed_at]"
+      },
+      "created": "[created]",
+      "updated": "[updated]"
+    }
+    "###);
+
+    let (status, replies) = suite
+        .send(USER_ID, TestRequest::get().uri(&format!("{}/replies", thread_url)), None)
+        .await;
+    check!(status == 200);
+    check!(replies.unwrap().get("comments").unwrap().as_array().unwrap().len() == 1);
+}
@@ -24,7 +24,7 @@ pub struct TestDatabase {
 impl TestDatabase {
     /// Create a new test database.
     #[allow(clippy::new_without_default)] // This is a non-trivial implementation. Hiding it behind default() is unwise.
-    pub async fn new() -> Self {
+    pub fn new() -> Self {
         tracing::info!("Starting Postgres database");
         let node = DOCKER.run(Postgres::default());
 
@@ -53,6 +53,11 @@ impl TestDatabase {
 
         let conn = pool.get().await.expect("Failed to get database connection");
 
-        conn.execute(data.sql(), &data.binds()[..]).await.unwrap();
+        let binds = data.binds();
+        let binds: Vec<_> = binds
+            .iter()
+            .map(|bind| bind.as_ref() as &(dyn postgres_types::ToSql + Sync))
+            .collect();
+        conn.execute(data.sql(), &binds[..]).await.unwrap();
     }
 }
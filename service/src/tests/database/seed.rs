@@ -10,7 +10,7 @@ pub trait SeedData: std::fmt::Debug {
 
     /// Generate the binds that are used with the SQL.
     /// If not implemented then the default returns the empty set.
-    fn binds(&self) -> Vec<&(dyn ToSql + Sync)> {
+    fn binds(&self) -> Vec<Box<dyn ToSql + Sync + '_>> {
         vec![]
     }
 }
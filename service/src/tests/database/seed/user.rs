@@ -3,6 +3,7 @@ use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use super::SeedData;
+use crate::users::Username;
 
 #[derive(Debug)]
 pub struct SeedUser {
@@ -47,19 +48,22 @@ impl SeedUser {
 
 impl SeedData for SeedUser {
     fn sql(&self) -> &str {
-        "INSERT INTO users(user_id, version, created, updated, username, display_name, email, password) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)"
+        "INSERT INTO users(user_id, version, created, updated, username, username_key, display_name, email, password) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)"
     }
 
-    fn binds(&self) -> Vec<&(dyn postgres_types::ToSql + Sync)> {
+    fn binds(&self) -> Vec<Box<dyn postgres_types::ToSql + Sync + '_>> {
+        let username: Username = self.username.parse().expect("Invalid seed username");
+
         vec![
-            &self.user_id,
-            &self.version,
-            &self.created,
-            &self.updated,
-            &self.username,
-            &self.display_name,
-            &self.email,
-            &self.password,
+            Box::new(&self.user_id),
+            Box::new(&self.version),
+            Box::new(&self.created),
+            Box::new(&self.updated),
+            Box::new(&self.username),
+            Box::new(username.key()),
+            Box::new(&self.display_name),
+            Box::new(&self.email),
+            Box::new(&self.password),
         ]
     }
 }
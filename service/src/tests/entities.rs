@@ -0,0 +1,2 @@
+mod characters;
+mod locations;
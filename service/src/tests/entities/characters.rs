@@ -0,0 +1,213 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use insta::assert_json_snapshot;
+use serde_json::json;
+
+use crate::tests::suite::{field, TestSuite};
+
+const USER_ID: &str = "4ea96dc3-df11-43c0-8a33-a0813f03937f";
+
+/// Build a test suite with a world containing the character "Frodo Baggins".
+///
+/// # Returns
+/// The test suite, the ID of the world and the ID of the character.
+async fn build_character() -> (TestSuite, String, String) {
+    let suite = TestSuite::new().await;
+    let world_id = suite.seed_world(USER_ID).await;
+
+    let (_, character) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/characters")),
+            Some(json!({"name": "Frodo Baggins", "custom": {"ringBearer": true, "height": "Short"}})),
+        )
+        .await;
+    let character_id = field(character.as_ref(), "entityId");
+
+    (suite, world_id, character_id)
+}
+
+#[actix_rt::test]
+async fn create_character() {
+    let suite = TestSuite::new().await;
+    let world_id = suite.seed_world(USER_ID).await;
+
+    let (status, faction) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/factions")),
+            Some(json!({"name": "The Fellowship", "attributes": {"type": "Company"}})),
+        )
+        .await;
+    check!(status == 201);
+    let faction_id = field(faction.as_ref(), "entityId");
+
+    let (status, character) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/characters")),
+            Some(json!({
+                "name": "Frodo Baggins",
+                "attributes": {
+                    "species": "Hobbit",
+                    "born": "TA 2968",
+                    "affiliations": [faction_id]
+                },
+                "custom": {
+                    "ringBearer": true
+                }
+            })),
+        )
+        .await;
+
+    check!(status == 201);
+    assert_json_snapshot!(character.unwrap(), {
+        ".entityId" => "[entity_id]",
+        ".attributes.affiliations[]" => "[faction_id]",
+        ".created" => "[created]",
+        ".updated" => "[updated]",
+    }, @r###"
+    {
+      "entityId": "[entity_id]",
+      "kind": "character",
+      "name": "Frodo Baggins",
+      "summary": null,
+      "attributes": {
+        "species": "Hobbit",
+        "born": "TA 2968",
+        "died": null,
+        "affiliations": [
+          "[faction_id]"
+        ]
+      },
+      "custom": {
+        "ringBearer": true
+      },
+      "created": "[created]",
+      "updated": "[updated]"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn get_character() {
+    let (suite, world_id, character_id) = build_character().await;
+
+    let (status, character) = suite
+        .send(
+            USER_ID,
+            TestRequest::get().uri(&format!("/worlds/{world_id}/characters/{character_id}")),
+            None,
+        )
+        .await;
+
+    check!(status == 200);
+    check!(field(character.as_ref(), "entityId") == character_id);
+    assert_json_snapshot!(character.unwrap(), {
+        ".entityId" => "[entity_id]",
+        ".created" => "[created]",
+        ".updated" => "[updated]",
+    }, @r###"
+    {
+      "entityId": "[entity_id]",
+      "kind": "character",
+      "name": "Frodo Baggins",
+      "summary": null,
+      "attributes": {
+        "species": null,
+        "born": null,
+        "died": null,
+        "affiliations": []
+      },
+      "custom": {
+        "height": "Short",
+        "ringBearer": true
+      },
+      "created": "[created]",
+      "updated": "[updated]"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn get_character_as_faction() {
+    let (suite, world_id, character_id) = build_character().await;
+
+    let (status, _) = suite
+        .send(
+            USER_ID,
+            TestRequest::get().uri(&format!("/worlds/{world_id}/factions/{character_id}")),
+            None,
+        )
+        .await;
+
+    check!(status == 404);
+}
+
+#[actix_rt::test]
+async fn create_character_invalid_affiliation() {
+    let suite = TestSuite::new().await;
+    let world_id = suite.seed_world(USER_ID).await;
+
+    let (status, problem) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/characters")),
+            Some(json!({
+                "name": "Frodo Baggins",
+                "attributes": {
+                    "affiliations": ["37f35c28-1c26-465d-9a45-b87e59a9760a"]
+                }
+            })),
+        )
+        .await;
+
+    check!(status == 422);
+    assert_json_snapshot!(problem.unwrap(), @r###"
+    {
+      "type": "tag:worlds,2021:problems/entities/invalid_reference",
+      "title": "Invalid Entity Reference",
+      "status": 422,
+      "entityId": "37f35c28-1c26-465d-9a45-b87e59a9760a"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn patch_custom_fields() {
+    let (suite, world_id, character_id) = build_character().await;
+
+    let (status, character) = suite
+        .send(
+            USER_ID,
+            TestRequest::patch().uri(&format!("/worlds/{world_id}/characters/{character_id}")),
+            Some(json!({"custom": {"ringBearer": null, "home": "Bag End"}})),
+        )
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(character.unwrap(), {
+        ".entityId" => "[entity_id]",
+        ".created" => "[created]",
+        ".updated" => "[updated]",
+    }, @r###"
+    {
+      "entityId": "[entity_id]",
+      "kind": "character",
+      "name": "Frodo Baggins",
+      "summary": null,
+      "attributes": {
+        "species": null,
+        "born": null,
+        "died": null,
+        "affiliations": []
+      },
+      "custom": {
+        "home": "Bag End",
+        "height": "Short"
+      },
+      "created": "[created]",
+      "updated": "[updated]"
+    }
+    "###);
+}
@@ -0,0 +1,105 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use serde_json::json;
+
+use crate::tests::{database::seed::SeedUser, suite::TestSuite};
+
+#[actix_rt::test]
+async fn nested_locations() {
+    let suite = TestSuite::new().await;
+    suite
+        .seed(&SeedUser {
+            user_id: "4ea96dc3-df11-43c0-8a33-a0813f03937f".parse().unwrap(),
+            ..SeedUser::default()
+        })
+        .await;
+
+    let response = suite
+        .inject(
+            TestRequest::post()
+                .uri("/worlds")
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
+                .set_json(&json!({
+                  "name": "Middle Earth"
+                }))
+                .to_request(),
+        )
+        .await;
+    let world_id = response.to_json().unwrap().get("worldId").unwrap().as_str().unwrap().to_owned();
+
+    let response = suite
+        .inject(
+            TestRequest::post()
+                .uri(&format!("/worlds/{}/locations", world_id))
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
+                .set_json(&json!({
+                  "name": "Gondor",
+                  "attributes": {
+                    "type": "Kingdom"
+                  }
+                }))
+                .to_request(),
+        )
+        .await;
+    let gondor_id = response.to_json().unwrap().get("entityId").unwrap().as_str().unwrap().to_owned();
+
+    let response = suite
+        .inject(
+            TestRequest::post()
+                .uri(&format!("/worlds/{}/locations", world_id))
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
+                .set_json(&json!({
+                  "name": "Minas Tirith",
+                  "attributes": {
+                    "type": "City",
+                    "parent": gondor_id,
+                    "coordinates": {"x": 120.5, "y": 340}
+                  }
+                }))
+                .to_request(),
+        )
+        .await;
+    check!(response.status == 201);
+    let minas_tirith_id = response.to_json().unwrap().get("entityId").unwrap().as_str().unwrap().to_owned();
+
+    // Gondor can't be within Minas Tirith, since Minas Tirith is within Gondor.
+    let response = suite
+        .inject(
+            TestRequest::patch()
+                .uri(&format!("/worlds/{}/locations/{}", world_id, gondor_id))
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
+                .set_json(&json!({
+                  "attributes": {
+                    "parent": minas_tirith_id
+                  }
+                }))
+                .to_request(),
+        )
+        .await;
+    check!(response.status == 422);
+
+    let response = suite
+        .inject(
+            TestRequest::get()
+                .uri(&format!("/worlds/{}/locations", world_id))
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
+                .to_request(),
+        )
+        .await;
+    check!(response.status == 200);
+    let entities = response.to_json().unwrap();
+    let entities = entities.get("entities").unwrap().as_array().unwrap();
+    check!(entities.len() == 2);
+    check!(entities[0].get("name").unwrap() == "Gondor");
+    check!(entities[1].get("name").unwrap() == "Minas Tirith");
+
+    let response = suite
+        .inject(
+            TestRequest::delete()
+                .uri(&format!("/worlds/{}/locations/{}", world_id, minas_tirith_id))
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
+                .to_request(),
+        )
+        .await;
+    check!(response.status == 204);
+}
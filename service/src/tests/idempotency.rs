@@ -0,0 +1,163 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use insta::assert_json_snapshot;
+use serde_json::json;
+
+use crate::tests::suite::TestSuite;
+
+/// Register a new user, without an idempotency key.
+///
+/// # Returns
+/// The ID of the user, and the `Authorization` header to send as them.
+async fn register(suite: &TestSuite) -> (String, String) {
+    let response = suite
+        .inject(
+            TestRequest::post()
+                .uri("/authenticate/register")
+                .set_json(&json!({
+                  "username": "testuser",
+                  "email": "testuser@example.com",
+                  "displayName": "Test User",
+                  "password": "Hedgehog-Lantern-47"
+                }))
+                .to_request(),
+        )
+        .await;
+    let body = response.to_json().unwrap();
+
+    (
+        body.get("userId").unwrap().as_str().unwrap().to_owned(),
+        format!("Bearer {}", body.get("token").unwrap().as_str().unwrap()),
+    )
+}
+
+#[actix_rt::test]
+async fn retry_create_world() {
+    let suite = TestSuite::new().await;
+    let (_, authorization) = register(&suite).await;
+
+    let request = || {
+        TestRequest::post()
+            .uri("/worlds")
+            .insert_header(("Authorization", authorization.as_str()))
+            .insert_header(("Idempotency-Key", "7d6f1b9c-world"))
+            .set_json(&json!({"name": "Middle Earth"}))
+            .to_request()
+    };
+
+    let first = suite.inject(request()).await;
+    check!(first.status == 201);
+    check!(first.headers.get("idempotent-replayed").is_none());
+
+    let retry = suite.inject(request()).await;
+    check!(retry.status == 201);
+    check!(retry.headers.get("content-type").unwrap() == "application/json");
+    check!(retry.headers.get("idempotent-replayed").unwrap() == "true");
+    check!(retry.to_json().unwrap() == first.to_json().unwrap());
+}
+
+#[actix_rt::test]
+async fn reuse_key_for_different_request() {
+    let suite = TestSuite::new().await;
+    let (_, authorization) = register(&suite).await;
+
+    let request = |name: &str| {
+        TestRequest::post()
+            .uri("/worlds")
+            .insert_header(("Authorization", authorization.as_str()))
+            .insert_header(("Idempotency-Key", "7d6f1b9c-world"))
+            .set_json(&json!({ "name": name }))
+            .to_request()
+    };
+
+    let first = suite.inject(request("Middle Earth")).await;
+    check!(first.status == 201);
+
+    let response = suite.inject(request("Narnia")).await;
+    check!(response.status == 409);
+
+    check!(response.headers.get("content-type").unwrap() == "application/problem+json");
+
+    assert_json_snapshot!(response.to_json().unwrap(), @r###"
+    {
+      "type": "tag:worlds,2021:problems/idempotency/key_reused",
+      "title": "Idempotency Key Already Used For A Different Request",
+      "status": 409
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn retry_after_validation_failure() {
+    let suite = TestSuite::new().await;
+    let (_, authorization) = register(&suite).await;
+
+    let request = || {
+        TestRequest::post()
+            .uri("/worlds")
+            .insert_header(("Authorization", authorization.as_str()))
+            .insert_header(("Idempotency-Key", "7d6f1b9c-world"))
+            .set_json(&json!({}))
+            .to_request()
+    };
+
+    check!(suite.inject(request()).await.status == 422);
+
+    let retry = suite.inject(request()).await;
+    check!(retry.status == 422);
+    check!(retry.headers.get("idempotent-replayed").unwrap() == "true");
+}
+
+#[actix_rt::test]
+async fn retry_anonymous_request() {
+    let suite = TestSuite::new().await;
+
+    let request = || {
+        TestRequest::post()
+            .uri("/authenticate/register")
+            .insert_header(("Idempotency-Key", "7d6f1b9c-register"))
+            .set_json(&json!({
+              "username": "testuser",
+              "email": "testuser@example.com",
+              "displayName": "Test User",
+              "password": "Hedgehog-Lantern-47"
+            }))
+            .to_request()
+    };
+
+    let first = suite.inject(request()).await;
+    check!(first.status == 200);
+
+    let retry = suite.inject(request()).await;
+    check!(retry.status == 200);
+    check!(retry.headers.get("idempotent-replayed").unwrap() == "true");
+    check!(retry.to_json().unwrap() == first.to_json().unwrap());
+}
+
+#[actix_rt::test]
+async fn never_replay_token_secrets() {
+    let suite = TestSuite::new().await;
+    let (user_id, authorization) = register(&suite).await;
+
+    let request = || {
+        TestRequest::post()
+            .uri(&format!("/users/{user_id}/tokens"))
+            .insert_header(("Authorization", authorization.as_str()))
+            .insert_header(("Idempotency-Key", "7d6f1b9c-token"))
+            .set_json(&json!({
+              "name": "Scripting",
+              "scopes": ["users:read"],
+              "expiresInDays": 30
+            }))
+            .to_request()
+    };
+
+    let first = suite.inject(request()).await;
+    check!(first.status == 201);
+    check!(first.headers.get("cache-control").unwrap() == "no-store");
+
+    let retry = suite.inject(request()).await;
+    check!(retry.status == 201);
+    check!(retry.headers.get("idempotent-replayed").is_none());
+    check!(retry.to_json().unwrap().get("token") != first.to_json().unwrap().get("token"));
+}
@@ -0,0 +1,372 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use insta::assert_json_snapshot;
+use serde_json::json;
+
+use crate::tests::{
+    database::seed::SeedUser,
+    suite::{field, TestSuite},
+    uploads::{multipart, png, Part},
+};
+
+const USER_ID: &str = "1b7cfc5a-4c9f-4b6b-a0a4-b7a6f6e0d7c2";
+
+/// Build a test suite containing a world with a 600x300 map in it.
+///
+/// # Returns
+/// The test suite, and the URL of the map.
+async fn build_map() -> (TestSuite, String) {
+    let suite = TestSuite::new().await;
+    suite
+        .seed(&SeedUser {
+            user_id: USER_ID.parse().unwrap(),
+            ..SeedUser::default()
+        })
+        .await;
+
+    let (_, world) = suite
+        .send(USER_ID, TestRequest::post().uri("/worlds"), Some(json!({"name": "Middle Earth"})))
+        .await;
+    let world_id = field(world.as_ref(), "worldId");
+
+    let image = png(600, 300);
+    let (content_type, body) = multipart(&[
+        Part {
+            name:     "purpose",
+            filename: None,
+            data:     b"map",
+        },
+        Part {
+            name:     "file",
+            filename: Some("middle-earth.png"),
+            data:     &image,
+        },
+    ]);
+    let response = suite
+        .inject(
+            TestRequest::post()
+                .uri(&format!("/worlds/{}/uploads", world_id))
+                .append_header(suite.authenticate(USER_ID).await)
+                .append_header(("content-type", content_type))
+                .set_payload(body)
+                .to_request(),
+        )
+        .await;
+    let upload_id = field(response.to_json().ok().as_ref(), "uploadId");
+
+    let (_, map) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{}/maps", world_id)),
+            Some(json!({"name": "Middle Earth", "uploadId": upload_id})),
+        )
+        .await;
+    let map_url = format!("/worlds/{}/maps/{}", world_id, field(map.as_ref(), "mapId"));
+
+    (suite, map_url)
+}
+
+/// Build a test suite containing a map with a layer on it.
+///
+/// # Returns
+/// The test suite, the URL of the map and the ID of the layer.
+async fn build_layer() -> (TestSuite, String, String) {
+    let (suite, map_url) = build_map().await;
+
+    let (_, layer) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("{}/layers", map_url)),
+            Some(json!({"name": "Cities"})),
+        )
+        .await;
+    let layer_id = field(layer.as_ref(), "layerId");
+
+    (suite, map_url, layer_id)
+}
+
+#[actix_rt::test]
+async fn get_map() {
+    let (suite, map_url) = build_map().await;
+
+    let (status, map) = suite.send(USER_ID, TestRequest::get().uri(&map_url), None).await;
+
+    check!(status == 200);
+    assert_json_snapshot!(map.unwrap(), {
+        ".mapId" => "[map_id]",
+        ".uploadId" => "[upload_id]",
+        ".tileUrl" => "[tile_url]",
+        ".created" => "[created]",
+        ".updated" => "[updated]",
+    }, @r###"
+    {
+      "mapId": "[map_id]",
+      "name": "Middle Earth",
+      "uploadId": "[upload_id]",
+      "width": 600,
+      "height": 300,
+      "maxZoom": 2,
+      "tileSize": 256,
+      "tileUrl": "[tile_url]",
+      "created": "[created]",
+      "updated": "[updated]"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn get_tile() {
+    let (suite, map_url) = build_map().await;
+
+    let response = suite
+        .inject(
+            TestRequest::get()
+                .uri(&format!("{}/tiles/2/2/1", map_url))
+                .append_header(suite.authenticate(USER_ID).await)
+                .to_request(),
+        )
+        .await;
+
+    check!(response.status == 200);
+    check!(response.header("content-type").unwrap() == "image/png");
+}
+
+#[actix_rt::test]
+async fn get_tile_outside_map() {
+    let (suite, map_url) = build_map().await;
+
+    let (status, _) = suite
+        .send(USER_ID, TestRequest::get().uri(&format!("{}/tiles/2/3/0", map_url)), None)
+        .await;
+
+    check!(status == 404);
+}
+
+#[actix_rt::test]
+async fn create_layer() {
+    let (suite, map_url) = build_map().await;
+
+    let (status, layer) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("{}/layers", map_url)),
+            Some(json!({"name": "Cities"})),
+        )
+        .await;
+
+    check!(status == 201);
+    assert_json_snapshot!(layer.unwrap(), {
+        ".layerId" => "[layer_id]",
+        ".created" => "[created]",
+        ".updated" => "[updated]",
+    }, @r###"
+    {
+      "layerId": "[layer_id]",
+      "name": "Cities",
+      "position": 0,
+      "visible": true,
+      "created": "[created]",
+      "updated": "[updated]"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn create_pin_marker() {
+    let (suite, map_url, layer_id) = build_layer().await;
+
+    let (status, marker) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("{}/markers", map_url)),
+            Some(json!({"layerId": layer_id, "label": "Hobbiton", "shape": {"type": "pin", "x": 100.0, "y": 50.0}})),
+        )
+        .await;
+
+    check!(status == 201);
+    assert_json_snapshot!(marker.unwrap(), {
+        ".markerId" => "[marker_id]",
+        ".layerId" => "[layer_id]",
+        ".created" => "[created]",
+        ".updated" => "[updated]",
+    }, @r###"
+    {
+      "markerId": "[marker_id]",
+      "layerId": "[layer_id]",
+      "label": "Hobbiton",
+      "description": null,
+      "shape": {
+        "type": "pin",
+        "x": 100.0,
+        "y": 50.0
+      },
+      "locationId": null,
+      "articleId": null,
+      "created": "[created]",
+      "updated": "[updated]"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn create_polygon_marker() {
+    let (suite, map_url, layer_id) = build_layer().await;
+
+    let (status, marker) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("{}/markers", map_url)),
+            Some(json!({
+                "layerId": layer_id,
+                "label": "Mordor",
+                "shape": {"type": "polygon", "points": [{"x": 500.0, "y": 200.0}, {"x": 590.0, "y": 200.0}, {"x": 550.0, "y": 290.0}]}
+            })),
+        )
+        .await;
+
+    check!(status == 201);
+    assert_json_snapshot!(marker.unwrap().get("shape").unwrap(), @r###"
+    {
+      "type": "polygon",
+      "points": [
+        {
+          "x": 500.0,
+          "y": 200.0
+        },
+        {
+          "x": 590.0,
+          "y": 200.0
+        },
+        {
+          "x": 550.0,
+          "y": 290.0
+        }
+      ]
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn create_marker_outside_map() {
+    let (suite, map_url, layer_id) = build_layer().await;
+
+    let (status, _) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("{}/markers", map_url)),
+            Some(json!({"layerId": layer_id, "label": "Valinor", "shape": {"type": "pin", "x": -10.0, "y": 50.0}})),
+        )
+        .await;
+
+    check!(status == 422);
+}
+
+#[actix_rt::test]
+async fn list_markers_in_bounding_box() {
+    let (suite, map_url, layer_id) = build_layer().await;
+    for (label, x, y) in &[
+        ("Hobbiton", 100.0, 50.0),
+        ("Minas Tirith", 450.0, 250.0),
+        ("Rivendell", 300.0, 60.0),
+    ] {
+        suite
+            .send(
+                USER_ID,
+                TestRequest::post().uri(&format!("{}/markers", map_url)),
+                Some(json!({"layerId": layer_id, "label": label, "shape": {"type": "pin", "x": x, "y": y}})),
+            )
+            .await;
+    }
+
+    let (status, markers) = suite
+        .send(
+            USER_ID,
+            TestRequest::get().uri(&format!("{}/markers?bbox=400,150,600,300", map_url)),
+            None,
+        )
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(markers.unwrap(), {
+        ".markers[].markerId" => "[marker_id]",
+        ".markers[].layerId" => "[layer_id]",
+        ".markers[].created" => "[created]",
+        ".markers[].updated" => "[updated]",
+    }, @r###"
+    {
+      "markers": [
+        {
+          "markerId": "[marker_id]",
+          "layerId": "[layer_id]",
+          "label": "Minas Tirith",
+          "description": null,
+          "shape": {
+            "type": "pin",
+            "x": 450.0,
+            "y": 250.0
+          },
+          "locationId": null,
+          "articleId": null,
+          "created": "[created]",
+          "updated": "[updated]"
+        }
+      ]
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn list_markers_in_inverted_bounding_box() {
+    let (suite, map_url) = build_map().await;
+
+    let (status, _) = suite
+        .send(
+            USER_ID,
+            TestRequest::get().uri(&format!("{}/markers?bbox=600,0,0,300", map_url)),
+            None,
+        )
+        .await;
+
+    check!(status == 400);
+}
+
+#[actix_rt::test]
+async fn delete_layer_with_markers() {
+    let (suite, map_url, layer_id) = build_layer().await;
+    suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("{}/markers", map_url)),
+            Some(json!({"layerId": layer_id, "label": "Hobbiton", "shape": {"type": "pin", "x": 100.0, "y": 50.0}})),
+        )
+        .await;
+
+    let (status, _) = suite
+        .send(
+            USER_ID,
+            TestRequest::delete().uri(&format!("{}/layers/{}", map_url, layer_id)),
+            None,
+        )
+        .await;
+    check!(status == 204);
+
+    let (_, markers) = suite
+        .send(USER_ID, TestRequest::get().uri(&format!("{}/markers", map_url)), None)
+        .await;
+    assert_json_snapshot!(markers.unwrap(), @r###"
+    {
+      "markers": []
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn delete_map() {
+    let (suite, map_url) = build_map().await;
+
+    let (status, _) = suite.send(USER_ID, TestRequest::delete().uri(&map_url), None).await;
+    check!(status == 204);
+
+    let (status, _) = suite.send(USER_ID, TestRequest::get().uri(&map_url), None).await;
+    check!(status == 404);
+}
@@ -0,0 +1,182 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use insta::assert_json_snapshot;
+use serde_json::json;
+
+use crate::tests::suite::{field, TestSuite};
+
+const USER_ID: &str = "4ea96dc3-df11-43c0-8a33-a0813f03937f";
+
+/// Build a test suite with a world containing Arathorn and Aragorn.
+///
+/// # Returns
+/// The test suite, the ID of the world, and the IDs of Arathorn and Aragorn.
+async fn build_family() -> (TestSuite, String, String, String) {
+    let suite = TestSuite::new().await;
+    let world_id = suite.seed_world(USER_ID).await;
+
+    let mut ids = vec![];
+    for name in ["Arathorn", "Aragorn"] {
+        let (_, character) = suite
+            .send(
+                USER_ID,
+                TestRequest::post().uri(&format!("/worlds/{world_id}/characters")),
+                Some(json!({ "name": name })),
+            )
+            .await;
+        ids.push(field(character.as_ref(), "entityId"));
+    }
+    let aragorn = ids.pop().unwrap();
+    let arathorn = ids.pop().unwrap();
+
+    (suite, world_id, arathorn, aragorn)
+}
+
+/// Build a test suite with a world where Arathorn is the parent of Aragorn.
+///
+/// # Returns
+/// The test suite, the ID of the world, and the ID of Aragorn.
+async fn build_relationship() -> (TestSuite, String, String) {
+    let (suite, world_id, arathorn, aragorn) = build_family().await;
+
+    let (status, _) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/relationships")),
+            Some(json!({"sourceId": arathorn, "targetId": aragorn, "kind": "parent_of", "starts": "TA 2931"})),
+        )
+        .await;
+    check!(status == 201);
+
+    (suite, world_id, aragorn)
+}
+
+#[actix_rt::test]
+async fn create_relationship() {
+    let (suite, world_id, arathorn, aragorn) = build_family().await;
+
+    let (status, relationship) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/relationships")),
+            Some(json!({"sourceId": arathorn, "targetId": aragorn, "kind": "parent_of", "starts": "TA 2931"})),
+        )
+        .await;
+
+    check!(status == 201);
+    check!(field(relationship.as_ref(), "sourceId") == arathorn);
+    check!(field(relationship.as_ref(), "targetId") == aragorn);
+    assert_json_snapshot!(relationship.unwrap(), {
+        ".relationshipId" => "[relationship_id]",
+        ".sourceId" => "[arathorn]",
+        ".targetId" => "[aragorn]",
+        ".created" => "[created]",
+        ".updated" => "[updated]",
+    }, @r###"
+    {
+      "relationshipId": "[relationship_id]",
+      "sourceId": "[arathorn]",
+      "targetId": "[aragorn]",
+      "kind": "parent_of",
+      "starts": "TA 2931",
+      "ends": null,
+      "created": "[created]",
+      "updated": "[updated]"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn family_graph() {
+    let (suite, world_id, aragorn) = build_relationship().await;
+
+    let (status, graph) = suite
+        .send(
+            USER_ID,
+            TestRequest::get().uri(&format!("/worlds/{world_id}/graph/{aragorn}/family")),
+            None,
+        )
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(graph.unwrap(), {
+        ".graph.nodes[].id" => "[entity_id]",
+        ".graph.edges[].id" => "[relationship_id]",
+        ".graph.edges[].source" => "[arathorn]",
+        ".graph.edges[].target" => "[aragorn]",
+    }, @r###"
+    {
+      "graph": {
+        "directed": true,
+        "nodes": [
+          {
+            "id": "[entity_id]",
+            "label": "Aragorn",
+            "metadata": {
+              "kind": "character"
+            }
+          },
+          {
+            "id": "[entity_id]",
+            "label": "Arathorn",
+            "metadata": {
+              "kind": "character"
+            }
+          }
+        ],
+        "edges": [
+          {
+            "id": "[relationship_id]",
+            "source": "[arathorn]",
+            "target": "[aragorn]",
+            "relation": "parent_of",
+            "label": "parent of",
+            "metadata": {
+              "starts": "TA 2931",
+              "ends": null
+            }
+          }
+        ]
+      }
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn graph_as_dot() {
+    let (suite, world_id, _) = build_relationship().await;
+
+    let response = suite
+        .inject(
+            TestRequest::get()
+                .uri(&format!("/worlds/{world_id}/graph?format=dot"))
+                .append_header(suite.authenticate(USER_ID).await)
+                .to_request(),
+        )
+        .await;
+
+    check!(response.status == 200);
+    check!(response.header("content-type").unwrap() == "text/vnd.graphviz; charset=utf-8");
+}
+
+#[actix_rt::test]
+async fn parent_of_self() {
+    let (suite, world_id, _, aragorn) = build_family().await;
+
+    let (status, problem) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/relationships")),
+            Some(json!({"sourceId": aragorn, "targetId": aragorn, "kind": "parent_of"})),
+        )
+        .await;
+
+    check!(status == 422);
+    assert_json_snapshot!(problem.unwrap(), @r###"
+    {
+      "type": "tag:worlds,2021:problems/relationships/invalid_relationship",
+      "title": "Invalid Relationship",
+      "status": 422
+    }
+    "###);
+}
@@ -0,0 +1 @@
+mod search_world;
@@ -0,0 +1,181 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use insta::assert_json_snapshot;
+use serde_json::json;
+
+use crate::tests::{
+    database::seed::SeedUser,
+    suite::{field, TestSuite},
+};
+
+const USER_ID: &str = "6a0e3a4e-5c1b-4d6e-9c39-0b2f5b1d8e41";
+const OTHER_USER_ID: &str = "d3f4c1a2-7e8b-4f0a-b2c5-91e6a7d4c3b8";
+
+/// Build a test suite containing a world with an article and a character in it.
+///
+/// # Returns
+/// The test suite, and the ID of the world.
+async fn build_world() -> (TestSuite, String) {
+    let suite = TestSuite::new().await;
+    for user_id in &[USER_ID, OTHER_USER_ID] {
+        suite
+            .seed(&SeedUser {
+                user_id: user_id.parse().unwrap(),
+                ..SeedUser::default()
+            })
+            .await;
+    }
+
+    let (_, world) = suite
+        .send(USER_ID, TestRequest::post().uri("/worlds"), Some(json!({"name": "Middle Earth"})))
+        .await;
+    let world_id = field(world.as_ref(), "worldId");
+
+    suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{}/articles", world_id)),
+            Some(json!({"title": "The Fellowship", "body": "Nine walkers set out from [[Rivendell]] to destroy the ring."})),
+        )
+        .await;
+    suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{}/characters", world_id)),
+            Some(json!({"name": "Frodo Baggins", "summary": "The bearer of the ring."})),
+        )
+        .await;
+
+    (suite, world_id)
+}
+
+#[actix_rt::test]
+async fn search_world() {
+    let (suite, world_id) = build_world().await;
+
+    let (status, results) = suite
+        .send(
+            USER_ID,
+            TestRequest::get().uri(&format!("/worlds/{}/search?q=rings", world_id)),
+            None,
+        )
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(results.unwrap(), {
+        ".results[].resourceId" => "[resource_id]",
+        ".results[].rank" => "[rank]",
+    }, @r###"
+    {
+      "results": [
+        {
+          "kind": "character",
+          "resourceId": "[resource_id]",
+          "title": "Frodo Baggins",
+          "snippet": "bearer of the <mark>ring</mark>",
+          "rank": "[rank]"
+        },
+        {
+          "kind": "article",
+          "resourceId": "[resource_id]",
+          "title": "The Fellowship",
+          "snippet": "Nine walkers set out from Rivendell to destroy the <mark>ring</mark>",
+          "rank": "[rank]"
+        }
+      ],
+      "facets": {
+        "kind": [
+          {
+            "value": "article",
+            "count": 1
+          },
+          {
+            "value": "character",
+            "count": 1
+          }
+        ],
+        "tag": []
+      },
+      "page": {
+        "offset": 0,
+        "limit": 20,
+        "total": 2
+      }
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn search_world_by_kind() {
+    let (suite, world_id) = build_world().await;
+
+    let (status, results) = suite
+        .send(
+            USER_ID,
+            TestRequest::get().uri(&format!("/worlds/{}/search?q=ring&kind=character", world_id)),
+            None,
+        )
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(results.unwrap(), {
+        ".results[].resourceId" => "[resource_id]",
+        ".results[].rank" => "[rank]",
+    }, @r###"
+    {
+      "results": [
+        {
+          "kind": "character",
+          "resourceId": "[resource_id]",
+          "title": "Frodo Baggins",
+          "snippet": "bearer of the <mark>ring</mark>",
+          "rank": "[rank]"
+        }
+      ],
+      "facets": {
+        "kind": [
+          {
+            "value": "article",
+            "count": 1
+          },
+          {
+            "value": "character",
+            "count": 1
+          }
+        ],
+        "tag": []
+      },
+      "page": {
+        "offset": 0,
+        "limit": 20,
+        "total": 1
+      }
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn search_world_blank_query() {
+    let (suite, world_id) = build_world().await;
+
+    let (status, _) = suite
+        .send(USER_ID, TestRequest::get().uri(&format!("/worlds/{}/search?q=+", world_id)), None)
+        .await;
+
+    check!(status == 400);
+}
+
+#[actix_rt::test]
+async fn search_world_not_member() {
+    let (suite, world_id) = build_world().await;
+
+    let (status, _) = suite
+        .send(
+            OTHER_USER_ID,
+            TestRequest::get().uri(&format!("/worlds/{}/search?q=ring", world_id)),
+            None,
+        )
+        .await;
+
+    check!(status == 404);
+}
@@ -0,0 +1,2 @@
+mod delete_session;
+mod list_sessions;
@@ -0,0 +1,154 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use serde_json::json;
+
+use crate::tests::{database::seed::SeedUser, suite::TestSuite};
+
+/// Authenticate as the test user, returning the token and the ID of the session it belongs to.
+async fn authenticate(suite: &TestSuite) -> (String, String) {
+    let response = suite
+        .inject(
+            TestRequest::post()
+                .uri("/authenticate/authenticate")
+                .set_json(&json!({
+                  "username": "testuser",
+                  "password": "password"
+                }))
+                .to_request(),
+        )
+        .await;
+
+    check!(response.status == 200);
+    let token = response.to_json().unwrap().get("token").unwrap().as_str().unwrap().to_owned();
+
+    let response = suite
+        .inject(
+            TestRequest::get()
+                .uri("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f/sessions")
+                .append_header(("Authorization", format!("Bearer {}", token)))
+                .to_request(),
+        )
+        .await;
+
+    check!(response.status == 200);
+    let sessions = response.to_json().unwrap();
+    let session_id = sessions
+        .get("sessions")
+        .unwrap()
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|s| s.get("current").unwrap() == true)
+        .unwrap()
+        .get("sessionId")
+        .unwrap()
+        .as_str()
+        .unwrap()
+        .to_owned();
+
+    (token, session_id)
+}
+
+async fn build_suite() -> TestSuite {
+    let user = SeedUser {
+        user_id: "4ea96dc3-df11-43c0-8a33-a0813f03937f".parse().unwrap(),
+        username: "testuser".to_owned(),
+        ..SeedUser::default()
+    }
+    .with_password("password");
+
+    let suite = TestSuite::new().await;
+    suite.seed(&user).await;
+
+    suite
+}
+
+#[actix_rt::test]
+async fn delete_other_session() {
+    let suite = build_suite().await;
+    let (first_token, first_session) = authenticate(&suite).await;
+    let (second_token, _) = authenticate(&suite).await;
+
+    let response = suite
+        .inject(
+            TestRequest::delete()
+                .uri(&format!("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f/sessions/{}", first_session))
+                .append_header(("Authorization", format!("Bearer {}", second_token)))
+                .to_request(),
+        )
+        .await;
+
+    check!(response.status == 204);
+
+    let response = suite
+        .inject(
+            TestRequest::get()
+                .uri("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f")
+                .append_header(("Authorization", format!("Bearer {}", first_token)))
+                .to_request(),
+        )
+        .await;
+
+    check!(response.status == 401);
+
+    let response = suite
+        .inject(
+            TestRequest::get()
+                .uri("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f")
+                .append_header(("Authorization", format!("Bearer {}", second_token)))
+                .to_request(),
+        )
+        .await;
+
+    check!(response.status == 200);
+}
+
+#[actix_rt::test]
+async fn delete_unknown_session() {
+    let suite = build_suite().await;
+    let (token, _) = authenticate(&suite).await;
+
+    let response = suite
+        .inject(
+            TestRequest::delete()
+                .uri("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f/sessions/8d3a8f51-2b4c-4a0e-9f53-64f4c2e1d1b7")
+                .append_header(("Authorization", format!("Bearer {}", token)))
+                .to_request(),
+        )
+        .await;
+
+    check!(response.status == 404);
+
+    check!(response.headers.get("content-type").unwrap() == "application/problem+json");
+}
+
+#[actix_rt::test]
+async fn delete_all_sessions() {
+    let suite = build_suite().await;
+    let (first_token, _) = authenticate(&suite).await;
+    let (second_token, _) = authenticate(&suite).await;
+
+    let response = suite
+        .inject(
+            TestRequest::delete()
+                .uri("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f/sessions")
+                .append_header(("Authorization", format!("Bearer {}", second_token)))
+                .to_request(),
+        )
+        .await;
+
+    check!(response.status == 204);
+
+    for token in &[first_token, second_token] {
+        let response = suite
+            .inject(
+                TestRequest::get()
+                    .uri("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f")
+                    .append_header(("Authorization", format!("Bearer {}", token)))
+                    .to_request(),
+            )
+            .await;
+
+        check!(response.status == 401);
+    }
+}
@@ -0,0 +1,123 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use insta::assert_json_snapshot;
+use serde_json::json;
+
+use crate::tests::{database::seed::SeedUser, suite::TestSuite};
+
+#[actix_rt::test]
+async fn unauthenticated() {
+    let suite = TestSuite::new().await;
+
+    let response = suite
+        .inject(
+            TestRequest::get()
+                .uri("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f/sessions")
+                .to_request(),
+        )
+        .await;
+
+    check!(response.status == 401);
+
+    check!(response.headers.get("content-type").unwrap() == "application/problem+json");
+}
+
+#[actix_rt::test]
+async fn wrong_user() {
+    let user = SeedUser {
+        user_id: "4ea96dc3-df11-43c0-8a33-a0813f03937f".parse().unwrap(),
+        ..SeedUser::default()
+    };
+    let other = SeedUser {
+        user_id: "37f35c28-1c26-465d-9a45-b87e59a9760a".parse().unwrap(),
+        ..SeedUser::default()
+    };
+
+    let suite = TestSuite::new().await;
+    suite.seed(&user).await;
+    suite.seed(&other).await;
+
+    let response = suite
+        .inject(
+            TestRequest::get()
+                .uri("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f/sessions")
+                .append_header(suite.authenticate("37f35c28-1c26-465d-9a45-b87e59a9760a").await)
+                .to_request(),
+        )
+        .await;
+
+    check!(response.status == 403);
+
+    check!(response.headers.get("content-type").unwrap() == "application/problem+json");
+}
+
+#[actix_rt::test]
+async fn success() {
+    let user = SeedUser {
+        user_id: "4ea96dc3-df11-43c0-8a33-a0813f03937f".parse().unwrap(),
+        username: "testuser".to_owned(),
+        ..SeedUser::default()
+    }
+    .with_password("password");
+
+    let suite = TestSuite::new().await;
+    suite.seed(&user).await;
+
+    let response = suite
+        .inject(
+            TestRequest::post()
+                .uri("/authenticate/authenticate")
+                .insert_header(("User-Agent", "Test Agent/1.0"))
+                .set_json(&json!({
+                  "username": "testuser",
+                  "password": "password",
+                  "deviceName": "Laptop"
+                }))
+                .to_request(),
+        )
+        .await;
+
+    check!(response.status == 200);
+
+    let response = suite
+        .inject(
+            TestRequest::get()
+                .uri("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f/sessions")
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
+                .to_request(),
+        )
+        .await;
+
+    check!(response.status == 200);
+
+    check!(response.headers.get("content-type").unwrap() == "application/json");
+
+    assert_json_snapshot!(response.to_json().unwrap(), {
+        ".sessions[].sessionId" => "[session_id]",
+        ".sessions[].created" => "[created]",
+        ".sessions[].lastSeen" => "[last_seen]",
+      }, @r###"
+    {
+      "sessions": [
+        {
+          "sessionId": "[session_id]",
+          "deviceName": null,
+          "userAgent": null,
+          "ipAddress": null,
+          "created": "[created]",
+          "lastSeen": "[last_seen]",
+          "current": true
+        },
+        {
+          "sessionId": "[session_id]",
+          "deviceName": "Laptop",
+          "userAgent": "Test Agent/1.0",
+          "ipAddress": null,
+          "created": "[created]",
+          "lastSeen": "[last_seen]",
+          "current": false
+        }
+      ]
+    }
+    "###);
+}
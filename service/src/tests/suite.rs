@@ -1,9 +1,14 @@
 use actix_http::{http::header::IntoHeaderPair, Request};
+use actix_web::test::TestRequest;
+use serde_json::{json, Value};
 
-use super::database::{seed::SeedData, TestDatabase};
+use super::database::{
+    seed::{SeedData, SeedUser},
+    TestDatabase,
+};
 use crate::{
     service::{testing::TestResponse, Service},
-    settings::Settings,
+    settings::{Mode, Settings},
 };
 
 /// Wrapper around the components needed to test the service.
@@ -19,11 +24,40 @@ impl TestSuite {
     pub async fn new() -> Self {
         let _ = env_logger::try_init();
 
-        let db = TestDatabase::new().await;
+        let db = TestDatabase::new();
 
         let service = Service::new(Settings {
-            port:         0,
-            database_url: db.url.clone(),
+            port:                     0,
+            database_url:             db.url.clone(),
+            password_min_length:      8,
+            password_min_strength:    3,
+            breached_passwords_path:  None,
+            password_memory_size:     64,
+            password_iterations:      1,
+            password_lanes:           1,
+            password_pepper:          None,
+            blob_store:               "filesystem".to_owned(),
+            blob_path:                std::env::temp_dir()
+                .join(format!("worlds-uploads-{}", uuid::Uuid::new_v4()))
+                .to_string_lossy()
+                .into_owned(),
+            s3_bucket:                None,
+            s3_region:                "us-east-1".to_owned(),
+            s3_endpoint:              None,
+            s3_access_key:            None,
+            s3_secret_key:            None,
+            upload_max_size:          1024 * 1024,
+            mode:                     Mode::All,
+            job_concurrency:          1,
+            outbox_sinks:             "log".to_owned(),
+            outbox_webhook_url:       None,
+            outbox_webhook_secret:    None,
+            outbox_nats_url:          None,
+            outbox_nats_prefix:       "worlds".to_owned(),
+            outbox_kafka_url:         None,
+            outbox_kafka_topic:       "worlds-events".to_owned(),
+            idempotency_window_hours: 24,
+            trusted_proxies:          None,
         })
         .await;
 
@@ -41,7 +75,62 @@ impl TestSuite {
         self.db.seed(data).await;
     }
 
-    pub fn authenticate(&self, user_id: &str) -> impl IntoHeaderPair {
-        self.service.authorize(user_id)
+    /// Seed a user with the given ID and otherwise default details.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user
+    pub async fn seed_user(&self, user_id: &str) {
+        self.seed(&SeedUser {
+            user_id: user_id.parse().unwrap(),
+            ..SeedUser::default()
+        })
+        .await;
+    }
+
+    /// Seed a user, and create a world that they own.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user that owns the world
+    ///
+    /// # Returns
+    /// The ID of the world.
+    pub async fn seed_world(&self, user_id: &str) -> String {
+        self.seed_user(user_id).await;
+
+        let (_, world) = self
+            .send(user_id, TestRequest::post().uri("/worlds"), Some(json!({"name": "Middle Earth"})))
+            .await;
+
+        field(world.as_ref(), "worldId")
     }
+
+    pub async fn authenticate(&self, user_id: &str) -> impl IntoHeaderPair {
+        self.service.authorize(user_id).await
+    }
+
+    /// Send a request as a user, returning the status code and JSON body of the response.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user to send the request as
+    /// - `request` - The request to send
+    /// - `body` - The JSON body to send with the request, if any
+    pub async fn send(&self, user_id: &str, request: TestRequest, body: Option<Value>) -> (u16, Option<Value>) {
+        let mut request = request.append_header(self.authenticate(user_id).await);
+        if let Some(body) = body {
+            request = request.set_json(&body);
+        }
+
+        let response = self.inject(request.to_request()).await;
+
+        (response.status.as_u16(), response.to_json().ok())
+    }
+}
+
+/// Get a string field from a JSON body.
+///
+/// # Parameters
+/// - `body` - The JSON body
+/// - `name` - The name of the field
+pub fn field(body: Option<&Value>, name: &str) -> String {
+    body.unwrap().get(name).unwrap().as_str().unwrap().to_owned()
 }
@@ -0,0 +1 @@
+mod tag_content;
@@ -0,0 +1,348 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use insta::assert_json_snapshot;
+use serde_json::json;
+
+use crate::tests::{
+    database::seed::SeedUser,
+    suite::{field, TestSuite},
+};
+
+const USER_ID: &str = "4b8e2f1c-9d3a-4e6b-a7c5-2f1e8d9b0a34";
+const OTHER_USER_ID: &str = "e7a1c3d5-2b4f-4a6e-8c9d-0f1a2b3c4d5e";
+
+/// Build a test suite containing a world with a character in it.
+///
+/// # Returns
+/// The test suite, the ID of the world and the ID of the character.
+async fn build_world() -> (TestSuite, String, String) {
+    let suite = TestSuite::new().await;
+    for user_id in &[USER_ID, OTHER_USER_ID] {
+        suite
+            .seed(&SeedUser {
+                user_id: user_id.parse().unwrap(),
+                ..SeedUser::default()
+            })
+            .await;
+    }
+
+    let (_, world) = suite
+        .send(USER_ID, TestRequest::post().uri("/worlds"), Some(json!({"name": "Middle Earth"})))
+        .await;
+    let world_id = field(world.as_ref(), "worldId");
+
+    let (_, character) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{}/characters", world_id)),
+            Some(json!({"name": "Frodo Baggins", "summary": "The bearer of the ring."})),
+        )
+        .await;
+    let entity_id = field(character.as_ref(), "entityId");
+
+    (suite, world_id, entity_id)
+}
+
+/// Create a tag in a world.
+///
+/// # Returns
+/// The ID of the new tag.
+async fn create_tag(suite: &TestSuite, world_id: &str, name: &str) -> String {
+    let (_, tag) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{}/tags", world_id)),
+            Some(json!({ "name": name })),
+        )
+        .await;
+
+    field(tag.as_ref(), "tagId")
+}
+
+/// Tag the character in a world.
+async fn tag_character(suite: &TestSuite, world_id: &str, entity_id: &str, tag_id: &str) {
+    suite
+        .send(
+            USER_ID,
+            TestRequest::put().uri(&format!("/worlds/{}/taxonomy/character/{}", world_id, entity_id)),
+            Some(json!({"tags": [tag_id], "categories": []})),
+        )
+        .await;
+}
+
+#[actix_rt::test]
+async fn create_tag_in_world() {
+    let (suite, world_id, _) = build_world().await;
+
+    let (status, tag) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{}/tags", world_id)),
+            Some(json!({"name": "Hobbits"})),
+        )
+        .await;
+
+    check!(status == 201);
+    assert_json_snapshot!(tag.unwrap(), {
+        ".tagId" => "[tag_id]",
+        ".created" => "[created]",
+        ".updated" => "[updated]",
+    }, @r###"
+    {
+      "tagId": "[tag_id]",
+      "name": "Hobbits",
+      "created": "[created]",
+      "updated": "[updated]"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn create_duplicate_tag() {
+    let (suite, world_id, _) = build_world().await;
+    create_tag(&suite, &world_id, "Hobbits").await;
+
+    let (status, _) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{}/tags", world_id)),
+            Some(json!({"name": "hobbits"})),
+        )
+        .await;
+
+    check!(status == 422);
+}
+
+#[actix_rt::test]
+async fn list_category_tree() {
+    let (suite, world_id, _) = build_world().await;
+    let (status, people) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{}/categories", world_id)),
+            Some(json!({"name": "People"})),
+        )
+        .await;
+    check!(status == 201);
+    let (status, _) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{}/categories", world_id)),
+            Some(json!({"name": "Shire Folk", "parentId": field(people.as_ref(), "categoryId")})),
+        )
+        .await;
+    check!(status == 201);
+
+    let (status, categories) = suite
+        .send(USER_ID, TestRequest::get().uri(&format!("/worlds/{}/categories", world_id)), None)
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(categories.unwrap(), {
+        ".categories[].categoryId" => "[category_id]",
+        ".categories[].children[].categoryId" => "[category_id]",
+        ".categories[].children[].parentId" => "[category_id]",
+        ".categories[].created" => "[created]",
+        ".categories[].updated" => "[updated]",
+        ".categories[].children[].created" => "[created]",
+        ".categories[].children[].updated" => "[updated]",
+    }, @r###"
+    {
+      "categories": [
+        {
+          "categoryId": "[category_id]",
+          "name": "People",
+          "parentId": null,
+          "created": "[created]",
+          "updated": "[updated]",
+          "children": [
+            {
+              "categoryId": "[category_id]",
+              "name": "Shire Folk",
+              "parentId": "[category_id]",
+              "created": "[created]",
+              "updated": "[updated]",
+              "children": []
+            }
+          ]
+        }
+      ]
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn set_taxonomy() {
+    let (suite, world_id, entity_id) = build_world().await;
+    let tag_id = create_tag(&suite, &world_id, "Hobbits").await;
+    let (_, people) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{}/categories", world_id)),
+            Some(json!({"name": "People"})),
+        )
+        .await;
+
+    let (status, taxonomy) = suite
+        .send(
+            USER_ID,
+            TestRequest::put().uri(&format!("/worlds/{}/taxonomy/character/{}", world_id, entity_id)),
+            Some(json!({"tags": [tag_id], "categories": [field(people.as_ref(), "categoryId")]})),
+        )
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(taxonomy.unwrap(), {
+        ".tags[].tagId" => "[tag_id]",
+        ".categories[].categoryId" => "[category_id]",
+        ".tags[].created" => "[created]",
+        ".tags[].updated" => "[updated]",
+        ".categories[].created" => "[created]",
+        ".categories[].updated" => "[updated]",
+    }, @r###"
+    {
+      "tags": [
+        {
+          "tagId": "[tag_id]",
+          "name": "Hobbits",
+          "created": "[created]",
+          "updated": "[updated]"
+        }
+      ],
+      "categories": [
+        {
+          "categoryId": "[category_id]",
+          "name": "People",
+          "parentId": null,
+          "created": "[created]",
+          "updated": "[updated]"
+        }
+      ]
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn merge_tags() {
+    let (suite, world_id, entity_id) = build_world().await;
+    let hobbits_id = create_tag(&suite, &world_id, "Hobbits").await;
+    let halflings_id = create_tag(&suite, &world_id, "Halflings").await;
+    tag_character(&suite, &world_id, &entity_id, &halflings_id).await;
+
+    let (status, merged) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{}/tags/{}/merge", world_id, halflings_id)),
+            Some(json!({ "tagId": hobbits_id })),
+        )
+        .await;
+    check!(status == 200);
+    check!(field(merged.as_ref(), "tagId") == hobbits_id);
+
+    let (status, tags) = suite
+        .send(USER_ID, TestRequest::get().uri(&format!("/worlds/{}/tags", world_id)), None)
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(tags.unwrap(), {
+        ".tags[].tagId" => "[tag_id]",
+        ".tags[].created" => "[created]",
+        ".tags[].updated" => "[updated]",
+    }, @r###"
+    {
+      "tags": [
+        {
+          "tagId": "[tag_id]",
+          "name": "Hobbits",
+          "created": "[created]",
+          "updated": "[updated]",
+          "count": 1
+        }
+      ]
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn list_tagged_content() {
+    let (suite, world_id, entity_id) = build_world().await;
+    let tag_id = create_tag(&suite, &world_id, "Hobbits").await;
+    tag_character(&suite, &world_id, &entity_id, &tag_id).await;
+
+    let (status, tagged) = suite
+        .send(
+            USER_ID,
+            TestRequest::get().uri(&format!("/worlds/{}/tagged?all={}", world_id, tag_id)),
+            None,
+        )
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(tagged.unwrap(), {
+        ".content[].resourceId" => "[resource_id]",
+    }, @r###"
+    {
+      "content": [
+        {
+          "kind": "character",
+          "resourceId": "[resource_id]",
+          "title": "Frodo Baggins"
+        }
+      ],
+      "page": {
+        "offset": 0,
+        "limit": 20,
+        "total": 1
+      }
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn search_by_tag() {
+    let (suite, world_id, entity_id) = build_world().await;
+    let tag_id = create_tag(&suite, &world_id, "Hobbits").await;
+    tag_character(&suite, &world_id, &entity_id, &tag_id).await;
+    suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{}/characters", world_id)),
+            Some(json!({"name": "Sauron", "summary": "The maker of the ring."})),
+        )
+        .await;
+
+    let (status, results) = suite
+        .send(
+            USER_ID,
+            TestRequest::get().uri(&format!("/worlds/{}/search?q=ring&tag=hobbits", world_id)),
+            None,
+        )
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(results.unwrap().get("results").unwrap(), {
+        "[].resourceId" => "[resource_id]",
+        "[].rank" => "[rank]",
+    }, @r###"
+    [
+      {
+        "kind": "character",
+        "resourceId": "[resource_id]",
+        "title": "Frodo Baggins",
+        "snippet": "bearer of the <mark>ring</mark>",
+        "rank": "[rank]"
+      }
+    ]
+    "###);
+}
+
+#[actix_rt::test]
+async fn list_tags_not_member() {
+    let (suite, world_id, _) = build_world().await;
+
+    let (status, _) = suite
+        .send(OTHER_USER_ID, TestRequest::get().uri(&format!("/worlds/{}/tags", world_id)), None)
+        .await;
+
+    check!(status == 404);
+}
@@ -0,0 +1,2 @@
+mod create_token;
+mod use_token;
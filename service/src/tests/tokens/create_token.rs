@@ -0,0 +1,123 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use insta::assert_json_snapshot;
+use serde_json::json;
+
+use crate::tests::suite::{field, TestSuite};
+
+const USER_ID: &str = "4ea96dc3-df11-43c0-8a33-a0813f03937f";
+const OTHER_USER_ID: &str = "37f35c28-1c26-465d-9a45-b87e59a9760a";
+
+#[actix_rt::test]
+async fn create_token() {
+    let suite = TestSuite::new().await;
+    suite.seed_user(USER_ID).await;
+
+    let (status, token) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/users/{USER_ID}/tokens")),
+            Some(json!({"name": "Scripting", "scopes": ["users:read"], "expiresInDays": 30})),
+        )
+        .await;
+
+    check!(status == 201);
+    check!(field(token.as_ref(), "token").starts_with("wpat_"));
+    assert_json_snapshot!(token.unwrap(), {
+        ".tokenId" => "[token_id]",
+        ".token" => "[token]",
+        ".created" => "[created]",
+        ".expiresAt" => "[expires_at]",
+    }, @r###"
+    {
+      "tokenId": "[token_id]",
+      "name": "Scripting",
+      "scopes": [
+        "users:read"
+      ],
+      "created": "[created]",
+      "expiresAt": "[expires_at]",
+      "lastUsed": null,
+      "token": "[token]"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn list_tokens() {
+    let suite = TestSuite::new().await;
+    suite.seed_user(USER_ID).await;
+    suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/users/{USER_ID}/tokens")),
+            Some(json!({"name": "Scripting", "scopes": ["users:read"], "expiresInDays": 30})),
+        )
+        .await;
+
+    let (status, tokens) = suite
+        .send(USER_ID, TestRequest::get().uri(&format!("/users/{USER_ID}/tokens")), None)
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(tokens.unwrap(), {
+        ".tokens[].tokenId" => "[token_id]",
+        ".tokens[].created" => "[created]",
+        ".tokens[].expiresAt" => "[expires_at]",
+    }, @r###"
+    {
+      "tokens": [
+        {
+          "tokenId": "[token_id]",
+          "name": "Scripting",
+          "scopes": [
+            "users:read"
+          ],
+          "created": "[created]",
+          "expiresAt": "[expires_at]",
+          "lastUsed": null
+        }
+      ]
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn create_token_unknown_scope() {
+    let suite = TestSuite::new().await;
+    suite.seed_user(USER_ID).await;
+
+    let (status, _) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/users/{USER_ID}/tokens")),
+            Some(json!({"name": "Scripting", "scopes": ["users:admin"], "expiresInDays": 30})),
+        )
+        .await;
+
+    check!(status == 422);
+}
+
+#[actix_rt::test]
+async fn create_token_for_other_user() {
+    let suite = TestSuite::new().await;
+    suite.seed_user(USER_ID).await;
+    suite.seed_user(OTHER_USER_ID).await;
+
+    let (status, problem) = suite
+        .send(
+            OTHER_USER_ID,
+            TestRequest::post().uri(&format!("/users/{USER_ID}/tokens")),
+            Some(json!({"name": "Scripting", "scopes": ["users:read"], "expiresInDays": 30})),
+        )
+        .await;
+
+    check!(status == 403);
+    assert_json_snapshot!(problem.unwrap(), @r###"
+    {
+      "type": "about:blank",
+      "title": "Forbidden",
+      "status": 403
+    }
+    "###);
+}
@@ -0,0 +1,144 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use insta::assert_json_snapshot;
+use serde_json::{json, Value};
+
+use crate::tests::suite::{field, TestSuite};
+
+const USER_ID: &str = "4ea96dc3-df11-43c0-8a33-a0813f03937f";
+
+/// Create a personal access token for the test user with the given scopes, returning the secret
+/// value of it.
+async fn create_token(suite: &TestSuite, scopes: &[&str]) -> String {
+    let (status, token) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/users/{USER_ID}/tokens")),
+            Some(json!({"name": "Scripting", "scopes": scopes, "expiresInDays": 30})),
+        )
+        .await;
+
+    check!(status == 201);
+    field(token.as_ref(), "token")
+}
+
+/// Send a request authenticated with a personal access token, returning the status code and JSON
+/// body of the response.
+async fn send_with_token(suite: &TestSuite, token: &str, request: TestRequest, body: Option<Value>) -> (u16, Option<Value>) {
+    let mut request = request.append_header(("Authorization", format!("Bearer {token}")));
+    if let Some(body) = body {
+        request = request.set_json(&body);
+    }
+
+    let response = suite.inject(request.to_request()).await;
+
+    (response.status.as_u16(), response.to_json().ok())
+}
+
+#[actix_rt::test]
+async fn read_user_with_scope() {
+    let suite = TestSuite::new().await;
+    suite.seed_user(USER_ID).await;
+    let token = create_token(&suite, &["users:read"]).await;
+
+    let (status, user) = send_with_token(&suite, &token, TestRequest::get().uri(&format!("/users/{USER_ID}")), None).await;
+
+    check!(status == 200);
+    assert_json_snapshot!(user.unwrap(), {
+        ".username" => "[username]",
+        ".email" => "[email]",
+    }, @r###"
+    {
+      "userId": "4ea96dc3-df11-43c0-8a33-a0813f03937f",
+      "username": "[username]",
+      "email": "[email]",
+      "displayName": "Test User"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn read_user_without_scope() {
+    let suite = TestSuite::new().await;
+    suite.seed_user(USER_ID).await;
+    let token = create_token(&suite, &["users:write"]).await;
+
+    let (status, user) = send_with_token(&suite, &token, TestRequest::get().uri(&format!("/users/{USER_ID}")), None).await;
+
+    check!(status == 200);
+    assert_json_snapshot!(user.unwrap(), {
+        ".username" => "[username]",
+    }, @r###"
+    {
+      "userId": "4ea96dc3-df11-43c0-8a33-a0813f03937f",
+      "username": "[username]",
+      "displayName": "Test User"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn patch_user_without_scope() {
+    let suite = TestSuite::new().await;
+    suite.seed_user(USER_ID).await;
+    let token = create_token(&suite, &["users:read"]).await;
+
+    let (status, problem) = send_with_token(
+        &suite,
+        &token,
+        TestRequest::patch().uri(&format!("/users/{USER_ID}")),
+        Some(json!({"displayName": "New Name"})),
+    )
+    .await;
+
+    check!(status == 403);
+    assert_json_snapshot!(problem.unwrap(), @r###"
+    {
+      "type": "about:blank",
+      "title": "Forbidden",
+      "status": 403
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn manage_tokens_with_token() {
+    let suite = TestSuite::new().await;
+    suite.seed_user(USER_ID).await;
+    let token = create_token(&suite, &["users:read", "users:write"]).await;
+
+    let (status, problem) = send_with_token(&suite, &token, TestRequest::get().uri(&format!("/users/{USER_ID}/tokens")), None).await;
+
+    check!(status == 403);
+    assert_json_snapshot!(problem.unwrap(), @r###"
+    {
+      "type": "about:blank",
+      "title": "Forbidden",
+      "status": 403
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn revoked_token() {
+    let suite = TestSuite::new().await;
+    suite.seed_user(USER_ID).await;
+    let token = create_token(&suite, &["users:read"]).await;
+
+    let (_, tokens) = suite
+        .send(USER_ID, TestRequest::get().uri(&format!("/users/{USER_ID}/tokens")), None)
+        .await;
+    let token_id = tokens.unwrap()["tokens"][0]["tokenId"].as_str().unwrap().to_owned();
+
+    let (status, _) = suite
+        .send(
+            USER_ID,
+            TestRequest::delete().uri(&format!("/users/{USER_ID}/tokens/{token_id}")),
+            None,
+        )
+        .await;
+    check!(status == 204);
+
+    let (status, _) = send_with_token(&suite, &token, TestRequest::get().uri(&format!("/users/{USER_ID}")), None).await;
+    check!(status == 401);
+}
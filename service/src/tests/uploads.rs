@@ -0,0 +1,75 @@
+mod avatars;
+mod world_uploads;
+
+use actix_web::test::TestRequest;
+use image::{DynamicImage, ImageOutputFormat, RgbImage};
+
+use crate::{service::testing::TestResponse, tests::suite::TestSuite};
+
+/// The boundary between the parts of the multipart forms built by the tests.
+const BOUNDARY: &str = "worlds-test-boundary";
+
+/// A single part of a multipart form.
+pub struct Part<'a> {
+    pub name:     &'a str,
+    pub filename: Option<&'a str>,
+    pub data:     &'a [u8],
+}
+
+/// Build the `Content-Type` and body of a `multipart/form-data` request.
+///
+/// # Parameters
+/// - `parts` - The parts of the form
+pub fn multipart(parts: &[Part]) -> (String, Vec<u8>) {
+    let mut body = vec![];
+
+    for part in parts {
+        body.extend_from_slice(format!("--{}\r\n", BOUNDARY).as_bytes());
+        match part.filename {
+            Some(filename) => body.extend_from_slice(
+                format!(
+                    "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: application/octet-stream\r\n\r\n",
+                    part.name, filename
+                )
+                .as_bytes(),
+            ),
+            None => body.extend_from_slice(format!("Content-Disposition: form-data; name=\"{}\"\r\n\r\n", part.name).as_bytes()),
+        }
+        body.extend_from_slice(part.data);
+        body.extend_from_slice(b"\r\n");
+    }
+    body.extend_from_slice(format!("--{}--\r\n", BOUNDARY).as_bytes());
+
+    (format!("multipart/form-data; boundary={}", BOUNDARY), body)
+}
+
+/// Generate a PNG image of the given size.
+pub fn png(width: u32, height: u32) -> Vec<u8> {
+    let mut data = vec![];
+    DynamicImage::ImageRgb8(RgbImage::new(width, height))
+        .write_to(&mut data, ImageOutputFormat::Png)
+        .unwrap();
+
+    data
+}
+
+/// Send a `multipart/form-data` request as a user.
+///
+/// # Parameters
+/// - `suite` - The test suite to send the request to
+/// - `user_id` - The ID of the user to send the request as
+/// - `request` - The request to send
+/// - `parts` - The parts of the form to send as the body of the request
+pub async fn send_multipart(suite: &TestSuite, user_id: &str, request: TestRequest, parts: &[Part<'_>]) -> TestResponse {
+    let (content_type, body) = multipart(parts);
+
+    suite
+        .inject(
+            request
+                .append_header(suite.authenticate(user_id).await)
+                .append_header(("content-type", content_type))
+                .set_payload(body)
+                .to_request(),
+        )
+        .await
+}
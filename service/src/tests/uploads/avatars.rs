@@ -0,0 +1,121 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use insta::assert_json_snapshot;
+
+use super::{png, send_multipart, Part};
+use crate::tests::suite::TestSuite;
+
+const USER_ID: &str = "4ea96dc3-df11-43c0-8a33-a0813f03937f";
+
+/// Build a test suite with a user whose avatar has been set.
+///
+/// # Returns
+/// The test suite, the URL of the avatar and the image that it was set to.
+async fn build_avatar() -> (TestSuite, String, Vec<u8>) {
+    let suite = TestSuite::new().await;
+    suite.seed_user(USER_ID).await;
+    let avatar = format!("/users/{USER_ID}/avatar");
+
+    let image = png(64, 64);
+    let response = send_multipart(
+        &suite,
+        USER_ID,
+        TestRequest::put().uri(&avatar),
+        &[Part {
+            name:     "file",
+            filename: Some("me.png"),
+            data:     &image,
+        }],
+    )
+    .await;
+    check!(response.status == 200);
+
+    (suite, avatar, image)
+}
+
+#[actix_rt::test]
+async fn set_avatar() {
+    let suite = TestSuite::new().await;
+    suite.seed_user(USER_ID).await;
+
+    let response = send_multipart(
+        &suite,
+        USER_ID,
+        TestRequest::put().uri(&format!("/users/{USER_ID}/avatar")),
+        &[Part {
+            name:     "file",
+            filename: Some("me.png"),
+            data:     &png(64, 64),
+        }],
+    )
+    .await;
+
+    check!(response.status == 200);
+    assert_json_snapshot!(response.to_json().unwrap(), {
+        ".uploadId" => "[upload_id]",
+        ".created" => "[created]",
+    }, @r###"
+    {
+      "uploadId": "[upload_id]",
+      "purpose": "avatar",
+      "filename": "me.png",
+      "contentType": "image/png",
+      "size": 143,
+      "checksum": "70d40824fee37e16ee60ffbe177bc68dfaf24137a6d8ddb83f8ea0f6ca4ea573",
+      "width": 64,
+      "height": 64,
+      "entityId": null,
+      "hasThumbnail": true,
+      "created": "[created]"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn set_avatar_not_image() {
+    let suite = TestSuite::new().await;
+    suite.seed_user(USER_ID).await;
+
+    let response = send_multipart(
+        &suite,
+        USER_ID,
+        TestRequest::put().uri(&format!("/users/{USER_ID}/avatar")),
+        &[Part {
+            name:     "file",
+            filename: Some("notes.txt"),
+            data:     b"Not an image",
+        }],
+    )
+    .await;
+
+    check!(response.status == 415);
+    assert_json_snapshot!(response.to_json().unwrap(), @r###"
+    {
+      "type": "tag:worlds,2021:problems/uploads/unsupported_type",
+      "title": "The type of file is not supported",
+      "status": 415,
+      "contentType": "text/plain"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn get_avatar_unauthenticated() {
+    let (suite, avatar, image) = build_avatar().await;
+
+    let response = suite.inject(TestRequest::get().uri(&avatar).to_request()).await;
+
+    check!(response.status == 200);
+    check!(response.body.as_ref() == image.as_slice());
+}
+
+#[actix_rt::test]
+async fn remove_avatar() {
+    let (suite, avatar, _) = build_avatar().await;
+
+    let (status, _) = suite.send(USER_ID, TestRequest::delete().uri(&avatar), None).await;
+    check!(status == 204);
+
+    let response = suite.inject(TestRequest::get().uri(&avatar).to_request()).await;
+    check!(response.status == 404);
+}
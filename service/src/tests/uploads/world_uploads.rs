@@ -0,0 +1,167 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use insta::assert_json_snapshot;
+
+use super::{png, send_multipart, Part};
+use crate::{
+    service::testing::TestResponse,
+    tests::suite::{field, TestSuite},
+};
+
+const USER_ID: &str = "4ea96dc3-df11-43c0-8a33-a0813f03937f";
+
+/// Upload a 512x256 map image to a world.
+///
+/// # Returns
+/// The response to uploading it, and the image that was uploaded.
+async fn upload_map(suite: &TestSuite, world_id: &str) -> (TestResponse, Vec<u8>) {
+    let image = png(512, 256);
+    let response = send_multipart(
+        suite,
+        USER_ID,
+        TestRequest::post().uri(&format!("/worlds/{world_id}/uploads")),
+        &[
+            Part {
+                name:     "purpose",
+                filename: None,
+                data:     b"map",
+            },
+            Part {
+                name:     "file",
+                filename: Some("middle-earth.png"),
+                data:     &image,
+            },
+        ],
+    )
+    .await;
+
+    (response, image)
+}
+
+/// Build a test suite with a world that a map image has been uploaded to.
+///
+/// # Returns
+/// The test suite, the URL of the upload and the image that was uploaded.
+async fn build_upload() -> (TestSuite, String, Vec<u8>) {
+    let suite = TestSuite::new().await;
+    let world_id = suite.seed_world(USER_ID).await;
+
+    let (response, image) = upload_map(&suite, &world_id).await;
+    check!(response.status == 201);
+    let upload_id = field(response.to_json().ok().as_ref(), "uploadId");
+
+    (suite, format!("/worlds/{world_id}/uploads/{upload_id}"), image)
+}
+
+/// Download the content of an upload.
+///
+/// # Parameters
+/// - `suite` - The test suite to download from
+/// - `url` - The URL of the content
+/// - `header` - An extra header to send with the request, if any
+async fn download(suite: &TestSuite, url: &str, header: Option<(&str, String)>) -> TestResponse {
+    let mut request = TestRequest::get().uri(url).append_header(suite.authenticate(USER_ID).await);
+    if let Some(header) = header {
+        request = request.append_header(header);
+    }
+
+    suite.inject(request.to_request()).await
+}
+
+#[actix_rt::test]
+async fn upload_image() {
+    let suite = TestSuite::new().await;
+    let world_id = suite.seed_world(USER_ID).await;
+
+    let (response, _) = upload_map(&suite, &world_id).await;
+
+    check!(response.status == 201);
+    assert_json_snapshot!(response.to_json().unwrap(), {
+        ".uploadId" => "[upload_id]",
+        ".created" => "[created]",
+    }, @r###"
+    {
+      "uploadId": "[upload_id]",
+      "purpose": "map",
+      "filename": "middle-earth.png",
+      "contentType": "image/png",
+      "size": 943,
+      "checksum": "55036f51ac69cfd273cdc15e027f54d038e29d3e05b239acdb2f4e2b4c448aa0",
+      "width": 512,
+      "height": 256,
+      "entityId": null,
+      "hasThumbnail": true,
+      "created": "[created]"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn download_content() {
+    let (suite, upload_url, image) = build_upload().await;
+
+    let response = download(&suite, &format!("{upload_url}/content"), None).await;
+
+    check!(response.status == 200);
+    check!(response.header("content-type").unwrap() == "image/png");
+    check!(response.header("accept-ranges").unwrap() == "bytes");
+    check!(response.body.as_ref() == image.as_slice());
+}
+
+#[actix_rt::test]
+async fn download_unmodified_content() {
+    let (suite, upload_url, _) = build_upload().await;
+    let response = download(&suite, &format!("{upload_url}/content"), None).await;
+    let etag = response.header("etag").unwrap().to_str().unwrap().to_owned();
+
+    let response = download(&suite, &format!("{upload_url}/content"), Some(("if-none-match", etag))).await;
+
+    check!(response.status == 304);
+}
+
+#[actix_rt::test]
+async fn download_range() {
+    let (suite, upload_url, image) = build_upload().await;
+
+    let response = download(&suite, &format!("{upload_url}/content"), Some(("range", "bytes=0-7".to_owned()))).await;
+
+    check!(response.status == 206);
+    check!(response.header("content-range").unwrap().to_str().unwrap() == format!("bytes 0-7/{}", image.len()));
+    check!(response.body.as_ref() == &image[0..8]);
+}
+
+#[actix_rt::test]
+async fn download_unsatisfiable_range() {
+    let (suite, upload_url, image) = build_upload().await;
+
+    let response = download(
+        &suite,
+        &format!("{upload_url}/content"),
+        Some(("range", format!("bytes={}-", image.len()))),
+    )
+    .await;
+
+    check!(response.status == 416);
+    check!(response.header("content-range").unwrap().to_str().unwrap() == format!("bytes */{}", image.len()));
+}
+
+#[actix_rt::test]
+async fn download_thumbnail() {
+    let (suite, upload_url, _) = build_upload().await;
+
+    let response = download(&suite, &format!("{upload_url}/thumbnail"), None).await;
+
+    check!(response.status == 200);
+    check!(response.header("content-type").unwrap() == "image/png");
+}
+
+#[actix_rt::test]
+async fn delete_upload() {
+    let (suite, upload_url, _) = build_upload().await;
+
+    let (status, _) = suite.send(USER_ID, TestRequest::delete().uri(&upload_url), None).await;
+    check!(status == 204);
+
+    let response = download(&suite, &format!("{upload_url}/content"), None).await;
+    check!(response.status == 404);
+}
@@ -1,2 +1,3 @@
 mod get_user;
 mod patch_user;
+mod revisions;
@@ -116,12 +116,18 @@ async fn valid_user_id_authenticated_wrong_user() {
 
     let suite = TestSuite::new().await;
     suite.seed(&user).await;
+    suite
+        .seed(&SeedUser {
+            user_id: "37f35c28-1c26-465d-9a45-b87e59a9760a".parse().unwrap(),
+            ..SeedUser::default()
+        })
+        .await;
 
     let response = suite
         .inject(
             TestRequest::get()
                 .uri("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f")
-                .append_header(suite.authenticate("37f35c28-1c26-465d-9a45-b87e59a9760a"))
+                .append_header(suite.authenticate("37f35c28-1c26-465d-9a45-b87e59a9760a").await)
                 .to_request(),
         )
         .await;
@@ -159,7 +165,7 @@ async fn valid_user_id_authenticated_same_user() {
         .inject(
             TestRequest::get()
                 .uri("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f")
-                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f"))
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
                 .to_request(),
         )
         .await;
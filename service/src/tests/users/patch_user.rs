@@ -102,12 +102,18 @@ async fn valid_user_id_wrong_user() {
 
     let suite = TestSuite::new().await;
     suite.seed(&user).await;
+    suite
+        .seed(&SeedUser {
+            user_id: "37f35c28-1c26-465d-9a45-b87e59a9760a".parse().unwrap(),
+            ..SeedUser::default()
+        })
+        .await;
 
     let response = suite
         .inject(
             TestRequest::patch()
                 .uri("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f")
-                .append_header(suite.authenticate("37f35c28-1c26-465d-9a45-b87e59a9760a"))
+                .append_header(suite.authenticate("37f35c28-1c26-465d-9a45-b87e59a9760a").await)
                 .set_json(&json!({}))
                 .to_request(),
         )
@@ -134,34 +140,40 @@ async fn unknown_user() {
         .inject(
             TestRequest::patch()
                 .uri("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f")
-                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f"))
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
                 .set_json(&json!({}))
                 .to_request(),
         )
         .await;
 
-    check!(response.status == 404);
+    check!(response.status == 401);
 
     check!(response.headers.get("content-type").unwrap() == "application/problem+json");
 
     assert_json_snapshot!(response.to_json().unwrap(), @r###"
         {
           "type": "about:blank",
-          "title": "The requested resource was not found",
-          "status": 404
+          "title": "Unauthorized",
+          "status": 401
         }
         "###);
 }
 
 #[actix_rt::test]
 async fn invalid_email() {
+    let user = SeedUser {
+        user_id: "4ea96dc3-df11-43c0-8a33-a0813f03937f".parse().unwrap(),
+        ..SeedUser::default()
+    };
+
     let suite = TestSuite::new().await;
+    suite.seed(&user).await;
 
     let response = suite
         .inject(
             TestRequest::patch()
                 .uri("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f")
-                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f"))
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
                 .set_json(&json!({
                     "email": "invalid"
                 }))
@@ -191,13 +203,19 @@ async fn invalid_email() {
 
 #[actix_rt::test]
 async fn missing_old_password() {
+    let user = SeedUser {
+        user_id: "4ea96dc3-df11-43c0-8a33-a0813f03937f".parse().unwrap(),
+        ..SeedUser::default()
+    };
+
     let suite = TestSuite::new().await;
+    suite.seed(&user).await;
 
     let response = suite
         .inject(
             TestRequest::patch()
                 .uri("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f")
-                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f"))
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
                 .set_json(&json!({
                     "password": "Pa55word"
                 }))
@@ -243,7 +261,7 @@ async fn change_email_and_name() {
         .inject(
             TestRequest::patch()
                 .uri("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f")
-                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f"))
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
                 .set_json(&json!({
                     "email": "new@example.com",
                     "displayName": "New User",
@@ -286,7 +304,7 @@ async fn change_email_and_name_refetch() {
         .inject(
             TestRequest::patch()
                 .uri("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f")
-                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f"))
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
                 .set_json(&json!({
                     "email": "new@example.com",
                     "displayName": "New User",
@@ -301,7 +319,7 @@ async fn change_email_and_name_refetch() {
         .inject(
             TestRequest::get()
                 .uri("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f")
-                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f"))
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
                 .to_request(),
         )
         .await;
@@ -339,7 +357,7 @@ async fn change_password() {
         .inject(
             TestRequest::patch()
                 .uri("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f")
-                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f"))
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
                 .set_json(&json!({
                     "oldPassword": "Pa55word",
                     "password": r#"SuperSecret\u{2603}"#
@@ -378,6 +396,55 @@ async fn change_password() {
     check!(response.status == 200);
 }
 
+#[actix_rt::test]
+async fn weak_new_password() {
+    let user = SeedUser {
+        user_id: "4ea96dc3-df11-43c0-8a33-a0813f03937f".parse().unwrap(),
+        version: "d61dac0c-45f2-49ed-85cc-f24bbe939404".parse().unwrap(),
+        username: "testuser".to_owned(),
+        email: "testuser@example.com".to_owned(),
+        display_name: "Test User".to_owned(),
+        ..SeedUser::default()
+    }
+    .with_password(r#"SuperSecret\u{2603}"#);
+
+    let suite = TestSuite::new().await;
+    suite.seed(&user).await;
+
+    let response = suite
+        .inject(
+            TestRequest::patch()
+                .uri("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f")
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
+                .set_json(&json!({
+                    "oldPassword": r#"SuperSecret\u{2603}"#,
+                    "password": "Pa55word"
+                }))
+                .to_request(),
+        )
+        .await;
+
+    check!(response.status == 422);
+
+    check!(response.headers.get("content-type").unwrap() == "application/problem+json");
+
+    assert_json_snapshot!(response.to_json().unwrap(), @r###"
+    {
+      "type": "tag:worlds,2021:problems/validation",
+      "title": "Request body failed validation",
+      "status": 422,
+      "validationErrors": [
+        {
+          "code": "password_too_weak",
+          "title": "Password is too easy to guess",
+          "path": "/password",
+          "detail": "This is similar to a commonly used password."
+        }
+      ]
+    }
+    "###);
+}
+
 #[actix_rt::test]
 async fn incorrect_old_password() {
     let user = SeedUser {
@@ -397,7 +464,7 @@ async fn incorrect_old_password() {
         .inject(
             TestRequest::patch()
                 .uri("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f")
-                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f"))
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
                 .set_json(&json!({
                     "oldPassword": "wrong",
                     "password": r#"SuperSecret\u{2603}"#
@@ -418,3 +485,78 @@ async fn incorrect_old_password() {
     }
     "###);
 }
+
+#[actix_rt::test]
+async fn json_patch_display_name() {
+    let user = SeedUser {
+        user_id: "4ea96dc3-df11-43c0-8a33-a0813f03937f".parse().unwrap(),
+        email: "testuser@example.com".to_owned(),
+        display_name: "Test User".to_owned(),
+        ..SeedUser::default()
+    };
+
+    let suite = TestSuite::new().await;
+    suite.seed(&user).await;
+
+    let response = suite
+        .inject(
+            TestRequest::patch()
+                .uri("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f")
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
+                .insert_header(("content-type", "application/json-patch+json"))
+                .set_payload(
+                    json!([
+                        { "op": "test", "path": "/displayName", "value": "Test User" },
+                        { "op": "replace", "path": "/displayName", "value": "New User" }
+                    ])
+                    .to_string(),
+                )
+                .to_request(),
+        )
+        .await;
+
+    check!(response.status == 200);
+
+    let body = response.to_json().unwrap();
+    check!(body.get("displayName").unwrap() == "New User");
+    check!(body.get("email").unwrap() == "testuser@example.com");
+}
+
+#[actix_rt::test]
+async fn clear_display_name() {
+    let user = SeedUser {
+        user_id: "4ea96dc3-df11-43c0-8a33-a0813f03937f".parse().unwrap(),
+        ..SeedUser::default()
+    };
+
+    let suite = TestSuite::new().await;
+    suite.seed(&user).await;
+
+    let response = suite
+        .inject(
+            TestRequest::patch()
+                .uri("/users/4ea96dc3-df11-43c0-8a33-a0813f03937f")
+                .append_header(suite.authenticate("4ea96dc3-df11-43c0-8a33-a0813f03937f").await)
+                .insert_header(("content-type", "application/merge-patch+json"))
+                .set_payload(json!({ "displayName": null }).to_string())
+                .to_request(),
+        )
+        .await;
+
+    check!(response.status == 422);
+
+    assert_json_snapshot!(response.to_json().unwrap(), @r###"
+    {
+      "type": "tag:worlds,2021:problems/validation",
+      "title": "Request body failed validation",
+      "status": 422,
+      "validationErrors": [
+        {
+          "code": "required",
+          "title": "This property is required",
+          "path": "/displayName"
+        }
+      ]
+    }
+    "###);
+}
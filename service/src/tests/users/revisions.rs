@@ -0,0 +1,174 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use insta::assert_json_snapshot;
+use serde_json::json;
+
+use crate::tests::suite::TestSuite;
+
+const USER_ID: &str = "4ea96dc3-df11-43c0-8a33-a0813f03937f";
+const OTHER_USER_ID: &str = "37f35c28-1c26-465d-9a45-b87e59a9760a";
+
+/// Build a test suite with a user whose display name has been changed twice.
+///
+/// # Returns
+/// The test suite, and the versions of the user's revisions, newest first.
+async fn build_revisions() -> (TestSuite, Vec<String>) {
+    let suite = TestSuite::new().await;
+    suite.seed_user(USER_ID).await;
+
+    for display_name in ["First Name", "Second Name"] {
+        let (status, _) = suite
+            .send(
+                USER_ID,
+                TestRequest::patch().uri(&format!("/users/{USER_ID}")),
+                Some(json!({ "displayName": display_name })),
+            )
+            .await;
+        check!(status == 200);
+    }
+
+    let (_, revisions) = suite
+        .send(USER_ID, TestRequest::get().uri(&format!("/users/{USER_ID}/revisions")), None)
+        .await;
+    let versions = revisions.unwrap()["revisions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|revision| revision["version"].as_str().unwrap().to_owned())
+        .collect();
+
+    (suite, versions)
+}
+
+#[actix_rt::test]
+async fn list_revisions() {
+    let (suite, _) = build_revisions().await;
+
+    let (status, revisions) = suite
+        .send(USER_ID, TestRequest::get().uri(&format!("/users/{USER_ID}/revisions")), None)
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(revisions.unwrap(), {
+        ".revisions[].version" => "[version]",
+        ".revisions[].changedAt" => "[changed_at]",
+    }, @r###"
+    {
+      "revisions": [
+        {
+          "version": "[version]",
+          "changedBy": "4ea96dc3-df11-43c0-8a33-a0813f03937f",
+          "changedAt": "[changed_at]"
+        },
+        {
+          "version": "[version]",
+          "changedBy": "4ea96dc3-df11-43c0-8a33-a0813f03937f",
+          "changedAt": "[changed_at]"
+        }
+      ]
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn diff_revision() {
+    let (suite, versions) = build_revisions().await;
+
+    let (status, diff) = suite
+        .send(
+            USER_ID,
+            TestRequest::get().uri(&format!("/users/{USER_ID}/revisions/{}/diff", versions[0])),
+            None,
+        )
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(diff.unwrap(), {
+        ".version" => "[version]",
+    }, @r###"
+    {
+      "version": "[version]",
+      "from": null,
+      "fields": [
+        {
+          "field": "displayName",
+          "lines": [
+            {
+              "change": "delete",
+              "text": "First Name"
+            },
+            {
+              "change": "insert",
+              "text": "Second Name"
+            }
+          ]
+        }
+      ]
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn restore_revision() {
+    let (suite, versions) = build_revisions().await;
+
+    let (status, user) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/users/{USER_ID}/revisions/{}/restore", versions[1])),
+            None,
+        )
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(user.unwrap(), {
+        ".username" => "[username]",
+        ".email" => "[email]",
+    }, @r###"
+    {
+      "userId": "4ea96dc3-df11-43c0-8a33-a0813f03937f",
+      "username": "[username]",
+      "email": "[email]",
+      "displayName": "First Name"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn restore_records_revision() {
+    let (suite, versions) = build_revisions().await;
+    suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/users/{USER_ID}/revisions/{}/restore", versions[1])),
+            None,
+        )
+        .await;
+
+    let (status, revisions) = suite
+        .send(USER_ID, TestRequest::get().uri(&format!("/users/{USER_ID}/revisions")), None)
+        .await;
+
+    check!(status == 200);
+    check!(revisions.unwrap()["revisions"].as_array().unwrap().len() == 3);
+}
+
+#[actix_rt::test]
+async fn other_user() {
+    let suite = TestSuite::new().await;
+    suite.seed_user(USER_ID).await;
+    suite.seed_user(OTHER_USER_ID).await;
+
+    let (status, problem) = suite
+        .send(OTHER_USER_ID, TestRequest::get().uri(&format!("/users/{USER_ID}/revisions")), None)
+        .await;
+
+    check!(status == 403);
+    assert_json_snapshot!(problem.unwrap(), @r###"
+    {
+      "type": "about:blank",
+      "title": "Forbidden",
+      "status": 403
+    }
+    "###);
+}
@@ -0,0 +1,2 @@
+mod manage_webhooks;
+mod queue_deliveries;
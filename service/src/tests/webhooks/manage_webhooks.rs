@@ -0,0 +1,192 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use insta::assert_json_snapshot;
+use serde_json::json;
+
+use crate::tests::suite::{field, TestSuite};
+
+const USER_ID: &str = "4ea96dc3-df11-43c0-8a33-a0813f03937f";
+
+/// Build a test suite with a world that a webhook has been created for.
+///
+/// # Returns
+/// The test suite, the ID of the world and the URL of the webhook.
+async fn build_webhook() -> (TestSuite, String, String) {
+    let suite = TestSuite::new().await;
+    let world_id = suite.seed_world(USER_ID).await;
+
+    let (status, webhook) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/webhooks")),
+            Some(json!({"url": "https://example.com/hook", "events": ["article.*", "*.deleted"]})),
+        )
+        .await;
+    check!(status == 201);
+    let webhook_url = format!("/worlds/{world_id}/webhooks/{}", field(webhook.as_ref(), "webhookId"));
+
+    (suite, world_id, webhook_url)
+}
+
+#[actix_rt::test]
+async fn create_webhook() {
+    let suite = TestSuite::new().await;
+    let world_id = suite.seed_world(USER_ID).await;
+
+    let (status, webhook) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/webhooks")),
+            Some(json!({"url": "https://example.com/hook", "events": ["article.*", "*.deleted"]})),
+        )
+        .await;
+
+    check!(status == 201);
+    check!(field(webhook.as_ref(), "secret").starts_with("whsec_"));
+    assert_json_snapshot!(webhook.unwrap(), {
+        ".webhookId" => "[webhook_id]",
+        ".secret" => "[secret]",
+        ".created" => "[created]",
+        ".updated" => "[updated]",
+    }, @r###"
+    {
+      "webhookId": "[webhook_id]",
+      "url": "https://example.com/hook",
+      "events": [
+        "article.*",
+        "*.deleted"
+      ],
+      "active": true,
+      "createdBy": "4ea96dc3-df11-43c0-8a33-a0813f03937f",
+      "created": "[created]",
+      "updated": "[updated]",
+      "secret": "[secret]"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn deactivate_webhook() {
+    let (suite, _, webhook_url) = build_webhook().await;
+
+    let (status, webhook) = suite
+        .send(USER_ID, TestRequest::patch().uri(&webhook_url), Some(json!({"active": false})))
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(webhook.unwrap(), {
+        ".webhookId" => "[webhook_id]",
+        ".created" => "[created]",
+        ".updated" => "[updated]",
+    }, @r###"
+    {
+      "webhookId": "[webhook_id]",
+      "url": "https://example.com/hook",
+      "events": [
+        "article.*",
+        "*.deleted"
+      ],
+      "active": false,
+      "createdBy": "4ea96dc3-df11-43c0-8a33-a0813f03937f",
+      "created": "[created]",
+      "updated": "[updated]"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn list_webhooks() {
+    let (suite, world_id, _) = build_webhook().await;
+
+    let (status, webhooks) = suite
+        .send(USER_ID, TestRequest::get().uri(&format!("/worlds/{world_id}/webhooks")), None)
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(webhooks.unwrap(), {
+        ".webhooks[].webhookId" => "[webhook_id]",
+        ".webhooks[].created" => "[created]",
+        ".webhooks[].updated" => "[updated]",
+    }, @r###"
+    {
+      "webhooks": [
+        {
+          "webhookId": "[webhook_id]",
+          "url": "https://example.com/hook",
+          "events": [
+            "article.*",
+            "*.deleted"
+          ],
+          "active": true,
+          "createdBy": "4ea96dc3-df11-43c0-8a33-a0813f03937f",
+          "created": "[created]",
+          "updated": "[updated]"
+        }
+      ]
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn delete_webhook() {
+    let (suite, _, webhook_url) = build_webhook().await;
+
+    let (status, _) = suite.send(USER_ID, TestRequest::delete().uri(&webhook_url), None).await;
+    check!(status == 204);
+
+    let (status, problem) = suite.send(USER_ID, TestRequest::get().uri(&webhook_url), None).await;
+    check!(status == 404);
+    assert_json_snapshot!(problem.unwrap(), @r###"
+    {
+      "type": "about:blank",
+      "title": "The requested resource was not found",
+      "status": 404
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn create_webhook_invalid_url() {
+    let suite = TestSuite::new().await;
+    let world_id = suite.seed_world(USER_ID).await;
+
+    let (status, problem) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/webhooks")),
+            Some(json!({"url": "ftp://example.com/hook", "events": ["article.created"]})),
+        )
+        .await;
+
+    check!(status == 422);
+    assert_json_snapshot!(problem.unwrap(), @r###"
+    {
+      "type": "tag:worlds,2021:problems/webhooks/invalid_url",
+      "title": "Invalid Webhook URL",
+      "status": 422
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn create_webhook_private_url() {
+    let suite = TestSuite::new().await;
+    let world_id = suite.seed_world(USER_ID).await;
+
+    let (status, problem) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/webhooks")),
+            Some(json!({"url": "http://169.254.169.254/latest/meta-data", "events": ["article.created"]})),
+        )
+        .await;
+
+    check!(status == 422);
+    assert_json_snapshot!(problem.unwrap(), @r###"
+    {
+      "type": "tag:worlds,2021:problems/webhooks/invalid_url",
+      "title": "Invalid Webhook URL",
+      "status": 422
+    }
+    "###);
+}
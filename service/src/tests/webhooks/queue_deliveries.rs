@@ -0,0 +1,167 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use insta::assert_json_snapshot;
+use serde_json::json;
+
+use crate::tests::suite::{field, TestSuite};
+
+const USER_ID: &str = "4ea96dc3-df11-43c0-8a33-a0813f03937f";
+
+/// Build a test suite with a webhook for created articles, and an article that was created and then
+/// deleted.
+///
+/// # Returns
+/// The test suite, the URL of the webhook and the ID of the article.
+async fn build_deliveries() -> (TestSuite, String, String) {
+    let suite = TestSuite::new().await;
+    let world_id = suite.seed_world(USER_ID).await;
+
+    let (_, webhook) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/webhooks")),
+            Some(json!({"url": "https://example.com/hook", "events": ["article.created"]})),
+        )
+        .await;
+    let webhook_url = format!("/worlds/{world_id}/webhooks/{}", field(webhook.as_ref(), "webhookId"));
+
+    let (_, article) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/articles")),
+            Some(json!({"title": "Gondor", "body": "A kingdom of men."})),
+        )
+        .await;
+    let article_id = field(article.as_ref(), "articleId");
+
+    let (status, _) = suite
+        .send(
+            USER_ID,
+            TestRequest::delete().uri(&format!("/worlds/{world_id}/articles/{article_id}")),
+            None,
+        )
+        .await;
+    check!(status == 204);
+
+    (suite, webhook_url, article_id)
+}
+
+/// Find the ID of the only delivery queued for a webhook.
+async fn delivery_id(suite: &TestSuite, webhook_url: &str) -> i64 {
+    let (_, deliveries) = suite
+        .send(USER_ID, TestRequest::get().uri(&format!("{webhook_url}/deliveries")), None)
+        .await;
+
+    deliveries.unwrap()["deliveries"][0]["deliveryId"].as_i64().unwrap()
+}
+
+#[actix_rt::test]
+async fn list_deliveries() {
+    let (suite, webhook_url, _) = build_deliveries().await;
+
+    let (status, deliveries) = suite
+        .send(USER_ID, TestRequest::get().uri(&format!("{webhook_url}/deliveries")), None)
+        .await;
+
+    // Changes that the webhook isn't interested in aren't queued for it.
+    check!(status == 200);
+    assert_json_snapshot!(deliveries.unwrap(), {
+        ".deliveries[].deliveryId" => "[delivery_id]",
+        ".deliveries[].created" => "[created]",
+        ".deliveries[].nextAttempt" => "[next_attempt]",
+    }, @r###"
+    {
+      "deliveries": [
+        {
+          "deliveryId": "[delivery_id]",
+          "event": "article.created",
+          "status": "pending",
+          "attempts": 0,
+          "nextAttempt": "[next_attempt]",
+          "created": "[created]"
+        }
+      ],
+      "page": {
+        "limit": 20,
+        "next": null
+      }
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn get_delivery() {
+    let (suite, webhook_url, article_id) = build_deliveries().await;
+    let delivery_id = delivery_id(&suite, &webhook_url).await;
+
+    let (status, delivery) = suite
+        .send(
+            USER_ID,
+            TestRequest::get().uri(&format!("{webhook_url}/deliveries/{delivery_id}")),
+            None,
+        )
+        .await;
+
+    check!(status == 200);
+    check!(delivery.as_ref().unwrap()["payload"]["resourceId"] == article_id.as_str());
+    assert_json_snapshot!(delivery.unwrap(), {
+        ".deliveryId" => "[delivery_id]",
+        ".created" => "[created]",
+        ".nextAttempt" => "[next_attempt]",
+        ".payload.worldId" => "[world_id]",
+        ".payload.resourceId" => "[article_id]",
+        ".payload.changeId" => "[change_id]",
+        ".payload.occurred" => "[occurred]",
+        ".payload.version" => "[version]",
+    }, @r###"
+    {
+      "deliveryId": "[delivery_id]",
+      "event": "article.created",
+      "status": "pending",
+      "attempts": 0,
+      "nextAttempt": "[next_attempt]",
+      "created": "[created]",
+      "payload": {
+        "event": "article.created",
+        "action": "created",
+        "version": "[version]",
+        "worldId": "[world_id]",
+        "changeId": "[change_id]",
+        "occurred": "[occurred]",
+        "resource": "article",
+        "resourceId": "[article_id]"
+      },
+      "attemptLog": []
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn redeliver() {
+    let (suite, webhook_url, _) = build_deliveries().await;
+    let delivery_id = delivery_id(&suite, &webhook_url).await;
+
+    let (status, delivery) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri(&format!("{webhook_url}/deliveries/{delivery_id}/redeliver")),
+            None,
+        )
+        .await;
+
+    check!(status == 202);
+    assert_json_snapshot!(delivery.unwrap(), {
+        ".deliveryId" => "[delivery_id]",
+        ".created" => "[created]",
+        ".nextAttempt" => "[next_attempt]",
+    }, @r###"
+    {
+      "deliveryId": "[delivery_id]",
+      "event": "article.created",
+      "status": "pending",
+      "attempts": 0,
+      "nextAttempt": "[next_attempt]",
+      "created": "[created]"
+    }
+    "###);
+}
@@ -0,0 +1,3 @@
+mod create_world;
+mod invitations;
+mod members;
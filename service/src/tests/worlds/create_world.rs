@@ -0,0 +1,116 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use insta::assert_json_snapshot;
+use serde_json::json;
+
+use crate::tests::suite::{field, TestSuite};
+
+const USER_ID: &str = "4ea96dc3-df11-43c0-8a33-a0813f03937f";
+const OTHER_USER_ID: &str = "37f35c28-1c26-465d-9a45-b87e59a9760a";
+
+#[actix_rt::test]
+async fn create_world() {
+    let suite = TestSuite::new().await;
+    suite.seed_user(USER_ID).await;
+
+    let (status, world) = suite
+        .send(
+            USER_ID,
+            TestRequest::post().uri("/worlds"),
+            Some(json!({"name": "Middle Earth", "description": "A world of hobbits"})),
+        )
+        .await;
+
+    check!(status == 201);
+    assert_json_snapshot!(world.unwrap(), {
+        ".worldId" => "[world_id]",
+        ".created" => "[created]",
+    }, @r###"
+    {
+      "worldId": "[world_id]",
+      "name": "Middle Earth",
+      "description": "A world of hobbits",
+      "language": "english",
+      "created": "[created]"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn get_world() {
+    let suite = TestSuite::new().await;
+    let world_id = suite.seed_world(USER_ID).await;
+
+    let (status, world) = suite
+        .send(USER_ID, TestRequest::get().uri(&format!("/worlds/{world_id}")), None)
+        .await;
+
+    check!(status == 200);
+    check!(field(world.as_ref(), "worldId") == world_id);
+    assert_json_snapshot!(world.unwrap(), {
+        ".worldId" => "[world_id]",
+        ".created" => "[created]",
+    }, @r###"
+    {
+      "worldId": "[world_id]",
+      "name": "Middle Earth",
+      "description": null,
+      "language": "english",
+      "created": "[created]"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn creator_owns_world() {
+    let suite = TestSuite::new().await;
+    let world_id = suite.seed_world(USER_ID).await;
+
+    let (status, members) = suite
+        .send(USER_ID, TestRequest::get().uri(&format!("/worlds/{world_id}/members")), None)
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(members.unwrap(), {
+        ".members[].joined" => "[joined]",
+    }, @r###"
+    {
+      "members": [
+        {
+          "userId": "4ea96dc3-df11-43c0-8a33-a0813f03937f",
+          "role": "owner",
+          "joined": "[joined]"
+        }
+      ]
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn create_world_unauthenticated() {
+    let suite = TestSuite::new().await;
+
+    let response = suite
+        .inject(
+            TestRequest::post()
+                .uri("/worlds")
+                .set_json(&json!({"name": "Middle Earth"}))
+                .to_request(),
+        )
+        .await;
+
+    check!(response.status == 401);
+}
+
+#[actix_rt::test]
+async fn get_world_not_member() {
+    let suite = TestSuite::new().await;
+    let world_id = suite.seed_world(USER_ID).await;
+    suite.seed_user(OTHER_USER_ID).await;
+
+    let (status, _) = suite
+        .send(OTHER_USER_ID, TestRequest::get().uri(&format!("/worlds/{world_id}")), None)
+        .await;
+
+    check!(status == 404);
+}
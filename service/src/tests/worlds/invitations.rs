@@ -0,0 +1,211 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use insta::assert_json_snapshot;
+use serde_json::{json, Value};
+
+use crate::tests::{
+    database::seed::SeedUser,
+    suite::{field, TestSuite},
+};
+
+const OWNER_ID: &str = "4ea96dc3-df11-43c0-8a33-a0813f03937f";
+const INVITEE_ID: &str = "37f35c28-1c26-465d-9a45-b87e59a9760a";
+
+/// Build a test suite with an owner, an invitee, and a world that the owner has created.
+async fn build_suite() -> (TestSuite, String) {
+    let suite = TestSuite::new().await;
+    let world_id = suite.seed_world(OWNER_ID).await;
+    suite
+        .seed(&SeedUser {
+            user_id: INVITEE_ID.parse().unwrap(),
+            username: "invitee".to_owned(),
+            email: "invitee@example.com".to_owned(),
+            ..SeedUser::default()
+        })
+        .await;
+
+    (suite, world_id)
+}
+
+/// Invite somebody to the world, returning the response body.
+async fn invite(suite: &TestSuite, world_id: &str, invitee: Value) -> Value {
+    let (status, invitation) = suite
+        .send(
+            OWNER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/invitations")),
+            Some(invitee),
+        )
+        .await;
+
+    check!(status == 201);
+    invitation.unwrap()
+}
+
+#[actix_rt::test]
+async fn invite_by_username() {
+    let (suite, world_id) = build_suite().await;
+
+    let invitation = invite(&suite, &world_id, json!({"username": "Invitee", "role": "editor"})).await;
+
+    assert_json_snapshot!(invitation, {
+        ".invitationId" => "[invitation_id]",
+        ".token" => "[token]",
+        ".created" => "[created]",
+        ".expiresAt" => "[expires_at]",
+    }, @r###"
+    {
+      "invitationId": "[invitation_id]",
+      "username": "invitee",
+      "role": "editor",
+      "invitedBy": "4ea96dc3-df11-43c0-8a33-a0813f03937f",
+      "created": "[created]",
+      "expiresAt": "[expires_at]",
+      "token": "[token]"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn accept_invitation_by_username() {
+    let (suite, world_id) = build_suite().await;
+    let invitation = invite(&suite, &world_id, json!({"username": "Invitee", "role": "editor"})).await;
+    let token = field(Some(&invitation), "token");
+
+    let (status, membership) = suite
+        .send(INVITEE_ID, TestRequest::post().uri(&format!("/invitations/{token}/accept")), None)
+        .await;
+
+    check!(status == 200);
+    check!(field(membership.as_ref(), "worldId") == world_id);
+    assert_json_snapshot!(membership.unwrap(), {
+        ".worldId" => "[world_id]",
+    }, @r###"
+    {
+      "worldId": "[world_id]",
+      "role": "editor"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn accepted_invitation_grants_access() {
+    let (suite, world_id) = build_suite().await;
+    let invitation = invite(&suite, &world_id, json!({"username": "invitee", "role": "editor"})).await;
+    let token = field(Some(&invitation), "token");
+    suite
+        .send(INVITEE_ID, TestRequest::post().uri(&format!("/invitations/{token}/accept")), None)
+        .await;
+
+    let (status, _) = suite
+        .send(INVITEE_ID, TestRequest::get().uri(&format!("/worlds/{world_id}")), None)
+        .await;
+
+    check!(status == 200);
+}
+
+#[actix_rt::test]
+async fn accept_invitation_by_email() {
+    let (suite, world_id) = build_suite().await;
+    let invitation = invite(&suite, &world_id, json!({"email": "INVITEE@example.com", "role": "viewer"})).await;
+    let token = field(Some(&invitation), "token");
+
+    let (status, membership) = suite
+        .send(INVITEE_ID, TestRequest::post().uri(&format!("/invitations/{token}/accept")), None)
+        .await;
+
+    check!(status == 200);
+    assert_json_snapshot!(membership.unwrap(), {
+        ".worldId" => "[world_id]",
+    }, @r###"
+    {
+      "worldId": "[world_id]",
+      "role": "viewer"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn accept_invitation_for_other_user() {
+    let (suite, world_id) = build_suite().await;
+    let invitation = invite(&suite, &world_id, json!({"email": "someone@example.com", "role": "viewer"})).await;
+    let token = field(Some(&invitation), "token");
+
+    let (status, problem) = suite
+        .send(INVITEE_ID, TestRequest::post().uri(&format!("/invitations/{token}/accept")), None)
+        .await;
+
+    check!(status == 403);
+    assert_json_snapshot!(problem.unwrap(), @r###"
+    {
+      "type": "tag:worlds,2021:problems/worlds/invitations/other_user",
+      "title": "The invitation is for a different user",
+      "status": 403
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn decline_invitation() {
+    let (suite, world_id) = build_suite().await;
+    let invitation = invite(&suite, &world_id, json!({"username": "invitee", "role": "editor"})).await;
+    let token = field(Some(&invitation), "token");
+
+    let (status, _) = suite
+        .send(INVITEE_ID, TestRequest::post().uri(&format!("/invitations/{token}/decline")), None)
+        .await;
+    check!(status == 204);
+
+    let (status, _) = suite
+        .send(INVITEE_ID, TestRequest::post().uri(&format!("/invitations/{token}/accept")), None)
+        .await;
+    check!(status == 404);
+}
+
+#[actix_rt::test]
+async fn invite_unknown_username() {
+    let (suite, world_id) = build_suite().await;
+
+    let (status, problem) = suite
+        .send(
+            OWNER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/invitations")),
+            Some(json!({"username": "nobody", "role": "editor"})),
+        )
+        .await;
+
+    check!(status == 422);
+    assert_json_snapshot!(problem.unwrap(), @r###"
+    {
+      "type": "tag:worlds,2021:problems/worlds/invitations/unknown_username",
+      "title": "Unknown Username",
+      "status": 422
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn invite_as_non_owner() {
+    let (suite, world_id) = build_suite().await;
+    let invitation = invite(&suite, &world_id, json!({"username": "invitee", "role": "editor"})).await;
+    let token = field(Some(&invitation), "token");
+    suite
+        .send(INVITEE_ID, TestRequest::post().uri(&format!("/invitations/{token}/accept")), None)
+        .await;
+
+    let (status, problem) = suite
+        .send(
+            INVITEE_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/invitations")),
+            Some(json!({"email": "someone@example.com", "role": "editor"})),
+        )
+        .await;
+
+    check!(status == 403);
+    assert_json_snapshot!(problem.unwrap(), @r###"
+    {
+      "type": "about:blank",
+      "title": "Forbidden",
+      "status": 403
+    }
+    "###);
+}
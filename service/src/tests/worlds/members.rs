@@ -0,0 +1,145 @@
+use actix_web::test::TestRequest;
+use assert2::check;
+use insta::assert_json_snapshot;
+use serde_json::{json, Value};
+
+use crate::{
+    http::format::Format,
+    tests::{
+        database::seed::SeedUser,
+        suite::{field, TestSuite},
+    },
+};
+
+const OWNER_ID: &str = "4ea96dc3-df11-43c0-8a33-a0813f03937f";
+const MEMBER_ID: &str = "37f35c28-1c26-465d-9a45-b87e59a9760a";
+
+/// Build a test suite with a world that has an owner and one other member.
+async fn build_suite() -> (TestSuite, String) {
+    let suite = TestSuite::new().await;
+    let world_id = suite.seed_world(OWNER_ID).await;
+    suite
+        .seed(&SeedUser {
+            user_id: MEMBER_ID.parse().unwrap(),
+            username: "member".to_owned(),
+            ..SeedUser::default()
+        })
+        .await;
+
+    let (_, invitation) = suite
+        .send(
+            OWNER_ID,
+            TestRequest::post().uri(&format!("/worlds/{world_id}/invitations")),
+            Some(json!({"username": "member", "role": "commenter"})),
+        )
+        .await;
+    let token = field(invitation.as_ref(), "token");
+
+    let (status, _) = suite
+        .send(MEMBER_ID, TestRequest::post().uri(&format!("/invitations/{token}/accept")), None)
+        .await;
+    check!(status == 200);
+
+    (suite, world_id)
+}
+
+#[actix_rt::test]
+async fn leave_world() {
+    let (suite, world_id) = build_suite().await;
+
+    let (status, _) = suite
+        .send(
+            MEMBER_ID,
+            TestRequest::delete().uri(&format!("/worlds/{world_id}/members/{MEMBER_ID}")),
+            None,
+        )
+        .await;
+    check!(status == 204);
+
+    let (status, _) = suite
+        .send(MEMBER_ID, TestRequest::get().uri(&format!("/worlds/{world_id}")), None)
+        .await;
+    check!(status == 404);
+}
+
+#[actix_rt::test]
+async fn remove_other_member_as_non_owner() {
+    let (suite, world_id) = build_suite().await;
+
+    let (status, problem) = suite
+        .send(
+            MEMBER_ID,
+            TestRequest::delete().uri(&format!("/worlds/{world_id}/members/{OWNER_ID}")),
+            None,
+        )
+        .await;
+
+    check!(status == 403);
+    assert_json_snapshot!(problem.unwrap(), @r###"
+    {
+      "type": "about:blank",
+      "title": "Forbidden",
+      "status": 403
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn last_owner_leaves() {
+    let (suite, world_id) = build_suite().await;
+
+    let (status, problem) = suite
+        .send(
+            OWNER_ID,
+            TestRequest::delete().uri(&format!("/worlds/{world_id}/members/{OWNER_ID}")),
+            None,
+        )
+        .await;
+
+    check!(status == 409);
+    assert_json_snapshot!(problem.unwrap(), @r###"
+    {
+      "type": "tag:worlds,2021:problems/worlds/members/last_owner",
+      "title": "The last owner of a world can not be removed",
+      "status": 409
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn list_members_as_yaml() {
+    let (suite, world_id) = build_suite().await;
+
+    let response = suite
+        .inject(
+            TestRequest::get()
+                .uri(&format!("/worlds/{world_id}/members"))
+                .append_header(suite.authenticate(OWNER_ID).await)
+                .insert_header(("Accept", "application/yaml"))
+                .to_request(),
+        )
+        .await;
+
+    check!(response.status == 200);
+    check!(response.headers.get("content-type").unwrap() == "application/yaml");
+
+    let members: Value = Format::Yaml.decode(&response.body).unwrap();
+    assert_json_snapshot!(members, {
+        ".members[].joined" => "[joined]",
+    }, @r###"
+    {
+      "members": [
+        {
+          "userId": "4ea96dc3-df11-43c0-8a33-a0813f03937f",
+          "role": "owner",
+          "joined": "[joined]"
+        },
+        {
+          "userId": "37f35c28-1c26-465d-9a45-b87e59a9760a",
+          "role": "commenter",
+          "joined": "[joined]"
+        }
+      ]
+    }
+    "###);
+}
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use actix_web::web::{delete, get, patch, post, resource, ServiceConfig};
+
+use super::{repository::EventRepository, service::TimelineService};
+use crate::{entities::EntityService, server::RouteConfigurer};
+
+/// Component for working with the timelines of worlds.
+pub struct Component {
+    pub service: Arc<TimelineService>,
+}
+
+impl Component {
+    /// Create a new timeline component.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store events in
+    /// - `entities` - The service to check the entities involved in events against
+    pub fn new(repository: Arc<dyn EventRepository>, entities: Arc<EntityService>) -> Arc<Self> {
+        let service = Arc::new(TimelineService::new(repository, entities));
+
+        Arc::new(Self { service })
+    }
+}
+
+impl RouteConfigurer for Component {
+    fn configure_routes(&self, config: &mut ServiceConfig) {
+        config.data(self.service.clone());
+
+        config.service(resource("/worlds/{id}/events").route(post().to(super::endpoints::create_event::handle)));
+        config.service(
+            resource("/worlds/{id}/events/{event}")
+                .route(get().to(super::endpoints::get_event::handle))
+                .route(patch().to(super::endpoints::patch_event::handle))
+                .route(delete().to(super::endpoints::delete_event::handle)),
+        );
+        config.service(resource("/worlds/{id}/timeline").route(get().to(super::endpoints::get_timeline::handle)));
+    }
+}
@@ -0,0 +1,127 @@
+pub(super) mod create_event;
+pub(super) mod delete_event;
+pub(super) mod get_event;
+pub(super) mod get_timeline;
+mod model;
+pub(super) mod patch_event;
+mod problems;
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use self::problems::{CALENDAR_REQUIRED, UNKNOWN_CALENDAR};
+use crate::{
+    calendars::{endpoints::invalid_date, CalendarId, CalendarResource, CalendarService, DateSpec},
+    http::{
+        problem::{Problem, NOT_FOUND},
+        valid::Validatable,
+    },
+    timeline::EventId,
+    worlds::WorldId,
+};
+
+/// A day as it is provided on the HTTP API - either an absolute day, or a date written in terms
+/// of a calendar.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum DayInput {
+    Day(i64),
+    Date(DateSpec),
+}
+
+impl DayInput {
+    /// The JSON Schema for a day on the HTTP API.
+    pub fn schema() -> Value {
+        json!({
+            "oneOf": [
+                {
+                    "type": "integer"
+                },
+                DateSpec::schema()
+            ]
+        })
+    }
+
+    /// Convert the input into an absolute day.
+    ///
+    /// # Parameters
+    /// - `calendar` - The calendar to interpret dates with, if one was provided
+    ///
+    /// # Returns
+    /// The absolute day, or a problem if the date can't be interpreted. An absolute day is only a
+    /// problem if it can't be described in terms of the calendar.
+    fn resolve(&self, calendar: Option<&CalendarResource>) -> Result<i64, Problem> {
+        match self {
+            Self::Day(day) => {
+                if let Some(calendar) = calendar {
+                    calendar.data.definition.format(*day).map_err(|e| invalid_date(&e))?;
+                }
+
+                Ok(*day)
+            },
+            Self::Date(spec) => {
+                let calendar = calendar.ok_or(CALENDAR_REQUIRED)?;
+
+                calendar.data.definition.resolve(spec).map_err(|e| invalid_date(&e))
+            },
+        }
+    }
+}
+
+/// Query parameters for choosing the calendar to describe days with.
+#[derive(Deserialize)]
+pub struct CalendarQuery {
+    pub calendar: Option<String>,
+}
+
+/// Load the calendar that has been requested to interpret or describe days with.
+///
+/// # Parameters
+/// - `service` - The service to load the calendar from
+/// - `world_id` - The ID of the world that the calendar belongs to
+/// - `calendar_id` - The ID of the calendar, if one was requested
+///
+/// # Returns
+/// The calendar if one was requested, or a problem if the requested calendar doesn't exist.
+async fn load_calendar(
+    service: &CalendarService,
+    world_id: &WorldId,
+    calendar_id: Option<&str>,
+) -> Result<Option<CalendarResource>, Problem> {
+    let Some(calendar_id) = calendar_id else {
+        return Ok(None);
+    };
+
+    let unknown_calendar = || Problem::from(UNKNOWN_CALENDAR).with_extra("calendarId", calendar_id);
+
+    let parsed: CalendarId = calendar_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, calendar_id = ?calendar_id, "Failed to parse Calendar ID");
+
+        unknown_calendar()
+    })?;
+
+    service.get_calendar(world_id, &parsed).await.map(Some).ok_or_else(unknown_calendar)
+}
+
+/// Parse the world and event IDs from the URL.
+///
+/// # Parameters
+/// - `world_id` - The ID of the world
+/// - `event_id` - The ID of the event
+///
+/// # Returns
+/// The parsed IDs, or a Not Found problem if either of them isn't valid.
+fn parse_event_path(world_id: &str, event_id: &str) -> Result<(WorldId, EventId), Problem> {
+    let world_id: WorldId = world_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, world_id = ?world_id, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+    let event_id: EventId = event_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, event_id = ?event_id, "Failed to parse Event ID");
+
+        NOT_FOUND
+    })?;
+
+    Ok((world_id, event_id))
+}
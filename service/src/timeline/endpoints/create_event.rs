@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use actix_http::http::StatusCode;
+use actix_web::web::{Data, Path};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{
+    load_calendar,
+    model::{respond, EventResponse},
+    problems::{unknown_entity, INVALID_RANGE},
+    DayInput,
+};
+use crate::{
+    authorization::Authentication,
+    calendars::CalendarService,
+    entities::EntityId,
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+        valid::{Valid, Validatable},
+    },
+    timeline::{CreateEventError, NewEvent, TimelineService},
+    tokens::Scope,
+    worlds::{Role, WorldId, WorldService},
+};
+
+/// Create a new event on the timeline of a world.
+pub async fn handle(
+    service: Data<Arc<TimelineService>>,
+    calendars_service: Data<Arc<CalendarService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<String>,
+    request: Valid<CreateEventRequest>,
+    authentication: Authentication,
+) -> Result<EventResponse, Problem> {
+    let world_id: WorldId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let request = request.unwrap();
+
+    let calendar = load_calendar(&calendars_service, &world_id, request.calendar_id.as_deref()).await?;
+    let starts = request.starts.resolve(calendar.as_ref())?;
+    let ends = request.ends.map(|ends| ends.resolve(calendar.as_ref())).transpose()?;
+
+    let event = service
+        .create_event(
+            &world_id,
+            NewEvent {
+                title: request.title,
+                description: request.description,
+                starts,
+                ends,
+                entity_ids: request.entity_ids,
+            },
+        )
+        .await
+        .map_err(|e| match e {
+            CreateEventError::UnknownEntity(entity_id) => unknown_entity(&entity_id),
+            CreateEventError::InvalidRange => INVALID_RANGE.into(),
+            CreateEventError::UnknownError => INTERNAL_SERVER_ERROR.into(),
+        })?;
+
+    Ok(respond(event, calendar.as_ref().map(|c| &c.data.definition))?
+        .with_status_code(StatusCode::CREATED)
+        .into())
+}
+
+/// The incoming request to create an event.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateEventRequest {
+    pub title:       String,
+    pub description: Option<String>,
+    pub starts:      DayInput,
+    pub ends:        Option<DayInput>,
+    /// The calendar that any dates in the request are written in.
+    pub calendar_id: Option<String>,
+    #[serde(default)]
+    pub entity_ids:  Vec<EntityId>,
+}
+
+impl Validatable for CreateEventRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "title": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 200
+                },
+                "description": {
+                    "type": "string",
+                    "maxLength": 10000
+                },
+                "starts": DayInput::schema(),
+                "ends": DayInput::schema(),
+                "calendarId": {
+                    "type": "string"
+                },
+                "entityIds": {
+                    "type": "array",
+                    "maxItems": 100,
+                    "items": EntityId::schema()
+                }
+            },
+            "required": [
+                "title",
+                "starts"
+            ]
+        })
+    }
+}
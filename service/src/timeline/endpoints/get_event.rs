@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path, Query};
+
+use super::{
+    load_calendar,
+    model::{respond, EventResponse},
+    parse_event_path, CalendarQuery,
+};
+use crate::{
+    authorization::Authentication,
+    calendars::CalendarService,
+    http::problem::{Problem, NOT_FOUND},
+    timeline::TimelineService,
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+pub async fn handle(
+    service: Data<Arc<TimelineService>>,
+    calendars_service: Data<Arc<CalendarService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    query: Query<CalendarQuery>,
+    authentication: Authentication,
+) -> Result<EventResponse, Problem> {
+    let (world_id, event_id) = path.into_inner();
+    let (world_id, event_id) = parse_event_path(&world_id, &event_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let event = service.get_event(&world_id, &event_id).await.ok_or(NOT_FOUND)?;
+    let calendar = load_calendar(&calendars_service, &world_id, query.calendar.as_deref()).await?;
+
+    Ok(respond(event, calendar.as_ref().map(|c| &c.data.definition))?.into())
+}
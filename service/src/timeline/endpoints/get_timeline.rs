@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path, Query};
+use serde::Deserialize;
+
+use super::{
+    load_calendar,
+    model::{EventModel, TimelineModel},
+    problems::CALENDAR_REQUIRED,
+};
+use crate::{
+    authorization::Authentication,
+    calendars::{endpoints::invalid_date, CalendarService},
+    entities::EntityId,
+    http::{
+        problem::{Problem, NOT_FOUND},
+        response::Response,
+    },
+    timeline::{TimelineFilter, TimelineService},
+    tokens::Scope,
+    worlds::{Role, WorldId, WorldService},
+};
+
+/// Query parameters for selecting the events on a timeline.
+#[derive(Deserialize)]
+pub struct TimelineQuery {
+    /// Only include events that are still happening on or after this absolute day.
+    pub from:     Option<i64>,
+    /// Only include events that have started on or before this absolute day.
+    pub to:       Option<i64>,
+    /// Only include events involving this entity.
+    pub entity:   Option<String>,
+    /// The calendar to describe the days of the events with.
+    pub calendar: Option<String>,
+    /// Only include events that happened during this era of the calendar.
+    pub era:      Option<String>,
+}
+
+/// Get the events on the timeline of a world, in chronological order.
+pub async fn handle(
+    service: Data<Arc<TimelineService>>,
+    calendars_service: Data<Arc<CalendarService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<String>,
+    query: Query<TimelineQuery>,
+    authentication: Authentication,
+) -> Result<Response<TimelineModel>, Problem> {
+    let world_id: WorldId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let query = query.into_inner();
+
+    let calendar = load_calendar(&calendars_service, &world_id, query.calendar.as_deref()).await?;
+    let definition = calendar.as_ref().map(|c| &c.data.definition);
+
+    let mut filter = TimelineFilter {
+        from:      query.from,
+        to:        query.to,
+        entity_id: None,
+    };
+
+    if let Some(entity) = &query.entity {
+        let entity_id: EntityId = entity.parse().map_err(|e| {
+            tracing::warn!(e = ?e, entity = ?entity, "Failed to parse Entity ID");
+
+            NOT_FOUND
+        })?;
+        filter.entity_id = Some(entity_id);
+    }
+
+    if let Some(era) = &query.era {
+        let (starts, ends) = definition.ok_or(CALENDAR_REQUIRED)?.era_range(era).map_err(|e| invalid_date(&e))?;
+
+        filter.from = Some(filter.from.map_or(starts, |from| from.max(starts)));
+        filter.to = match (filter.to, ends) {
+            (Some(to), Some(ends)) => Some(to.min(ends)),
+            (to, ends) => to.or(ends),
+        };
+    }
+
+    let events = service
+        .get_events(&world_id, &filter)
+        .await
+        .into_iter()
+        .map(|event| EventModel::new(event, definition))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Response(TimelineModel {
+        from: filter.from,
+        to: filter.to,
+        events,
+    }))
+}
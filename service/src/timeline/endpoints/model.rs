@@ -0,0 +1,105 @@
+use actix_web::http::header::{CacheControl, CacheDirective, ETag, EntityTag};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::{
+    calendars::{
+        endpoints::{invalid_date, FormattedDateModel},
+        CalendarDefinition,
+    },
+    entities::EntityId,
+    http::{
+        problem::Problem,
+        response::{Response, SimpleRespondable},
+    },
+    timeline::{EventId, EventResource},
+};
+
+/// Representation of an event on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventModel {
+    pub event_id:    EventId,
+    pub title:       String,
+    pub description: Option<String>,
+    pub starts:      i64,
+    pub ends:        Option<i64>,
+    pub entity_ids:  Vec<EntityId>,
+    /// The days of the event described in terms of a calendar, if one was requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dates:       Option<EventDatesModel>,
+    pub created:     DateTime<Utc>,
+    pub updated:     DateTime<Utc>,
+}
+
+/// Representation of the days of an event described in terms of a calendar.
+#[derive(Serialize)]
+pub struct EventDatesModel {
+    pub starts: FormattedDateModel,
+    pub ends:   Option<FormattedDateModel>,
+}
+
+impl EventModel {
+    /// Build the representation of an event.
+    ///
+    /// # Parameters
+    /// - `event` - The event
+    /// - `calendar` - The calendar to describe the days of the event with, if any
+    ///
+    /// # Returns
+    /// The representation, or a problem if the days of the event can't be described in the
+    /// calendar.
+    pub fn new(event: EventResource, calendar: Option<&CalendarDefinition>) -> Result<Self, Problem> {
+        let dates = calendar
+            .map(|calendar| -> Result<EventDatesModel, Problem> {
+                let format = |day| calendar.format(day).map(FormattedDateModel::from).map_err(|e| invalid_date(&e));
+
+                Ok(EventDatesModel {
+                    starts: format(event.data.starts)?,
+                    ends:   event.data.ends.map(format).transpose()?,
+                })
+            })
+            .transpose()?;
+
+        Ok(Self {
+            event_id: event.identity.id,
+            title: event.data.title,
+            description: event.data.description,
+            starts: event.data.starts,
+            ends: event.data.ends,
+            entity_ids: event.data.entity_ids,
+            dates,
+            created: event.identity.created,
+            updated: event.identity.updated,
+        })
+    }
+}
+
+pub type EventResponse = Response<SimpleRespondable<EventModel>>;
+
+/// Build the response for a single event.
+///
+/// # Parameters
+/// - `event` - The event
+/// - `calendar` - The calendar to describe the days of the event with, if any
+///
+/// # Returns
+/// The response, or a problem if the days of the event can't be described in the calendar.
+pub fn respond(event: EventResource, calendar: Option<&CalendarDefinition>) -> Result<SimpleRespondable<EventModel>, Problem> {
+    let etag = EntityTag::strong(event.identity.version.to_string());
+
+    Ok(SimpleRespondable::new(EventModel::new(event, calendar)?)
+        .with_header(ETag(etag))
+        .with_header(CacheControl(vec![CacheDirective::Private, CacheDirective::NoCache])))
+}
+
+/// Representation of a timeline of events on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineModel {
+    /// The first day that the timeline covers, if it is bounded.
+    pub from:   Option<i64>,
+    /// The last day that the timeline covers, if it is bounded.
+    pub to:     Option<i64>,
+    pub events: Vec<EventModel>,
+}
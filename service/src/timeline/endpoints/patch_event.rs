@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{
+    load_calendar,
+    model::{respond, EventResponse},
+    parse_event_path,
+    problems::{unknown_entity, INVALID_RANGE},
+    DayInput,
+};
+use crate::{
+    authorization::Authentication,
+    calendars::CalendarService,
+    entities::EntityId,
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+        valid::{Valid, Validatable},
+    },
+    timeline::{EventData, TimelineService, UpdateEventError},
+    tokens::Scope,
+    worlds::{Role, WorldService},
+};
+
+pub async fn handle(
+    service: Data<Arc<TimelineService>>,
+    calendars_service: Data<Arc<CalendarService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    request: Valid<PatchRequest>,
+    authentication: Authentication,
+) -> Result<EventResponse, Problem> {
+    let (world_id, event_id) = path.into_inner();
+    let (world_id, event_id) = parse_event_path(&world_id, &event_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let request = request.unwrap();
+
+    let calendar = load_calendar(&calendars_service, &world_id, request.calendar_id.as_deref()).await?;
+    let starts = request
+        .starts
+        .as_ref()
+        .map(|starts| starts.resolve(calendar.as_ref()))
+        .transpose()?;
+    let ends = request.ends.as_ref().map(|ends| ends.resolve(calendar.as_ref())).transpose()?;
+
+    let event = service
+        .update_event_by_id(&world_id, &event_id, |event| -> Result<EventData, Problem> {
+            let starts = starts.unwrap_or(event.starts);
+            let ends = ends.or(event.ends);
+
+            // The days that aren't changing may not be describable in the requested calendar either.
+            for day in std::iter::once(starts).chain(ends) {
+                DayInput::Day(day).resolve(calendar.as_ref())?;
+            }
+
+            Ok(EventData {
+                title: request.title.unwrap_or(event.title),
+                description: request.description.or(event.description),
+                starts,
+                ends,
+                entity_ids: request.entity_ids.unwrap_or(event.entity_ids),
+                ..event
+            })
+        })
+        .await
+        .map_err(|e| match e {
+            UpdateEventError::UpdateError(p) => p,
+            UpdateEventError::UnknownEvent => NOT_FOUND.into(),
+            UpdateEventError::UnknownEntity(entity_id) => unknown_entity(&entity_id),
+            UpdateEventError::InvalidRange => INVALID_RANGE.into(),
+            UpdateEventError::UnknownError => INTERNAL_SERVER_ERROR.into(),
+        })?;
+
+    Ok(respond(event, calendar.as_ref().map(|c| &c.data.definition))?.into())
+}
+
+/// The incoming request to patch an event.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchRequest {
+    pub title:       Option<String>,
+    pub description: Option<String>,
+    pub starts:      Option<DayInput>,
+    pub ends:        Option<DayInput>,
+    /// The calendar that any dates in the request are written in.
+    pub calendar_id: Option<String>,
+    pub entity_ids:  Option<Vec<EntityId>>,
+}
+
+impl Validatable for PatchRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "title": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 200
+                },
+                "description": {
+                    "type": "string",
+                    "maxLength": 10000
+                },
+                "starts": DayInput::schema(),
+                "ends": DayInput::schema(),
+                "calendarId": {
+                    "type": "string"
+                },
+                "entityIds": {
+                    "type": "array",
+                    "maxItems": 100,
+                    "items": EntityId::schema()
+                }
+            }
+        })
+    }
+}
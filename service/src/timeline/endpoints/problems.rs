@@ -0,0 +1,42 @@
+use actix_http::http::StatusCode;
+
+use crate::{
+    entities::EntityId,
+    http::problem::{Problem, SimpleProblemType},
+};
+
+/// Problem to indicate that an event ends before it starts.
+pub const INVALID_RANGE: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/timeline/invalid_range",
+    problem_title: "Event ends before it starts",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate that an event involves an entity that doesn't exist in the world.
+pub const UNKNOWN_ENTITY: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/timeline/unknown_entity",
+    problem_title: "Unknown Entity",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate that the requested calendar doesn't exist in the world.
+pub const UNKNOWN_CALENDAR: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/timeline/unknown_calendar",
+    problem_title: "Unknown Calendar",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate that a date was provided without a calendar to interpret it with.
+pub const CALENDAR_REQUIRED: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/timeline/calendar_required",
+    problem_title: "A calendar is required to interpret dates",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Build the problem for an event involving an entity that doesn't exist.
+///
+/// # Parameters
+/// - `entity_id` - The ID of the entity that doesn't exist
+pub fn unknown_entity(entity_id: &EntityId) -> Problem {
+    Problem::from(UNKNOWN_ENTITY).with_extra("entityId", entity_id)
+}
@@ -0,0 +1,52 @@
+mod event_id;
+
+pub use event_id::*;
+
+use crate::{entities::EntityId, model::Resource, worlds::WorldId};
+
+/// The data representing an event on the timeline of a world.
+///
+/// Events happen on absolute days rather than on dates within a particular calendar, so that they
+/// can be described in terms of any of the calendars of the world.
+#[derive(Debug, Clone)]
+pub struct EventData {
+    pub world_id:    WorldId,
+    pub title:       String,
+    pub description: Option<String>,
+    /// The absolute day on which the event starts.
+    pub starts:      i64,
+    /// The absolute day on which the event ends, if it lasts for more than a single day.
+    pub ends:        Option<i64>,
+    /// The entities that were involved in the event.
+    pub entity_ids:  Vec<EntityId>,
+}
+
+/// Type representing a persisted event.
+pub type EventResource = Resource<EventId, EventData>;
+
+/// Criteria for selecting events from a timeline.
+#[derive(Debug, Clone, Default)]
+pub struct TimelineFilter {
+    /// Only include events that are still happening on or after this day.
+    pub from:      Option<i64>,
+    /// Only include events that have started on or before this day.
+    pub to:        Option<i64>,
+    /// Only include events that involved this entity.
+    pub entity_id: Option<EntityId>,
+}
+
+impl EventData {
+    /// The last day of the event.
+    pub fn last_day(&self) -> i64 {
+        self.ends.unwrap_or(self.starts)
+    }
+}
+
+impl TimelineFilter {
+    /// Determine whether an event matches the filter.
+    pub fn matches(&self, event: &EventData) -> bool {
+        self.from.is_none_or(|from| event.last_day() >= from)
+            && self.to.is_none_or(|to| event.starts <= to)
+            && self.entity_id.as_ref().is_none_or(|entity_id| event.entity_ids.contains(entity_id))
+    }
+}
@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod memory;
+mod parse;
+mod postgres;
+mod save_event;
+
+#[cfg(test)]
+pub use memory::MemoryEventRepository;
+pub use postgres::PostgresEventRepository;
+pub use save_event::SaveEventError;
+
+use super::{EventData, EventId, EventResource, TimelineFilter};
+use crate::worlds::WorldId;
+
+/// Repository of the events on the timelines of worlds.
+#[async_trait::async_trait]
+pub trait EventRepository: Send + Sync {
+    /// Create a new event.
+    ///
+    /// # Parameters
+    /// - `event` - The details of the event to create.
+    ///
+    /// # Returns
+    /// The created event.
+    async fn create_event(&self, event: &EventData) -> Result<EventResource, SaveEventError>;
+
+    /// Get a single event.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the event belongs to.
+    /// - `event_id` - The ID of the event.
+    ///
+    /// # Returns
+    /// The event, or `None` if it couldn't be found.
+    async fn get_event(&self, world_id: &WorldId, event_id: &EventId) -> Option<EventResource>;
+
+    /// Get the events within a world that match a filter.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world.
+    /// - `filter` - The criteria that the events must match.
+    ///
+    /// # Returns
+    /// The events, in the order that they started. Events that start on the same day are ordered
+    /// with single day events first, then by when they end, and then by title.
+    async fn get_events(&self, world_id: &WorldId, filter: &TimelineFilter) -> Vec<EventResource>;
+
+    /// Update an existing event.
+    ///
+    /// # Parameters
+    /// - `event_id` - The ID of the event.
+    /// - `event` - The new details of the event.
+    ///
+    /// # Returns
+    /// The updated event.
+    async fn update_event(&self, event_id: &EventId, event: &EventData) -> Result<EventResource, SaveEventError>;
+
+    /// Delete a single event.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the event belongs to.
+    /// - `event_id` - The ID of the event.
+    ///
+    /// # Returns
+    /// True if the event existed and was deleted. False if not.
+    async fn delete_event(&self, world_id: &WorldId, event_id: &EventId) -> bool;
+}
@@ -0,0 +1,85 @@
+use std::sync::Mutex;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::{EventRepository, SaveEventError};
+use crate::{
+    model::Identity,
+    timeline::{EventData, EventId, EventResource, TimelineFilter},
+    worlds::WorldId,
+};
+
+/// Repository of events that are stored in memory.
+#[derive(Default)]
+pub struct MemoryEventRepository {
+    events: Mutex<Vec<EventResource>>,
+}
+
+impl MemoryEventRepository {
+    /// Create a new, empty event repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl EventRepository for MemoryEventRepository {
+    async fn create_event(&self, event: &EventData) -> Result<EventResource, SaveEventError> {
+        let mut events = self.events.lock().unwrap();
+
+        let created = EventResource {
+            identity: Identity::default(),
+            data:     event.clone(),
+        };
+        events.push(created.clone());
+
+        Ok(created)
+    }
+
+    async fn get_event(&self, world_id: &WorldId, event_id: &EventId) -> Option<EventResource> {
+        let events = self.events.lock().unwrap();
+
+        events
+            .iter()
+            .find(|e| &e.data.world_id == world_id && &e.identity.id == event_id)
+            .cloned()
+    }
+
+    async fn get_events(&self, world_id: &WorldId, filter: &TimelineFilter) -> Vec<EventResource> {
+        let events = self.events.lock().unwrap();
+
+        let mut result: Vec<EventResource> = events
+            .iter()
+            .filter(|e| &e.data.world_id == world_id && filter.matches(&e.data))
+            .cloned()
+            .collect();
+        result.sort_by_key(|e| (e.data.starts, e.data.ends, e.data.title.to_lowercase()));
+
+        result
+    }
+
+    async fn update_event(&self, event_id: &EventId, event: &EventData) -> Result<EventResource, SaveEventError> {
+        let mut events = self.events.lock().unwrap();
+
+        let existing = events
+            .iter_mut()
+            .find(|e| &e.identity.id == event_id && e.data.world_id == event.world_id)
+            .ok_or(SaveEventError::UnknownEvent)?;
+
+        existing.identity.version = Uuid::new_v4();
+        existing.identity.updated = Utc::now();
+        existing.data = event.clone();
+
+        Ok(existing.clone())
+    }
+
+    async fn delete_event(&self, world_id: &WorldId, event_id: &EventId) -> bool {
+        let mut events = self.events.lock().unwrap();
+
+        let before = events.len();
+        events.retain(|e| !(&e.data.world_id == world_id && &e.identity.id == event_id));
+
+        events.len() != before
+    }
+}
@@ -0,0 +1,27 @@
+use tokio_postgres::Row;
+
+use crate::{
+    model::Identity,
+    timeline::{EventData, EventResource},
+};
+
+impl From<Row> for EventResource {
+    fn from(row: Row) -> Self {
+        EventResource {
+            identity: Identity {
+                id:      row.get("event_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data:     EventData {
+                world_id:    row.get("world_id"),
+                title:       row.get("title"),
+                description: row.get("description"),
+                starts:      row.get("starts"),
+                ends:        row.get("ends"),
+                entity_ids:  row.get("entity_ids"),
+            },
+        }
+    }
+}
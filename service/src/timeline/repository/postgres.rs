@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::{EventRepository, SaveEventError};
+use crate::{
+    database::Database,
+    model::Identity,
+    timeline::{EventData, EventId, EventResource, TimelineFilter},
+    worlds::WorldId,
+};
+
+/// Repository of events that are stored in Postgres.
+pub struct PostgresEventRepository {
+    database: Arc<Database>,
+}
+
+impl PostgresEventRepository {
+    /// Create a new event repository.
+    pub fn new(database: Arc<Database>) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait::async_trait]
+impl EventRepository for PostgresEventRepository {
+    #[tracing::instrument(skip(self))]
+    async fn create_event(&self, event: &EventData) -> Result<EventResource, SaveEventError> {
+        let conn = self.database.connect().await;
+
+        let identity = Identity::<EventId>::default();
+
+        let created: EventResource = conn.query_one("INSERT INTO events(event_id, version, created, updated, world_id, title, description, starts, ends, entity_ids) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) RETURNING *",
+        &[
+          &identity.id,
+          &identity.version,
+          &identity.created,
+          &identity.updated,
+          &event.world_id,
+          &event.title,
+          &event.description,
+          &event.starts,
+          &event.ends,
+          &event.entity_ids,
+          ])
+            .await
+            .map(EventResource::from)?;
+
+        Ok(created)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_event(&self, world_id: &WorldId, event_id: &EventId) -> Option<EventResource> {
+        let conn = self.database.connect().await;
+        conn.query_opt(
+            "SELECT * FROM events WHERE world_id = $1 AND event_id = $2",
+            &[&world_id, &event_id],
+        )
+        .await
+        .map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to load event");
+        })
+        .ok()
+        .flatten()
+        .map(EventResource::from)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_events(&self, world_id: &WorldId, filter: &TimelineFilter) -> Vec<EventResource> {
+        let conn = self.database.connect().await;
+        conn.query(
+            "SELECT * FROM events
+            WHERE world_id = $1
+            AND ($2::BIGINT IS NULL OR COALESCE(ends, starts) >= $2)
+            AND ($3::BIGINT IS NULL OR starts <= $3)
+            AND ($4::UUID IS NULL OR $4 = ANY(entity_ids))
+            ORDER BY starts, ends NULLS FIRST, LOWER(title)",
+            &[&world_id, &filter.from, &filter.to, &filter.entity_id],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to load events");
+                vec![]
+            },
+            |rows| rows.into_iter().map(EventResource::from).collect(),
+        )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn update_event(&self, event_id: &EventId, event: &EventData) -> Result<EventResource, SaveEventError> {
+        let conn = self.database.connect().await;
+
+        let version = Uuid::new_v4();
+        let updated = Utc::now();
+
+        conn.query_opt("UPDATE events SET version = $3, updated = $4, title = $5, description = $6, starts = $7, ends = $8, entity_ids = $9 WHERE event_id = $1 AND world_id = $2 RETURNING *",
+        &[
+          &event_id,
+          &event.world_id,
+          &version,
+          &updated,
+          &event.title,
+          &event.description,
+          &event.starts,
+          &event.ends,
+          &event.entity_ids,
+          ])
+            .await?
+            .map(EventResource::from)
+            .ok_or(SaveEventError::UnknownEvent)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_event(&self, world_id: &WorldId, event_id: &EventId) -> bool {
+        let conn = self.database.connect().await;
+        conn.execute("DELETE FROM events WHERE world_id = $1 AND event_id = $2", &[&world_id, &event_id])
+            .await
+            .map_or_else(
+                |e| {
+                    tracing::warn!(e = ?e, "Failed to delete event");
+                    false
+                },
+                |count| count == 1,
+            )
+    }
+}
@@ -0,0 +1,17 @@
+/// Errors that can occur when saving an event.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveEventError {
+    #[error("The event was not found")]
+    UnknownEvent,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl From<tokio_postgres::Error> for SaveEventError {
+    fn from(e: tokio_postgres::Error) -> Self {
+        tracing::warn!("Unexpected database error: {:?}", e);
+
+        SaveEventError::UnknownError
+    }
+}
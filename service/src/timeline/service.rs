@@ -0,0 +1,138 @@
+mod create_event;
+mod delete_event;
+mod get_event;
+mod update_event;
+
+use std::sync::Arc;
+
+pub use create_event::{CreateEventError, NewEvent};
+pub use update_event::UpdateEventError;
+
+use super::{repository::EventRepository, EventData};
+use crate::entities::{EntityId, EntityService};
+
+/// Service layer for working with the timelines of worlds.
+pub struct TimelineService {
+    repository: Arc<dyn EventRepository>,
+    entities:   Arc<EntityService>,
+}
+
+/// Reasons that the details of an event can't be saved.
+#[derive(Debug, PartialEq)]
+enum InvalidEvent {
+    /// The event ends before it starts.
+    InvalidRange,
+    /// The event involves an entity that isn't part of the world.
+    UnknownEntity(EntityId),
+}
+
+impl TimelineService {
+    /// Create a new timeline service.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store events in
+    /// - `entities` - The service to check the entities involved in events against
+    pub fn new(repository: Arc<dyn EventRepository>, entities: Arc<EntityService>) -> Self {
+        Self { repository, entities }
+    }
+
+    /// Check that the details of an event make sense.
+    ///
+    /// # Parameters
+    /// - `event` - The details of the event
+    ///
+    /// # Returns
+    /// The reason that the event is invalid, if it is.
+    async fn check_event(&self, event: &EventData) -> Result<(), InvalidEvent> {
+        if event.ends.is_some_and(|ends| ends < event.starts) {
+            return Err(InvalidEvent::InvalidRange);
+        }
+
+        let entities = self.entities.get_entities_by_ids(&event.world_id, &event.entity_ids).await;
+        if let Some(unknown) = event
+            .entity_ids
+            .iter()
+            .find(|entity_id| !entities.iter().any(|e| &e.identity.id == *entity_id))
+        {
+            return Err(InvalidEvent::UnknownEntity(unknown.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Remove any repeated entities from a list, keeping the first mention of each one.
+fn dedupe(entity_ids: Vec<EntityId>) -> Vec<EntityId> {
+    let mut result: Vec<EntityId> = Vec::with_capacity(entity_ids.len());
+    for entity_id in entity_ids {
+        if !result.contains(&entity_id) {
+            result.push(entity_id);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        entities::{CharacterAttributes, CustomFields, EntityService, MemoryEntityRepository, NewEntity},
+        revisions::{MemoryRevisionRepository, RevisionService},
+        search::{MemorySearchRepository, SearchService},
+        timeline::MemoryEventRepository,
+        users::UserId,
+        worlds::WorldId,
+    };
+
+    /// Fixture for testing the timeline service, with a single character already in the world.
+    pub struct Fixture {
+        pub sut:       TimelineService,
+        pub world_id:  WorldId,
+        pub character: EntityId,
+    }
+
+    pub async fn build_fixture() -> Fixture {
+        let entities = Arc::new(EntityService::new(
+            Arc::new(MemoryEntityRepository::new()),
+            Arc::new(RevisionService::new(Arc::new(MemoryRevisionRepository::new()))),
+            Arc::new(SearchService::new(Arc::new(MemorySearchRepository::new()))),
+        ));
+        let world_id = WorldId::default();
+
+        let character = entities
+            .create_entity(
+                &world_id,
+                &UserId::default(),
+                NewEntity {
+                    name:       "Aragorn".to_owned(),
+                    summary:    None,
+                    attributes: CharacterAttributes::default(),
+                    custom:     CustomFields::new(),
+                },
+            )
+            .await
+            .unwrap()
+            .identity
+            .id;
+
+        Fixture {
+            sut: TimelineService::new(Arc::new(MemoryEventRepository::new()), entities),
+            world_id,
+            character,
+        }
+    }
+
+    /// Build the details of a new event.
+    pub fn new_event(title: &str, starts: i64, ends: Option<i64>, entity_ids: Vec<EntityId>) -> NewEvent {
+        NewEvent {
+            title: title.to_owned(),
+            description: None,
+            starts,
+            ends,
+            entity_ids,
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use super::{dedupe, InvalidEvent, TimelineService};
+use crate::{
+    entities::EntityId,
+    timeline::{EventData, EventResource, SaveEventError},
+    worlds::WorldId,
+};
+
+/// The details needed to create a new event.
+#[derive(Debug)]
+pub struct NewEvent {
+    pub title:       String,
+    pub description: Option<String>,
+    pub starts:      i64,
+    pub ends:        Option<i64>,
+    pub entity_ids:  Vec<EntityId>,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum CreateEventError {
+    #[error("Unknown entity: {0}")]
+    UnknownEntity(EntityId),
+
+    #[error("The event ends before it starts")]
+    InvalidRange,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl TimelineService {
+    /// Create a new event on the timeline of a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world to create the event in
+    /// - `event` - The details of the event
+    ///
+    /// # Returns
+    /// The newly created event.
+    pub async fn create_event(&self, world_id: &WorldId, event: NewEvent) -> Result<EventResource, CreateEventError> {
+        let data = EventData {
+            world_id:    world_id.clone(),
+            title:       event.title,
+            description: event.description,
+            starts:      event.starts,
+            ends:        event.ends,
+            entity_ids:  dedupe(event.entity_ids),
+        };
+
+        self.check_event(&data).await?;
+
+        let result = self.repository.create_event(&data).await?;
+
+        Ok(result)
+    }
+}
+
+impl From<InvalidEvent> for CreateEventError {
+    fn from(e: InvalidEvent) -> Self {
+        match e {
+            InvalidEvent::InvalidRange => Self::InvalidRange,
+            InvalidEvent::UnknownEntity(entity_id) => Self::UnknownEntity(entity_id),
+        }
+    }
+}
+
+impl From<SaveEventError> for CreateEventError {
+    fn from(e: SaveEventError) -> Self {
+        match e {
+            SaveEventError::UnknownEvent | SaveEventError::UnknownError => Self::UnknownError,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::timeline::service::tests::{build_fixture, new_event};
+
+    #[actix_rt::test]
+    async fn create_event() {
+        let f = build_fixture().await;
+
+        let result = f
+            .sut
+            .create_event(
+                &f.world_id,
+                new_event("Coronation", 100, None, vec![f.character.clone(), f.character.clone()]),
+            )
+            .await;
+
+        let_assert!(Ok(event) = result);
+        check!(event.data.title == "Coronation");
+        check!(event.data.starts == 100);
+        check!(event.data.ends == None);
+        check!(event.data.entity_ids == vec![f.character.clone()]);
+
+        let_assert!(Some(loaded) = f.sut.get_event(&f.world_id, &event.identity.id).await);
+        check!(loaded.identity.id == event.identity.id);
+    }
+
+    #[actix_rt::test]
+    async fn create_event_ending_before_it_starts() {
+        let f = build_fixture().await;
+
+        let result = f.sut.create_event(&f.world_id, new_event("Backwards", 100, Some(99), vec![])).await;
+
+        let_assert!(Err(e) = result);
+        check!(e == CreateEventError::InvalidRange);
+    }
+
+    #[actix_rt::test]
+    async fn create_event_with_unknown_entity() {
+        let f = build_fixture().await;
+        let unknown = EntityId::default();
+
+        let result = f
+            .sut
+            .create_event(
+                &f.world_id,
+                new_event("Mystery", 100, None, vec![f.character.clone(), unknown.clone()]),
+            )
+            .await;
+
+        let_assert!(Err(e) = result);
+        check!(e == CreateEventError::UnknownEntity(unknown));
+    }
+
+    #[actix_rt::test]
+    async fn create_event_with_entity_in_other_world() {
+        let f = build_fixture().await;
+
+        let result = f
+            .sut
+            .create_event(&WorldId::default(), new_event("Elsewhere", 100, None, vec![f.character.clone()]))
+            .await;
+
+        let_assert!(Err(e) = result);
+        check!(e == CreateEventError::UnknownEntity(f.character.clone()));
+    }
+}
@@ -0,0 +1,16 @@
+use super::TimelineService;
+use crate::{timeline::EventId, worlds::WorldId};
+
+impl TimelineService {
+    /// Delete a single event.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the event belongs to
+    /// - `event_id` - The ID of the event
+    ///
+    /// # Returns
+    /// True if the event existed and was deleted. False if not.
+    pub async fn delete_event(&self, world_id: &WorldId, event_id: &EventId) -> bool {
+        self.repository.delete_event(world_id, event_id).await
+    }
+}
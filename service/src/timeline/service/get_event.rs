@@ -0,0 +1,107 @@
+use super::TimelineService;
+use crate::{
+    timeline::{EventId, EventResource, TimelineFilter},
+    worlds::WorldId,
+};
+
+impl TimelineService {
+    /// Get a single event.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the event belongs to
+    /// - `event_id` - The ID of the event
+    ///
+    /// # Returns
+    /// The event, or `None` if it doesn't exist.
+    pub async fn get_event(&self, world_id: &WorldId, event_id: &EventId) -> Option<EventResource> {
+        self.repository.get_event(world_id, event_id).await
+    }
+
+    /// Get the events on the timeline of a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    /// - `filter` - The criteria that the events must match
+    ///
+    /// # Returns
+    /// The matching events, in chronological order.
+    pub async fn get_events(&self, world_id: &WorldId, filter: &TimelineFilter) -> Vec<EventResource> {
+        if let (Some(from), Some(to)) = (filter.from, filter.to) {
+            if to < from {
+                return vec![];
+            }
+        }
+
+        self.repository.get_events(world_id, filter).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use test_case::test_case;
+
+    use super::*;
+    use crate::timeline::service::tests::{build_fixture, new_event, Fixture};
+
+    async fn build_timeline() -> Fixture {
+        let f = build_fixture().await;
+
+        for event in [
+            new_event("War of the Ring", 300, Some(400), vec![f.character.clone()]),
+            new_event("Founding", 10, None, vec![]),
+            new_event("Coronation", 400, None, vec![f.character.clone()]),
+            new_event("Long Peace", 10, Some(250), vec![]),
+            new_event("Battle", 350, None, vec![]),
+            new_event("Alliance", 10, None, vec![]),
+        ] {
+            f.sut.create_event(&f.world_id, event).await.unwrap();
+        }
+
+        f
+    }
+
+    #[test_case(None, None, vec!["Alliance", "Founding", "Long Peace", "War of the Ring", "Battle", "Coronation"] ; "Everything")]
+    #[test_case(Some(300), None, vec!["War of the Ring", "Battle", "Coronation"] ; "From")]
+    #[test_case(None, Some(300), vec!["Alliance", "Founding", "Long Peace", "War of the Ring"] ; "To")]
+    #[test_case(Some(200), Some(350), vec!["Long Peace", "War of the Ring", "Battle"] ; "Overlapping")]
+    #[test_case(Some(251), Some(299), vec![] ; "Gap")]
+    #[test_case(Some(400), Some(300), vec![] ; "Backwards")]
+    #[actix_rt::test]
+    async fn get_events_in_range(from: Option<i64>, to: Option<i64>, expected: Vec<&str>) {
+        let f = build_timeline().await;
+
+        let events = f.sut.get_events(&f.world_id, &TimelineFilter { from, to, entity_id: None }).await;
+
+        let titles: Vec<&str> = events.iter().map(|e| e.data.title.as_str()).collect();
+        check!(titles == expected);
+    }
+
+    #[actix_rt::test]
+    async fn get_events_for_entity() {
+        let f = build_timeline().await;
+
+        let events = f
+            .sut
+            .get_events(
+                &f.world_id,
+                &TimelineFilter {
+                    entity_id: Some(f.character.clone()),
+                    ..TimelineFilter::default()
+                },
+            )
+            .await;
+
+        let titles: Vec<&str> = events.iter().map(|e| e.data.title.as_str()).collect();
+        check!(titles == vec!["War of the Ring", "Coronation"]);
+    }
+
+    #[actix_rt::test]
+    async fn get_events_for_other_world() {
+        let f = build_timeline().await;
+
+        let events = f.sut.get_events(&WorldId::default(), &TimelineFilter::default()).await;
+
+        check!(events.is_empty());
+    }
+}
@@ -0,0 +1,86 @@
+use super::{dedupe, InvalidEvent, TimelineService};
+use crate::{
+    entities::EntityId,
+    timeline::{EventData, EventId, EventResource, SaveEventError},
+    worlds::WorldId,
+};
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum UpdateEventError<E>
+where
+    E: std::fmt::Debug,
+{
+    #[error("Unknown event")]
+    UnknownEvent,
+
+    #[error("Unknown entity: {0}")]
+    UnknownEntity(EntityId),
+
+    #[error("The event ends before it starts")]
+    InvalidRange,
+
+    #[error("An error occurred updating the event data")]
+    UpdateError(E),
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl TimelineService {
+    /// Update the event that has the provided ID, using the provided lambda to perform the updates.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the event belongs to
+    /// - `event_id` - The ID of the event to update
+    /// - `f` - The function to update the event details
+    ///
+    /// # Returns
+    /// The newly updated event.
+    pub async fn update_event_by_id<F, E>(&self, world_id: &WorldId, event_id: &EventId, f: F) -> Result<EventResource, UpdateEventError<E>>
+    where
+        F: FnOnce(EventData) -> Result<EventData, E>,
+        E: std::fmt::Debug,
+    {
+        let event = self
+            .repository
+            .get_event(world_id, event_id)
+            .await
+            .ok_or(UpdateEventError::UnknownEvent)?;
+
+        let data = f(event.data).map_err(UpdateEventError::UpdateError)?;
+        let data = EventData {
+            world_id: world_id.clone(),
+            entity_ids: dedupe(data.entity_ids),
+            ..data
+        };
+        self.check_event(&data).await?;
+
+        let result = self.repository.update_event(event_id, &data).await?;
+
+        Ok(result)
+    }
+}
+
+impl<E> From<InvalidEvent> for UpdateEventError<E>
+where
+    E: std::fmt::Debug,
+{
+    fn from(e: InvalidEvent) -> Self {
+        match e {
+            InvalidEvent::InvalidRange => Self::InvalidRange,
+            InvalidEvent::UnknownEntity(entity_id) => Self::UnknownEntity(entity_id),
+        }
+    }
+}
+
+impl<E> From<SaveEventError> for UpdateEventError<E>
+where
+    E: std::fmt::Debug,
+{
+    fn from(e: SaveEventError) -> Self {
+        match e {
+            SaveEventError::UnknownEvent => Self::UnknownEvent,
+            SaveEventError::UnknownError => Self::UnknownError,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use actix_web::web::{delete, get, post, resource, ServiceConfig};
+
+use super::{repository::TokenRepository, service::TokenService};
+use crate::server::RouteConfigurer;
+
+/// Component for working with personal access tokens.
+pub struct Component {
+    pub service: Arc<TokenService>,
+}
+
+impl Component {
+    /// Create a new tokens component.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store tokens in
+    pub fn new(repository: Arc<dyn TokenRepository>) -> Arc<Self> {
+        let service = Arc::new(TokenService::new(repository));
+
+        Arc::new(Self { service })
+    }
+}
+
+impl RouteConfigurer for Component {
+    fn configure_routes(&self, config: &mut ServiceConfig) {
+        config.data(self.service.clone());
+
+        config.service(
+            resource("/users/{id}/tokens")
+                .route(post().to(super::endpoints::create_token::handle))
+                .route(get().to(super::endpoints::list_tokens::handle)),
+        );
+        config.service(resource("/users/{id}/tokens/{token}").route(delete().to(super::endpoints::delete_token::handle)));
+    }
+}
@@ -0,0 +1,4 @@
+pub(super) mod create_token;
+pub(super) mod delete_token;
+pub(super) mod list_tokens;
+mod model;
@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use actix_http::http::StatusCode;
+use actix_web::{
+    http::header::{CacheControl, CacheDirective},
+    web::{Data, Path},
+};
+use chrono::{Duration, Utc};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::model::{CreatedTokenModel, TokenModel};
+use crate::{
+    authorization::{Authentication, Principal},
+    http::{
+        problem::{Problem, FORBIDDEN, INTERNAL_SERVER_ERROR},
+        response::{Response, SimpleRespondable},
+        valid::{Valid, Validatable},
+    },
+    tokens::{NewToken, Scope, TokenService},
+    users::UserId,
+};
+
+/// Create a new personal access token for the user.
+pub async fn handle(
+    service: Data<Arc<TokenService>>,
+    path: Path<String>,
+    request: Valid<CreateTokenRequest>,
+    authentication: Authentication,
+) -> Result<Response<SimpleRespondable<CreatedTokenModel>>, Problem> {
+    let user_id: UserId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse User ID");
+
+        FORBIDDEN
+    })?;
+
+    authentication.same_principal(&Principal::from(&user_id))?;
+    authentication.require_session()?;
+
+    let request = request.unwrap();
+
+    let (token, secret) = service
+        .create_token(
+            &user_id,
+            NewToken {
+                name:    request.name,
+                scopes:  request.scopes,
+                expires: Utc::now() + Duration::days(request.expires_in_days),
+            },
+        )
+        .await
+        .map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to create personal access token");
+
+            INTERNAL_SERVER_ERROR
+        })?;
+
+    let model = CreatedTokenModel {
+        details: TokenModel::from(token),
+        token:   secret.value().to_owned(),
+    };
+
+    // The secret mustn't be kept anywhere, including for replaying to retries.
+    Ok(SimpleRespondable::new(model)
+        .with_status_code(StatusCode::CREATED)
+        .with_header(CacheControl(vec![CacheDirective::NoStore]))
+        .into())
+}
+
+/// The incoming request to create a personal access token.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTokenRequest {
+    pub name:            String,
+    pub scopes:          Vec<Scope>,
+    pub expires_in_days: i64,
+}
+
+impl Validatable for CreateTokenRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 100
+                },
+                "scopes": {
+                    "type": "array",
+                    "items": Scope::schema(),
+                    "minItems": 1,
+                    "uniqueItems": true
+                },
+                "expiresInDays": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "maximum": 365
+                }
+            },
+            "required": [
+                "name",
+                "scopes",
+                "expiresInDays"
+            ]
+        })
+    }
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use actix_web::{
+    web::{Data, Path},
+    HttpResponse,
+};
+
+use crate::{
+    authorization::{Authentication, Principal},
+    http::problem::{Problem, FORBIDDEN, NOT_FOUND},
+    tokens::{TokenId, TokenService},
+    users::UserId,
+};
+
+/// Revoke a personal access token, after which it can no longer be used.
+pub async fn handle(
+    service: Data<Arc<TokenService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<HttpResponse, Problem> {
+    let (user_id, token_id) = path.into_inner();
+
+    let user_id: UserId = user_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, user_id = ?user_id, "Failed to parse User ID");
+
+        FORBIDDEN
+    })?;
+
+    authentication.same_principal(&Principal::from(&user_id))?;
+    authentication.require_session()?;
+
+    let token_id: TokenId = token_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, token_id = ?token_id, "Failed to parse Token ID");
+
+        NOT_FOUND
+    })?;
+
+    if service.delete_token(&user_id, &token_id).await {
+        Ok(HttpResponse::NoContent().finish())
+    } else {
+        Err(NOT_FOUND.into())
+    }
+}
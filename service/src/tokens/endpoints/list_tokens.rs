@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::model::{TokenModel, TokensModel};
+use crate::{
+    authorization::{Authentication, Principal},
+    http::{
+        problem::{Problem, FORBIDDEN},
+        response::Response,
+    },
+    tokens::TokenService,
+    users::UserId,
+};
+
+pub async fn handle(
+    service: Data<Arc<TokenService>>,
+    path: Path<String>,
+    authentication: Authentication,
+) -> Result<Response<TokensModel>, Problem> {
+    let user_id: UserId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse User ID");
+
+        FORBIDDEN
+    })?;
+
+    authentication.same_principal(&Principal::from(&user_id))?;
+    authentication.require_session()?;
+
+    let tokens = service
+        .get_tokens_for_user(&user_id)
+        .await
+        .into_iter()
+        .map(TokenModel::from)
+        .collect();
+
+    Ok(Response(TokensModel { tokens }))
+}
@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::tokens::{Scope, TokenId, TokenResource};
+
+/// Representation of a personal access token on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenModel {
+    pub token_id:   TokenId,
+    pub name:       String,
+    pub scopes:     Vec<Scope>,
+    pub created:    DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub last_used:  Option<DateTime<Utc>>,
+}
+
+/// Representation of a newly created personal access token on the HTTP API.
+/// This is the only time that the secret value of the token is ever returned.
+#[derive(Serialize)]
+pub struct CreatedTokenModel {
+    #[serde(flatten)]
+    pub details: TokenModel,
+    pub token:   String,
+}
+
+/// Representation of all the personal access tokens for a user on the HTTP API.
+#[derive(Serialize)]
+pub struct TokensModel {
+    pub tokens: Vec<TokenModel>,
+}
+
+impl From<TokenResource> for TokenModel {
+    fn from(token: TokenResource) -> Self {
+        Self {
+            token_id:   token.identity.id,
+            name:       token.data.name,
+            scopes:     token.data.scopes,
+            created:    token.identity.created,
+            expires_at: token.data.expires,
+            last_used:  token.data.last_used,
+        }
+    }
+}
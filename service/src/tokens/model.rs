@@ -0,0 +1,24 @@
+mod scope;
+mod token_id;
+mod token_secret;
+
+use chrono::{DateTime, Utc};
+pub use scope::*;
+pub use token_id::*;
+pub use token_secret::*;
+
+use crate::{model::Resource, users::UserId};
+
+/// The data representing a personal access token.
+#[derive(Debug, Clone)]
+pub struct TokenData {
+    pub user_id:    UserId,
+    pub name:       String,
+    pub scopes:     Vec<Scope>,
+    pub token_hash: String,
+    pub expires:    DateTime<Utc>,
+    pub last_used:  Option<DateTime<Utc>>,
+}
+
+/// Type representing a persisted personal access token.
+pub type TokenResource = Resource<TokenId, TokenData>;
@@ -0,0 +1,111 @@
+use std::str::FromStr;
+
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+/// A permission that can be granted to a personal access token.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Scope {
+    /// Permission to read the full details of the user.
+    #[serde(rename = "users:read")]
+    UsersRead,
+    /// Permission to change the details of the user.
+    #[serde(rename = "users:write")]
+    UsersWrite,
+    /// Permission to read the worlds that the user is a member of.
+    #[serde(rename = "worlds:read")]
+    WorldsRead,
+    /// Permission to change the worlds that the user is a member of, and their membership of them.
+    #[serde(rename = "worlds:write")]
+    WorldsWrite,
+}
+
+/// All of the scopes that exist.
+const ALL_SCOPES: &[Scope] = &[Scope::UsersRead, Scope::UsersWrite, Scope::WorldsRead, Scope::WorldsWrite];
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ParseScopeError {
+    #[error("The scope is not known")]
+    Unknown,
+}
+
+impl Scope {
+    /// The name of the scope, as used on the API and in the database.
+    pub fn name(self) -> &'static str {
+        match self {
+            Scope::UsersRead => "users:read",
+            Scope::UsersWrite => "users:write",
+            Scope::WorldsRead => "worlds:read",
+            Scope::WorldsWrite => "worlds:write",
+        }
+    }
+
+    /// Generate the JSON Schema fragment for a scope.
+    pub fn schema() -> Value {
+        let names: Vec<&str> = ALL_SCOPES.iter().map(|s| s.name()).collect();
+
+        json!({
+            "type": "string",
+            "enum": names
+        })
+    }
+}
+
+impl FromStr for Scope {
+    type Err = ParseScopeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ALL_SCOPES
+            .iter()
+            .find(|scope| scope.name() == s)
+            .copied()
+            .ok_or(ParseScopeError::Unknown)
+    }
+}
+
+impl ToSql for Scope {
+    accepts!(TEXT, VARCHAR);
+    to_sql_checked!();
+
+    fn to_sql(&self, t: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.name().to_sql(t, w)
+    }
+}
+
+impl<'a> FromSql<'a> for Scope {
+    accepts!(TEXT, VARCHAR);
+
+    fn from_sql(t: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        let name = <&str>::from_sql(t, raw)?;
+
+        Ok(name.parse()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("users:read", Scope::UsersRead ; "Users Read")]
+    #[test_case("users:write", Scope::UsersWrite ; "Users Write")]
+    #[test_case("worlds:read", Scope::WorldsRead ; "Worlds Read")]
+    #[test_case("worlds:write", Scope::WorldsWrite ; "Worlds Write")]
+    fn test_parse_success(input: &str, expected: Scope) {
+        let_assert!(Ok(scope) = input.parse::<Scope>());
+        check!(scope == expected);
+        check!(serde_json::to_value(scope).unwrap() == input);
+    }
+
+    #[test_case("" ; "Blank")]
+    #[test_case("users" ; "Partial")]
+    #[test_case("USERS:READ" ; "Upper case")]
+    fn test_parse_fail(input: &str) {
+        let_assert!(Err(e) = input.parse::<Scope>());
+        check!(e == ParseScopeError::Unknown);
+    }
+}
@@ -0,0 +1,98 @@
+use rand::{distributions::Alphanumeric, Rng};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+/// The prefix that every personal access token starts with, to distinguish them from other kinds of
+/// access token.
+pub const TOKEN_PREFIX: &str = "wpat_";
+
+/// The number of random characters in a personal access token, after the prefix.
+const TOKEN_LENGTH: usize = 40;
+
+/// The secret value of a personal access token.
+/// This is only ever known at the point the token is created - only the hash of it is stored.
+#[derive(Serialize)]
+pub struct TokenSecret(String);
+
+impl TokenSecret {
+    /// Generate a new, random, token secret.
+    pub fn generate() -> Self {
+        let random: String = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(TOKEN_LENGTH)
+            .map(char::from)
+            .collect();
+
+        Self(format!("{}{}", TOKEN_PREFIX, random))
+    }
+
+    /// Determine if the provided access token looks like a personal access token.
+    ///
+    /// # Parameters
+    /// - `token` - The access token to check
+    ///
+    /// # Returns
+    /// True if the token has the personal access token prefix. False if not.
+    pub fn is_token_secret(token: &str) -> bool {
+        token.starts_with(TOKEN_PREFIX)
+    }
+
+    /// Produce the hash of the provided token secret, for storing and looking it up by.
+    /// The secret is entirely random, so a fast unsalted hash is sufficient here.
+    ///
+    /// # Parameters
+    /// - `token` - The token secret to hash
+    ///
+    /// # Returns
+    /// The hash of the token secret.
+    pub fn hash_of(token: &str) -> String {
+        format!("{:x}", Sha256::digest(token.as_bytes()))
+    }
+
+    /// Produce the hash of this token secret.
+    pub fn hash(&self) -> String {
+        Self::hash_of(&self.0)
+    }
+
+    /// Get the value of the token secret.
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for TokenSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TokenSecret(Redacted)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+
+    use super::*;
+
+    #[test]
+    fn generate() {
+        let first = TokenSecret::generate();
+        let second = TokenSecret::generate();
+
+        check!(first.value().starts_with(TOKEN_PREFIX));
+        check!(first.value().len() == TOKEN_PREFIX.len() + TOKEN_LENGTH);
+        check!(TokenSecret::is_token_secret(first.value()));
+        check!(first.value() != second.value());
+        check!(first.hash() != second.hash());
+    }
+
+    #[test]
+    fn hash() {
+        check!(TokenSecret::hash_of("wpat_abc") == "57a7dceda4ffc96496e6c754c798e9c0f4423af09379afbec11f882c53d02da9");
+    }
+
+    #[test]
+    fn debug() {
+        let formatted = format!("{:?}", TokenSecret::generate());
+
+        check!(formatted == "TokenSecret(Redacted)");
+    }
+}
@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod memory;
+mod parse;
+mod postgres;
+mod save_token;
+
+use chrono::{DateTime, Utc};
+#[cfg(test)]
+pub use memory::MemoryTokenRepository;
+pub use postgres::PostgresTokenRepository;
+pub use save_token::SaveTokenError;
+
+use crate::{
+    tokens::{TokenData, TokenId, TokenResource},
+    users::UserId,
+};
+
+/// Repository of personal access token records.
+#[async_trait::async_trait]
+pub trait TokenRepository: Send + Sync {
+    /// Create a new token record from the provided Token data.
+    ///
+    /// # Parameters
+    /// - `token` - The details of the token to create.
+    ///
+    /// # Returns
+    /// The created token resource.
+    async fn create_token(&self, token: &TokenData) -> Result<TokenResource, SaveTokenError>;
+
+    /// Get all of the tokens that belong to the provided User ID.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user to fetch the tokens for.
+    ///
+    /// # Returns
+    /// The token resources, newest first.
+    async fn get_tokens_for_user(&self, user_id: &UserId) -> Vec<TokenResource>;
+
+    /// Get the token that has the provided hash.
+    ///
+    /// # Parameters
+    /// - `token_hash` - The hash of the token secret.
+    ///
+    /// # Returns
+    /// The token resource, or `None` if it couldn't be found.
+    async fn get_token_by_hash(&self, token_hash: &str) -> Option<TokenResource>;
+
+    /// Record that a token has been used.
+    /// The token is only written to if it was last used before `stale_before`, so that
+    /// authenticating with a token on every request doesn't also write to it on every request.
+    ///
+    /// # Parameters
+    /// - `token_id` - The ID of the token.
+    /// - `used` - When the token was used.
+    /// - `stale_before` - The time before which the last used time needs updating.
+    async fn touch_token(&self, token_id: &TokenId, used: DateTime<Utc>, stale_before: DateTime<Utc>);
+
+    /// Delete a single token.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user that the token belongs to.
+    /// - `token_id` - The ID of the token.
+    ///
+    /// # Returns
+    /// True if the token existed and was deleted. False if not.
+    async fn delete_token(&self, user_id: &UserId, token_id: &TokenId) -> bool;
+}
@@ -0,0 +1,72 @@
+use std::{cmp::Reverse, sync::Mutex};
+
+use chrono::{DateTime, Utc};
+
+use super::{SaveTokenError, TokenRepository};
+use crate::{
+    model::Identity,
+    tokens::{TokenData, TokenId, TokenResource},
+    users::UserId,
+};
+
+/// Repository of personal access token records that are stored in memory.
+#[derive(Default)]
+pub struct MemoryTokenRepository {
+    tokens: Mutex<Vec<TokenResource>>,
+}
+
+impl MemoryTokenRepository {
+    /// Create a new, empty token repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenRepository for MemoryTokenRepository {
+    async fn create_token(&self, token: &TokenData) -> Result<TokenResource, SaveTokenError> {
+        let mut tokens = self.tokens.lock().unwrap();
+
+        let created = TokenResource {
+            identity: Identity::default(),
+            data:     token.clone(),
+        };
+        tokens.push(created.clone());
+
+        Ok(created)
+    }
+
+    async fn get_tokens_for_user(&self, user_id: &UserId) -> Vec<TokenResource> {
+        let tokens = self.tokens.lock().unwrap();
+
+        let mut result: Vec<TokenResource> = tokens.iter().filter(|t| &t.data.user_id == user_id).cloned().collect();
+        result.sort_by_key(|t| Reverse(t.identity.created));
+
+        result
+    }
+
+    async fn get_token_by_hash(&self, token_hash: &str) -> Option<TokenResource> {
+        let tokens = self.tokens.lock().unwrap();
+
+        tokens.iter().find(|t| t.data.token_hash == token_hash).cloned()
+    }
+
+    async fn touch_token(&self, token_id: &TokenId, used: DateTime<Utc>, stale_before: DateTime<Utc>) {
+        let mut tokens = self.tokens.lock().unwrap();
+
+        if let Some(token) = tokens.iter_mut().find(|t| &t.identity.id == token_id) {
+            if token.data.last_used.is_none_or(|last_used| last_used < stale_before) {
+                token.data.last_used = Some(used);
+            }
+        }
+    }
+
+    async fn delete_token(&self, user_id: &UserId, token_id: &TokenId) -> bool {
+        let mut tokens = self.tokens.lock().unwrap();
+
+        let before = tokens.len();
+        tokens.retain(|t| &t.identity.id != token_id || &t.data.user_id != user_id);
+
+        tokens.len() != before
+    }
+}
@@ -0,0 +1,27 @@
+use tokio_postgres::Row;
+
+use crate::{
+    model::Identity,
+    tokens::{TokenData, TokenResource},
+};
+
+impl From<Row> for TokenResource {
+    fn from(row: Row) -> Self {
+        TokenResource {
+            identity: Identity {
+                id:      row.get("token_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data:     TokenData {
+                user_id:    row.get("user_id"),
+                name:       row.get("name"),
+                scopes:     row.get("scopes"),
+                token_hash: row.get("token_hash"),
+                expires:    row.get("expires"),
+                last_used:  row.get("last_used"),
+            },
+        }
+    }
+}
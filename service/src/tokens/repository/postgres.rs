@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+use super::{SaveTokenError, TokenRepository};
+use crate::{
+    database::Database,
+    model::Identity,
+    tokens::{TokenData, TokenId, TokenResource},
+    users::UserId,
+};
+
+/// Repository of personal access token records that are stored in Postgres.
+pub struct PostgresTokenRepository {
+    database: Arc<Database>,
+}
+
+impl PostgresTokenRepository {
+    /// Create a new token repository.
+    pub fn new(database: Arc<Database>) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenRepository for PostgresTokenRepository {
+    #[tracing::instrument(skip(self))]
+    async fn create_token(&self, token: &TokenData) -> Result<TokenResource, SaveTokenError> {
+        let conn = self.database.connect().await;
+
+        let identity = Identity::<TokenId>::default();
+
+        let created: TokenResource = conn.query_one("INSERT INTO access_tokens(token_id, version, created, updated, user_id, name, scopes, token_hash, expires, last_used) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) RETURNING *",
+        &[
+          &identity.id,
+          &identity.version,
+          &identity.created,
+          &identity.updated,
+          &token.user_id,
+          &token.name,
+          &token.scopes,
+          &token.token_hash,
+          &token.expires,
+          &token.last_used,
+          ])
+            .await
+            .map(TokenResource::from)?;
+
+        Ok(created)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_tokens_for_user(&self, user_id: &UserId) -> Vec<TokenResource> {
+        let conn = self.database.connect().await;
+        conn.query("SELECT * FROM access_tokens WHERE user_id = $1 ORDER BY created DESC", &[&user_id])
+            .await
+            .map_or_else(
+                |e| {
+                    tracing::warn!(e = ?e, "Failed to load tokens");
+                    vec![]
+                },
+                |rows| rows.into_iter().map(TokenResource::from).collect(),
+            )
+    }
+
+    #[tracing::instrument(skip(self, token_hash))]
+    async fn get_token_by_hash(&self, token_hash: &str) -> Option<TokenResource> {
+        let conn = self.database.connect().await;
+        conn.query_opt("SELECT * FROM access_tokens WHERE token_hash = $1", &[&token_hash])
+            .await
+            .ok()?
+            .map(TokenResource::from)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn touch_token(&self, token_id: &TokenId, used: DateTime<Utc>, stale_before: DateTime<Utc>) {
+        let conn = self.database.connect().await;
+        if let Err(e) = conn
+            .execute(
+                "UPDATE access_tokens SET last_used = $2 WHERE token_id = $1 AND (last_used IS NULL OR last_used < $3)",
+                &[&token_id, &used, &stale_before],
+            )
+            .await
+        {
+            tracing::warn!(e = ?e, "Failed to update token");
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_token(&self, user_id: &UserId, token_id: &TokenId) -> bool {
+        let conn = self.database.connect().await;
+        conn.execute(
+            "DELETE FROM access_tokens WHERE token_id = $1 AND user_id = $2",
+            &[&token_id, &user_id],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to delete token");
+                false
+            },
+            |count| count == 1,
+        )
+    }
+}
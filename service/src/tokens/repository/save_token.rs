@@ -0,0 +1,22 @@
+use tokio_postgres::error::SqlState;
+
+/// Errors that can occur when saving a token record.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveTokenError {
+    #[error("The user was not found")]
+    UnknownUser,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl From<tokio_postgres::Error> for SaveTokenError {
+    fn from(e: tokio_postgres::Error) -> Self {
+        if e.code() == Some(&SqlState::FOREIGN_KEY_VIOLATION) {
+            SaveTokenError::UnknownUser
+        } else {
+            tracing::warn!("Unexpected database error: {:?}", e);
+            SaveTokenError::UnknownError
+        }
+    }
+}
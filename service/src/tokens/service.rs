@@ -0,0 +1,187 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+
+use super::{SaveTokenError, Scope, TokenData, TokenId, TokenRepository, TokenResource, TokenSecret};
+use crate::users::UserId;
+
+/// How long after a token was last used before it is recorded as being used again.
+const TOUCH_INTERVAL_SECONDS: i64 = 60;
+
+/// Service layer for working with personal access tokens.
+pub struct TokenService {
+    repository: Arc<dyn TokenRepository>,
+}
+
+/// Details needed to create a new personal access token.
+#[derive(Debug)]
+pub struct NewToken {
+    pub name:    String,
+    pub scopes:  Vec<Scope>,
+    pub expires: DateTime<Utc>,
+}
+
+impl TokenService {
+    /// Create a new token service.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store tokens in
+    pub fn new(repository: Arc<dyn TokenRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Create a new personal access token for a user.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user that the token is for
+    /// - `token` - The details of the token to create
+    ///
+    /// # Returns
+    /// The newly created token, and the secret value of it. The secret is not stored, so this is
+    /// the only time that it is available.
+    pub async fn create_token(&self, user_id: &UserId, token: NewToken) -> Result<(TokenResource, TokenSecret), SaveTokenError> {
+        let secret = TokenSecret::generate();
+
+        let created = self
+            .repository
+            .create_token(&TokenData {
+                user_id:    user_id.clone(),
+                name:       token.name,
+                scopes:     token.scopes,
+                token_hash: secret.hash(),
+                expires:    token.expires,
+                last_used:  None,
+            })
+            .await?;
+
+        Ok((created, secret))
+    }
+
+    /// Get all of the tokens for a user.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user to get the tokens for
+    ///
+    /// # Returns
+    /// The tokens, newest first.
+    pub async fn get_tokens_for_user(&self, user_id: &UserId) -> Vec<TokenResource> {
+        self.repository.get_tokens_for_user(user_id).await
+    }
+
+    /// Authenticate a token secret, recording that the token has been used if it is valid.
+    /// The last used time is only updated once a minute at most, so this is usually a read.
+    ///
+    /// # Parameters
+    /// - `secret` - The token secret that was presented
+    ///
+    /// # Returns
+    /// The token, or `None` if it is unknown or has expired.
+    pub async fn authenticate_token(&self, secret: &str) -> Option<TokenResource> {
+        let token = self.repository.get_token_by_hash(&TokenSecret::hash_of(secret)).await?;
+
+        let now = Utc::now();
+        if token.data.expires <= now {
+            tracing::warn!(token_id = ?token.identity.id, "Personal access token has expired");
+            return None;
+        }
+
+        self.repository
+            .touch_token(&token.identity.id, now, now - Duration::seconds(TOUCH_INTERVAL_SECONDS))
+            .await;
+
+        Some(token)
+    }
+
+    /// Delete a token for a user.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user that the token belongs to
+    /// - `token_id` - The ID of the token
+    ///
+    /// # Returns
+    /// True if the token was deleted. False if it didn't exist.
+    pub async fn delete_token(&self, user_id: &UserId, token_id: &TokenId) -> bool {
+        self.repository.delete_token(user_id, token_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::tokens::MemoryTokenRepository;
+
+    fn new_token(expires: DateTime<Utc>) -> NewToken {
+        NewToken {
+            name: "Test Token".to_owned(),
+            scopes: vec![Scope::UsersRead],
+            expires,
+        }
+    }
+
+    #[actix_rt::test]
+    async fn create_and_authenticate() {
+        let sut = TokenService::new(Arc::new(MemoryTokenRepository::new()));
+        let user_id = UserId::default();
+
+        let_assert!(Ok((created, secret)) = sut.create_token(&user_id, new_token(Utc::now() + Duration::days(1))).await);
+        check!(created.data.token_hash != secret.value());
+        check!(created.data.last_used == None);
+
+        let_assert!(Some(token) = sut.authenticate_token(secret.value()).await);
+        check!(token.identity.id == created.identity.id);
+        check!(token.data.scopes == vec![Scope::UsersRead]);
+
+        let tokens = sut.get_tokens_for_user(&user_id).await;
+        check!(tokens.len() == 1);
+        check!(tokens[0].data.last_used.is_some());
+    }
+
+    #[actix_rt::test]
+    async fn authenticate_recently_used() {
+        let sut = TokenService::new(Arc::new(MemoryTokenRepository::new()));
+        let user_id = UserId::default();
+
+        let_assert!(Ok((_, secret)) = sut.create_token(&user_id, new_token(Utc::now() + Duration::days(1))).await);
+
+        let_assert!(Some(_) = sut.authenticate_token(secret.value()).await);
+        let first_used = sut.get_tokens_for_user(&user_id).await[0].data.last_used;
+
+        let_assert!(Some(_) = sut.authenticate_token(secret.value()).await);
+        let second_used = sut.get_tokens_for_user(&user_id).await[0].data.last_used;
+
+        check!(first_used.is_some());
+        check!(second_used == first_used);
+    }
+
+    #[actix_rt::test]
+    async fn authenticate_unknown() {
+        let sut = TokenService::new(Arc::new(MemoryTokenRepository::new()));
+
+        check!(sut.authenticate_token("wpat_unknown").await.is_none());
+    }
+
+    #[actix_rt::test]
+    async fn authenticate_expired() {
+        let sut = TokenService::new(Arc::new(MemoryTokenRepository::new()));
+        let user_id = UserId::default();
+
+        let_assert!(Ok((_, secret)) = sut.create_token(&user_id, new_token(Utc::now() - Duration::seconds(1))).await);
+
+        check!(sut.authenticate_token(secret.value()).await.is_none());
+    }
+
+    #[actix_rt::test]
+    async fn delete_token() {
+        let sut = TokenService::new(Arc::new(MemoryTokenRepository::new()));
+        let user_id = UserId::default();
+
+        let_assert!(Ok((created, secret)) = sut.create_token(&user_id, new_token(Utc::now() + Duration::days(1))).await);
+
+        check!(!sut.delete_token(&UserId::default(), &created.identity.id).await);
+        check!(sut.delete_token(&user_id, &created.identity.id).await);
+        check!(!sut.delete_token(&user_id, &created.identity.id).await);
+        check!(sut.authenticate_token(secret.value()).await.is_none());
+    }
+}
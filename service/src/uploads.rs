@@ -0,0 +1,11 @@
+pub mod component;
+mod endpoints;
+mod images;
+mod model;
+mod repository;
+mod service;
+mod sniff;
+
+pub use model::*;
+pub use repository::*;
+pub use service::*;
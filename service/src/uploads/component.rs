@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use actix_web::web::{delete, get, post, put, resource, ServiceConfig};
+
+use super::{repository::UploadRepository, service::UploadService};
+use crate::{blobs::BlobStore, entities::EntityService, server::RouteConfigurer};
+
+/// Component for working with uploaded files.
+pub struct Component {
+    pub service: Arc<UploadService>,
+}
+
+impl Component {
+    /// Create a new uploads component.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to store the details of uploads in
+    /// - `store` - The blob store to store the contents of uploads in
+    /// - `entities` - The service to check the entities that uploads are attached to against
+    /// - `max_size` - The largest file that can be uploaded, in bytes
+    pub fn new(
+        repository: Arc<dyn UploadRepository>,
+        store: Arc<dyn BlobStore>,
+        entities: Arc<EntityService>,
+        max_size: usize,
+    ) -> Arc<Self> {
+        let service = Arc::new(UploadService::new(repository, store, entities, max_size));
+
+        Arc::new(Self { service })
+    }
+}
+
+impl RouteConfigurer for Component {
+    fn configure_routes(&self, config: &mut ServiceConfig) {
+        config.data(self.service.clone());
+
+        config.service(
+            resource("/worlds/{id}/uploads")
+                .route(post().to(super::endpoints::create_upload::handle))
+                .route(get().to(super::endpoints::list_uploads::handle)),
+        );
+        config.service(
+            resource("/worlds/{id}/uploads/{upload}")
+                .route(get().to(super::endpoints::get_upload::handle))
+                .route(delete().to(super::endpoints::delete_upload::handle)),
+        );
+        config.service(resource("/worlds/{id}/uploads/{upload}/content").route(get().to(super::endpoints::get_content::original)));
+        config.service(resource("/worlds/{id}/uploads/{upload}/thumbnail").route(get().to(super::endpoints::get_content::thumbnail)));
+        config.service(
+            resource("/users/{id}/avatar")
+                .route(put().to(super::endpoints::set_avatar::handle))
+                .route(get().to(super::endpoints::get_avatar::original))
+                .route(delete().to(super::endpoints::delete_avatar::handle)),
+        );
+        config.service(resource("/users/{id}/avatar/thumbnail").route(get().to(super::endpoints::get_avatar::thumbnail)));
+    }
+}
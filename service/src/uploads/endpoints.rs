@@ -0,0 +1,155 @@
+pub(super) mod create_upload;
+pub(super) mod delete_avatar;
+pub(super) mod delete_upload;
+pub(super) mod get_avatar;
+pub(super) mod get_content;
+pub(super) mod get_upload;
+pub(super) mod list_uploads;
+mod model;
+mod problems;
+pub(super) mod set_avatar;
+
+use std::convert::TryFrom;
+
+use actix_http::http::{header, StatusCode};
+use actix_web::{
+    http::header::{
+        CacheControl, CacheDirective, ContentDisposition, DispositionParam, DispositionType, ETag, EntityTag, Header, IfNoneMatch,
+    },
+    HttpRequest, HttpResponse,
+};
+
+use self::problems::{unknown_entity, FILE_REQUIRED, INVALID_IMAGE, UNSUPPORTED_TYPE};
+use crate::{
+    blobs::ByteRange,
+    http::problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND, PAYLOAD_TOO_LARGE},
+    uploads::{CreateUploadError, UploadId, UploadResource, UploadService, UploadVariant},
+    worlds::WorldId,
+};
+
+/// Parse the world and upload IDs from the URL.
+///
+/// # Parameters
+/// - `world_id` - The ID of the world
+/// - `upload_id` - The ID of the upload
+///
+/// # Returns
+/// The parsed IDs, or a Not Found problem if either of them isn't valid.
+fn parse_upload_path(world_id: &str, upload_id: &str) -> Result<(WorldId, UploadId), Problem> {
+    let world_id: WorldId = world_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, world_id = ?world_id, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    let upload_id: UploadId = upload_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, upload_id = ?upload_id, "Failed to parse Upload ID");
+
+        NOT_FOUND
+    })?;
+
+    Ok((world_id, upload_id))
+}
+
+/// Convert an error from creating an upload into the problem to return to the client.
+///
+/// # Parameters
+/// - `e` - The error
+fn create_problem(e: CreateUploadError) -> Problem {
+    match e {
+        CreateUploadError::Empty => FILE_REQUIRED.into(),
+        CreateUploadError::TooLarge(max_size) => Problem::from(PAYLOAD_TOO_LARGE).with_extra("maxSize", max_size),
+        CreateUploadError::UnsupportedType(content_type) => Problem::from(UNSUPPORTED_TYPE).with_extra("contentType", content_type),
+        CreateUploadError::InvalidImage(e) => Problem::from(INVALID_IMAGE).with_detail(e.to_string()),
+        CreateUploadError::UnknownEntity(entity_id) => unknown_entity(&entity_id),
+        CreateUploadError::UnknownError => INTERNAL_SERVER_ERROR.into(),
+    }
+}
+
+/// Build the response to download the contents of an upload.
+///
+/// This supports conditional requests using `If-None-Match`, and requests for a single range of
+/// bytes using `Range`.
+///
+/// # Parameters
+/// - `service` - The service to read the contents of the upload with
+/// - `request` - The incoming request
+/// - `upload` - The upload to download
+/// - `variant` - The variant of the upload to download
+///
+/// # Returns
+/// The response containing the requested bytes.
+async fn download(
+    service: &UploadService,
+    request: &HttpRequest,
+    upload: &UploadResource,
+    variant: UploadVariant,
+) -> Result<HttpResponse, Problem> {
+    let (_, size, content_type) = upload.data.variant(variant).ok_or(NOT_FOUND)?;
+    let size = u64::try_from(size).map_err(|_| INTERNAL_SERVER_ERROR)?;
+
+    let etag = match variant {
+        UploadVariant::Original => EntityTag::strong(upload.data.checksum.clone()),
+        UploadVariant::Thumbnail => EntityTag::strong(format!("{}-thumbnail", upload.data.checksum)),
+    };
+
+    let mut response = HttpResponse::Ok();
+    response
+        .insert_header(ETag(etag.clone()))
+        .insert_header(CacheControl(vec![CacheDirective::Private, CacheDirective::NoCache]))
+        .insert_header((header::ACCEPT_RANGES, "bytes"));
+
+    let not_modified = match IfNoneMatch::parse(request) {
+        Ok(IfNoneMatch::Any) => true,
+        Ok(IfNoneMatch::Items(tags)) => tags.iter().any(|tag| tag.weak_eq(&etag)),
+        Err(_) => false,
+    };
+    if not_modified {
+        return Ok(response.status(StatusCode::NOT_MODIFIED).finish());
+    }
+
+    let range = match request.headers().get(header::RANGE).and_then(|value| value.to_str().ok()) {
+        Some(value) => match ByteRange::parse(value, size) {
+            Ok(range) => range,
+            Err(_) => {
+                return Ok(response
+                    .status(StatusCode::RANGE_NOT_SATISFIABLE)
+                    .insert_header((header::CONTENT_RANGE, format!("bytes */{}", size)))
+                    .finish())
+            },
+        },
+        None => None,
+    };
+
+    let data = service
+        .read_upload(upload, variant, range.as_ref())
+        .await
+        .map_err(|e| {
+            tracing::warn!(e = ?e, upload_id = ?upload.identity.id, "Failed to read upload");
+
+            INTERNAL_SERVER_ERROR
+        })?
+        .ok_or_else(|| {
+            tracing::warn!(upload_id = ?upload.identity.id, "Contents of upload are missing");
+
+            NOT_FOUND
+        })?;
+
+    if let Some(range) = range {
+        response
+            .status(StatusCode::PARTIAL_CONTENT)
+            .insert_header((header::CONTENT_RANGE, format!("bytes {}-{}/{}", range.start, range.end, size)));
+    }
+
+    if let (UploadVariant::Original, Some(filename)) = (variant, &upload.data.filename) {
+        response.insert_header(ContentDisposition {
+            disposition: DispositionType::Inline,
+            parameters:  vec![DispositionParam::Filename(filename.clone())],
+        });
+    }
+
+    Ok(response
+        .content_type(content_type)
+        .insert_header((header::X_CONTENT_TYPE_OPTIONS, "nosniff"))
+        .body(data))
+}
@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use actix_http::http::StatusCode;
+use actix_multipart::Multipart;
+use actix_web::web::{Data, Path};
+
+use super::{
+    create_problem,
+    model::{respond, UploadResponse},
+    problems::{FILE_REQUIRED, INVALID_PURPOSE, UNKNOWN_ENTITY},
+};
+use crate::{
+    authorization::Authentication,
+    entities::EntityId,
+    http::{
+        multipart::MultipartForm,
+        problem::{Problem, NOT_FOUND},
+    },
+    tokens::Scope,
+    uploads::{NewUpload, UploadPurpose, UploadService},
+    worlds::{Role, WorldId, WorldService},
+};
+
+/// Upload a new file to a world.
+///
+/// The request is a `multipart/form-data` form with the fields:
+/// - `file` - The file itself
+/// - `purpose` - What the file is for - `portrait`, `map` or `handout`
+/// - `entityId` - Optionally, the ID of the entity in the world to attach the file to
+pub async fn handle(
+    service: Data<Arc<UploadService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<String>,
+    payload: Multipart,
+    authentication: Authentication,
+) -> Result<UploadResponse, Problem> {
+    let world_id: WorldId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+    let user_id = authentication.require_user()?;
+
+    let form = MultipartForm::read(payload, service.max_size()).await?;
+
+    let purpose = form
+        .field("purpose")
+        .and_then(|purpose| purpose.parse().ok())
+        .filter(|purpose| *purpose != UploadPurpose::Avatar)
+        .ok_or(INVALID_PURPOSE)?;
+
+    let entity_id = form
+        .field("entityId")
+        .map(|entity_id| {
+            entity_id
+                .parse::<EntityId>()
+                .map_err(|_| Problem::from(UNKNOWN_ENTITY).with_extra("entityId", entity_id))
+        })
+        .transpose()?;
+
+    let file = form.file.ok_or(FILE_REQUIRED)?;
+
+    let upload = service
+        .create_upload(NewUpload {
+            owner_id: user_id,
+            world_id: Some(world_id),
+            entity_id,
+            purpose,
+            filename: file.filename,
+            data: file.data,
+        })
+        .await
+        .map_err(create_problem)?;
+
+    Ok(respond(upload).with_status_code(StatusCode::CREATED).into())
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use actix_web::{
+    web::{Data, Path},
+    HttpResponse,
+};
+
+use crate::{
+    authorization::{Authentication, Principal},
+    http::problem::{Problem, FORBIDDEN, NOT_FOUND},
+    tokens::Scope,
+    uploads::UploadService,
+    users::UserId,
+};
+
+pub async fn handle(
+    service: Data<Arc<UploadService>>,
+    path: Path<String>,
+    authentication: Authentication,
+) -> Result<HttpResponse, Problem> {
+    let user_id: UserId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse User ID");
+
+        FORBIDDEN
+    })?;
+
+    authentication.same_principal(&Principal::from(&user_id))?;
+    authentication.require_scope(Scope::UsersWrite)?;
+
+    if service.delete_avatar(&user_id).await {
+        Ok(HttpResponse::NoContent().finish())
+    } else {
+        Err(NOT_FOUND.into())
+    }
+}
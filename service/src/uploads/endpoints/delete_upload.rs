@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use actix_web::{
+    web::{Data, Path},
+    HttpResponse,
+};
+
+use super::parse_upload_path;
+use crate::{
+    authorization::Authentication,
+    http::problem::{Problem, NOT_FOUND},
+    tokens::Scope,
+    uploads::UploadService,
+    worlds::{Role, WorldService},
+};
+
+pub async fn handle(
+    service: Data<Arc<UploadService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<HttpResponse, Problem> {
+    let (world_id, upload_id) = path.into_inner();
+    let (world_id, upload_id) = parse_upload_path(&world_id, &upload_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Editor).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    if service.delete_upload(&world_id, &upload_id).await {
+        Ok(HttpResponse::NoContent().finish())
+    } else {
+        Err(NOT_FOUND.into())
+    }
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use actix_web::{
+    web::{Data, Path},
+    HttpRequest, HttpResponse,
+};
+
+use super::download;
+use crate::{
+    http::problem::{Problem, NOT_FOUND},
+    uploads::{UploadService, UploadVariant},
+    users::UserId,
+};
+
+/// Download the avatar of a user. Avatars are visible to everyone.
+pub async fn original(service: Data<Arc<UploadService>>, path: Path<String>, request: HttpRequest) -> Result<HttpResponse, Problem> {
+    handle(&service, &path, &request, UploadVariant::Original).await
+}
+
+/// Download the thumbnail of the avatar of a user.
+pub async fn thumbnail(service: Data<Arc<UploadService>>, path: Path<String>, request: HttpRequest) -> Result<HttpResponse, Problem> {
+    handle(&service, &path, &request, UploadVariant::Thumbnail).await
+}
+
+async fn handle(service: &UploadService, user_id: &str, request: &HttpRequest, variant: UploadVariant) -> Result<HttpResponse, Problem> {
+    let user_id: UserId = user_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, user_id = ?user_id, "Failed to parse User ID");
+
+        NOT_FOUND
+    })?;
+
+    let avatar = service.get_avatar(&user_id).await.ok_or(NOT_FOUND)?;
+
+    download(service, request, &avatar, variant).await
+}
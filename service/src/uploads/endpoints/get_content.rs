@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use actix_web::{
+    web::{Data, Path},
+    HttpRequest, HttpResponse,
+};
+
+use super::{download, parse_upload_path};
+use crate::{
+    authorization::Authentication,
+    http::problem::{Problem, NOT_FOUND},
+    tokens::Scope,
+    uploads::{UploadService, UploadVariant},
+    worlds::{Role, WorldService},
+};
+
+/// Download the contents of an upload exactly as they were uploaded.
+pub async fn original(
+    service: Data<Arc<UploadService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    request: HttpRequest,
+    authentication: Authentication,
+) -> Result<HttpResponse, Problem> {
+    handle(&service, &worlds_service, path, &request, &authentication, UploadVariant::Original).await
+}
+
+/// Download the thumbnail of an uploaded image.
+pub async fn thumbnail(
+    service: Data<Arc<UploadService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    request: HttpRequest,
+    authentication: Authentication,
+) -> Result<HttpResponse, Problem> {
+    handle(&service, &worlds_service, path, &request, &authentication, UploadVariant::Thumbnail).await
+}
+
+async fn handle(
+    service: &UploadService,
+    worlds_service: &WorldService,
+    path: Path<(String, String)>,
+    request: &HttpRequest,
+    authentication: &Authentication,
+    variant: UploadVariant,
+) -> Result<HttpResponse, Problem> {
+    let (world_id, upload_id) = path.into_inner();
+    let (world_id, upload_id) = parse_upload_path(&world_id, &upload_id)?;
+
+    authentication.require_world_role(worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let upload = service.get_upload(&world_id, &upload_id).await.ok_or(NOT_FOUND)?;
+
+    download(service, request, &upload, variant).await
+}
@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::{
+    model::{respond, UploadResponse},
+    parse_upload_path,
+};
+use crate::{
+    authorization::Authentication,
+    http::problem::{Problem, NOT_FOUND},
+    tokens::Scope,
+    uploads::UploadService,
+    worlds::{Role, WorldService},
+};
+
+pub async fn handle(
+    service: Data<Arc<UploadService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<UploadResponse, Problem> {
+    let (world_id, upload_id) = path.into_inner();
+    let (world_id, upload_id) = parse_upload_path(&world_id, &upload_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let upload = service.get_upload(&world_id, &upload_id).await.ok_or(NOT_FOUND)?;
+
+    Ok(respond(upload).into())
+}
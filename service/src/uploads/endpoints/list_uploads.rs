@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path, Query};
+use serde::Deserialize;
+
+use super::model::{UploadModel, UploadsModel};
+use crate::{
+    authorization::Authentication,
+    entities::EntityId,
+    http::{
+        problem::{Problem, NOT_FOUND},
+        response::Response,
+    },
+    tokens::Scope,
+    uploads::UploadService,
+    worlds::{Role, WorldId, WorldService},
+};
+
+pub async fn handle(
+    service: Data<Arc<UploadService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<String>,
+    query: Query<ListUploadsQuery>,
+    authentication: Authentication,
+) -> Result<Response<UploadsModel>, Problem> {
+    let world_id: WorldId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Viewer).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    // An entity that can't exist has no uploads attached to it.
+    let entity_id = match query.entity.as_deref().map(str::parse::<EntityId>) {
+        Some(Ok(entity_id)) => Some(entity_id),
+        Some(Err(_)) => return Ok(Response(UploadsModel { uploads: vec![] })),
+        None => None,
+    };
+
+    let uploads = service
+        .get_uploads(&world_id, entity_id.as_ref())
+        .await
+        .into_iter()
+        .map(UploadModel::from)
+        .collect();
+
+    Ok(Response(UploadsModel { uploads }))
+}
+
+/// Query parameters for listing the uploads in a world.
+#[derive(Deserialize)]
+pub struct ListUploadsQuery {
+    /// Only list the uploads attached to this entity.
+    pub entity: Option<String>,
+}
@@ -0,0 +1,64 @@
+use actix_web::http::header::{CacheControl, CacheDirective, ETag, EntityTag};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::{
+    entities::EntityId,
+    http::response::{Response, SimpleRespondable},
+    uploads::{UploadId, UploadPurpose, UploadResource},
+};
+
+/// Representation of an upload on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadModel {
+    pub upload_id:     UploadId,
+    pub purpose:       UploadPurpose,
+    pub filename:      Option<String>,
+    pub content_type:  String,
+    pub size:          i64,
+    pub checksum:      String,
+    pub width:         Option<i32>,
+    pub height:        Option<i32>,
+    pub entity_id:     Option<EntityId>,
+    pub has_thumbnail: bool,
+    pub created:       DateTime<Utc>,
+}
+
+impl From<UploadResource> for UploadModel {
+    fn from(upload: UploadResource) -> Self {
+        Self {
+            upload_id:     upload.identity.id,
+            purpose:       upload.data.purpose,
+            filename:      upload.data.filename,
+            content_type:  upload.data.content_type,
+            size:          upload.data.size,
+            checksum:      upload.data.checksum,
+            width:         upload.data.width,
+            height:        upload.data.height,
+            entity_id:     upload.data.entity_id,
+            has_thumbnail: upload.data.thumbnail_key.is_some(),
+            created:       upload.identity.created,
+        }
+    }
+}
+
+pub type UploadResponse = Response<SimpleRespondable<UploadModel>>;
+
+/// Build the response for a single upload.
+///
+/// # Parameters
+/// - `upload` - The upload
+pub fn respond(upload: UploadResource) -> SimpleRespondable<UploadModel> {
+    let etag = EntityTag::strong(upload.identity.version.to_string());
+
+    SimpleRespondable::new(UploadModel::from(upload))
+        .with_header(ETag(etag))
+        .with_header(CacheControl(vec![CacheDirective::Private, CacheDirective::NoCache]))
+}
+
+/// Representation of a list of uploads on the HTTP API.
+#[derive(Serialize)]
+pub struct UploadsModel {
+    pub uploads: Vec<UploadModel>,
+}
@@ -0,0 +1,49 @@
+use actix_http::http::StatusCode;
+
+use crate::{
+    entities::EntityId,
+    http::problem::{Problem, SimpleProblemType},
+};
+
+/// Problem to indicate that no file, or an empty file, was uploaded.
+pub const FILE_REQUIRED: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/uploads/file_required",
+    problem_title: "A non-empty file is required",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate that the purpose of an upload is missing or not one that is permitted.
+pub const INVALID_PURPOSE: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/uploads/invalid_purpose",
+    problem_title: "Invalid purpose for the upload",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate that the type of the uploaded file isn't supported for its purpose.
+pub const UNSUPPORTED_TYPE: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/uploads/unsupported_type",
+    problem_title: "The type of file is not supported",
+    status_code:   StatusCode::UNSUPPORTED_MEDIA_TYPE,
+};
+
+/// Problem to indicate that an uploaded image couldn't be processed.
+pub const INVALID_IMAGE: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/uploads/invalid_image",
+    problem_title: "The image is not valid",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate that an upload is attached to an entity that doesn't exist in the world.
+pub const UNKNOWN_ENTITY: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/uploads/unknown_entity",
+    problem_title: "Unknown Entity",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Build the problem for an upload attached to an entity that doesn't exist.
+///
+/// # Parameters
+/// - `entity_id` - The ID of the entity that doesn't exist
+pub fn unknown_entity(entity_id: &EntityId) -> Problem {
+    Problem::from(UNKNOWN_ENTITY).with_extra("entityId", entity_id)
+}
@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use actix_multipart::Multipart;
+use actix_web::web::{Data, Path};
+
+use super::{
+    create_problem,
+    model::{respond, UploadResponse},
+    problems::FILE_REQUIRED,
+};
+use crate::{
+    authorization::{Authentication, Principal},
+    http::{
+        multipart::MultipartForm,
+        problem::{Problem, FORBIDDEN},
+    },
+    tokens::Scope,
+    uploads::UploadService,
+    users::UserId,
+};
+
+/// Set the avatar of a user, replacing any that they already had.
+///
+/// The request is a `multipart/form-data` form with a single `file` field containing the image.
+pub async fn handle(
+    service: Data<Arc<UploadService>>,
+    path: Path<String>,
+    payload: Multipart,
+    authentication: Authentication,
+) -> Result<UploadResponse, Problem> {
+    let user_id: UserId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse User ID");
+
+        FORBIDDEN
+    })?;
+
+    authentication.same_principal(&Principal::from(&user_id))?;
+    authentication.require_scope(Scope::UsersWrite)?;
+
+    let form = MultipartForm::read(payload, service.max_size()).await?;
+    let file = form.file.ok_or(FILE_REQUIRED)?;
+
+    let avatar = service
+        .set_avatar(&user_id, file.filename, file.data)
+        .await
+        .map_err(create_problem)?;
+
+    Ok(respond(avatar).into())
+}
@@ -0,0 +1,124 @@
+use std::io::Cursor;
+
+use bytes::Bytes;
+use image::{io::Reader, ImageOutputFormat};
+
+/// The largest width or height of a thumbnail, in pixels.
+pub const THUMBNAIL_SIZE: u32 = 256;
+
+/// The largest width or height of an image that will be decoded, in pixels. This protects against
+/// small files that decompress into enormous images.
+const MAX_DIMENSION: u32 = 16_384;
+
+/// The details of an image that has been processed.
+#[derive(Debug)]
+pub struct ProcessedImage {
+    pub width:     u32,
+    pub height:    u32,
+    /// A PNG thumbnail of the image, fitting within `THUMBNAIL_SIZE` pixels in each direction.
+    pub thumbnail: Bytes,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ImageError {
+    #[error("The image could not be decoded")]
+    Undecodable,
+
+    #[error("The image is too large: {0}x{1}")]
+    TooLarge(u32, u32),
+}
+
+/// Decode an image to determine its size and generate a thumbnail of it.
+///
+/// This is expensive, so should not be called on the async executor.
+///
+/// # Parameters
+/// - `data` - The contents of the image file
+///
+/// # Returns
+/// The details of the image.
+pub fn process_image(data: &[u8]) -> Result<ProcessedImage, ImageError> {
+    let reader = || {
+        Reader::new(Cursor::new(data))
+            .with_guessed_format()
+            .map_err(|_| ImageError::Undecodable)
+    };
+
+    let (width, height) = reader()?.into_dimensions().map_err(|_| ImageError::Undecodable)?;
+    if width > MAX_DIMENSION || height > MAX_DIMENSION {
+        return Err(ImageError::TooLarge(width, height));
+    }
+
+    let image = reader()?.decode().map_err(|e| {
+        tracing::warn!(e = ?e, "Failed to decode image");
+
+        ImageError::Undecodable
+    })?;
+
+    // Small images are used as they are, since generating the thumbnail would scale them up.
+    let small = if width > THUMBNAIL_SIZE || height > THUMBNAIL_SIZE {
+        image.thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE)
+    } else {
+        image
+    };
+
+    let mut thumbnail = vec![];
+    small.write_to(&mut thumbnail, ImageOutputFormat::Png).map_err(|e| {
+        tracing::warn!(e = ?e, "Failed to encode thumbnail");
+
+        ImageError::Undecodable
+    })?;
+
+    Ok(ProcessedImage {
+        width,
+        height,
+        thumbnail: Bytes::from(thumbnail),
+    })
+}
+
+#[cfg(test)]
+pub mod tests {
+    use assert2::{check, let_assert};
+    use image::{DynamicImage, GenericImageView, RgbImage};
+
+    use super::*;
+
+    /// Generate a PNG image of the given size.
+    pub fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut data = vec![];
+        DynamicImage::ImageRgb8(RgbImage::new(width, height))
+            .write_to(&mut data, ImageOutputFormat::Png)
+            .unwrap();
+
+        data
+    }
+
+    #[test]
+    fn process_landscape_image() {
+        let_assert!(Ok(processed) = process_image(&png(600, 300)));
+
+        check!(processed.width == 600);
+        check!(processed.height == 300);
+
+        let_assert!(Ok(thumbnail) = image::load_from_memory(&processed.thumbnail));
+        check!(thumbnail.dimensions() == (256, 128));
+    }
+
+    #[test]
+    fn process_small_image() {
+        let_assert!(Ok(processed) = process_image(&png(20, 40)));
+
+        let_assert!(Ok(thumbnail) = image::load_from_memory(&processed.thumbnail));
+        check!(thumbnail.dimensions() == (20, 40));
+    }
+
+    #[test]
+    fn process_truncated_image() {
+        let data = png(100, 100);
+
+        let result = process_image(&data[..data.len() / 2]);
+
+        let_assert!(Err(e) = result);
+        check!(e == ImageError::Undecodable);
+    }
+}
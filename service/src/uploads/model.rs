@@ -0,0 +1,71 @@
+mod purpose;
+mod upload_id;
+
+pub use purpose::*;
+pub use upload_id::*;
+
+use crate::{entities::EntityId, model::Resource, users::UserId, worlds::WorldId};
+
+/// The data representing an uploaded file.
+///
+/// The contents of the file are kept in the blob store, and this records where to find them.
+#[derive(Debug, Clone)]
+pub struct UploadData {
+    /// The user that uploaded the file.
+    pub owner_id:       UserId,
+    /// The world that the file belongs to. Avatars belong to users rather than worlds.
+    pub world_id:       Option<WorldId>,
+    /// The entity within the world that the file is attached to, if any.
+    pub entity_id:      Option<EntityId>,
+    pub purpose:        UploadPurpose,
+    /// The filename that was provided when the file was uploaded.
+    pub filename:       Option<String>,
+    /// The media type of the file, as determined from its contents.
+    pub content_type:   String,
+    /// The size of the file, in bytes.
+    pub size:           i64,
+    /// The SHA-256 digest of the file, in hex.
+    pub checksum:       String,
+    /// The key of the file in the blob store.
+    pub blob_key:       String,
+    /// The width of the file in pixels, if it is an image.
+    pub width:          Option<i32>,
+    /// The height of the file in pixels, if it is an image.
+    pub height:         Option<i32>,
+    /// The key of the thumbnail of the file in the blob store, if it is an image.
+    pub thumbnail_key:  Option<String>,
+    /// The size of the thumbnail, in bytes.
+    pub thumbnail_size: Option<i64>,
+}
+
+/// Type representing a persisted upload.
+pub type UploadResource = Resource<UploadId, UploadData>;
+
+/// The different forms that the contents of an upload can be retrieved in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UploadVariant {
+    /// The file exactly as it was uploaded.
+    Original,
+    /// A small PNG version of an image.
+    Thumbnail,
+}
+
+impl UploadData {
+    /// Get the details of one of the variants of the upload.
+    ///
+    /// # Parameters
+    /// - `variant` - The variant to get
+    ///
+    /// # Returns
+    /// The blob key, size and media type of the variant, or `None` if the upload doesn't have it.
+    pub fn variant(&self, variant: UploadVariant) -> Option<(&str, i64, &str)> {
+        match variant {
+            UploadVariant::Original => Some((self.blob_key.as_str(), self.size, self.content_type.as_str())),
+            UploadVariant::Thumbnail => self
+                .thumbnail_key
+                .as_deref()
+                .zip(self.thumbnail_size)
+                .map(|(key, size)| (key, size, "image/png")),
+        }
+    }
+}
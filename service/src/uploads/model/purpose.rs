@@ -0,0 +1,118 @@
+use std::str::FromStr;
+
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use serde::Serialize;
+
+/// What an uploaded file is used for, which determines the types of file that are acceptable.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UploadPurpose {
+    /// The avatar of a user.
+    Avatar,
+    /// A picture of an entity within a world.
+    Portrait,
+    /// A map of some part of a world.
+    Map,
+    /// A document to hand out to the players of a game.
+    Handout,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ParseUploadPurposeError {
+    #[error("Unknown upload purpose: {0}")]
+    Unknown(String),
+}
+
+impl UploadPurpose {
+    /// Every purpose of upload.
+    pub const ALL: [UploadPurpose; 4] = [Self::Avatar, Self::Portrait, Self::Map, Self::Handout];
+
+    /// The identifier of the purpose, as used on the API and in the database.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Avatar => "avatar",
+            Self::Portrait => "portrait",
+            Self::Map => "map",
+            Self::Handout => "handout",
+        }
+    }
+
+    /// Determine whether a type of file is acceptable for this purpose.
+    ///
+    /// # Parameters
+    /// - `content_type` - The media type of the file
+    pub fn accepts(self, content_type: &str) -> bool {
+        let is_image = content_type.starts_with("image/");
+
+        match self {
+            Self::Avatar | Self::Portrait | Self::Map => is_image,
+            Self::Handout => is_image || content_type == "application/pdf" || content_type.starts_with("text/"),
+        }
+    }
+}
+
+impl std::fmt::Display for UploadPurpose {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for UploadPurpose {
+    type Err = ParseUploadPurposeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|p| p.as_str() == s)
+            .copied()
+            .ok_or_else(|| ParseUploadPurposeError::Unknown(s.to_owned()))
+    }
+}
+
+impl<'a> FromSql<'a> for UploadPurpose {
+    accepts!(TEXT, VARCHAR);
+
+    fn from_sql(t: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        let value = <&str>::from_sql(t, raw)?;
+
+        Ok(value.parse()?)
+    }
+}
+
+impl ToSql for UploadPurpose {
+    accepts!(TEXT, VARCHAR);
+    to_sql_checked!();
+
+    fn to_sql(&self, t: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.as_str().to_sql(t, w)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(UploadPurpose::Avatar, "image/png", true ; "Avatar image")]
+    #[test_case(UploadPurpose::Avatar, "application/pdf", false ; "Avatar document")]
+    #[test_case(UploadPurpose::Map, "image/jpeg", true ; "Map image")]
+    #[test_case(UploadPurpose::Map, "text/plain", false ; "Map text")]
+    #[test_case(UploadPurpose::Handout, "application/pdf", true ; "Handout document")]
+    #[test_case(UploadPurpose::Handout, "text/plain", true ; "Handout text")]
+    #[test_case(UploadPurpose::Handout, "application/zip", false ; "Handout archive")]
+    fn test_accepts(purpose: UploadPurpose, content_type: &str, expected: bool) {
+        check!(purpose.accepts(content_type) == expected);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for purpose in &UploadPurpose::ALL {
+            let_assert!(Ok(parsed) = purpose.as_str().parse::<UploadPurpose>());
+            check!(&parsed == purpose);
+            check!(serde_json::to_value(purpose).unwrap() == purpose.as_str());
+        }
+    }
+}
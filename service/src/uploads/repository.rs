@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod memory;
+mod parse;
+mod postgres;
+mod save_upload;
+
+#[cfg(test)]
+pub use memory::MemoryUploadRepository;
+pub use postgres::PostgresUploadRepository;
+pub use save_upload::SaveUploadError;
+
+use super::{UploadData, UploadId, UploadPurpose, UploadResource};
+use crate::{entities::EntityId, users::UserId, worlds::WorldId};
+
+/// Repository of the details of uploaded files.
+#[async_trait::async_trait]
+pub trait UploadRepository: Send + Sync {
+    /// Record a new upload.
+    ///
+    /// # Parameters
+    /// - `upload` - The details of the upload.
+    ///
+    /// # Returns
+    /// The created upload.
+    async fn create_upload(&self, upload: &UploadData) -> Result<UploadResource, SaveUploadError>;
+
+    /// Get a single upload.
+    ///
+    /// # Parameters
+    /// - `upload_id` - The ID of the upload.
+    ///
+    /// # Returns
+    /// The upload, or `None` if it couldn't be found.
+    async fn get_upload(&self, upload_id: &UploadId) -> Option<UploadResource>;
+
+    /// Get the uploads that belong to a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world.
+    /// - `entity_id` - If provided, only get the uploads that are attached to this entity.
+    ///
+    /// # Returns
+    /// The uploads, oldest first.
+    async fn get_world_uploads(&self, world_id: &WorldId, entity_id: Option<&EntityId>) -> Vec<UploadResource>;
+
+    /// Get the uploads that a user has made for a particular purpose.
+    ///
+    /// # Parameters
+    /// - `owner_id` - The ID of the user.
+    /// - `purpose` - The purpose of the uploads.
+    ///
+    /// # Returns
+    /// The uploads, newest first.
+    async fn get_owned_uploads(&self, owner_id: &UserId, purpose: UploadPurpose) -> Vec<UploadResource>;
+
+    /// Delete the record of a single upload.
+    ///
+    /// # Parameters
+    /// - `upload_id` - The ID of the upload.
+    ///
+    /// # Returns
+    /// True if the upload existed and was deleted. False if not.
+    async fn delete_upload(&self, upload_id: &UploadId) -> bool;
+}
@@ -0,0 +1,75 @@
+use std::sync::Mutex;
+
+use super::{SaveUploadError, UploadRepository};
+use crate::{
+    entities::EntityId,
+    model::Identity,
+    uploads::{UploadData, UploadId, UploadPurpose, UploadResource},
+    users::UserId,
+    worlds::WorldId,
+};
+
+/// Repository of upload details that are stored in memory.
+#[derive(Default)]
+pub struct MemoryUploadRepository {
+    uploads: Mutex<Vec<UploadResource>>,
+}
+
+impl MemoryUploadRepository {
+    /// Create a new, empty upload repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl UploadRepository for MemoryUploadRepository {
+    async fn create_upload(&self, upload: &UploadData) -> Result<UploadResource, SaveUploadError> {
+        let mut uploads = self.uploads.lock().unwrap();
+
+        let created = UploadResource {
+            identity: Identity::default(),
+            data:     upload.clone(),
+        };
+        uploads.push(created.clone());
+
+        Ok(created)
+    }
+
+    async fn get_upload(&self, upload_id: &UploadId) -> Option<UploadResource> {
+        let uploads = self.uploads.lock().unwrap();
+
+        uploads.iter().find(|u| &u.identity.id == upload_id).cloned()
+    }
+
+    async fn get_world_uploads(&self, world_id: &WorldId, entity_id: Option<&EntityId>) -> Vec<UploadResource> {
+        let uploads = self.uploads.lock().unwrap();
+
+        uploads
+            .iter()
+            .filter(|u| u.data.world_id.as_ref() == Some(world_id))
+            .filter(|u| entity_id.is_none_or(|entity_id| u.data.entity_id.as_ref() == Some(entity_id)))
+            .cloned()
+            .collect()
+    }
+
+    async fn get_owned_uploads(&self, owner_id: &UserId, purpose: UploadPurpose) -> Vec<UploadResource> {
+        let uploads = self.uploads.lock().unwrap();
+
+        uploads
+            .iter()
+            .rev()
+            .filter(|u| &u.data.owner_id == owner_id && u.data.purpose == purpose)
+            .cloned()
+            .collect()
+    }
+
+    async fn delete_upload(&self, upload_id: &UploadId) -> bool {
+        let mut uploads = self.uploads.lock().unwrap();
+
+        let before = uploads.len();
+        uploads.retain(|u| &u.identity.id != upload_id);
+
+        uploads.len() != before
+    }
+}
@@ -0,0 +1,34 @@
+use tokio_postgres::Row;
+
+use crate::{
+    model::Identity,
+    uploads::{UploadData, UploadResource},
+};
+
+impl From<Row> for UploadResource {
+    fn from(row: Row) -> Self {
+        UploadResource {
+            identity: Identity {
+                id:      row.get("upload_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data:     UploadData {
+                owner_id:       row.get("owner_id"),
+                world_id:       row.get("world_id"),
+                entity_id:      row.get("entity_id"),
+                purpose:        row.get("purpose"),
+                filename:       row.get("filename"),
+                content_type:   row.get("content_type"),
+                size:           row.get("size"),
+                checksum:       row.get("checksum"),
+                blob_key:       row.get("blob_key"),
+                width:          row.get("width"),
+                height:         row.get("height"),
+                thumbnail_key:  row.get("thumbnail_key"),
+                thumbnail_size: row.get("thumbnail_size"),
+            },
+        }
+    }
+}
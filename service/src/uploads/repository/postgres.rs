@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use super::{SaveUploadError, UploadRepository};
+use crate::{
+    database::Database,
+    entities::EntityId,
+    model::Identity,
+    uploads::{UploadData, UploadId, UploadPurpose, UploadResource},
+    users::UserId,
+    worlds::WorldId,
+};
+
+/// Repository of upload details that are stored in Postgres.
+pub struct PostgresUploadRepository {
+    database: Arc<Database>,
+}
+
+impl PostgresUploadRepository {
+    /// Create a new upload repository.
+    pub fn new(database: Arc<Database>) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait::async_trait]
+impl UploadRepository for PostgresUploadRepository {
+    #[tracing::instrument(skip(self))]
+    async fn create_upload(&self, upload: &UploadData) -> Result<UploadResource, SaveUploadError> {
+        let conn = self.database.connect().await;
+
+        let identity = Identity::<UploadId>::default();
+
+        let created: UploadResource = conn.query_one("INSERT INTO uploads(upload_id, version, created, updated, owner_id, world_id, entity_id, purpose, filename, content_type, size, checksum, blob_key, width, height, thumbnail_key, thumbnail_size) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17) RETURNING *",
+        &[
+          &identity.id,
+          &identity.version,
+          &identity.created,
+          &identity.updated,
+          &upload.owner_id,
+          &upload.world_id,
+          &upload.entity_id,
+          &upload.purpose,
+          &upload.filename,
+          &upload.content_type,
+          &upload.size,
+          &upload.checksum,
+          &upload.blob_key,
+          &upload.width,
+          &upload.height,
+          &upload.thumbnail_key,
+          &upload.thumbnail_size,
+          ])
+            .await
+            .map(UploadResource::from)?;
+
+        Ok(created)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_upload(&self, upload_id: &UploadId) -> Option<UploadResource> {
+        let conn = self.database.connect().await;
+        conn.query_opt("SELECT * FROM uploads WHERE upload_id = $1", &[&upload_id])
+            .await
+            .map_err(|e| {
+                tracing::warn!(e = ?e, "Failed to load upload");
+            })
+            .ok()
+            .flatten()
+            .map(UploadResource::from)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_world_uploads(&self, world_id: &WorldId, entity_id: Option<&EntityId>) -> Vec<UploadResource> {
+        let conn = self.database.connect().await;
+        conn.query(
+            "SELECT * FROM uploads WHERE world_id = $1 AND ($2::UUID IS NULL OR entity_id = $2) ORDER BY created",
+            &[&world_id, &entity_id],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to load uploads");
+                vec![]
+            },
+            |rows| rows.into_iter().map(UploadResource::from).collect(),
+        )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_owned_uploads(&self, owner_id: &UserId, purpose: UploadPurpose) -> Vec<UploadResource> {
+        let conn = self.database.connect().await;
+        conn.query(
+            "SELECT * FROM uploads WHERE owner_id = $1 AND purpose = $2 ORDER BY created DESC",
+            &[&owner_id, &purpose],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to load uploads");
+                vec![]
+            },
+            |rows| rows.into_iter().map(UploadResource::from).collect(),
+        )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_upload(&self, upload_id: &UploadId) -> bool {
+        let conn = self.database.connect().await;
+        conn.execute("DELETE FROM uploads WHERE upload_id = $1", &[&upload_id])
+            .await
+            .map_or_else(
+                |e| {
+                    tracing::warn!(e = ?e, "Failed to delete upload");
+                    false
+                },
+                |count| count == 1,
+            )
+    }
+}
@@ -0,0 +1,14 @@
+/// Errors that can occur when saving an upload.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveUploadError {
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl From<tokio_postgres::Error> for SaveUploadError {
+    fn from(e: tokio_postgres::Error) -> Self {
+        tracing::warn!("Unexpected database error: {:?}", e);
+
+        SaveUploadError::UnknownError
+    }
+}
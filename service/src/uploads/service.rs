@@ -0,0 +1,128 @@
+mod avatar;
+mod create_upload;
+mod delete_upload;
+mod get_upload;
+
+use std::sync::Arc;
+
+use bytes::Bytes;
+pub use create_upload::{CreateUploadError, NewUpload};
+
+use super::{repository::UploadRepository, UploadResource, UploadVariant};
+use crate::{
+    blobs::{BlobError, BlobStore, ByteRange},
+    entities::EntityService,
+};
+
+/// Service layer for working with uploaded files.
+pub struct UploadService {
+    repository: Arc<dyn UploadRepository>,
+    store:      Arc<dyn BlobStore>,
+    entities:   Arc<EntityService>,
+    max_size:   usize,
+}
+
+impl UploadService {
+    /// Create a new upload service.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to store the details of uploads in
+    /// - `store` - The blob store to store the contents of uploads in
+    /// - `entities` - The service to check the entities that uploads are attached to against
+    /// - `max_size` - The largest file that can be uploaded, in bytes
+    pub fn new(repository: Arc<dyn UploadRepository>, store: Arc<dyn BlobStore>, entities: Arc<EntityService>, max_size: usize) -> Self {
+        Self {
+            repository,
+            store,
+            entities,
+            max_size,
+        }
+    }
+
+    /// The largest file that can be uploaded, in bytes.
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    /// Read the contents of an upload.
+    ///
+    /// # Parameters
+    /// - `upload` - The upload to read
+    /// - `variant` - The variant of the upload to read
+    /// - `range` - The range of bytes to read, or `None` to read everything
+    ///
+    /// # Returns
+    /// The requested bytes, or `None` if the upload doesn't have the variant or its contents are
+    /// missing from the blob store.
+    pub async fn read_upload(
+        &self,
+        upload: &UploadResource,
+        variant: UploadVariant,
+        range: Option<&ByteRange>,
+    ) -> Result<Option<Bytes>, BlobError> {
+        match upload.data.variant(variant) {
+            Some((key, ..)) => self.store.get(key, range).await,
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        blobs::MemoryBlobStore,
+        entities::{CharacterAttributes, CustomFields, EntityId, MemoryEntityRepository, NewEntity},
+        revisions::{MemoryRevisionRepository, RevisionService},
+        search::{MemorySearchRepository, SearchService},
+        uploads::MemoryUploadRepository,
+        users::UserId,
+        worlds::WorldId,
+    };
+
+    /// Fixture for testing the upload service, with a single character already in a world.
+    pub struct Fixture {
+        pub sut:       UploadService,
+        pub store:     Arc<MemoryBlobStore>,
+        pub user_id:   UserId,
+        pub world_id:  WorldId,
+        pub character: EntityId,
+    }
+
+    pub async fn build_fixture() -> Fixture {
+        let entities = Arc::new(EntityService::new(
+            Arc::new(MemoryEntityRepository::new()),
+            Arc::new(RevisionService::new(Arc::new(MemoryRevisionRepository::new()))),
+            Arc::new(SearchService::new(Arc::new(MemorySearchRepository::new()))),
+        ));
+        let store = Arc::new(MemoryBlobStore::new());
+        let user_id = UserId::default();
+        let world_id = WorldId::default();
+
+        let character = entities
+            .create_entity(
+                &world_id,
+                &user_id,
+                NewEntity {
+                    name:       "Aragorn".to_owned(),
+                    summary:    None,
+                    attributes: CharacterAttributes::default(),
+                    custom:     CustomFields::new(),
+                },
+            )
+            .await
+            .unwrap()
+            .identity
+            .id;
+
+        Fixture {
+            sut: UploadService::new(Arc::new(MemoryUploadRepository::new()), store.clone(), entities, 64 * 1024),
+            store,
+            user_id,
+            world_id,
+            character,
+        }
+    }
+}
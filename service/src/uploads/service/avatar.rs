@@ -0,0 +1,113 @@
+use bytes::Bytes;
+
+use super::{CreateUploadError, NewUpload, UploadService};
+use crate::{
+    uploads::{UploadPurpose, UploadResource},
+    users::UserId,
+};
+
+impl UploadService {
+    /// Set the avatar of a user, replacing any avatar they already had.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user
+    /// - `filename` - The filename of the new avatar, if one was provided
+    /// - `data` - The contents of the new avatar
+    ///
+    /// # Returns
+    /// The upload of the new avatar.
+    pub async fn set_avatar(&self, user_id: &UserId, filename: Option<String>, data: Bytes) -> Result<UploadResource, CreateUploadError> {
+        let avatar = self
+            .create_upload(NewUpload {
+                owner_id: user_id.clone(),
+                world_id: None,
+                entity_id: None,
+                purpose: UploadPurpose::Avatar,
+                filename,
+                data,
+            })
+            .await?;
+
+        for previous in self.repository.get_owned_uploads(user_id, UploadPurpose::Avatar).await {
+            if previous.identity.id != avatar.identity.id {
+                self.remove(&previous).await;
+            }
+        }
+
+        Ok(avatar)
+    }
+
+    /// Get the avatar of a user.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user
+    ///
+    /// # Returns
+    /// The upload of the avatar, or `None` if the user doesn't have one.
+    pub async fn get_avatar(&self, user_id: &UserId) -> Option<UploadResource> {
+        self.repository
+            .get_owned_uploads(user_id, UploadPurpose::Avatar)
+            .await
+            .into_iter()
+            .next()
+    }
+
+    /// Remove the avatar of a user.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user
+    ///
+    /// # Returns
+    /// True if the user had an avatar that was removed. False if not.
+    pub async fn delete_avatar(&self, user_id: &UserId) -> bool {
+        let mut deleted = false;
+        for avatar in self.repository.get_owned_uploads(user_id, UploadPurpose::Avatar).await {
+            deleted |= self.remove(&avatar).await;
+        }
+
+        deleted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use bytes::Bytes;
+
+    use crate::uploads::{images::tests::png, service::tests::build_fixture, CreateUploadError};
+
+    #[actix_rt::test]
+    async fn replace_avatar() {
+        let f = build_fixture().await;
+
+        let_assert!(Ok(first) = f.sut.set_avatar(&f.user_id, None, Bytes::from(png(64, 64))).await);
+        check!(first.data.world_id == None);
+        let_assert!(Some(current) = f.sut.get_avatar(&f.user_id).await);
+        check!(current.identity.id == first.identity.id);
+
+        let_assert!(
+            Ok(second) = f
+                .sut
+                .set_avatar(&f.user_id, Some("me.png".to_owned()), Bytes::from(png(32, 32)))
+                .await
+        );
+        let_assert!(Some(current) = f.sut.get_avatar(&f.user_id).await);
+        check!(current.identity.id == second.identity.id);
+        check!(!f.store.contains(&first.data.blob_key));
+        check!(f.store.contains(&second.data.blob_key));
+
+        check!(f.sut.delete_avatar(&f.user_id).await);
+        check!(f.sut.get_avatar(&f.user_id).await.is_none());
+        check!(!f.store.contains(&second.data.blob_key));
+        check!(!f.sut.delete_avatar(&f.user_id).await);
+    }
+
+    #[actix_rt::test]
+    async fn avatar_must_be_image() {
+        let f = build_fixture().await;
+
+        let_assert!(Err(e) = f.sut.set_avatar(&f.user_id, None, Bytes::from_static(b"Not an image")).await);
+        check!(e == CreateUploadError::UnsupportedType(Some("text/plain".to_owned())));
+        check!(f.sut.get_avatar(&f.user_id).await.is_none());
+    }
+}
@@ -0,0 +1,287 @@
+use std::convert::TryFrom;
+
+use bytes::Bytes;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use super::UploadService;
+use crate::{
+    entities::EntityId,
+    uploads::{
+        images::{process_image, ImageError, ProcessedImage},
+        sniff::sniff_content_type,
+        UploadData, UploadPurpose, UploadResource,
+    },
+    users::UserId,
+    worlds::WorldId,
+};
+
+/// The details needed to upload a new file.
+#[derive(Debug)]
+pub struct NewUpload {
+    pub owner_id:  UserId,
+    pub world_id:  Option<WorldId>,
+    pub entity_id: Option<EntityId>,
+    pub purpose:   UploadPurpose,
+    pub filename:  Option<String>,
+    pub data:      Bytes,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum CreateUploadError {
+    #[error("The file is empty")]
+    Empty,
+
+    #[error("The file is larger than the limit of {0} bytes")]
+    TooLarge(usize),
+
+    #[error("The type of file is not supported for this purpose: {0:?}")]
+    UnsupportedType(Option<String>),
+
+    #[error("The image is not valid: {0}")]
+    InvalidImage(ImageError),
+
+    #[error("Unknown entity: {0}")]
+    UnknownEntity(EntityId),
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl UploadService {
+    /// Upload a new file.
+    ///
+    /// The type of the file is determined from its contents, and images have a thumbnail generated
+    /// for them.
+    ///
+    /// # Parameters
+    /// - `upload` - The details of the file to upload
+    ///
+    /// # Returns
+    /// The newly created upload.
+    pub async fn create_upload(&self, upload: NewUpload) -> Result<UploadResource, CreateUploadError> {
+        if upload.data.is_empty() {
+            return Err(CreateUploadError::Empty);
+        }
+        if upload.data.len() > self.max_size {
+            return Err(CreateUploadError::TooLarge(self.max_size));
+        }
+
+        let content_type = sniff_content_type(&upload.data);
+        let content_type = content_type
+            .filter(|content_type| upload.purpose.accepts(content_type))
+            .ok_or_else(|| CreateUploadError::UnsupportedType(content_type.map(ToOwned::to_owned)))?;
+
+        if let Some(entity_id) = &upload.entity_id {
+            let world_id = upload
+                .world_id
+                .as_ref()
+                .ok_or_else(|| CreateUploadError::UnknownEntity(entity_id.clone()))?;
+            let entities = self.entities.get_entities_by_ids(world_id, std::slice::from_ref(entity_id)).await;
+            if entities.is_empty() {
+                return Err(CreateUploadError::UnknownEntity(entity_id.clone()));
+            }
+        }
+
+        let image = if content_type.starts_with("image/") {
+            let data = upload.data.clone();
+            let processed = actix_web::web::block(move || process_image(&data))
+                .await
+                .map_err(|e| {
+                    tracing::warn!(e = ?e, "Failed to schedule image processing");
+
+                    CreateUploadError::UnknownError
+                })?
+                .map_err(CreateUploadError::InvalidImage)?;
+
+            Some(processed)
+        } else {
+            None
+        };
+
+        let blob_key = format!("uploads/{}", Uuid::new_v4());
+        let thumbnail_key = image.as_ref().map(|_| format!("{}.thumbnail", blob_key));
+
+        let data = UploadData {
+            owner_id: upload.owner_id,
+            world_id: upload.world_id,
+            entity_id: upload.entity_id,
+            purpose: upload.purpose,
+            filename: upload.filename,
+            content_type: content_type.to_owned(),
+            size: i64::try_from(upload.data.len()).map_err(|_| CreateUploadError::TooLarge(self.max_size))?,
+            checksum: format!("{:x}", Sha256::digest(&upload.data)),
+            blob_key,
+            width: image.as_ref().and_then(|i| i32::try_from(i.width).ok()),
+            height: image.as_ref().and_then(|i| i32::try_from(i.height).ok()),
+            thumbnail_key,
+            thumbnail_size: image.as_ref().and_then(|i| i64::try_from(i.thumbnail.len()).ok()),
+        };
+
+        self.store_blobs(&data, upload.data, image).await?;
+
+        self.repository.create_upload(&data).await.map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to record upload");
+
+            CreateUploadError::UnknownError
+        })
+    }
+
+    /// Store the contents of a new upload in the blob store.
+    ///
+    /// # Parameters
+    /// - `upload` - The details of the upload
+    /// - `data` - The contents of the upload
+    /// - `image` - The processed image, if the upload is an image
+    async fn store_blobs(&self, upload: &UploadData, data: Bytes, image: Option<ProcessedImage>) -> Result<(), CreateUploadError> {
+        let failed = |e| {
+            tracing::warn!(e = ?e, "Failed to store upload");
+
+            CreateUploadError::UnknownError
+        };
+
+        self.store.put(&upload.blob_key, &upload.content_type, data).await.map_err(failed)?;
+
+        if let (Some(key), Some(image)) = (&upload.thumbnail_key, image) {
+            if let Err(e) = self.store.put(key, "image/png", image.thumbnail).await {
+                self.remove_blobs(upload).await;
+
+                return Err(failed(e));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use test_case::test_case;
+
+    use super::*;
+    use crate::uploads::{images::tests::png, service::tests::build_fixture, UploadVariant};
+
+    #[actix_rt::test]
+    async fn upload_image() {
+        let f = build_fixture().await;
+
+        let result = f
+            .sut
+            .create_upload(NewUpload {
+                owner_id:  f.user_id.clone(),
+                world_id:  Some(f.world_id.clone()),
+                entity_id: Some(f.character.clone()),
+                purpose:   UploadPurpose::Portrait,
+                filename:  Some("aragorn.png".to_owned()),
+                data:      Bytes::from(png(400, 800)),
+            })
+            .await;
+
+        let_assert!(Ok(upload) = result);
+        check!(upload.data.content_type == "image/png");
+        check!(upload.data.width == Some(400));
+        check!(upload.data.height == Some(800));
+        check!(upload.data.entity_id == Some(f.character.clone()));
+        check!(upload.data.checksum.len() == 64);
+        check!(f.store.contains(&upload.data.blob_key));
+
+        let_assert!(Some(thumbnail_key) = &upload.data.thumbnail_key);
+        check!(f.store.contains(thumbnail_key));
+
+        let_assert!(Ok(Some(thumbnail)) = f.sut.read_upload(&upload, UploadVariant::Thumbnail, None).await);
+        check!(Some(i64::try_from(thumbnail.len()).unwrap()) == upload.data.thumbnail_size);
+    }
+
+    #[actix_rt::test]
+    async fn upload_text() {
+        let f = build_fixture().await;
+
+        let result = f
+            .sut
+            .create_upload(NewUpload {
+                owner_id:  f.user_id.clone(),
+                world_id:  Some(f.world_id.clone()),
+                entity_id: None,
+                purpose:   UploadPurpose::Handout,
+                filename:  None,
+                data:      Bytes::from_static(b"One Ring to rule them all"),
+            })
+            .await;
+
+        let_assert!(Ok(upload) = result);
+        check!(upload.data.content_type == "text/plain");
+        check!(upload.data.size == 25);
+        check!(upload.data.width == None);
+        check!(upload.data.thumbnail_key == None);
+
+        let_assert!(Ok(Some(data)) = f.sut.read_upload(&upload, UploadVariant::Original, None).await);
+        check!(data.as_ref() == b"One Ring to rule them all");
+        let_assert!(Ok(thumbnail) = f.sut.read_upload(&upload, UploadVariant::Thumbnail, None).await);
+        check!(thumbnail.is_none());
+    }
+
+    #[test_case(UploadPurpose::Map, b"One Ring to rule them all", &CreateUploadError::UnsupportedType(Some("text/plain".to_owned())) ; "Text map")]
+    #[test_case(UploadPurpose::Handout, b"PK\x03\x04\x14\0\0\0", &CreateUploadError::UnsupportedType(None) ; "Unknown type")]
+    #[test_case(UploadPurpose::Handout, b"", &CreateUploadError::Empty ; "Empty")]
+    #[test_case(UploadPurpose::Map, b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR", &CreateUploadError::InvalidImage(ImageError::Undecodable) ; "Broken image")]
+    #[actix_rt::test]
+    async fn upload_invalid(purpose: UploadPurpose, data: &'static [u8], expected: &CreateUploadError) {
+        let f = build_fixture().await;
+
+        let result = f
+            .sut
+            .create_upload(NewUpload {
+                owner_id: f.user_id.clone(),
+                world_id: Some(f.world_id.clone()),
+                entity_id: None,
+                purpose,
+                filename: None,
+                data: Bytes::from_static(data),
+            })
+            .await;
+
+        let_assert!(Err(e) = result);
+        check!(&e == expected);
+    }
+
+    #[actix_rt::test]
+    async fn upload_too_large() {
+        let f = build_fixture().await;
+
+        let result = f
+            .sut
+            .create_upload(NewUpload {
+                owner_id:  f.user_id.clone(),
+                world_id:  Some(f.world_id.clone()),
+                entity_id: None,
+                purpose:   UploadPurpose::Handout,
+                filename:  None,
+                data:      Bytes::from(vec![b'a'; 64 * 1024 + 1]),
+            })
+            .await;
+
+        let_assert!(Err(e) = result);
+        check!(e == CreateUploadError::TooLarge(64 * 1024));
+    }
+
+    #[actix_rt::test]
+    async fn upload_for_entity_in_other_world() {
+        let f = build_fixture().await;
+
+        let result = f
+            .sut
+            .create_upload(NewUpload {
+                owner_id:  f.user_id.clone(),
+                world_id:  Some(WorldId::default()),
+                entity_id: Some(f.character.clone()),
+                purpose:   UploadPurpose::Portrait,
+                filename:  None,
+                data:      Bytes::from(png(10, 10)),
+            })
+            .await;
+
+        let_assert!(Err(e) = result);
+        check!(e == CreateUploadError::UnknownEntity(f.character.clone()));
+    }
+}
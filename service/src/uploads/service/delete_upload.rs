@@ -0,0 +1,94 @@
+use super::UploadService;
+use crate::{
+    uploads::{UploadData, UploadId, UploadResource},
+    worlds::WorldId,
+};
+
+impl UploadService {
+    /// Delete an upload from a world, along with its contents.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world the upload belongs to
+    /// - `upload_id` - The ID of the upload
+    ///
+    /// # Returns
+    /// True if the upload existed and was deleted. False if not.
+    pub async fn delete_upload(&self, world_id: &WorldId, upload_id: &UploadId) -> bool {
+        match self.get_upload(world_id, upload_id).await {
+            Some(upload) => self.remove(&upload).await,
+            None => false,
+        }
+    }
+
+    /// Delete an upload along with its contents.
+    ///
+    /// # Parameters
+    /// - `upload` - The upload to delete
+    ///
+    /// # Returns
+    /// True if the upload existed and was deleted. False if not.
+    pub(super) async fn remove(&self, upload: &UploadResource) -> bool {
+        let deleted = self.repository.delete_upload(&upload.identity.id).await;
+        if deleted {
+            self.remove_blobs(&upload.data).await;
+        }
+
+        deleted
+    }
+
+    /// Delete the contents of an upload from the blob store.
+    ///
+    /// Failures are logged rather than returned, since a stray blob is harmless once nothing
+    /// refers to it.
+    ///
+    /// # Parameters
+    /// - `upload` - The upload to delete the contents of
+    pub(super) async fn remove_blobs(&self, upload: &UploadData) {
+        let keys = std::iter::once(&upload.blob_key).chain(upload.thumbnail_key.as_ref());
+
+        for key in keys {
+            if let Err(e) = self.store.delete(key).await {
+                tracing::warn!(e = ?e, key = ?key, "Failed to delete blob");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use bytes::Bytes;
+
+    use crate::{
+        uploads::{images::tests::png, service::tests::build_fixture, NewUpload, UploadPurpose},
+        worlds::WorldId,
+    };
+
+    #[actix_rt::test]
+    async fn delete_upload() {
+        let f = build_fixture().await;
+        let upload = f
+            .sut
+            .create_upload(NewUpload {
+                owner_id:  f.user_id.clone(),
+                world_id:  Some(f.world_id.clone()),
+                entity_id: None,
+                purpose:   UploadPurpose::Map,
+                filename:  None,
+                data:      Bytes::from(png(20, 20)),
+            })
+            .await
+            .unwrap();
+        let thumbnail_key = upload.data.thumbnail_key.clone().unwrap();
+
+        check!(!f.sut.delete_upload(&WorldId::default(), &upload.identity.id).await);
+        check!(f.store.contains(&upload.data.blob_key));
+
+        check!(f.sut.delete_upload(&f.world_id, &upload.identity.id).await);
+        check!(!f.store.contains(&upload.data.blob_key));
+        check!(!f.store.contains(&thumbnail_key));
+        check!(f.sut.get_upload(&f.world_id, &upload.identity.id).await.is_none());
+
+        check!(!f.sut.delete_upload(&f.world_id, &upload.identity.id).await);
+    }
+}
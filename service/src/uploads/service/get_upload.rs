@@ -0,0 +1,88 @@
+use super::UploadService;
+use crate::{
+    entities::EntityId,
+    uploads::{UploadId, UploadResource},
+    worlds::WorldId,
+};
+
+impl UploadService {
+    /// Get a single upload from a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world the upload belongs to
+    /// - `upload_id` - The ID of the upload
+    ///
+    /// # Returns
+    /// The upload, or `None` if it doesn't exist in this world.
+    pub async fn get_upload(&self, world_id: &WorldId, upload_id: &UploadId) -> Option<UploadResource> {
+        self.repository
+            .get_upload(upload_id)
+            .await
+            .filter(|upload| upload.data.world_id.as_ref() == Some(world_id))
+    }
+
+    /// Get the uploads that belong to a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    /// - `entity_id` - If provided, only get the uploads that are attached to this entity
+    ///
+    /// # Returns
+    /// The uploads, oldest first.
+    pub async fn get_uploads(&self, world_id: &WorldId, entity_id: Option<&EntityId>) -> Vec<UploadResource> {
+        self.repository.get_world_uploads(world_id, entity_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use bytes::Bytes;
+
+    use crate::uploads::{
+        service::tests::{build_fixture, Fixture},
+        NewUpload, UploadPurpose, UploadResource,
+    };
+
+    async fn upload(f: &Fixture, text: &'static str, entity: bool) -> UploadResource {
+        f.sut
+            .create_upload(NewUpload {
+                owner_id:  f.user_id.clone(),
+                world_id:  Some(f.world_id.clone()),
+                entity_id: if entity { Some(f.character.clone()) } else { None },
+                purpose:   UploadPurpose::Handout,
+                filename:  None,
+                data:      Bytes::from_static(text.as_bytes()),
+            })
+            .await
+            .unwrap()
+    }
+
+    #[actix_rt::test]
+    async fn get_upload_from_world() {
+        let f = build_fixture().await;
+        let created = upload(&f, "Speak friend and enter", false).await;
+
+        let_assert!(Some(found) = f.sut.get_upload(&f.world_id, &created.identity.id).await);
+        check!(found.data.checksum == created.data.checksum);
+
+        check!(f
+            .sut
+            .get_upload(&crate::worlds::WorldId::default(), &created.identity.id)
+            .await
+            .is_none());
+    }
+
+    #[actix_rt::test]
+    async fn get_uploads_for_entity() {
+        let f = build_fixture().await;
+        let first = upload(&f, "Speak friend and enter", false).await;
+        let second = upload(&f, "Not all those who wander are lost", true).await;
+
+        let all = f.sut.get_uploads(&f.world_id, None).await;
+        check!(all.iter().map(|u| u.identity.id.clone()).collect::<Vec<_>>() == vec![first.identity.id, second.identity.id.clone()]);
+
+        let attached = f.sut.get_uploads(&f.world_id, Some(&f.character)).await;
+        check!(attached.iter().map(|u| u.identity.id.clone()).collect::<Vec<_>>() == vec![second.identity.id]);
+    }
+}
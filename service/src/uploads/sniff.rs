@@ -0,0 +1,78 @@
+//! Detection of the type of an uploaded file from its contents, rather than trusting whatever the
+//! client claims it to be.
+
+/// The signatures of the binary file types that are recognised.
+const SIGNATURES: &[(&[u8], &str)] = &[
+    (b"\x89PNG\r\n\x1a\n", "image/png"),
+    (b"\xff\xd8\xff", "image/jpeg"),
+    (b"GIF87a", "image/gif"),
+    (b"GIF89a", "image/gif"),
+    (b"%PDF-", "application/pdf"),
+];
+
+/// How much of a file is inspected when deciding whether it is text.
+const TEXT_SAMPLE_SIZE: usize = 8 * 1024;
+
+/// Determine the media type of a file from its contents.
+///
+/// # Parameters
+/// - `data` - The contents of the file
+///
+/// # Returns
+/// The media type, or `None` if the file isn't of a recognised type.
+pub fn sniff_content_type(data: &[u8]) -> Option<&'static str> {
+    if let Some((_, content_type)) = SIGNATURES.iter().find(|(signature, _)| data.starts_with(signature)) {
+        return Some(content_type);
+    }
+
+    if data.len() >= 12 && &data[0..4] == b"RIFF" && &data[8..12] == b"WEBP" {
+        return Some("image/webp");
+    }
+
+    if is_text(data) {
+        return Some("text/plain");
+    }
+
+    None
+}
+
+/// Determine whether a file looks like UTF-8 text.
+fn is_text(data: &[u8]) -> bool {
+    if data.is_empty() {
+        return false;
+    }
+
+    let sample = &data[..data.len().min(TEXT_SAMPLE_SIZE)];
+    let valid = match std::str::from_utf8(sample) {
+        Ok(_) => true,
+        // The sample may have cut a multi-byte character in half.
+        Err(e) => e.error_len().is_none(),
+    };
+
+    valid
+        && !sample
+            .iter()
+            .any(|b| *b == 0 || (*b < 0x20 && !matches!(b, b'\t' | b'\n' | b'\r' | 0x0c)))
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR", Some("image/png") ; "PNG")]
+    #[test_case(b"\xff\xd8\xff\xe0\0\x10JFIF", Some("image/jpeg") ; "JPEG")]
+    #[test_case(b"GIF89a\x01\0\x01\0", Some("image/gif") ; "GIF")]
+    #[test_case(b"RIFF\x24\0\0\0WEBPVP8 ", Some("image/webp") ; "WebP")]
+    #[test_case(b"%PDF-1.7\n", Some("application/pdf") ; "PDF")]
+    #[test_case(b"The road goes ever on and on\n", Some("text/plain") ; "Text")]
+    #[test_case("Ancalagon the Black \u{2014} greatest of dragons".as_bytes(), Some("text/plain") ; "Unicode text")]
+    #[test_case(b"PK\x03\x04\x14\0\0\0", None ; "Zip")]
+    #[test_case(b"\x7fELF\x02\x01\x01\0", None ; "Executable")]
+    #[test_case(b"", None ; "Empty")]
+    fn test_sniff(data: &[u8], expected: Option<&str>) {
+        check!(sniff_content_type(data) == expected);
+    }
+}
@@ -1,8 +1,13 @@
 pub mod component;
 mod endpoints;
 mod model;
+mod password_hasher;
+mod password_policy;
 mod repository;
 mod service;
 
 pub use model::*;
+pub use password_hasher::*;
+pub use password_policy::*;
+pub use repository::*;
 pub use service::*;
@@ -1,33 +1,55 @@
 use std::sync::Arc;
 
-use actix_web::web::{get, patch, resource, ServiceConfig};
+use actix_web::web::{get, patch, post, resource, ServiceConfig};
 
-use super::{repository::UserRepository, service::UserService};
-use crate::{database::Database, server::RouteConfigurer};
+use super::{repository::UserRepository, service::UserService, PasswordHasher, PasswordPolicy};
+use crate::{revisions::RevisionService, server::RouteConfigurer};
 
 /// Component for working with user records.
 pub struct Component {
-    pub service: Arc<UserService>,
+    pub service:         Arc<UserService>,
+    pub password_hasher: Arc<PasswordHasher>,
 }
 
 impl Component {
     /// Create a new users component.
-    pub fn new(database: Arc<Database>) -> Arc<Self> {
-        let repository = UserRepository::new(database);
-        let service = Arc::new(UserService::new(repository));
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store users in
+    /// - `password_policy` - The policy that new passwords must satisfy
+    /// - `password_hasher` - The mechanism to hash and verify passwords with
+    /// - `revisions` - The service to record the revision history of users with
+    pub fn new(
+        repository: Arc<dyn UserRepository>,
+        password_policy: PasswordPolicy,
+        password_hasher: PasswordHasher,
+        revisions: Arc<RevisionService>,
+    ) -> Arc<Self> {
+        let password_hasher = Arc::new(password_hasher);
+        let service = Arc::new(UserService::new(
+            repository,
+            revisions,
+            Arc::new(password_policy),
+            password_hasher.clone(),
+        ));
 
-        Arc::new(Self { service })
+        Arc::new(Self { service, password_hasher })
     }
 }
 
 impl RouteConfigurer for Component {
     fn configure_routes(&self, config: &mut ServiceConfig) {
         config.data(self.service.clone());
+        config.data(self.password_hasher.clone());
 
         config.service(
             resource("/users/{id}")
                 .route(get().to(super::endpoints::get_user::handle))
                 .route(patch().to(super::endpoints::patch_user::handle)),
         );
+        config.service(resource("/users/{id}/revisions").route(get().to(super::endpoints::list_revisions::handle)));
+        config.service(resource("/users/{id}/revisions/{version}").route(get().to(super::endpoints::get_revision::handle)));
+        config.service(resource("/users/{id}/revisions/{version}/diff").route(get().to(super::endpoints::diff_revisions::handle)));
+        config.service(resource("/users/{id}/revisions/{version}/restore").route(post().to(super::endpoints::restore_revision::handle)));
     }
 }
@@ -1,3 +1,8 @@
+pub(super) mod diff_revisions;
+pub(super) mod get_revision;
 pub(super) mod get_user;
+pub(super) mod list_revisions;
 mod model;
 pub(super) mod patch_user;
+mod problems;
+pub(super) mod restore_revision;
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path, Query};
+
+use crate::{
+    authorization::{Authentication, Principal},
+    http::{
+        problem::{Problem, FORBIDDEN},
+        response::Response,
+    },
+    revisions::{
+        endpoints::{DiffModel, DiffQuery},
+        RevisionService,
+    },
+    tokens::Scope,
+    users::{UserData, UserId},
+};
+
+/// Show the changes that were made in a revision of a user.
+pub async fn handle(
+    revisions: Data<Arc<RevisionService>>,
+    path: Path<(String, String)>,
+    query: Query<DiffQuery>,
+    authentication: Authentication,
+) -> Result<Response<DiffModel>, Problem> {
+    let (user_id, version) = path.into_inner();
+
+    let user_id: UserId = user_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, user_id = ?user_id, "Failed to parse User ID");
+
+        FORBIDDEN
+    })?;
+
+    authentication.same_principal(&Principal::from(&user_id))?;
+    authentication.require_scope(Scope::UsersRead)?;
+
+    crate::revisions::endpoints::diff_revisions::<UserData>(&revisions, &user_id, &version, &query).await
+}
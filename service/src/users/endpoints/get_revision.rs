@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use crate::{
+    authorization::{Authentication, Principal},
+    http::{
+        problem::{Problem, FORBIDDEN},
+        response::Response,
+    },
+    revisions::{endpoints::RevisionDetailModel, RevisionService},
+    tokens::Scope,
+    users::{UserData, UserId},
+};
+
+pub async fn handle(
+    revisions: Data<Arc<RevisionService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<Response<RevisionDetailModel>, Problem> {
+    let (user_id, version) = path.into_inner();
+
+    let user_id: UserId = user_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, user_id = ?user_id, "Failed to parse User ID");
+
+        FORBIDDEN
+    })?;
+
+    authentication.same_principal(&Principal::from(&user_id))?;
+    authentication.require_scope(Scope::UsersRead)?;
+
+    crate::revisions::endpoints::get_revision::<UserData>(&revisions, &user_id, &version).await
+}
@@ -9,6 +9,7 @@ use super::model::{FullUserResponse, SimpleUserResponse};
 use crate::{
     authorization::{Authentication, Principal},
     http::problem::{Problem, NOT_FOUND},
+    tokens::Scope,
     users::{UserId, UserService},
 };
 
@@ -25,7 +26,7 @@ pub async fn handle(
 
     let user = service.get_user_by_id(&user_id).await.ok_or(NOT_FOUND)?;
 
-    if authentication.same_principal(&Principal::from(&user_id)).is_ok() {
+    if authentication.same_principal(&Principal::from(&user_id)).is_ok() && authentication.require_scope(Scope::UsersRead).is_ok() {
         Ok(Either::Left(user.into()))
     } else {
         Ok(Either::Right(user.into()))
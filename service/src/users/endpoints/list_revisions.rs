@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use crate::{
+    authorization::{Authentication, Principal},
+    http::{
+        problem::{Problem, FORBIDDEN},
+        response::Response,
+    },
+    revisions::{endpoints::RevisionsModel, RevisionService},
+    tokens::Scope,
+    users::{UserData, UserId},
+};
+
+/// List the revision history of a user, newest first.
+pub async fn handle(
+    revisions: Data<Arc<RevisionService>>,
+    path: Path<String>,
+    authentication: Authentication,
+) -> Result<Response<RevisionsModel>, Problem> {
+    let user_id: UserId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse User ID");
+
+        FORBIDDEN
+    })?;
+
+    authentication.same_principal(&Principal::from(&user_id))?;
+    authentication.require_scope(Scope::UsersRead)?;
+
+    Ok(crate::revisions::endpoints::list_revisions::<UserData>(&revisions, &user_id).await)
+}
@@ -2,23 +2,26 @@ use std::sync::Arc;
 
 use actix_http::http::StatusCode;
 use actix_web::web::{Data, Path};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 
-use super::model::FullUserResponse;
+use super::{model::FullUserResponse, problems::DUPLICATE_EMAIL};
 use crate::{
     authorization::{Authentication, Principal},
     http::{
+        patch::Patch,
         problem::{Problem, SimpleProblemType, FORBIDDEN, INTERNAL_SERVER_ERROR, NOT_FOUND},
-        valid::{Valid, Validatable},
+        valid::Validatable,
     },
-    users::{Email, Password, UpdateUserError, UserData, UserId, UserService},
+    tokens::Scope,
+    users::{Email, PasswordHasher, PasswordMatch, UpdateUserError, UserData, UserId, UserService},
 };
 
 pub async fn handle(
     service: Data<Arc<UserService>>,
+    password_hasher: Data<Arc<PasswordHasher>>,
     path: Path<String>,
-    request: Valid<PatchRequest>,
+    request: Patch,
     authentication: Authentication,
 ) -> Result<FullUserResponse, Problem> {
     let user_id: UserId = path.parse().map_err(|e| {
@@ -28,48 +31,98 @@ pub async fn handle(
     })?;
 
     authentication.same_principal(&Principal::from(&user_id))?;
+    authentication.require_scope(Scope::UsersWrite)?;
 
-    let request = request.unwrap();
+    let user = service.get_user_by_id(&user_id).await.ok_or(NOT_FOUND)?;
+    let changes = request.apply_to(&PatchModel::from(&user.data))?;
 
-    let user = service
-        .update_user_by_id(&user_id, move |user| {
-            if let Some(old_password) = request.old_password {
-                if user.password != &old_password {
-                    return Err(INCORECT_OLD_PASSWORD);
-                }
+    // Password hashing is too slow to happen inside the update, so the old password is verified and
+    // any new password is checked and hashed up front against the current state of the user.
+    let new_password = match &changes.old_password {
+        Some(old_password) => {
+            if password_hasher.verify(&user.data.password, old_password).await == PasswordMatch::Mismatch {
+                return Err(INCORECT_OLD_PASSWORD.into());
             }
 
+            let password = match &changes.password {
+                Some(password) => {
+                    service
+                        .hash_new_password(
+                            password,
+                            &[user.data.username.as_ref(), changes.email.as_ref(), &changes.display_name],
+                        )
+                        .await?
+                },
+                None => user.data.password.clone(),
+            };
+
+            Some((user.data.password, password))
+        },
+        None => None,
+    };
+
+    let user = service
+        .update_user_by_id(&user_id, &user_id, move |user| {
+            // The patch is applied again to the user as they are now, in case they changed since
+            // they were loaded above.
+            let PatchModel { email, display_name, .. } = request.apply_to(&PatchModel::from(&user))?;
+
+            let password = match new_password {
+                // Don't overwrite a password that was changed since the old password was verified.
+                Some((old_password, _)) if old_password != user.password => {
+                    return Err(Problem::from(INCORECT_OLD_PASSWORD));
+                },
+                Some((_, new_password)) => new_password,
+                None => user.password,
+            };
+
             Ok(UserData {
-                email: request.email.unwrap_or(user.email),
-                display_name: request.display_name.unwrap_or(user.display_name),
-                password: request.password.map_or(user.password, |p| Password::from_plaintext(&p)),
+                email,
+                display_name,
+                password,
                 ..user
             })
         })
         .await
         .map_err(|e| match e {
             UpdateUserError::UpdateError(p) => p,
-            UpdateUserError::UnknownUser => NOT_FOUND,
-            UpdateUserError::DuplicateUsername | UpdateUserError::UnknownError => INTERNAL_SERVER_ERROR,
+            UpdateUserError::UnknownUser => NOT_FOUND.into(),
+            UpdateUserError::DuplicateEmail => DUPLICATE_EMAIL.into(),
+            UpdateUserError::DuplicateUsername | UpdateUserError::UnknownError => INTERNAL_SERVER_ERROR.into(),
         })?;
 
     Ok(user.into())
 }
 
-/// The incoming request to patch user details.
-#[derive(Deserialize)]
+/// The details of a user that a patch is applied to.
+/// The password is never exposed, so it is changed by adding both `password` and `oldPassword`.
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct PatchRequest {
-    pub email:        Option<Email>,
-    pub display_name: Option<String>,
+pub struct PatchModel {
+    pub email:        Email,
+    pub display_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub password:     Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub old_password: Option<String>,
 }
 
-impl Validatable for PatchRequest {
+impl From<&UserData> for PatchModel {
+    fn from(user: &UserData) -> Self {
+        Self {
+            email:        user.email.clone(),
+            display_name: user.display_name.clone(),
+            password:     None,
+            old_password: None,
+        }
+    }
+}
+
+impl Validatable for PatchModel {
     fn schema() -> Value {
         json!({
             "type": "object",
+            "required": ["email", "displayName"],
             "properties": {
                 "email": Email::schema(),
                 "displayName": {
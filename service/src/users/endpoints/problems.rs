@@ -0,0 +1,10 @@
+use actix_http::http::StatusCode;
+
+use crate::http::problem::SimpleProblemType;
+
+/// Problem to indicate that the provided email address is already in use by another user.
+pub const DUPLICATE_EMAIL: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/users/duplicate_email",
+    problem_title: "Duplicate Email Address",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
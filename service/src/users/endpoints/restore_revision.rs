@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::{model::FullUserResponse, problems::DUPLICATE_EMAIL};
+use crate::{
+    authorization::{Authentication, Principal},
+    http::problem::{Problem, FORBIDDEN, INTERNAL_SERVER_ERROR, NOT_FOUND},
+    revisions::{endpoints::parse_version, Revisable, RevisionService},
+    tokens::Scope,
+    users::{UpdateUserError, UserData, UserId, UserService},
+};
+
+/// Restore a user to the state that they were in at a previous revision.
+/// This is recorded as a new revision, so that the restore itself can be undone.
+pub async fn handle(
+    service: Data<Arc<UserService>>,
+    revisions: Data<Arc<RevisionService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<FullUserResponse, Problem> {
+    let (user_id, version) = path.into_inner();
+
+    let user_id: UserId = user_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, user_id = ?user_id, "Failed to parse User ID");
+
+        FORBIDDEN
+    })?;
+
+    authentication.same_principal(&Principal::from(&user_id))?;
+    authentication.require_scope(Scope::UsersWrite)?;
+
+    let version = parse_version(&version)?;
+    let snapshot = revisions.get_snapshot::<UserData>(&user_id, &version).await.ok_or(NOT_FOUND)?;
+
+    let user = service
+        .update_user_by_id(&user_id, &user_id, move |user| Ok::<_, Problem>(user.restore(snapshot)))
+        .await
+        .map_err(|e| match e {
+            UpdateUserError::UpdateError(p) => p,
+            UpdateUserError::UnknownUser => NOT_FOUND.into(),
+            UpdateUserError::DuplicateEmail => DUPLICATE_EMAIL.into(),
+            UpdateUserError::DuplicateUsername | UpdateUserError::UnknownError => INTERNAL_SERVER_ERROR.into(),
+        })?;
+
+    Ok(user.into())
+}
@@ -1,17 +1,20 @@
 mod email;
 mod password;
+mod user_event;
 mod user_id;
 mod username;
 
 pub use email::*;
 pub use password::*;
+use serde::{Deserialize, Serialize};
+pub use user_event::*;
 pub use user_id::*;
 pub use username::*;
 
-use crate::model::Resource;
+use crate::{model::Resource, revisions::Revisable};
 
 /// The data representing a user.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct UserData {
     pub username:     Username,
     pub email:        Email,
@@ -21,3 +24,32 @@ pub struct UserData {
 
 /// Type representing a persisted user.
 pub type UserResource = Resource<UserId, UserData>;
+
+/// The parts of a user that are kept in their revision history.
+/// The password is deliberately left out, so that it is never exposed or restored.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSnapshot {
+    pub email:        Email,
+    pub display_name: String,
+}
+
+impl Revisable for UserData {
+    const RESOURCE_TYPE: &'static str = "user";
+    type Snapshot = UserSnapshot;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        UserSnapshot {
+            email:        self.email.clone(),
+            display_name: self.display_name.clone(),
+        }
+    }
+
+    fn restore(self, snapshot: Self::Snapshot) -> Self {
+        Self {
+            email: snapshot.email,
+            display_name: snapshot.display_name,
+            ..self
+        }
+    }
+}
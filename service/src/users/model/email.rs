@@ -1,13 +1,18 @@
-use std::str::FromStr;
+use std::{convert::TryFrom, str::FromStr};
 
 use bytes::BytesMut;
 use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
 use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
 
 use crate::http::valid::Validatable;
 
 /// The email address of a user.
-#[derive(Debug, PartialEq, Deserialize, Serialize, FromSql)]
+///
+/// Email addresses are stored in Unicode NFKC form and in lower case, so that they can be compared
+/// for uniqueness directly.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromSql)]
+#[serde(try_from = "String")]
 pub struct Email(String);
 
 #[derive(Debug, PartialEq, thiserror::Error)]
@@ -20,15 +25,30 @@ impl FromStr for Email {
     type Err = ParseEmailError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let trimmed = s.trim();
+        let normalized: String = s.nfkc().collect();
+        let trimmed = normalized.trim();
         if trimmed.is_empty() {
             Err(ParseEmailError::Blank)
         } else {
-            Ok(Email(trimmed.to_owned()))
+            Ok(Email(trimmed.to_lowercase()))
         }
     }
 }
 
+impl TryFrom<String> for Email {
+    type Error = ParseEmailError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl AsRef<str> for Email {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
 impl ToSql for Email {
     accepts!(TEXT, VARCHAR);
     to_sql_checked!();
@@ -51,6 +71,7 @@ impl Validatable for Email {
 #[cfg(test)]
 mod tests {
     use assert2::{check, let_assert};
+    use serde_json::json;
     use test_case::test_case;
 
     use super::*;
@@ -59,6 +80,8 @@ mod tests {
     #[test_case("   testuser@example.com", "testuser@example.com" ; "Left padded")]
     #[test_case("testuser@example.com   ", "testuser@example.com" ; "Right padded")]
     #[test_case("   testuser@example.com   ", "testuser@example.com" ; "Both padded")]
+    #[test_case("TestUser@Example.COM", "testuser@example.com" ; "Mixed case")]
+    #[test_case("ｔｅｓｔｕｓｅｒ@example.com", "testuser@example.com" ; "Fullwidth")]
     fn test_parse_success(input: &str, expected: &str) {
         let result: Result<Email, ParseEmailError> = input.parse();
 
@@ -75,4 +98,11 @@ mod tests {
         let_assert!(Err(e) = result);
         check!(&e == expected);
     }
+
+    #[test]
+    fn test_deserialize_normalizes() {
+        let email: Email = serde_json::from_value(json!(" TestUser@Example.com ")).unwrap();
+
+        check!(email == Email("testuser@example.com".to_owned()));
+    }
 }
@@ -1,23 +1,28 @@
-use argonautica::{Hasher, Verifier};
 use bytes::BytesMut;
 use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
 
 /// Wrapper around a hashed password.
-#[derive(FromSql)]
+#[derive(Clone, PartialEq, FromSql)]
 pub struct Password(String);
 
 impl Password {
-    /// Hash a plaintext password into a `Password` object.
+    /// Wrap an already hashed password into a `Password` object.
     ///
     /// # Parameters
-    /// - `input` - The plaintext password to hash
+    /// - `hash` - The hashed password
     ///
     /// # Returns
-    /// The hashed version.
-    pub fn from_plaintext(input: &str) -> Password {
-        let hash = Hasher::default().with_password(input).opt_out_of_secret_key(true).hash().unwrap();
+    /// The wrapped version.
+    pub fn from_hash<S>(hash: S) -> Password
+    where
+        S: Into<String>,
+    {
+        Password(hash.into())
+    }
 
-        Password(hash)
+    /// Get the hashed form of the password.
+    pub(crate) fn hashed(&self) -> &str {
+        &self.0
     }
 }
 
@@ -36,20 +41,6 @@ impl std::fmt::Debug for Password {
     }
 }
 
-impl PartialEq<&str> for Password {
-    fn eq(&self, other: &&str) -> bool {
-        Verifier::default()
-            .with_hash(&self.0)
-            .with_password(*other)
-            .verify()
-            .unwrap_or_else(|e| {
-                tracing::warn!(e = ?e, "Failed to verify password");
-
-                false
-            })
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use assert2::check;
@@ -58,21 +49,9 @@ mod tests {
 
     #[test]
     fn debug() {
-        let password = Password::from_plaintext("hello");
+        let password = Password::from_hash("$argon2id$v=19$m=4096,t=192,p=8$c2FsdHNhbHQ$aGFzaGhhc2g");
         let formatted = format!("{:?}", password);
 
         check!(formatted == "Password(Redacted)");
     }
-
-    #[test]
-    fn verify() {
-        let password = Password::from_plaintext("hello");
-
-        check!(password == "hello");
-        check!(password != "Hello");
-        check!(password != "hell0");
-        check!(password != "hello ");
-        check!(password != " hello");
-        check!(password != " hello ");
-    }
 }
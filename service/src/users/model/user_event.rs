@@ -0,0 +1,149 @@
+use serde::Serialize;
+
+use super::{Email, UserData, Username};
+use crate::outbox::DomainEvent;
+
+/// Something that happened to a user, which is written to the outbox for other services to see.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum UserEvent {
+    /// A new user registered.
+    #[serde(rename_all = "camelCase")]
+    Registered {
+        username:     Username,
+        email:        Email,
+        display_name: String,
+    },
+
+    /// The profile of a user changed.
+    #[serde(rename_all = "camelCase")]
+    Updated {
+        username:     Username,
+        email:        Email,
+        display_name: String,
+    },
+
+    /// A user changed their password. The password itself is never included.
+    PasswordChanged {},
+}
+
+impl UserEvent {
+    /// Build the event for a user that has just registered.
+    ///
+    /// # Parameters
+    /// - `user` - The details of the new user
+    pub fn registered(user: &UserData) -> Self {
+        Self::Registered {
+            username:     user.username.clone(),
+            email:        user.email.clone(),
+            display_name: user.display_name.clone(),
+        }
+    }
+
+    /// Build the events for the changes made to a user.
+    ///
+    /// # Parameters
+    /// - `before` - The details of the user before the change
+    /// - `after` - The details of the user after the change
+    ///
+    /// # Returns
+    /// The events, which is empty if nothing changed.
+    pub fn changes(before: &UserData, after: &UserData) -> Vec<Self> {
+        let mut events = vec![];
+
+        if before.username != after.username || before.email != after.email || before.display_name != after.display_name {
+            events.push(Self::Updated {
+                username:     after.username.clone(),
+                email:        after.email.clone(),
+                display_name: after.display_name.clone(),
+            });
+        }
+
+        if before.password != after.password {
+            events.push(Self::PasswordChanged {});
+        }
+
+        events
+    }
+}
+
+impl DomainEvent for UserEvent {
+    const AGGREGATE_TYPE: &'static str = "user";
+
+    fn event_type(&self) -> &'static str {
+        match self {
+            Self::Registered { .. } => "user.registered",
+            Self::Updated { .. } => "user.updated",
+            Self::PasswordChanged {} => "user.password_changed",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use serde_json::json;
+
+    use super::*;
+    use crate::{outbox::NewOutboxEvent, users::Password};
+
+    fn user() -> UserData {
+        UserData {
+            username:     "frodo".parse().unwrap(),
+            email:        "frodo@example.com".parse().unwrap(),
+            display_name: "Frodo Baggins".to_owned(),
+            password:     Password::from_hash("hashed"),
+        }
+    }
+
+    #[test]
+    fn registered() {
+        let event = NewOutboxEvent::new("abc", &UserEvent::registered(&user()));
+
+        check!(event.aggregate_type == "user");
+        check!(event.event_type == "user.registered");
+        check!(
+            event.payload
+                == json!({
+                    "username": "frodo",
+                    "email": "frodo@example.com",
+                    "displayName": "Frodo Baggins"
+                })
+        );
+    }
+
+    #[test]
+    fn no_changes() {
+        check!(UserEvent::changes(&user(), &user()).is_empty());
+    }
+
+    #[test]
+    fn profile_changed() {
+        let after = UserData {
+            display_name: "Mr Underhill".to_owned(),
+            ..user()
+        };
+
+        let events = UserEvent::changes(&user(), &after);
+
+        let_assert!([event] = events.as_slice());
+        check!(event.event_type() == "user.updated");
+        check!(serde_json::to_value(event).unwrap()["displayName"] == json!("Mr Underhill"));
+    }
+
+    #[test]
+    fn password_changed() {
+        let after = UserData {
+            email: "underhill@example.com".parse().unwrap(),
+            password: Password::from_hash("rehashed"),
+            ..user()
+        };
+
+        let events = UserEvent::changes(&user(), &after);
+
+        let_assert!([updated, password] = events.as_slice());
+        check!(updated.event_type() == "user.updated");
+        check!(password.event_type() == "user.password_changed");
+        check!(serde_json::to_value(password).unwrap() == json!({}));
+    }
+}
@@ -2,13 +2,13 @@ use std::str::FromStr;
 
 use bytes::BytesMut;
 use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::authorization::Principal;
 
 /// The ID of a user.
-#[derive(Debug, PartialEq, Serialize, FromSql)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromSql)]
 pub struct UserId(Uuid);
 
 #[derive(Debug, PartialEq, thiserror::Error)]
@@ -26,6 +26,12 @@ impl Default for UserId {
     }
 }
 
+impl std::fmt::Display for UserId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 impl FromStr for UserId {
     type Err = ParseUserIdError;
 
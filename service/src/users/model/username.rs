@@ -1,34 +1,109 @@
-use std::str::FromStr;
+use std::{convert::TryFrom, str::FromStr};
 
 use bytes::BytesMut;
 use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
 use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
 
 use crate::http::valid::Validatable;
 
+/// Usernames that are not allowed to be registered, compared case-insensitively.
+const RESERVED_USERNAMES: &[&str] = &[
+    "admin",
+    "administrator",
+    "anonymous",
+    "api",
+    "help",
+    "me",
+    "moderator",
+    "null",
+    "root",
+    "staff",
+    "support",
+    "system",
+    "undefined",
+    "worlds",
+];
+
+/// The longest that a username can be, in characters.
+const MAX_LENGTH: usize = 64;
+
 /// The Username of a user.
-#[derive(Debug, PartialEq, Deserialize, Serialize, FromSql)]
+///
+/// Usernames are stored in Unicode NFKC form, and compared for uniqueness case-insensitively.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromSql)]
+#[serde(try_from = "String")]
 pub struct Username(String);
 
 #[derive(Debug, PartialEq, thiserror::Error)]
 pub enum ParseUsernameError {
     #[error("The username was blank")]
     Blank,
+
+    #[error("The username was too long")]
+    TooLong,
+
+    #[error("The username contained invalid characters")]
+    InvalidCharacters,
+
+    #[error("The username is reserved")]
+    Reserved,
+}
+
+impl Username {
+    /// Generate the key to use when comparing usernames for uniqueness.
+    ///
+    /// # Returns
+    /// The case-folded form of the username.
+    pub fn key(&self) -> String {
+        self.0.to_lowercase().nfkc().collect()
+    }
+}
+
+/// Check if the given character is allowed to appear in a username.
+fn is_valid_character(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '.' || c == '-'
 }
 
 impl FromStr for Username {
     type Err = ParseUsernameError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let trimmed = s.trim();
+        let normalized: String = s.nfkc().collect();
+        let trimmed = normalized.trim();
+
         if trimmed.is_empty() {
             Err(ParseUsernameError::Blank)
+        } else if trimmed.chars().count() > MAX_LENGTH {
+            Err(ParseUsernameError::TooLong)
+        } else if !trimmed.chars().all(is_valid_character) {
+            Err(ParseUsernameError::InvalidCharacters)
         } else {
-            Ok(Username(trimmed.to_owned()))
+            let username = Username(trimmed.to_owned());
+
+            if RESERVED_USERNAMES.contains(&username.key().as_str()) {
+                Err(ParseUsernameError::Reserved)
+            } else {
+                Ok(username)
+            }
         }
     }
 }
 
+impl TryFrom<String> for Username {
+    type Error = ParseUsernameError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl AsRef<str> for Username {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
 impl ToSql for Username {
     accepts!(TEXT, VARCHAR);
     to_sql_checked!();
@@ -42,7 +117,12 @@ impl Validatable for Username {
     fn schema() -> serde_json::Value {
         serde_json::json!({
             "type": "string",
-            "minLength": 1
+            "minLength": 1,
+            "maxLength": MAX_LENGTH,
+            "pattern": "^\\s*[\\p{L}\\p{N}_.-]*\\s*$",
+            "not": {
+                "pattern": format!("^\\s*(?i:{})\\s*$", RESERVED_USERNAMES.join("|"))
+            }
         })
     }
 }
@@ -50,6 +130,7 @@ impl Validatable for Username {
 #[cfg(test)]
 mod tests {
     use assert2::{check, let_assert};
+    use serde_json::json;
     use test_case::test_case;
 
     use super::*;
@@ -58,6 +139,10 @@ mod tests {
     #[test_case("   testUsername", "testUsername" ; "Left padded")]
     #[test_case("testUsername   ", "testUsername" ; "Right padded")]
     #[test_case("   testUsername   ", "testUsername" ; "Both padded")]
+    #[test_case("test_user.name-1", "test_user.name-1" ; "Punctuation")]
+    #[test_case("ｔｅｓｔＵｓｅｒ", "testUser" ; "Fullwidth")]
+    #[test_case("ﬁnn", "finn" ; "Ligature")]
+    #[test_case("Zoë", "Zoë" ; "Accented")]
     fn test_parse_success(input: &str, expected: &str) {
         let result: Result<Username, ParseUsernameError> = input.parse();
 
@@ -68,10 +153,54 @@ mod tests {
 
     #[test_case("", &ParseUsernameError::Blank ; "Blank")]
     #[test_case("   ", &ParseUsernameError::Blank ; "Whitespace")]
+    #[test_case(&"a".repeat(65), &ParseUsernameError::TooLong ; "Too long")]
+    #[test_case("test user", &ParseUsernameError::InvalidCharacters ; "Embedded space")]
+    #[test_case("test@user", &ParseUsernameError::InvalidCharacters ; "Symbol")]
+    #[test_case("admin", &ParseUsernameError::Reserved ; "Reserved")]
+    #[test_case("Admin", &ParseUsernameError::Reserved ; "Reserved different case")]
+    #[test_case("ａｄｍｉｎ", &ParseUsernameError::Reserved ; "Reserved fullwidth")]
     fn test_parse_fail(input: &str, expected: &ParseUsernameError) {
         let result: Result<Username, ParseUsernameError> = input.parse();
 
         let_assert!(Err(e) = result);
         check!(&e == expected);
     }
+
+    #[test_case("testuser", "testuser" ; "Lower case")]
+    #[test_case("TestUser", "testuser" ; "Mixed case")]
+    #[test_case("ＴＥＳＴＵＳＥＲ", "testuser" ; "Fullwidth upper case")]
+    fn test_key(input: &str, expected: &str) {
+        let username: Username = input.parse().unwrap();
+
+        check!(username.key() == expected);
+    }
+
+    #[test]
+    fn test_deserialize_normalizes() {
+        let username: Username = serde_json::from_value(json!("  ｔｅｓｔＵｓｅｒ ")).unwrap();
+
+        check!(username == Username("testUser".to_owned()));
+    }
+
+    #[test]
+    fn test_deserialize_reserved() {
+        let result: Result<Username, _> = serde_json::from_value(json!("root"));
+
+        check!(result.is_err());
+    }
+
+    #[test_case("testuser", true ; "Simple")]
+    #[test_case("  testuser  ", true ; "Padded")]
+    #[test_case("Zoë", true ; "Accented")]
+    #[test_case("test user", false ; "Embedded space")]
+    #[test_case("test@user", false ; "Symbol")]
+    #[test_case("admin", false ; "Reserved")]
+    #[test_case("ADMIN", false ; "Reserved upper case")]
+    #[test_case("administrators", true ; "Reserved prefix")]
+    fn test_schema(input: &str, expected: bool) {
+        let mut scope = valico::json_schema::Scope::new();
+        let schema = scope.compile_and_return(Username::schema(), false).unwrap();
+
+        check!(schema.validate(&json!(input)).is_valid() == expected);
+    }
 }
@@ -0,0 +1,242 @@
+use argonautica::{Hasher, Verifier};
+
+use super::Password;
+
+/// The outcome of verifying a plaintext password against a hashed one.
+#[derive(Debug, PartialEq)]
+pub enum PasswordMatch {
+    /// The plaintext password does not match the hash.
+    Mismatch,
+    /// The plaintext password matches the hash.
+    Match,
+    /// The plaintext password matches the hash, but the hash was generated with outdated settings
+    /// and should be replaced.
+    Outdated,
+}
+
+/// Mechanism to hash and verify passwords using Argon2.
+#[derive(Clone)]
+pub struct PasswordHasher {
+    /// The amount of memory to use, in kibibytes
+    memory_size: u32,
+    /// The number of iterations to perform
+    iterations:  u32,
+    /// The degree of parallelism to use
+    lanes:       u32,
+    /// A secret key - a "pepper" - to mix into every hash, if one is configured
+    secret_key:  Option<String>,
+}
+
+impl PasswordHasher {
+    /// Create a new password hasher.
+    ///
+    /// # Parameters
+    /// - `memory_size` - The amount of memory to use, in kibibytes
+    /// - `iterations` - The number of iterations to perform
+    /// - `lanes` - The degree of parallelism to use
+    pub fn new(memory_size: u32, iterations: u32, lanes: u32) -> Self {
+        Self {
+            memory_size,
+            iterations,
+            lanes,
+            secret_key: None,
+        }
+    }
+
+    /// Specify a secret key to mix into every hash.
+    ///
+    /// # Parameters
+    /// - `secret_key` - The secret key to use
+    pub fn with_secret_key<S>(mut self, secret_key: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.secret_key = Some(secret_key.into());
+        self
+    }
+
+    /// Hash a plaintext password into a `Password` object.
+    /// The hashing is performed on a separate thread pool, so as not to block the async executor.
+    ///
+    /// # Parameters
+    /// - `plaintext` - The plaintext password to hash
+    ///
+    /// # Returns
+    /// The hashed version.
+    pub async fn hash(&self, plaintext: &str) -> Password {
+        let this = self.clone();
+        let plaintext = plaintext.to_owned();
+
+        actix_web::web::block(move || this.hasher(&plaintext).hash())
+            .await
+            .expect("Failed to schedule password hashing")
+            .map(Password::from_hash)
+            .expect("Failed to hash password")
+    }
+
+    /// Verify a plaintext password against a hashed one.
+    /// The verification is performed on a separate thread pool, so as not to block the async
+    /// executor.
+    ///
+    /// # Parameters
+    /// - `password` - The hashed password to verify against
+    /// - `plaintext` - The plaintext password to verify
+    ///
+    /// # Returns
+    /// Whether the password matched, and if so whether the hash should be regenerated.
+    pub async fn verify(&self, password: &Password, plaintext: &str) -> PasswordMatch {
+        let hash = password.hashed().to_owned();
+        let plaintext = plaintext.to_owned();
+        let secret_key = self.secret_key.clone();
+
+        let verified = actix_web::web::block(move || {
+            if verify_hash(&hash, &plaintext, secret_key.as_deref()) {
+                Some(false)
+            } else if secret_key.is_some() && verify_hash(&hash, &plaintext, None) {
+                // The hash was generated before the secret key was configured.
+                Some(true)
+            } else {
+                None
+            }
+        })
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!(e = ?e, "Failed to schedule password verification");
+            None
+        });
+
+        match verified {
+            None => PasswordMatch::Mismatch,
+            Some(true) => PasswordMatch::Outdated,
+            Some(false) if self.is_outdated(password) => PasswordMatch::Outdated,
+            Some(false) => PasswordMatch::Match,
+        }
+    }
+
+    /// Determine if the provided password was hashed with different settings to those that would be
+    /// used now.
+    ///
+    /// # Parameters
+    /// - `password` - The hashed password to check
+    ///
+    /// # Returns
+    /// True if the password should be re-hashed. False if not.
+    fn is_outdated(&self, password: &Password) -> bool {
+        // Hashes are of the form "$argon2id$v=19$m=4096,t=192,p=8$<salt>$<hash>"
+        let mut parts = password.hashed().split('$').skip(1);
+        let variant = parts.next();
+        let params = parts.nth(1).unwrap_or_default();
+
+        let mut memory_size = None;
+        let mut iterations = None;
+        let mut lanes = None;
+        for param in params.split(',') {
+            let mut param = param.splitn(2, '=');
+            let name = param.next();
+            let value = param.next().and_then(|v| v.parse::<u32>().ok());
+            match name {
+                Some("m") => memory_size = value,
+                Some("t") => iterations = value,
+                Some("p") => lanes = value,
+                _ => {},
+            }
+        }
+
+        variant != Some("argon2id")
+            || memory_size != Some(self.memory_size)
+            || iterations != Some(self.iterations)
+            || lanes != Some(self.lanes)
+    }
+
+    /// Build the Argon2 hasher to use for the provided password.
+    fn hasher<'a>(&self, plaintext: &'a str) -> Hasher<'a> {
+        let mut hasher = Hasher::default();
+        hasher
+            .configure_memory_size(self.memory_size)
+            .configure_iterations(self.iterations)
+            .configure_lanes(self.lanes)
+            .configure_threads(self.lanes)
+            .with_password(plaintext);
+
+        match &self.secret_key {
+            Some(secret_key) => hasher.with_secret_key(secret_key.as_str()),
+            None => hasher.opt_out_of_secret_key(true),
+        };
+
+        hasher
+    }
+}
+
+/// Verify a plaintext password against a hash, optionally with a secret key.
+fn verify_hash(hash: &str, plaintext: &str, secret_key: Option<&str>) -> bool {
+    let mut verifier = Verifier::default();
+    verifier.with_hash(hash).with_password(plaintext);
+
+    if let Some(secret_key) = secret_key {
+        verifier.with_secret_key(secret_key);
+    }
+
+    verifier.verify().unwrap_or_else(|e| {
+        tracing::warn!(e = ?e, "Failed to verify password");
+
+        false
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("hello", PasswordMatch::Match ; "correct")]
+    #[test_case("Hello", PasswordMatch::Mismatch ; "different case")]
+    #[test_case("hell0", PasswordMatch::Mismatch ; "different character")]
+    #[test_case("hello ", PasswordMatch::Mismatch ; "right padded")]
+    #[test_case(" hello", PasswordMatch::Mismatch ; "left padded")]
+    #[actix_rt::test]
+    async fn verify(input: &str, expected: PasswordMatch) {
+        let sut = PasswordHasher::new(64, 1, 1);
+        let password = sut.hash("hello").await;
+
+        check!(sut.verify(&password, input).await == expected);
+    }
+
+    #[test_case(PasswordHasher::new(128, 1, 1) ; "memory size")]
+    #[test_case(PasswordHasher::new(64, 2, 1) ; "iterations")]
+    #[test_case(PasswordHasher::new(64, 1, 2) ; "lanes")]
+    #[test_case(PasswordHasher::new(64, 1, 1).with_secret_key("pepper") ; "secret key added")]
+    #[actix_rt::test]
+    async fn verify_outdated(sut: PasswordHasher) {
+        let password = PasswordHasher::new(64, 1, 1).hash("hello").await;
+
+        check!(sut.verify(&password, "hello").await == PasswordMatch::Outdated);
+        check!(sut.verify(&password, "hell0").await == PasswordMatch::Mismatch);
+    }
+
+    #[actix_rt::test]
+    async fn verify_secret_key() {
+        let sut = PasswordHasher::new(64, 1, 1).with_secret_key("pepper");
+        let password = sut.hash("hello").await;
+
+        check!(sut.verify(&password, "hello").await == PasswordMatch::Match);
+        check!(PasswordHasher::new(64, 1, 1).verify(&password, "hello").await == PasswordMatch::Mismatch);
+        check!(
+            PasswordHasher::new(64, 1, 1)
+                .with_secret_key("other")
+                .verify(&password, "hello")
+                .await
+                == PasswordMatch::Mismatch
+        );
+    }
+
+    #[test]
+    fn outdated_hash_format() {
+        let sut = PasswordHasher::new(4096, 192, 8);
+
+        check!(sut.is_outdated(&Password::from_hash("$argon2i$v=19$m=4096,t=192,p=8$c2FsdHNhbHQ$aGFzaGhhc2g")));
+        check!(sut.is_outdated(&Password::from_hash("not a hash")));
+        check!(!sut.is_outdated(&Password::from_hash("$argon2id$v=19$m=4096,t=192,p=8$c2FsdHNhbHQ$aGFzaGhhc2g")));
+    }
+}
@@ -0,0 +1,198 @@
+mod breached;
+mod violation;
+
+pub use breached::*;
+pub use violation::*;
+
+/// The maximum number of characters a password can contain.
+/// This keeps the cost of estimating the strength of the password, and of hashing it, bounded.
+pub const MAX_PASSWORD_LENGTH: usize = 128;
+
+/// The policy that passwords must satisfy before they can be used.
+pub struct PasswordPolicy {
+    /// The minimum number of characters a password must contain
+    min_length:         usize,
+    /// The minimum zxcvbn strength score, from 0 to 4, that a password must achieve
+    min_strength:       u8,
+    /// The source of known breached passwords, if one is configured
+    breached_passwords: Option<Box<dyn BreachedPasswords>>,
+}
+
+impl PasswordPolicy {
+    /// Create a new password policy.
+    ///
+    /// # Parameters
+    /// - `min_length` - The minimum number of characters a password must contain
+    /// - `min_strength` - The minimum zxcvbn strength score, from 0 to 4, that a password must
+    ///   achieve
+    pub fn new(min_length: usize, min_strength: u8) -> Self {
+        Self {
+            min_length,
+            min_strength,
+            breached_passwords: None,
+        }
+    }
+
+    /// Specify the source of known breached passwords to reject.
+    ///
+    /// # Parameters
+    /// - `breached` - The source of breached passwords
+    pub fn with_breached_passwords<B>(mut self, breached: B) -> Self
+    where
+        B: BreachedPasswords + 'static,
+    {
+        self.breached_passwords = Some(Box::new(breached));
+        self
+    }
+
+    /// Check a password against the policy.
+    ///
+    /// # Parameters
+    /// - `password` - The plaintext password to check
+    /// - `user_inputs` - Other details of the user, such as their username or email address, that
+    ///   the password should not be based on
+    ///
+    /// # Returns
+    /// Nothing if the password is acceptable, or the complete list of rules that it violates.
+    pub fn check(&self, password: &str, user_inputs: &[&str]) -> Result<(), PasswordPolicyError> {
+        let length = password.chars().count();
+        if length > MAX_PASSWORD_LENGTH {
+            tracing::debug!(length = ?length, "Password is too long to check");
+            return Err(PasswordPolicyError(vec![PasswordPolicyViolation::TooLong {
+                max_length: MAX_PASSWORD_LENGTH,
+            }]));
+        }
+
+        let mut violations = vec![];
+
+        if length < self.min_length {
+            violations.push(PasswordPolicyViolation::TooShort {
+                min_length: self.min_length,
+            });
+        }
+
+        match zxcvbn::zxcvbn(password, user_inputs) {
+            Ok(entropy) if entropy.score() >= self.min_strength => {},
+            Ok(entropy) => violations.push(PasswordPolicyViolation::TooWeak {
+                warning: entropy
+                    .feedback()
+                    .as_ref()
+                    .and_then(zxcvbn::feedback::Feedback::warning)
+                    .map(|w| w.to_string()),
+            }),
+            Err(e) => {
+                tracing::debug!(e = ?e, "Failed to estimate password strength");
+                violations.push(PasswordPolicyViolation::TooWeak { warning: None });
+            },
+        }
+
+        if let Some(breached) = &self.breached_passwords {
+            if breached.is_breached(password) {
+                violations.push(PasswordPolicyViolation::Breached);
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            tracing::debug!(violations = ?violations, "Password failed policy checks");
+            Err(PasswordPolicyError(violations))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+
+    /// Breached passwords source that knows about exactly one password.
+    struct SingleBreachedPassword(&'static str);
+
+    impl BreachedPasswords for SingleBreachedPassword {
+        fn is_breached(&self, password: &str) -> bool {
+            password == self.0
+        }
+    }
+
+    #[test]
+    fn strong_password() {
+        let sut = PasswordPolicy::new(8, 3);
+
+        check!(sut.check("Hedgehog-Lantern-47", &["testuser"]).is_ok());
+    }
+
+    #[test]
+    fn too_short() {
+        let sut = PasswordPolicy::new(30, 3);
+
+        let_assert!(Err(PasswordPolicyError(violations)) = sut.check("Hedgehog-Lantern-47", &[]));
+        check!(violations == vec![PasswordPolicyViolation::TooShort { min_length: 30 }]);
+    }
+
+    #[test]
+    fn too_long() {
+        let sut = PasswordPolicy::new(8, 3).with_breached_passwords(SingleBreachedPassword("pass"));
+
+        let_assert!(Err(PasswordPolicyError(violations)) = sut.check(&"Hedgehog-Lantern-47".repeat(10), &[]));
+        check!(violations == vec![PasswordPolicyViolation::TooLong { max_length: 128 }]);
+    }
+
+    #[test]
+    fn longest_password() {
+        let sut = PasswordPolicy::new(8, 3);
+
+        check!(sut.check(&"Hedgehog-Lantern-47".repeat(10)[..128], &[]).is_ok());
+    }
+
+    #[test]
+    fn blank_password() {
+        let sut = PasswordPolicy::new(0, 3);
+
+        let_assert!(Err(PasswordPolicyError(violations)) = sut.check("", &[]));
+        check!(violations == vec![PasswordPolicyViolation::TooWeak { warning: None }]);
+    }
+
+    #[test]
+    fn too_weak() {
+        let sut = PasswordPolicy::new(8, 3);
+
+        let_assert!(Err(PasswordPolicyError(violations)) = sut.check("password", &[]));
+        check!(
+            violations
+                == vec![PasswordPolicyViolation::TooWeak {
+                    warning: Some("This is a top-10 common password.".to_owned()),
+                }]
+        );
+    }
+
+    #[test]
+    fn based_on_user_inputs() {
+        let sut = PasswordPolicy::new(8, 3);
+
+        check!(sut.check("zaphodbeeblebrox42", &[]).is_ok());
+        let_assert!(Err(PasswordPolicyError(violations)) = sut.check("zaphodbeeblebrox42", &["zaphodbeeblebrox", "zaphod@example.com"]));
+        check!(violations == vec![PasswordPolicyViolation::TooWeak { warning: None }]);
+    }
+
+    #[test]
+    fn breached() {
+        let sut = PasswordPolicy::new(8, 3).with_breached_passwords(SingleBreachedPassword("Hedgehog-Lantern-47"));
+
+        check!(sut.check("Lantern-Hedgehog-47", &[]).is_ok());
+        let_assert!(Err(PasswordPolicyError(violations)) = sut.check("Hedgehog-Lantern-47", &[]));
+        check!(violations == vec![PasswordPolicyViolation::Breached]);
+    }
+
+    #[test]
+    fn multiple_violations() {
+        let sut = PasswordPolicy::new(8, 3).with_breached_passwords(SingleBreachedPassword("pass"));
+
+        let_assert!(Err(PasswordPolicyError(violations)) = sut.check("pass", &[]));
+        check!(violations.len() == 3);
+        check!(violations[0] == PasswordPolicyViolation::TooShort { min_length: 8 });
+        check!(matches!(violations[1], PasswordPolicyViolation::TooWeak { .. }));
+        check!(violations[2] == PasswordPolicyViolation::Breached);
+    }
+}
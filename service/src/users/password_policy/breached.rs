@@ -0,0 +1,100 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    path::PathBuf,
+};
+
+use sha1::{Digest, Sha1};
+
+/// Source of passwords that are known to have appeared in data breaches.
+pub trait BreachedPasswords: Send + Sync {
+    /// Determine if the provided password has appeared in a known data breach.
+    ///
+    /// # Parameters
+    /// - `password` - The plaintext password to check
+    ///
+    /// # Returns
+    /// True if the password is known to be breached. False if not.
+    fn is_breached(&self, password: &str) -> bool;
+}
+
+/// Breached passwords source backed by a local copy of a k-anonymity hash-prefix dataset.
+///
+/// The dataset is a directory containing one file for every 5 character prefix of the upper-case
+/// hex SHA-1 password hashes, named `<PREFIX>.txt`. Each file contains one line for every breached
+/// password hash starting with that prefix, in the form `<SUFFIX>:<COUNT>`. This is the same format
+/// as served by the "Pwned Passwords" range API.
+pub struct HashPrefixDataset {
+    root: PathBuf,
+}
+
+impl HashPrefixDataset {
+    /// Create a new hash-prefix dataset.
+    ///
+    /// # Parameters
+    /// - `root` - The directory that the dataset files are stored in
+    pub fn new<P>(root: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self { root: root.into() }
+    }
+}
+
+impl BreachedPasswords for HashPrefixDataset {
+    fn is_breached(&self, password: &str) -> bool {
+        let hash = format!("{:X}", Sha1::digest(password.as_bytes()));
+        let (prefix, suffix) = hash.split_at(5);
+
+        let path = self.root.join(format!("{}.txt", prefix));
+        let file = match File::open(&path) {
+            Ok(file) => file,
+            Err(e) => {
+                tracing::warn!(e = ?e, path = ?path, "Failed to open breached passwords file");
+                return false;
+            },
+        };
+
+        BufReader::new(file).lines().map_while(Result::ok).any(|line| {
+            let mut parts = line.trim().splitn(2, ':');
+            let candidate = parts.next().unwrap_or_default();
+            let count = parts.next().and_then(|c| c.parse::<u64>().ok()).unwrap_or(1);
+
+            count > 0 && candidate.eq_ignore_ascii_case(suffix)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use test_case::test_case;
+
+    use super::*;
+
+    fn build_dataset() -> HashPrefixDataset {
+        let root = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+        std::fs::create_dir_all(&root).unwrap();
+
+        // SHA-1 of "password" is 5BAA61E4C9B93F3F0682250B6CF8331B7EE68FD8
+        std::fs::write(
+            root.join("5BAA6.txt"),
+            "003D68EB55068C33ACE09247EE4C639306B:3\r\n1E4C9B93F3F0682250B6CF8331B7EE68FD8:3861493\r\n",
+        )
+        .unwrap();
+        // SHA-1 of "letmein" is B7A875FC1EA228B9061041B7CEC4BD3C52AB3CE3
+        std::fs::write(root.join("B7A87.txt"), "5FC1EA228B9061041B7CEC4BD3C52AB3CE3:0\r\n").unwrap();
+
+        HashPrefixDataset::new(root)
+    }
+
+    #[test_case("password", true ; "Breached")]
+    #[test_case("Password", false ; "Different case")]
+    #[test_case("letmein", false ; "Padding entry")]
+    #[test_case("Hedgehog-Lantern-47", false ; "Missing prefix file")]
+    fn is_breached(password: &str, expected: bool) {
+        let sut = build_dataset();
+
+        check!(sut.is_breached(password) == expected);
+    }
+}
@@ -0,0 +1,132 @@
+use serde::{ser::SerializeMap, Serialize, Serializer};
+
+use crate::http::{problem::Problem, valid::VALIDATION_ERROR};
+
+/// A single rule of the password policy that a password failed to satisfy.
+#[derive(Debug, PartialEq)]
+pub enum PasswordPolicyViolation {
+    /// The password was shorter than the minimum length.
+    TooShort { min_length: usize },
+    /// The password was longer than the maximum length.
+    TooLong { max_length: usize },
+    /// The password was too easy to guess.
+    TooWeak { warning: Option<String> },
+    /// The password is known to have appeared in a data breach.
+    Breached,
+}
+
+/// Error indicating that a password failed to satisfy the password policy.
+#[derive(Debug, PartialEq, thiserror::Error)]
+#[error("The password failed to satisfy the password policy")]
+pub struct PasswordPolicyError(pub Vec<PasswordPolicyViolation>);
+
+impl PasswordPolicyViolation {
+    /// The code to use for this violation.
+    fn code(&self) -> &'static str {
+        match self {
+            Self::TooShort { .. } => "password_too_short",
+            Self::TooLong { .. } => "password_too_long",
+            Self::TooWeak { .. } => "password_too_weak",
+            Self::Breached => "password_breached",
+        }
+    }
+
+    /// The title to use for this violation.
+    fn title(&self) -> &'static str {
+        match self {
+            Self::TooShort { .. } => "Password is too short",
+            Self::TooLong { .. } => "Password is too long",
+            Self::TooWeak { .. } => "Password is too easy to guess",
+            Self::Breached => "Password has appeared in a known data breach",
+        }
+    }
+
+    /// Any extra detail to use for this violation.
+    fn detail(&self) -> Option<String> {
+        match self {
+            Self::TooShort { min_length } => Some(format!("The password must be at least {} characters long", min_length)),
+            Self::TooLong { max_length } => Some(format!("The password must be at most {} characters long", max_length)),
+            Self::TooWeak { warning } => warning.clone(),
+            Self::Breached => None,
+        }
+    }
+}
+
+/// Serialize the violation in the same shape as the JSON Schema validation errors, so that clients
+/// can handle both the same way.
+impl Serialize for PasswordPolicyViolation {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let detail = self.detail();
+
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("code", self.code())?;
+        map.serialize_entry("title", self.title())?;
+        map.serialize_entry("path", "/password")?;
+        if let Some(detail) = detail {
+            map.serialize_entry("detail", &detail)?;
+        }
+        map.end()
+    }
+}
+
+impl From<PasswordPolicyError> for Problem {
+    fn from(e: PasswordPolicyError) -> Self {
+        Problem::from(VALIDATION_ERROR).with_extra("validationErrors", e.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_json_snapshot;
+
+    use super::*;
+
+    #[test]
+    fn serialize_violations() {
+        let violations = vec![
+            PasswordPolicyViolation::TooShort { min_length: 8 },
+            PasswordPolicyViolation::TooLong { max_length: 128 },
+            PasswordPolicyViolation::TooWeak {
+                warning: Some("This is a top-10 common password.".to_owned()),
+            },
+            PasswordPolicyViolation::TooWeak { warning: None },
+            PasswordPolicyViolation::Breached,
+        ];
+
+        assert_json_snapshot!(violations, @r###"
+        [
+          {
+            "code": "password_too_short",
+            "title": "Password is too short",
+            "path": "/password",
+            "detail": "The password must be at least 8 characters long"
+          },
+          {
+            "code": "password_too_long",
+            "title": "Password is too long",
+            "path": "/password",
+            "detail": "The password must be at most 128 characters long"
+          },
+          {
+            "code": "password_too_weak",
+            "title": "Password is too easy to guess",
+            "path": "/password",
+            "detail": "This is a top-10 common password."
+          },
+          {
+            "code": "password_too_weak",
+            "title": "Password is too easy to guess",
+            "path": "/password"
+          },
+          {
+            "code": "password_breached",
+            "title": "Password has appeared in a known data breach",
+            "path": "/password"
+          }
+        ]
+        "###);
+    }
+}
@@ -1,21 +1,68 @@
-mod get_user;
+#[cfg(test)]
+mod memory;
 mod parse;
+mod postgres;
 mod save_user;
 
-use std::sync::Arc;
-
+#[cfg(test)]
+pub use memory::MemoryUserRepository;
+pub use postgres::PostgresUserRepository;
 pub use save_user::SaveUserError;
 
-use crate::database::Database;
+use crate::users::{Password, UserData, UserEvent, UserId, UserResource, Username};
 
 /// Repository of user records.
-pub struct UserRepository {
-    database: Arc<Database>,
-}
+#[async_trait::async_trait]
+pub trait UserRepository: Send + Sync {
+    /// Get the User Resource that has the provided User ID.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user to fetch.
+    ///
+    /// # Returns
+    /// The user resource, or `None` if it couldn't be found.
+    async fn get_user_by_id(&self, user_id: &UserId) -> Option<UserResource>;
+
+    /// Get the User Resource that has the provided Username.
+    ///
+    /// # Parameters
+    /// - `username` - The username of the user to fetch.
+    ///
+    /// # Returns
+    /// The user resource, or `None` if it couldn't be found.
+    async fn get_user_by_username(&self, username: &Username) -> Option<UserResource>;
+
+    /// Create a new user record from the provided User data.
+    ///
+    /// # Parameters
+    /// - `user` - The details of the user to create.
+    /// - `events` - The events to write to the outbox, only if the user is created.
+    ///
+    /// # Returns
+    /// The created user resource.
+    async fn create_user(&self, user: &UserData, events: &[UserEvent]) -> Result<UserResource, SaveUserError>;
+
+    /// Update an existing user record from the provided User data.
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the user to update.
+    /// - `data` - The new details of the user.
+    /// - `events` - The events to write to the outbox, only if the user is updated.
+    ///
+    /// # Returns
+    /// The updated user resource.
+    async fn update_user(&self, id: &UserId, data: &UserData, events: &[UserEvent]) -> Result<UserResource, SaveUserError>;
 
-impl UserRepository {
-    /// Create a new user repository.
-    pub fn new(database: Arc<Database>) -> Self {
-        Self { database }
-    }
+    /// Replace the stored hash of a user's password with a new hash of the same password. This
+    /// isn't a change to the user, so no events are written and the version is left alone.
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the user
+    /// - `old_password` - The hash that is expected to be stored
+    /// - `new_password` - The new hash to store
+    ///
+    /// # Returns
+    /// Whether the hash was replaced. It isn't if the user doesn't exist, or if their password has
+    /// changed since `old_password` was read.
+    async fn replace_password_hash(&self, id: &UserId, old_password: &Password, new_password: &Password) -> bool;
 }
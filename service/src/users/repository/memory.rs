@@ -0,0 +1,258 @@
+use std::sync::Mutex;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::{SaveUserError, UserRepository};
+use crate::{
+    model::Identity,
+    outbox::NewOutboxEvent,
+    users::{Password, UserData, UserEvent, UserId, UserResource, Username},
+};
+
+/// Repository of user records that are stored in memory.
+///
+/// Usernames and emails are checked for uniqueness the same way as the unique indexes on the
+/// `users` table, so that services can be tested against it without starting Postgres.
+#[derive(Default)]
+pub struct MemoryUserRepository {
+    users:  Mutex<Vec<UserResource>>,
+    events: Mutex<Vec<NewOutboxEvent>>,
+}
+
+impl MemoryUserRepository {
+    /// Create a new, empty user repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get every event that has been written to the outbox, oldest first.
+    pub fn events(&self) -> Vec<NewOutboxEvent> {
+        self.events.lock().unwrap().clone()
+    }
+
+    /// Write events about a user to the outbox.
+    fn write_events(&self, user_id: &UserId, events: &[UserEvent]) {
+        let user_id = user_id.to_string();
+
+        self.events
+            .lock()
+            .unwrap()
+            .extend(events.iter().map(|event| NewOutboxEvent::new(&user_id, event)));
+    }
+}
+
+#[async_trait::async_trait]
+impl UserRepository for MemoryUserRepository {
+    async fn get_user_by_id(&self, user_id: &UserId) -> Option<UserResource> {
+        let users = self.users.lock().unwrap();
+
+        users.iter().find(|u| &u.identity.id == user_id).cloned()
+    }
+
+    async fn get_user_by_username(&self, username: &Username) -> Option<UserResource> {
+        let users = self.users.lock().unwrap();
+
+        users.iter().find(|u| u.data.username.key() == username.key()).cloned()
+    }
+
+    async fn create_user(&self, user: &UserData, events: &[UserEvent]) -> Result<UserResource, SaveUserError> {
+        let mut users = self.users.lock().unwrap();
+
+        check_unique(users.iter(), user)?;
+
+        let created = UserResource {
+            identity: Identity::default(),
+            data:     user.clone(),
+        };
+        users.push(created.clone());
+        self.write_events(&created.identity.id, events);
+
+        Ok(created)
+    }
+
+    async fn update_user(&self, id: &UserId, data: &UserData, events: &[UserEvent]) -> Result<UserResource, SaveUserError> {
+        let mut users = self.users.lock().unwrap();
+
+        check_unique(users.iter().filter(|u| &u.identity.id != id), data)?;
+
+        let user = users.iter_mut().find(|u| &u.identity.id == id).ok_or(SaveUserError::UnknownUser)?;
+        user.identity.version = Uuid::new_v4();
+        user.identity.updated = Utc::now();
+        user.data = data.clone();
+        self.write_events(id, events);
+
+        Ok(user.clone())
+    }
+
+    async fn replace_password_hash(&self, id: &UserId, old_password: &Password, new_password: &Password) -> bool {
+        let mut users = self.users.lock().unwrap();
+
+        match users.iter_mut().find(|u| &u.identity.id == id && &u.data.password == old_password) {
+            Some(user) => {
+                user.data.password = new_password.clone();
+                true
+            },
+            None => false,
+        }
+    }
+}
+
+/// Check that the provided user data doesn't clash with any of the existing users.
+///
+/// # Parameters
+/// - `existing` - The existing users to compare against
+/// - `data` - The user data to check
+fn check_unique<'a, I>(mut existing: I, data: &UserData) -> Result<(), SaveUserError>
+where
+    I: Iterator<Item = &'a UserResource>,
+{
+    existing.try_for_each(|u| {
+        if u.data.username.key() == data.username.key() {
+            Err(SaveUserError::DuplicateUsername)
+        } else if u.data.email == data.email {
+            Err(SaveUserError::DuplicateEmail)
+        } else {
+            Ok(())
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+
+    fn user_data(username: &str) -> UserData {
+        UserData {
+            username:     username.parse().unwrap(),
+            email:        format!("{}@example.com", username).parse().unwrap(),
+            display_name: "Test User".to_owned(),
+            password:     Password::from_hash("hashed"),
+        }
+    }
+
+    #[actix_rt::test]
+    async fn create_and_fetch() {
+        let sut = MemoryUserRepository::new();
+
+        let_assert!(Ok(created) = sut.create_user(&user_data("testuser"), &[]).await);
+
+        let_assert!(Some(by_id) = sut.get_user_by_id(&created.identity.id).await);
+        check!(by_id.identity.version == created.identity.version);
+        check!(by_id.data.username == created.data.username);
+
+        let_assert!(Some(by_username) = sut.get_user_by_username(&"testuser".parse().unwrap()).await);
+        check!(by_username.identity.id == created.identity.id);
+
+        let_assert!(Some(by_username) = sut.get_user_by_username(&"TestUser".parse().unwrap()).await);
+        check!(by_username.identity.id == created.identity.id);
+
+        check!(sut.get_user_by_username(&"otheruser".parse().unwrap()).await.is_none());
+    }
+
+    #[actix_rt::test]
+    async fn create_duplicate_username() {
+        let sut = MemoryUserRepository::new();
+
+        let_assert!(Ok(_) = sut.create_user(&user_data("testuser"), &[]).await);
+        let_assert!(Err(e) = sut.create_user(&user_data("testuser"), &[]).await);
+        check!(e == SaveUserError::DuplicateUsername);
+    }
+
+    #[actix_rt::test]
+    async fn create_duplicate_username_different_case() {
+        let sut = MemoryUserRepository::new();
+
+        let_assert!(Ok(_) = sut.create_user(&user_data("testuser"), &[]).await);
+        let_assert!(Err(e) = sut.create_user(&user_data("TestUser"), &[]).await);
+        check!(e == SaveUserError::DuplicateUsername);
+    }
+
+    #[actix_rt::test]
+    async fn create_duplicate_email() {
+        let sut = MemoryUserRepository::new();
+
+        let_assert!(Ok(_) = sut.create_user(&user_data("testuser"), &[]).await);
+        let_assert!(
+            Err(e) = sut
+                .create_user(
+                    &UserData {
+                        email: "TestUser@Example.com".parse().unwrap(),
+                        ..user_data("otheruser")
+                    },
+                    &[]
+                )
+                .await
+        );
+        check!(e == SaveUserError::DuplicateEmail);
+    }
+
+    #[actix_rt::test]
+    async fn update_user() {
+        let sut = MemoryUserRepository::new();
+
+        let_assert!(Ok(created) = sut.create_user(&user_data("testuser"), &[]).await);
+        let_assert!(Ok(updated) = sut.update_user(&created.identity.id, &user_data("renamed"), &[]).await);
+
+        check!(updated.identity.id == created.identity.id);
+        check!(updated.identity.version != created.identity.version);
+        check!(updated.data.username == "renamed".parse().unwrap());
+    }
+
+    #[actix_rt::test]
+    async fn update_unknown_user() {
+        let sut = MemoryUserRepository::new();
+
+        let_assert!(Err(e) = sut.update_user(&UserId::default(), &user_data("testuser"), &[]).await);
+        check!(e == SaveUserError::UnknownUser);
+    }
+
+    #[actix_rt::test]
+    async fn update_duplicate_username() {
+        let sut = MemoryUserRepository::new();
+
+        let_assert!(Ok(_) = sut.create_user(&user_data("first"), &[]).await);
+        let_assert!(Ok(second) = sut.create_user(&user_data("second"), &[]).await);
+        let_assert!(Err(e) = sut.update_user(&second.identity.id, &user_data("first"), &[]).await);
+        check!(e == SaveUserError::DuplicateUsername);
+    }
+
+    #[actix_rt::test]
+    async fn replace_password_hash() {
+        let sut = MemoryUserRepository::new();
+
+        let_assert!(Ok(created) = sut.create_user(&user_data("testuser"), &[]).await);
+        check!(
+            sut.replace_password_hash(
+                &created.identity.id,
+                &Password::from_hash("hashed"),
+                &Password::from_hash("rehashed")
+            )
+            .await
+        );
+
+        let_assert!(Some(user) = sut.get_user_by_id(&created.identity.id).await);
+        check!(user.data.password == Password::from_hash("rehashed"));
+        check!(user.identity.version == created.identity.version);
+    }
+
+    #[actix_rt::test]
+    async fn replace_changed_password_hash() {
+        let sut = MemoryUserRepository::new();
+
+        let_assert!(Ok(created) = sut.create_user(&user_data("testuser"), &[]).await);
+        check!(
+            !sut.replace_password_hash(
+                &created.identity.id,
+                &Password::from_hash("stale"),
+                &Password::from_hash("rehashed")
+            )
+            .await
+        );
+
+        let_assert!(Some(user) = sut.get_user_by_id(&created.identity.id).await);
+        check!(user.data.password == Password::from_hash("hashed"));
+    }
+}
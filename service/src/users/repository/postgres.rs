@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::{SaveUserError, UserRepository};
+use crate::{
+    database::Database,
+    model::Identity,
+    outbox::{write_outbox_events, NewOutboxEvent},
+    users::{Password, UserData, UserEvent, UserId, UserResource, Username},
+};
+
+/// Repository of user records that are stored in Postgres.
+pub struct PostgresUserRepository {
+    database: Arc<Database>,
+}
+
+impl PostgresUserRepository {
+    /// Create a new user repository.
+    pub fn new(database: Arc<Database>) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait::async_trait]
+impl UserRepository for PostgresUserRepository {
+    #[tracing::instrument(skip(self))]
+    async fn get_user_by_id(&self, user_id: &UserId) -> Option<UserResource> {
+        let conn = self.database.connect().await;
+        conn.query_opt("SELECT * FROM users WHERE user_id = $1", &[&user_id])
+            .await
+            .ok()?
+            .map(UserResource::from)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_user_by_username(&self, username: &Username) -> Option<UserResource> {
+        let conn = self.database.connect().await;
+        conn.query_opt("SELECT * FROM users WHERE username_key = $1", &[&username.key()])
+            .await
+            .ok()?
+            .map(UserResource::from)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn create_user(&self, user: &UserData, events: &[UserEvent]) -> Result<UserResource, SaveUserError> {
+        let mut conn = self.database.connect().await;
+        let tx = conn.begin().await;
+
+        let identity = Identity::<UserId>::default();
+
+        let created: UserResource = tx.query("INSERT INTO users(user_id, version, created, updated, username, username_key, display_name, email, password) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) RETURNING *", 
+        &[
+          &identity.id,
+          &identity.version,
+          &identity.created,
+          &identity.updated,
+          &user.username,
+          &user.username.key(),
+          &user.display_name,
+          &user.email,
+          &user.password,
+          ])
+            .await?
+            .into_iter()
+            .map(UserResource::from)
+            .next()
+            .ok_or(SaveUserError::UnknownError)?;
+
+        write_outbox_events(&tx, &outbox_events(&created.identity.id, events)).await?;
+        tx.commit().await?;
+
+        Ok(created)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn update_user(&self, id: &UserId, data: &UserData, events: &[UserEvent]) -> Result<UserResource, SaveUserError> {
+        let mut conn = self.database.connect().await;
+        let tx = conn.begin().await;
+
+        let version = Uuid::new_v4();
+        let updated = Utc::now();
+
+        let user = tx.query("UPDATE users SET version = $2, updated = $3, username = $4, username_key = $5, display_name = $6, email = $7, password = $8 WHERE user_id = $1 RETURNING *", 
+        &[
+          &id,
+          &version,
+          &updated,
+          &data.username,
+          &data.username.key(),
+          &data.display_name,
+          &data.email,
+          &data.password,
+          ])
+            .await?
+            .into_iter()
+            .map(UserResource::from)
+            .next()
+            .ok_or(SaveUserError::UnknownUser)?;
+
+        write_outbox_events(&tx, &outbox_events(id, events)).await?;
+        tx.commit().await?;
+
+        Ok(user)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn replace_password_hash(&self, id: &UserId, old_password: &Password, new_password: &Password) -> bool {
+        let conn = self.database.connect().await;
+        conn.execute(
+            "UPDATE users SET password = $3 WHERE user_id = $1 AND password = $2",
+            &[&id, &old_password, &new_password],
+        )
+        .await
+        .is_ok_and(|count| count == 1)
+    }
+}
+
+/// Prepare the events about a user to be written to the outbox.
+///
+/// # Parameters
+/// - `user_id` - The ID of the user
+/// - `events` - The events
+fn outbox_events(user_id: &UserId, events: &[UserEvent]) -> Vec<NewOutboxEvent> {
+    let user_id = user_id.to_string();
+
+    events.iter().map(|event| NewOutboxEvent::new(&user_id, event)).collect()
+}
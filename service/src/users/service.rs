@@ -1,20 +1,43 @@
 mod create_user;
 mod get_user;
+mod passwords;
 mod update_user;
 
-pub use create_user::CreateUserError;
+use std::sync::Arc;
+
+pub use create_user::{CreateUserError, NewUser};
 pub use update_user::UpdateUserError;
 
-use super::repository::UserRepository;
+use super::{repository::UserRepository, PasswordHasher, PasswordPolicy};
+use crate::revisions::RevisionService;
 
 /// Service layer for working with users.
 pub struct UserService {
-    repository: UserRepository,
+    repository:      Arc<dyn UserRepository>,
+    revisions:       Arc<RevisionService>,
+    password_policy: Arc<PasswordPolicy>,
+    password_hasher: Arc<PasswordHasher>,
 }
 
 impl UserService {
     /// Create a new user service.
-    pub fn new(repository: UserRepository) -> Self {
-        Self { repository }
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store users in
+    /// - `revisions` - The service to record the revision history of users with
+    /// - `password_policy` - The policy that new passwords must satisfy
+    /// - `password_hasher` - The mechanism to hash new passwords with
+    pub fn new(
+        repository: Arc<dyn UserRepository>,
+        revisions: Arc<RevisionService>,
+        password_policy: Arc<PasswordPolicy>,
+        password_hasher: Arc<PasswordHasher>,
+    ) -> Self {
+        Self {
+            repository,
+            revisions,
+            password_policy,
+            password_hasher,
+        }
     }
 }
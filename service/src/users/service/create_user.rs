@@ -1,25 +1,55 @@
 use super::UserService;
-use crate::users::{repository::SaveUserError, UserData, UserResource};
+use crate::users::{repository::SaveUserError, Email, PasswordPolicyError, UserData, UserEvent, UserResource, Username};
+
+/// The details needed to create a new user.
+#[derive(Debug)]
+pub struct NewUser {
+    pub username:     Username,
+    pub email:        Email,
+    pub display_name: String,
+    /// The plaintext password, which is checked against the password policy before being hashed.
+    pub password:     String,
+}
 
 #[derive(Debug, PartialEq, thiserror::Error)]
 pub enum CreateUserError {
+    #[error("The password failed to satisfy the password policy")]
+    InvalidPassword(#[from] PasswordPolicyError),
+
     #[error("Duplicate username")]
     DuplicateUsername,
 
+    #[error("Duplicate email address")]
+    DuplicateEmail,
+
     #[error("An unknown error occurred")]
     UnknownError,
 }
 
 impl UserService {
-    /// Create a new user in the repositry from the provided user data.
+    /// Create a new user in the repositry from the provided details, as long as their password
+    /// satisfies the password policy.
+    /// The new user is recorded as the first revision of itself, and a `user.registered` event is
+    /// written to the outbox along with it.
     ///
     /// # Parameters
-    /// - `user` - The user data to create the user from
+    /// - `user` - The details to create the user from
     ///
     /// # Returns
     /// The newly created user.
-    pub async fn create_user(&self, user: UserData) -> Result<UserResource, CreateUserError> {
-        let user = self.repository.create_user(&user).await?;
+    pub async fn create_user(&self, user: NewUser) -> Result<UserResource, CreateUserError> {
+        let password = self
+            .hash_new_password(&user.password, &[user.username.as_ref(), user.email.as_ref(), &user.display_name])
+            .await?;
+        let user = UserData {
+            username: user.username,
+            email: user.email,
+            display_name: user.display_name,
+            password,
+        };
+
+        let user = self.repository.create_user(&user, &[UserEvent::registered(&user)]).await?;
+        self.revisions.record(&user, &user.identity.id).await;
 
         Ok(user)
     }
@@ -29,6 +59,7 @@ impl From<SaveUserError> for CreateUserError {
     fn from(e: SaveUserError) -> Self {
         match e {
             SaveUserError::DuplicateUsername => Self::DuplicateUsername,
+            SaveUserError::DuplicateEmail => Self::DuplicateEmail,
             SaveUserError::UnknownUser => unreachable!("This error is impossible for creating new users"),
             SaveUserError::UnknownError => Self::UnknownError,
         }
@@ -0,0 +1,46 @@
+use super::UserService;
+use crate::users::{Password, PasswordPolicyError, UserId};
+
+impl UserService {
+    /// Check a new password for a user against the password policy, and hash it ready to be stored.
+    /// Every password that a user chooses must come through here.
+    ///
+    /// Checking the policy estimates the strength of the password and may read the breached
+    /// passwords dataset from disk, so it is performed on a separate thread pool, as hashing is.
+    ///
+    /// # Parameters
+    /// - `password` - The plaintext password
+    /// - `user_inputs` - Other details of the user, such as their username or email address, that
+    ///   the password should not be based on
+    ///
+    /// # Returns
+    /// The hashed password, or the rules of the policy that it violates.
+    pub async fn hash_new_password(&self, password: &str, user_inputs: &[&str]) -> Result<Password, PasswordPolicyError> {
+        let policy = self.password_policy.clone();
+        let plaintext = password.to_owned();
+        let user_inputs: Vec<String> = user_inputs.iter().map(|input| (*input).to_owned()).collect();
+
+        actix_web::web::block(move || {
+            let user_inputs: Vec<&str> = user_inputs.iter().map(String::as_str).collect();
+            policy.check(&plaintext, &user_inputs)
+        })
+        .await
+        .expect("Failed to schedule password policy check")?;
+
+        Ok(self.password_hasher.hash(password).await)
+    }
+
+    /// Store a new hash of a user's current password, such as one made with stronger settings.
+    /// The user hasn't changed, so this writes no events and doesn't record a revision.
+    ///
+    /// # Parameters
+    /// - `user_id` - The ID of the user
+    /// - `old_password` - The hash of the password that the new hash replaces
+    /// - `new_password` - The new hash of the same password
+    ///
+    /// # Returns
+    /// Whether the new hash was stored. It isn't if the password was changed concurrently.
+    pub async fn rehash_password(&self, user_id: &UserId, old_password: &Password, new_password: &Password) -> bool {
+        self.repository.replace_password_hash(user_id, old_password, new_password).await
+    }
+}
@@ -1,5 +1,5 @@
 use super::UserService;
-use crate::users::{repository::SaveUserError, UserData, UserId, UserResource};
+use crate::users::{repository::SaveUserError, UserData, UserEvent, UserId, UserResource};
 
 #[derive(Debug, PartialEq, thiserror::Error)]
 pub enum UpdateUserError<E>
@@ -12,6 +12,9 @@ where
     #[error("Duplicate username")]
     DuplicateUsername,
 
+    #[error("Duplicate email address")]
+    DuplicateEmail,
+
     #[error("An error occurred updating the user data")]
     UpdateError(E),
 
@@ -21,23 +24,27 @@ where
 
 impl UserService {
     /// Update the user that has the provided ID, using the provided lambda to perform the updates.
+    /// Events describing what changed are written to the outbox along with the user.
     ///
     /// # Parameters
     /// - `user_id` - The ID of the user to update
+    /// - `changed_by` - The ID of the user that is making the change
     /// - `f` - The function to update the user details
     ///
     /// # Returns
     /// The newly updated user.
-    pub async fn update_user_by_id<F, E>(&self, user_id: &UserId, f: F) -> Result<UserResource, UpdateUserError<E>>
+    pub async fn update_user_by_id<F, E>(&self, user_id: &UserId, changed_by: &UserId, f: F) -> Result<UserResource, UpdateUserError<E>>
     where
         F: FnOnce(UserData) -> Result<UserData, E>,
         E: std::fmt::Debug,
     {
         let user = self.repository.get_user_by_id(user_id).await.ok_or(UpdateUserError::UnknownUser)?;
 
-        let data = f(user.data).map_err(UpdateUserError::UpdateError)?;
+        let data = f(user.data.clone()).map_err(UpdateUserError::UpdateError)?;
+        let events = UserEvent::changes(&user.data, &data);
 
-        let result = self.repository.update_user(&user_id, &data).await?;
+        let result = self.repository.update_user(user_id, &data, &events).await?;
+        self.revisions.record(&result, changed_by).await;
 
         Ok(result)
     }
@@ -50,8 +57,124 @@ where
     fn from(e: SaveUserError) -> Self {
         match e {
             SaveUserError::DuplicateUsername => Self::DuplicateUsername,
+            SaveUserError::DuplicateEmail => Self::DuplicateEmail,
             SaveUserError::UnknownUser => Self::UnknownUser,
             SaveUserError::UnknownError => Self::UnknownError,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::{
+        revisions::{MemoryRevisionRepository, RevisionService},
+        users::{MemoryUserRepository, NewUser, Password, PasswordHasher, PasswordPolicy},
+    };
+
+    async fn build_service() -> (UserService, UserResource) {
+        let sut = UserService::new(
+            Arc::new(MemoryUserRepository::new()),
+            Arc::new(RevisionService::new(Arc::new(MemoryRevisionRepository::new()))),
+            Arc::new(PasswordPolicy::new(8, 3)),
+            Arc::new(PasswordHasher::new(64, 1, 1)),
+        );
+
+        let user = sut
+            .create_user(NewUser {
+                username:     "testuser".parse().unwrap(),
+                email:        "testuser@example.com".parse().unwrap(),
+                display_name: "Test User".to_owned(),
+                password:     "Hedgehog-Lantern-47".to_owned(),
+            })
+            .await
+            .unwrap();
+
+        (sut, user)
+    }
+
+    #[actix_rt::test]
+    async fn update_user() {
+        let (sut, user) = build_service().await;
+
+        let result = sut
+            .update_user_by_id(&user.identity.id, &user.identity.id, |data| {
+                Ok::<_, ()>(UserData {
+                    display_name: "New Name".to_owned(),
+                    ..data
+                })
+            })
+            .await;
+
+        let_assert!(Ok(updated) = result);
+        check!(updated.identity.id == user.identity.id);
+        check!(updated.data.display_name == "New Name");
+
+        let_assert!(Some(refetched) = sut.get_user_by_id(&user.identity.id).await);
+        check!(refetched.data.display_name == "New Name");
+    }
+
+    #[actix_rt::test]
+    async fn update_unknown_user() {
+        let (sut, user) = build_service().await;
+
+        let result = sut.update_user_by_id(&UserId::default(), &user.identity.id, Ok::<_, ()>).await;
+
+        let_assert!(Err(e) = result);
+        check!(e == UpdateUserError::UnknownUser);
+    }
+
+    #[actix_rt::test]
+    async fn update_fails() {
+        let (sut, user) = build_service().await;
+
+        let result = sut.update_user_by_id(&user.identity.id, &user.identity.id, |_| Err("Oops")).await;
+
+        let_assert!(Err(e) = result);
+        check!(e == UpdateUserError::UpdateError("Oops"));
+
+        let_assert!(Some(refetched) = sut.get_user_by_id(&user.identity.id).await);
+        check!(refetched.identity.version == user.identity.version);
+    }
+
+    #[actix_rt::test]
+    async fn write_events() {
+        let repository = Arc::new(MemoryUserRepository::new());
+        let sut = UserService::new(
+            repository.clone(),
+            Arc::new(RevisionService::new(Arc::new(MemoryRevisionRepository::new()))),
+            Arc::new(PasswordPolicy::new(8, 3)),
+            Arc::new(PasswordHasher::new(64, 1, 1)),
+        );
+        let user = sut
+            .create_user(NewUser {
+                username:     "testuser".parse().unwrap(),
+                email:        "testuser@example.com".parse().unwrap(),
+                display_name: "Test User".to_owned(),
+                password:     "Hedgehog-Lantern-47".to_owned(),
+            })
+            .await
+            .unwrap();
+
+        sut.update_user_by_id(&user.identity.id, &user.identity.id, Ok::<_, ()>)
+            .await
+            .unwrap();
+        sut.update_user_by_id(&user.identity.id, &user.identity.id, |data| {
+            Ok::<_, ()>(UserData {
+                password: Password::from_hash("rehashed"),
+                ..data
+            })
+        })
+        .await
+        .unwrap();
+
+        let events = repository.events();
+        let types: Vec<_> = events.iter().map(|e| e.event_type.as_str()).collect();
+        check!(types == vec!["user.registered", "user.password_changed"]);
+        check!(events.iter().all(|e| e.aggregate_id == user.identity.id.to_string()));
+    }
+}
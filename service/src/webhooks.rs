@@ -0,0 +1,15 @@
+pub mod component;
+mod deliver_webhook;
+mod endpoints;
+mod model;
+mod repository;
+mod sender;
+mod service;
+#[cfg(test)]
+pub mod testing;
+
+pub use deliver_webhook::*;
+pub use model::*;
+pub use repository::*;
+pub use sender::*;
+pub use service::*;
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use actix_web::web::{delete, get, patch, post, resource, ServiceConfig};
+
+use super::{repository::WebhookRepository, service::WebhookService, DeliverWebhook, WebhookSender};
+use crate::{
+    jobs::{JobRegistry, JobService},
+    server::RouteConfigurer,
+};
+
+/// Component for working with the webhooks of worlds, and for delivering changes to them.
+pub struct Component {
+    pub service: Arc<WebhookService>,
+}
+
+impl Component {
+    /// Create a new webhooks component.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store webhooks and their deliveries in
+    /// - `jobs` - The service to queue the deliveries that are sent again with
+    pub fn new(repository: Arc<dyn WebhookRepository>, jobs: Arc<JobService>) -> Arc<Self> {
+        let service = Arc::new(WebhookService::new(repository, WebhookSender::default(), jobs));
+
+        Arc::new(Self { service })
+    }
+
+    /// Register the jobs that deliver changes to webhooks.
+    ///
+    /// # Parameters
+    /// - `registry` - The registry to add the jobs to
+    ///
+    /// # Returns
+    /// The registry, with the jobs added.
+    pub fn register_jobs(&self, registry: JobRegistry) -> JobRegistry {
+        registry.with_job(DeliverWebhook::new(self.service.clone()))
+    }
+}
+
+impl RouteConfigurer for Component {
+    fn configure_routes(&self, config: &mut ServiceConfig) {
+        config.data(self.service.clone());
+
+        config.service(
+            resource("/worlds/{id}/webhooks")
+                .route(post().to(super::endpoints::create_webhook::handle))
+                .route(get().to(super::endpoints::list_webhooks::handle)),
+        );
+        config.service(
+            resource("/worlds/{id}/webhooks/{webhook}")
+                .route(get().to(super::endpoints::get_webhook::handle))
+                .route(patch().to(super::endpoints::patch_webhook::handle))
+                .route(delete().to(super::endpoints::delete_webhook::handle)),
+        );
+        config.service(resource("/worlds/{id}/webhooks/{webhook}/deliveries").route(get().to(super::endpoints::list_deliveries::handle)));
+        config.service(
+            resource("/worlds/{id}/webhooks/{webhook}/deliveries/{delivery}").route(get().to(super::endpoints::get_delivery::handle)),
+        );
+        config.service(
+            resource("/worlds/{id}/webhooks/{webhook}/deliveries/{delivery}/redeliver")
+                .route(post().to(super::endpoints::redeliver::handle)),
+        );
+    }
+}
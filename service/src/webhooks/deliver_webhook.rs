@@ -0,0 +1,136 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    service::{retry_delay, MAX_ATTEMPTS},
+    DeliveryId, DeliveryStatus, WebhookId, WebhookService,
+};
+use crate::jobs::{Job, JobError};
+
+/// Details of which delivery to attempt.
+///
+/// Jobs with this payload are also queued by the database, whenever a change is recorded, so its
+/// shape must match what `enqueue_webhook_deliveries` builds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliverWebhookPayload {
+    pub webhook_id:  WebhookId,
+    pub delivery_id: DeliveryId,
+}
+
+/// Job that makes an attempt at a delivery to a webhook, and has the queue retry it until the
+/// webhook accepts it or it is given up on.
+pub struct DeliverWebhook {
+    service: Arc<WebhookService>,
+}
+
+impl DeliverWebhook {
+    /// Create the job.
+    ///
+    /// # Parameters
+    /// - `service` - The service to deliver changes to webhooks with
+    pub fn new(service: Arc<WebhookService>) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Job for DeliverWebhook {
+    type Payload = DeliverWebhookPayload;
+
+    const KIND: &'static str = "webhooks.deliver";
+    const MAX_ATTEMPTS: u32 = MAX_ATTEMPTS;
+
+    async fn run(&self, payload: DeliverWebhookPayload) -> Result<(), JobError> {
+        match self.service.deliver(&payload.webhook_id, &payload.delivery_id, Utc::now()).await {
+            Some(DeliveryStatus::Pending) => Err(JobError::Retry("The webhook didn't accept the delivery".to_owned())),
+            _ => Ok(()),
+        }
+    }
+
+    fn retry_delay(attempts: u32) -> Duration {
+        retry_delay(attempts).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::{
+        jobs::{JobService, MemoryJobRepository},
+        realtime::{Change, ChangeAction},
+        users::UserId,
+        webhooks::{testing::StandInReceiver, MemoryWebhookRepository, NewWebhook, WebhookSender},
+        worlds::WorldId,
+    };
+
+    /// Queue a delivery to a webhook that is sent to a stand-in receiver.
+    ///
+    /// # Returns
+    /// The job to run, and the details of the delivery to run it with.
+    async fn build_delivery(receiver: &StandInReceiver) -> (DeliverWebhook, DeliverWebhookPayload) {
+        let repository = Arc::new(MemoryWebhookRepository::new());
+        let service = Arc::new(WebhookService::new(
+            repository.clone(),
+            WebhookSender::default().allowing_private_addresses(),
+            Arc::new(JobService::new(Arc::new(MemoryJobRepository::new()))),
+        ));
+        let world_id = WorldId::default();
+        let webhook = service
+            .create_webhook(
+                &world_id,
+                &UserId::default(),
+                NewWebhook {
+                    url:    receiver.url(),
+                    events: vec!["*.*".parse().unwrap()],
+                    active: true,
+                },
+            )
+            .await
+            .unwrap();
+        let queued = repository.record_change(&Change {
+            world_id,
+            action: ChangeAction::Created,
+            resource: "article".to_owned(),
+            resource_id: "gondor".to_owned(),
+            version: None,
+        });
+        let_assert!([delivery_id] = queued.as_slice());
+
+        let payload = DeliverWebhookPayload {
+            webhook_id:  webhook.identity.id,
+            delivery_id: *delivery_id,
+        };
+
+        (DeliverWebhook::new(service), payload)
+    }
+
+    #[actix_rt::test]
+    async fn accepted() {
+        let receiver = StandInReceiver::start(&[]);
+        let (sut, payload) = build_delivery(&receiver).await;
+
+        check!(sut.run(payload).await == Ok(()));
+        check!(receiver.received().len() == 1);
+    }
+
+    #[actix_rt::test]
+    async fn rejected() {
+        let receiver = StandInReceiver::start(&[500]);
+        let (sut, payload) = build_delivery(&receiver).await;
+
+        check!(sut.run(payload.clone()).await == Err(JobError::Retry("The webhook didn't accept the delivery".to_owned())));
+        check!(sut.run(payload).await == Ok(()));
+        check!(receiver.received().len() == 2);
+    }
+
+    #[test]
+    fn retry_on_the_schedule_of_deliveries() {
+        check!(DeliverWebhook::retry_delay(1) == Duration::from_mins(1));
+        check!(DeliverWebhook::retry_delay(3) == Duration::from_mins(4));
+    }
+}
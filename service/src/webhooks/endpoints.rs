@@ -0,0 +1,72 @@
+pub(super) mod create_webhook;
+pub(super) mod delete_webhook;
+pub(super) mod get_delivery;
+pub(super) mod get_webhook;
+pub(super) mod list_deliveries;
+pub(super) mod list_webhooks;
+mod model;
+pub(super) mod patch_webhook;
+mod problems;
+pub(super) mod redeliver;
+
+use crate::{
+    http::{
+        pagination::{CursorPagination, CursorQuery},
+        problem::{Problem, NOT_FOUND},
+    },
+    webhooks::{DeliveryId, WebhookId},
+    worlds::WorldId,
+};
+
+/// Parse the world and webhook IDs from the URL.
+///
+/// # Parameters
+/// - `world_id` - The ID of the world
+/// - `webhook_id` - The ID of the webhook
+///
+/// # Returns
+/// The parsed IDs, or a Not Found problem if either of them isn't valid.
+fn parse_webhook_path(world_id: &str, webhook_id: &str) -> Result<(WorldId, WebhookId), Problem> {
+    let world_id: WorldId = world_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, world_id = ?world_id, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+    let webhook_id: WebhookId = webhook_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, webhook_id = ?webhook_id, "Failed to parse Webhook ID");
+
+        NOT_FOUND
+    })?;
+
+    Ok((world_id, webhook_id))
+}
+
+/// Parse the ID of a delivery from the URL.
+///
+/// # Parameters
+/// - `delivery_id` - The ID of the delivery
+///
+/// # Returns
+/// The parsed ID, or a Not Found problem if it isn't valid.
+fn parse_delivery_id(delivery_id: &str) -> Result<DeliveryId, Problem> {
+    delivery_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, delivery_id = ?delivery_id, "Failed to parse Delivery ID");
+
+        NOT_FOUND.into()
+    })
+}
+
+/// Determine the page of deliveries that was requested.
+///
+/// # Parameters
+/// - `query` - The query parameters for the page
+///
+/// # Returns
+/// The page, or an Invalid Cursor problem if the cursor isn't the ID of a delivery.
+fn parse_pagination(query: &CursorQuery) -> Result<CursorPagination<DeliveryId>, Problem> {
+    query.pagination().map_err(|e| {
+        tracing::warn!(e = ?e, after = ?query.after, "Failed to parse delivery cursor");
+
+        problems::INVALID_CURSOR.into()
+    })
+}
@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use actix_http::http::StatusCode;
+use actix_web::web::{Data, Path};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{
+    model::{CreatedWebhookModel, WebhookModel},
+    problems::INVALID_URL,
+};
+use crate::{
+    authorization::Authentication,
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+        response::{Response, SimpleRespondable},
+        valid::{Valid, Validatable},
+    },
+    tokens::Scope,
+    webhooks::{CreateWebhookError, EventFilter, NewWebhook, WebhookService},
+    worlds::{Role, WorldId, WorldService},
+};
+
+/// Register a new webhook within a world, to be sent the changes to it as they happen.
+pub async fn handle(
+    service: Data<Arc<WebhookService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<String>,
+    request: Valid<CreateWebhookRequest>,
+    authentication: Authentication,
+) -> Result<Response<SimpleRespondable<CreatedWebhookModel>>, Problem> {
+    let world_id: WorldId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Owner).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+    let user_id = authentication.require_user()?;
+
+    let request = request.unwrap();
+
+    let webhook = service
+        .create_webhook(
+            &world_id,
+            &user_id,
+            NewWebhook {
+                url:    request.url,
+                events: request.events,
+                active: request.active.unwrap_or(true),
+            },
+        )
+        .await
+        .map_err(|e| match e {
+            CreateWebhookError::InvalidUrl => Problem::from(INVALID_URL),
+            CreateWebhookError::UnknownError => INTERNAL_SERVER_ERROR.into(),
+        })?;
+
+    let secret = webhook.data.secret.value().to_owned();
+    let model = CreatedWebhookModel {
+        details: WebhookModel::from(webhook),
+        secret,
+    };
+
+    Ok(SimpleRespondable::new(model).with_status_code(StatusCode::CREATED).into())
+}
+
+/// The incoming request to register a webhook.
+#[derive(Deserialize)]
+pub struct CreateWebhookRequest {
+    pub url:    String,
+    pub events: Vec<EventFilter>,
+    pub active: Option<bool>,
+}
+
+impl Validatable for CreateWebhookRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "url": {
+                    "type": "string",
+                    "format": "uri",
+                    "minLength": 1,
+                    "maxLength": 2000
+                },
+                "events": {
+                    "type": "array",
+                    "items": EventFilter::schema(),
+                    "minItems": 1,
+                    "uniqueItems": true
+                },
+                "active": {
+                    "type": "boolean"
+                }
+            },
+            "required": [
+                "url",
+                "events"
+            ]
+        })
+    }
+}
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use actix_web::{
+    web::{Data, Path},
+    HttpResponse,
+};
+
+use super::parse_webhook_path;
+use crate::{
+    authorization::Authentication,
+    http::problem::{Problem, NOT_FOUND},
+    tokens::Scope,
+    webhooks::WebhookService,
+    worlds::{Role, WorldService},
+};
+
+pub async fn handle(
+    service: Data<Arc<WebhookService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<HttpResponse, Problem> {
+    let (world_id, webhook_id) = path.into_inner();
+    let (world_id, webhook_id) = parse_webhook_path(&world_id, &webhook_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Owner).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    if service.delete_webhook(&world_id, &webhook_id).await {
+        Ok(HttpResponse::NoContent().finish())
+    } else {
+        Err(NOT_FOUND.into())
+    }
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::{
+    model::{AttemptModel, DeliveryDetailsModel, DeliveryModel},
+    parse_delivery_id, parse_webhook_path,
+};
+use crate::{
+    authorization::Authentication,
+    http::{
+        problem::{Problem, NOT_FOUND},
+        response::Response,
+    },
+    tokens::Scope,
+    webhooks::WebhookService,
+    worlds::{Role, WorldService},
+};
+
+/// Get a single delivery to a webhook, along with the response to every attempt at it.
+pub async fn handle(
+    service: Data<Arc<WebhookService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String, String)>,
+    authentication: Authentication,
+) -> Result<Response<DeliveryDetailsModel>, Problem> {
+    let (world_id, webhook_id, delivery_id) = path.into_inner();
+    let (world_id, webhook_id) = parse_webhook_path(&world_id, &webhook_id)?;
+    let delivery_id = parse_delivery_id(&delivery_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Owner).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let (delivery, attempts) = service.get_delivery(&world_id, &webhook_id, &delivery_id).await.ok_or(NOT_FOUND)?;
+
+    Ok(Response(DeliveryDetailsModel {
+        payload:     delivery.payload.clone(),
+        details:     DeliveryModel::from(delivery),
+        attempt_log: attempts.into_iter().map(AttemptModel::from).collect(),
+    }))
+}
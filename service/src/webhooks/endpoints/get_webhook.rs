@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::{model::WebhookResponse, parse_webhook_path};
+use crate::{
+    authorization::Authentication,
+    http::problem::{Problem, NOT_FOUND},
+    tokens::Scope,
+    webhooks::WebhookService,
+    worlds::{Role, WorldService},
+};
+
+pub async fn handle(
+    service: Data<Arc<WebhookService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<WebhookResponse, Problem> {
+    let (world_id, webhook_id) = path.into_inner();
+    let (world_id, webhook_id) = parse_webhook_path(&world_id, &webhook_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Owner).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let webhook = service.get_webhook(&world_id, &webhook_id).await.ok_or(NOT_FOUND)?;
+
+    Ok(webhook.into())
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path, Query};
+
+use super::{
+    model::{DeliveriesModel, DeliveryModel},
+    parse_pagination, parse_webhook_path,
+};
+use crate::{
+    authorization::Authentication,
+    http::{
+        pagination::{CursorModel, CursorQuery},
+        problem::{Problem, NOT_FOUND},
+        response::Response,
+    },
+    tokens::Scope,
+    webhooks::WebhookService,
+    worlds::{Role, WorldService},
+};
+
+/// List the deliveries that have been made to a webhook, newest first.
+pub async fn handle(
+    service: Data<Arc<WebhookService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    query: Query<CursorQuery>,
+    authentication: Authentication,
+) -> Result<Response<DeliveriesModel>, Problem> {
+    let (world_id, webhook_id) = path.into_inner();
+    let (world_id, webhook_id) = parse_webhook_path(&world_id, &webhook_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Owner).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let pagination = parse_pagination(&query)?;
+    let page = service.get_deliveries(&world_id, &webhook_id, &pagination).await.ok_or(NOT_FOUND)?;
+
+    Ok(Response(DeliveriesModel {
+        page:       CursorModel::new(&pagination, page.next.as_ref()),
+        deliveries: page.items.into_iter().map(DeliveryModel::from).collect(),
+    }))
+}
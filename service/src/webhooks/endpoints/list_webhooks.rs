@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::model::{WebhookModel, WebhooksModel};
+use crate::{
+    authorization::Authentication,
+    http::{
+        problem::{Problem, NOT_FOUND},
+        response::Response,
+    },
+    tokens::Scope,
+    webhooks::WebhookService,
+    worlds::{Role, WorldId, WorldService},
+};
+
+pub async fn handle(
+    service: Data<Arc<WebhookService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<String>,
+    authentication: Authentication,
+) -> Result<Response<WebhooksModel>, Problem> {
+    let world_id: WorldId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Owner).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let webhooks = service.get_webhooks(&world_id).await.into_iter().map(WebhookModel::from).collect();
+
+    Ok(Response(WebhooksModel { webhooks }))
+}
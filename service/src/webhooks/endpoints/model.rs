@@ -0,0 +1,182 @@
+use actix_web::http::header::CacheDirective;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    http::{
+        model::ResourceResponse,
+        pagination::CursorModel,
+        response::{Response, SimpleRespondable},
+    },
+    users::UserId,
+    webhooks::{Delivery, DeliveryAttempt, DeliveryId, DeliveryStatus, EventFilter, WebhookId, WebhookResource},
+};
+
+/// Representation of a webhook on the HTTP API. The secret is left out, since it is only ever
+/// returned when the webhook is created.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookModel {
+    pub webhook_id: WebhookId,
+    pub url:        String,
+    pub events:     Vec<EventFilter>,
+    pub active:     bool,
+    pub created_by: Option<UserId>,
+    pub created:    DateTime<Utc>,
+    pub updated:    DateTime<Utc>,
+}
+
+impl From<WebhookResource> for WebhookModel {
+    fn from(webhook: WebhookResource) -> Self {
+        Self {
+            webhook_id: webhook.identity.id,
+            url:        webhook.data.url,
+            events:     webhook.data.events,
+            active:     webhook.data.active,
+            created_by: webhook.data.created_by,
+            created:    webhook.identity.created,
+            updated:    webhook.identity.updated,
+        }
+    }
+}
+
+impl ResourceResponse for WebhookResource {
+    fn cache_control(&self) -> Option<Vec<CacheDirective>> {
+        Some(vec![CacheDirective::Private, CacheDirective::NoCache])
+    }
+}
+
+pub type WebhookResponse = Response<SimpleRespondable<WebhookModel>>;
+
+/// Representation of a newly created webhook on the HTTP API.
+/// This is the only time that the secret that payloads are signed with is ever returned.
+#[derive(Serialize)]
+pub struct CreatedWebhookModel {
+    #[serde(flatten)]
+    pub details: WebhookModel,
+    pub secret:  String,
+}
+
+/// Representation of a list of webhooks on the HTTP API.
+#[derive(Serialize)]
+pub struct WebhooksModel {
+    pub webhooks: Vec<WebhookModel>,
+}
+
+/// Representation of a single delivery to a webhook on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryModel {
+    pub delivery_id:  DeliveryId,
+    pub event:        String,
+    pub status:       DeliveryStatus,
+    pub attempts:     u32,
+    pub next_attempt: Option<DateTime<Utc>>,
+    pub created:      DateTime<Utc>,
+}
+
+impl From<Delivery> for DeliveryModel {
+    fn from(delivery: Delivery) -> Self {
+        Self {
+            delivery_id:  delivery.id,
+            event:        delivery.event,
+            status:       delivery.status,
+            attempts:     delivery.attempts,
+            next_attempt: delivery.next_attempt,
+            created:      delivery.created,
+        }
+    }
+}
+
+/// Representation of a page of the deliveries to a webhook on the HTTP API.
+#[derive(Serialize)]
+pub struct DeliveriesModel {
+    pub deliveries: Vec<DeliveryModel>,
+    pub page:       CursorModel,
+}
+
+/// Representation of a single delivery to a webhook on the HTTP API, along with what was sent and
+/// how every attempt at it went.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryDetailsModel {
+    #[serde(flatten)]
+    pub details:     DeliveryModel,
+    pub payload:     Value,
+    pub attempt_log: Vec<AttemptModel>,
+}
+
+/// Representation of a single attempt at a delivery on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttemptModel {
+    pub attempted:   DateTime<Utc>,
+    pub status_code: Option<u16>,
+    pub error:       Option<String>,
+    pub duration_ms: u32,
+}
+
+impl From<DeliveryAttempt> for AttemptModel {
+    fn from(attempt: DeliveryAttempt) -> Self {
+        Self {
+            attempted:   attempt.attempted,
+            status_code: attempt.status_code,
+            error:       attempt.error,
+            duration_ms: attempt.duration_ms,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn serialize_delivery_details() {
+        let model = DeliveryDetailsModel {
+            details:     DeliveryModel::from(Delivery {
+                id:           DeliveryId::from(7),
+                webhook_id:   "d4a4cb16-a5f1-4e4e-9b4c-27b4f0bd1d7a".parse().unwrap(),
+                event:        "article.created".to_owned(),
+                payload:      json!({ "event": "article.created" }),
+                status:       DeliveryStatus::Pending,
+                attempts:     1,
+                next_attempt: Some("2021-08-23T10:21:30Z".parse().unwrap()),
+                created:      "2021-08-23T10:20:30Z".parse().unwrap(),
+            }),
+            payload:     json!({ "event": "article.created" }),
+            attempt_log: vec![AttemptModel::from(DeliveryAttempt {
+                attempted:   "2021-08-23T10:20:31Z".parse().unwrap(),
+                status_code: Some(503),
+                error:       Some("The webhook responded with 503 Service Unavailable".to_owned()),
+                duration_ms: 120,
+            })],
+        };
+
+        assert_json_snapshot!(model, @r###"
+        {
+          "deliveryId": 7,
+          "event": "article.created",
+          "status": "pending",
+          "attempts": 1,
+          "nextAttempt": "2021-08-23T10:21:30Z",
+          "created": "2021-08-23T10:20:30Z",
+          "payload": {
+            "event": "article.created"
+          },
+          "attemptLog": [
+            {
+              "attempted": "2021-08-23T10:20:31Z",
+              "statusCode": 503,
+              "error": "The webhook responded with 503 Service Unavailable",
+              "durationMs": 120
+            }
+          ]
+        }
+        "###);
+    }
+}
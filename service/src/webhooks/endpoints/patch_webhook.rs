@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{model::WebhookResponse, parse_webhook_path, problems::INVALID_URL};
+use crate::{
+    authorization::Authentication,
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+        valid::{Valid, Validatable},
+    },
+    tokens::Scope,
+    webhooks::{EventFilter, UpdateWebhookError, WebhookData, WebhookService},
+    worlds::{Role, WorldService},
+};
+
+/// Change where a webhook is sent to, what it is sent, or whether it is sent anything at all.
+pub async fn handle(
+    service: Data<Arc<WebhookService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    request: Valid<PatchRequest>,
+    authentication: Authentication,
+) -> Result<WebhookResponse, Problem> {
+    let (world_id, webhook_id) = path.into_inner();
+    let (world_id, webhook_id) = parse_webhook_path(&world_id, &webhook_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Owner).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let request = request.unwrap();
+
+    let webhook = service
+        .update_webhook_by_id(&world_id, &webhook_id, move |webhook| -> Result<WebhookData, Problem> {
+            Ok(WebhookData {
+                url: request.url.unwrap_or(webhook.url),
+                events: request.events.unwrap_or(webhook.events),
+                active: request.active.unwrap_or(webhook.active),
+                ..webhook
+            })
+        })
+        .await
+        .map_err(|e| match e {
+            UpdateWebhookError::UpdateError(p) => p,
+            UpdateWebhookError::UnknownWebhook => NOT_FOUND.into(),
+            UpdateWebhookError::InvalidUrl => INVALID_URL.into(),
+            UpdateWebhookError::UnknownError => INTERNAL_SERVER_ERROR.into(),
+        })?;
+
+    Ok(webhook.into())
+}
+
+/// The incoming request to patch a webhook. The events are replaced as a whole.
+#[derive(Deserialize)]
+pub struct PatchRequest {
+    pub url:    Option<String>,
+    pub events: Option<Vec<EventFilter>>,
+    pub active: Option<bool>,
+}
+
+impl Validatable for PatchRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "url": {
+                    "type": "string",
+                    "format": "uri",
+                    "minLength": 1,
+                    "maxLength": 2000
+                },
+                "events": {
+                    "type": "array",
+                    "items": EventFilter::schema(),
+                    "minItems": 1,
+                    "uniqueItems": true
+                },
+                "active": {
+                    "type": "boolean"
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,17 @@
+use actix_http::http::StatusCode;
+
+use crate::http::problem::SimpleProblemType;
+
+/// Problem to indicate that the URL of a webhook isn't one that changes can be delivered to.
+pub const INVALID_URL: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/webhooks/invalid_url",
+    problem_title: "Invalid Webhook URL",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate that the position to continue on from in the log of deliveries isn't valid.
+pub const INVALID_CURSOR: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/webhooks/invalid_cursor",
+    problem_title: "Invalid Cursor",
+    status_code:   StatusCode::BAD_REQUEST,
+};
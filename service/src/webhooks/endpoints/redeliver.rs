@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use actix_http::http::StatusCode;
+use actix_web::web::{Data, Path};
+
+use super::{model::DeliveryModel, parse_delivery_id, parse_webhook_path};
+use crate::{
+    authorization::Authentication,
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+        response::{Response, SimpleRespondable},
+    },
+    tokens::Scope,
+    webhooks::{RedeliverError, WebhookService},
+    worlds::{Role, WorldService},
+};
+
+/// Send a delivery to a webhook again as soon as possible, whether or not it succeeded before.
+pub async fn handle(
+    service: Data<Arc<WebhookService>>,
+    worlds_service: Data<Arc<WorldService>>,
+    path: Path<(String, String, String)>,
+    authentication: Authentication,
+) -> Result<Response<SimpleRespondable<DeliveryModel>>, Problem> {
+    let (world_id, webhook_id, delivery_id) = path.into_inner();
+    let (world_id, webhook_id) = parse_webhook_path(&world_id, &webhook_id)?;
+    let delivery_id = parse_delivery_id(&delivery_id)?;
+
+    authentication.require_world_role(&worlds_service, &world_id, Role::Owner).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let delivery = service.redeliver(&world_id, &webhook_id, &delivery_id).await.map_err(|e| match e {
+        RedeliverError::UnknownDelivery => Problem::from(NOT_FOUND),
+        RedeliverError::UnknownError => INTERNAL_SERVER_ERROR.into(),
+    })?;
+
+    Ok(SimpleRespondable::new(DeliveryModel::from(delivery))
+        .with_status_code(StatusCode::ACCEPTED)
+        .into())
+}
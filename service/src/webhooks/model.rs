@@ -0,0 +1,33 @@
+mod delivery;
+mod delivery_id;
+mod event_filter;
+mod webhook_id;
+mod webhook_secret;
+
+pub use delivery::*;
+pub use delivery_id::*;
+pub use event_filter::*;
+pub use webhook_id::*;
+pub use webhook_secret::*;
+
+use crate::{model::Resource, users::UserId, worlds::WorldId};
+
+/// The data representing a webhook, which is sent the changes to a world as they happen.
+#[derive(Debug, Clone)]
+pub struct WebhookData {
+    pub world_id:   WorldId,
+    /// The URL that the changes are sent to.
+    pub url:        String,
+    /// The changes that the webhook is sent. Any change that matches at least one of them is sent.
+    pub events:     Vec<EventFilter>,
+    /// Whether the webhook is being sent changes. Nothing is queued for it while it is inactive.
+    pub active:     bool,
+    /// The secret that the changes are signed with, so that the receiver can check where they came
+    /// from.
+    pub secret:     WebhookSecret,
+    /// The user that registered the webhook, if they still exist.
+    pub created_by: Option<UserId>,
+}
+
+/// Type representing a persisted webhook.
+pub type WebhookResource = Resource<WebhookId, WebhookData>;
@@ -0,0 +1,141 @@
+use std::str::FromStr;
+
+use bytes::BytesMut;
+use chrono::{DateTime, Utc};
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use serde::Serialize;
+use serde_json::Value;
+
+use super::{DeliveryId, WebhookId};
+
+/// Where a delivery to a webhook has got to.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeliveryStatus {
+    /// The delivery is waiting for its next attempt.
+    Pending,
+    /// The webhook accepted the delivery.
+    Succeeded,
+    /// Every attempt to deliver failed, and it has been given up on.
+    Failed,
+}
+
+/// All of the statuses that a delivery can have.
+const ALL_STATUSES: &[DeliveryStatus] = &[DeliveryStatus::Pending, DeliveryStatus::Succeeded, DeliveryStatus::Failed];
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ParseDeliveryStatusError {
+    #[error("The delivery status is not known")]
+    Unknown,
+}
+
+impl DeliveryStatus {
+    /// The name of the status, as used on the API and in the database.
+    pub fn name(self) -> &'static str {
+        match self {
+            DeliveryStatus::Pending => "pending",
+            DeliveryStatus::Succeeded => "succeeded",
+            DeliveryStatus::Failed => "failed",
+        }
+    }
+}
+
+impl FromStr for DeliveryStatus {
+    type Err = ParseDeliveryStatusError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ALL_STATUSES
+            .iter()
+            .find(|status| status.name() == s)
+            .copied()
+            .ok_or(ParseDeliveryStatusError::Unknown)
+    }
+}
+
+impl ToSql for DeliveryStatus {
+    accepts!(TEXT, VARCHAR);
+    to_sql_checked!();
+
+    fn to_sql(&self, t: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.name().to_sql(t, w)
+    }
+}
+
+impl<'a> FromSql<'a> for DeliveryStatus {
+    accepts!(TEXT, VARCHAR);
+
+    fn from_sql(t: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        let name = <&str>::from_sql(t, raw)?;
+
+        Ok(name.parse()?)
+    }
+}
+
+/// A single change that is being delivered to a webhook.
+#[derive(Debug, Clone)]
+pub struct Delivery {
+    pub id:           DeliveryId,
+    pub webhook_id:   WebhookId,
+    /// The name of the event, such as `article.created`.
+    pub event:        String,
+    /// The body that is sent to the webhook.
+    pub payload:      Value,
+    pub status:       DeliveryStatus,
+    /// The number of attempts that have been made since the delivery was last queued.
+    pub attempts:     u32,
+    /// When the next attempt is due, if there is going to be one.
+    pub next_attempt: Option<DateTime<Utc>>,
+    pub created:      DateTime<Utc>,
+}
+
+/// The outcome of a single attempt to deliver a change to a webhook.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeliveryAttempt {
+    pub attempted:   DateTime<Utc>,
+    /// The status code that the webhook responded with, or `None` if it couldn't be reached.
+    pub status_code: Option<u16>,
+    /// Why the attempt failed, if it did.
+    pub error:       Option<String>,
+    /// How long the webhook took to respond, in milliseconds.
+    pub duration_ms: u32,
+}
+
+impl DeliveryAttempt {
+    /// Determine if the webhook accepted the delivery.
+    pub fn succeeded(&self) -> bool {
+        self.error.is_none() && self.status_code.is_some_and(|code| (200..300).contains(&code))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("pending", DeliveryStatus::Pending ; "Pending")]
+    #[test_case("succeeded", DeliveryStatus::Succeeded ; "Succeeded")]
+    #[test_case("failed", DeliveryStatus::Failed ; "Failed")]
+    fn test_parse_status(input: &str, expected: DeliveryStatus) {
+        let_assert!(Ok(status) = input.parse::<DeliveryStatus>());
+        check!(status == expected);
+        check!(serde_json::to_value(status).unwrap() == input);
+    }
+
+    #[test_case(Some(200), None, true ; "OK")]
+    #[test_case(Some(204), None, true ; "No content")]
+    #[test_case(Some(301), None, false ; "Redirect")]
+    #[test_case(Some(500), None, false ; "Server error")]
+    #[test_case(None, Some("Connection refused"), false ; "Unreachable")]
+    fn test_succeeded(status_code: Option<u16>, error: Option<&str>, expected: bool) {
+        let attempt = DeliveryAttempt {
+            attempted: Utc::now(),
+            status_code,
+            error: error.map(str::to_owned),
+            duration_ms: 10,
+        };
+
+        check!(attempt.succeeded() == expected);
+    }
+}
@@ -0,0 +1,92 @@
+use std::str::FromStr;
+
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use serde::{Deserialize, Serialize};
+
+/// The ID of a single delivery of a change to a webhook. Deliveries are numbered in the order that
+/// they were queued.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, FromSql)]
+pub struct DeliveryId(i64);
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ParseDeliveryIdError {
+    #[error("The Delivery ID was blank")]
+    Blank,
+
+    #[error("The Delivery ID was malformed")]
+    Malformed,
+}
+
+impl std::fmt::Display for DeliveryId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for DeliveryId {
+    type Err = ParseDeliveryIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            Err(ParseDeliveryIdError::Blank)
+        } else {
+            let id = trimmed.parse::<i64>().map_err(|e| {
+                tracing::warn!(e = ?e, "Failed to parse Delivery ID as a number");
+                ParseDeliveryIdError::Malformed
+            })?;
+
+            if id > 0 {
+                Ok(DeliveryId(id))
+            } else {
+                Err(ParseDeliveryIdError::Malformed)
+            }
+        }
+    }
+}
+
+impl ToSql for DeliveryId {
+    accepts!(INT8);
+    to_sql_checked!();
+
+    fn to_sql(&self, t: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.0.to_sql(t, w)
+    }
+}
+
+#[cfg(test)]
+impl From<i64> for DeliveryId {
+    fn from(id: i64) -> Self {
+        Self(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("42", "42" ; "Simple")]
+    #[test_case("   42   ", "42" ; "Padded")]
+    fn test_parse_success(input: &str, expected: &str) {
+        let result: Result<DeliveryId, ParseDeliveryIdError> = input.parse();
+
+        let_assert!(Ok(output) = result);
+        check!(output.to_string() == expected);
+    }
+
+    #[test_case("", &ParseDeliveryIdError::Blank ; "Blank")]
+    #[test_case("   ", &ParseDeliveryIdError::Blank ; "Whitespace")]
+    #[test_case("xxx", &ParseDeliveryIdError::Malformed ; "Malformed")]
+    #[test_case("0", &ParseDeliveryIdError::Malformed ; "Zero")]
+    #[test_case("-5", &ParseDeliveryIdError::Malformed ; "Negative")]
+    fn test_parse_fail(input: &str, expected: &ParseDeliveryIdError) {
+        let result: Result<DeliveryId, ParseDeliveryIdError> = input.parse();
+
+        let_assert!(Err(e) = result);
+        check!(&e == expected);
+    }
+}
@@ -0,0 +1,128 @@
+use std::{convert::TryFrom, str::FromStr};
+
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::realtime::ChangeAction;
+
+/// Wildcard that matches any resource, or any action.
+const WILDCARD: &str = "*";
+
+/// A filter for the changes that a webhook is sent, in the form `<resource>.<action>`, such as
+/// `article.created`. Either half can be `*` to match anything.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromSql)]
+#[serde(try_from = "String")]
+pub struct EventFilter(String);
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ParseEventFilterError {
+    #[error("The event filter was malformed")]
+    Malformed,
+}
+
+impl EventFilter {
+    /// Determine if a change to a resource matches this filter.
+    ///
+    /// # Parameters
+    /// - `resource` - The kind of resource that changed, such as `article` or `character`
+    /// - `action` - How the resource changed
+    ///
+    /// # Returns
+    /// True if the change matches. False if not.
+    pub fn matches(&self, resource: &str, action: ChangeAction) -> bool {
+        match self.0.split_once('.') {
+            Some((r, a)) => (r == WILDCARD || r == resource) && (a == WILDCARD || a == action.name()),
+            None => false,
+        }
+    }
+
+    /// Generate the JSON Schema fragment for an event filter.
+    pub fn schema() -> Value {
+        json!({
+            "type": "string",
+            "pattern": "^([a-z_]+|\\*)\\.(created|updated|deleted|\\*)$"
+        })
+    }
+}
+
+impl std::fmt::Display for EventFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for EventFilter {
+    type Err = ParseEventFilterError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (resource, action) = s.split_once('.').ok_or(ParseEventFilterError::Malformed)?;
+
+        let valid_resource = resource == WILDCARD || (!resource.is_empty() && resource.chars().all(|c| c.is_ascii_lowercase() || c == '_'));
+        let valid_action = action == WILDCARD || action.parse::<ChangeAction>().is_ok();
+
+        if valid_resource && valid_action {
+            Ok(Self(s.to_owned()))
+        } else {
+            Err(ParseEventFilterError::Malformed)
+        }
+    }
+}
+
+impl TryFrom<String> for EventFilter {
+    type Error = ParseEventFilterError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl ToSql for EventFilter {
+    accepts!(TEXT, VARCHAR);
+    to_sql_checked!();
+
+    fn to_sql(&self, t: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.0.to_sql(t, w)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("article.created" ; "Exact")]
+    #[test_case("*.deleted" ; "Any resource")]
+    #[test_case("character.*" ; "Any action")]
+    #[test_case("*.*" ; "Anything")]
+    fn test_parse_success(input: &str) {
+        let_assert!(Ok(filter) = input.parse::<EventFilter>());
+        check!(filter.to_string() == input);
+    }
+
+    #[test_case("" ; "Blank")]
+    #[test_case("article" ; "No action")]
+    #[test_case(".created" ; "No resource")]
+    #[test_case("article.moved" ; "Unknown action")]
+    #[test_case("Article.created" ; "Capitalised")]
+    #[test_case("article.created.again" ; "Too many parts")]
+    fn test_parse_fail(input: &str) {
+        let_assert!(Err(e) = input.parse::<EventFilter>());
+        check!(e == ParseEventFilterError::Malformed);
+    }
+
+    #[test_case("article.created", "article", ChangeAction::Created, true ; "Exact match")]
+    #[test_case("article.created", "article", ChangeAction::Updated, false ; "Different action")]
+    #[test_case("article.created", "character", ChangeAction::Created, false ; "Different resource")]
+    #[test_case("*.deleted", "character", ChangeAction::Deleted, true ; "Any resource")]
+    #[test_case("character.*", "character", ChangeAction::Updated, true ; "Any action")]
+    #[test_case("*.*", "map", ChangeAction::Created, true ; "Anything")]
+    fn test_matches(filter: &str, resource: &str, action: ChangeAction, expected: bool) {
+        let filter: EventFilter = filter.parse().unwrap();
+
+        check!(filter.matches(resource, action) == expected);
+    }
+}
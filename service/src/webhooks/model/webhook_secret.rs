@@ -0,0 +1,99 @@
+use hmac::{Hmac, Mac, NewMac};
+use postgres_types::FromSql;
+use rand::{distributions::Alphanumeric, Rng};
+use sha2::Sha256;
+
+/// The prefix that every webhook secret starts with, to distinguish them from other kinds of
+/// secret.
+const SECRET_PREFIX: &str = "whsec_";
+
+/// The number of random characters in a webhook secret, after the prefix.
+const SECRET_LENGTH: usize = 32;
+
+/// The secret that the payloads sent to a webhook are signed with.
+/// Unlike a personal access token, this has to be stored as it is so that payloads can be signed.
+#[derive(Clone, PartialEq, FromSql)]
+pub struct WebhookSecret(String);
+
+impl WebhookSecret {
+    /// Generate a new, random, webhook secret.
+    pub fn generate() -> Self {
+        let random: String = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(SECRET_LENGTH)
+            .map(char::from)
+            .collect();
+
+        Self(format!("{}{}", SECRET_PREFIX, random))
+    }
+
+    /// Sign a payload that is being sent to the webhook.
+    ///
+    /// The signature covers the time that the payload was sent as well as the payload itself, so
+    /// that the receiver can reject payloads that are replayed later on.
+    ///
+    /// # Parameters
+    /// - `timestamp` - The time that the payload is being sent, as seconds since the Unix epoch
+    /// - `payload` - The payload
+    ///
+    /// # Returns
+    /// The signature, as a hex-encoded HMAC-SHA256 of `<timestamp>.<payload>`.
+    pub fn sign(&self, timestamp: i64, payload: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_varkey(self.0.as_bytes()).expect("HMAC accepts keys of any length");
+        mac.update(timestamp.to_string().as_bytes());
+        mac.update(b".");
+        mac.update(payload);
+
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    /// Get the value of the webhook secret.
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for WebhookSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "WebhookSecret(Redacted)")
+    }
+}
+
+impl From<&str> for WebhookSecret {
+    fn from(value: &str) -> Self {
+        Self(value.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+
+    use super::*;
+
+    #[test]
+    fn generate() {
+        let first = WebhookSecret::generate();
+        let second = WebhookSecret::generate();
+
+        check!(first.value().starts_with(SECRET_PREFIX));
+        check!(first.value().len() == SECRET_PREFIX.len() + SECRET_LENGTH);
+        check!(first != second);
+    }
+
+    #[test]
+    fn sign() {
+        let secret = WebhookSecret::from("whsec_abc");
+
+        let signature = secret.sign(1_629_108_030, br#"{"event":"article.created"}"#);
+
+        check!(signature == "2dfd1b59870e97f8b166f786ac15162415a99997db801420a464074555012316");
+    }
+
+    #[test]
+    fn debug() {
+        let formatted = format!("{:?}", WebhookSecret::generate());
+
+        check!(formatted == "WebhookSecret(Redacted)");
+    }
+}
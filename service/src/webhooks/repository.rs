@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod memory;
+mod parse;
+mod postgres;
+mod save_webhook;
+
+use chrono::{DateTime, Utc};
+#[cfg(test)]
+pub use memory::MemoryWebhookRepository;
+pub use postgres::PostgresWebhookRepository;
+pub use save_webhook::SaveWebhookError;
+
+use super::{Delivery, DeliveryAttempt, DeliveryId, DeliveryStatus, WebhookData, WebhookId, WebhookResource};
+use crate::worlds::WorldId;
+
+/// Repository of webhooks, and of the deliveries that are made to them.
+///
+/// Deliveries are queued by the database itself, whenever a change is recorded that an active
+/// webhook is interested in. That way nothing is lost if the service stops before sending them.
+#[async_trait::async_trait]
+pub trait WebhookRepository: Send + Sync {
+    /// Create a new webhook.
+    ///
+    /// # Parameters
+    /// - `webhook` - The details of the webhook to create.
+    ///
+    /// # Returns
+    /// The created webhook.
+    async fn create_webhook(&self, webhook: &WebhookData) -> Result<WebhookResource, SaveWebhookError>;
+
+    /// Get a single webhook.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the webhook belongs to.
+    /// - `webhook_id` - The ID of the webhook.
+    ///
+    /// # Returns
+    /// The webhook, or `None` if it couldn't be found.
+    async fn get_webhook(&self, world_id: &WorldId, webhook_id: &WebhookId) -> Option<WebhookResource>;
+
+    /// Find a single webhook, whichever world it belongs to.
+    ///
+    /// # Parameters
+    /// - `webhook_id` - The ID of the webhook.
+    ///
+    /// # Returns
+    /// The webhook, or `None` if it couldn't be found.
+    async fn find_webhook(&self, webhook_id: &WebhookId) -> Option<WebhookResource>;
+
+    /// Get every webhook within a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world.
+    ///
+    /// # Returns
+    /// The webhooks, oldest first.
+    async fn get_webhooks(&self, world_id: &WorldId) -> Vec<WebhookResource>;
+
+    /// Update an existing webhook.
+    ///
+    /// # Parameters
+    /// - `webhook_id` - The ID of the webhook.
+    /// - `webhook` - The new details of the webhook.
+    ///
+    /// # Returns
+    /// The updated webhook.
+    async fn update_webhook(&self, webhook_id: &WebhookId, webhook: &WebhookData) -> Result<WebhookResource, SaveWebhookError>;
+
+    /// Delete a single webhook, along with every delivery to it.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the webhook belongs to.
+    /// - `webhook_id` - The ID of the webhook.
+    ///
+    /// # Returns
+    /// True if the webhook existed and was deleted. False if not.
+    async fn delete_webhook(&self, world_id: &WorldId, webhook_id: &WebhookId) -> bool;
+
+    /// Get a page of the deliveries to a webhook, newest first.
+    ///
+    /// # Parameters
+    /// - `webhook_id` - The ID of the webhook.
+    /// - `after` - The ID of the delivery that the page starts after, if any.
+    /// - `limit` - The most deliveries to return.
+    ///
+    /// # Returns
+    /// The deliveries.
+    async fn get_deliveries(&self, webhook_id: &WebhookId, after: Option<&DeliveryId>, limit: u32) -> Vec<Delivery>;
+
+    /// Get a single delivery to a webhook.
+    ///
+    /// # Parameters
+    /// - `webhook_id` - The ID of the webhook.
+    /// - `delivery_id` - The ID of the delivery.
+    ///
+    /// # Returns
+    /// The delivery, or `None` if it couldn't be found.
+    async fn get_delivery(&self, webhook_id: &WebhookId, delivery_id: &DeliveryId) -> Option<Delivery>;
+
+    /// Get every attempt that has been made at a delivery.
+    ///
+    /// # Parameters
+    /// - `delivery_id` - The ID of the delivery.
+    ///
+    /// # Returns
+    /// The attempts, oldest first.
+    async fn get_attempts(&self, delivery_id: &DeliveryId) -> Vec<DeliveryAttempt>;
+
+    /// Record an attempt at a delivery, along with what happens to the delivery next.
+    ///
+    /// # Parameters
+    /// - `delivery_id` - The ID of the delivery.
+    /// - `attempt` - The attempt.
+    /// - `status` - The new status of the delivery.
+    /// - `next_attempt` - When the next attempt is due, if there is going to be one.
+    async fn record_attempt(
+        &self,
+        delivery_id: &DeliveryId,
+        attempt: &DeliveryAttempt,
+        status: DeliveryStatus,
+        next_attempt: Option<DateTime<Utc>>,
+    );
+
+    /// Queue a delivery to be attempted again, starting its retries over.
+    ///
+    /// # Parameters
+    /// - `webhook_id` - The ID of the webhook.
+    /// - `delivery_id` - The ID of the delivery.
+    /// - `next_attempt` - When the next attempt is due.
+    ///
+    /// # Returns
+    /// The queued delivery, or `None` if it couldn't be found.
+    async fn requeue_delivery(&self, webhook_id: &WebhookId, delivery_id: &DeliveryId, next_attempt: DateTime<Utc>) -> Option<Delivery>;
+}
@@ -0,0 +1,204 @@
+use std::{convert::TryFrom, sync::Mutex};
+
+use chrono::{DateTime, Utc};
+use serde_json::json;
+use uuid::Uuid;
+
+use super::{SaveWebhookError, WebhookRepository};
+use crate::{
+    model::Identity,
+    realtime::Change,
+    webhooks::{Delivery, DeliveryAttempt, DeliveryId, DeliveryStatus, WebhookData, WebhookId, WebhookResource},
+    worlds::WorldId,
+};
+
+/// Repository of webhooks that are stored in memory.
+#[derive(Default)]
+pub struct MemoryWebhookRepository {
+    webhooks:   Mutex<Vec<WebhookResource>>,
+    deliveries: Mutex<Vec<(Delivery, Vec<DeliveryAttempt>)>>,
+}
+
+impl MemoryWebhookRepository {
+    /// Create a new, empty webhook repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a delivery of a change to every active webhook that is interested in it, as the
+    /// database would whenever a change is recorded.
+    ///
+    /// # Parameters
+    /// - `change` - The change
+    ///
+    /// # Returns
+    /// The IDs of the queued deliveries.
+    pub fn record_change(&self, change: &Change) -> Vec<DeliveryId> {
+        let webhooks = self.webhooks.lock().unwrap();
+        let mut deliveries = self.deliveries.lock().unwrap();
+
+        let event = format!("{}.{}", change.resource, change.action.name());
+        let mut queued = vec![];
+        for webhook in webhooks.iter().filter(|w| {
+            w.data.world_id == change.world_id && w.data.active && w.data.events.iter().any(|e| e.matches(&change.resource, change.action))
+        }) {
+            let now = Utc::now();
+            let delivery_id = DeliveryId::from(i64::try_from(deliveries.len()).unwrap() + 1);
+            deliveries.push((
+                Delivery {
+                    id:           delivery_id,
+                    webhook_id:   webhook.identity.id.clone(),
+                    event:        event.clone(),
+                    payload:      json!({
+                        "event": event,
+                        "worldId": change.world_id,
+                        "action": change.action,
+                        "resource": change.resource,
+                        "resourceId": change.resource_id,
+                        "version": change.version,
+                    }),
+                    status:       DeliveryStatus::Pending,
+                    attempts:     0,
+                    next_attempt: Some(now),
+                    created:      now,
+                },
+                vec![],
+            ));
+            queued.push(delivery_id);
+        }
+
+        queued
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookRepository for MemoryWebhookRepository {
+    async fn create_webhook(&self, webhook: &WebhookData) -> Result<WebhookResource, SaveWebhookError> {
+        let mut webhooks = self.webhooks.lock().unwrap();
+
+        let created = WebhookResource {
+            identity: Identity::default(),
+            data:     webhook.clone(),
+        };
+        webhooks.push(created.clone());
+
+        Ok(created)
+    }
+
+    async fn get_webhook(&self, world_id: &WorldId, webhook_id: &WebhookId) -> Option<WebhookResource> {
+        let webhooks = self.webhooks.lock().unwrap();
+
+        webhooks
+            .iter()
+            .find(|w| &w.data.world_id == world_id && &w.identity.id == webhook_id)
+            .cloned()
+    }
+
+    async fn find_webhook(&self, webhook_id: &WebhookId) -> Option<WebhookResource> {
+        let webhooks = self.webhooks.lock().unwrap();
+
+        webhooks.iter().find(|w| &w.identity.id == webhook_id).cloned()
+    }
+
+    async fn get_webhooks(&self, world_id: &WorldId) -> Vec<WebhookResource> {
+        let webhooks = self.webhooks.lock().unwrap();
+
+        webhooks.iter().filter(|w| &w.data.world_id == world_id).cloned().collect()
+    }
+
+    async fn update_webhook(&self, webhook_id: &WebhookId, webhook: &WebhookData) -> Result<WebhookResource, SaveWebhookError> {
+        let mut webhooks = self.webhooks.lock().unwrap();
+
+        let existing = webhooks
+            .iter_mut()
+            .find(|w| &w.identity.id == webhook_id && w.data.world_id == webhook.world_id)
+            .ok_or(SaveWebhookError::UnknownWebhook)?;
+
+        existing.identity.version = Uuid::new_v4();
+        existing.identity.updated = Utc::now();
+        existing.data = WebhookData {
+            secret: existing.data.secret.clone(),
+            created_by: existing.data.created_by.clone(),
+            ..webhook.clone()
+        };
+
+        Ok(existing.clone())
+    }
+
+    async fn delete_webhook(&self, world_id: &WorldId, webhook_id: &WebhookId) -> bool {
+        let mut webhooks = self.webhooks.lock().unwrap();
+
+        let before = webhooks.len();
+        webhooks.retain(|w| !(&w.data.world_id == world_id && &w.identity.id == webhook_id));
+        let deleted = webhooks.len() != before;
+
+        if deleted {
+            self.deliveries.lock().unwrap().retain(|(d, _)| &d.webhook_id != webhook_id);
+        }
+
+        deleted
+    }
+
+    async fn get_deliveries(&self, webhook_id: &WebhookId, after: Option<&DeliveryId>, limit: u32) -> Vec<Delivery> {
+        let deliveries = self.deliveries.lock().unwrap();
+
+        deliveries
+            .iter()
+            .rev()
+            .map(|(d, _)| d)
+            .filter(|d| &d.webhook_id == webhook_id && after.is_none_or(|after| &d.id < after))
+            .take(usize::try_from(limit).unwrap())
+            .cloned()
+            .collect()
+    }
+
+    async fn get_delivery(&self, webhook_id: &WebhookId, delivery_id: &DeliveryId) -> Option<Delivery> {
+        let deliveries = self.deliveries.lock().unwrap();
+
+        deliveries
+            .iter()
+            .map(|(d, _)| d)
+            .find(|d| &d.webhook_id == webhook_id && &d.id == delivery_id)
+            .cloned()
+    }
+
+    async fn get_attempts(&self, delivery_id: &DeliveryId) -> Vec<DeliveryAttempt> {
+        let deliveries = self.deliveries.lock().unwrap();
+
+        deliveries
+            .iter()
+            .find(|(d, _)| &d.id == delivery_id)
+            .map(|(_, attempts)| attempts.clone())
+            .unwrap_or_default()
+    }
+
+    async fn record_attempt(
+        &self,
+        delivery_id: &DeliveryId,
+        attempt: &DeliveryAttempt,
+        status: DeliveryStatus,
+        next_attempt: Option<DateTime<Utc>>,
+    ) {
+        let mut deliveries = self.deliveries.lock().unwrap();
+
+        if let Some((delivery, attempts)) = deliveries.iter_mut().find(|(d, _)| &d.id == delivery_id) {
+            delivery.status = status;
+            delivery.attempts += 1;
+            delivery.next_attempt = next_attempt;
+            attempts.push(attempt.clone());
+        }
+    }
+
+    async fn requeue_delivery(&self, webhook_id: &WebhookId, delivery_id: &DeliveryId, next_attempt: DateTime<Utc>) -> Option<Delivery> {
+        let mut deliveries = self.deliveries.lock().unwrap();
+
+        let (delivery, _) = deliveries
+            .iter_mut()
+            .find(|(d, _)| &d.webhook_id == webhook_id && &d.id == delivery_id)?;
+        delivery.status = DeliveryStatus::Pending;
+        delivery.attempts = 0;
+        delivery.next_attempt = Some(next_attempt);
+
+        Some(delivery.clone())
+    }
+}
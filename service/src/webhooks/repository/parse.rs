@@ -0,0 +1,55 @@
+use std::convert::TryFrom;
+
+use tokio_postgres::Row;
+
+use crate::{
+    model::Identity,
+    webhooks::{Delivery, DeliveryAttempt, WebhookData, WebhookResource},
+};
+
+impl From<Row> for WebhookResource {
+    fn from(row: Row) -> Self {
+        WebhookResource {
+            identity: Identity {
+                id:      row.get("webhook_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data:     WebhookData {
+                world_id:   row.get("world_id"),
+                url:        row.get("url"),
+                events:     row.get("events"),
+                active:     row.get("active"),
+                secret:     row.get("secret"),
+                created_by: row.get("created_by"),
+            },
+        }
+    }
+}
+
+impl From<Row> for Delivery {
+    fn from(row: Row) -> Self {
+        Delivery {
+            id:           row.get("delivery_id"),
+            webhook_id:   row.get("webhook_id"),
+            event:        row.get("event"),
+            payload:      row.get("payload"),
+            status:       row.get("status"),
+            attempts:     u32::try_from(row.get::<_, i32>("attempts")).unwrap_or_default(),
+            next_attempt: row.get("next_attempt"),
+            created:      row.get("created"),
+        }
+    }
+}
+
+impl From<Row> for DeliveryAttempt {
+    fn from(row: Row) -> Self {
+        DeliveryAttempt {
+            attempted:   row.get("attempted"),
+            status_code: row.get::<_, Option<i32>>("status_code").and_then(|code| u16::try_from(code).ok()),
+            error:       row.get("error"),
+            duration_ms: u32::try_from(row.get::<_, i32>("duration_ms")).unwrap_or_default(),
+        }
+    }
+}
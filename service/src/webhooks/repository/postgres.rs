@@ -0,0 +1,246 @@
+use std::{convert::TryFrom, sync::Arc};
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::{SaveWebhookError, WebhookRepository};
+use crate::{
+    database::Database,
+    model::Identity,
+    webhooks::{Delivery, DeliveryAttempt, DeliveryId, DeliveryStatus, WebhookData, WebhookId, WebhookResource},
+    worlds::WorldId,
+};
+
+/// Repository of webhooks that are stored in Postgres.
+pub struct PostgresWebhookRepository {
+    database: Arc<Database>,
+}
+
+impl PostgresWebhookRepository {
+    /// Create a new webhook repository.
+    pub fn new(database: Arc<Database>) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookRepository for PostgresWebhookRepository {
+    #[tracing::instrument(skip(self))]
+    async fn create_webhook(&self, webhook: &WebhookData) -> Result<WebhookResource, SaveWebhookError> {
+        let conn = self.database.connect().await;
+
+        let identity = Identity::<WebhookId>::default();
+
+        let created: WebhookResource = conn
+            .query_one(
+                "INSERT INTO webhooks(webhook_id, version, created, updated, world_id, url, events, active, secret, created_by) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) RETURNING *",
+                &[
+                    &identity.id,
+                    &identity.version,
+                    &identity.created,
+                    &identity.updated,
+                    &webhook.world_id,
+                    &webhook.url,
+                    &webhook.events,
+                    &webhook.active,
+                    &webhook.secret.value(),
+                    &webhook.created_by,
+                ],
+            )
+            .await
+            .map(WebhookResource::from)?;
+
+        Ok(created)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_webhook(&self, world_id: &WorldId, webhook_id: &WebhookId) -> Option<WebhookResource> {
+        let conn = self.database.connect().await;
+        conn.query_opt(
+            "SELECT * FROM webhooks WHERE world_id = $1 AND webhook_id = $2",
+            &[&world_id, &webhook_id],
+        )
+        .await
+        .map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to load webhook");
+        })
+        .ok()
+        .flatten()
+        .map(WebhookResource::from)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_webhook(&self, webhook_id: &WebhookId) -> Option<WebhookResource> {
+        let conn = self.database.connect().await;
+        conn.query_opt("SELECT * FROM webhooks WHERE webhook_id = $1", &[&webhook_id])
+            .await
+            .map_err(|e| {
+                tracing::warn!(e = ?e, "Failed to load webhook");
+            })
+            .ok()
+            .flatten()
+            .map(WebhookResource::from)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_webhooks(&self, world_id: &WorldId) -> Vec<WebhookResource> {
+        let conn = self.database.connect().await;
+        conn.query("SELECT * FROM webhooks WHERE world_id = $1 ORDER BY created", &[&world_id])
+            .await
+            .map_or_else(
+                |e| {
+                    tracing::warn!(e = ?e, "Failed to load webhooks");
+                    vec![]
+                },
+                |rows| rows.into_iter().map(WebhookResource::from).collect(),
+            )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn update_webhook(&self, webhook_id: &WebhookId, webhook: &WebhookData) -> Result<WebhookResource, SaveWebhookError> {
+        let conn = self.database.connect().await;
+
+        let version = Uuid::new_v4();
+        let updated = Utc::now();
+
+        conn.query_opt(
+            "UPDATE webhooks SET version = $3, updated = $4, url = $5, events = $6, active = $7 WHERE webhook_id = $1 AND world_id = $2 RETURNING *",
+            &[
+                &webhook_id,
+                &webhook.world_id,
+                &version,
+                &updated,
+                &webhook.url,
+                &webhook.events,
+                &webhook.active,
+            ],
+        )
+        .await?
+        .map(WebhookResource::from)
+        .ok_or(SaveWebhookError::UnknownWebhook)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_webhook(&self, world_id: &WorldId, webhook_id: &WebhookId) -> bool {
+        let conn = self.database.connect().await;
+        conn.execute(
+            "DELETE FROM webhooks WHERE world_id = $1 AND webhook_id = $2",
+            &[&world_id, &webhook_id],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to delete webhook");
+                false
+            },
+            |count| count == 1,
+        )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_deliveries(&self, webhook_id: &WebhookId, after: Option<&DeliveryId>, limit: u32) -> Vec<Delivery> {
+        let conn = self.database.connect().await;
+        conn.query(
+            "SELECT * FROM webhook_deliveries
+              WHERE webhook_id = $1
+                AND ($2::BIGINT IS NULL OR delivery_id < $2)
+              ORDER BY delivery_id DESC
+              LIMIT $3",
+            &[&webhook_id, &after, &i64::from(limit)],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to load deliveries");
+                vec![]
+            },
+            |rows| rows.into_iter().map(Delivery::from).collect(),
+        )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_delivery(&self, webhook_id: &WebhookId, delivery_id: &DeliveryId) -> Option<Delivery> {
+        let conn = self.database.connect().await;
+        conn.query_opt(
+            "SELECT * FROM webhook_deliveries WHERE webhook_id = $1 AND delivery_id = $2",
+            &[&webhook_id, &delivery_id],
+        )
+        .await
+        .map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to load delivery");
+        })
+        .ok()
+        .flatten()
+        .map(Delivery::from)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_attempts(&self, delivery_id: &DeliveryId) -> Vec<DeliveryAttempt> {
+        let conn = self.database.connect().await;
+        conn.query(
+            "SELECT * FROM webhook_attempts WHERE delivery_id = $1 ORDER BY attempted",
+            &[&delivery_id],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to load delivery attempts");
+                vec![]
+            },
+            |rows| rows.into_iter().map(DeliveryAttempt::from).collect(),
+        )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn record_attempt(
+        &self,
+        delivery_id: &DeliveryId,
+        attempt: &DeliveryAttempt,
+        status: DeliveryStatus,
+        next_attempt: Option<DateTime<Utc>>,
+    ) {
+        let mut conn = self.database.connect().await;
+        let tx = conn.begin().await;
+
+        let result = async move {
+            tx.execute(
+                "INSERT INTO webhook_attempts(delivery_id, attempted, status_code, error, duration_ms) VALUES ($1, $2, $3, $4, $5)",
+                &[
+                    &delivery_id,
+                    &attempt.attempted,
+                    &attempt.status_code.map(i32::from),
+                    &attempt.error,
+                    &i32::try_from(attempt.duration_ms).unwrap_or(i32::MAX),
+                ],
+            )
+            .await?;
+            tx.execute(
+                "UPDATE webhook_deliveries SET status = $2, attempts = attempts + 1, next_attempt = $3 WHERE delivery_id = $1",
+                &[&delivery_id, &status, &next_attempt],
+            )
+            .await?;
+            tx.commit().await
+        }
+        .await;
+
+        if let Err(e) = result {
+            tracing::warn!(e = ?e, "Failed to record delivery attempt");
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn requeue_delivery(&self, webhook_id: &WebhookId, delivery_id: &DeliveryId, next_attempt: DateTime<Utc>) -> Option<Delivery> {
+        let conn = self.database.connect().await;
+        conn.query_opt(
+            "UPDATE webhook_deliveries SET status = $3, attempts = 0, next_attempt = $4 WHERE webhook_id = $1 AND delivery_id = $2 RETURNING *",
+            &[&webhook_id, &delivery_id, &DeliveryStatus::Pending, &next_attempt],
+        )
+        .await
+        .map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to requeue delivery");
+        })
+        .ok()
+        .flatten()
+        .map(Delivery::from)
+    }
+}
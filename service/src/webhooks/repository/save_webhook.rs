@@ -0,0 +1,17 @@
+/// Errors that can occur when saving a webhook.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveWebhookError {
+    #[error("The webhook was not found")]
+    UnknownWebhook,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl From<tokio_postgres::Error> for SaveWebhookError {
+    fn from(e: tokio_postgres::Error) -> Self {
+        tracing::warn!("Unexpected database error: {:?}", e);
+
+        SaveWebhookError::UnknownError
+    }
+}
@@ -0,0 +1,393 @@
+use std::{
+    convert::TryFrom,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    time::{Duration, Instant},
+};
+
+use actix_http::http::{StatusCode, Uri};
+use actix_tls::connect::{new_connector, Resolve, Resolver};
+use chrono::Utc;
+use futures::future::LocalBoxFuture;
+
+use super::{Delivery, DeliveryAttempt, WebhookSecret};
+
+/// The header that carries the name of the event that is being delivered.
+pub const EVENT_HEADER: &str = "X-Worlds-Event";
+
+/// The header that carries the ID of the delivery, which stays the same across every attempt.
+pub const DELIVERY_HEADER: &str = "X-Worlds-Delivery";
+
+/// The header that carries the time that the attempt was sent, as seconds since the Unix epoch.
+pub const TIMESTAMP_HEADER: &str = "X-Worlds-Timestamp";
+
+/// The header that carries the signature of the timestamp and the payload.
+pub const SIGNATURE_HEADER: &str = "X-Worlds-Signature";
+
+/// The default for how long to wait for a webhook to respond before giving up on the attempt.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Sender of the deliveries to webhooks over HTTP.
+///
+/// Webhooks are only ever sent to public addresses, so that they can't be used to reach the
+/// network that the service runs in.
+#[derive(Debug, Clone)]
+pub struct WebhookSender {
+    timeout:       Duration,
+    allow_private: bool,
+}
+
+impl Default for WebhookSender {
+    fn default() -> Self {
+        Self::new(DEFAULT_TIMEOUT)
+    }
+}
+
+impl WebhookSender {
+    /// Create a new webhook sender.
+    ///
+    /// # Parameters
+    /// - `timeout` - How long to wait for a webhook to respond before giving up on the attempt
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            allow_private: false,
+        }
+    }
+
+    /// Allow webhooks to be sent to loopback, link-local and private addresses, such as to a
+    /// receiver on the same machine when testing.
+    pub fn allowing_private_addresses(self) -> Self {
+        Self {
+            allow_private: true,
+            ..self
+        }
+    }
+
+    /// Check that the URL of a webhook is one that changes can be delivered to.
+    ///
+    /// Host names are checked again when a delivery is sent, since what they resolve to can change.
+    ///
+    /// # Parameters
+    /// - `url` - The URL
+    ///
+    /// # Returns
+    /// True if the URL is an absolute HTTP or HTTPS URL, for a host that isn't known to be private.
+    /// False if not.
+    pub fn accepts_url(&self, url: &str) -> bool {
+        let Ok(uri) = url.parse::<Uri>() else {
+            return false;
+        };
+        let Some(host) = uri.host().filter(|host| !host.is_empty()) else {
+            return false;
+        };
+
+        matches!(uri.scheme_str(), Some("http" | "https")) && (self.allow_private || is_public_host(host))
+    }
+
+    /// Make a single attempt at a delivery.
+    ///
+    /// The payload is signed with the secret of the webhook, and redirects are not followed so that
+    /// the payload is only ever sent to the URL that was registered.
+    ///
+    /// # Parameters
+    /// - `url` - The URL of the webhook
+    /// - `secret` - The secret of the webhook
+    /// - `delivery` - The delivery to attempt
+    ///
+    /// # Returns
+    /// The outcome of the attempt.
+    #[tracing::instrument(skip(self, secret, delivery), fields(delivery_id = %delivery.id))]
+    pub async fn send(&self, url: &str, secret: &WebhookSecret, delivery: &Delivery) -> DeliveryAttempt {
+        let attempted = Utc::now();
+        let payload = serde_json::to_vec(&delivery.payload).expect("Failed to serialize payload");
+        let timestamp = attempted.timestamp();
+        let signature = secret.sign(timestamp, &payload);
+
+        if !self.accepts_url(url) {
+            tracing::warn!(url = ?url, "Refusing to send to a webhook that isn't at a public address");
+
+            return DeliveryAttempt {
+                attempted,
+                status_code: None,
+                error: Some("The webhook is not at a public address".to_owned()),
+                duration_ms: 0,
+            };
+        }
+
+        // The client can't be shared between threads, and creating one is cheap next to the request.
+        let resolver = Resolver::new_custom(AddressResolver {
+            allow_private: self.allow_private,
+        });
+        let client = awc::Client::builder()
+            .connector(awc::Connector::new().connector(new_connector(resolver)))
+            .timeout(self.timeout)
+            .disable_redirects()
+            .finish();
+
+        let started = Instant::now();
+        let response = client
+            .post(url)
+            .content_type("application/json")
+            .insert_header((EVENT_HEADER, delivery.event.as_str()))
+            .insert_header((DELIVERY_HEADER, delivery.id.to_string()))
+            .insert_header((TIMESTAMP_HEADER, timestamp.to_string()))
+            .insert_header((SIGNATURE_HEADER, format!("sha256={}", signature)))
+            .send_body(payload)
+            .await;
+        let duration_ms = u32::try_from(started.elapsed().as_millis()).unwrap_or(u32::MAX);
+
+        match response {
+            Ok(response) => {
+                let status = response.status();
+                tracing::debug!(status = ?status, "Webhook responded");
+
+                DeliveryAttempt {
+                    attempted,
+                    status_code: Some(status.as_u16()),
+                    error: if status.is_success() { None } else { Some(describe_status(status)) },
+                    duration_ms,
+                }
+            },
+            Err(e) => {
+                tracing::debug!(e = ?e, "Failed to reach webhook");
+
+                DeliveryAttempt {
+                    attempted,
+                    status_code: None,
+                    error: Some(e.to_string()),
+                    duration_ms,
+                }
+            },
+        }
+    }
+}
+
+/// Resolver of the host names of webhooks, which leaves out any addresses that webhooks aren't
+/// allowed to be sent to.
+struct AddressResolver {
+    allow_private: bool,
+}
+
+impl Resolve for AddressResolver {
+    fn lookup<'a>(&'a self, host: &'a str, port: u16) -> LocalBoxFuture<'a, Result<Vec<SocketAddr>, Box<dyn std::error::Error>>> {
+        Box::pin(async move {
+            let addresses: Vec<_> = tokio::net::lookup_host((host, port))
+                .await?
+                .filter(|address| self.allow_private || is_public_address(address.ip()))
+                .collect();
+
+            if addresses.is_empty() {
+                return Err(format!("{} does not resolve to a public address", host).into());
+            }
+
+            Ok(addresses)
+        })
+    }
+}
+
+/// Check that the host of a webhook isn't known to be private without resolving it.
+///
+/// # Parameters
+/// - `host` - The host, which is either a name or an IP address
+fn is_public_host(host: &str) -> bool {
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+
+    if let Ok(address) = host.parse::<IpAddr>() {
+        return is_public_address(address);
+    }
+
+    let host = host.trim_end_matches('.').to_lowercase();
+    host != "localhost" && !host.ends_with(".localhost")
+}
+
+/// Check that an address is one that webhooks can be sent to, rather than a loopback, link-local,
+/// private or otherwise special one.
+///
+/// # Parameters
+/// - `address` - The address
+fn is_public_address(address: IpAddr) -> bool {
+    match address {
+        IpAddr::V4(address) => is_public_ipv4(address),
+        IpAddr::V6(address) => match address.to_ipv4_mapped() {
+            Some(mapped) => is_public_ipv4(mapped),
+            None => is_public_ipv6(address),
+        },
+    }
+}
+
+fn is_public_ipv4(address: Ipv4Addr) -> bool {
+    let [first, second, ..] = address.octets();
+    // 100.64.0.0/10 is shared between the networks of carriers.
+    let shared = first == 100 && (second & 0b1100_0000) == 64;
+
+    !(address.is_loopback()
+        || address.is_private()
+        || address.is_link_local()
+        || address.is_unspecified()
+        || address.is_broadcast()
+        || address.is_multicast()
+        || address.is_documentation()
+        || shared)
+}
+
+fn is_public_ipv6(address: Ipv6Addr) -> bool {
+    let first = address.segments()[0];
+    // fc00::/7 is for unique local addresses, and fe80::/10 for link-local ones.
+    let unique_local = (first & 0xfe00) == 0xfc00;
+    let link_local = (first & 0xffc0) == 0xfe80;
+
+    !(address.is_loopback() || address.is_unspecified() || address.is_multicast() || unique_local || link_local)
+}
+
+/// Describe why a status code that a webhook responded with means that the attempt failed.
+///
+/// # Parameters
+/// - `status` - The status code
+fn describe_status(status: StatusCode) -> String {
+    match status.canonical_reason() {
+        Some(reason) => format!("The webhook responded with {} {}", status.as_u16(), reason),
+        None => format!("The webhook responded with {}", status.as_u16()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use serde_json::json;
+    use test_case::test_case;
+
+    use super::*;
+    use crate::webhooks::{testing::StandInReceiver, DeliveryId, DeliveryStatus};
+
+    /// A sender that can reach the stand-in receivers, which are on the loopback address.
+    fn sender() -> WebhookSender {
+        WebhookSender::default().allowing_private_addresses()
+    }
+
+    #[test_case("https://example.com/hook", true ; "HTTPS")]
+    #[test_case("http://example.com:8080/hook?x=1", true ; "HTTP with port and query")]
+    #[test_case("https://93.184.216.34/hook", true ; "Public IPv4 address")]
+    #[test_case("https://[2606:2800:220:1::248]/hook", true ; "Public IPv6 address")]
+    #[test_case("ftp://example.com/hook", false ; "Other scheme")]
+    #[test_case("/hook", false ; "Relative")]
+    #[test_case("example.com/hook", false ; "No scheme")]
+    #[test_case("https://exa mple.com/hook", false ; "Unparseable")]
+    #[test_case("", false ; "Blank")]
+    #[test_case("http://localhost:8080/hook", false ; "Localhost")]
+    #[test_case("http://api.localhost/hook", false ; "Localhost subdomain")]
+    #[test_case("http://127.0.0.1/hook", false ; "Loopback")]
+    #[test_case("http://10.1.2.3/hook", false ; "Private")]
+    #[test_case("http://192.168.0.1/hook", false ; "Private class C")]
+    #[test_case("http://169.254.169.254/latest/meta-data", false ; "Link-local")]
+    #[test_case("http://100.64.0.1/hook", false ; "Shared")]
+    #[test_case("http://0.0.0.0/hook", false ; "Unspecified")]
+    #[test_case("http://[::1]/hook", false ; "IPv6 loopback")]
+    #[test_case("http://[fd00::1]/hook", false ; "IPv6 unique local")]
+    #[test_case("http://[fe80::1]/hook", false ; "IPv6 link-local")]
+    #[test_case("http://[::ffff:10.0.0.1]/hook", false ; "IPv4 mapped private")]
+    fn test_accepts_url(input: &str, expected: bool) {
+        check!(WebhookSender::default().accepts_url(input) == expected);
+    }
+
+    #[test]
+    fn accepts_private_url_when_allowed() {
+        check!(sender().accepts_url("http://127.0.0.1:8080/hook"));
+        check!(!sender().accepts_url("ftp://127.0.0.1/hook"));
+    }
+
+    fn delivery() -> Delivery {
+        Delivery {
+            id:           DeliveryId::from(42),
+            webhook_id:   "d4a4cb16-a5f1-4e4e-9b4c-27b4f0bd1d7a".parse().unwrap(),
+            event:        "article.created".to_owned(),
+            payload:      json!({ "event": "article.created" }),
+            status:       DeliveryStatus::Pending,
+            attempts:     0,
+            next_attempt: Some(Utc::now()),
+            created:      Utc::now(),
+        }
+    }
+
+    #[actix_rt::test]
+    async fn send_signed() {
+        let receiver = StandInReceiver::start(&[204]);
+        let secret = WebhookSecret::from("whsec_abc");
+
+        let attempt = sender().send(&receiver.url(), &secret, &delivery()).await;
+
+        check!(attempt.succeeded());
+        check!(attempt.status_code == Some(204));
+        check!(attempt.error.is_none());
+
+        let received = receiver.received();
+        let_assert!([request] = received.as_slice());
+        check!(request.header(EVENT_HEADER) == Some("article.created"));
+        check!(request.header(DELIVERY_HEADER) == Some("42"));
+        check!(request.header("content-type") == Some("application/json"));
+        check!(serde_json::from_slice::<serde_json::Value>(&request.body).unwrap() == json!({ "event": "article.created" }));
+        check!(request.verify(&secret));
+        check!(!request.verify(&WebhookSecret::from("whsec_other")));
+    }
+
+    #[actix_rt::test]
+    async fn send_rejected() {
+        let receiver = StandInReceiver::start(&[500]);
+
+        let attempt = sender().send(&receiver.url(), &WebhookSecret::generate(), &delivery()).await;
+
+        check!(!attempt.succeeded());
+        check!(attempt.status_code == Some(500));
+        check!(attempt.error == Some("The webhook responded with 500 Internal Server Error".to_owned()));
+    }
+
+    #[actix_rt::test]
+    async fn send_redirected() {
+        let receiver = StandInReceiver::start(&[302]);
+
+        let attempt = sender().send(&receiver.url(), &WebhookSecret::generate(), &delivery()).await;
+
+        check!(!attempt.succeeded());
+        check!(attempt.status_code == Some(302));
+        check!(receiver.received().len() == 1);
+    }
+
+    #[actix_rt::test]
+    async fn send_unreachable() {
+        let url = StandInReceiver::unused_url();
+
+        let attempt = sender().send(&url, &WebhookSecret::generate(), &delivery()).await;
+
+        check!(!attempt.succeeded());
+        check!(attempt.status_code.is_none());
+        check!(attempt.error.is_some());
+    }
+
+    #[actix_rt::test]
+    async fn send_to_private_address() {
+        let receiver = StandInReceiver::start(&[]);
+
+        let attempt = WebhookSender::default()
+            .send(&receiver.url(), &WebhookSecret::generate(), &delivery())
+            .await;
+
+        check!(!attempt.succeeded());
+        check!(attempt.status_code.is_none());
+        check!(attempt.error == Some("The webhook is not at a public address".to_owned()));
+        check!(receiver.received().is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn resolve_private_address() {
+        let resolver = AddressResolver { allow_private: false };
+
+        check!(resolver.lookup("localhost", 80).await.is_err());
+    }
+
+    #[actix_rt::test]
+    async fn resolve_private_address_when_allowed() {
+        let resolver = AddressResolver { allow_private: true };
+
+        let_assert!(Ok(addresses) = resolver.lookup("localhost", 80).await);
+        check!(addresses.iter().all(|address| address.ip().is_loopback()));
+    }
+}
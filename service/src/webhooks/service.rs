@@ -0,0 +1,35 @@
+mod create_webhook;
+mod delete_webhook;
+mod deliver;
+mod deliveries;
+mod get_webhook;
+mod update_webhook;
+
+use std::sync::Arc;
+
+pub use create_webhook::{CreateWebhookError, NewWebhook};
+pub(super) use deliver::{retry_delay, MAX_ATTEMPTS};
+pub use deliveries::RedeliverError;
+pub use update_webhook::UpdateWebhookError;
+
+use super::{repository::WebhookRepository, WebhookSender};
+use crate::jobs::JobService;
+
+/// Service layer for working with the webhooks of worlds, and for delivering changes to them.
+pub struct WebhookService {
+    repository: Arc<dyn WebhookRepository>,
+    sender:     WebhookSender,
+    jobs:       Arc<JobService>,
+}
+
+impl WebhookService {
+    /// Create a new webhook service.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store webhooks and their deliveries in
+    /// - `sender` - The sender to deliver changes to webhooks with
+    /// - `jobs` - The service to queue the deliveries that are sent again with
+    pub fn new(repository: Arc<dyn WebhookRepository>, sender: WebhookSender, jobs: Arc<JobService>) -> Self {
+        Self { repository, sender, jobs }
+    }
+}
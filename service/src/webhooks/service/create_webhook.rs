@@ -0,0 +1,131 @@
+use super::WebhookService;
+use crate::{
+    users::UserId,
+    webhooks::{EventFilter, SaveWebhookError, WebhookData, WebhookResource, WebhookSecret},
+    worlds::WorldId,
+};
+
+/// Details needed to create a new webhook.
+#[derive(Debug)]
+pub struct NewWebhook {
+    pub url:    String,
+    pub events: Vec<EventFilter>,
+    pub active: bool,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum CreateWebhookError {
+    #[error("The URL of the webhook is not an absolute HTTP or HTTPS URL for a public host")]
+    InvalidUrl,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl WebhookService {
+    /// Create a new webhook within a world, with a newly generated secret.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world to create the webhook in
+    /// - `user_id` - The ID of the user that is registering the webhook
+    /// - `webhook` - The details of the webhook to create
+    ///
+    /// # Returns
+    /// The newly created webhook, including its secret.
+    pub async fn create_webhook(
+        &self,
+        world_id: &WorldId,
+        user_id: &UserId,
+        webhook: NewWebhook,
+    ) -> Result<WebhookResource, CreateWebhookError> {
+        if !self.sender.accepts_url(&webhook.url) {
+            return Err(CreateWebhookError::InvalidUrl);
+        }
+
+        let created = self
+            .repository
+            .create_webhook(&WebhookData {
+                world_id:   world_id.clone(),
+                url:        webhook.url,
+                events:     webhook.events,
+                active:     webhook.active,
+                secret:     WebhookSecret::generate(),
+                created_by: Some(user_id.clone()),
+            })
+            .await?;
+
+        Ok(created)
+    }
+}
+
+impl From<SaveWebhookError> for CreateWebhookError {
+    fn from(_: SaveWebhookError) -> Self {
+        Self::UnknownError
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::{
+        jobs::{JobService, MemoryJobRepository},
+        webhooks::{MemoryWebhookRepository, WebhookSender},
+    };
+
+    #[actix_rt::test]
+    async fn create_webhook() {
+        let sut = WebhookService::new(
+            Arc::new(MemoryWebhookRepository::new()),
+            WebhookSender::default(),
+            Arc::new(JobService::new(Arc::new(MemoryJobRepository::new()))),
+        );
+        let world_id = WorldId::default();
+
+        let result = sut
+            .create_webhook(
+                &world_id,
+                &UserId::default(),
+                NewWebhook {
+                    url:    "https://example.com/hook".to_owned(),
+                    events: vec!["article.*".parse().unwrap()],
+                    active: true,
+                },
+            )
+            .await;
+
+        let_assert!(Ok(webhook) = result);
+        check!(webhook.data.url == "https://example.com/hook");
+        check!(webhook.data.secret.value().starts_with("whsec_"));
+
+        let_assert!(Some(loaded) = sut.get_webhook(&world_id, &webhook.identity.id).await);
+        check!(loaded.data.secret == webhook.data.secret);
+    }
+
+    #[actix_rt::test]
+    async fn create_webhook_invalid_url() {
+        let sut = WebhookService::new(
+            Arc::new(MemoryWebhookRepository::new()),
+            WebhookSender::default(),
+            Arc::new(JobService::new(Arc::new(MemoryJobRepository::new()))),
+        );
+
+        let result = sut
+            .create_webhook(
+                &WorldId::default(),
+                &UserId::default(),
+                NewWebhook {
+                    url:    "ftp://example.com/hook".to_owned(),
+                    events: vec![],
+                    active: true,
+                },
+            )
+            .await;
+
+        let_assert!(Err(e) = result);
+        check!(e == CreateWebhookError::InvalidUrl);
+    }
+}
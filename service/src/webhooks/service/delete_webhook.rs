@@ -0,0 +1,16 @@
+use super::WebhookService;
+use crate::{webhooks::WebhookId, worlds::WorldId};
+
+impl WebhookService {
+    /// Delete a single webhook. Any deliveries that are still waiting to be sent to it are dropped.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the webhook belongs to
+    /// - `webhook_id` - The ID of the webhook
+    ///
+    /// # Returns
+    /// True if the webhook existed and was deleted. False if not.
+    pub async fn delete_webhook(&self, world_id: &WorldId, webhook_id: &WebhookId) -> bool {
+        self.repository.delete_webhook(world_id, webhook_id).await
+    }
+}
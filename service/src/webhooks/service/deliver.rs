@@ -0,0 +1,275 @@
+use std::{cmp::min, time::Duration};
+
+use chrono::{DateTime, Utc};
+
+use super::WebhookService;
+use crate::webhooks::{DeliveryId, DeliveryStatus, WebhookId};
+
+/// How long to wait before the first retry. Each retry after that waits twice as long as the one
+/// before it.
+const FIRST_RETRY: Duration = Duration::from_mins(1);
+
+/// The longest to wait between retries.
+const MAX_RETRY: Duration = Duration::from_hours(4);
+
+/// The most attempts to make at a delivery before giving up on it.
+pub const MAX_ATTEMPTS: u32 = 10;
+
+/// Determine how long to wait before retrying a delivery.
+///
+/// # Parameters
+/// - `attempts` - The number of attempts that have failed so far
+///
+/// # Returns
+/// How long to wait, or `None` if the delivery should be given up on.
+pub fn retry_delay(attempts: u32) -> Option<Duration> {
+    if attempts == 0 || attempts >= MAX_ATTEMPTS {
+        return None;
+    }
+
+    let delay = 2_u32
+        .checked_pow(attempts - 1)
+        .and_then(|factor| FIRST_RETRY.checked_mul(factor))
+        .unwrap_or(MAX_RETRY);
+
+    Some(min(delay, MAX_RETRY))
+}
+
+impl WebhookService {
+    /// Make an attempt at a delivery that is still pending, and record how it went.
+    ///
+    /// # Parameters
+    /// - `webhook_id` - The ID of the webhook
+    /// - `delivery_id` - The ID of the delivery
+    /// - `now` - The current time, which any retry is scheduled from
+    ///
+    /// # Returns
+    /// The status of the delivery after the attempt, or `None` if no attempt was made because the
+    /// delivery is no longer pending, the webhook isn't active or either of them has been deleted.
+    #[tracing::instrument(skip(self))]
+    pub async fn deliver(&self, webhook_id: &WebhookId, delivery_id: &DeliveryId, now: DateTime<Utc>) -> Option<DeliveryStatus> {
+        let delivery = self
+            .repository
+            .get_delivery(webhook_id, delivery_id)
+            .await
+            .filter(|delivery| delivery.status == DeliveryStatus::Pending)?;
+        let webhook = self
+            .repository
+            .find_webhook(webhook_id)
+            .await
+            .filter(|webhook| webhook.data.active)?;
+
+        let attempt = self.sender.send(&webhook.data.url, &webhook.data.secret, &delivery).await;
+
+        let (status, next_attempt) = if attempt.succeeded() {
+            (DeliveryStatus::Succeeded, None)
+        } else {
+            match retry_delay(delivery.attempts + 1) {
+                Some(delay) => (DeliveryStatus::Pending, Some(now + chrono::Duration::from_std(delay).unwrap())),
+                None => (DeliveryStatus::Failed, None),
+            }
+        };
+        tracing::debug!(status = ?status, "Attempted delivery");
+
+        self.repository.record_attempt(delivery_id, &attempt, status, next_attempt).await;
+
+        Some(status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert2::{check, let_assert};
+    use serde_json::json;
+    use test_case::test_case;
+
+    use super::*;
+    use crate::{
+        jobs::{Job, JobService, MemoryJobRepository},
+        realtime::{Change, ChangeAction},
+        users::UserId,
+        webhooks::{
+            testing::StandInReceiver, DeliverWebhook, DeliverWebhookPayload, MemoryWebhookRepository, NewWebhook, WebhookData,
+            WebhookRepository, WebhookResource, WebhookSender,
+        },
+        worlds::WorldId,
+    };
+
+    #[test_case(0, None ; "Never attempted")]
+    #[test_case(1, Some(60) ; "First retry")]
+    #[test_case(2, Some(120) ; "Second retry")]
+    #[test_case(5, Some(960) ; "Fifth retry")]
+    #[test_case(8, Some(7680) ; "Eighth retry")]
+    #[test_case(9, Some(14400) ; "Capped")]
+    #[test_case(10, None ; "Given up")]
+    #[test_case(u32::MAX, None ; "Far beyond")]
+    fn test_retry_delay(attempts: u32, expected: Option<u64>) {
+        check!(retry_delay(attempts) == expected.map(Duration::from_secs));
+    }
+
+    struct Fixture {
+        repository: Arc<MemoryWebhookRepository>,
+        jobs:       Arc<MemoryJobRepository>,
+        sut:        Arc<WebhookService>,
+        world_id:   WorldId,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let repository = Arc::new(MemoryWebhookRepository::new());
+            let jobs = Arc::new(MemoryJobRepository::new());
+            let sut = Arc::new(WebhookService::new(
+                repository.clone(),
+                WebhookSender::new(Duration::from_secs(5)).allowing_private_addresses(),
+                Arc::new(JobService::new(jobs.clone())),
+            ));
+
+            Self {
+                repository,
+                jobs,
+                sut,
+                world_id: WorldId::default(),
+            }
+        }
+
+        async fn webhook(&self, url: String, events: &[&str]) -> WebhookResource {
+            self.sut
+                .create_webhook(
+                    &self.world_id,
+                    &UserId::default(),
+                    NewWebhook {
+                        url,
+                        events: events.iter().map(|e| e.parse().unwrap()).collect(),
+                        active: true,
+                    },
+                )
+                .await
+                .unwrap()
+        }
+
+        fn change(&self, resource: &str, action: ChangeAction) -> Vec<DeliveryId> {
+            self.repository.record_change(&Change {
+                world_id: self.world_id.clone(),
+                action,
+                resource: resource.to_owned(),
+                resource_id: uuid::Uuid::new_v4().to_string(),
+                version: None,
+            })
+        }
+    }
+
+    #[actix_rt::test]
+    async fn deliver_successfully() {
+        let receiver = StandInReceiver::start(&[]);
+        let fixture = Fixture::new();
+        let webhook = fixture.webhook(receiver.url(), &["article.*"]).await;
+
+        let queued = fixture.change("article", ChangeAction::Created);
+        let_assert!([delivery_id] = queued.as_slice());
+        check!(fixture.change("character", ChangeAction::Created).is_empty());
+
+        check!(fixture.sut.deliver(&webhook.identity.id, delivery_id, Utc::now()).await == Some(DeliveryStatus::Succeeded));
+
+        let received = receiver.received();
+        let_assert!([request] = received.as_slice());
+        check!(request.verify(&webhook.data.secret));
+        check!(request.header("X-Worlds-Event") == Some("article.created"));
+
+        let_assert!(Some((delivery, attempts)) = fixture.sut.get_delivery(&fixture.world_id, &webhook.identity.id, delivery_id).await);
+        check!(delivery.status == DeliveryStatus::Succeeded);
+        check!(delivery.attempts == 1);
+        check!(delivery.next_attempt.is_none());
+        let_assert!([attempt] = attempts.as_slice());
+        check!(attempt.status_code == Some(200));
+
+        // Running the same job again doesn't send it twice.
+        check!(fixture.sut.deliver(&webhook.identity.id, delivery_id, Utc::now()).await.is_none());
+        check!(receiver.received().len() == 1);
+    }
+
+    #[actix_rt::test]
+    async fn retry_with_backoff() {
+        let receiver = StandInReceiver::start(&[503, 500]);
+        let fixture = Fixture::new();
+        let webhook = fixture.webhook(receiver.url(), &["*.*"]).await;
+        let queued = fixture.change("article", ChangeAction::Updated);
+        let_assert!([delivery_id] = queued.as_slice());
+
+        let start = Utc::now();
+        check!(fixture.sut.deliver(&webhook.identity.id, delivery_id, start).await == Some(DeliveryStatus::Pending));
+        let_assert!(Some(delivery) = fixture.repository.get_delivery(&webhook.identity.id, delivery_id).await);
+        check!(delivery.attempts == 1);
+        let_assert!(Some(next_attempt) = delivery.next_attempt);
+        check!(next_attempt >= start + chrono::Duration::seconds(60));
+
+        check!(fixture.sut.deliver(&webhook.identity.id, delivery_id, next_attempt).await == Some(DeliveryStatus::Pending));
+        let_assert!(Some(delivery) = fixture.repository.get_delivery(&webhook.identity.id, delivery_id).await);
+        check!(delivery.attempts == 2);
+        let_assert!(Some(later_attempt) = delivery.next_attempt);
+        check!(later_attempt >= next_attempt + chrono::Duration::seconds(120));
+
+        check!(fixture.sut.deliver(&webhook.identity.id, delivery_id, later_attempt).await == Some(DeliveryStatus::Succeeded));
+
+        let attempts = fixture.repository.get_attempts(delivery_id).await;
+        let codes: Vec<_> = attempts.iter().map(|a| a.status_code).collect();
+        check!(codes == vec![Some(503), Some(500), Some(200)]);
+        check!(receiver.received().len() == 3);
+    }
+
+    #[actix_rt::test]
+    async fn give_up_and_redeliver() {
+        let receiver = StandInReceiver::start(&[500; MAX_ATTEMPTS as usize]);
+        let fixture = Fixture::new();
+        let webhook = fixture.webhook(receiver.url(), &["article.deleted"]).await;
+        let queued = fixture.change("article", ChangeAction::Deleted);
+        let_assert!([delivery_id] = queued.as_slice());
+
+        for _ in 1..MAX_ATTEMPTS {
+            check!(fixture.sut.deliver(&webhook.identity.id, delivery_id, Utc::now()).await == Some(DeliveryStatus::Pending));
+        }
+        check!(fixture.sut.deliver(&webhook.identity.id, delivery_id, Utc::now()).await == Some(DeliveryStatus::Failed));
+
+        let_assert!(Some(delivery) = fixture.repository.get_delivery(&webhook.identity.id, delivery_id).await);
+        check!(delivery.attempts == MAX_ATTEMPTS);
+        check!(delivery.next_attempt.is_none());
+        check!(fixture.sut.deliver(&webhook.identity.id, delivery_id, Utc::now()).await.is_none());
+
+        let_assert!(Ok(requeued) = fixture.sut.redeliver(&fixture.world_id, &webhook.identity.id, delivery_id).await);
+        check!(requeued.status == DeliveryStatus::Pending);
+        check!(requeued.attempts == 0);
+
+        let jobs = fixture.jobs.all_jobs();
+        let_assert!([job] = jobs.as_slice());
+        check!(job.kind == "webhooks.deliver");
+        check!(job.payload == json!({ "webhookId": webhook.identity.id, "deliveryId": delivery_id }));
+
+        let payload: DeliverWebhookPayload = serde_json::from_value(job.payload.clone()).unwrap();
+        check!(DeliverWebhook::new(fixture.sut.clone()).run(payload).await == Ok(()));
+        let_assert!(Some(delivery) = fixture.repository.get_delivery(&webhook.identity.id, delivery_id).await);
+        check!(delivery.status == DeliveryStatus::Succeeded);
+        check!(fixture.repository.get_attempts(delivery_id).await.len() == MAX_ATTEMPTS as usize + 1);
+    }
+
+    #[actix_rt::test]
+    async fn skip_inactive_webhooks() {
+        let receiver = StandInReceiver::start(&[]);
+        let fixture = Fixture::new();
+        let webhook = fixture.webhook(receiver.url(), &["article.created"]).await;
+        let queued = fixture.change("article", ChangeAction::Created);
+        let_assert!([delivery_id] = queued.as_slice());
+
+        fixture
+            .sut
+            .update_webhook_by_id(&fixture.world_id, &webhook.identity.id, |data| {
+                Ok::<_, ()>(WebhookData { active: false, ..data })
+            })
+            .await
+            .unwrap();
+
+        check!(fixture.sut.deliver(&webhook.identity.id, delivery_id, Utc::now()).await.is_none());
+        check!(fixture.change("article", ChangeAction::Created).is_empty());
+        check!(receiver.received().is_empty());
+    }
+}
@@ -0,0 +1,110 @@
+use chrono::Utc;
+
+use super::WebhookService;
+use crate::{
+    http::pagination::{CursorPage, CursorPagination},
+    jobs::EnqueueJobError,
+    webhooks::{DeliverWebhook, DeliverWebhookPayload, Delivery, DeliveryAttempt, DeliveryId, WebhookId},
+    worlds::WorldId,
+};
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum RedeliverError {
+    #[error("Unknown delivery")]
+    UnknownDelivery,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl WebhookService {
+    /// Get a page of the deliveries to a webhook, newest first.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the webhook belongs to
+    /// - `webhook_id` - The ID of the webhook
+    /// - `pagination` - The page of deliveries to get
+    ///
+    /// # Returns
+    /// The page of deliveries, or `None` if the webhook doesn't exist.
+    pub async fn get_deliveries(
+        &self,
+        world_id: &WorldId,
+        webhook_id: &WebhookId,
+        pagination: &CursorPagination<DeliveryId>,
+    ) -> Option<CursorPage<Delivery, DeliveryId>> {
+        self.repository.get_webhook(world_id, webhook_id).await?;
+
+        let deliveries = self
+            .repository
+            .get_deliveries(webhook_id, pagination.after.as_ref(), pagination.fetch_limit())
+            .await;
+
+        Some(pagination.page(deliveries, |delivery| delivery.id))
+    }
+
+    /// Get a single delivery to a webhook, along with every attempt that has been made at it.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the webhook belongs to
+    /// - `webhook_id` - The ID of the webhook
+    /// - `delivery_id` - The ID of the delivery
+    ///
+    /// # Returns
+    /// The delivery and its attempts, oldest first, or `None` if it doesn't exist.
+    pub async fn get_delivery(
+        &self,
+        world_id: &WorldId,
+        webhook_id: &WebhookId,
+        delivery_id: &DeliveryId,
+    ) -> Option<(Delivery, Vec<DeliveryAttempt>)> {
+        self.repository.get_webhook(world_id, webhook_id).await?;
+
+        let delivery = self.repository.get_delivery(webhook_id, delivery_id).await?;
+        let attempts = self.repository.get_attempts(delivery_id).await;
+
+        Some((delivery, attempts))
+    }
+
+    /// Queue a delivery to be sent again straight away, whatever happened to it before. It is given
+    /// the full set of retries again.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the webhook belongs to
+    /// - `webhook_id` - The ID of the webhook
+    /// - `delivery_id` - The ID of the delivery
+    ///
+    /// # Returns
+    /// The queued delivery.
+    pub async fn redeliver(
+        &self,
+        world_id: &WorldId,
+        webhook_id: &WebhookId,
+        delivery_id: &DeliveryId,
+    ) -> Result<Delivery, RedeliverError> {
+        self.repository
+            .get_webhook(world_id, webhook_id)
+            .await
+            .ok_or(RedeliverError::UnknownDelivery)?;
+
+        let delivery = self
+            .repository
+            .requeue_delivery(webhook_id, delivery_id, Utc::now())
+            .await
+            .ok_or(RedeliverError::UnknownDelivery)?;
+        self.jobs
+            .enqueue::<DeliverWebhook>(&DeliverWebhookPayload {
+                webhook_id:  webhook_id.clone(),
+                delivery_id: *delivery_id,
+            })
+            .await?;
+
+        Ok(delivery)
+    }
+}
+
+impl From<EnqueueJobError> for RedeliverError {
+    fn from(_: EnqueueJobError) -> Self {
+        Self::UnknownError
+    }
+}
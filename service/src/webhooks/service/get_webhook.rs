@@ -0,0 +1,30 @@
+use super::WebhookService;
+use crate::{
+    webhooks::{WebhookId, WebhookResource},
+    worlds::WorldId,
+};
+
+impl WebhookService {
+    /// Get a single webhook.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the webhook belongs to
+    /// - `webhook_id` - The ID of the webhook
+    ///
+    /// # Returns
+    /// The webhook, or `None` if it doesn't exist.
+    pub async fn get_webhook(&self, world_id: &WorldId, webhook_id: &WebhookId) -> Option<WebhookResource> {
+        self.repository.get_webhook(world_id, webhook_id).await
+    }
+
+    /// Get every webhook within a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    ///
+    /// # Returns
+    /// The webhooks, oldest first.
+    pub async fn get_webhooks(&self, world_id: &WorldId) -> Vec<WebhookResource> {
+        self.repository.get_webhooks(world_id).await
+    }
+}
@@ -0,0 +1,81 @@
+use super::WebhookService;
+use crate::{
+    webhooks::{SaveWebhookError, WebhookData, WebhookId, WebhookResource},
+    worlds::WorldId,
+};
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum UpdateWebhookError<E>
+where
+    E: std::fmt::Debug,
+{
+    #[error("Unknown webhook")]
+    UnknownWebhook,
+
+    #[error("The URL of the webhook is not an absolute HTTP or HTTPS URL for a public host")]
+    InvalidUrl,
+
+    #[error("An error occurred updating the webhook data")]
+    UpdateError(E),
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl WebhookService {
+    /// Update the webhook that has the provided ID, using the provided lambda to perform the
+    /// updates. The secret of the webhook and the user that registered it can't be changed.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the webhook belongs to
+    /// - `webhook_id` - The ID of the webhook to update
+    /// - `f` - The function to update the webhook details
+    ///
+    /// # Returns
+    /// The newly updated webhook.
+    pub async fn update_webhook_by_id<F, E>(
+        &self,
+        world_id: &WorldId,
+        webhook_id: &WebhookId,
+        f: F,
+    ) -> Result<WebhookResource, UpdateWebhookError<E>>
+    where
+        F: FnOnce(WebhookData) -> Result<WebhookData, E>,
+        E: std::fmt::Debug,
+    {
+        let webhook = self
+            .repository
+            .get_webhook(world_id, webhook_id)
+            .await
+            .ok_or(UpdateWebhookError::UnknownWebhook)?;
+
+        let secret = webhook.data.secret.clone();
+        let created_by = webhook.data.created_by.clone();
+        let data = f(webhook.data).map_err(UpdateWebhookError::UpdateError)?;
+        let data = WebhookData {
+            world_id: world_id.clone(),
+            secret,
+            created_by,
+            ..data
+        };
+        if !self.sender.accepts_url(&data.url) {
+            return Err(UpdateWebhookError::InvalidUrl);
+        }
+
+        let result = self.repository.update_webhook(webhook_id, &data).await?;
+
+        Ok(result)
+    }
+}
+
+impl<E> From<SaveWebhookError> for UpdateWebhookError<E>
+where
+    E: std::fmt::Debug,
+{
+    fn from(e: SaveWebhookError) -> Self {
+        match e {
+            SaveWebhookError::UnknownWebhook => Self::UnknownWebhook,
+            SaveWebhookError::UnknownError => Self::UnknownError,
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    net::TcpListener,
+    sync::{Arc, Mutex},
+};
+
+use actix_web::{
+    web::{self, Bytes, Data},
+    App, HttpRequest, HttpResponse, HttpServer,
+};
+
+use super::{WebhookSecret, SIGNATURE_HEADER, TIMESTAMP_HEADER};
+
+/// A request that a stand-in receiver was sent.
+#[derive(Debug, Clone)]
+pub struct ReceivedRequest {
+    /// The headers of the request, with lowercase names.
+    pub headers: HashMap<String, String>,
+    pub body:    Bytes,
+}
+
+impl ReceivedRequest {
+    /// Get the value of a header of the request.
+    ///
+    /// # Parameters
+    /// - `name` - The name of the header, in any case
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(&name.to_lowercase()).map(String::as_str)
+    }
+
+    /// Check that the request was signed with a secret, as a real receiver would.
+    ///
+    /// # Parameters
+    /// - `secret` - The secret
+    pub fn verify(&self, secret: &WebhookSecret) -> bool {
+        let Some(timestamp) = self.header(TIMESTAMP_HEADER).and_then(|t| t.parse().ok()) else {
+            return false;
+        };
+
+        self.header(SIGNATURE_HEADER) == Some(&format!("sha256={}", secret.sign(timestamp, &self.body)))
+    }
+}
+
+#[derive(Default)]
+struct State {
+    /// The status codes to respond with, in order. Once they run out, requests are accepted.
+    statuses: VecDeque<u16>,
+    received: Vec<ReceivedRequest>,
+}
+
+/// A local HTTP server that stands in for the receiver of a webhook, recording what it is sent.
+pub struct StandInReceiver {
+    port:  u16,
+    state: Arc<Mutex<State>>,
+}
+
+impl StandInReceiver {
+    /// Start a new stand-in receiver on a free port.
+    ///
+    /// # Parameters
+    /// - `statuses` - The status codes to respond to the first requests with
+    pub fn start(statuses: &[u16]) -> Self {
+        let state = Arc::new(Mutex::new(State {
+            statuses: statuses.iter().copied().collect(),
+            received: vec![],
+        }));
+
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind stand-in receiver");
+        let port = listener.local_addr().unwrap().port();
+
+        let data = Data::new(state.clone());
+        let server = HttpServer::new(move || App::new().app_data(data.clone()).default_service(web::route().to(receive)))
+            .workers(1)
+            .listen(listener)
+            .expect("Failed to listen with stand-in receiver")
+            .run();
+        actix_rt::spawn(async move {
+            let _ = server.await;
+        });
+
+        Self { port, state }
+    }
+
+    /// Get a URL that nothing is listening on, to stand in for a receiver that can't be reached.
+    pub fn unused_url() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind unused port");
+        let port = listener.local_addr().unwrap().port();
+
+        format!("http://127.0.0.1:{}/hook", port)
+    }
+
+    /// The URL to send requests to the receiver at.
+    pub fn url(&self) -> String {
+        format!("http://127.0.0.1:{}/hook", self.port)
+    }
+
+    /// Get every request that the receiver has been sent so far.
+    pub fn received(&self) -> Vec<ReceivedRequest> {
+        self.state.lock().unwrap().received.clone()
+    }
+}
+
+async fn receive(state: Data<Arc<Mutex<State>>>, request: HttpRequest, body: Bytes) -> HttpResponse {
+    let mut state = state.lock().unwrap();
+
+    state.received.push(ReceivedRequest {
+        headers: request
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| Some((name.as_str().to_lowercase(), value.to_str().ok()?.to_owned())))
+            .collect(),
+        body,
+    });
+
+    let status = state.statuses.pop_front().unwrap_or(200);
+    HttpResponse::build(actix_http::http::StatusCode::from_u16(status).unwrap()).finish()
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use actix_web::web::{delete, get, post, resource, ServiceConfig};
+
+use super::{repository::WorldRepository, service::WorldService};
+use crate::server::RouteConfigurer;
+
+/// Component for working with worlds, and the users that collaborate on them.
+pub struct Component {
+    pub service: Arc<WorldService>,
+}
+
+impl Component {
+    /// Create a new worlds component.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store worlds in
+    pub fn new(repository: Arc<dyn WorldRepository>) -> Arc<Self> {
+        let service = Arc::new(WorldService::new(repository));
+
+        Arc::new(Self { service })
+    }
+}
+
+impl RouteConfigurer for Component {
+    fn configure_routes(&self, config: &mut ServiceConfig) {
+        config.data(self.service.clone());
+
+        config.service(resource("/worlds").route(post().to(super::endpoints::create_world::handle)));
+        config.service(resource("/worlds/{id}").route(get().to(super::endpoints::get_world::handle)));
+        config.service(resource("/worlds/{id}/members").route(get().to(super::endpoints::list_members::handle)));
+        config.service(resource("/worlds/{id}/members/{user}").route(delete().to(super::endpoints::remove_member::handle)));
+        config.service(
+            resource("/worlds/{id}/invitations")
+                .route(post().to(super::endpoints::create_invitation::handle))
+                .route(get().to(super::endpoints::list_invitations::handle)),
+        );
+        config.service(resource("/worlds/{id}/invitations/{invitation}").route(delete().to(super::endpoints::revoke_invitation::handle)));
+        config.service(resource("/invitations/{token}/accept").route(post().to(super::endpoints::accept_invitation::handle)));
+        config.service(resource("/invitations/{token}/decline").route(post().to(super::endpoints::decline_invitation::handle)));
+    }
+}
@@ -0,0 +1,11 @@
+pub(super) mod accept_invitation;
+pub(super) mod create_invitation;
+pub(super) mod create_world;
+pub(super) mod decline_invitation;
+pub(super) mod get_world;
+pub(super) mod list_invitations;
+pub(super) mod list_members;
+mod model;
+mod problems;
+pub(super) mod remove_member;
+pub(super) mod revoke_invitation;
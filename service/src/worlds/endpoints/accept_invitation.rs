@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::{model::AcceptedInvitationModel, problems::INVITATION_FOR_OTHER_USER};
+use crate::{
+    authorization::Authentication,
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND, UNAUTHORIZED},
+        response::Response,
+    },
+    tokens::Scope,
+    users::UserService,
+    worlds::{InvitationError, WorldService},
+};
+
+/// Accept an invitation, joining the world that it is for.
+pub async fn handle(
+    service: Data<Arc<WorldService>>,
+    users_service: Data<Arc<UserService>>,
+    path: Path<String>,
+    authentication: Authentication,
+) -> Result<Response<AcceptedInvitationModel>, Problem> {
+    let user_id = authentication.require_user()?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let user = users_service.get_user_by_id(&user_id).await.ok_or(UNAUTHORIZED)?;
+
+    let (world_id, role) = service.accept_invitation(&path, &user).await.map_err(|e| match e {
+        InvitationError::UnknownInvitation => NOT_FOUND,
+        InvitationError::WrongUser => INVITATION_FOR_OTHER_USER,
+        InvitationError::UnknownError => INTERNAL_SERVER_ERROR,
+    })?;
+
+    Ok(Response(AcceptedInvitationModel { world_id, role }))
+}
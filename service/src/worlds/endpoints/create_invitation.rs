@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use actix_http::http::StatusCode;
+use actix_web::web::{Data, Path};
+use chrono::{Duration, Utc};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{
+    model::{CreatedInvitationModel, InvitationModel},
+    problems::UNKNOWN_USERNAME,
+};
+use crate::{
+    authorization::Authentication,
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND},
+        response::{Response, SimpleRespondable},
+        valid::{Valid, Validatable},
+    },
+    tokens::Scope,
+    users::{Email, UserService, Username},
+    worlds::{Invitee, NewInvitation, Role, WorldId, WorldService},
+};
+
+/// The number of days that an invitation is valid for, if not otherwise specified.
+const DEFAULT_EXPIRY_DAYS: i64 = 7;
+
+/// Invite somebody to join a world, either by their username or their email address.
+pub async fn handle(
+    service: Data<Arc<WorldService>>,
+    users_service: Data<Arc<UserService>>,
+    path: Path<String>,
+    request: Valid<CreateInvitationRequest>,
+    authentication: Authentication,
+) -> Result<Response<SimpleRespondable<CreatedInvitationModel>>, Problem> {
+    let world_id: WorldId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&service, &world_id, Role::Owner).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+    let user_id = authentication.require_user()?;
+
+    let request = request.unwrap();
+
+    let invitee = match (request.username, request.email) {
+        (Some(username), _) => {
+            let user = users_service.get_user_by_username(&username).await.ok_or(UNKNOWN_USERNAME)?;
+            Invitee::Username(user.data.username)
+        },
+        (None, Some(email)) => Invitee::Email(email),
+        (None, None) => unreachable!("The schema requires either a username or an email address"),
+    };
+
+    let (invitation, secret) = service
+        .invite(
+            &world_id,
+            &user_id,
+            NewInvitation {
+                invitee,
+                role: request.role,
+                expires: Utc::now() + Duration::days(request.expires_in_days.unwrap_or(DEFAULT_EXPIRY_DAYS)),
+            },
+        )
+        .await
+        .map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to create invitation");
+
+            INTERNAL_SERVER_ERROR
+        })?;
+
+    let model = CreatedInvitationModel {
+        details: InvitationModel::from(invitation),
+        token:   secret.value().to_owned(),
+    };
+
+    Ok(SimpleRespondable::new(model).with_status_code(StatusCode::CREATED).into())
+}
+
+/// The incoming request to invite somebody to a world.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateInvitationRequest {
+    pub username:        Option<Username>,
+    pub email:           Option<Email>,
+    pub role:            Role,
+    pub expires_in_days: Option<i64>,
+}
+
+impl Validatable for CreateInvitationRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "username": Username::schema(),
+                "email": Email::schema(),
+                "role": Role::schema(),
+                "expiresInDays": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "maximum": 30
+                }
+            },
+            "required": [
+                "role"
+            ],
+            "oneOf": [
+                {
+                    "required": ["username"]
+                },
+                {
+                    "required": ["email"]
+                }
+            ]
+        })
+    }
+}
@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use actix_http::http::StatusCode;
+use actix_web::web::Data;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::model::WorldResponse;
+use crate::{
+    authorization::Authentication,
+    http::{
+        problem::{Problem, INTERNAL_SERVER_ERROR},
+        response::SimpleRespondable,
+        valid::{Valid, Validatable},
+    },
+    tokens::Scope,
+    worlds::{Language, WorldData, WorldService},
+};
+
+/// Create a new world, owned by the authenticated user.
+pub async fn handle(
+    service: Data<Arc<WorldService>>,
+    request: Valid<CreateWorldRequest>,
+    authentication: Authentication,
+) -> Result<WorldResponse, Problem> {
+    let user_id = authentication.require_user()?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let request = request.unwrap();
+
+    let world = service
+        .create_world(
+            &user_id,
+            WorldData {
+                name:        request.name,
+                description: request.description,
+                language:    request.language.unwrap_or_default(),
+            },
+        )
+        .await
+        .map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to create world");
+
+            INTERNAL_SERVER_ERROR
+        })?;
+
+    Ok(SimpleRespondable::from(world).with_status_code(StatusCode::CREATED).into())
+}
+
+/// The incoming request to create a world.
+#[derive(Deserialize)]
+pub struct CreateWorldRequest {
+    pub name:        String,
+    pub description: Option<String>,
+    pub language:    Option<Language>,
+}
+
+impl Validatable for CreateWorldRequest {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 200
+                },
+                "description": {
+                    "type": "string"
+                },
+                "language": Language::schema()
+            },
+            "required": [
+                "name"
+            ]
+        })
+    }
+}
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use actix_web::{
+    web::{Data, Path},
+    HttpResponse,
+};
+
+use super::problems::INVITATION_FOR_OTHER_USER;
+use crate::{
+    authorization::Authentication,
+    http::problem::{Problem, INTERNAL_SERVER_ERROR, NOT_FOUND, UNAUTHORIZED},
+    tokens::Scope,
+    users::UserService,
+    worlds::{InvitationError, WorldService},
+};
+
+/// Decline an invitation, so that it can no longer be accepted.
+pub async fn handle(
+    service: Data<Arc<WorldService>>,
+    users_service: Data<Arc<UserService>>,
+    path: Path<String>,
+    authentication: Authentication,
+) -> Result<HttpResponse, Problem> {
+    let user_id = authentication.require_user()?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let user = users_service.get_user_by_id(&user_id).await.ok_or(UNAUTHORIZED)?;
+
+    service.decline_invitation(&path, &user).await.map_err(|e| match e {
+        InvitationError::UnknownInvitation => NOT_FOUND,
+        InvitationError::WrongUser => INVITATION_FOR_OTHER_USER,
+        InvitationError::UnknownError => INTERNAL_SERVER_ERROR,
+    })?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
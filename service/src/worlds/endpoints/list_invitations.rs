@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use actix_web::web::{Data, Path};
+
+use super::model::{InvitationModel, InvitationsModel};
+use crate::{
+    authorization::Authentication,
+    http::{
+        problem::{Problem, NOT_FOUND},
+        response::Response,
+    },
+    tokens::Scope,
+    worlds::{Role, WorldId, WorldService},
+};
+
+pub async fn handle(
+    service: Data<Arc<WorldService>>,
+    path: Path<String>,
+    authentication: Authentication,
+) -> Result<Response<InvitationsModel>, Problem> {
+    let world_id: WorldId = path.parse().map_err(|e| {
+        tracing::warn!(e = ?e, path = ?path, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&service, &world_id, Role::Owner).await?;
+    authentication.require_scope(Scope::WorldsRead)?;
+
+    let invitations = service
+        .get_invitations(&world_id)
+        .await
+        .into_iter()
+        .map(InvitationModel::from)
+        .collect();
+
+    Ok(Response(InvitationsModel { invitations }))
+}
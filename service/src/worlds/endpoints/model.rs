@@ -0,0 +1,125 @@
+use actix_web::http::header::CacheDirective;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::{
+    http::{
+        model::ResourceResponse,
+        response::{Response, SimpleRespondable},
+    },
+    users::{Email, UserId, Username},
+    worlds::{InvitationId, InvitationResource, Invitee, Language, Membership, Role, WorldId, WorldResource},
+};
+
+/// Representation of a world on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorldModel {
+    pub world_id:    WorldId,
+    pub name:        String,
+    pub description: Option<String>,
+    pub language:    Language,
+    pub created:     DateTime<Utc>,
+}
+
+impl From<WorldResource> for WorldModel {
+    fn from(world: WorldResource) -> Self {
+        Self {
+            world_id:    world.identity.id,
+            name:        world.data.name,
+            description: world.data.description,
+            language:    world.data.language,
+            created:     world.identity.created,
+        }
+    }
+}
+
+impl ResourceResponse for WorldResource {
+    fn cache_control(&self) -> Option<Vec<CacheDirective>> {
+        Some(vec![CacheDirective::Private, CacheDirective::NoCache])
+    }
+}
+
+pub type WorldResponse = Response<SimpleRespondable<WorldModel>>;
+
+/// Representation of a member of a world on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemberModel {
+    pub user_id: UserId,
+    pub role:    Role,
+    pub joined:  DateTime<Utc>,
+}
+
+impl From<Membership> for MemberModel {
+    fn from(membership: Membership) -> Self {
+        Self {
+            user_id: membership.user_id,
+            role:    membership.role,
+            joined:  membership.joined,
+        }
+    }
+}
+
+/// Representation of all the members of a world on the HTTP API.
+#[derive(Serialize)]
+pub struct MembersModel {
+    pub members: Vec<MemberModel>,
+}
+
+/// Representation of an invitation to join a world on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvitationModel {
+    pub invitation_id: InvitationId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username:      Option<Username>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email:         Option<Email>,
+    pub role:          Role,
+    pub invited_by:    UserId,
+    pub created:       DateTime<Utc>,
+    pub expires_at:    DateTime<Utc>,
+}
+
+impl From<InvitationResource> for InvitationModel {
+    fn from(invitation: InvitationResource) -> Self {
+        let (username, email) = match invitation.data.invitee {
+            Invitee::Username(username) => (Some(username), None),
+            Invitee::Email(email) => (None, Some(email)),
+        };
+
+        Self {
+            invitation_id: invitation.identity.id,
+            username,
+            email,
+            role: invitation.data.role,
+            invited_by: invitation.data.invited_by,
+            created: invitation.identity.created,
+            expires_at: invitation.data.expires,
+        }
+    }
+}
+
+/// Representation of a newly created invitation on the HTTP API.
+/// This is the only time that the secret needed to respond to the invitation is ever returned.
+#[derive(Serialize)]
+pub struct CreatedInvitationModel {
+    #[serde(flatten)]
+    pub details: InvitationModel,
+    pub token:   String,
+}
+
+/// Representation of all the outstanding invitations to join a world on the HTTP API.
+#[derive(Serialize)]
+pub struct InvitationsModel {
+    pub invitations: Vec<InvitationModel>,
+}
+
+/// Representation of the membership that results from accepting an invitation on the HTTP API.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AcceptedInvitationModel {
+    pub world_id: WorldId,
+    pub role:     Role,
+}
@@ -0,0 +1,24 @@
+use actix_http::http::StatusCode;
+
+use crate::http::problem::SimpleProblemType;
+
+/// Problem to indicate inviting a username that is not registered.
+pub const UNKNOWN_USERNAME: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/worlds/invitations/unknown_username",
+    problem_title: "Unknown Username",
+    status_code:   StatusCode::UNPROCESSABLE_ENTITY,
+};
+
+/// Problem to indicate responding to an invitation that was sent to somebody else.
+pub const INVITATION_FOR_OTHER_USER: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/worlds/invitations/other_user",
+    problem_title: "The invitation is for a different user",
+    status_code:   StatusCode::FORBIDDEN,
+};
+
+/// Problem to indicate removing the only owner of a world.
+pub const LAST_OWNER: SimpleProblemType = SimpleProblemType {
+    problem_type:  "tag:worlds,2021:problems/worlds/members/last_owner",
+    problem_title: "The last owner of a world can not be removed",
+    status_code:   StatusCode::CONFLICT,
+};
@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use actix_web::{
+    web::{Data, Path},
+    HttpResponse,
+};
+
+use super::problems::LAST_OWNER;
+use crate::{
+    authorization::Authentication,
+    http::problem::{Problem, NOT_FOUND},
+    tokens::Scope,
+    users::UserId,
+    worlds::{RemoveMemberError, Role, WorldId, WorldService},
+};
+
+/// Remove a member from a world. Any member can remove themselves, in order to leave the world, but
+/// only owners can remove other members.
+pub async fn handle(
+    service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<HttpResponse, Problem> {
+    let (world_id, user_id) = path.into_inner();
+
+    let world_id: WorldId = world_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, world_id = ?world_id, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    let user_id: UserId = user_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, user_id = ?user_id, "Failed to parse User ID");
+
+        NOT_FOUND
+    })?;
+
+    let required = if authentication.require_user()? == user_id {
+        Role::Viewer
+    } else {
+        Role::Owner
+    };
+    authentication.require_world_role(&service, &world_id, required).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    service.remove_member(&world_id, &user_id).await.map_err(|e| match e {
+        RemoveMemberError::NotMember => NOT_FOUND,
+        RemoveMemberError::LastOwner => LAST_OWNER,
+    })?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use actix_web::{
+    web::{Data, Path},
+    HttpResponse,
+};
+
+use crate::{
+    authorization::Authentication,
+    http::problem::{Problem, NOT_FOUND},
+    tokens::Scope,
+    worlds::{InvitationId, Role, WorldId, WorldService},
+};
+
+/// Revoke an outstanding invitation, so that it can no longer be accepted.
+pub async fn handle(
+    service: Data<Arc<WorldService>>,
+    path: Path<(String, String)>,
+    authentication: Authentication,
+) -> Result<HttpResponse, Problem> {
+    let (world_id, invitation_id) = path.into_inner();
+
+    let world_id: WorldId = world_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, world_id = ?world_id, "Failed to parse World ID");
+
+        NOT_FOUND
+    })?;
+
+    authentication.require_world_role(&service, &world_id, Role::Owner).await?;
+    authentication.require_scope(Scope::WorldsWrite)?;
+
+    let invitation_id: InvitationId = invitation_id.parse().map_err(|e| {
+        tracing::warn!(e = ?e, invitation_id = ?invitation_id, "Failed to parse Invitation ID");
+
+        NOT_FOUND
+    })?;
+
+    if service.revoke_invitation(&world_id, &invitation_id).await {
+        Ok(HttpResponse::NoContent().finish())
+    } else {
+        Err(NOT_FOUND.into())
+    }
+}
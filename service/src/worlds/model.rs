@@ -0,0 +1,51 @@
+mod invitation_id;
+mod invitation_secret;
+mod invitee;
+mod language;
+mod role;
+mod world_id;
+
+use chrono::{DateTime, Utc};
+pub use invitation_id::*;
+pub use invitation_secret::*;
+pub use invitee::*;
+pub use language::*;
+pub use role::*;
+pub use world_id::*;
+
+use crate::{model::Resource, users::UserId};
+
+/// The data representing a world.
+#[derive(Debug, Clone)]
+pub struct WorldData {
+    pub name:        String,
+    pub description: Option<String>,
+    /// The language that the content of the world is written in.
+    pub language:    Language,
+}
+
+/// Type representing a persisted world.
+pub type WorldResource = Resource<WorldId, WorldData>;
+
+/// The membership of a single user in a world.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Membership {
+    pub world_id: WorldId,
+    pub user_id:  UserId,
+    pub role:     Role,
+    pub joined:   DateTime<Utc>,
+}
+
+/// The data representing an invitation for somebody to join a world.
+#[derive(Debug, Clone)]
+pub struct InvitationData {
+    pub world_id:   WorldId,
+    pub invited_by: UserId,
+    pub invitee:    Invitee,
+    pub role:       Role,
+    pub token_hash: String,
+    pub expires:    DateTime<Utc>,
+}
+
+/// Type representing a persisted invitation.
+pub type InvitationResource = Resource<InvitationId, InvitationData>;
@@ -0,0 +1,67 @@
+use rand::{distributions::Alphanumeric, Rng};
+use sha2::{Digest, Sha256};
+
+/// The number of random characters in an invitation secret.
+const SECRET_LENGTH: usize = 40;
+
+/// The secret value of an invitation to join a world, which is needed to accept or decline it.
+/// This is only ever known at the point the invitation is created - only the hash of it is stored.
+pub struct InvitationSecret(String);
+
+impl InvitationSecret {
+    /// Generate a new, random, invitation secret.
+    pub fn generate() -> Self {
+        let random: String = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(SECRET_LENGTH)
+            .map(char::from)
+            .collect();
+
+        Self(random)
+    }
+
+    /// Produce the hash of the provided invitation secret, for storing and looking it up by.
+    ///
+    /// # Parameters
+    /// - `secret` - The invitation secret to hash
+    ///
+    /// # Returns
+    /// The hash of the invitation secret.
+    pub fn hash_of(secret: &str) -> String {
+        format!("{:x}", Sha256::digest(secret.as_bytes()))
+    }
+
+    /// Produce the hash of this invitation secret.
+    pub fn hash(&self) -> String {
+        Self::hash_of(&self.0)
+    }
+
+    /// Get the value of the invitation secret.
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for InvitationSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "InvitationSecret(Redacted)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+
+    use super::*;
+
+    #[test]
+    fn generate() {
+        let first = InvitationSecret::generate();
+        let second = InvitationSecret::generate();
+
+        check!(first.value().len() == SECRET_LENGTH);
+        check!(first.value() != second.value());
+        check!(first.hash() == InvitationSecret::hash_of(first.value()));
+        check!(first.hash() != second.hash());
+    }
+}
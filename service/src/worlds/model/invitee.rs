@@ -0,0 +1,55 @@
+use crate::users::{Email, UserData, Username};
+
+/// The person that an invitation to join a world is for.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Invitee {
+    /// An existing user, identified by their username.
+    Username(Username),
+    /// Anybody who has an account with this email address, including one registered later.
+    Email(Email),
+}
+
+impl Invitee {
+    /// Determine if the provided user is the one that this invitation is for.
+    ///
+    /// # Parameters
+    /// - `user` - The user to check
+    ///
+    /// # Returns
+    /// True if the user is the invitee. False if not.
+    pub fn matches(&self, user: &UserData) -> bool {
+        match self {
+            Invitee::Username(username) => username.key() == user.username.key(),
+            Invitee::Email(email) => email == &user.email,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+
+    use super::*;
+    use crate::users::Password;
+
+    fn user() -> UserData {
+        UserData {
+            username:     "testuser".parse().unwrap(),
+            email:        "testuser@example.com".parse().unwrap(),
+            display_name: "Test User".to_owned(),
+            password:     Password::from_hash("hashed"),
+        }
+    }
+
+    #[test]
+    fn matches_username() {
+        check!(Invitee::Username("TestUser".parse().unwrap()).matches(&user()));
+        check!(!Invitee::Username("otheruser".parse().unwrap()).matches(&user()));
+    }
+
+    #[test]
+    fn matches_email() {
+        check!(Invitee::Email("TestUser@Example.com".parse().unwrap()).matches(&user()));
+        check!(!Invitee::Email("other@example.com".parse().unwrap()).matches(&user()));
+    }
+}
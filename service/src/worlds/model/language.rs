@@ -0,0 +1,148 @@
+use std::str::FromStr;
+
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+/// The language that the content of a world is written in, which determines how words are
+/// stemmed when the world is searched.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    /// No stemming at all, for invented languages or a mix of languages.
+    Simple,
+    Danish,
+    Dutch,
+    #[default]
+    English,
+    Finnish,
+    French,
+    German,
+    Hungarian,
+    Italian,
+    Norwegian,
+    Portuguese,
+    Romanian,
+    Russian,
+    Spanish,
+    Swedish,
+    Turkish,
+}
+
+/// All of the languages that are supported.
+const ALL_LANGUAGES: &[Language] = &[
+    Language::Simple,
+    Language::Danish,
+    Language::Dutch,
+    Language::English,
+    Language::Finnish,
+    Language::French,
+    Language::German,
+    Language::Hungarian,
+    Language::Italian,
+    Language::Norwegian,
+    Language::Portuguese,
+    Language::Romanian,
+    Language::Russian,
+    Language::Spanish,
+    Language::Swedish,
+    Language::Turkish,
+];
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ParseLanguageError {
+    #[error("The language is not known")]
+    Unknown,
+}
+
+impl Language {
+    /// The name of the language, as used on the API and in the database. This is also the name of
+    /// the Postgres text search configuration for the language.
+    pub fn name(self) -> &'static str {
+        match self {
+            Language::Simple => "simple",
+            Language::Danish => "danish",
+            Language::Dutch => "dutch",
+            Language::English => "english",
+            Language::Finnish => "finnish",
+            Language::French => "french",
+            Language::German => "german",
+            Language::Hungarian => "hungarian",
+            Language::Italian => "italian",
+            Language::Norwegian => "norwegian",
+            Language::Portuguese => "portuguese",
+            Language::Romanian => "romanian",
+            Language::Russian => "russian",
+            Language::Spanish => "spanish",
+            Language::Swedish => "swedish",
+            Language::Turkish => "turkish",
+        }
+    }
+
+    /// Generate the JSON Schema fragment for a language.
+    pub fn schema() -> Value {
+        let names: Vec<&str> = ALL_LANGUAGES.iter().map(|l| l.name()).collect();
+
+        json!({
+            "type": "string",
+            "enum": names
+        })
+    }
+}
+
+impl FromStr for Language {
+    type Err = ParseLanguageError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ALL_LANGUAGES
+            .iter()
+            .find(|language| language.name() == s)
+            .copied()
+            .ok_or(ParseLanguageError::Unknown)
+    }
+}
+
+impl ToSql for Language {
+    accepts!(TEXT, VARCHAR);
+    to_sql_checked!();
+
+    fn to_sql(&self, t: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.name().to_sql(t, w)
+    }
+}
+
+impl<'a> FromSql<'a> for Language {
+    accepts!(TEXT, VARCHAR);
+
+    fn from_sql(t: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        let name = <&str>::from_sql(t, raw)?;
+
+        Ok(name.parse()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("simple", Language::Simple ; "Simple")]
+    #[test_case("english", Language::English ; "English")]
+    #[test_case("german", Language::German ; "German")]
+    fn test_parse_success(input: &str, expected: Language) {
+        let_assert!(Ok(language) = input.parse::<Language>());
+        check!(language == expected);
+        check!(serde_json::to_value(language).unwrap() == input);
+    }
+
+    #[test_case("" ; "Blank")]
+    #[test_case("elvish" ; "Unknown")]
+    #[test_case("English" ; "Capitalised")]
+    fn test_parse_fail(input: &str) {
+        let_assert!(Err(e) = input.parse::<Language>());
+        check!(e == ParseLanguageError::Unknown);
+    }
+}
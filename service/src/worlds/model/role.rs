@@ -0,0 +1,135 @@
+use std::str::FromStr;
+
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+/// The role that a member has within a world.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    /// Full control of the world, including managing its members.
+    Owner,
+    /// Able to change the content of the world.
+    Editor,
+    /// Able to read and comment on the content of the world.
+    Commenter,
+    /// Able to read the content of the world.
+    Viewer,
+}
+
+/// All of the roles that exist, from most to least privileged.
+const ALL_ROLES: &[Role] = &[Role::Owner, Role::Editor, Role::Commenter, Role::Viewer];
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ParseRoleError {
+    #[error("The role is not known")]
+    Unknown,
+}
+
+impl Role {
+    /// The name of the role, as used on the API and in the database.
+    pub fn name(self) -> &'static str {
+        match self {
+            Role::Owner => "owner",
+            Role::Editor => "editor",
+            Role::Commenter => "commenter",
+            Role::Viewer => "viewer",
+        }
+    }
+
+    /// Determine if this role grants everything that the required role does.
+    ///
+    /// # Parameters
+    /// - `required` - The role that is required
+    ///
+    /// # Returns
+    /// True if this role is at least as privileged as the required role. False if not.
+    pub fn includes(self, required: Role) -> bool {
+        self.rank() <= required.rank()
+    }
+
+    /// The position of the role in the list of all roles, where lower is more privileged.
+    fn rank(self) -> usize {
+        ALL_ROLES.iter().position(|r| *r == self).unwrap()
+    }
+
+    /// Generate the JSON Schema fragment for a role.
+    pub fn schema() -> Value {
+        let names: Vec<&str> = ALL_ROLES.iter().map(|r| r.name()).collect();
+
+        json!({
+            "type": "string",
+            "enum": names
+        })
+    }
+}
+
+impl FromStr for Role {
+    type Err = ParseRoleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ALL_ROLES
+            .iter()
+            .find(|role| role.name() == s)
+            .copied()
+            .ok_or(ParseRoleError::Unknown)
+    }
+}
+
+impl ToSql for Role {
+    accepts!(TEXT, VARCHAR);
+    to_sql_checked!();
+
+    fn to_sql(&self, t: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.name().to_sql(t, w)
+    }
+}
+
+impl<'a> FromSql<'a> for Role {
+    accepts!(TEXT, VARCHAR);
+
+    fn from_sql(t: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        let name = <&str>::from_sql(t, raw)?;
+
+        Ok(name.parse()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("owner", Role::Owner ; "Owner")]
+    #[test_case("editor", Role::Editor ; "Editor")]
+    #[test_case("commenter", Role::Commenter ; "Commenter")]
+    #[test_case("viewer", Role::Viewer ; "Viewer")]
+    fn test_parse_success(input: &str, expected: Role) {
+        let_assert!(Ok(role) = input.parse::<Role>());
+        check!(role == expected);
+        check!(serde_json::to_value(role).unwrap() == input);
+    }
+
+    #[test_case("" ; "Blank")]
+    #[test_case("admin" ; "Unknown")]
+    #[test_case("Owner" ; "Capitalised")]
+    fn test_parse_fail(input: &str) {
+        let_assert!(Err(e) = input.parse::<Role>());
+        check!(e == ParseRoleError::Unknown);
+    }
+
+    #[test_case(Role::Owner, Role::Owner, true ; "Owner is Owner")]
+    #[test_case(Role::Owner, Role::Viewer, true ; "Owner is Viewer")]
+    #[test_case(Role::Editor, Role::Owner, false ; "Editor is not Owner")]
+    #[test_case(Role::Editor, Role::Commenter, true ; "Editor is Commenter")]
+    #[test_case(Role::Commenter, Role::Editor, false ; "Commenter is not Editor")]
+    #[test_case(Role::Viewer, Role::Viewer, true ; "Viewer is Viewer")]
+    #[test_case(Role::Viewer, Role::Commenter, false ; "Viewer is not Commenter")]
+    fn test_includes(role: Role, required: Role, expected: bool) {
+        check!(role.includes(required) == expected);
+    }
+}
@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod memory;
+mod parse;
+mod postgres;
+mod save_world;
+
+#[cfg(test)]
+pub use memory::MemoryWorldRepository;
+pub use postgres::PostgresWorldRepository;
+pub use save_world::SaveWorldError;
+
+use crate::{
+    users::UserId,
+    worlds::{InvitationData, InvitationId, InvitationResource, Membership, Role, WorldData, WorldId, WorldResource},
+};
+
+/// Repository of worlds, along with their members and outstanding invitations.
+#[async_trait::async_trait]
+pub trait WorldRepository: Send + Sync {
+    /// Create a new world, with the provided user as its owner.
+    ///
+    /// # Parameters
+    /// - `world` - The details of the world to create.
+    /// - `owner` - The ID of the user that owns the new world.
+    ///
+    /// # Returns
+    /// The created world resource.
+    async fn create_world(&self, world: &WorldData, owner: &UserId) -> Result<WorldResource, SaveWorldError>;
+
+    /// Get the world that has the provided ID.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world.
+    ///
+    /// # Returns
+    /// The world resource, or `None` if it doesn't exist.
+    async fn get_world_by_id(&self, world_id: &WorldId) -> Option<WorldResource>;
+
+    /// Get the role that a user has within a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world.
+    /// - `user_id` - The ID of the user.
+    ///
+    /// # Returns
+    /// The role of the user, or `None` if they are not a member of the world.
+    async fn get_role(&self, world_id: &WorldId, user_id: &UserId) -> Option<Role>;
+
+    /// Get all of the members of a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world.
+    ///
+    /// # Returns
+    /// The members of the world, in the order that they joined.
+    async fn get_members(&self, world_id: &WorldId) -> Vec<Membership>;
+
+    /// Add a member to a world, replacing the role that they have if they are already a member.
+    ///
+    /// # Parameters
+    /// - `membership` - The membership to store.
+    async fn save_member(&self, membership: &Membership) -> Result<(), SaveWorldError>;
+
+    /// Remove a member from a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world.
+    /// - `user_id` - The ID of the user.
+    ///
+    /// # Returns
+    /// True if the user was a member and has been removed. False if not.
+    async fn remove_member(&self, world_id: &WorldId, user_id: &UserId) -> bool;
+
+    /// Create a new invitation to join a world.
+    ///
+    /// # Parameters
+    /// - `invitation` - The details of the invitation to create.
+    ///
+    /// # Returns
+    /// The created invitation resource.
+    async fn create_invitation(&self, invitation: &InvitationData) -> Result<InvitationResource, SaveWorldError>;
+
+    /// Get all of the outstanding invitations to join a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world.
+    ///
+    /// # Returns
+    /// The invitations, newest first.
+    async fn get_invitations_for_world(&self, world_id: &WorldId) -> Vec<InvitationResource>;
+
+    /// Get the invitation that has the provided secret hash.
+    ///
+    /// # Parameters
+    /// - `token_hash` - The hash of the invitation secret.
+    ///
+    /// # Returns
+    /// The invitation resource, or `None` if it doesn't exist.
+    async fn get_invitation_by_hash(&self, token_hash: &str) -> Option<InvitationResource>;
+
+    /// Delete an invitation.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world that the invitation is for.
+    /// - `invitation_id` - The ID of the invitation.
+    ///
+    /// # Returns
+    /// True if the invitation existed and was deleted. False if not.
+    async fn delete_invitation(&self, world_id: &WorldId, invitation_id: &InvitationId) -> bool;
+}
@@ -0,0 +1,137 @@
+use std::{cmp::Reverse, sync::Mutex};
+
+use super::{SaveWorldError, WorldRepository};
+use crate::{
+    model::Identity,
+    users::UserId,
+    worlds::{InvitationData, InvitationId, InvitationResource, Membership, Role, WorldData, WorldId, WorldResource},
+};
+
+/// Repository of world records that are stored in memory.
+#[derive(Default)]
+pub struct MemoryWorldRepository {
+    worlds:      Mutex<Vec<WorldResource>>,
+    members:     Mutex<Vec<Membership>>,
+    invitations: Mutex<Vec<InvitationResource>>,
+}
+
+impl MemoryWorldRepository {
+    /// Create a new, empty world repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Determine if a world with the provided ID exists.
+    fn world_exists(&self, world_id: &WorldId) -> bool {
+        self.worlds.lock().unwrap().iter().any(|w| &w.identity.id == world_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl WorldRepository for MemoryWorldRepository {
+    async fn create_world(&self, world: &WorldData, owner: &UserId) -> Result<WorldResource, SaveWorldError> {
+        let created = WorldResource {
+            identity: Identity::default(),
+            data:     world.clone(),
+        };
+
+        self.worlds.lock().unwrap().push(created.clone());
+        self.members.lock().unwrap().push(Membership {
+            world_id: created.identity.id.clone(),
+            user_id:  owner.clone(),
+            role:     Role::Owner,
+            joined:   created.identity.created,
+        });
+
+        Ok(created)
+    }
+
+    async fn get_world_by_id(&self, world_id: &WorldId) -> Option<WorldResource> {
+        let worlds = self.worlds.lock().unwrap();
+
+        worlds.iter().find(|w| &w.identity.id == world_id).cloned()
+    }
+
+    async fn get_role(&self, world_id: &WorldId, user_id: &UserId) -> Option<Role> {
+        let members = self.members.lock().unwrap();
+
+        members
+            .iter()
+            .find(|m| &m.world_id == world_id && &m.user_id == user_id)
+            .map(|m| m.role)
+    }
+
+    async fn get_members(&self, world_id: &WorldId) -> Vec<Membership> {
+        let members = self.members.lock().unwrap();
+
+        let mut result: Vec<Membership> = members.iter().filter(|m| &m.world_id == world_id).cloned().collect();
+        result.sort_by_key(|m| m.joined);
+
+        result
+    }
+
+    async fn save_member(&self, membership: &Membership) -> Result<(), SaveWorldError> {
+        if !self.world_exists(&membership.world_id) {
+            return Err(SaveWorldError::UnknownReference);
+        }
+
+        let mut members = self.members.lock().unwrap();
+
+        match members
+            .iter_mut()
+            .find(|m| m.world_id == membership.world_id && m.user_id == membership.user_id)
+        {
+            Some(existing) => existing.role = membership.role,
+            None => members.push(membership.clone()),
+        }
+
+        Ok(())
+    }
+
+    async fn remove_member(&self, world_id: &WorldId, user_id: &UserId) -> bool {
+        let mut members = self.members.lock().unwrap();
+
+        let before = members.len();
+        members.retain(|m| &m.world_id != world_id || &m.user_id != user_id);
+
+        members.len() != before
+    }
+
+    async fn create_invitation(&self, invitation: &InvitationData) -> Result<InvitationResource, SaveWorldError> {
+        if !self.world_exists(&invitation.world_id) {
+            return Err(SaveWorldError::UnknownReference);
+        }
+
+        let created = InvitationResource {
+            identity: Identity::default(),
+            data:     invitation.clone(),
+        };
+        self.invitations.lock().unwrap().push(created.clone());
+
+        Ok(created)
+    }
+
+    async fn get_invitations_for_world(&self, world_id: &WorldId) -> Vec<InvitationResource> {
+        let invitations = self.invitations.lock().unwrap();
+
+        let mut result: Vec<InvitationResource> = invitations.iter().filter(|i| &i.data.world_id == world_id).cloned().collect();
+        result.sort_by_key(|i| Reverse(i.identity.created));
+
+        result
+    }
+
+    async fn get_invitation_by_hash(&self, token_hash: &str) -> Option<InvitationResource> {
+        let invitations = self.invitations.lock().unwrap();
+
+        invitations.iter().find(|i| i.data.token_hash == token_hash).cloned()
+    }
+
+    async fn delete_invitation(&self, world_id: &WorldId, invitation_id: &InvitationId) -> bool {
+        let mut invitations = self.invitations.lock().unwrap();
+
+        let before = invitations.len();
+        invitations.retain(|i| &i.identity.id != invitation_id || &i.data.world_id != world_id);
+
+        invitations.len() != before
+    }
+}
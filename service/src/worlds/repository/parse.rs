@@ -0,0 +1,60 @@
+use tokio_postgres::Row;
+
+use crate::{
+    model::Identity,
+    users::{Email, Username},
+    worlds::{InvitationData, InvitationResource, Invitee, Membership, WorldData, WorldResource},
+};
+
+impl From<Row> for WorldResource {
+    fn from(row: Row) -> Self {
+        WorldResource {
+            identity: Identity {
+                id:      row.get("world_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data:     WorldData {
+                name:        row.get("name"),
+                description: row.get("description"),
+                language:    row.get("language"),
+            },
+        }
+    }
+}
+
+impl From<Row> for Membership {
+    fn from(row: Row) -> Self {
+        Membership {
+            world_id: row.get("world_id"),
+            user_id:  row.get("user_id"),
+            role:     row.get("role"),
+            joined:   row.get("joined"),
+        }
+    }
+}
+
+impl From<Row> for InvitationResource {
+    fn from(row: Row) -> Self {
+        let username: Option<Username> = row.get("invitee_username");
+        let email: Option<Email> = row.get("invitee_email");
+
+        InvitationResource {
+            identity: Identity {
+                id:      row.get("invitation_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data:     InvitationData {
+                world_id:   row.get("world_id"),
+                invited_by: row.get("invited_by"),
+                invitee:    username.map_or_else(|| Invitee::Email(email.unwrap()), Invitee::Username),
+                role:       row.get("role"),
+                token_hash: row.get("token_hash"),
+                expires:    row.get("expires"),
+            },
+        }
+    }
+}
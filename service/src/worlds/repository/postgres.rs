@@ -0,0 +1,213 @@
+use std::sync::Arc;
+
+use super::{SaveWorldError, WorldRepository};
+use crate::{
+    database::Database,
+    model::Identity,
+    users::UserId,
+    worlds::{InvitationData, InvitationId, InvitationResource, Invitee, Membership, Role, WorldData, WorldId, WorldResource},
+};
+
+/// Repository of world records that are stored in Postgres.
+pub struct PostgresWorldRepository {
+    database: Arc<Database>,
+}
+
+impl PostgresWorldRepository {
+    /// Create a new world repository.
+    pub fn new(database: Arc<Database>) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait::async_trait]
+impl WorldRepository for PostgresWorldRepository {
+    #[tracing::instrument(skip(self))]
+    async fn create_world(&self, world: &WorldData, owner: &UserId) -> Result<WorldResource, SaveWorldError> {
+        let mut conn = self.database.connect().await;
+        let tx = conn.begin().await;
+
+        let identity = Identity::<WorldId>::default();
+
+        let created: WorldResource = tx
+            .query(
+                "INSERT INTO worlds(world_id, version, created, updated, name, description, language) VALUES ($1, $2, $3, $4, $5, $6, $7) RETURNING *",
+                &[
+                    &identity.id,
+                    &identity.version,
+                    &identity.created,
+                    &identity.updated,
+                    &world.name,
+                    &world.description,
+                    &world.language,
+                ],
+            )
+            .await?
+            .into_iter()
+            .map(WorldResource::from)
+            .next()
+            .ok_or(SaveWorldError::UnknownError)?;
+
+        tx.execute(
+            "INSERT INTO world_members(world_id, user_id, role, joined) VALUES ($1, $2, $3, $4)",
+            &[&identity.id, &owner, &Role::Owner, &identity.created],
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(created)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_world_by_id(&self, world_id: &WorldId) -> Option<WorldResource> {
+        let conn = self.database.connect().await;
+        conn.query_opt("SELECT * FROM worlds WHERE world_id = $1", &[&world_id])
+            .await
+            .map_or_else(
+                |e| {
+                    tracing::warn!(e = ?e, "Failed to load world");
+                    None
+                },
+                |row| row.map(WorldResource::from),
+            )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_role(&self, world_id: &WorldId, user_id: &UserId) -> Option<Role> {
+        let conn = self.database.connect().await;
+        conn.query_opt(
+            "SELECT role FROM world_members WHERE world_id = $1 AND user_id = $2",
+            &[&world_id, &user_id],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to load world membership");
+                None
+            },
+            |row| row.map(|row| row.get("role")),
+        )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_members(&self, world_id: &WorldId) -> Vec<Membership> {
+        let conn = self.database.connect().await;
+        conn.query("SELECT * FROM world_members WHERE world_id = $1 ORDER BY joined ASC", &[&world_id])
+            .await
+            .map_or_else(
+                |e| {
+                    tracing::warn!(e = ?e, "Failed to load world members");
+                    vec![]
+                },
+                |rows| rows.into_iter().map(Membership::from).collect(),
+            )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn save_member(&self, membership: &Membership) -> Result<(), SaveWorldError> {
+        let conn = self.database.connect().await;
+        conn.execute(
+            "INSERT INTO world_members(world_id, user_id, role, joined) VALUES ($1, $2, $3, $4) ON CONFLICT (world_id, user_id) DO UPDATE SET role = EXCLUDED.role",
+            &[&membership.world_id, &membership.user_id, &membership.role, &membership.joined],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn remove_member(&self, world_id: &WorldId, user_id: &UserId) -> bool {
+        let conn = self.database.connect().await;
+        conn.execute(
+            "DELETE FROM world_members WHERE world_id = $1 AND user_id = $2",
+            &[&world_id, &user_id],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to remove world member");
+                false
+            },
+            |count| count == 1,
+        )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn create_invitation(&self, invitation: &InvitationData) -> Result<InvitationResource, SaveWorldError> {
+        let conn = self.database.connect().await;
+
+        let identity = Identity::<InvitationId>::default();
+        let (username, email) = match &invitation.invitee {
+            Invitee::Username(username) => (Some(username), None),
+            Invitee::Email(email) => (None, Some(email)),
+        };
+
+        let created: InvitationResource = conn.query_one("INSERT INTO world_invitations(invitation_id, version, created, updated, world_id, invited_by, invitee_username, invitee_email, role, token_hash, expires) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11) RETURNING *",
+        &[
+          &identity.id,
+          &identity.version,
+          &identity.created,
+          &identity.updated,
+          &invitation.world_id,
+          &invitation.invited_by,
+          &username,
+          &email,
+          &invitation.role,
+          &invitation.token_hash,
+          &invitation.expires,
+          ])
+            .await
+            .map(InvitationResource::from)?;
+
+        Ok(created)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_invitations_for_world(&self, world_id: &WorldId) -> Vec<InvitationResource> {
+        let conn = self.database.connect().await;
+        conn.query(
+            "SELECT * FROM world_invitations WHERE world_id = $1 ORDER BY created DESC",
+            &[&world_id],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to load world invitations");
+                vec![]
+            },
+            |rows| rows.into_iter().map(InvitationResource::from).collect(),
+        )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_invitation_by_hash(&self, token_hash: &str) -> Option<InvitationResource> {
+        let conn = self.database.connect().await;
+        conn.query_opt("SELECT * FROM world_invitations WHERE token_hash = $1", &[&token_hash])
+            .await
+            .map_or_else(
+                |e| {
+                    tracing::warn!(e = ?e, "Failed to load world invitation");
+                    None
+                },
+                |row| row.map(InvitationResource::from),
+            )
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_invitation(&self, world_id: &WorldId, invitation_id: &InvitationId) -> bool {
+        let conn = self.database.connect().await;
+        conn.execute(
+            "DELETE FROM world_invitations WHERE world_id = $1 AND invitation_id = $2",
+            &[&world_id, &invitation_id],
+        )
+        .await
+        .map_or_else(
+            |e| {
+                tracing::warn!(e = ?e, "Failed to delete world invitation");
+                false
+            },
+            |count| count == 1,
+        )
+    }
+}
@@ -0,0 +1,22 @@
+use tokio_postgres::error::SqlState;
+
+/// Errors that can occur when saving a world, or the members and invitations of one.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveWorldError {
+    #[error("The world or user was not found")]
+    UnknownReference,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl From<tokio_postgres::Error> for SaveWorldError {
+    fn from(e: tokio_postgres::Error) -> Self {
+        if e.code() == Some(&SqlState::FOREIGN_KEY_VIOLATION) {
+            SaveWorldError::UnknownReference
+        } else {
+            tracing::warn!("Unexpected database error: {:?}", e);
+            SaveWorldError::UnknownError
+        }
+    }
+}
@@ -0,0 +1,25 @@
+mod invitations;
+mod members;
+mod worlds;
+
+use std::sync::Arc;
+
+pub use invitations::{InvitationError, NewInvitation};
+pub use members::RemoveMemberError;
+
+use super::repository::WorldRepository;
+
+/// Service layer for working with worlds, and the users that collaborate on them.
+pub struct WorldService {
+    repository: Arc<dyn WorldRepository>,
+}
+
+impl WorldService {
+    /// Create a new world service.
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to load and store worlds in
+    pub fn new(repository: Arc<dyn WorldRepository>) -> Self {
+        Self { repository }
+    }
+}
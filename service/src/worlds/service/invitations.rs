@@ -0,0 +1,403 @@
+use chrono::{DateTime, Utc};
+
+use super::WorldService;
+use crate::{
+    users::{UserId, UserResource},
+    worlds::{InvitationData, InvitationId, InvitationResource, InvitationSecret, Invitee, Membership, Role, SaveWorldError, WorldId},
+};
+
+/// Details needed to invite somebody to join a world.
+#[derive(Debug)]
+pub struct NewInvitation {
+    pub invitee: Invitee,
+    pub role:    Role,
+    pub expires: DateTime<Utc>,
+}
+
+/// Errors that can happen when responding to an invitation.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum InvitationError {
+    #[error("The invitation was not found")]
+    UnknownInvitation,
+
+    #[error("The invitation is for a different user")]
+    WrongUser,
+
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl WorldService {
+    /// Invite somebody to join a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    /// - `invited_by` - The ID of the user that is sending the invitation
+    /// - `invitation` - The details of the invitation
+    ///
+    /// # Returns
+    /// The newly created invitation, and the secret needed to respond to it. The secret is not
+    /// stored, so this is the only time that it is available.
+    pub async fn invite(
+        &self,
+        world_id: &WorldId,
+        invited_by: &UserId,
+        invitation: NewInvitation,
+    ) -> Result<(InvitationResource, InvitationSecret), SaveWorldError> {
+        let secret = InvitationSecret::generate();
+
+        let created = self
+            .repository
+            .create_invitation(&InvitationData {
+                world_id:   world_id.clone(),
+                invited_by: invited_by.clone(),
+                invitee:    invitation.invitee,
+                role:       invitation.role,
+                token_hash: secret.hash(),
+                expires:    invitation.expires,
+            })
+            .await?;
+
+        Ok((created, secret))
+    }
+
+    /// Get all of the outstanding invitations to join a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    ///
+    /// # Returns
+    /// The invitations, newest first.
+    pub async fn get_invitations(&self, world_id: &WorldId) -> Vec<InvitationResource> {
+        self.repository.get_invitations_for_world(world_id).await
+    }
+
+    /// Revoke an outstanding invitation, so that it can no longer be accepted.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    /// - `invitation_id` - The ID of the invitation
+    ///
+    /// # Returns
+    /// True if the invitation was revoked. False if it didn't exist.
+    pub async fn revoke_invitation(&self, world_id: &WorldId, invitation_id: &InvitationId) -> bool {
+        self.repository.delete_invitation(world_id, invitation_id).await
+    }
+
+    /// Accept an invitation, making the user a member of the world.
+    /// If the user is already a member of the world with a more privileged role then they keep it.
+    ///
+    /// # Parameters
+    /// - `secret` - The secret of the invitation
+    /// - `user` - The user that is accepting the invitation
+    ///
+    /// # Returns
+    /// The ID of the world, and the role that the user now has within it.
+    pub async fn accept_invitation(&self, secret: &str, user: &UserResource) -> Result<(WorldId, Role), InvitationError> {
+        let invitation = self.find_invitation(secret, user).await?;
+        let world_id = invitation.data.world_id;
+
+        let role = match self.repository.get_role(&world_id, &user.identity.id).await {
+            Some(existing) if existing.includes(invitation.data.role) => existing,
+            _ => {
+                self.repository
+                    .save_member(&Membership {
+                        world_id: world_id.clone(),
+                        user_id:  user.identity.id.clone(),
+                        role:     invitation.data.role,
+                        joined:   Utc::now(),
+                    })
+                    .await
+                    .map_err(|e| {
+                        tracing::warn!(e = ?e, "Failed to add world member");
+                        InvitationError::UnknownError
+                    })?;
+
+                invitation.data.role
+            },
+        };
+
+        self.repository.delete_invitation(&world_id, &invitation.identity.id).await;
+
+        Ok((world_id, role))
+    }
+
+    /// Decline an invitation, so that it can no longer be accepted.
+    ///
+    /// # Parameters
+    /// - `secret` - The secret of the invitation
+    /// - `user` - The user that is declining the invitation
+    pub async fn decline_invitation(&self, secret: &str, user: &UserResource) -> Result<(), InvitationError> {
+        let invitation = self.find_invitation(secret, user).await?;
+
+        self.repository
+            .delete_invitation(&invitation.data.world_id, &invitation.identity.id)
+            .await;
+
+        Ok(())
+    }
+
+    /// Find the invitation that has the provided secret, ensuring that it is for the provided user
+    /// and has not expired.
+    ///
+    /// # Parameters
+    /// - `secret` - The secret of the invitation
+    /// - `user` - The user that is responding to the invitation
+    ///
+    /// # Returns
+    /// The invitation.
+    async fn find_invitation(&self, secret: &str, user: &UserResource) -> Result<InvitationResource, InvitationError> {
+        let invitation = self
+            .repository
+            .get_invitation_by_hash(&InvitationSecret::hash_of(secret))
+            .await
+            .ok_or(InvitationError::UnknownInvitation)?;
+
+        if invitation.data.expires <= Utc::now() {
+            tracing::warn!(invitation_id = ?invitation.identity.id, "Invitation has expired");
+            self.repository
+                .delete_invitation(&invitation.data.world_id, &invitation.identity.id)
+                .await;
+            return Err(InvitationError::UnknownInvitation);
+        }
+
+        if !invitation.data.invitee.matches(&user.data) {
+            tracing::warn!(invitation_id = ?invitation.identity.id, user_id = ?user.identity.id, "Invitation is for a different user");
+            return Err(InvitationError::WrongUser);
+        }
+
+        Ok(invitation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert2::{check, let_assert};
+    use chrono::Duration;
+
+    use super::*;
+    use crate::{
+        model::Identity,
+        users::{Password, UserData},
+        worlds::{Language, MemoryWorldRepository, WorldData},
+    };
+
+    fn user(username: &str) -> UserResource {
+        UserResource {
+            identity: Identity::default(),
+            data:     UserData {
+                username:     username.parse().unwrap(),
+                email:        format!("{}@example.com", username).parse().unwrap(),
+                display_name: "Test User".to_owned(),
+                password:     Password::from_hash("hashed"),
+            },
+        }
+    }
+
+    async fn build_sut(owner: &UserResource) -> (WorldService, WorldId) {
+        let sut = WorldService::new(Arc::new(MemoryWorldRepository::new()));
+        let world = sut
+            .create_world(
+                &owner.identity.id,
+                WorldData {
+                    name:        "Middle Earth".to_owned(),
+                    description: None,
+                    language:    Language::English,
+                },
+            )
+            .await
+            .unwrap();
+
+        (sut, world.identity.id)
+    }
+
+    fn new_invitation(invitee: Invitee, role: Role, expires: DateTime<Utc>) -> NewInvitation {
+        NewInvitation { invitee, role, expires }
+    }
+
+    #[actix_rt::test]
+    async fn accept_by_username() {
+        let owner = user("owner");
+        let invitee = user("invitee");
+        let (sut, world_id) = build_sut(&owner).await;
+
+        let_assert!(
+            Ok((invitation, secret)) = sut
+                .invite(
+                    &world_id,
+                    &owner.identity.id,
+                    new_invitation(
+                        Invitee::Username("Invitee".parse().unwrap()),
+                        Role::Editor,
+                        Utc::now() + Duration::days(1)
+                    )
+                )
+                .await
+        );
+        check!(invitation.data.token_hash != secret.value());
+        check!(sut.get_invitations(&world_id).await.len() == 1);
+
+        let_assert!(Ok((joined, role)) = sut.accept_invitation(secret.value(), &invitee).await);
+        check!(joined == world_id);
+        check!(role == Role::Editor);
+        check!(sut.get_role(&world_id, &invitee.identity.id).await == Some(Role::Editor));
+        check!(sut.get_invitations(&world_id).await.is_empty());
+
+        let_assert!(Err(e) = sut.accept_invitation(secret.value(), &invitee).await);
+        check!(e == InvitationError::UnknownInvitation);
+    }
+
+    #[actix_rt::test]
+    async fn accept_by_email() {
+        let owner = user("owner");
+        let invitee = user("invitee");
+        let (sut, world_id) = build_sut(&owner).await;
+
+        let_assert!(
+            Ok((_, secret)) = sut
+                .invite(
+                    &world_id,
+                    &owner.identity.id,
+                    new_invitation(
+                        Invitee::Email("Invitee@Example.com".parse().unwrap()),
+                        Role::Viewer,
+                        Utc::now() + Duration::days(1)
+                    )
+                )
+                .await
+        );
+
+        let_assert!(Ok((_, role)) = sut.accept_invitation(secret.value(), &invitee).await);
+        check!(role == Role::Viewer);
+    }
+
+    #[actix_rt::test]
+    async fn accept_keeps_better_role() {
+        let owner = user("owner");
+        let (sut, world_id) = build_sut(&owner).await;
+
+        let_assert!(
+            Ok((_, secret)) = sut
+                .invite(
+                    &world_id,
+                    &owner.identity.id,
+                    new_invitation(
+                        Invitee::Username("owner".parse().unwrap()),
+                        Role::Viewer,
+                        Utc::now() + Duration::days(1)
+                    )
+                )
+                .await
+        );
+
+        let_assert!(Ok((_, role)) = sut.accept_invitation(secret.value(), &owner).await);
+        check!(role == Role::Owner);
+        check!(sut.get_role(&world_id, &owner.identity.id).await == Some(Role::Owner));
+    }
+
+    #[actix_rt::test]
+    async fn accept_wrong_user() {
+        let owner = user("owner");
+        let (sut, world_id) = build_sut(&owner).await;
+
+        let_assert!(
+            Ok((_, secret)) = sut
+                .invite(
+                    &world_id,
+                    &owner.identity.id,
+                    new_invitation(
+                        Invitee::Username("invitee".parse().unwrap()),
+                        Role::Editor,
+                        Utc::now() + Duration::days(1)
+                    )
+                )
+                .await
+        );
+
+        let other = user("other");
+        let_assert!(Err(e) = sut.accept_invitation(secret.value(), &other).await);
+        check!(e == InvitationError::WrongUser);
+        check!(sut.get_role(&world_id, &other.identity.id).await == None);
+        check!(sut.get_invitations(&world_id).await.len() == 1);
+    }
+
+    #[actix_rt::test]
+    async fn accept_expired() {
+        let owner = user("owner");
+        let invitee = user("invitee");
+        let (sut, world_id) = build_sut(&owner).await;
+
+        let_assert!(
+            Ok((_, secret)) = sut
+                .invite(
+                    &world_id,
+                    &owner.identity.id,
+                    new_invitation(
+                        Invitee::Username("invitee".parse().unwrap()),
+                        Role::Editor,
+                        Utc::now() - Duration::seconds(1)
+                    )
+                )
+                .await
+        );
+
+        let_assert!(Err(e) = sut.accept_invitation(secret.value(), &invitee).await);
+        check!(e == InvitationError::UnknownInvitation);
+        check!(sut.get_role(&world_id, &invitee.identity.id).await == None);
+        check!(sut.get_invitations(&world_id).await.is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn decline() {
+        let owner = user("owner");
+        let invitee = user("invitee");
+        let (sut, world_id) = build_sut(&owner).await;
+
+        let_assert!(
+            Ok((_, secret)) = sut
+                .invite(
+                    &world_id,
+                    &owner.identity.id,
+                    new_invitation(
+                        Invitee::Username("invitee".parse().unwrap()),
+                        Role::Editor,
+                        Utc::now() + Duration::days(1)
+                    )
+                )
+                .await
+        );
+
+        let_assert!(Ok(()) = sut.decline_invitation(secret.value(), &invitee).await);
+        check!(sut.get_role(&world_id, &invitee.identity.id).await == None);
+        check!(sut.get_invitations(&world_id).await.is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn revoke() {
+        let owner = user("owner");
+        let invitee = user("invitee");
+        let (sut, world_id) = build_sut(&owner).await;
+
+        let_assert!(
+            Ok((invitation, secret)) = sut
+                .invite(
+                    &world_id,
+                    &owner.identity.id,
+                    new_invitation(
+                        Invitee::Username("invitee".parse().unwrap()),
+                        Role::Editor,
+                        Utc::now() + Duration::days(1)
+                    )
+                )
+                .await
+        );
+
+        check!(!sut.revoke_invitation(&WorldId::default(), &invitation.identity.id).await);
+        check!(sut.revoke_invitation(&world_id, &invitation.identity.id).await);
+
+        let_assert!(Err(e) = sut.accept_invitation(secret.value(), &invitee).await);
+        check!(e == InvitationError::UnknownInvitation);
+    }
+}
@@ -0,0 +1,136 @@
+use super::WorldService;
+use crate::{
+    users::UserId,
+    worlds::{Membership, Role, WorldId},
+};
+
+/// Errors that can happen when removing a member from a world.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum RemoveMemberError {
+    #[error("The user is not a member of the world")]
+    NotMember,
+
+    #[error("The last owner of a world can not be removed")]
+    LastOwner,
+}
+
+impl WorldService {
+    /// Get all of the members of a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    ///
+    /// # Returns
+    /// The members of the world, in the order that they joined.
+    pub async fn get_members(&self, world_id: &WorldId) -> Vec<Membership> {
+        self.repository.get_members(world_id).await
+    }
+
+    /// Remove a member from a world. This is used both for members leaving a world, and for owners
+    /// removing other members.
+    /// Every world must always have an owner, so the last owner is not allowed to be removed.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    /// - `user_id` - The ID of the user to remove
+    pub async fn remove_member(&self, world_id: &WorldId, user_id: &UserId) -> Result<(), RemoveMemberError> {
+        let members = self.repository.get_members(world_id).await;
+
+        let member = members.iter().find(|m| &m.user_id == user_id).ok_or(RemoveMemberError::NotMember)?;
+
+        if member.role == Role::Owner && members.iter().filter(|m| m.role == Role::Owner).count() == 1 {
+            return Err(RemoveMemberError::LastOwner);
+        }
+
+        if self.repository.remove_member(world_id, user_id).await {
+            Ok(())
+        } else {
+            Err(RemoveMemberError::NotMember)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert2::{check, let_assert};
+    use chrono::Utc;
+
+    use super::*;
+    use crate::worlds::{Language, MemoryWorldRepository, WorldData, WorldRepository};
+
+    async fn build_sut(owner: &UserId) -> (WorldService, WorldId, Arc<MemoryWorldRepository>) {
+        let repository = Arc::new(MemoryWorldRepository::new());
+        let world = repository
+            .create_world(
+                &WorldData {
+                    name:        "Middle Earth".to_owned(),
+                    description: None,
+                    language:    Language::English,
+                },
+                owner,
+            )
+            .await
+            .unwrap();
+
+        (WorldService::new(repository.clone()), world.identity.id, repository)
+    }
+
+    async fn add_member(repository: &MemoryWorldRepository, world_id: &WorldId, role: Role) -> UserId {
+        let user_id = UserId::default();
+        repository
+            .save_member(&Membership {
+                world_id: world_id.clone(),
+                user_id: user_id.clone(),
+                role,
+                joined: Utc::now(),
+            })
+            .await
+            .unwrap();
+
+        user_id
+    }
+
+    #[actix_rt::test]
+    async fn remove_member() {
+        let owner = UserId::default();
+        let (sut, world_id, repository) = build_sut(&owner).await;
+        let member = add_member(&repository, &world_id, Role::Editor).await;
+
+        check!(sut.get_members(&world_id).await.len() == 2);
+
+        let_assert!(Ok(()) = sut.remove_member(&world_id, &member).await);
+        check!(sut.get_role(&world_id, &member).await == None);
+        check!(sut.get_members(&world_id).await.len() == 1);
+    }
+
+    #[actix_rt::test]
+    async fn remove_non_member() {
+        let owner = UserId::default();
+        let (sut, world_id, _) = build_sut(&owner).await;
+
+        let_assert!(Err(e) = sut.remove_member(&world_id, &UserId::default()).await);
+        check!(e == RemoveMemberError::NotMember);
+    }
+
+    #[actix_rt::test]
+    async fn remove_last_owner() {
+        let owner = UserId::default();
+        let (sut, world_id, repository) = build_sut(&owner).await;
+        add_member(&repository, &world_id, Role::Editor).await;
+
+        let_assert!(Err(e) = sut.remove_member(&world_id, &owner).await);
+        check!(e == RemoveMemberError::LastOwner);
+        check!(sut.get_role(&world_id, &owner).await == Some(Role::Owner));
+    }
+
+    #[actix_rt::test]
+    async fn remove_one_of_several_owners() {
+        let owner = UserId::default();
+        let (sut, world_id, repository) = build_sut(&owner).await;
+        add_member(&repository, &world_id, Role::Owner).await;
+
+        let_assert!(Ok(()) = sut.remove_member(&world_id, &owner).await);
+    }
+}
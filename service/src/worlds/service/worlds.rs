@@ -0,0 +1,77 @@
+use super::WorldService;
+use crate::{
+    users::UserId,
+    worlds::{Role, SaveWorldError, WorldData, WorldId, WorldResource},
+};
+
+impl WorldService {
+    /// Create a new world, owned by the provided user.
+    ///
+    /// # Parameters
+    /// - `owner` - The ID of the user that is creating the world
+    /// - `world` - The details of the world to create
+    ///
+    /// # Returns
+    /// The newly created world.
+    pub async fn create_world(&self, owner: &UserId, world: WorldData) -> Result<WorldResource, SaveWorldError> {
+        self.repository.create_world(&world, owner).await
+    }
+
+    /// Get the world that has the provided ID.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    ///
+    /// # Returns
+    /// The world, or `None` if it doesn't exist.
+    pub async fn get_world_by_id(&self, world_id: &WorldId) -> Option<WorldResource> {
+        self.repository.get_world_by_id(world_id).await
+    }
+
+    /// Get the role that a user has within a world.
+    ///
+    /// # Parameters
+    /// - `world_id` - The ID of the world
+    /// - `user_id` - The ID of the user
+    ///
+    /// # Returns
+    /// The role of the user, or `None` if they are not a member of the world.
+    pub async fn get_role(&self, world_id: &WorldId, user_id: &UserId) -> Option<Role> {
+        self.repository.get_role(world_id, user_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::worlds::{Language, MemoryWorldRepository};
+
+    #[actix_rt::test]
+    async fn create_world() {
+        let sut = WorldService::new(Arc::new(MemoryWorldRepository::new()));
+        let owner = UserId::default();
+
+        let_assert!(
+            Ok(created) = sut
+                .create_world(
+                    &owner,
+                    WorldData {
+                        name:        "Middle Earth".to_owned(),
+                        description: None,
+                        language:    Language::English,
+                    }
+                )
+                .await
+        );
+
+        let_assert!(Some(world) = sut.get_world_by_id(&created.identity.id).await);
+        check!(world.data.name == "Middle Earth");
+
+        check!(sut.get_role(&created.identity.id, &owner).await == Some(Role::Owner));
+        check!(sut.get_role(&created.identity.id, &UserId::default()).await == None);
+    }
+}